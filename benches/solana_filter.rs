@@ -0,0 +1,88 @@
+//! Benchmarks the matching of the transactions of a Solana block.
+//!
+//! The transactions are matched on the global rayon pool, and on a single-threaded pool to
+//! compare with matching them one after the other.
+
+use std::{collections::HashMap, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use openzeppelin_monitor::{
+	models::{Monitor, SolanaDecodedInstruction, SolanaTransaction},
+	services::{
+		decoders::kamino_lending_decoder::PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID,
+		filter::SolanaBlockFilter,
+	},
+	utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+/// Number of transactions of the benchmarked block, close to a busy mainnet block
+const TRANSACTION_COUNT: u64 = 3000;
+
+fn deposit_transaction(amount: u64) -> SolanaTransaction {
+	let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+	data.extend_from_slice(&amount.to_le_bytes());
+
+	TransactionBuilder::new()
+		.instruction(SolanaDecodedInstruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			data,
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+		})
+		.build()
+}
+
+fn deposit_monitor(name: &str, expression: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+		.function("DepositReserveLiquidity", Some(expression))
+		.build()
+}
+
+fn match_transactions(c: &mut Criterion) {
+	let filter = SolanaBlockFilter::<()>::new();
+	let transactions = (0..TRANSACTION_COUNT)
+		.map(|index| deposit_transaction(index * 1_000))
+		.collect::<Vec<_>>();
+	let monitors = vec![
+		deposit_monitor("Large deposits", "liquidity_amount > 2000000"),
+		deposit_monitor("Small deposits", "liquidity_amount < 1000"),
+		deposit_monitor("Round deposits", "liquidity_amount == 1000000"),
+	];
+	let enhanced = HashMap::new();
+	let sequential_pool = rayon::ThreadPoolBuilder::new()
+		.num_threads(1)
+		.build()
+		.expect("Failed to build the single-threaded pool");
+
+	let mut group = c.benchmark_group("match_transactions");
+	group.bench_function("parallel", |b| {
+		b.iter(|| {
+			filter.match_transactions(
+				black_box(&transactions),
+				&monitors,
+				&[],
+				"solana_mainnet",
+				&enhanced,
+			)
+		})
+	});
+	group.bench_function("sequential", |b| {
+		b.iter(|| {
+			sequential_pool.install(|| {
+				filter.match_transactions(
+					black_box(&transactions),
+					&monitors,
+					&[],
+					"solana_mainnet",
+					&enhanced,
+				)
+			})
+		})
+	});
+	group.finish();
+}
+
+criterion_group!(benches, match_transactions);
+criterion_main!(benches);
@@ -17,24 +17,31 @@
 
 use futures::future::BoxFuture;
 use std::{collections::HashMap, error::Error, sync::Arc};
-use tokio::sync::{watch, Mutex};
+use tokio::{
+	sync::{watch, Mutex},
+	task::JoinSet,
+};
 
 use crate::{
 	models::{
 		BlockChainType, BlockType, ContractSpec, Monitor, MonitorMatch, Network, ProcessedBlock,
-		ScriptLanguage, TriggerConditions,
+		ScriptLanguage, SolanaContractSpec, SolanaDecoderType, TriggerConditions,
 	},
 	repositories::{
 		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
 		TriggerRepositoryTrait, TriggerService,
 	},
 	services::{
-		blockchain::{BlockChainClient, BlockFilterFactory, ClientPoolTrait},
-		filter::{evm_helpers, handle_match, stellar_helpers, FilterService},
-		notification::NotificationService,
+		blockchain::{BlockChainClient, BlockFilterFactory, ClientPoolTrait, SolanaClientTrait},
+		decoders::DecoderRegistry,
+		filter::{
+			evm_helpers, handle_match, solana_helpers, stellar_helpers, FilterService,
+			MatchDeduplicator,
+		},
+		notification::{register_explorer, NotificationService},
 		trigger::{
-			ScriptError, ScriptExecutorFactory, TriggerError, TriggerExecutionService,
-			TriggerExecutionServiceTrait,
+			ScriptError, ScriptExecutorFactory, TriggerDispatcher, TriggerError,
+			TriggerExecutionService, TriggerExecutionServiceTrait,
 		},
 	},
 	utils::normalize_string,
@@ -117,6 +124,13 @@ where
 	let active_monitors = filter_active_monitors(monitors);
 	let networks = network_service.get_all();
 
+	// Link the matches of the networks configuring an explorer to it
+	for network in networks.values() {
+		if let Some(explorer) = &network.explorer {
+			register_explorer(&network.slug, explorer.clone());
+		}
+	}
+
 	Ok((
 		filter_service,
 		trigger_execution_service,
@@ -362,24 +376,93 @@ pub async fn get_contract_specs<P: ClientPoolTrait + 'static>(
 	all_specs
 }
 
+/// Get the contract specs of the monitored Solana programs that publish their Anchor IDL
+/// on-chain
+///
+/// Only networks enabling `fetch_onchain_idls` are considered, and only the monitored
+/// addresses without a contract spec. Programs with a built-in decoder, which is picked
+/// by their program id, and programs that publish no IDL are skipped.
+///
+/// # Arguments
+/// * `client` - The Solana client to fetch the IDLs with
+/// * `network` - The network of the monitors
+/// * `monitors` - The monitors to get the contract specs for
+///
+/// # Returns
+/// Returns a vector of contract specs, by program address
+pub async fn get_solana_idl_specs<C: SolanaClientTrait + Sync>(
+	client: &C,
+	network: &Network,
+	monitors: &[Monitor],
+) -> Vec<(String, ContractSpec)> {
+	if !network.fetch_onchain_idls.unwrap_or(false) {
+		return Vec::new();
+	}
+
+	let registry = DecoderRegistry::with_builtin_decoders();
+	let mut program_ids = monitors
+		.iter()
+		.flat_map(|monitor| &monitor.addresses)
+		.filter(|address| address.contract_spec.is_none())
+		.filter_map(|address| solana_helpers::parse_pubkey(&address.address))
+		.filter(|program_id| !registry.has_instruction_decoder(program_id))
+		.collect::<Vec<_>>();
+	program_ids.sort();
+	program_ids.dedup();
+
+	futures::future::join_all(
+		program_ids
+			.iter()
+			.map(|program_id| async move { (program_id, client.get_idl(program_id).await) }),
+	)
+	.await
+	.into_iter()
+	.filter_map(|(program_id, idl)| match idl {
+		Ok(idl) => Some((
+			program_id.to_string(),
+			ContractSpec::Solana(SolanaContractSpec::new(SolanaDecoderType::Idl(idl?))),
+		)),
+		Err(e) => {
+			tracing::warn!("Failed to fetch IDL for program {}: {:?}", program_id, e);
+			None
+		}
+	})
+	.collect()
+}
+
 /// Creates a trigger handler function that processes trigger events from the block processing
 /// pipeline.
 ///
+/// Matches already seen by the deduplicator, e.g. when a block is re-processed after a
+/// restart, do not execute their triggers again. The triggers of the other matches are
+/// executed concurrently through the dispatcher, which bounds the number of matches executing
+/// and queued across blocks. Queueing a match waits while the queue is full, so block
+/// processing, which awaits the handler, waits with it.
+///
 /// # Arguments
 /// * `shutdown_tx` - Watch channel for shutdown signals
 /// * `trigger_service` - Service for executing triggers
+/// * `match_deduplicator` - Filter for the matches already seen recently
+/// * `trigger_dispatcher` - Bounded queue of the matches whose triggers are executed
 ///
 /// # Returns
-/// Returns a function that handles trigger execution for matching monitors
+/// Returns a function that handles trigger execution for matching monitors, whose task
+/// completes once all the matches of the block are queued for the execution of their triggers
 pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 'static>(
 	shutdown_tx: watch::Sender<bool>,
 	trigger_service: Arc<S>,
 	active_monitors_trigger_scripts: HashMap<String, (ScriptLanguage, String)>,
+	match_deduplicator: Arc<MatchDeduplicator>,
+	trigger_dispatcher: TriggerDispatcher,
 ) -> Arc<impl Fn(&ProcessedBlock) -> tokio::task::JoinHandle<()> + Send + Sync> {
+	let active_monitors_trigger_scripts = Arc::new(active_monitors_trigger_scripts);
 	Arc::new(move |block: &ProcessedBlock| {
 		let mut shutdown_rx = shutdown_tx.subscribe();
+		let mut executions_shutdown_rx = shutdown_tx.subscribe();
 		let trigger_service = trigger_service.clone();
 		let trigger_scripts = active_monitors_trigger_scripts.clone();
+		let match_deduplicator = match_deduplicator.clone();
+		let trigger_dispatcher = trigger_dispatcher.clone();
 		let block = block.clone();
 
 		tokio::spawn(async move {
@@ -388,12 +471,29 @@ pub fn create_trigger_handler<S: TriggerExecutionServiceTrait + Send + Sync + 's
 					if block.processing_results.is_empty() {
 						return;
 					}
-					let filtered_matches = run_trigger_filters(&block.processing_results, &block.network_slug, &trigger_scripts).await;
-					for monitor_match in &filtered_matches {
-						if let Err(e) = handle_match(monitor_match.clone(), &*trigger_service, &trigger_scripts).await {
-							TriggerError::execution_error(e.to_string(), None, None);
-						}
+					let new_matches = match_deduplicator.retain_new(&block.processing_results).await;
+					let filtered_matches = run_trigger_filters(&new_matches, &block.network_slug, &trigger_scripts).await;
+
+					let mut executions = JoinSet::new();
+					for monitor_match in filtered_matches {
+						let queued = trigger_dispatcher.enqueue().await;
+						let trigger_service = trigger_service.clone();
+						let trigger_scripts = trigger_scripts.clone();
+						executions.spawn(queued.run(async move {
+							if let Err(e) = handle_match(monitor_match, &*trigger_service, &trigger_scripts).await {
+								TriggerError::execution_error(e.to_string(), None, None);
+							}
+						}));
 					}
+
+					// The matches of the block are queued, their executions go on without holding
+					// block processing back. Dropping the set on shutdown aborts them.
+					tokio::spawn(async move {
+						tokio::select! {
+							_ = async { while executions.join_next().await.is_some() {} } => {}
+							_ = executions_shutdown_rx.changed() => {}
+						}
+					});
 				} => {}
 				_ = shutdown_rx.changed() => {
 					tracing::info!("Shutting down trigger handling task");
@@ -485,14 +585,14 @@ async fn run_trigger_filters(
 		let trigger_conditions = match monitor_match {
 			MonitorMatch::EVM(evm_match) => &evm_match.monitor.trigger_conditions,
 			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.trigger_conditions,
-			MonitorMatch::Solana(solana_match) => todo!(),
+			MonitorMatch::Solana(solana_match) => &solana_match.monitor.trigger_conditions,
 		};
 
 		for trigger_condition in trigger_conditions {
 			let monitor_name = match monitor_match {
 				MonitorMatch::EVM(evm_match) => evm_match.monitor.name.clone(),
 				MonitorMatch::Stellar(stellar_match) => stellar_match.monitor.name.clone(),
-				MonitorMatch::Solana(solana_match) => todo!(),
+				MonitorMatch::Solana(solana_match) => solana_match.monitor.name.clone(),
 			};
 
 			let script_content = trigger_scripts
@@ -525,9 +625,9 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			EVMMonitorMatch, EVMReceiptLog, EVMTransaction, EVMTransactionReceipt, MatchConditions,
-			Monitor, MonitorMatch, ScriptLanguage, StellarBlock, StellarMonitorMatch,
-			StellarTransaction, StellarTransactionInfo, TriggerConditions,
+			ConditionLogic, EVMMonitorMatch, EVMReceiptLog, EVMTransaction, EVMTransactionReceipt,
+			MatchConditions, Monitor, MonitorMatch, ScriptLanguage, StellarBlock,
+			StellarMonitorMatch, StellarTransaction, StellarTransactionInfo, TriggerConditions,
 		},
 		utils::tests::{builders::evm::monitor::MonitorBuilder, evm::receipt::ReceiptBuilder},
 	};
@@ -625,6 +725,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					accounts: vec![],
+					balance_changes: vec![],
+					logs: vec![],
+					sequences: vec![],
+					blocks: vec![],
+					condition_logic: ConditionLogic::All,
 				},
 				matched_on_args: None,
 			})),
@@ -637,6 +743,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					accounts: vec![],
+					balance_changes: vec![],
+					logs: vec![],
+					sequences: vec![],
+					blocks: vec![],
+					condition_logic: ConditionLogic::All,
 				},
 				matched_on_args: None,
 			})),
@@ -660,6 +772,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					accounts: vec![],
+					balance_changes: vec![],
+					logs: vec![],
+					sequences: vec![],
+					blocks: vec![],
+					condition_logic: ConditionLogic::All,
 				},
 				matched_on_args: None,
 			})),
@@ -672,6 +790,12 @@ mod tests {
 					functions: vec![],
 					events: vec![],
 					transactions: vec![],
+					accounts: vec![],
+					balance_changes: vec![],
+					logs: vec![],
+					sequences: vec![],
+					blocks: vec![],
+					condition_logic: ConditionLogic::All,
 				},
 				matched_on_args: None,
 			})),
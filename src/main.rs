@@ -36,14 +36,25 @@ use crate::{
 	services::{
 		blockchain::{ClientPool, ClientPoolTrait},
 		blockwatcher::{BlockTracker, BlockTrackerTrait, BlockWatcherService, FileBlockStorage},
-		filter::FilterService,
-		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+		filter::{
+			FilterService, MatchDeduplicator, DEFAULT_MATCH_DEDUP_PATH, DEFAULT_MATCH_DEDUP_TTL,
+			MATCH_DEDUP_FLUSH_INTERVAL,
+		},
+		trigger::{
+			RetryPolicy, TriggerDispatcher, TriggerExecutionService, TriggerExecutionServiceTrait,
+			DEFAULT_CALLBACK_ADDRESS, DEFAULT_DEAD_LETTER_PATH, DEFAULT_INCIDENT_RESOLVE_AFTER,
+			DEFAULT_RETRY_QUEUE_PATH, DEFAULT_TRIGGER_MAX_CONCURRENCY,
+			DEFAULT_TRIGGER_QUEUE_CAPACITY, INCIDENT_SWEEP_INTERVAL, RETRY_POLL_INTERVAL,
+		},
 	},
 	utils::{
+		callback::server::create_callback_server,
 		constants::DOCUMENTATION_URL,
 		logging::setup_logging,
 		metrics::server::create_metrics_server,
 		monitor::{
+			backfill::{backfill_monitor, BackfillConfig},
+			dry_run::{dry_run_monitor, DryRunConfig, TransactionSource},
 			execution::{execute_monitor, MonitorExecutionConfig},
 			MonitorExecutionError,
 		},
@@ -51,12 +62,14 @@ use crate::{
 	},
 };
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use dotenvy::dotenv_override;
 use std::collections::HashMap;
 use std::env::{set_var, var};
+use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::sync::{watch, Mutex};
+use std::time::Duration;
+use tokio::sync::{broadcast::error::RecvError, watch, Mutex};
 use tokio_cron_scheduler::JobScheduler;
 use tracing::{error, info, instrument};
 
@@ -121,6 +134,10 @@ struct Cli {
 	#[arg(long)]
 	metrics: bool,
 
+	/// Seconds during which identical matches are not notified again (default: 0, disabled)
+	#[arg(long, value_name = "SECONDS")]
+	match_dedup_ttl: Option<u64>,
+
 	/// Path to the monitor to execute
 	#[arg(long, value_name = "MONITOR_PATH")]
 	monitor_path: Option<String>,
@@ -136,6 +153,54 @@ struct Cli {
 	/// Validate configuration files without starting the service
 	#[arg(long)]
 	check: bool,
+
+	#[command(subcommand)]
+	command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Evaluate the conditions of a Solana monitor against a single transaction
+	TestExpression {
+		/// Path to the monitor to evaluate
+		#[arg(long, value_name = "MONITOR_PATH")]
+		monitor_path: String,
+
+		/// Signature of the transaction to fetch from the network
+		#[arg(
+			long,
+			value_name = "SIGNATURE",
+			required_unless_present = "fixture",
+			conflicts_with = "fixture"
+		)]
+		signature: Option<String>,
+
+		/// Path to a JSON fixture holding a getTransaction RPC response
+		#[arg(long, value_name = "FIXTURE_PATH")]
+		fixture: Option<String>,
+
+		/// Network to fetch the transaction from (default: first Solana network of the monitor)
+		#[arg(long, value_name = "NETWORK_SLUG")]
+		network: Option<String>,
+	},
+	/// Match a Solana monitor against the history of its addresses over a range of slots
+	Backfill {
+		/// Path to the monitor to backfill
+		#[arg(long, value_name = "MONITOR_PATH")]
+		monitor_path: String,
+
+		/// First slot to backfill
+		#[arg(long, value_name = "SLOT")]
+		start_slot: u64,
+
+		/// Last slot to backfill (default: latest slot of the network)
+		#[arg(long, value_name = "SLOT")]
+		end_slot: Option<u64>,
+
+		/// Network to backfill (default: first Solana network of the monitor)
+		#[arg(long, value_name = "NETWORK_SLUG")]
+		network: Option<String>,
+	},
 }
 
 impl Cli {
@@ -183,6 +248,11 @@ impl Cli {
 				set_var("METRICS_PORT", port);
 			}
 		}
+
+		// Match deduplication TTL - override if CLI flag is set
+		if let Some(ttl) = self.match_dedup_ttl {
+			set_var("MATCH_DEDUP_TTL", ttl.to_string());
+		}
 	}
 }
 
@@ -208,6 +278,69 @@ async fn main() -> Result<()> {
 		return Ok(());
 	}
 
+	// If the test-expression subcommand is provided, only evaluate the monitor and exit
+	if let Some(Command::TestExpression {
+		monitor_path,
+		signature,
+		fixture,
+		network,
+	}) = &cli.command
+	{
+		let (_, _, _, _, monitor_service, network_service, _) = initialize_services::<
+			MonitorRepository<NetworkRepository, TriggerRepository>,
+			NetworkRepository,
+			TriggerRepository,
+		>(None, None, None)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))?;
+
+		let source = match (signature, fixture) {
+			(_, Some(fixture)) => TransactionSource::Fixture(fixture.clone()),
+			(Some(signature), None) => TransactionSource::Signature(signature.clone()),
+			(None, None) => {
+				return Err(
+					anyhow::anyhow!("A transaction signature or fixture is required").into(),
+				)
+			}
+		};
+
+		return test_expression(DryRunConfig {
+			path: monitor_path.clone(),
+			source,
+			network_slug: network.clone(),
+			monitor_service,
+			network_service,
+		})
+		.await;
+	}
+
+	// If the backfill subcommand is provided, only report the matches of the slot range and exit
+	if let Some(Command::Backfill {
+		monitor_path,
+		start_slot,
+		end_slot,
+		network,
+	}) = &cli.command
+	{
+		let (_, _, _, _, monitor_service, network_service, _) = initialize_services::<
+			MonitorRepository<NetworkRepository, TriggerRepository>,
+			NetworkRepository,
+			TriggerRepository,
+		>(None, None, None)
+		.await
+		.map_err(|e| anyhow::anyhow!("Failed to initialize services: {}. Please refer to the documentation quickstart ({}) on how to configure the service.", e, DOCUMENTATION_URL))?;
+
+		return backfill(BackfillConfig {
+			path: monitor_path.clone(),
+			network_slug: network.clone(),
+			start_slot: *start_slot,
+			end_slot: *end_slot,
+			monitor_service,
+			network_service,
+		})
+		.await;
+	}
+
 	let (
 		filter_service,
 		trigger_execution_service,
@@ -323,6 +456,49 @@ async fn main() -> Result<()> {
 		})
 		.collect::<Vec<_>>();
 
+	// Incidents without match for INCIDENT_RESOLVE_AFTER seconds are resolved
+	trigger_execution_service.lifecycle().set_resolve_after(
+		var("INCIDENT_RESOLVE_AFTER")
+			.ok()
+			.and_then(|secs| secs.parse().ok())
+			.map(Duration::from_secs)
+			.unwrap_or(DEFAULT_INCIDENT_RESOLVE_AFTER),
+	);
+
+	// Escalated matches and incidents are acknowledged through the callback server, which
+	// always runs when a monitor has an escalation
+	let callback_enabled = var("CALLBACK_ENABLED")
+		.map(|v| v == "true")
+		.unwrap_or(false);
+	let callback_server = if callback_enabled
+		|| active_monitors
+			.iter()
+			.any(|monitor| monitor.escalation.is_some())
+	{
+		let callback_address =
+			var("CALLBACK_ADDRESS").unwrap_or_else(|_| DEFAULT_CALLBACK_ADDRESS.to_string());
+		let callback_url =
+			var("CALLBACK_URL").unwrap_or_else(|_| format!("http://{}", callback_address));
+		match create_callback_server(
+			callback_address,
+			trigger_execution_service.escalations(),
+			trigger_execution_service.lifecycle(),
+		) {
+			Ok(server) => {
+				trigger_execution_service.set_callback_url(&callback_url);
+				let handle = server.handle();
+				tokio::spawn(server);
+				Some(handle)
+			}
+			Err(e) => {
+				error!("Failed to create callback server: {}", e);
+				None
+			}
+		}
+	} else {
+		None
+	};
+
 	// Fetch all contract specs for all active monitors
 	let contract_specs = get_contract_specs(&client_pool, &network_monitors).await;
 
@@ -334,10 +510,115 @@ async fn main() -> Result<()> {
 		client_pool.clone(),
 		contract_specs,
 	);
+
+	// When MATCH_DEDUP_TTL is set, matches seen within the TTL, e.g. when re-processing blocks
+	// after a restart, are not notified again
+	let match_dedup_ttl = var("MATCH_DEDUP_TTL")
+		.ok()
+		.and_then(|ttl| ttl.parse().ok())
+		.map(Duration::from_secs)
+		.unwrap_or(DEFAULT_MATCH_DEDUP_TTL);
+	let match_deduplicator = Arc::new(
+		MatchDeduplicator::with_storage(match_dedup_ttl, PathBuf::from(DEFAULT_MATCH_DEDUP_PATH))
+			.await,
+	);
+	let dedup_flusher = match_deduplicator.clone();
+	let mut dedup_shutdown_rx = shutdown_tx.subscribe();
+	tokio::spawn(async move {
+		let mut interval = tokio::time::interval(MATCH_DEDUP_FLUSH_INTERVAL);
+		loop {
+			tokio::select! {
+				_ = interval.tick() => dedup_flusher.flush().await,
+				_ = dedup_shutdown_rx.changed() => break,
+			}
+		}
+	});
+
+	// Notifications failing after the HTTP retries are sent again with an exponential backoff,
+	// and appended to the dead-letter file once they exhausted their retries
+	let default_retry_policy = RetryPolicy::default();
+	let retry_policy = RetryPolicy {
+		max_attempts: var("NOTIFICATION_RETRY_MAX_ATTEMPTS")
+			.ok()
+			.and_then(|attempts| attempts.parse().ok())
+			.unwrap_or(default_retry_policy.max_attempts),
+		initial_backoff: var("NOTIFICATION_RETRY_INITIAL_BACKOFF")
+			.ok()
+			.and_then(|backoff| backoff.parse().ok())
+			.map(Duration::from_secs)
+			.unwrap_or(default_retry_policy.initial_backoff),
+		max_backoff: var("NOTIFICATION_RETRY_MAX_BACKOFF")
+			.ok()
+			.and_then(|backoff| backoff.parse().ok())
+			.map(Duration::from_secs)
+			.unwrap_or(default_retry_policy.max_backoff),
+	};
+	trigger_execution_service
+		.retry_queue()
+		.configure(
+			retry_policy,
+			PathBuf::from(DEFAULT_RETRY_QUEUE_PATH),
+			PathBuf::from(DEFAULT_DEAD_LETTER_PATH),
+		)
+		.await;
+	let retry_service = trigger_execution_service.clone();
+	let mut retry_shutdown_rx = shutdown_tx.subscribe();
+	tokio::spawn(async move {
+		let mut interval = tokio::time::interval(RETRY_POLL_INTERVAL);
+		loop {
+			tokio::select! {
+				_ = interval.tick() => retry_service.retry_pending().await,
+				_ = retry_shutdown_rx.changed() => break,
+			}
+		}
+	});
+
+	// Resolved incidents resolve the PagerDuty alerts of their matches, and incidents without
+	// recent match are swept so that their resolution is not delayed until their next match
+	let lifecycle = trigger_execution_service.lifecycle();
+	let mut resolutions = lifecycle.subscribe_resolutions();
+	let resolution_service = trigger_execution_service.clone();
+	let mut lifecycle_shutdown_rx = shutdown_tx.subscribe();
+	tokio::spawn(async move {
+		let mut interval = tokio::time::interval(INCIDENT_SWEEP_INTERVAL);
+		loop {
+			tokio::select! {
+				_ = interval.tick() => lifecycle.sweep(),
+				resolution = resolutions.recv() => match resolution {
+					Ok(incident) => resolution_service.resolve_incident(&incident).await,
+					Err(RecvError::Lagged(skipped)) => {
+						tracing::warn!("{} incident resolutions were not propagated", skipped)
+					}
+					Err(RecvError::Closed) => break,
+				},
+				_ = lifecycle_shutdown_rx.changed() => break,
+			}
+		}
+	});
+
+	let email_digests = trigger_execution_service
+		.notification_service()
+		.email_digests();
+	let match_aggregator = trigger_execution_service.aggregator();
+
+	// At most TRIGGER_MAX_CONCURRENCY matches execute their triggers at a time, and block
+	// processing waits once TRIGGER_QUEUE_CAPACITY matches are queued or executing
+	let trigger_dispatcher = TriggerDispatcher::new(
+		var("TRIGGER_MAX_CONCURRENCY")
+			.ok()
+			.and_then(|concurrency| concurrency.parse().ok())
+			.unwrap_or(DEFAULT_TRIGGER_MAX_CONCURRENCY),
+		var("TRIGGER_QUEUE_CAPACITY")
+			.ok()
+			.and_then(|capacity| capacity.parse().ok())
+			.unwrap_or(DEFAULT_TRIGGER_QUEUE_CAPACITY),
+	);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx.clone(),
 		trigger_execution_service,
 		active_monitors_trigger_scripts,
+		match_deduplicator.clone(),
+		trigger_dispatcher,
 	);
 
 	let file_block_storage = Arc::new(FileBlockStorage::default());
@@ -420,6 +701,15 @@ async fn main() -> Result<()> {
 
 	tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
+	if let Some(callback_server) = callback_server {
+		callback_server.stop(true).await;
+	}
+
+	// Send the aggregated matches and email digests still waiting for their window
+	match_aggregator.flush().await;
+	email_digests.flush().await;
+	match_deduplicator.flush().await;
+
 	info!("Shutdown complete");
 	Ok(())
 }
@@ -682,6 +972,114 @@ async fn test_monitor_execution(config: MonitorExecutionTestConfig) -> Result<()
 	}
 }
 
+/// Evaluates a monitor against a single transaction and prints the outcome of each condition.
+///
+/// This is meant for debugging monitors that do not fire, by showing which conditions matched
+/// and the parameters extracted from the transaction.
+///
+/// # Arguments
+/// * `config` - Configuration of the dry run
+///
+/// # Returns
+/// * `Result<()>` - Ok(()) if the monitor could be evaluated, or an error otherwise
+#[instrument(skip_all)]
+async fn test_expression(
+	config: DryRunConfig<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>,
+) -> Result<()> {
+	let path = config.path.clone();
+	let report = dry_run_monitor(config).await.map_err(|e| {
+		MonitorExecutionError::execution_error(
+			"Expression test failed",
+			Some(e.into()),
+			Some(HashMap::from([("path".to_string(), path)])),
+		)
+	})?;
+
+	info!("=========== Expression Test Results ===========");
+	info!("Monitor: {}", report.monitor);
+	info!("Transaction: {} (slot {})", report.signature, report.slot);
+	info!(
+		"Result: {}",
+		if report.matched {
+			"monitor matches the transaction"
+		} else {
+			"monitor does not match the transaction"
+		}
+	);
+
+	info!("Conditions:");
+	for condition in &report.conditions {
+		let mut line = format!(
+			"  {} {}: {}",
+			if condition.matched { "✓" } else { "✗" },
+			condition.kind,
+			condition.target
+		);
+		if let Some(expression) = &condition.expression {
+			line.push_str(&format!(" where {}", expression));
+		}
+		if let Some(reason) = &condition.reason {
+			line.push_str(&format!(" ({})", reason));
+		}
+		info!("{}", line);
+	}
+	if report.skipped_account_conditions > 0 {
+		tracing::warn!(
+			"{} account condition(s) skipped, they are evaluated against account state only",
+			report.skipped_account_conditions
+		);
+	}
+
+	info!("Extracted Parameters:");
+	for extracted in &report.params {
+		info!("  {} {}:", extracted.kind, extracted.signature);
+		for param in &extracted.params {
+			info!("    - {} ({}) = {}", param.name, param.kind, param.value);
+		}
+	}
+
+	info!("===============================================");
+	Ok(())
+}
+
+/// Matches a Solana monitor against a range of slots and prints the matches found.
+///
+/// This is meant for recovering the matches of a period the monitor was not running, such as
+/// an outage. Triggers are not executed.
+///
+/// # Arguments
+/// * `config` - Configuration of the backfill
+///
+/// # Returns
+/// * `Result<()>` - Ok(()) if the slots could be backfilled, or an error otherwise
+#[instrument(skip_all)]
+async fn backfill(
+	config: BackfillConfig<
+		MonitorRepository<NetworkRepository, TriggerRepository>,
+		NetworkRepository,
+		TriggerRepository,
+	>,
+) -> Result<()> {
+	let path = config.path.clone();
+	let matches = backfill_monitor(config).await.map_err(|e| {
+		MonitorExecutionError::execution_error(
+			"Backfill failed",
+			Some(e.into()),
+			Some(HashMap::from([("path".to_string(), path)])),
+		)
+	})?;
+
+	info!("=========== Backfill Results ===========");
+	info!("Found {} match(es)", matches.len());
+	info!("{}", serde_json::to_string_pretty(&matches)?);
+	info!("========================================");
+	Ok(())
+}
+
 /// Validates configuration files and their structure
 async fn validate_configuration() {
 	info!("Validating configuration files...");
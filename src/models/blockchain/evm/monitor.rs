@@ -128,7 +128,10 @@ impl std::ops::Deref for ContractSpec {
 #[cfg(test)]
 mod tests {
 	use crate::{
-		models::{ContractSpec as ModelsContractSpec, FunctionCondition, StellarContractSpec},
+		models::{
+			ConditionLogic, ContractSpec as ModelsContractSpec, FunctionCondition,
+			StellarContractSpec,
+		},
 		utils::tests::evm::{
 			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
 		},
@@ -195,6 +198,12 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
@@ -60,9 +60,15 @@ pub enum TransactionType {
 	/// EVM transaction
 	EVM(evm::EVMTransaction),
 	/// Stellar transaction
-	Stellar(stellar::StellarTransaction),
+	///
+	/// # Note
+	/// Box is used here to equalize the enum variants
+	Stellar(Box<stellar::StellarTransaction>),
 	/// Solana transaction
-	Solana(solana::SolanaTransaction),
+	///
+	/// # Note
+	/// Box is used here to equalize the enum variants
+	Solana(Box<solana::SolanaTransaction>),
 }
 
 /// Contract spec from different blockchain platforms
@@ -27,6 +27,10 @@ pub struct SolanaBlock {
 	pub rewards: Option<Vec<SolanaReward>>,
 	/// The block's commitment level
 	pub commitment: CommitmentConfig,
+	/// The transactions decoded with their status metadata, in the order of `transactions`,
+	/// when the block was received from a subscription
+	#[serde(default)]
+	pub decoded_transactions: Vec<SolanaTransaction>,
 }
 
 /// Represents a reward in a Solana block
@@ -44,6 +48,7 @@ pub struct SolanaReward {
 
 impl SolanaBlock {
 	/// Creates a new SolanaBlock with the given slot and transactions
+	#[allow(clippy::too_many_arguments)]
 	pub fn new(
 		slot: u64,
 		blockhash: String,
@@ -63,9 +68,20 @@ impl SolanaBlock {
 			transactions,
 			rewards,
 			commitment,
+			decoded_transactions: vec![],
 		}
 	}
 
+	/// Sets the transactions decoded with their status metadata, in the order of the
+	/// transactions of the block
+	pub fn with_decoded_transactions(
+		mut self,
+		decoded_transactions: Vec<SolanaTransaction>,
+	) -> Self {
+		self.decoded_transactions = decoded_transactions;
+		self
+	}
+
 	/// Returns the block's slot number
 	pub fn slot(&self) -> u64 {
 		self.slot
@@ -0,0 +1,89 @@
+//! Parsed transactions returned by enhanced transaction APIs (e.g. Helius).
+//!
+//! Enhanced APIs classify a transaction (e.g. `SWAP`, `NFT_SALE`) and describe its token
+//! and native transfers in a human-readable form, including for programs without a decoder.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Type given by enhanced APIs to transactions they cannot classify
+pub const UNKNOWN_TRANSACTION_TYPE: &str = "UNKNOWN";
+
+/// A transaction parsed by an enhanced transaction API
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnhancedTransaction {
+	/// Signature of the transaction
+	pub signature: String,
+	/// Classification of the transaction (e.g. `SWAP`)
+	#[serde(rename = "type")]
+	pub type_: String,
+	/// Program or marketplace the transaction was made on (e.g. `JUPITER`)
+	#[serde(default)]
+	pub source: String,
+	/// Human-readable description of the transaction
+	#[serde(default)]
+	pub description: String,
+	/// Native SOL transfers of the transaction
+	#[serde(default)]
+	pub native_transfers: Vec<Value>,
+	/// Token transfers of the transaction
+	#[serde(default)]
+	pub token_transfers: Vec<Value>,
+	/// Program-specific events of the transaction (e.g. `swap`, `nft`)
+	#[serde(default)]
+	pub events: Value,
+}
+
+impl EnhancedTransaction {
+	/// Returns whether the API classified the transaction
+	pub fn is_classified(&self) -> bool {
+		!self.type_.is_empty() && self.type_ != UNKNOWN_TRANSACTION_TYPE
+	}
+
+	/// Returns the parsed fields exposed as instruction arguments
+	pub fn args(&self) -> Value {
+		json!({
+			"type": self.type_,
+			"source": self.source,
+			"description": self.description,
+			"native_transfers": self.native_transfers,
+			"token_transfers": self.token_transfers,
+			"events": self.events,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_deserialize_enhanced_transaction() {
+		let transaction: EnhancedTransaction = serde_json::from_value(json!({
+			"description": "A swapped 1 SOL for 150 USDC on JUPITER",
+			"type": "SWAP",
+			"source": "JUPITER",
+			"fee": 5000,
+			"signature": "5h6xBEauJ3PK6SWCZ1PGjBvj8vDdWG3KpwATGy1ARAXFSDwt8GFXM7W5Ncn16wmqokgpiKRLuS83KUxyZyv2sUYv",
+			"tokenTransfers": [{ "mint": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "tokenAmount": 150 }],
+			"instructions": []
+		}))
+		.unwrap();
+
+		assert!(transaction.is_classified());
+		assert!(transaction.native_transfers.is_empty());
+		assert_eq!(transaction.events, Value::Null);
+
+		let args = transaction.args();
+		assert_eq!(args["type"], "SWAP");
+		assert_eq!(args["source"], "JUPITER");
+		assert_eq!(args["token_transfers"][0]["tokenAmount"], 150);
+
+		let unknown = EnhancedTransaction {
+			type_: UNKNOWN_TRANSACTION_TYPE.to_string(),
+			..transaction
+		};
+		assert!(!unknown.is_classified());
+	}
+}
@@ -1,16 +1,21 @@
 mod block;
+mod enhanced;
 mod instruction;
 mod monitor;
 mod transaction;
 
 pub use block::{SolanaBlock, SolanaReward};
+pub use enhanced::EnhancedTransaction as SolanaEnhancedTransaction;
 pub use monitor::{
-	ContractSpec as SolanaContractSpec, SolanaMatchArguments, SolanaMatchParamEntry,
-	SolanaMatchParamsMap, SolanaMonitorMatch,
+	ContractSpec as SolanaContractSpec, DecoderType as SolanaDecoderType,
+	NestedInstruction as SolanaNestedInstruction, SolanaMatchArguments, SolanaMatchParamEntry,
+	SolanaMatchParamsMap, SolanaMatchedInstruction, SolanaMonitorMatch,
 };
 pub use transaction::{
-	SolanaTransaction, TransactionMetadata as SolanaTransactionMetadata,
+	ProgramLog as SolanaProgramLog, SolanaTransaction, SolanaTransactionError,
+	TransactionMetadata as SolanaTransactionMetadata,
 	TransactionStatusMeta as SolanaTransactionStatusMeta,
+	TransactionTokenBalance as SolanaTransactionTokenBalance,
 };
 
 pub use instruction::{
@@ -1,11 +1,9 @@
 use {
 	crate::{
-		models::{
-			MatchConditions, Monitor, SolanaInstructionMetadata, SolanaTransaction,
-			SolanaTransactionMetadata,
-		},
-		services::decoders::solana::{
-			AccountDecoder, AccountType, InstructionDecoder, InstructionType,
+		models::{MatchConditions, Monitor, SolanaInstructionMetadata, SolanaTransaction},
+		services::decoders::{
+			serde_helpers::{hex_bytes, pubkey_base58},
+			AccountType, AnchorIdl, InstructionType, ProgramLayout,
 		},
 	},
 	serde::{Deserialize, Serialize},
@@ -27,8 +25,122 @@ pub struct NestedInstruction {
 	pub inner_instructions: Vec<NestedInstruction>,
 }
 
+impl NestedInstruction {
+	/// Builds the instruction trees of a transaction
+	///
+	/// Each top-level instruction becomes a root, and the inner instructions recorded in
+	/// the status metadata are attached to the root at their outer instruction index,
+	/// under the last instruction one level above their stack height. Inner instruction
+	/// groups may come in any order. Groups referencing a missing top-level instruction,
+	/// and inner instructions referencing unknown accounts, are skipped. Inner
+	/// instructions without a stack height are attached directly to their root.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to build the trees of
+	///
+	/// # Returns
+	/// One tree per top-level instruction, in transaction order
+	pub fn from_transaction(transaction: &SolanaTransaction) -> Vec<Self> {
+		let metadata = |instruction_index: usize, stack_height: usize| SolanaInstructionMetadata {
+			slot: transaction.slot(),
+			signature: *transaction.signature(),
+			fee_payer: *transaction.fee_payer(),
+			block_time: transaction.block_time(),
+			block_height: None,
+			blockhash: None,
+			parent_slot: None,
+			stack_height,
+			instruction_index,
+		};
+
+		let mut roots = transaction
+			.instructions()
+			.iter()
+			.enumerate()
+			.map(|(index, instruction)| Self {
+				metadata: metadata(index, 0),
+				instruction: Instruction {
+					program_id: instruction.program_id,
+					accounts: instruction.accounts.clone(),
+					data: instruction.data.clone(),
+				},
+				inner_instructions: Vec::new(),
+			})
+			.collect::<Vec<_>>();
+
+		let account_keys = transaction.account_keys();
+		let message = transaction.message();
+		let account_meta = |index: u8| {
+			Some(AccountMeta {
+				pubkey: *account_keys.get(index as usize)?,
+				is_signer: message.is_signer(index as usize),
+				is_writable: message.is_maybe_writable(index as usize, None),
+			})
+		};
+
+		for group in transaction.meta().inner_instructions.iter().flatten() {
+			let index = group.index as usize;
+			let Some(root) = roots.get_mut(index) else {
+				continue;
+			};
+
+			for inner in &group.instructions {
+				let compiled = &inner.instruction;
+				let (Some(program_id), Some(accounts)) = (
+					account_keys.get(compiled.program_id_index as usize),
+					compiled
+						.accounts
+						.iter()
+						.map(|&account| account_meta(account))
+						.collect::<Option<Vec<_>>>(),
+				) else {
+					continue;
+				};
+
+				// Stack heights of inner instructions start at 2, top-level ones being at 1
+				let depth = inner
+					.stack_height
+					.map_or(1, |stack_height| (stack_height as usize).saturating_sub(1))
+					.max(1);
+
+				let mut parent = &mut *root;
+				for _ in 1..depth {
+					if parent.inner_instructions.is_empty() {
+						break;
+					}
+					parent = parent.inner_instructions.last_mut().unwrap();
+				}
+				let stack_height = parent.metadata.stack_height + 1;
+				parent.inner_instructions.push(Self {
+					metadata: metadata(index, stack_height),
+					instruction: Instruction {
+						program_id: *program_id,
+						accounts,
+						data: compiled.data.clone(),
+					},
+					inner_instructions: Vec::new(),
+				});
+			}
+		}
+
+		roots
+	}
+
+	/// Flattens the instruction tree, each instruction being followed by the instructions
+	/// it invoked
+	///
+	/// # Returns
+	/// The instructions of the tree in execution order, without their inner instructions
+	pub fn flatten(mut self) -> Vec<Self> {
+		let inner_instructions = std::mem::take(&mut self.inner_instructions);
+		std::iter::once(self)
+			.chain(inner_instructions.into_iter().flat_map(Self::flatten))
+			.collect()
+	}
+}
+
 /// Represents a matched parameter in a Solana instruction
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SolanaMatchParamEntry {
 	/// Name of the parameter
 	pub name: String,
@@ -56,6 +168,40 @@ pub struct SolanaMatchParamsMap {
 pub struct SolanaMatchArguments {
 	/// Matched instructions
 	pub instructions: Option<Vec<SolanaMatchParamsMap>>,
+	/// Matched account states
+	#[serde(default)]
+	pub accounts: Option<Vec<SolanaMatchParamsMap>>,
+	/// Matched token balance changes
+	#[serde(default)]
+	pub balance_changes: Option<Vec<SolanaMatchParamsMap>>,
+	/// Matched program events
+	#[serde(default)]
+	pub events: Option<Vec<SolanaMatchParamsMap>>,
+	/// Matched log messages
+	#[serde(default)]
+	pub logs: Option<Vec<SolanaMatchParamsMap>>,
+	/// Instructions matching the steps of instruction sequences, in transaction order
+	#[serde(default)]
+	pub sequences: Option<Vec<SolanaMatchParamsMap>>,
+	/// Aggregated properties of the block, for matches of block conditions
+	#[serde(default)]
+	pub blocks: Option<Vec<SolanaMatchParamsMap>>,
+}
+
+/// Location and raw data of an instruction that matched a monitor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SolanaMatchedInstruction {
+	/// Index of the top-level instruction, or of the one that invoked it for inner
+	/// instructions
+	pub instruction_index: usize,
+	/// Depth of the instruction in the invocation tree, top-level instructions being at 0
+	pub stack_height: usize,
+	/// Program executing the instruction
+	#[serde(with = "pubkey_base58")]
+	pub program_id: Pubkey,
+	/// Raw instruction data
+	#[serde(with = "hex_bytes")]
+	pub data: Vec<u8>,
 }
 
 /// Represents a matched condition in a Solana transaction
@@ -71,6 +217,10 @@ pub struct SolanaMonitorMatch {
 	pub matched_on_args: Option<SolanaMatchArguments>,
 	/// Transaction that triggered the match
 	pub transaction: SolanaTransaction,
+	/// Instructions that matched the function and sequence conditions, in transaction
+	/// order
+	#[serde(default)]
+	pub matched_instructions: Vec<SolanaMatchedInstruction>,
 }
 
 impl SolanaMonitorMatch {
@@ -88,6 +238,7 @@ impl SolanaMonitorMatch {
 			matched_on,
 			matched_on_args,
 			transaction,
+			matched_instructions: Vec::new(),
 		}
 	}
 
@@ -101,29 +252,62 @@ impl SolanaMonitorMatch {
 		self.transaction.signature()
 	}
 
-	/// Returns the program ID
-	pub fn program_id(&self) -> &Pubkey {
-		&self.transaction.instructions()[0].program_id
+	/// Returns the first matched instruction, if any
+	pub fn matched_instruction(&self) -> Option<&SolanaMatchedInstruction> {
+		self.matched_instructions.first()
+	}
+
+	/// Returns the program ID of the first matched instruction, or of the first
+	/// instruction of the transaction
+	///
+	/// Returns `None` for matches without instructions, such as block matches.
+	pub fn program_id(&self) -> Option<&Pubkey> {
+		match self.matched_instruction() {
+			Some(instruction) => Some(&instruction.program_id),
+			None => self
+				.transaction
+				.instructions()
+				.first()
+				.map(|instruction| &instruction.program_id),
+		}
 	}
 
-	/// Returns the accounts involved
-	pub fn accounts(&self) -> &[AccountMeta] {
-		&self.transaction.instructions()[0].accounts
+	/// Returns the accounts of the top-level instruction of the first matched instruction,
+	/// or of the first instruction of the transaction
+	///
+	/// Returns `None` for matches without instructions, such as block matches.
+	pub fn accounts(&self) -> Option<&[AccountMeta]> {
+		self.transaction
+			.instructions()
+			.get(self.instruction_index())
+			.map(|instruction| instruction.accounts.as_slice())
 	}
 
-	/// Returns the instruction data
-	pub fn data(&self) -> &[u8] {
-		&self.transaction.instructions()[0].data
+	/// Returns the data of the first matched instruction, or of the first instruction of
+	/// the transaction
+	///
+	/// Returns `None` for matches without instructions, such as block matches.
+	pub fn data(&self) -> Option<&[u8]> {
+		match self.matched_instruction() {
+			Some(instruction) => Some(&instruction.data),
+			None => self
+				.transaction
+				.instructions()
+				.first()
+				.map(|instruction| instruction.data.as_slice()),
+		}
 	}
 
-	/// Returns the instruction index
+	/// Returns the index of the top-level instruction of the first matched instruction
 	pub fn instruction_index(&self) -> usize {
-		0 // Since we're only storing the matched instruction
+		self.matched_instruction()
+			.map_or(0, |instruction| instruction.instruction_index)
 	}
 
-	/// Returns the stack height
+	/// Returns the stack height of the first matched instruction
 	pub fn stack_height(&self) -> usize {
-		0 // Since we're only storing the matched instruction
+		self.matched_instruction()
+			.map_or(0, |instruction| instruction.stack_height)
 	}
 
 	/// Returns the network slug
@@ -149,8 +333,22 @@ impl SolanaMonitorMatch {
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum DecoderType {
+	/// Account whose variant the account at the address is expected to be
 	Account(AccountType),
 	Instruction(InstructionType),
+	/// Anchor IDL used to decode instructions of programs without a built-in decoder
+	Idl(AnchorIdl),
+	/// Path to an Anchor IDL JSON file, replaced by the parsed IDL when the monitor is
+	/// loaded
+	IdlFile(String),
+	/// Borsh layout used to decode instructions of non-Anchor programs
+	Layout(ProgramLayout),
+	/// Names of the instruction variants to match, for programs with a built-in decoder
+	InstructionNames(Vec<String>),
+	/// Instructions whose variants to match, for programs with a built-in decoder
+	Instructions(Vec<InstructionType>),
+	/// Names of the account variants the account at the address is expected to be
+	AccountNames(Vec<String>),
 }
 
 impl Default for DecoderType {
@@ -167,12 +365,74 @@ impl Default for DecoderType {
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
 pub struct ContractSpec(DecoderType);
 
+impl ContractSpec {
+	/// Creates a new contract spec from a decoder type
+	pub fn new(decoder_type: DecoderType) -> Self {
+		Self(decoder_type)
+	}
+
+	/// Returns the decoder type of the contract spec
+	pub fn decoder_type(&self) -> &DecoderType {
+		&self.0
+	}
+
+	/// Returns the Anchor IDL of the contract spec, if any
+	pub fn idl(&self) -> Option<&AnchorIdl> {
+		match &self.0 {
+			DecoderType::Idl(idl) => Some(idl),
+			_ => None,
+		}
+	}
+
+	/// Returns the Borsh layout of the contract spec, if any
+	pub fn layout(&self) -> Option<&ProgramLayout> {
+		match &self.0 {
+			DecoderType::Layout(layout) => Some(layout),
+			_ => None,
+		}
+	}
+
+	/// Returns the names of the instruction variants the contract spec restricts its
+	/// program to, if any
+	///
+	/// A spec holding instructions restricts the program to the variants of those
+	/// instructions rather than to every instruction of the program. Instructions without
+	/// variants are ignored.
+	pub fn instruction_names(&self) -> Option<Vec<String>> {
+		let names: Vec<String> = match &self.0 {
+			DecoderType::Instruction(instruction) => {
+				instruction.variant_name().into_iter().collect()
+			}
+			DecoderType::Instructions(instructions) => instructions
+				.iter()
+				.filter_map(InstructionType::variant_name)
+				.collect(),
+			DecoderType::InstructionNames(names) => return Some(names.clone()),
+			_ => return None,
+		};
+		Some(names).filter(|names| !names.is_empty())
+	}
+
+	/// Returns the names of the account variants the contract spec expects the account at
+	/// its address to be, if any
+	///
+	/// Account conditions on the address only match the account when it decodes as one
+	/// of these variants.
+	pub fn account_names(&self) -> Option<Vec<String>> {
+		match &self.0 {
+			DecoderType::Account(account) => account.variant_name().map(|name| vec![name]),
+			DecoderType::AccountNames(names) => Some(names.clone()),
+			_ => None,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
 		models::{
-			MatchConditions, SolanaDecodedInstruction, SolanaInstructionDecoder,
-			SolanaTransactionStatusMeta,
+			ConditionLogic, MatchConditions, SolanaDecodedInstruction, SolanaInstructionDecoder,
+			SolanaTransactionMetadata, SolanaTransactionStatusMeta,
 		},
 		utils::tests::solana::{
 			instruction::{InstructionBuilder, InstructionMetadataBuilder},
@@ -182,11 +442,13 @@ mod tests {
 	};
 
 	use super::*;
+	use serde_json::json;
 	use solana_sdk::{
-		instruction::{AccountMeta, Instruction},
+		instruction::{AccountMeta, CompiledInstruction, Instruction},
 		message::{Message, VersionedMessage},
 		pubkey::Pubkey,
 	};
+	use solana_transaction_status::{InnerInstruction, InnerInstructions};
 	use std::str::FromStr;
 
 	// Helper function to create a test monitor
@@ -201,7 +463,7 @@ mod tests {
 
 	// Helper function to create a test Kamino Lend instruction
 	fn create_kamino_lend_instruction() -> Instruction {
-		let instruction = InstructionBuilder::new()
+		InstructionBuilder::new()
 			.program_id(Pubkey::from_str("11111111111111111111111111111111").unwrap())
 			.account(AccountMeta::new(Pubkey::new_unique(), false)) // user
 			.account(AccountMeta::new(Pubkey::new_unique(), true))  // lending market
@@ -215,8 +477,7 @@ mod tests {
 				0x01, // instruction discriminator for deposit
 				0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // amount (u64)
 			])
-			.build();
-		instruction
+			.build()
 	}
 
 	#[test]
@@ -243,6 +504,12 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			None,
 			transaction.clone(),
@@ -251,9 +518,12 @@ mod tests {
 		assert_eq!(monitor_match.monitor.name, "KaminoLendMonitor");
 		assert_eq!(monitor_match.slot(), metadata.slot);
 		assert_eq!(monitor_match.signature(), &metadata.signature);
-		assert_eq!(monitor_match.program_id(), &instruction.program_id);
-		assert_eq!(monitor_match.accounts(), &instruction.accounts);
-		assert_eq!(monitor_match.data(), &instruction.data);
+		assert_eq!(monitor_match.program_id(), Some(&instruction.program_id));
+		assert_eq!(
+			monitor_match.accounts(),
+			Some(instruction.accounts.as_slice())
+		);
+		assert_eq!(monitor_match.data(), Some(instruction.data.as_slice()));
 		assert_eq!(monitor_match.instruction_index(), 0);
 		assert_eq!(monitor_match.stack_height(), 0);
 		assert_eq!(monitor_match.network_slug, "solana_mainnet");
@@ -263,11 +533,69 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			}
 		);
 		assert_eq!(monitor_match.transaction, transaction);
 	}
 
+	#[test]
+	fn test_solana_monitor_match_exposes_matched_instruction() {
+		let instruction = create_kamino_lend_instruction();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: Pubkey::new_unique(),
+				data: vec![0x01],
+				accounts: vec![],
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: instruction.program_id,
+				data: instruction.data.clone(),
+				accounts: instruction.accounts.clone(),
+			})
+			.build();
+		let inner_program_id = Pubkey::new_unique();
+
+		let mut monitor_match = SolanaMonitorMatch::new(
+			create_test_monitor(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			transaction,
+		);
+		monitor_match.matched_instructions = vec![SolanaMatchedInstruction {
+			instruction_index: 1,
+			stack_height: 1,
+			program_id: inner_program_id,
+			data: vec![0xab, 0xcd],
+		}];
+
+		assert_eq!(monitor_match.program_id(), Some(&inner_program_id));
+		assert_eq!(monitor_match.data(), Some([0xab, 0xcd].as_slice()));
+		assert_eq!(monitor_match.instruction_index(), 1);
+		assert_eq!(monitor_match.stack_height(), 1);
+		assert_eq!(
+			monitor_match.accounts(),
+			Some(instruction.accounts.as_slice())
+		);
+
+		let serialized = serde_json::to_value(&monitor_match).unwrap();
+		assert_eq!(
+			serialized["matched_instructions"][0],
+			json!({
+				"instruction_index": 1,
+				"stack_height": 1,
+				"program_id": inner_program_id.to_string(),
+				"data": "0xabcd",
+			})
+		);
+	}
+
 	#[test]
 	fn test_nested_instruction_handling() {
 		let monitor = create_test_monitor();
@@ -294,6 +622,12 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			None,
 			SolanaTransaction {
@@ -318,6 +652,174 @@ mod tests {
 		assert_eq!(instruction.accounts.len(), 2);
 	}
 
+	fn create_nested_transaction(
+		program_ids: &[Pubkey],
+		inner_instructions: Vec<InnerInstructions>,
+	) -> SolanaTransaction {
+		let payer = Pubkey::new_unique();
+		let message = Message::new_with_compiled_instructions(
+			1,
+			0,
+			program_ids.len() as u8,
+			[vec![payer], program_ids.to_vec()].concat(),
+			Default::default(),
+			vec![],
+		);
+		let mut builder = TransactionBuilder::new()
+			.fee_payer(payer)
+			.message(VersionedMessage::Legacy(message))
+			.meta(SolanaTransactionStatusMeta {
+				inner_instructions: Some(inner_instructions),
+				..Default::default()
+			});
+		for (index, program_id) in program_ids.iter().take(2).enumerate() {
+			builder = builder.instruction(SolanaDecodedInstruction {
+				program_id: *program_id,
+				data: vec![index as u8],
+				accounts: vec![],
+			});
+		}
+		builder.build()
+	}
+
+	fn inner_instruction(
+		program_id_index: u8,
+		data: u8,
+		stack_height: Option<u32>,
+	) -> InnerInstruction {
+		InnerInstruction {
+			instruction: CompiledInstruction::new_from_raw_parts(
+				program_id_index,
+				vec![data],
+				vec![0],
+			),
+			stack_height,
+		}
+	}
+
+	/// Returns the data of each instruction of a tree with its depth, in depth-first order
+	fn flatten(instruction: &NestedInstruction) -> Vec<(u8, usize)> {
+		let mut instructions = vec![(
+			instruction.instruction.data[0],
+			instruction.metadata.stack_height,
+		)];
+		for inner in &instruction.inner_instructions {
+			instructions.extend(flatten(inner));
+		}
+		instructions
+	}
+
+	#[test]
+	fn test_flatten_nested_instructions_in_execution_order() {
+		let program_ids = [Pubkey::new_unique(), Pubkey::new_unique()];
+		let transaction = create_nested_transaction(
+			&program_ids,
+			vec![InnerInstructions {
+				index: 1,
+				instructions: vec![
+					inner_instruction(2, 10, Some(2)),
+					inner_instruction(2, 11, Some(3)),
+					inner_instruction(2, 12, Some(2)),
+				],
+			}],
+		);
+
+		let instructions = NestedInstruction::from_transaction(&transaction)
+			.into_iter()
+			.flat_map(NestedInstruction::flatten)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			instructions
+				.iter()
+				.map(|nested| (
+					nested.instruction.data[0],
+					nested.metadata.instruction_index,
+					nested.metadata.stack_height
+				))
+				.collect::<Vec<_>>(),
+			vec![(0, 0, 0), (1, 1, 0), (10, 1, 1), (11, 1, 2), (12, 1, 1)]
+		);
+		assert!(instructions
+			.iter()
+			.all(|nested| nested.inner_instructions.is_empty()));
+	}
+
+	#[test]
+	fn test_nested_instructions_from_multi_level_cpis() {
+		let program_ids = [
+			Pubkey::new_unique(),
+			Pubkey::new_unique(),
+			Pubkey::new_unique(),
+		];
+		// The inner instructions of the second instruction come first, and a group
+		// references an instruction that does not exist
+		let transaction = create_nested_transaction(
+			&program_ids,
+			vec![
+				InnerInstructions {
+					index: 1,
+					instructions: vec![
+						inner_instruction(3, 10, Some(2)),
+						inner_instruction(3, 11, Some(3)),
+						inner_instruction(3, 12, Some(4)),
+						inner_instruction(3, 13, Some(3)),
+						inner_instruction(3, 14, Some(2)),
+					],
+				},
+				InnerInstructions {
+					index: 0,
+					instructions: vec![inner_instruction(2, 20, Some(2))],
+				},
+				InnerInstructions {
+					index: 5,
+					instructions: vec![inner_instruction(3, 30, Some(2))],
+				},
+			],
+		);
+
+		let trees = NestedInstruction::from_transaction(&transaction);
+		assert_eq!(trees.len(), 2);
+		assert_eq!(flatten(&trees[0]), vec![(0, 0), (20, 1)]);
+		assert_eq!(
+			flatten(&trees[1]),
+			vec![(1, 0), (10, 1), (11, 2), (12, 3), (13, 2), (14, 1)]
+		);
+
+		let cpi = &trees[1].inner_instructions[0];
+		assert_eq!(cpi.instruction.program_id, program_ids[2]);
+		assert_eq!(cpi.metadata.instruction_index, 1);
+		assert_eq!(cpi.metadata.signature, *transaction.signature());
+		assert_eq!(
+			cpi.instruction.accounts,
+			vec![AccountMeta::new(*transaction.fee_payer(), true)]
+		);
+		assert_eq!(cpi.inner_instructions[0].inner_instructions.len(), 1);
+		assert_eq!(trees[1].inner_instructions.len(), 2);
+	}
+
+	#[test]
+	fn test_nested_instructions_without_stack_heights() {
+		let program_ids = [Pubkey::new_unique(), Pubkey::new_unique()];
+		let transaction = create_nested_transaction(
+			&program_ids,
+			vec![InnerInstructions {
+				index: 0,
+				instructions: vec![
+					inner_instruction(2, 10, None),
+					// Unknown program accounts are skipped
+					inner_instruction(9, 11, None),
+					inner_instruction(2, 12, None),
+					// A stack height skipping a level is attached to the deepest instruction
+					inner_instruction(2, 13, Some(5)),
+				],
+			}],
+		);
+
+		let trees = NestedInstruction::from_transaction(&transaction);
+		assert_eq!(flatten(&trees[0]), vec![(0, 0), (10, 1), (12, 1), (13, 2)]);
+		assert_eq!(flatten(&trees[1]), vec![(1, 0)]);
+	}
+
 	#[test]
 	fn test_instruction_decoder_trait() {
 		struct TestDecoder;
@@ -350,4 +852,59 @@ mod tests {
 		assert_eq!(decoded.data, "Kamino Lend Deposit");
 		assert_eq!(decoded.accounts.len(), 8);
 	}
+
+	#[test]
+	fn test_contract_spec_instruction_names() {
+		let spec: crate::models::ContractSpec =
+			serde_json::from_str(r#"{"InstructionNames": ["DepositReserveLiquidity"]}"#).unwrap();
+		let crate::models::ContractSpec::Solana(spec) = spec else {
+			panic!("Expected a Solana contract spec");
+		};
+		assert_eq!(
+			spec.instruction_names(),
+			Some(vec!["DepositReserveLiquidity".to_string()])
+		);
+
+		let spec = ContractSpec::new(DecoderType::Instruction(InstructionType::Custom(
+			crate::services::decoders::CustomInstruction {
+				name: "Swap".to_string(),
+				args: serde_json::Value::Null,
+				accounts: vec![],
+			},
+		)));
+		assert_eq!(spec.instruction_names(), Some(vec!["Swap".to_string()]));
+
+		let spec = ContractSpec::new(DecoderType::Instruction(InstructionType::JupiterDCA));
+		assert_eq!(spec.instruction_names(), None);
+		assert_eq!(ContractSpec::default().instruction_names(), None);
+
+		let spec = ContractSpec::new(DecoderType::Instructions(vec![
+			InstructionType::Custom(crate::services::decoders::CustomInstruction {
+				name: "Swap".to_string(),
+				args: serde_json::Value::Null,
+				accounts: vec![],
+			}),
+			InstructionType::JupiterDCA,
+		]));
+		assert_eq!(spec.instruction_names(), Some(vec!["Swap".to_string()]));
+		let spec = ContractSpec::new(DecoderType::Instructions(vec![InstructionType::JupiterDCA]));
+		assert_eq!(spec.instruction_names(), None);
+	}
+
+	#[test]
+	fn test_contract_spec_account_names() {
+		let spec: crate::models::ContractSpec =
+			serde_json::from_str(r#"{"AccountNames": ["Obligation"]}"#).unwrap();
+		let crate::models::ContractSpec::Solana(spec) = spec else {
+			panic!("Expected a Solana contract spec");
+		};
+		assert_eq!(spec.account_names(), Some(vec!["Obligation".to_string()]));
+
+		let spec = ContractSpec::new(DecoderType::Account(AccountType::JupiterSwap));
+		assert_eq!(spec.account_names(), Some(vec!["JupiterSwap".to_string()]));
+		assert_eq!(
+			ContractSpec::new(DecoderType::InstructionNames(vec![])).account_names(),
+			None
+		);
+	}
 }
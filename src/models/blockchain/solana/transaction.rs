@@ -1,20 +1,62 @@
 use {
 	crate::models::blockchain::solana::block::SolanaBlock,
+	base64::Engine,
 	serde::{Deserialize, Serialize},
 	solana_account_decoder::parse_token::UiTokenAmount,
 	solana_sdk::{
-		instruction::AccountMeta,
+		bs58,
+		clock::DEFAULT_SLOTS_PER_EPOCH,
+		instruction::{AccountMeta, CompiledInstruction},
 		message::{v0::LoadedAddresses, Message, VersionedMessage},
 		pubkey::Pubkey,
 		signature::Signature,
-		transaction::Result as TransactionResult,
+		transaction::{Result as TransactionResult, VersionedTransaction},
 		transaction_context::TransactionReturnData,
 	},
-	solana_transaction_status::{InnerInstructions, Rewards},
+	solana_transaction_status::{
+		EncodedConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions, Rewards,
+		UiInstruction, UiReturnDataEncoding, UiTransactionStatusMeta, UiTransactionTokenBalance,
+	},
+	std::{collections::HashMap, str::FromStr},
+	thiserror::Error,
 };
 
 use super::instruction::DecodedInstruction;
 
+/// Errors that can occur while building a transaction from its RPC representation
+#[derive(Debug, Error, PartialEq)]
+pub enum SolanaTransactionError {
+	/// The transaction is not binary encoded
+	#[error("Unsupported transaction encoding, only base64 and base58 are supported")]
+	UnsupportedEncoding,
+
+	/// The transaction has no signature
+	#[error("Transaction has no signature")]
+	MissingSignature,
+
+	/// The status metadata lacks the addresses loaded from lookup tables, and the given
+	/// lookup tables do not include all the tables the transaction references
+	#[error("Missing addresses loaded from lookup tables {}", format_tables(.0))]
+	MissingLoadedAddresses(Vec<Pubkey>),
+
+	/// The transaction references an address beyond the end of a lookup table
+	#[error("Index {index} is out of bounds of lookup table {table}")]
+	InvalidLookupIndex { table: Pubkey, index: u8 },
+
+	/// An instruction references an account index beyond the account keys
+	#[error("Account index {0} is out of bounds of the account keys")]
+	UnknownAccount(u8),
+}
+
+fn format_tables(tables: &[Pubkey]) -> String {
+	tables
+		.iter()
+		.map(Pubkey::to_string)
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+/// Token balance of an account before or after a transaction
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TransactionTokenBalance {
 	pub account_index: u8,
@@ -72,6 +114,85 @@ impl Default for TransactionStatusMeta {
 	}
 }
 
+impl From<UiTransactionStatusMeta> for TransactionStatusMeta {
+	/// Converts the status metadata returned by the RPC API
+	///
+	/// Parsed inner instructions and addresses that are not valid public keys are skipped.
+	fn from(meta: UiTransactionStatusMeta) -> Self {
+		let parse_pubkeys = |addresses: Vec<String>| {
+			addresses
+				.iter()
+				.filter_map(|address| Pubkey::from_str(address).ok())
+				.collect::<Vec<_>>()
+		};
+		let token_balances = |balances: Vec<UiTransactionTokenBalance>| {
+			balances
+				.into_iter()
+				.map(|balance| TransactionTokenBalance {
+					account_index: balance.account_index,
+					mint: balance.mint,
+					ui_token_amount: balance.ui_token_amount,
+					owner: balance.owner.unwrap_or(String::new()),
+					program_id: balance.program_id.unwrap_or(String::new()),
+				})
+				.collect::<Vec<_>>()
+		};
+
+		Self {
+			status: meta.status,
+			fee: meta.fee,
+			pre_balances: meta.pre_balances,
+			post_balances: meta.post_balances,
+			inner_instructions: meta.inner_instructions.map(|inner_instructions| {
+				inner_instructions
+					.into_iter()
+					.map(|inner| InnerInstructions {
+						index: inner.index,
+						instructions: inner
+							.instructions
+							.into_iter()
+							.filter_map(|instruction| match instruction {
+								UiInstruction::Compiled(compiled) => Some(InnerInstruction {
+									instruction: CompiledInstruction {
+										program_id_index: compiled.program_id_index,
+										accounts: compiled.accounts,
+										data: bs58::decode(&compiled.data).into_vec().ok()?,
+									},
+									stack_height: compiled.stack_height,
+								}),
+								UiInstruction::Parsed(_) => None,
+							})
+							.collect(),
+					})
+					.collect()
+			}),
+			log_messages: meta.log_messages.into(),
+			pre_token_balances: meta.pre_token_balances.map(token_balances),
+			post_token_balances: meta.post_token_balances.map(token_balances),
+			rewards: meta.rewards.into(),
+			loaded_addresses: meta
+				.loaded_addresses
+				.map(|addresses| LoadedAddresses {
+					writable: parse_pubkeys(addresses.writable),
+					readonly: parse_pubkeys(addresses.readonly),
+				})
+				.unwrap_or_default(),
+			return_data: meta.return_data.map(|return_data| {
+				let (data, encoding) = return_data.data;
+				TransactionReturnData {
+					program_id: Pubkey::from_str(&return_data.program_id).unwrap_or_default(),
+					data: match encoding {
+						UiReturnDataEncoding::Base64 => base64::engine::general_purpose::STANDARD
+							.decode(data)
+							.unwrap_or_default(),
+					},
+				}
+			}),
+			compute_units_consumed: meta.compute_units_consumed.into(),
+		}
+	}
+}
+
 /// Metadata associated with a Solana transaction
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TransactionMetadata {
@@ -113,43 +234,150 @@ pub struct SolanaTransaction {
 
 impl SolanaTransaction {
 	/// Creates a new SolanaTransaction from a block and transaction index
-	pub fn new(block: &SolanaBlock, tx_index: usize) -> Option<Self> {
+	///
+	/// Returns `None` if the block has no transaction at this index. Transactions the block
+	/// was received with already decoded keep their status metadata.
+	///
+	/// # Errors
+	/// - Returns `MissingSignature` or `UnknownAccount` if the transaction is malformed
+	pub fn new(
+		block: &SolanaBlock,
+		tx_index: usize,
+	) -> Option<Result<Self, SolanaTransactionError>> {
+		if let Some(transaction) = block.decoded_transactions.get(tx_index) {
+			return Some(Ok(transaction.clone()));
+		}
 		block.transactions.get(tx_index).map(|tx| {
-			let metadata = TransactionMetadata {
-				slot: block.slot,
-				signature: tx.signatures[0],
-				fee_payer: tx.message.account_keys[0],
-				meta: TransactionStatusMeta::default(),
-				message: VersionedMessage::Legacy(tx.message.clone()),
-				block_time: block.block_time,
-			};
-
-			let instructions = tx
-				.message
-				.instructions
-				.iter()
-				.map(|ix| DecodedInstruction {
-					program_id: *ix.program_id(&tx.message.account_keys),
+			Self::from_versioned(
+				block.slot,
+				block.block_time,
+				&VersionedTransaction::from(tx.clone()),
+				TransactionStatusMeta::default(),
+			)
+		})
+	}
+
+	/// Creates a new SolanaTransaction from a versioned transaction and its status metadata
+	///
+	/// Addresses loaded from lookup tables are taken from the status metadata.
+	///
+	/// # Errors
+	/// - Returns `MissingLoadedAddresses` if the status metadata lacks the addresses loaded
+	///   from the lookup tables the message references
+	/// - Returns `MissingSignature` or `UnknownAccount` if the transaction is malformed
+	pub fn from_versioned(
+		slot: u64,
+		block_time: Option<i64>,
+		transaction: &VersionedTransaction,
+		meta: TransactionStatusMeta,
+	) -> Result<Self, SolanaTransactionError> {
+		let message = &transaction.message;
+		if !has_loaded_addresses(message, &meta.loaded_addresses) {
+			return Err(SolanaTransactionError::MissingLoadedAddresses(
+				lookup_tables(message),
+			));
+		}
+
+		let account_keys = message
+			.static_account_keys()
+			.iter()
+			.chain(&meta.loaded_addresses.writable)
+			.chain(&meta.loaded_addresses.readonly)
+			.copied()
+			.collect::<Vec<_>>();
+		let account_key = |index: u8| {
+			account_keys
+				.get(index as usize)
+				.copied()
+				.ok_or(SolanaTransactionError::UnknownAccount(index))
+		};
+
+		let instructions = message
+			.instructions()
+			.iter()
+			.map(|ix| {
+				Ok(DecodedInstruction {
+					program_id: account_key(ix.program_id_index)?,
 					data: ix.data.clone(),
 					accounts: ix
 						.accounts
 						.iter()
-						.map(|&idx| AccountMeta {
-							pubkey: tx.message.account_keys[idx as usize],
-							is_signer: tx.message.is_signer(idx as usize),
-							is_writable: tx.message.is_maybe_writable(idx as usize, None),
+						.map(|&idx| {
+							Ok(AccountMeta {
+								pubkey: account_key(idx)?,
+								is_signer: message.is_signer(idx as usize),
+								is_writable: message.is_maybe_writable(idx as usize, None),
+							})
 						})
-						.collect(),
+						.collect::<Result<Vec<_>, _>>()?,
 				})
-				.collect();
-
-			Self {
-				metadata,
-				instructions,
-			}
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			metadata: TransactionMetadata {
+				slot,
+				signature: *transaction
+					.signatures
+					.first()
+					.ok_or(SolanaTransactionError::MissingSignature)?,
+				fee_payer: account_key(0)?,
+				meta,
+				message: message.clone(),
+				block_time,
+			},
+			instructions,
 		})
 	}
 
+	/// Creates a new SolanaTransaction from a transaction returned by the `getTransaction`
+	/// RPC method
+	///
+	/// # Errors
+	/// - Returns `UnsupportedEncoding` if the transaction is not binary encoded
+	/// - Returns `MissingLoadedAddresses` if the response lacks the addresses loaded from
+	///   lookup tables, see `from_encoded_with_lookup_tables`
+	pub fn from_encoded(
+		encoded: EncodedConfirmedTransactionWithStatusMeta,
+	) -> Result<Self, SolanaTransactionError> {
+		Self::from_encoded_with_lookup_tables(encoded, &HashMap::new())
+	}
+
+	/// Creates a new SolanaTransaction from a transaction returned by the `getTransaction`
+	/// RPC method, resolving the addresses loaded from lookup tables when the response
+	/// lacks them
+	///
+	/// # Arguments
+	/// * `encoded` - The transaction returned by the RPC method
+	/// * `lookup_tables` - Addresses stored in the lookup tables the transaction references,
+	///   by table address
+	///
+	/// # Errors
+	/// - Returns `UnsupportedEncoding` if the transaction is not binary encoded
+	/// - Returns `MissingLoadedAddresses` with the tables that are missing from
+	///   `lookup_tables`, when the response lacks the loaded addresses
+	/// - Returns `InvalidLookupIndex` if an index is out of bounds of its table
+	pub fn from_encoded_with_lookup_tables(
+		encoded: EncodedConfirmedTransactionWithStatusMeta,
+		lookup_tables: &HashMap<Pubkey, Vec<Pubkey>>,
+	) -> Result<Self, SolanaTransactionError> {
+		let transaction = encoded
+			.transaction
+			.transaction
+			.decode()
+			.ok_or(SolanaTransactionError::UnsupportedEncoding)?;
+		let mut meta = encoded
+			.transaction
+			.meta
+			.map(TransactionStatusMeta::from)
+			.unwrap_or_default();
+		if !has_loaded_addresses(&transaction.message, &meta.loaded_addresses) {
+			meta.loaded_addresses = resolve_loaded_addresses(&transaction.message, lookup_tables)?;
+		}
+
+		Self::from_versioned(encoded.slot, encoded.block_time, &transaction, meta)
+	}
+
 	/// Returns the transaction signature
 	pub fn signature(&self) -> &Signature {
 		&self.metadata.signature
@@ -160,6 +388,12 @@ impl SolanaTransaction {
 		self.metadata.slot
 	}
 
+	/// Returns the epoch of the transaction slot, with the 432,000 slot epochs of the public
+	/// clusters
+	pub fn epoch(&self) -> u64 {
+		self.slot() / DEFAULT_SLOTS_PER_EPOCH
+	}
+
 	/// Returns the fee payer's public key
 	pub fn fee_payer(&self) -> &Pubkey {
 		&self.metadata.fee_payer
@@ -184,6 +418,157 @@ impl SolanaTransaction {
 	pub fn instructions(&self) -> &[DecodedInstruction<Vec<u8>>] {
 		&self.instructions
 	}
+
+	/// Returns the account keys of the transaction, including the addresses loaded from
+	/// lookup tables, in the order used by the balances of the status metadata
+	pub fn account_keys(&self) -> Vec<Pubkey> {
+		let loaded_addresses = &self.metadata.meta.loaded_addresses;
+		self.metadata
+			.message
+			.static_account_keys()
+			.iter()
+			.chain(&loaded_addresses.writable)
+			.chain(&loaded_addresses.readonly)
+			.copied()
+			.collect()
+	}
+
+	/// Returns the log messages of the transaction, attributed to the programs that
+	/// logged them
+	pub fn program_logs(&self) -> Vec<ProgramLog<'_>> {
+		self.metadata
+			.meta
+			.log_messages
+			.as_deref()
+			.map(ProgramLog::from_log_messages)
+			.unwrap_or_default()
+	}
+}
+
+/// Log message of a transaction, along with the program executing when it was logged
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgramLog<'a> {
+	/// Program executing when the message was logged, if any
+	pub program_id: Option<Pubkey>,
+	/// Log message
+	pub message: &'a str,
+}
+
+impl<'a> ProgramLog<'a> {
+	/// Attributes log messages to the programs that logged them
+	///
+	/// The executing program is tracked through the `invoke` and `success`/`failed` lines
+	/// of the program invocations, which are attributed to the invoked program.
+	///
+	/// # Arguments
+	/// * `log_messages` - The log messages of a transaction, in order
+	pub fn from_log_messages(log_messages: &'a [String]) -> Vec<Self> {
+		let mut invocations = Vec::<Pubkey>::new();
+
+		log_messages
+			.iter()
+			.map(|message| {
+				let mut words = message.split_whitespace();
+				let program_id = match (words.next(), words.next(), words.next()) {
+					(Some("Program"), Some(program_id), Some(action)) => {
+						match (program_id.parse::<Pubkey>(), action) {
+							(Ok(program_id), "invoke") => {
+								invocations.push(program_id);
+								Some(program_id)
+							}
+							(Ok(program_id), "success" | "failed:") => {
+								invocations.pop();
+								Some(program_id)
+							}
+							_ => invocations.last().copied(),
+						}
+					}
+					_ => invocations.last().copied(),
+				};
+				Self {
+					program_id,
+					message,
+				}
+			})
+			.collect()
+	}
+}
+
+/// Returns the addresses of the lookup tables a message references
+fn lookup_tables(message: &VersionedMessage) -> Vec<Pubkey> {
+	message
+		.address_table_lookups()
+		.unwrap_or_default()
+		.iter()
+		.map(|lookup| lookup.account_key)
+		.collect()
+}
+
+/// Returns whether the loaded addresses match the lookups of a message
+fn has_loaded_addresses(message: &VersionedMessage, loaded_addresses: &LoadedAddresses) -> bool {
+	let lookups = message.address_table_lookups().unwrap_or_default();
+	let writable = lookups
+		.iter()
+		.map(|lookup| lookup.writable_indexes.len())
+		.sum::<usize>();
+	let readonly = lookups
+		.iter()
+		.map(|lookup| lookup.readonly_indexes.len())
+		.sum::<usize>();
+	loaded_addresses.writable.len() == writable && loaded_addresses.readonly.len() == readonly
+}
+
+/// Resolves the addresses a message loads from lookup tables
+///
+/// Writable addresses of all the lookups come first, followed by the readonly addresses,
+/// which is the order used by the account keys of the transaction.
+///
+/// # Arguments
+/// * `message` - The message referencing the lookup tables
+/// * `lookup_tables` - Addresses stored in the lookup tables, by table address
+///
+/// # Errors
+/// - Returns `MissingLoadedAddresses` with the tables that are missing from `lookup_tables`
+/// - Returns `InvalidLookupIndex` if an index is out of bounds of its table
+fn resolve_loaded_addresses(
+	message: &VersionedMessage,
+	lookup_tables: &HashMap<Pubkey, Vec<Pubkey>>,
+) -> Result<LoadedAddresses, SolanaTransactionError> {
+	let lookups = message.address_table_lookups().unwrap_or_default();
+	let missing = lookups
+		.iter()
+		.map(|lookup| lookup.account_key)
+		.filter(|table| !lookup_tables.contains_key(table))
+		.collect::<Vec<_>>();
+	if !missing.is_empty() {
+		return Err(SolanaTransactionError::MissingLoadedAddresses(missing));
+	}
+
+	let mut loaded_addresses = LoadedAddresses::default();
+	for lookup in lookups {
+		let table = &lookup_tables[&lookup.account_key];
+		let resolve = |indexes: &[u8]| {
+			indexes
+				.iter()
+				.map(|&index| {
+					table.get(index as usize).copied().ok_or(
+						SolanaTransactionError::InvalidLookupIndex {
+							table: lookup.account_key,
+							index,
+						},
+					)
+				})
+				.collect::<Result<Vec<_>, _>>()
+		};
+		loaded_addresses
+			.writable
+			.extend(resolve(&lookup.writable_indexes)?);
+		loaded_addresses
+			.readonly
+			.extend(resolve(&lookup.readonly_indexes)?);
+	}
+
+	Ok(loaded_addresses)
 }
 
 #[cfg(test)]
@@ -191,6 +576,7 @@ mod tests {
 	use crate::utils::tests::solana::transaction::TransactionBuilder;
 
 	use super::*;
+	use serde_json::Value;
 	use solana_sdk::{
 		commitment_config::CommitmentConfig,
 		instruction::{AccountMeta, Instruction},
@@ -249,6 +635,14 @@ mod tests {
 		assert_eq!(slot, tx.metadata.slot);
 	}
 
+	#[test]
+	fn test_epoch() {
+		assert_eq!(create_test_transaction().epoch(), 0);
+		for (slot, epoch) in [(431_999, 0), (432_000, 1), (367_200_000, 850)] {
+			assert_eq!(TransactionBuilder::new().slot(slot).build().epoch(), epoch);
+		}
+	}
+
 	#[test]
 	fn test_fee_payer() {
 		let tx = create_test_transaction();
@@ -305,12 +699,239 @@ mod tests {
 			block_height: Some(12345),
 			rewards: None,
 			commitment: CommitmentConfig::confirmed(),
+			decoded_transactions: vec![],
 		};
 
-		let tx = SolanaTransaction::new(&block, 0).unwrap();
+		let tx = SolanaTransaction::new(&block, 0).unwrap().unwrap();
 		assert_eq!(tx.slot(), block.slot);
 		assert_eq!(tx.block_time(), block.block_time);
 		assert_eq!(tx.instructions().len(), 1);
 		assert_eq!(tx.instructions()[0].data, vec![1, 2, 3, 4]);
+		assert!(SolanaTransaction::new(&block, 1).is_none());
+
+		// Transactions decoded with their status metadata are taken as they are
+		let decoded = create_test_transaction();
+		let block = block.with_decoded_transactions(vec![decoded.clone()]);
+		assert_eq!(SolanaTransaction::new(&block, 0), Some(Ok(decoded)));
+	}
+
+	#[test]
+	fn test_transaction_creation_from_malformed_block_transaction() {
+		let mut message = Message::new(
+			&[Instruction {
+				program_id: Pubkey::new_unique(),
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+				data: vec![],
+			}],
+			Some(&Pubkey::new_unique()),
+		);
+		let mut block = SolanaBlock {
+			slot: 12345,
+			blockhash: Signature::new_unique().to_string(),
+			parent_slot: 12344,
+			transactions: vec![solana_sdk::transaction::Transaction {
+				signatures: vec![],
+				message: message.clone(),
+			}],
+			block_time: None,
+			block_height: None,
+			rewards: None,
+			commitment: CommitmentConfig::confirmed(),
+			decoded_transactions: vec![],
+		};
+		assert_eq!(
+			SolanaTransaction::new(&block, 0),
+			Some(Err(SolanaTransactionError::MissingSignature))
+		);
+
+		message.instructions[0].accounts.push(42);
+		block.transactions[0] = solana_sdk::transaction::Transaction {
+			signatures: vec![Signature::new_unique()],
+			message,
+		};
+		assert_eq!(
+			SolanaTransaction::new(&block, 0),
+			Some(Err(SolanaTransactionError::UnknownAccount(42)))
+		);
+	}
+
+	#[test]
+	fn test_transaction_creation_from_encoded() {
+		use solana_sdk::transaction::Transaction;
+		use solana_transaction_status::{Encodable, UiTransactionEncoding};
+
+		let fee_payer = Keypair::new();
+		let program_id = Pubkey::new_unique();
+		let account = Pubkey::new_unique();
+		let mut transaction = Transaction::new_with_payer(
+			&[Instruction {
+				program_id,
+				accounts: vec![AccountMeta::new(account, false)],
+				data: vec![1, 2, 3, 4],
+			}],
+			Some(&fee_payer.pubkey()),
+		);
+		transaction.sign(&[&fee_payer], Default::default());
+		let encoded =
+			VersionedTransaction::from(transaction.clone()).encode(UiTransactionEncoding::Base64);
+
+		let encoded = serde_json::from_value(serde_json::json!({
+			"slot": 12345,
+			"blockTime": 1678901234,
+			"transaction": encoded,
+			"meta": {
+				"err": null,
+				"status": { "Ok": null },
+				"fee": 5000,
+				"preBalances": [1_000_000, 0, 1],
+				"postBalances": [995_000, 0, 1],
+				"logMessages": ["Program log: deposit"],
+				"preTokenBalances": [],
+				"postTokenBalances": [],
+				"loadedAddresses": { "writable": [], "readonly": [] },
+				"computeUnitsConsumed": 150
+			}
+		}))
+		.unwrap();
+
+		let tx = SolanaTransaction::from_encoded(encoded).unwrap();
+		assert_eq!(tx.signature(), &transaction.signatures[0]);
+		assert_eq!(tx.slot(), 12345);
+		assert_eq!(tx.block_time(), Some(1678901234));
+		assert_eq!(tx.fee_payer(), &fee_payer.pubkey());
+		assert_eq!(tx.meta().fee, 5000);
+		assert_eq!(tx.meta().post_balances, vec![995_000, 0, 1]);
+		assert_eq!(tx.meta().compute_units_consumed, Some(150));
+		assert_eq!(
+			tx.meta().log_messages,
+			Some(vec!["Program log: deposit".to_string()])
+		);
+		assert_eq!(tx.instructions().len(), 1);
+		assert_eq!(tx.instructions()[0].program_id, program_id);
+		assert_eq!(tx.instructions()[0].data, vec![1, 2, 3, 4]);
+		assert_eq!(
+			tx.instructions()[0].accounts,
+			vec![AccountMeta::new(account, false)]
+		);
+	}
+
+	/// Creates a v0 transaction loading a writable and a readonly address from a lookup table,
+	/// and its `getTransaction` response without the loaded addresses
+	fn create_v0_transaction(
+		table: Pubkey,
+		addresses: &[Pubkey],
+	) -> (Value, Pubkey, Vec<AccountMeta>) {
+		use solana_sdk::{
+			hash::Hash,
+			message::{v0, MessageHeader},
+		};
+		use solana_transaction_status::{Encodable, UiTransactionEncoding};
+
+		let program_id = Pubkey::new_unique();
+		let transaction = VersionedTransaction {
+			signatures: vec![Signature::new_unique()],
+			message: VersionedMessage::V0(v0::Message {
+				header: MessageHeader {
+					num_required_signatures: 1,
+					num_readonly_signed_accounts: 0,
+					num_readonly_unsigned_accounts: 1,
+				},
+				account_keys: vec![Pubkey::new_unique(), program_id],
+				recent_blockhash: Hash::default(),
+				instructions: vec![CompiledInstruction {
+					program_id_index: 1,
+					accounts: vec![2, 3],
+					data: vec![7],
+				}],
+				address_table_lookups: vec![v0::MessageAddressTableLookup {
+					account_key: table,
+					writable_indexes: vec![1],
+					readonly_indexes: vec![0],
+				}],
+			}),
+		};
+
+		let response = serde_json::json!({
+			"slot": 12345,
+			"blockTime": null,
+			"transaction": transaction.encode(UiTransactionEncoding::Base64),
+			"meta": {
+				"err": null,
+				"status": { "Ok": null },
+				"fee": 5000,
+				"preBalances": [],
+				"postBalances": []
+			}
+		});
+		let accounts = vec![
+			AccountMeta::new(addresses[1], false),
+			AccountMeta::new_readonly(addresses[0], false),
+		];
+		(response, program_id, accounts)
+	}
+
+	#[test]
+	fn test_transaction_creation_from_encoded_without_loaded_addresses() {
+		let table = Pubkey::new_unique();
+		let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+		let (response, program_id, accounts) = create_v0_transaction(table, &addresses);
+		let encoded = || serde_json::from_value(response.clone()).unwrap();
+
+		assert_eq!(
+			SolanaTransaction::from_encoded(encoded()),
+			Err(SolanaTransactionError::MissingLoadedAddresses(vec![table]))
+		);
+
+		let tx = SolanaTransaction::from_encoded_with_lookup_tables(
+			encoded(),
+			&HashMap::from([(table, addresses.to_vec())]),
+		)
+		.unwrap();
+		assert_eq!(tx.meta().loaded_addresses.writable, vec![addresses[1]]);
+		assert_eq!(tx.meta().loaded_addresses.readonly, vec![addresses[0]]);
+		assert_eq!(tx.instructions()[0].program_id, program_id);
+		assert_eq!(tx.instructions()[0].accounts, accounts);
+		assert_eq!(tx.account_keys()[2..], [addresses[1], addresses[0]]);
+
+		assert_eq!(
+			SolanaTransaction::from_encoded_with_lookup_tables(
+				encoded(),
+				&HashMap::from([(table, vec![addresses[0]])]),
+			),
+			Err(SolanaTransactionError::InvalidLookupIndex { table, index: 1 })
+		);
+	}
+
+	#[test]
+	fn test_program_logs_are_attributed_to_the_executing_program() {
+		let outer = Pubkey::new_unique();
+		let inner = Pubkey::new_unique();
+		let logs = vec![
+			"Log before any invocation".to_string(),
+			format!("Program {} invoke [1]", outer),
+			format!("Program {} invoke [2]", inner),
+			"Program log: success".to_string(),
+			format!("Program {} success", inner),
+			"Program log: Error: insufficient collateral".to_string(),
+			format!("Program {} failed: custom program error: 0x1", outer),
+			"Log after the invocations".to_string(),
+		];
+
+		assert_eq!(
+			ProgramLog::from_log_messages(&logs)
+				.iter()
+				.map(|log| log.program_id)
+				.collect::<Vec<_>>(),
+			vec![
+				None,
+				Some(outer),
+				Some(inner),
+				Some(inner),
+				Some(inner),
+				Some(outer),
+				Some(outer),
+				None,
+			]
+		);
 	}
 }
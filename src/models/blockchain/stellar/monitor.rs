@@ -240,7 +240,7 @@ mod tests {
 	use crate::models::{
 		blockchain::stellar::block::LedgerInfo as StellarLedgerInfo,
 		blockchain::stellar::transaction::TransactionInfo as StellarTransactionInfo,
-		ContractSpec as ModelsContractSpec, FunctionCondition, MatchConditions,
+		ConditionLogic, ContractSpec as ModelsContractSpec, FunctionCondition, MatchConditions,
 	};
 	use crate::utils::tests::builders::stellar::monitor::MonitorBuilder;
 	use serde_json::json;
@@ -490,6 +490,12 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			matched_on_args: Some(MatchArguments {
 				functions: Some(vec![match_params]),
@@ -0,0 +1,154 @@
+//! Migrations of monitor configurations written for earlier config versions.
+//!
+//! Monitor files declare the version of the format they are written in with a top-level
+//! `version` field, and files without it are considered to be written in version 1. Before
+//! a monitor is parsed, the migrations between its version and the current one are applied
+//! in order to its JSON, so that the serialization of contract specs can evolve without
+//! breaking existing configurations.
+
+use serde_json::Value;
+
+/// Version of the monitor configuration format understood by this build
+pub const MONITOR_CONFIG_VERSION: u64 = 2;
+
+/// Name of the field holding the version of a monitor configuration
+const VERSION_FIELD: &str = "version";
+
+/// Migration of a monitor configuration from a version to the next one
+type Migration = fn(&mut Value);
+
+/// Migrations indexed by the version they migrate from, starting at version 1
+const MIGRATIONS: [Migration; MONITOR_CONFIG_VERSION as usize - 1] = [migrate_v1_to_v2];
+
+/// Migrates the JSON of a monitor configuration to the current version
+///
+/// The `version` field is removed once the configuration is migrated.
+///
+/// # Arguments
+/// * `config` - JSON of the monitor configuration
+///
+/// # Returns
+/// The version the configuration was written in, or an error if the version is invalid or
+/// newer than the current one
+pub fn migrate_monitor_config(config: &mut Value) -> Result<u64, String> {
+	let version = match config
+		.as_object_mut()
+		.and_then(|object| object.remove(VERSION_FIELD))
+	{
+		None => 1,
+		Some(version) => version
+			.as_u64()
+			.filter(|version| *version >= 1)
+			.ok_or_else(|| format!("invalid monitor config version: {}", version))?,
+	};
+	if version > MONITOR_CONFIG_VERSION {
+		return Err(format!(
+			"unsupported monitor config version {}, the latest supported version is {}",
+			version, MONITOR_CONFIG_VERSION
+		));
+	}
+
+	for migration in &MIGRATIONS[version as usize - 1..] {
+		migration(config);
+	}
+	Ok(version)
+}
+
+/// Returns the contract specs of the addresses of a monitor configuration
+fn contract_specs_mut(config: &mut Value) -> impl Iterator<Item = &mut Value> {
+	config
+		.get_mut("addresses")
+		.and_then(Value::as_array_mut)
+		.into_iter()
+		.flatten()
+		.filter_map(|address| address.get_mut("contract_spec"))
+}
+
+/// Version 2 decodes SPL Token instructions, so `TokenProgram` instructions carry their
+/// decoded variant. Version 1 specs holding the bare `TokenProgram` instruction, which did
+/// not restrict the program to any variant, hold no instruction instead.
+fn migrate_v1_to_v2(config: &mut Value) {
+	const TOKEN_PROGRAM: &str = "TokenProgram";
+
+	for spec in contract_specs_mut(config) {
+		if spec.get("Instruction").and_then(Value::as_str) == Some(TOKEN_PROGRAM) {
+			*spec = serde_json::json!({ "Instructions": [] });
+		} else if let Some(instructions) =
+			spec.get_mut("Instructions").and_then(Value::as_array_mut)
+		{
+			instructions.retain(|instruction| instruction.as_str() != Some(TOKEN_PROGRAM));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_migrate_unversioned_config() {
+		let mut config = json!({
+			"name": "Token Monitor",
+			"addresses": [
+				{
+					"address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					"contract_spec": { "Instruction": "TokenProgram" }
+				},
+				{
+					"address": "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+					"contract_spec": { "Instructions": ["TokenProgram", "KaminoFarms"] }
+				},
+				{
+					"address": "0x0000000000000000000000000000000000000000"
+				}
+			]
+		});
+
+		assert_eq!(migrate_monitor_config(&mut config).unwrap(), 1);
+		assert_eq!(
+			config["addresses"][0]["contract_spec"],
+			json!({ "Instructions": [] })
+		);
+		assert_eq!(
+			config["addresses"][1]["contract_spec"],
+			json!({ "Instructions": ["KaminoFarms"] })
+		);
+		assert!(config["addresses"][2].get("contract_spec").is_none());
+	}
+
+	#[test]
+	fn test_migrate_current_config() {
+		let mut config = json!({
+			"version": MONITOR_CONFIG_VERSION,
+			"addresses": [
+				{
+					"address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					"contract_spec": { "InstructionNames": ["Transfer"] }
+				}
+			]
+		});
+		let expected = json!({
+			"addresses": [
+				{
+					"address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					"contract_spec": { "InstructionNames": ["Transfer"] }
+				}
+			]
+		});
+
+		assert_eq!(
+			migrate_monitor_config(&mut config).unwrap(),
+			MONITOR_CONFIG_VERSION
+		);
+		assert_eq!(config, expected);
+	}
+
+	#[test]
+	fn test_migrate_invalid_version() {
+		for version in [json!(0), json!("2"), json!(MONITOR_CONFIG_VERSION + 1)] {
+			let mut config = json!({ "version": version });
+			assert!(migrate_monitor_config(&mut config).is_err());
+		}
+	}
+}
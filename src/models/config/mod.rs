@@ -9,6 +9,7 @@ use async_trait::async_trait;
 use std::path::Path;
 
 mod error;
+mod migration;
 mod monitor_config;
 mod network_config;
 mod trigger_config;
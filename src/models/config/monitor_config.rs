@@ -7,11 +7,353 @@ use async_trait::async_trait;
 use std::{collections::HashMap, fs, path::Path};
 
 use crate::{
-	models::{config::error::ConfigError, ConfigLoader, Monitor},
-	services::trigger::validate_script_config,
+	models::{
+		config::{
+			error::ConfigError,
+			migration::{migrate_monitor_config, MONITOR_CONFIG_VERSION},
+		},
+		ConfigLoader, ContractSpec, FunctionCondition, Monitor, SolanaContractSpec,
+		SolanaDecoderType,
+	},
+	services::{
+		decoders::{
+			bpf_upgradeable_loader,
+			idl::{IdlPrimitiveType, IdlType},
+			AnchorIdl, DecoderRegistry,
+		},
+		filter::{
+			solana_helpers::{are_same_instruction, parse_pubkey, parse_signature_params},
+			SOLANA_RAW_INSTRUCTION_NAME,
+		},
+		trigger::validate_script_config,
+	},
 	utils::normalize_string,
 };
 
+/// Reads and parses an Anchor IDL JSON file
+fn load_idl_file(path: &str) -> Result<AnchorIdl, ConfigError> {
+	let metadata = || Some(HashMap::from([("idl_path".to_string(), path.to_string())]));
+	let file = fs::File::open(path).map_err(|e| {
+		ConfigError::file_error(
+			format!("failed to open IDL file: {}", e),
+			Some(Box::new(e)),
+			metadata(),
+		)
+	})?;
+	serde_json::from_reader(file).map_err(|e| {
+		ConfigError::parse_error(
+			format!("failed to parse IDL file: {}", e),
+			Some(Box::new(e)),
+			metadata(),
+		)
+	})
+}
+
+/// Checks an instruction signature against the instructions of an Anchor IDL
+///
+/// The signature must name an instruction of the IDL, unless it is the raw instruction
+/// signature. Declared parameters must match the instruction arguments in number, in name
+/// when named, and in type when the argument has a primitive type.
+fn validate_idl_signature(signature: &str, idl: &AnchorIdl) -> Result<(), String> {
+	if are_same_instruction(signature, SOLANA_RAW_INSTRUCTION_NAME) {
+		return Ok(());
+	}
+	let instruction = idl
+		.instructions
+		.iter()
+		.find(|instruction| are_same_instruction(signature, &instruction.name))
+		.ok_or_else(|| {
+			format!(
+				"the instruction is not declared in the IDL, matchable instructions are: {}",
+				idl.instructions
+					.iter()
+					.map(|instruction| instruction.name.as_str())
+					.collect::<Vec<_>>()
+					.join(", ")
+			)
+		})?;
+
+	let params = parse_signature_params(signature);
+	if params.is_empty() {
+		return Ok(());
+	}
+	if params.len() != instruction.args.len() {
+		return Err(format!(
+			"{} parameters declared, but '{}' takes {}",
+			params.len(),
+			instruction.name,
+			instruction.args.len()
+		));
+	}
+	for ((ty, name), arg) in params.iter().zip(&instruction.args) {
+		if let Some(name) = name
+			.as_ref()
+			.filter(|name| !are_same_instruction(name, &arg.name))
+		{
+			return Err(format!(
+				"parameter '{}' does not match argument '{}'",
+				name, arg.name
+			));
+		}
+		if let IdlType::Primitive(primitive) = &arg.ty {
+			let expected = serde_json::to_value(primitive)
+				.ok()
+				.and_then(|value| value.as_str().map(str::to_string))
+				.unwrap_or_default();
+			let declared = ty.to_lowercase();
+			let is_pubkey_alias = *primitive == IdlPrimitiveType::Pubkey && declared == "publickey";
+			if declared != expected && !is_pubkey_alias {
+				return Err(format!(
+					"parameter type '{}' does not match argument '{}' of type '{}'",
+					ty, arg.name, expected
+				));
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Checks a contract spec that relies on a built-in decoder against the decoders compiled
+/// into the binary
+///
+/// Instruction specs require a decoder for the program at the address, and the instruction
+/// names of the spec and of the function conditions must be decodable by it. Addresses
+/// without a contract spec are decoded by the built-in decoder of their program id, if
+/// any, whose instructions are returned unchecked. Account specs must name account variants that a registered account decoder can decode.
+/// Specs carrying an IDL or a layout decode on their own and are not checked here.
+///
+/// # Returns
+/// The instruction names the function conditions of the address can match
+fn validate_decoder_spec(
+	address: &str,
+	spec: Option<&SolanaContractSpec>,
+	functions: &[FunctionCondition],
+	registry: &DecoderRegistry,
+) -> Result<Vec<String>, String> {
+	let program_names = parse_pubkey(address)
+		.and_then(|program_id| registry.instruction_names(&program_id))
+		.map(|names| {
+			names
+				.iter()
+				.map(|name| name.to_string())
+				.collect::<Vec<_>>()
+		});
+
+	let matchable = match spec.map(SolanaContractSpec::decoder_type) {
+		// Function conditions of addresses without a spec may also name the transaction
+		// types of enhanced endpoints, so only the decodable instructions are reported
+		None => return Ok(program_names.unwrap_or_default()),
+		Some(SolanaDecoderType::AccountNames(names)) => {
+			let available = registry.account_names();
+			if let Some(name) = names.iter().find(|name| {
+				!available
+					.iter()
+					.any(|known| are_same_instruction(name, known))
+			}) {
+				return Err(format!(
+					"no decoder compiled into the binary decodes '{}' accounts, decodable \
+					 accounts are: {}",
+					name,
+					available.join(", ")
+				));
+			}
+			return Ok(Vec::new());
+		}
+		Some(
+			SolanaDecoderType::Instruction(_)
+			| SolanaDecoderType::Instructions(_)
+			| SolanaDecoderType::InstructionNames(_),
+		) => {
+			let available = program_names.ok_or_else(|| {
+				"no decoder is compiled into the binary for the program, provide an Anchor IDL \
+				 (`Idl` or `IdlFile`) or a `Layout` instead"
+					.to_string()
+			})?;
+			match spec.and_then(SolanaContractSpec::instruction_names) {
+				Some(names) => {
+					if let Some(name) = names.iter().find(|name| {
+						!available.is_empty()
+							&& !available
+								.iter()
+								.any(|known| are_same_instruction(name, known))
+					}) {
+						return Err(format!(
+							"the decoder of the program does not decode '{}' instructions, \
+							 decodable instructions are: {}",
+							name,
+							available.join(", ")
+						));
+					}
+					names
+				}
+				None => available,
+			}
+		}
+		Some(_) => return Ok(Vec::new()),
+	};
+
+	// Loader instructions acting on a monitored program match its address conditions too
+	let loader_names = registry
+		.instruction_names(&bpf_upgradeable_loader::PROGRAM_ID)
+		.unwrap_or_default();
+	if !matchable.is_empty() {
+		if let Some(func) = functions.iter().find(|func| {
+			!are_same_instruction(&func.signature, SOLANA_RAW_INSTRUCTION_NAME)
+				&& !matchable
+					.iter()
+					.map(String::as_str)
+					.chain(loader_names.iter().copied())
+					.any(|name| are_same_instruction(&func.signature, name))
+		}) {
+			return Err(format!(
+				"function signature '{}' cannot match, matchable instructions are: {}",
+				func.signature,
+				matchable.join(", ")
+			));
+		}
+	}
+	Ok(matchable)
+}
+
+impl Monitor {
+	/// Replace the `IdlFile` contract specs of Solana addresses with the IDLs they point
+	/// to, and store the discriminators missing from every IDL
+	///
+	/// IDL paths are relative to the working directory, like trigger script paths.
+	fn resolve_contract_specs(&self) -> Result<Self, ConfigError> {
+		let mut monitor = self.clone();
+		for address in &mut monitor.addresses {
+			let mut idl = match &address.contract_spec {
+				Some(ContractSpec::Solana(spec)) => match spec.decoder_type() {
+					SolanaDecoderType::Idl(idl) => idl.clone(),
+					SolanaDecoderType::IdlFile(path) => load_idl_file(path)?,
+					_ => continue,
+				},
+				_ => continue,
+			};
+			idl.resolve_discriminators();
+			address.contract_spec = Some(ContractSpec::Solana(SolanaContractSpec::new(
+				SolanaDecoderType::Idl(idl),
+			)));
+		}
+		Ok(monitor)
+	}
+
+	/// Replace the `$NAME` references of all expressions with the monitor constants
+	///
+	/// String constants are inserted as quoted string literals, numbers and booleans as
+	/// written. References inside quoted strings are left untouched.
+	fn resolve_constants(&self) -> Result<Self, ConfigError> {
+		let mut monitor = self.clone();
+		let conditions = &mut monitor.match_conditions;
+		let expressions = conditions
+			.functions
+			.iter_mut()
+			.map(|condition| &mut condition.expression)
+			.chain(conditions.events.iter_mut().map(|c| &mut c.expression))
+			.chain(
+				conditions
+					.transactions
+					.iter_mut()
+					.map(|c| &mut c.expression),
+			)
+			.chain(conditions.accounts.iter_mut().map(|c| &mut c.expression))
+			.chain(
+				conditions
+					.balance_changes
+					.iter_mut()
+					.map(|c| &mut c.expression),
+			)
+			.chain(conditions.logs.iter_mut().map(|c| &mut c.expression))
+			.chain(
+				conditions
+					.sequences
+					.iter_mut()
+					.flat_map(|c| c.steps.iter_mut().map(|step| &mut step.expression)),
+			)
+			.chain(conditions.blocks.iter_mut().map(|c| &mut c.expression))
+			.flatten();
+
+		for expression in expressions {
+			*expression = substitute_constants(expression, &self.constants)?;
+		}
+		for route in &mut monitor.trigger_routes {
+			route.expression = substitute_constants(&route.expression, &self.constants)?;
+		}
+
+		Ok(monitor)
+	}
+}
+
+/// Replace the `$NAME` references of an expression with the given constants
+fn substitute_constants(
+	expression: &str,
+	constants: &HashMap<String, serde_json::Value>,
+) -> Result<String, ConfigError> {
+	let mut resolved = String::with_capacity(expression.len());
+	let mut chars = expression.chars().peekable();
+	let mut quote: Option<char> = None;
+
+	while let Some(c) = chars.next() {
+		match (quote, c) {
+			(Some(_), '\\') => {
+				resolved.push(c);
+				if let Some(escaped) = chars.next() {
+					resolved.push(escaped);
+				}
+				continue;
+			}
+			(Some(open), c) if c == open => quote = None,
+			(None, '\'' | '"') => quote = Some(c),
+			(None, '$') => {
+				let mut name = String::new();
+				while let Some(&next) = chars.peek() {
+					if !(next.is_ascii_alphanumeric() || next == '_') {
+						break;
+					}
+					name.push(next);
+					chars.next();
+				}
+
+				let literal = match constants.get(&name) {
+					Some(serde_json::Value::String(value)) if !value.contains('\'') => {
+						format!("'{}'", value)
+					}
+					Some(serde_json::Value::String(value)) if !value.contains('"') => {
+						format!("\"{}\"", value)
+					}
+					Some(value @ (serde_json::Value::Number(_) | serde_json::Value::Bool(_))) => {
+						value.to_string()
+					}
+					Some(_) => {
+						return Err(ConfigError::validation_error(
+							format!("Constant '{}' must be a number, a boolean or a string without both quote characters", name),
+							None,
+							None,
+						))
+					}
+					None => {
+						return Err(ConfigError::validation_error(
+							format!(
+								"Undefined constant '${}' in expression: {}",
+								name, expression
+							),
+							None,
+							None,
+						))
+					}
+				};
+				resolved.push_str(&literal);
+				continue;
+			}
+			_ => {}
+		}
+		resolved.push(c);
+	}
+
+	Ok(resolved)
+}
+
 #[async_trait]
 impl ConfigLoader for Monitor {
 	/// Resolve all secrets in the monitor configuration
@@ -101,19 +443,58 @@ impl ConfigLoader for Monitor {
 				)])),
 			)
 		})?;
-		let mut config: Monitor = serde_json::from_reader(file).map_err(|e| {
+		let metadata = || {
+			Some(HashMap::from([(
+				"path".to_string(),
+				path.display().to_string(),
+			)]))
+		};
+		let mut json: serde_json::Value = serde_json::from_reader(file).map_err(|e| {
 			ConfigError::parse_error(
 				format!("failed to parse monitor config: {}", e),
 				Some(Box::new(e)),
-				Some(HashMap::from([(
-					"path".to_string(),
-					path.display().to_string(),
-				)])),
+				metadata(),
+			)
+		})?;
+
+		// Migrate configs written for earlier versions before parsing them
+		let version = migrate_monitor_config(&mut json).map_err(|reason| {
+			ConfigError::parse_error(
+				format!("failed to migrate monitor config: {}", reason),
+				None,
+				metadata(),
+			)
+		})?;
+		if version < MONITOR_CONFIG_VERSION {
+			tracing::debug!(
+				"Migrated monitor config {} from version {} to {}",
+				path.display(),
+				version,
+				MONITOR_CONFIG_VERSION
+			);
+		}
+
+		let mut config: Monitor = serde_json::from_value(json).map_err(|e| {
+			ConfigError::parse_error(
+				format!("failed to parse monitor config: {}", e),
+				Some(Box::new(e)),
+				metadata(),
 			)
 		})?;
 
-		// Resolve secrets before validating
+		// Resolve secrets and constants before validating
 		config = config.resolve_secrets().await?;
+		config = config.resolve_constants().map_err(|e| {
+			ConfigError::validation_error(
+				format!("failed to resolve monitor constants: {}", e),
+				Some(Box::new(e)),
+				Some(HashMap::from([
+					("path".to_string(), path.display().to_string()),
+					("monitor_name".to_string(), config.name.clone()),
+				])),
+			)
+		})?;
+		config = config.resolve_contract_specs()?;
 
 		// Validate the config after loading
 		config.validate().map_err(|e| {
@@ -150,8 +531,17 @@ impl ConfigLoader for Monitor {
 			));
 		}
 
+		let address_conditions = self
+			.addresses
+			.iter()
+			.filter_map(|address| address.match_conditions.as_ref());
+
 		// Validate function signatures
-		for func in &self.match_conditions.functions {
+		for func in self.match_conditions.functions.iter().chain(
+			address_conditions
+				.clone()
+				.flat_map(|conditions| &conditions.functions),
+		) {
 			if !func.signature.contains('(') || !func.signature.contains(')') {
 				return Err(ConfigError::validation_error(
 					format!("Invalid function signature format: {}", func.signature),
@@ -161,8 +551,69 @@ impl ConfigLoader for Monitor {
 			}
 		}
 
+		// Validate the Solana contract specs relying on a built-in decoder against the
+		// decoders compiled into the binary
+		let registry = DecoderRegistry::with_builtin_decoders();
+		for address in &self.addresses {
+			let spec = match &address.contract_spec {
+				Some(ContractSpec::Solana(spec)) => Some(spec),
+				Some(_) => continue,
+				None => None,
+			};
+			let functions = address
+				.match_conditions
+				.as_ref()
+				.map_or(&[][..], |conditions| &conditions.functions[..]);
+			let matchable = validate_decoder_spec(&address.address, spec, functions, &registry)
+				.map_err(|reason| {
+					ConfigError::validation_error(
+						format!("Invalid contract spec for {}: {}", address.address, reason),
+						None,
+						None,
+					)
+				})?;
+			if !matchable.is_empty() {
+				tracing::debug!(
+					"Monitor '{}' can match the instructions of {}: {}",
+					self.name,
+					address.address,
+					matchable.join(", ")
+				);
+			}
+		}
+
+		// Validate the function conditions of an address with an IDL against its
+		// instructions
+		for address in &self.addresses {
+			let (Some(ContractSpec::Solana(spec)), Some(conditions)) =
+				(&address.contract_spec, &address.match_conditions)
+			else {
+				continue;
+			};
+			let Some(idl) = spec.idl() else {
+				continue;
+			};
+			for func in &conditions.functions {
+				validate_idl_signature(&func.signature, idl).map_err(|reason| {
+					ConfigError::validation_error(
+						format!(
+							"Invalid function signature '{}' for {}: {}",
+							func.signature, address.address, reason
+						),
+						None,
+						None,
+					)
+				})?;
+			}
+		}
+
 		// Validate event signatures
-		for event in &self.match_conditions.events {
+		for event in self
+			.match_conditions
+			.events
+			.iter()
+			.chain(address_conditions.flat_map(|conditions| &conditions.events))
+		{
 			if !event.signature.contains('(') || !event.signature.contains(')') {
 				return Err(ConfigError::validation_error(
 					format!("Invalid event signature format: {}", event.signature),
@@ -172,6 +623,65 @@ impl ConfigLoader for Monitor {
 			}
 		}
 
+		// Validate sequences, which order at least two instructions
+		if let Some(sequence) = self
+			.match_conditions
+			.sequences
+			.iter()
+			.find(|sequence| sequence.steps.len() < 2)
+		{
+			return Err(ConfigError::validation_error(
+				format!(
+					"Sequence conditions require at least two steps, got {}",
+					sequence.steps.len()
+				),
+				None,
+				None,
+			));
+		}
+
+		// Validate escalations, whose steps fire in order
+		if let Some(escalation) = &self.escalation {
+			if escalation.is_empty() {
+				return Err(ConfigError::validation_error(
+					"Escalation requires at least one step",
+					None,
+					None,
+				));
+			}
+			if escalation
+				.windows(2)
+				.any(|steps| steps[1].after_secs < steps[0].after_secs)
+			{
+				return Err(ConfigError::validation_error(
+					"Escalation steps must be ordered by after_secs",
+					None,
+					None,
+				));
+			}
+		}
+
+		// Validate trigger routes
+		for route in &self.trigger_routes {
+			if route.expression.trim().is_empty() {
+				return Err(ConfigError::validation_error(
+					"Trigger route requires an expression",
+					None,
+					None,
+				));
+			}
+			if route.triggers.is_empty() {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Trigger route '{}' requires at least one trigger",
+						route.expression
+					),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate trigger conditions (focus on script path, timeout, and language)
 		for trigger_condition in &self.trigger_conditions {
 			validate_script_config(
@@ -239,7 +749,11 @@ impl ConfigLoader for Monitor {
 mod tests {
 	use super::*;
 	use crate::{
-		models::core::{ScriptLanguage, TransactionStatus},
+		models::core::{
+			AddressMatchConditions, AddressWithSpec, ConditionLogic, EscalationStep,
+			EventCondition, MonitorSeverity, ScriptLanguage, TransactionStatus, TriggerRoute,
+		},
+		services::decoders::ProgramLayout,
 		utils::tests::builders::evm::monitor::MonitorBuilder,
 	};
 	use std::collections::HashMap;
@@ -288,6 +802,438 @@ mod tests {
 		assert_eq!(monitor.name, "TestMonitor");
 	}
 
+	#[tokio::test]
+	async fn test_load_versioned_monitor() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("token_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [
+				{
+					"address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+					"contract_spec": { "Instruction": "TokenProgram" }
+				}
+			],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+
+		// Unversioned configs are migrated from version 1
+		fs::write(&file_path, config).unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.addresses[0].contract_spec,
+			Some(ContractSpec::Solana(SolanaContractSpec::new(
+				SolanaDecoderType::Instructions(vec![])
+			)))
+		);
+
+		let versioned = config.replacen(
+			'{',
+			&format!("{{\"version\": {},", MONITOR_CONFIG_VERSION),
+			1,
+		);
+		fs::write(&file_path, &versioned).unwrap();
+		assert!(matches!(
+			Monitor::load_from_path(&file_path).await,
+			Err(ConfigError::ParseError(_))
+		));
+		let versioned = versioned.replace(
+			r#"{ "Instruction": "TokenProgram" }"#,
+			r#"{ "InstructionNames": ["Transfer"] }"#,
+		);
+		fs::write(&file_path, versioned).unwrap();
+		assert!(Monitor::load_from_path(&file_path).await.is_ok());
+
+		let unsupported = config.replacen('{', "{\"version\": 99,", 1);
+		fs::write(&file_path, unsupported).unwrap();
+		assert!(matches!(
+			Monitor::load_from_path(&file_path).await,
+			Err(ConfigError::ParseError(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_matching_all_addresses() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("firehose_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_all_addresses": true,
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": [
+					{"status": "Success", "expression": "priority_fee_lamports > 500000000"}
+				]
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert!(monitor.match_all_addresses);
+		assert!(monitor.matches_all_addresses());
+
+		let config = config.replace(r#""match_all_addresses": true,"#, "");
+		fs::write(&file_path, config).unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert!(!monitor.matches_all_addresses());
+		assert!(!serde_json::to_string(&monitor)
+			.unwrap()
+			.contains("match_all_addresses"));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_sequences() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("sequence_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"constants": {"MIN_AMOUNT": 1000},
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": [],
+				"sequences": [{
+					"steps": [
+						{"signature": "FlashBorrowReserveLiquidity", "expression": "liquidity_amount > $MIN_AMOUNT"},
+						{"signature": "Swap", "expression": null}
+					]
+				}]
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		let steps = &monitor.match_conditions.sequences[0].steps;
+		assert_eq!(steps.len(), 2);
+		assert_eq!(
+			steps[0].expression.as_deref(),
+			Some("liquidity_amount > 1000")
+		);
+
+		// A sequence orders at least two instructions
+		let config = config.replace(
+			r#",
+						{"signature": "Swap", "expression": null}"#,
+			"",
+		);
+		fs::write(&file_path, config).unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_condition_logic() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("any_condition_monitor.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": [],
+				"condition_logic": "any",
+				"logs": [{"expression": "log contains 'Error'"}]
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.match_conditions.condition_logic,
+			ConditionLogic::Any
+		);
+
+		let config = config.replace(r#""condition_logic": "any","#, "");
+		fs::write(&file_path, config).unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.match_conditions.condition_logic,
+			ConditionLogic::All
+		);
+		assert!(!serde_json::to_string(&monitor)
+			.unwrap()
+			.contains("condition_logic"));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_constants() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor_with_constants.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": [
+					{
+						"status": "Success",
+						"expression": "fee_payer == $WHALE AND slot > $MIN_SLOT"
+					}
+				],
+				"balance_changes": [
+					{"expression": "mint in [$USDC_MINT] AND delta > $WHALE_THRESHOLD"}
+				]
+			},
+			"trigger_conditions": [],
+			"triggers": [],
+			"constants": {
+				"USDC_MINT": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+				"WHALE": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM",
+				"WHALE_THRESHOLD": 1000000000,
+				"MIN_SLOT": 100
+			}
+		}"#;
+
+		fs::write(&file_path, config).unwrap();
+
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.match_conditions.transactions[0]
+				.expression
+				.as_deref(),
+			Some("fee_payer == '9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM' AND slot > 100")
+		);
+		assert_eq!(
+			monitor.match_conditions.balance_changes[0]
+				.expression
+				.as_deref(),
+			Some("mint in ['EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v'] AND delta > 1000000000")
+		);
+		assert_eq!(monitor.constants.len(), 4);
+
+		// Undefined constants fail to load
+		let config = config.replace("$MIN_SLOT", "$MISSING");
+		fs::write(&file_path, config).unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_severity() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor_with_severity.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+		let with_severity = |severity: &str| {
+			config.replace(
+				"\"triggers\": []",
+				&format!("\"triggers\": [], \"severity\": \"{}\"", severity),
+			)
+		};
+
+		fs::write(&file_path, with_severity("critical")).unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(monitor.severity, Some(MonitorSeverity::Critical));
+		assert_eq!(monitor.severity(), MonitorSeverity::Critical);
+
+		// Monitors without severity are informational
+		fs::write(&file_path, config).unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(monitor.severity, None);
+		assert_eq!(monitor.severity(), MonitorSeverity::Info);
+
+		// Unknown severities fail to load
+		fs::write(&file_path, with_severity("urgent")).unwrap();
+		assert!(Monitor::load_from_path(&file_path).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_escalation() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor_with_escalation.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": []
+		}"#;
+		let with_escalation = |escalation: &str| {
+			config.replace(
+				"\"triggers\": []",
+				&format!("\"triggers\": [], \"escalation\": {}", escalation),
+			)
+		};
+
+		fs::write(
+			&file_path,
+			with_escalation(
+				r#"[{"trigger": "slack"}, {"trigger": "pagerduty", "after_secs": 600}]"#,
+			),
+		)
+		.unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.escalation,
+			Some(vec![
+				EscalationStep {
+					trigger: "slack".to_string(),
+					after_secs: 0,
+				},
+				EscalationStep {
+					trigger: "pagerduty".to_string(),
+					after_secs: 600,
+				},
+			])
+		);
+
+		// Empty and unordered escalations fail to load
+		fs::write(&file_path, with_escalation("[]")).unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+
+		fs::write(
+			&file_path,
+			with_escalation(
+				r#"[{"trigger": "pagerduty", "after_secs": 600}, {"trigger": "slack"}]"#,
+			),
+		)
+		.unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_trigger_routes() {
+		let temp_dir = TempDir::new().unwrap();
+		let file_path = temp_dir.path().join("monitor_with_trigger_routes.json");
+
+		let config = r#"{
+			"name": "TestMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [],
+			"match_conditions": {
+				"functions": [],
+				"events": [],
+				"transactions": []
+			},
+			"trigger_conditions": [],
+			"triggers": ["slack"],
+			"constants": {"WHALE": 1000000}
+		}"#;
+		let with_routes = |routes: &str| {
+			config.replace(
+				"\"triggers\": [\"slack\"]",
+				&format!("\"triggers\": [\"slack\"], \"trigger_routes\": {}", routes),
+			)
+		};
+
+		fs::write(
+			&file_path,
+			with_routes(r#"[{"expression": "amount >= $WHALE", "triggers": ["pagerduty"]}]"#),
+		)
+		.unwrap();
+		let monitor = Monitor::load_from_path(&file_path).await.unwrap();
+		assert_eq!(
+			monitor.trigger_routes,
+			vec![TriggerRoute {
+				expression: "amount >= 1000000".to_string(),
+				triggers: vec!["pagerduty".to_string()],
+			}]
+		);
+
+		// Routes without expression or triggers fail to load
+		fs::write(
+			&file_path,
+			with_routes(r#"[{"expression": " ", "triggers": ["pagerduty"]}]"#),
+		)
+		.unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+
+		fs::write(
+			&file_path,
+			with_routes(r#"[{"expression": "amount >= $WHALE", "triggers": []}]"#),
+		)
+		.unwrap();
+		let result = Monitor::load_from_path(&file_path).await;
+		assert!(matches!(result, Err(ConfigError::ValidationError(_))));
+	}
+
+	#[test]
+	fn test_substitute_constants() {
+		let constants = HashMap::from([
+			("NAME".to_string(), serde_json::json!("it's")),
+			("FLAG".to_string(), serde_json::json!(true)),
+			("LIST".to_string(), serde_json::json!([1])),
+			("BOTH".to_string(), serde_json::json!("'\"")),
+		]);
+
+		assert_eq!(
+			substitute_constants("name == $NAME AND flag == $FLAG", &constants).unwrap(),
+			"name == \"it's\" AND flag == true"
+		);
+		// References inside quoted strings are kept as written
+		assert_eq!(
+			substitute_constants("memo == '$NAME \\' $FLAG' OR a == $FLAG", &constants).unwrap(),
+			"memo == '$NAME \\' $FLAG' OR a == true"
+		);
+		assert_eq!(
+			substitute_constants("a == 1", &constants).unwrap(),
+			"a == 1"
+		);
+
+		assert!(substitute_constants("a == $LIST", &constants).is_err());
+		assert!(substitute_constants("a == $BOTH", &constants).is_err());
+		assert!(substitute_constants("a == $MISSING", &constants).is_err());
+		assert!(substitute_constants("a == $", &constants).is_err());
+	}
+
 	#[tokio::test]
 	async fn test_load_invalid_monitor() {
 		let temp_dir = TempDir::new().unwrap();
@@ -402,6 +1348,54 @@ mod tests {
 		assert!(invalid_monitor.validate().is_err());
 	}
 
+	#[test]
+	fn test_validate_monitor_with_address_conditions() {
+		let mut monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.networks(vec!["solana_mainnet".to_string()])
+			.address("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD")
+			.build();
+		monitor.addresses[0].match_conditions = Some(AddressMatchConditions {
+			functions: vec![FunctionCondition {
+				signature: "deposit(u64)".to_string(),
+				expression: Some("amount > 100".to_string()),
+			}],
+			events: vec![],
+		});
+		assert!(monitor.validate().is_ok());
+
+		monitor.addresses[0].match_conditions = Some(AddressMatchConditions {
+			functions: vec![],
+			events: vec![EventCondition {
+				signature: "Deposited".to_string(),
+				expression: None,
+			}],
+		});
+		assert!(monitor.validate().is_err());
+	}
+
+	#[test]
+	fn test_address_conditions_are_optional() {
+		let address: AddressWithSpec = serde_json::from_value(serde_json::json!({
+			"address": "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+			"contract_spec": null
+		}))
+		.unwrap();
+		assert_eq!(address.match_conditions, None);
+
+		let address: AddressWithSpec = serde_json::from_value(serde_json::json!({
+			"address": "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+			"contract_spec": null,
+			"match_conditions": {
+				"functions": [{ "signature": "deposit(u64)", "expression": null }]
+			}
+		}))
+		.unwrap();
+		let conditions = address.match_conditions.unwrap();
+		assert_eq!(conditions.functions[0].signature, "deposit(u64)");
+		assert!(conditions.events.is_empty());
+	}
+
 	#[test]
 	fn test_validate_monitor_with_trigger_conditions() {
 		// Create a temporary directory and script file
@@ -549,6 +1543,256 @@ mod tests {
 		));
 	}
 
+	fn write_idl_monitor(temp_dir: &TempDir, idl_path: &Path) -> std::path::PathBuf {
+		let monitor_path = temp_dir.path().join("monitor.json");
+		let monitor = serde_json::json!({
+			"name": "IdlMonitor",
+			"networks": ["solana_mainnet"],
+			"paused": false,
+			"addresses": [
+				{
+					"address": "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+					"contract_spec": { "IdlFile": idl_path.display().to_string() },
+					"match_conditions": {
+						"functions": [{ "signature": "deposit(u64)", "expression": "amount > 100" }]
+					}
+				}
+			],
+			"match_conditions": { "functions": [], "events": [], "transactions": [] },
+			"trigger_conditions": [],
+			"triggers": []
+		});
+		fs::write(&monitor_path, monitor.to_string()).unwrap();
+		monitor_path
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_idl_file() {
+		let temp_dir = TempDir::new().unwrap();
+		let idl_path = temp_dir.path().join("program.json");
+		let idl = serde_json::json!({
+			"name": "program",
+			"instructions": [
+				{ "name": "deposit", "accounts": [], "args": [{ "name": "amount", "type": "u64" }] }
+			]
+		});
+		fs::write(&idl_path, idl.to_string()).unwrap();
+
+		let monitor = Monitor::load_from_path(&write_idl_monitor(&temp_dir, &idl_path))
+			.await
+			.unwrap();
+
+		let Some(ContractSpec::Solana(spec)) = &monitor.addresses[0].contract_spec else {
+			panic!("expected a Solana contract spec");
+		};
+		let instruction = &spec.idl().unwrap().instructions[0];
+		assert_eq!(
+			instruction.discriminator.as_deref(),
+			Some(instruction.discriminator().as_slice())
+		);
+	}
+
+	#[tokio::test]
+	async fn test_load_monitor_with_idl_file_errors() {
+		let temp_dir = TempDir::new().unwrap();
+		let idl_path = temp_dir.path().join("program.json");
+
+		let monitor_path = write_idl_monitor(&temp_dir, &idl_path);
+		assert!(matches!(
+			Monitor::load_from_path(&monitor_path).await,
+			Err(ConfigError::FileError(_))
+		));
+
+		fs::write(&idl_path, "{\"instructions\": ").unwrap();
+		assert!(matches!(
+			Monitor::load_from_path(&monitor_path).await,
+			Err(ConfigError::ParseError(_))
+		));
+
+		fs::write(
+			&idl_path,
+			serde_json::json!({ "instructions": [{ "name": "withdraw" }] }).to_string(),
+		)
+		.unwrap();
+		assert!(matches!(
+			Monitor::load_from_path(&monitor_path).await,
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_idl_signature() {
+		let idl: AnchorIdl = serde_json::from_value(serde_json::json!({
+			"instructions": [
+				{
+					"name": "deposit",
+					"accounts": [],
+					"args": [
+						{ "name": "amount", "type": "u64" },
+						{ "name": "owner", "type": "publicKey" },
+						{ "name": "config", "type": { "defined": "Config" } }
+					]
+				}
+			]
+		}))
+		.unwrap();
+
+		assert!(validate_idl_signature("raw()", &idl).is_ok());
+		assert!(validate_idl_signature("deposit()", &idl).is_ok());
+		assert!(validate_idl_signature("deposit(u64, pubkey, Config)", &idl).is_ok());
+		assert!(validate_idl_signature(
+			"deposit(u64 amount, PublicKey owner, Config config)",
+			&idl
+		)
+		.is_ok());
+		assert!(validate_idl_signature("withdraw(u64)", &idl).is_err());
+		assert!(validate_idl_signature("deposit(u64 amount)", &idl).is_err());
+		assert!(validate_idl_signature("deposit(u32, pubkey, Config)", &idl).is_err());
+		assert!(validate_idl_signature("deposit(u64 value, pubkey, Config)", &idl).is_err());
+	}
+
+	#[test]
+	fn test_validate_decoder_spec() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let kamino = "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD";
+		let spec = |decoder_type| SolanaContractSpec::new(decoder_type);
+		let functions = |signatures: &[&str]| -> Vec<FunctionCondition> {
+			signatures
+				.iter()
+				.map(|signature| FunctionCondition {
+					signature: signature.to_string(),
+					expression: None,
+				})
+				.collect()
+		};
+		let names =
+			|names: &[&str]| -> Vec<String> { names.iter().map(|name| name.to_string()).collect() };
+
+		let restricted = spec(SolanaDecoderType::InstructionNames(names(&[
+			"deposit_reserve_liquidity",
+		])));
+		assert_eq!(
+			validate_decoder_spec(
+				kamino,
+				Some(&restricted),
+				&functions(&["depositReserveLiquidity(u64)", "raw()"]),
+				&registry
+			),
+			Ok(vec!["deposit_reserve_liquidity".to_string()])
+		);
+		let error = validate_decoder_spec(
+			kamino,
+			Some(&restricted),
+			&functions(&["RefreshReserve()"]),
+			&registry,
+		)
+		.unwrap_err();
+		assert!(error.contains("deposit_reserve_liquidity"));
+		// Loader instructions acting on the program match its address conditions too
+		assert!(validate_decoder_spec(
+			kamino,
+			Some(&restricted),
+			&functions(&["Upgrade()"]),
+			&registry
+		)
+		.is_ok());
+
+		// Addresses without a spec are decoded by the decoder of their program id
+		let matchable = validate_decoder_spec(
+			"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+			None,
+			&functions(&["TRANSFER()"]),
+			&registry,
+		)
+		.unwrap();
+		assert!(matchable.contains(&"TransferChecked".to_string()));
+		assert_eq!(
+			validate_decoder_spec(
+				"11111111111111111111111111111111",
+				None,
+				&functions(&["anything()"]),
+				&registry
+			),
+			Ok(vec![])
+		);
+
+		// Without conditions on the spec, every instruction of the decoder is matchable
+		let matchable = validate_decoder_spec(
+			kamino,
+			Some(&spec(SolanaDecoderType::Instructions(vec![]))),
+			&functions(&["RefreshReserve()"]),
+			&registry,
+		)
+		.unwrap();
+		assert!(matchable.contains(&"DepositReserveLiquidity".to_string()));
+
+		let error = validate_decoder_spec(
+			kamino,
+			Some(&spec(SolanaDecoderType::InstructionNames(names(&["Swap"])))),
+			&[],
+			&registry,
+		)
+		.unwrap_err();
+		assert!(error.contains("DepositReserveLiquidity"));
+
+		let error = validate_decoder_spec(
+			"11111111111111111111111111111111",
+			Some(&restricted),
+			&[],
+			&registry,
+		)
+		.unwrap_err();
+		assert!(error.contains("IdlFile"));
+
+		assert!(validate_decoder_spec(
+			kamino,
+			Some(&spec(SolanaDecoderType::AccountNames(names(&[
+				"obligation"
+			])))),
+			&[],
+			&registry
+		)
+		.is_ok());
+		let error = validate_decoder_spec(
+			kamino,
+			Some(&spec(SolanaDecoderType::AccountNames(names(&["Vault"])))),
+			&[],
+			&registry,
+		)
+		.unwrap_err();
+		assert!(error.contains("Obligation"));
+
+		// Specs decoding on their own are not checked against the registry
+		assert!(validate_decoder_spec(
+			"11111111111111111111111111111111",
+			Some(&spec(SolanaDecoderType::Layout(ProgramLayout::default()))),
+			&functions(&["anything()"]),
+			&registry
+		)
+		.is_ok());
+	}
+
+	#[test]
+	fn test_validate_monitor_with_unknown_decoder() {
+		let mut monitor = MonitorBuilder::new()
+			.name("TestMonitor")
+			.networks(vec!["solana_mainnet".to_string()])
+			.address("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD")
+			.build();
+		monitor.addresses[0].contract_spec = Some(ContractSpec::Solana(SolanaContractSpec::new(
+			SolanaDecoderType::InstructionNames(vec!["Swap".to_string()]),
+		)));
+		assert!(matches!(
+			monitor.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		monitor.addresses[0].contract_spec = Some(ContractSpec::Solana(SolanaContractSpec::new(
+			SolanaDecoderType::InstructionNames(vec!["RefreshReserve".to_string()]),
+		)));
+		assert!(monitor.validate().is_ok());
+	}
+
 	#[tokio::test]
 	async fn test_load_all_directory_not_found() {
 		let non_existent_path = Path::new("non_existent_directory");
@@ -588,10 +1832,18 @@ mod tests {
 			networks: vec!["ethereum_mainnet".to_string()],
 			paused: false,
 			addresses: vec![],
+			match_all_addresses: false,
+			excluded_addresses: vec![],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			trigger_conditions: vec![TriggerConditions {
 				script_path: script_path.to_str().unwrap().to_string(),
@@ -600,6 +1852,10 @@ mod tests {
 				language: ScriptLanguage::Bash,
 			}],
 			triggers: vec![],
+			constants: HashMap::new(),
+			severity: None,
+			escalation: None,
+			trigger_routes: vec![],
 		};
 
 		monitor.validate_protocol();
@@ -7,7 +7,10 @@ use async_trait::async_trait;
 use std::{collections::HashMap, path::Path, str::FromStr};
 
 use crate::{
-	models::{config::error::ConfigError, BlockChainType, ConfigLoader, Network, SecretValue},
+	models::{
+		config::error::ConfigError, BlockChainType, BlockExplorer, ConfigLoader, Network, RpcAuth,
+		SecretValue,
+	},
 	utils::{get_cron_interval_ms, normalize_string},
 };
 
@@ -50,6 +53,20 @@ impl ConfigLoader for Network {
 				)
 			})?;
 			rpc_url.url = SecretValue::Plain(resolved_url);
+
+			for (name, value) in rpc_url.headers.iter_mut().flatten() {
+				*value = resolve_secret(value, &format!("RPC header '{}'", name)).await?;
+			}
+			match &mut rpc_url.auth {
+				Some(RpcAuth::Bearer { token }) => {
+					*token = resolve_secret(token, "RPC bearer token").await?;
+				}
+				Some(RpcAuth::Basic { username, password }) => {
+					*username = resolve_secret(username, "RPC username").await?;
+					*password = resolve_secret(password, "RPC password").await?;
+				}
+				None => {}
+			}
 		}
 		Ok(network)
 	}
@@ -198,7 +215,7 @@ impl ConfigLoader for Network {
 		}
 
 		// Validate RPC URL types
-		let supported_types = ["rpc"];
+		let supported_types = ["rpc", "ws", "enhanced", "archive", "geyser"];
 		if !self
 			.rpc_urls
 			.iter()
@@ -216,7 +233,9 @@ impl ConfigLoader for Network {
 
 		// Validate RPC URLs format
 		if !self.rpc_urls.iter().all(|rpc_url| {
-			rpc_url.url.starts_with("http://") || rpc_url.url.starts_with("https://")
+			!["rpc", "enhanced", "archive", "geyser"].contains(&rpc_url.type_.as_str())
+				|| rpc_url.url.starts_with("http://")
+				|| rpc_url.url.starts_with("https://")
 		}) {
 			return Err(ConfigError::validation_error(
 				"All RPC URLs must start with http:// or https://",
@@ -225,6 +244,19 @@ impl ConfigLoader for Network {
 			));
 		}
 
+		// Validate WebSocket URLs format
+		if !self.rpc_urls.iter().all(|rpc_url| {
+			rpc_url.type_ != "ws"
+				|| rpc_url.url.starts_with("ws://")
+				|| rpc_url.url.starts_with("wss://")
+		}) {
+			return Err(ConfigError::validation_error(
+				"All WebSocket URLs must start with ws:// or wss://",
+				None,
+				None,
+			));
+		}
+
 		// Validate RPC URL weights
 		if !self.rpc_urls.iter().all(|rpc_url| rpc_url.weight <= 100) {
 			return Err(ConfigError::validation_error(
@@ -234,6 +266,35 @@ impl ConfigLoader for Network {
 			));
 		}
 
+		// Validate RPC URL rate limits
+		if self
+			.rpc_urls
+			.iter()
+			.any(|rpc_url| rpc_url.rate_limit == Some(0))
+		{
+			return Err(ConfigError::validation_error(
+				"RPC URL rate limits must be greater than 0",
+				None,
+				None,
+			));
+		}
+
+		// Validate RPC URL headers
+		for name in self
+			.rpc_urls
+			.iter()
+			.flat_map(|rpc_url| rpc_url.headers.iter().flatten())
+			.map(|(name, _)| name)
+		{
+			if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+				return Err(ConfigError::validation_error(
+					format!("Invalid RPC URL header name: {}", name),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Validate block time
 		if self.block_time_ms < 100 {
 			return Err(ConfigError::validation_error(
@@ -289,6 +350,20 @@ impl ConfigLoader for Network {
 			}
 		}
 
+		// Validate custom explorer URL
+		if let Some(BlockExplorer::Custom { url }) = &self.explorer {
+			if !(url.starts_with("http://") || url.starts_with("https://")) || !url.contains("{}") {
+				return Err(ConfigError::validation_error(
+					format!(
+						"Invalid explorer URL: {}, expected an HTTP(S) URL with a {{}} placeholder",
+						url
+					),
+					None,
+					None,
+				));
+			}
+		}
+
 		// Log a warning if the network uses an insecure protocol
 		self.validate_protocol();
 
@@ -351,6 +426,18 @@ impl ConfigLoader for Network {
 	}
 }
 
+/// Resolves a secret of an RPC URL to its plain value
+async fn resolve_secret(secret: &SecretValue, name: &str) -> Result<SecretValue, ConfigError> {
+	let resolved = secret.resolve().await.map_err(|e| {
+		ConfigError::parse_error(
+			format!("failed to resolve {}: {}", name, e),
+			Some(Box::new(e)),
+			None,
+		)
+	})?;
+	Ok(SecretValue::Plain(resolved))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -436,6 +523,218 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_validate_ws_url_format() {
+		let network = NetworkBuilder::new()
+			.name("Test Network")
+			.slug("test_network")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://test.network")
+			.add_rpc_url("wss://test.network", "ws", 100)
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("0 */5 * * * *")
+			.max_past_blocks(10)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let mut network = network;
+		network.rpc_urls[1].type_ = "rpc".to_string();
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		network.rpc_urls[0].type_ = "ws".to_string();
+		network.rpc_urls[1].type_ = "ws".to_string();
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_enhanced_url_format() {
+		let network = NetworkBuilder::new()
+			.name("Test Network")
+			.slug("test_network")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://test.network")
+			.add_rpc_url("https://api.helius.xyz/v0/transactions", "enhanced", 100)
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("0 */5 * * * *")
+			.max_past_blocks(10)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let mut network = network;
+		network.rpc_urls[1].url = SecretValue::Plain(crate::models::SecretString::new(
+			"wss://api.helius.xyz".to_string(),
+		));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_archive_url_format() {
+		let network = NetworkBuilder::new()
+			.name("Test Network")
+			.slug("test_network")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://test.network")
+			.add_rpc_url("https://archive.test.network", "archive", 100)
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("0 */5 * * * *")
+			.max_past_blocks(10)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let mut network = network;
+		network.rpc_urls[1].url = SecretValue::Plain(crate::models::SecretString::new(
+			"wss://archive.test.network".to_string(),
+		));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_geyser_url_format() {
+		let network = NetworkBuilder::new()
+			.name("Test Network")
+			.slug("test_network")
+			.network_type(BlockChainType::EVM)
+			.chain_id(1)
+			.rpc_url("https://test.network")
+			.add_rpc_url("https://geyser.test.network", "geyser", 100)
+			.block_time_ms(1000)
+			.confirmation_blocks(1)
+			.cron_schedule("0 */5 * * * *")
+			.max_past_blocks(10)
+			.build();
+		assert!(network.validate().is_ok());
+
+		let mut network = network;
+		network.rpc_urls[1].url = SecretValue::Plain(crate::models::SecretString::new(
+			"wss://geyser.test.network".to_string(),
+		));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_invalid_rpc_rate_limit() {
+		let mut network = create_valid_network();
+		network.rpc_urls[0].rate_limit = Some(10);
+		assert!(network.validate().is_ok());
+
+		network.rpc_urls[0].rate_limit = Some(0);
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_custom_explorer_url() {
+		let mut network = create_valid_network();
+		network.explorer = Some(BlockExplorer::Custom {
+			url: "https://explorer.example.com/tx/{}".to_string(),
+		});
+		assert!(network.validate().is_ok());
+
+		network.explorer = Some(BlockExplorer::Custom {
+			url: "https://explorer.example.com/tx/".to_string(),
+		});
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+
+		network.explorer = Some(BlockExplorer::Custom {
+			url: "explorer.example.com/tx/{}".to_string(),
+		});
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[test]
+	fn test_validate_invalid_rpc_header_name() {
+		let mut network = create_valid_network();
+		network.rpc_urls[0].headers = Some(HashMap::from([(
+			"x-api-key".to_string(),
+			SecretValue::Environment("API_KEY".to_string()),
+		)]));
+		assert!(network.validate().is_ok());
+
+		network.rpc_urls[0].headers = Some(HashMap::from([(
+			"x api key".to_string(),
+			SecretValue::Environment("API_KEY".to_string()),
+		)]));
+		assert!(matches!(
+			network.validate(),
+			Err(ConfigError::ValidationError(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_resolve_secrets_of_rpc_headers_and_auth() {
+		std::env::set_var("TEST_RPC_API_KEY", "api-key");
+		std::env::set_var("TEST_RPC_PASSWORD", "password");
+
+		let network: Network = serde_json::from_value(serde_json::json!({
+			"network_type": "Solana",
+			"slug": "test",
+			"name": "Test",
+			"rpc_urls": [{
+				"type_": "rpc",
+				"url": { "type": "plain", "value": "https://test.network" },
+				"weight": 100,
+				"headers": {
+					"x-api-key": { "type": "environment", "value": "TEST_RPC_API_KEY" }
+				},
+				"auth": {
+					"type": "basic",
+					"username": { "type": "plain", "value": "user" },
+					"password": { "type": "environment", "value": "TEST_RPC_PASSWORD" }
+				}
+			}],
+			"block_time_ms": 400,
+			"confirmation_blocks": 1,
+			"cron_schedule": "0 */5 * * * *"
+		}))
+		.unwrap();
+
+		let resolved = network.resolve_secrets().await.unwrap();
+		let rpc_url = &resolved.rpc_urls[0];
+		assert_eq!(
+			rpc_url.headers.as_ref().unwrap()["x-api-key"].as_str(),
+			"api-key"
+		);
+		match &rpc_url.auth {
+			Some(RpcAuth::Basic { username, password }) => {
+				assert_eq!(username.as_str(), "user");
+				assert_eq!(password.as_str(), "password");
+			}
+			auth => panic!("Unexpected auth: {:?}", auth),
+		}
+
+		std::env::remove_var("TEST_RPC_API_KEY");
+		std::env::remove_var("TEST_RPC_PASSWORD");
+	}
+
 	#[test]
 	fn test_validate_invalid_rpc_weight() {
 		let mut network = create_valid_network();
@@ -10,8 +10,13 @@ mod network;
 mod trigger;
 
 pub use monitor::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, ScriptLanguage,
-	TransactionCondition, TransactionStatus, TriggerConditions,
+	AccountCondition, AccountConditionMode, AddressMatchConditions, AddressWithSpec,
+	BalanceChangeCondition, BlockCondition, ConditionLogic, EscalationStep, EventCondition,
+	FunctionCondition, LogCondition, MatchConditions, Monitor, MonitorSeverity, ScriptLanguage,
+	SequenceCondition, TransactionCondition, TransactionStatus, TriggerConditions, TriggerRoute,
+};
+pub use network::{BlockExplorer, Network, RpcAuth, RpcUrl};
+pub use trigger::{
+	NotificationMessage, PagerDutySeverity, SmtpTlsMode, Trigger, TriggerAggregation,
+	TriggerThrottle, TriggerType, TriggerTypeConfig,
 };
-pub use network::{Network, RpcUrl};
-pub use trigger::{NotificationMessage, Trigger, TriggerType, TriggerTypeConfig};
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::models::blockchain::ContractSpec;
@@ -26,6 +28,14 @@ pub struct Monitor {
 	/// Contract addresses to monitor, optionally with their contract specs
 	pub addresses: Vec<AddressWithSpec>,
 
+	/// Whether to match the activity of any address instead of the listed ones (Solana only)
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub match_all_addresses: bool,
+
+	/// Addresses whose transactions are never matched, e.g. known bots (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub excluded_addresses: Vec<String>,
+
 	/// Conditions that should trigger this monitor
 	pub match_conditions: MatchConditions,
 
@@ -34,6 +44,99 @@ pub struct Monitor {
 
 	/// IDs of triggers to execute when conditions match
 	pub triggers: Vec<String>,
+
+	/// Triggers executed only for the matches whose arguments satisfy an expression
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub trigger_routes: Vec<TriggerRoute>,
+
+	/// Named constants referenced from expressions as `$NAME`
+	#[serde(default, skip_serializing_if = "HashMap::is_empty")]
+	pub constants: HashMap<String, serde_json::Value>,
+
+	/// Severity of the matches of this monitor, used to route them to triggers (optional)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub severity: Option<MonitorSeverity>,
+
+	/// Triggers fired in order while the matches of this monitor are not acknowledged
+	/// (optional)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub escalation: Option<Vec<EscalationStep>>,
+}
+
+impl Monitor {
+	/// Address matching any address, as an alternative to `match_all_addresses`
+	pub const WILDCARD_ADDRESS: &'static str = "*";
+
+	/// Returns whether the monitor matches the activity of any address
+	pub fn matches_all_addresses(&self) -> bool {
+		self.match_all_addresses
+			|| self
+				.addresses
+				.iter()
+				.any(|address| address.address.trim() == Self::WILDCARD_ADDRESS)
+	}
+
+	/// Returns the severity of the monitor, `info` if unset
+	pub fn severity(&self) -> MonitorSeverity {
+		self.severity.unwrap_or_default()
+	}
+}
+
+/// Severity of the matches of a monitor
+#[derive(
+	Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum MonitorSeverity {
+	/// Informational activity
+	#[default]
+	Info,
+	/// Activity worth a look
+	Warning,
+	/// Activity requiring action
+	Error,
+	/// Activity requiring immediate action
+	Critical,
+}
+
+impl std::fmt::Display for MonitorSeverity {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MonitorSeverity::Info => write!(f, "info"),
+			MonitorSeverity::Warning => write!(f, "warning"),
+			MonitorSeverity::Error => write!(f, "error"),
+			MonitorSeverity::Critical => write!(f, "critical"),
+		}
+	}
+}
+
+/// Triggers executed for the matches whose arguments satisfy an expression
+///
+/// Routes let a monitor notify its matches through different triggers depending on the
+/// matched arguments, e.g. small transfers on Slack and large ones on PagerDuty.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerRoute {
+	/// Expression over the matched arguments, in the syntax of the match conditions
+	pub expression: String,
+
+	/// IDs of the triggers to execute when the expression holds
+	pub triggers: Vec<String>,
+}
+
+/// Step of the escalation of a match
+///
+/// The trigger of a step fires once its delay elapsed since the match, unless the match was
+/// acknowledged through its callback URL in the meantime.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct EscalationStep {
+	/// Slug of the trigger to fire
+	pub trigger: String,
+
+	/// Seconds after the match at which the trigger fires, `0` firing it immediately
+	#[serde(default)]
+	pub after_secs: u64,
 }
 
 /// Contract address with optional ABI for decoding transactions and events
@@ -45,6 +148,26 @@ pub struct AddressWithSpec {
 
 	/// Optional contract spec for decoding contract interactions
 	pub contract_spec: Option<ContractSpec>,
+
+	/// Conditions that only apply to this address (Solana only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub match_conditions: Option<AddressMatchConditions>,
+}
+
+/// Collection of conditions scoped to a monitored address
+///
+/// They are evaluated in addition to the conditions of the monitor, but only against the
+/// function calls to and the events emitted by the address.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AddressMatchConditions {
+	/// Function calls to match
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub functions: Vec<FunctionCondition>,
+
+	/// Events to match
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub events: Vec<EventCondition>,
 }
 
 /// Collection of conditions that can trigger a monitor
@@ -59,6 +182,49 @@ pub struct MatchConditions {
 
 	/// Transaction states to match
 	pub transactions: Vec<TransactionCondition>,
+
+	/// Account states to match (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub accounts: Vec<AccountCondition>,
+
+	/// Token balance changes to match (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub balance_changes: Vec<BalanceChangeCondition>,
+
+	/// Log messages to match (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub logs: Vec<LogCondition>,
+
+	/// Ordered sequences of instructions to match (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub sequences: Vec<SequenceCondition>,
+
+	/// Conditions on the matches of a whole block, replacing them with a single
+	/// aggregated match (Solana only)
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub blocks: Vec<BlockCondition>,
+
+	/// How the defined condition groups combine (Solana only)
+	#[serde(default, skip_serializing_if = "ConditionLogic::is_all")]
+	pub condition_logic: ConditionLogic,
+}
+
+/// How the condition groups of a monitor combine into a match
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionLogic {
+	/// Every defined condition group must match
+	#[default]
+	All,
+	/// At least one defined condition group must match
+	Any,
+}
+
+impl ConditionLogic {
+	/// Returns whether every defined condition group must match
+	pub fn is_all(&self) -> bool {
+		*self == Self::All
+	}
 }
 
 /// Condition for matching contract function calls
@@ -94,6 +260,65 @@ pub struct TransactionCondition {
 	pub expression: Option<String>,
 }
 
+/// Condition for matching the decoded state of an account
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AccountCondition {
+	/// Address of the account to watch
+	pub address: String,
+
+	/// Optional expression to filter decoded account fields
+	pub expression: Option<String>,
+
+	/// Which changes of the account trigger a match
+	#[serde(default)]
+	pub mode: AccountConditionMode,
+}
+
+/// Mode in which the state of a watched account is matched
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountConditionMode {
+	/// Matches every observed state satisfying the expression
+	#[default]
+	State,
+	/// Matches only when the token owner or delegate of the account changes, or when its
+	/// close authority is set, compared to the previously observed state
+	OwnershipChange,
+}
+
+/// Condition for matching the token balance changes of a transaction
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BalanceChangeCondition {
+	/// Optional expression to filter balance change properties
+	pub expression: Option<String>,
+}
+
+/// Condition for matching the log messages of a transaction
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LogCondition {
+	/// Optional expression to filter log message properties
+	pub expression: Option<String>,
+}
+
+/// Condition for matching instructions appearing in a given order within a transaction
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SequenceCondition {
+	/// Instructions to match, in the order they must appear
+	pub steps: Vec<FunctionCondition>,
+}
+
+/// Condition on the aggregated matches of a monitor within a block
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct BlockCondition {
+	/// Optional expression to filter the aggregated block properties
+	pub expression: Option<String>,
+}
+
 /// Possible transaction execution states
 #[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::models::{BlockChainType, SecretValue};
 
@@ -41,6 +42,16 @@ pub struct Network {
 
 	/// Whether to store processed blocks
 	pub store_blocks: Option<bool>,
+
+	/// Whether to fetch the Anchor IDLs published on-chain by monitored programs without
+	/// a contract spec (Solana only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub fetch_onchain_idls: Option<bool>,
+
+	/// Block explorer linked from the notifications of the matches of this network (optional,
+	/// a default explorer is used for known networks)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub explorer: Option<BlockExplorer>,
 }
 
 /// RPC endpoint configuration with load balancing weight
@@ -55,4 +66,90 @@ pub struct RpcUrl {
 
 	/// Weight for load balancing (0-100)
 	pub weight: u32,
+
+	/// Maximum number of requests per second sent to the endpoint (Solana only)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub rate_limit: Option<u32>,
+
+	/// Additional HTTP headers sent with each request, e.g. `x-api-key` (values can be
+	/// secret values)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub headers: Option<HashMap<String, SecretValue>>,
+
+	/// Authentication sent with each request
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub auth: Option<RpcAuth>,
+}
+
+/// Authentication of the requests sent to an RPC endpoint
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub enum RpcAuth {
+	/// Bearer token, sent in the `Authorization` header
+	Bearer {
+		/// The token (can be a secret value)
+		token: SecretValue,
+	},
+	/// HTTP basic authentication
+	Basic {
+		/// The username (can be a secret value)
+		username: SecretValue,
+		/// The password (can be a secret value)
+		password: SecretValue,
+	},
+}
+
+/// Block explorer showing the transactions of a network
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub enum BlockExplorer {
+	/// Solscan (Solana)
+	Solscan,
+	/// Solana Explorer (Solana)
+	Explorer,
+	/// Helius XRAY (Solana)
+	Xray,
+	/// Any explorer, from a transaction URL template
+	Custom {
+		/// Transaction URL, with `{}` replaced by the transaction hash or signature
+		url: String,
+	},
+}
+
+impl BlockExplorer {
+	/// Returns the URL of a transaction on the explorer
+	///
+	/// The Solana explorers link to the cluster named in the network slug, e.g. `devnet` for
+	/// `solana_devnet`, and to mainnet otherwise.
+	///
+	/// # Arguments
+	/// * `network_slug` - Slug of the network of the transaction
+	/// * `transaction` - Hash or signature of the transaction
+	///
+	/// # Returns
+	/// * `String` - URL of the transaction
+	pub fn transaction_url(&self, network_slug: &str, transaction: &str) -> String {
+		let cluster = ["devnet", "testnet"]
+			.into_iter()
+			.find(|cluster| network_slug.contains(cluster));
+		let with_cluster = |url: String, param: &str| match cluster {
+			Some(cluster) => format!("{}?{}={}", url, param, cluster),
+			None => url,
+		};
+
+		match self {
+			BlockExplorer::Solscan => {
+				with_cluster(format!("https://solscan.io/tx/{}", transaction), "cluster")
+			}
+			BlockExplorer::Explorer => with_cluster(
+				format!("https://explorer.solana.com/tx/{}", transaction),
+				"cluster",
+			),
+			BlockExplorer::Xray => with_cluster(
+				format!("https://xray.helius.xyz/tx/{}", transaction),
+				"network",
+			),
+			BlockExplorer::Custom { url } => url.replace("{}", transaction),
+		}
+	}
 }
@@ -1,4 +1,7 @@
-use crate::models::{core::ScriptLanguage, SecretValue};
+use crate::models::{
+	core::{MonitorSeverity, ScriptLanguage},
+	SecretValue,
+};
 use email_address::EmailAddress;
 use serde::{Deserialize, Serialize};
 
@@ -9,11 +12,33 @@ pub struct Trigger {
 	/// Unique name identifying this trigger
 	pub name: String,
 
-	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, Script)
+	/// Type of trigger (Email, Slack, Webhook, Telegram, Discord, GoogleChat, Teams, PagerDuty,
+	/// Nats, Matrix, Twilio, Script)
 	pub trigger_type: TriggerType,
 
 	/// Configuration specific to the trigger type
 	pub config: TriggerTypeConfig,
+
+	/// Throttling of the notifications sent by the trigger for each monitor (optional)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub throttle: Option<TriggerThrottle>,
+
+	/// Aggregation of the matches of each monitor into a single notification (optional)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub aggregation: Option<TriggerAggregation>,
+
+	/// Severities of the monitors whose matches the trigger notifies (optional, all if unset)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub severities: Option<Vec<MonitorSeverity>>,
+}
+
+impl Trigger {
+	/// Returns whether the trigger notifies the matches of monitors with a severity
+	pub fn accepts_severity(&self, severity: MonitorSeverity) -> bool {
+		self.severities
+			.as_ref()
+			.is_none_or(|severities| severities.contains(&severity))
+	}
 }
 
 /// Supported trigger action types
@@ -31,6 +56,18 @@ pub enum TriggerType {
 	Telegram,
 	/// Send notification to Discord
 	Discord,
+	/// Send card to Google Chat
+	GoogleChat,
+	/// Send Adaptive Card to Microsoft Teams
+	Teams,
+	/// Page through PagerDuty
+	PagerDuty,
+	/// Publish to a NATS subject
+	Nats,
+	/// Send message to a Matrix room
+	Matrix,
+	/// Send SMS through Twilio
+	Twilio,
 	/// Execute local script
 	Script,
 }
@@ -45,6 +82,85 @@ pub struct NotificationMessage {
 	pub body: String,
 }
 
+/// Throttling policy of the notifications sent by a trigger
+///
+/// Notifications are counted for each monitor over windows starting with the first
+/// notification, and the notifications beyond the limit of a window are suppressed.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerThrottle {
+	/// Maximum number of notifications sent for a monitor over a window
+	pub max_notifications: u32,
+	/// Duration of a window in seconds
+	pub window_secs: u64,
+	/// Send a summary of the suppressed notifications once the window ends (default true)
+	#[serde(default)]
+	pub summarize: Option<bool>,
+}
+
+/// Aggregation policy of the matches notified by a trigger
+///
+/// The first match of a monitor opens a window, and the matches of the monitor within the
+/// window are sent together in a single notification once the window ends.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TriggerAggregation {
+	/// Duration of a window in seconds
+	pub window_secs: u64,
+}
+
+/// Severity of the PagerDuty alerts raised by a trigger
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PagerDutySeverity {
+	/// Pages on-call responders immediately
+	#[default]
+	Critical,
+	/// Error
+	Error,
+	/// Warning
+	Warning,
+	/// Informational
+	Info,
+}
+
+impl From<MonitorSeverity> for PagerDutySeverity {
+	fn from(severity: MonitorSeverity) -> Self {
+		match severity {
+			MonitorSeverity::Info => PagerDutySeverity::Info,
+			MonitorSeverity::Warning => PagerDutySeverity::Warning,
+			MonitorSeverity::Error => PagerDutySeverity::Error,
+			MonitorSeverity::Critical => PagerDutySeverity::Critical,
+		}
+	}
+}
+
+/// Security of the connection to an SMTP server
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SmtpTlsMode {
+	/// Connection encrypted from the start (SMTPS)
+	#[serde(rename = "tls")]
+	Tls,
+	/// Plain connection upgraded with STARTTLS
+	#[serde(rename = "starttls")]
+	StartTls,
+	/// Unencrypted connection
+	#[serde(rename = "none")]
+	Disabled,
+}
+
+impl SmtpTlsMode {
+	/// Returns the mode conventionally used on a port: STARTTLS on the submission port 587,
+	/// TLS otherwise
+	pub fn for_port(port: u16) -> Self {
+		if port == 587 {
+			Self::StartTls
+		} else {
+			Self::Tls
+		}
+	}
+}
+
 /// Type-specific configuration for triggers
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
@@ -63,6 +179,8 @@ pub enum TriggerTypeConfig {
 		host: String,
 		/// SMTP port (default 465)
 		port: Option<u16>,
+		/// Security of the connection (default STARTTLS on port 587, TLS otherwise)
+		tls: Option<SmtpTlsMode>,
 		/// SMTP username
 		username: SecretValue,
 		/// SMTP password
@@ -73,6 +191,8 @@ pub enum TriggerTypeConfig {
 		sender: EmailAddress,
 		/// Email recipients
 		recipients: Vec<EmailAddress>,
+		/// Minutes over which matches are batched into a single email, if they should be
+		digest_interval_mins: Option<u64>,
 	},
 	/// Webhook configuration
 	Webhook {
@@ -84,6 +204,13 @@ pub enum TriggerTypeConfig {
 		secret: Option<SecretValue>,
 		/// Optional HTTP headers
 		headers: Option<std::collections::HashMap<String, String>>,
+		/// Whether the full monitor match is sent along with the message
+		include_match: Option<bool>,
+		/// Schema version of the monitor match sent along with the message, the latest when
+		/// omitted
+		match_schema_version: Option<u32>,
+		/// Maximum number of retries of requests failing with transient errors
+		max_retries: Option<u32>,
 		/// Notification message
 		message: NotificationMessage,
 	},
@@ -92,7 +219,7 @@ pub enum TriggerTypeConfig {
 		/// Telegram bot token
 		token: SecretValue,
 		/// Telegram chat ID
-		chat_id: String,
+		chat_id: SecretValue,
 		/// Disable web preview
 		disable_web_preview: Option<bool>,
 		/// Notification message
@@ -102,6 +229,74 @@ pub enum TriggerTypeConfig {
 	Discord {
 		/// Discord webhook URL
 		discord_url: SecretValue,
+		/// Send matches as embeds with a field per matched instruction
+		embed: Option<bool>,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// Google Chat notification configuration
+	GoogleChat {
+		/// Google Chat webhook URL
+		google_chat_url: SecretValue,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// Microsoft Teams notification configuration
+	Teams {
+		/// Teams webhook URL
+		teams_url: SecretValue,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// PagerDuty Events API v2 configuration
+	PagerDuty {
+		/// Integration key of the PagerDuty service
+		routing_key: SecretValue,
+		/// Severity of the alerts (default: severity of the monitor, or critical if unset)
+		severity: Option<PagerDutySeverity>,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// NATS configuration
+	Nats {
+		/// NATS server URL, `nats://[user:password@]host[:port]`
+		server_url: SecretValue,
+		/// Subject the matches are published to
+		subject: String,
+		/// Authentication token
+		token: Option<SecretValue>,
+		/// Publish through JetStream and wait for the stream to acknowledge each match
+		jetstream: Option<bool>,
+		/// Milliseconds to wait for the server and for acknowledgements (default 5000)
+		ack_timeout_ms: Option<u64>,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// Matrix client-server API configuration
+	Matrix {
+		/// URL of the homeserver of the account sending the messages
+		homeserver_url: String,
+		/// ID of the room to send messages to, `!opaque_id:server`
+		room_id: String,
+		/// Access token of the account sending the messages
+		access_token: SecretValue,
+		/// Send messages along with their HTML rendering (default true)
+		html: Option<bool>,
+		/// Notification message
+		message: NotificationMessage,
+	},
+	/// Twilio SMS configuration
+	Twilio {
+		/// SID of the Twilio account, `AC...`
+		account_sid: String,
+		/// Auth token of the Twilio account
+		auth_token: SecretValue,
+		/// Phone number the SMS are sent from, in E.164 format
+		from: String,
+		/// Phone numbers the SMS are sent to, in E.164 format
+		to: Vec<String>,
+		/// Maximum number of matches sent per hour, beyond which they are dropped (default 5)
+		max_messages_per_hour: Option<u32>,
 		/// Notification message
 		message: NotificationMessage,
 	},
@@ -118,3 +313,23 @@ pub enum TriggerTypeConfig {
 		timeout_ms: u32,
 	},
 }
+
+impl TriggerTypeConfig {
+	/// Returns the notification message of the trigger, if its type sends messages
+	pub fn message_mut(&mut self) -> Option<&mut NotificationMessage> {
+		match self {
+			TriggerTypeConfig::Slack { message, .. }
+			| TriggerTypeConfig::Email { message, .. }
+			| TriggerTypeConfig::Webhook { message, .. }
+			| TriggerTypeConfig::Telegram { message, .. }
+			| TriggerTypeConfig::Discord { message, .. }
+			| TriggerTypeConfig::GoogleChat { message, .. }
+			| TriggerTypeConfig::Teams { message, .. }
+			| TriggerTypeConfig::PagerDuty { message, .. }
+			| TriggerTypeConfig::Nats { message, .. }
+			| TriggerTypeConfig::Matrix { message, .. }
+			| TriggerTypeConfig::Twilio { message, .. } => Some(message),
+			TriggerTypeConfig::Script { .. } => None,
+		}
+	}
+}
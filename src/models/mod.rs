@@ -32,16 +32,22 @@ pub use blockchain::stellar::{
 };
 
 pub use blockchain::solana::{
-	SolanaBlock, SolanaContractSpec, SolanaDecodedInstruction, SolanaInstructionDecoder,
-	SolanaInstructionMetadata, SolanaMonitorMatch, SolanaReward, SolanaTransaction,
-	SolanaTransactionMetadata, SolanaTransactionStatusMeta,
+	SolanaBlock, SolanaContractSpec, SolanaDecodedInstruction, SolanaDecoderType,
+	SolanaEnhancedTransaction, SolanaInstructionDecoder, SolanaInstructionMetadata,
+	SolanaMatchArguments, SolanaMatchParamEntry, SolanaMatchParamsMap, SolanaMatchedInstruction,
+	SolanaMonitorMatch, SolanaNestedInstruction, SolanaProgramLog, SolanaReward, SolanaTransaction,
+	SolanaTransactionError, SolanaTransactionMetadata, SolanaTransactionStatusMeta,
+	SolanaTransactionTokenBalance,
 };
 
 // Re-export core types
 pub use core::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor, Network,
-	NotificationMessage, RpcUrl, ScriptLanguage, TransactionCondition, TransactionStatus, Trigger,
-	TriggerConditions, TriggerType, TriggerTypeConfig,
+	AccountCondition, AccountConditionMode, AddressMatchConditions, AddressWithSpec,
+	BalanceChangeCondition, BlockCondition, BlockExplorer, ConditionLogic, EscalationStep,
+	EventCondition, FunctionCondition, LogCondition, MatchConditions, Monitor, MonitorSeverity,
+	Network, NotificationMessage, PagerDutySeverity, RpcAuth, RpcUrl, ScriptLanguage,
+	SequenceCondition, SmtpTlsMode, TransactionCondition, TransactionStatus, Trigger,
+	TriggerAggregation, TriggerConditions, TriggerRoute, TriggerThrottle, TriggerType, TriggerTypeConfig,
 };
 
 // Re-export config types
@@ -11,7 +11,7 @@ use std::{collections::HashMap, marker::PhantomData, path::Path};
 use async_trait::async_trait;
 
 use crate::{
-	models::{ConfigLoader, Monitor, Network, ScriptLanguage, Trigger},
+	models::{ConfigLoader, Monitor, Network, ScriptLanguage, Trigger, TriggerType},
 	repositories::{
 		error::RepositoryError,
 		network::{NetworkRepository, NetworkRepositoryTrait, NetworkService},
@@ -93,6 +93,46 @@ impl<
 				}
 			}
 
+			// Validate routed trigger references
+			for route in &monitor.trigger_routes {
+				for trigger_id in &route.triggers {
+					if !triggers.contains_key(trigger_id) {
+						validation_errors.push(format!(
+							"Monitor '{}' routes to non-existent trigger '{}'",
+							monitor_name, trigger_id
+						));
+						metadata.insert(
+							format!("monitor_{}_invalid_routed_trigger", monitor_name),
+							trigger_id.clone(),
+						);
+					}
+				}
+			}
+
+			// Validate escalation trigger references, which cannot be scripts as their scripts
+			// are only loaded for the triggers of the monitor
+			for step in monitor.escalation.iter().flatten() {
+				match triggers.get(&step.trigger) {
+					None => {
+						validation_errors.push(format!(
+							"Monitor '{}' escalates to non-existent trigger '{}'",
+							monitor_name, step.trigger
+						));
+						metadata.insert(
+							format!("monitor_{}_invalid_escalation_trigger", monitor_name),
+							step.trigger.clone(),
+						);
+					}
+					Some(trigger) if trigger.trigger_type == TriggerType::Script => {
+						validation_errors.push(format!(
+							"Monitor '{}' escalates to script trigger '{}', which is not supported",
+							monitor_name, step.trigger
+						));
+					}
+					Some(_) => {}
+				}
+			}
+
 			// Validate network references
 			for network_slug in &monitor.networks {
 				if !networks.contains_key(network_slug) {
@@ -434,7 +474,10 @@ impl<
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::{models::ScriptLanguage, utils::tests::builders::evm::monitor::MonitorBuilder};
+	use crate::{
+		models::ScriptLanguage,
+		utils::tests::builders::{evm::monitor::MonitorBuilder, trigger::TriggerBuilder},
+	};
 	use std::fs;
 	use tempfile::TempDir;
 
@@ -531,6 +574,86 @@ mod tests {
 		assert!(err.to_string().contains("timeout_ms greater than 0"));
 	}
 
+	#[test]
+	fn test_validate_escalation_triggers() {
+		let mut triggers = HashMap::new();
+		triggers.insert(
+			"slack".to_string(),
+			TriggerBuilder::new()
+				.name("slack")
+				.slack("https://hooks.slack.com/services/xxx")
+				.build(),
+		);
+		triggers.insert(
+			"script".to_string(),
+			TriggerBuilder::new()
+				.name("script")
+				.script("script.py", ScriptLanguage::Python)
+				.build(),
+		);
+		let networks = HashMap::new();
+		let validate = |escalation: Vec<(&str, u64)>| {
+			let mut monitors = HashMap::new();
+			monitors.insert(
+				"test_monitor".to_string(),
+				MonitorBuilder::new()
+					.name("test_monitor")
+					.networks(vec![])
+					.escalation(escalation)
+					.build(),
+			);
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+		};
+
+		assert!(validate(vec![("slack", 0)]).is_ok());
+
+		let err = validate(vec![("slack", 0), ("pagerduty", 600)]).unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("Monitor 'test_monitor' escalates to non-existent trigger 'pagerduty'"));
+
+		let err = validate(vec![("slack", 0), ("script", 600)]).unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("escalates to script trigger 'script', which is not supported"));
+	}
+
+	#[test]
+	fn test_validate_routed_triggers() {
+		let mut triggers = HashMap::new();
+		triggers.insert(
+			"slack".to_string(),
+			TriggerBuilder::new()
+				.name("slack")
+				.slack("https://hooks.slack.com/services/xxx")
+				.build(),
+		);
+		let networks = HashMap::new();
+		let validate = |routed_triggers: Vec<&str>| {
+			let mut monitors = HashMap::new();
+			monitors.insert(
+				"test_monitor".to_string(),
+				MonitorBuilder::new()
+					.name("test_monitor")
+					.networks(vec![])
+					.trigger_route("value > 1000000", routed_triggers)
+					.build(),
+			);
+			MonitorRepository::<NetworkRepository, TriggerRepository>::validate_monitor_references(
+				&monitors, &triggers, &networks,
+			)
+		};
+
+		assert!(validate(vec!["slack"]).is_ok());
+
+		let err = validate(vec!["slack", "pagerduty"]).unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("Monitor 'test_monitor' routes to non-existent trigger 'pagerduty'"));
+	}
+
 	#[tokio::test]
 	async fn test_load_error_messages() {
 		// Test with invalid path to trigger load error
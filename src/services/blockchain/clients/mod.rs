@@ -3,6 +3,7 @@
 //! Contains specific implementations for different blockchain types:
 //! - EVM client for Ethereum-compatible chains
 //! - Stellar client for Stellar network
+//! - Solana client for Solana network
 
 mod evm {
 	pub mod client;
@@ -11,7 +12,17 @@ mod stellar {
 	pub mod client;
 	pub mod error;
 }
+mod solana {
+	pub mod client;
+	pub mod enhanced;
+	pub mod stream;
+}
 
 pub use evm::client::{EvmClient, EvmClientTrait};
+pub use solana::{
+	client::{SolanaClient, SolanaClientTrait},
+	enhanced::SolanaEnhancedClient,
+	stream::{SolanaBlockSource, SolanaBlockStream},
+};
 pub use stellar::client::{StellarClient, StellarClientTrait};
 pub use stellar::error::StellarClientError;
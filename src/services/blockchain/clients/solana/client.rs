@@ -0,0 +1,746 @@
+//! Solana blockchain client implementation.
+//!
+//! This module provides functionality to interact with the Solana blockchain,
+//! supporting operations like fetching the state of monitored accounts, fetching
+//! transactions by signature and fetching the history of an address. Transactions can
+//! also be fetched already parsed from an enhanced transactions endpoint, when the network
+//! configures one, and the Anchor IDLs published on-chain by programs can be fetched.
+//!
+//! Blocks are fetched with `getBlocks` and `getBlock`, unless the network configures
+//! `geyser` or `ws` endpoints, in which case they are received through a Yellowstone gRPC
+//! stream or `blockSubscribe` as they are confirmed and only the slots not received are
+//! fetched.
+
+use anyhow::Context;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use solana_account::Account;
+use solana_account_decoder_client_types::UiAccount;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::{
+	collections::HashMap,
+	str::FromStr,
+	sync::{Arc, OnceLock, RwLock},
+	time::Instant,
+};
+use tracing::instrument;
+
+use crate::{
+	models::{
+		BlockType, Network, SolanaEnhancedTransaction, SolanaTransaction, SolanaTransactionError,
+	},
+	services::{
+		blockchain::{
+			client::{BlockChainClient, BlockFilterFactory},
+			clients::{
+				solana::stream::solana_block, SolanaBlockSource, SolanaBlockStream,
+				SolanaEnhancedClient,
+			},
+			transports::{
+				block_requests, parse_block, BlockFetchError, BlockchainTransport,
+				SolanaTransportClient,
+			},
+		},
+		decoders::{
+			address_lookup_table::{
+				parse_lookup_table_addresses, PROGRAM_ID as ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+			},
+			idl::idl_account_address,
+			AnchorIdl,
+		},
+		filter::SolanaBlockFilter,
+	},
+	utils::metrics::{RPC_REQUEST_DURATION_SECONDS, SOLANA_SLOT_LAG},
+};
+
+/// Solana RPC method constants
+const RPC_METHOD_GET_MULTIPLE_ACCOUNTS: &str = "getMultipleAccounts";
+const RPC_METHOD_GET_TRANSACTION: &str = "getTransaction";
+const RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS: &str = "getSignaturesForAddress";
+const RPC_METHOD_GET_SLOT: &str = "getSlot";
+const RPC_METHOD_GET_BLOCKS: &str = "getBlocks";
+
+/// Maximum number of accounts accepted by a single `getMultipleAccounts` request
+const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+
+/// Maximum number of signatures returned by a single `getSignaturesForAddress` request
+const MAX_SIGNATURES_PER_REQUEST: usize = 1000;
+
+/// Maximum number of blocks requested by a single batch of `getBlock` requests
+const MAX_BLOCKS_PER_REQUEST: usize = 50;
+
+/// Client implementation for the Solana blockchain
+///
+/// Provides high-level access to Solana blockchain data and operations through HTTP transport.
+#[derive(Clone)]
+pub struct SolanaClient<T: Send + Sync + Clone> {
+	/// The underlying Solana transport client for RPC communication
+	http_client: T,
+	/// Client of the enhanced transactions endpoint, if the network configures one
+	enhanced_client: Option<SolanaEnhancedClient>,
+	/// Slug of the network, used to label the metrics of the client
+	network_slug: String,
+	/// On-chain IDLs already fetched, by program, `None` for programs that publish none
+	idl_cache: Arc<RwLock<HashMap<Pubkey, Option<AnchorIdl>>>>,
+	/// Source pushing the blocks of the network, if it configures one
+	block_source: Option<SolanaBlockSource>,
+	/// Blocks pushed by the block source, followed from the first block request
+	block_stream: Arc<OnceLock<SolanaBlockStream>>,
+}
+
+impl<T: Send + Sync + Clone> SolanaClient<T> {
+	/// Creates a new Solana client instance with a specific transport client
+	pub fn new_with_transport(http_client: T) -> Self {
+		Self {
+			http_client,
+			enhanced_client: None,
+			network_slug: String::new(),
+			idl_cache: Arc::new(RwLock::new(HashMap::new())),
+			block_source: None,
+			block_stream: Arc::new(OnceLock::new()),
+		}
+	}
+
+	/// Labels the metrics recorded by the client with the slug of a network
+	pub fn with_network_slug(mut self, network_slug: &str) -> Self {
+		self.network_slug = network_slug.to_string();
+		self
+	}
+
+	/// Fetches parsed transactions from the given enhanced transactions endpoint
+	pub fn with_enhanced_client(mut self, enhanced_client: SolanaEnhancedClient) -> Self {
+		self.enhanced_client = Some(enhanced_client);
+		self
+	}
+
+	/// Receives the blocks of the network from a block source instead of fetching them
+	pub fn with_block_source(mut self, block_source: SolanaBlockSource) -> Self {
+		self.block_source = Some(block_source);
+		self
+	}
+
+	/// Returns the source pushing the blocks of the network, if any
+	pub fn block_source(&self) -> Option<&SolanaBlockSource> {
+		self.block_source.as_ref()
+	}
+
+	/// Returns the blocks pushed by the block source, subscribing to it on first use
+	fn block_stream(&self) -> Option<&SolanaBlockStream> {
+		let block_source = self.block_source.as_ref()?;
+		Some(
+			self.block_stream
+				.get_or_init(|| SolanaBlockStream::new(block_source.subscribe_blocks())),
+		)
+	}
+}
+
+impl SolanaClient<SolanaTransportClient> {
+	/// Creates a new Solana client instance
+	///
+	/// Blocks are received from the `ws` endpoints of the network, if it configures any.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC endpoints and chain details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
+	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let http_client = SolanaTransportClient::new(network).await?;
+		let block_source = SolanaBlockSource::from_network(network, &http_client)?;
+		let mut client = Self::new_with_transport(http_client).with_network_slug(&network.slug);
+		if let Some(block_source) = block_source {
+			client = client.with_block_source(block_source);
+		}
+		match SolanaEnhancedClient::from_network(network).await? {
+			Some(enhanced_client) => Ok(client.with_enhanced_client(enhanced_client)),
+			None => Ok(client),
+		}
+	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> SolanaClient<T> {
+	/// Sends a request to the transport and records its latency
+	///
+	/// The duration is observed in the `rpc_request_duration_seconds` histogram, labeled
+	/// with the network slug and the RPC method, whether or not the request succeeds.
+	async fn send_request(
+		&self,
+		method: &str,
+		params: Option<Value>,
+	) -> Result<Value, anyhow::Error> {
+		let start = Instant::now();
+		let response = self.http_client.send_raw_request(method, params).await;
+		RPC_REQUEST_DURATION_SECONDS
+			.with_label_values(&[self.network_slug.as_str(), method])
+			.observe(start.elapsed().as_secs_f64());
+		Ok(response?)
+	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> SolanaClient<T> {
+	/// Retrieves the addresses stored in lookup tables
+	///
+	/// Tables that do not exist or are not initialized lookup tables are omitted.
+	///
+	/// # Arguments
+	/// * `tables` - Addresses of the lookup tables
+	///
+	/// # Returns
+	/// * `Result<HashMap<Pubkey, Vec<Pubkey>>, anyhow::Error>` - The addresses of each table
+	async fn get_lookup_tables(
+		&self,
+		tables: &[Pubkey],
+	) -> Result<HashMap<Pubkey, Vec<Pubkey>>, anyhow::Error> {
+		let accounts = self
+			.get_multiple_accounts(tables)
+			.await
+			.with_context(|| "Failed to get lookup tables")?;
+
+		Ok(tables
+			.iter()
+			.zip(accounts)
+			.filter_map(|(table, account)| {
+				let account = account?;
+				if account.owner != ADDRESS_LOOKUP_TABLE_PROGRAM_ID {
+					return None;
+				}
+				Some((*table, parse_lookup_table_addresses(&account.data)?))
+			})
+			.collect())
+	}
+}
+
+#[async_trait]
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockChainClient for SolanaClient<T> {
+	/// Retrieves the latest slot, as received from the block source of the network if any
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	async fn get_latest_block_number(&self) -> Result<u64, anyhow::Error> {
+		if let Some(slot) = self.block_stream().and_then(SolanaBlockStream::latest_slot) {
+			return Ok(slot);
+		}
+		self.get_latest_slot().await
+	}
+
+	/// Retrieves the blocks of a range of slots, skipped slots having none
+	///
+	/// Blocks received from the block source of the network are returned as they are, and
+	/// the others are fetched with `getBlocks` and batches of `getBlock` requests. Blocks the
+	/// node no longer has in long-term storage are skipped.
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if an RPC request fails
+	/// - Returns `anyhow::Error` if a response cannot be parsed
+	#[instrument(skip(self))]
+	async fn get_blocks(
+		&self,
+		start_block: u64,
+		end_block: Option<u64>,
+	) -> Result<Vec<BlockType>, anyhow::Error> {
+		let end_block = end_block.unwrap_or(start_block);
+		if let Some(blocks) = self
+			.block_stream()
+			.and_then(|block_stream| block_stream.blocks(start_block, end_block))
+		{
+			return Ok(blocks
+				.into_iter()
+				.map(|block| BlockType::Solana(Box::new(block)))
+				.collect());
+		}
+
+		let response = self
+			.send_request(
+				RPC_METHOD_GET_BLOCKS,
+				Some(json!([start_block, end_block, { "commitment": "confirmed" }])),
+			)
+			.await
+			.with_context(|| format!("Failed to get slots {}-{}", start_block, end_block))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Solana RPC request failed for method '{}': {}",
+				RPC_METHOD_GET_BLOCKS,
+				error
+			));
+		}
+
+		let slots: Vec<u64> = serde_json::from_value(
+			response
+				.get("result")
+				.cloned()
+				.with_context(|| "Missing 'result' field")?,
+		)
+		.with_context(|| "Failed to parse slots")?;
+
+		let mut blocks = Vec::with_capacity(slots.len());
+		for chunk in slots.chunks(MAX_BLOCKS_PER_REQUEST) {
+			let responses = self
+				.http_client
+				.send_batch_request(&block_requests(chunk))
+				.await
+				.with_context(|| format!("Failed to get {} block(s)", chunk.len()))?;
+			for (&slot, response) in chunk.iter().zip(responses) {
+				match parse_block(slot, response) {
+					Ok(block) => blocks.push(BlockType::Solana(Box::new(solana_block(block)))),
+					Err(BlockFetchError::SlotSkipped(slot)) => {
+						tracing::debug!("Solana slot {} was skipped", slot)
+					}
+					Err(BlockFetchError::NotInStorage(slot)) => tracing::warn!(
+						"Solana block {} is not available in long-term storage",
+						slot
+					),
+					Err(e) => return Err(e.into()),
+				}
+			}
+		}
+
+		Ok(blocks)
+	}
+}
+
+impl<T: Send + Sync + Clone + BlockchainTransport> BlockFilterFactory<Self> for SolanaClient<T> {
+	type Filter = SolanaBlockFilter<Self>;
+	fn filter() -> Self::Filter {
+		SolanaBlockFilter::new()
+	}
+}
+
+/// Extended functionality specific to the Solana blockchain
+#[async_trait]
+pub trait SolanaClientTrait {
+	/// Retrieves the current state of multiple accounts
+	///
+	/// # Arguments
+	/// * `addresses` - Addresses of the accounts to fetch
+	///
+	/// # Returns
+	/// * `Result<Vec<Option<Account>>, anyhow::Error>` - The accounts in the order of
+	///   `addresses`, with `None` for accounts that do not exist
+	async fn get_multiple_accounts(
+		&self,
+		addresses: &[Pubkey],
+	) -> Result<Vec<Option<Account>>, anyhow::Error>;
+
+	/// Retrieves a confirmed transaction by signature
+	///
+	/// # Arguments
+	/// * `signature` - Signature of the transaction to fetch
+	///
+	/// # Returns
+	/// * `Result<Option<SolanaTransaction>, anyhow::Error>` - The transaction, or `None` if it
+	///   is not found
+	async fn get_transaction(
+		&self,
+		signature: &Signature,
+	) -> Result<Option<SolanaTransaction>, anyhow::Error>;
+
+	/// Retrieves the signatures of the confirmed transactions involving an address
+	///
+	/// # Arguments
+	/// * `address` - Address the transactions involve
+	/// * `start_slot` - First slot to include
+	/// * `end_slot` - Last slot to include
+	///
+	/// # Returns
+	/// * `Result<Vec<Signature>, anyhow::Error>` - The signatures, oldest first
+	async fn get_signatures_for_address(
+		&self,
+		address: &Pubkey,
+		start_slot: u64,
+		end_slot: u64,
+	) -> Result<Vec<Signature>, anyhow::Error>;
+
+	/// Retrieves the confirmed transactions involving an address
+	///
+	/// This is much cheaper than scanning every block of a long slot range when only a
+	/// few addresses are monitored.
+	///
+	/// # Arguments
+	/// * `address` - Address the transactions involve
+	/// * `start_slot` - First slot to include
+	/// * `end_slot` - Last slot to include
+	///
+	/// # Returns
+	/// * `Result<Vec<SolanaTransaction>, anyhow::Error>` - The transactions, oldest first
+	async fn get_transactions_for_address(
+		&self,
+		address: &Pubkey,
+		start_slot: u64,
+		end_slot: u64,
+	) -> Result<Vec<SolanaTransaction>, anyhow::Error>;
+
+	/// Retrieves the latest confirmed slot
+	///
+	/// # Returns
+	/// * `Result<u64, anyhow::Error>` - The latest confirmed slot
+	async fn get_latest_slot(&self) -> Result<u64, anyhow::Error>;
+
+	/// Records how many slots the last processed slot is behind the chain tip
+	///
+	/// # Arguments
+	/// * `last_processed_slot` - The last slot processed by the monitor
+	///
+	/// # Returns
+	/// * `Result<u64, anyhow::Error>` - The number of slots between the latest confirmed
+	///   slot and `last_processed_slot`
+	async fn record_slot_lag(&self, last_processed_slot: u64) -> Result<u64, anyhow::Error>;
+
+	/// Retrieves transactions parsed by the enhanced transactions endpoint of the network
+	///
+	/// Networks without such an endpoint return no parsed transactions.
+	///
+	/// # Arguments
+	/// * `signatures` - Signatures of the transactions to fetch
+	///
+	/// # Returns
+	/// * `Result<HashMap<Signature, SolanaEnhancedTransaction>, anyhow::Error>` - The parsed
+	///   transactions, by signature
+	async fn get_enhanced_transactions(
+		&self,
+		_signatures: &[Signature],
+	) -> Result<HashMap<Signature, SolanaEnhancedTransaction>, anyhow::Error> {
+		Ok(HashMap::new())
+	}
+
+	/// Retrieves the Anchor IDL a program publishes on-chain
+	///
+	/// # Arguments
+	/// * `program_id` - Address of the program
+	///
+	/// # Returns
+	/// * `Result<Option<AnchorIdl>, anyhow::Error>` - The IDL, or `None` if the program
+	///   publishes none
+	async fn get_idl(&self, program_id: &Pubkey) -> Result<Option<AnchorIdl>, anyhow::Error>;
+}
+
+#[async_trait]
+impl<T: Send + Sync + Clone + BlockchainTransport> SolanaClientTrait for SolanaClient<T> {
+	/// Retrieves the accounts in batches of at most 100 addresses per request
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	/// - Returns `anyhow::Error` if the response cannot be parsed
+	#[instrument(skip_all, fields(accounts = addresses.len()))]
+	async fn get_multiple_accounts(
+		&self,
+		addresses: &[Pubkey],
+	) -> Result<Vec<Option<Account>>, anyhow::Error> {
+		let mut accounts = Vec::with_capacity(addresses.len());
+
+		for chunk in addresses.chunks(MAX_ACCOUNTS_PER_REQUEST) {
+			let params = json!([
+				chunk.iter().map(Pubkey::to_string).collect::<Vec<_>>(),
+				{ "encoding": "base64", "commitment": "confirmed" }
+			]);
+
+			let response = self
+				.send_request(RPC_METHOD_GET_MULTIPLE_ACCOUNTS, Some(params))
+				.await
+				.with_context(|| "Failed to get multiple accounts")?;
+
+			if let Some(error) = response.get("error") {
+				return Err(anyhow::anyhow!(
+					"Solana RPC request failed for method '{}': {}",
+					RPC_METHOD_GET_MULTIPLE_ACCOUNTS,
+					error
+				));
+			}
+
+			let values = response
+				.get("result")
+				.and_then(|result| result.get("value"))
+				.with_context(|| "Missing 'result.value' field")?;
+
+			let ui_accounts: Vec<Option<UiAccount>> = serde_json::from_value(values.clone())
+				.with_context(|| "Failed to parse accounts")?;
+
+			if ui_accounts.len() != chunk.len() {
+				return Err(anyhow::anyhow!(
+					"Expected {} accounts, received {}",
+					chunk.len(),
+					ui_accounts.len()
+				));
+			}
+
+			for ui_account in ui_accounts {
+				let account = match ui_account {
+					Some(ui_account) => Some(
+						ui_account
+							.decode::<Account>()
+							.with_context(|| "Failed to decode account data")?,
+					),
+					None => None,
+				};
+				accounts.push(account);
+			}
+		}
+
+		Ok(accounts)
+	}
+
+	/// Retrieves the transaction in base64 encoding, including versioned transactions
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	/// - Returns `anyhow::Error` if the response cannot be parsed or decoded
+	#[instrument(skip(self), fields(signature = %signature))]
+	async fn get_transaction(
+		&self,
+		signature: &Signature,
+	) -> Result<Option<SolanaTransaction>, anyhow::Error> {
+		let params = json!([
+			signature.to_string(),
+			{
+				"encoding": "base64",
+				"commitment": "confirmed",
+				"maxSupportedTransactionVersion": 0
+			}
+		]);
+
+		let response = self
+			.send_request(RPC_METHOD_GET_TRANSACTION, Some(params))
+			.await
+			.with_context(|| format!("Failed to get transaction {}", signature))?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Solana RPC request failed for method '{}': {}",
+				RPC_METHOD_GET_TRANSACTION,
+				error
+			));
+		}
+
+		let result = response
+			.get("result")
+			.with_context(|| "Missing 'result' field")?;
+		if result.is_null() {
+			return Ok(None);
+		}
+
+		let parse = || -> Result<EncodedConfirmedTransactionWithStatusMeta, anyhow::Error> {
+			serde_json::from_value(result.clone()).with_context(|| "Failed to parse transaction")
+		};
+
+		// Responses may lack the addresses loaded from lookup tables, which are then
+		// resolved from the lookup table accounts
+		match SolanaTransaction::from_encoded(parse()?) {
+			Err(SolanaTransactionError::MissingLoadedAddresses(tables)) => {
+				let lookup_tables = self.get_lookup_tables(&tables).await?;
+				SolanaTransaction::from_encoded_with_lookup_tables(parse()?, &lookup_tables)
+			}
+			result => result,
+		}
+		.map(Some)
+		.with_context(|| format!("Failed to decode transaction {}", signature))
+	}
+
+	/// Pages through the signatures from the newest to the oldest, stopping at the first
+	/// signature older than `start_slot`
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	/// - Returns `anyhow::Error` if the response cannot be parsed
+	#[instrument(skip(self), fields(address = %address))]
+	async fn get_signatures_for_address(
+		&self,
+		address: &Pubkey,
+		start_slot: u64,
+		end_slot: u64,
+	) -> Result<Vec<Signature>, anyhow::Error> {
+		let mut signatures = Vec::new();
+		let mut before: Option<String> = None;
+
+		loop {
+			let mut config = json!({
+				"limit": MAX_SIGNATURES_PER_REQUEST,
+				"commitment": "confirmed"
+			});
+			if let Some(before) = &before {
+				config["before"] = json!(before);
+			}
+			let params = json!([address.to_string(), config]);
+
+			let response = self
+				.send_request(RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS, Some(params))
+				.await
+				.with_context(|| format!("Failed to get signatures for address {}", address))?;
+
+			if let Some(error) = response.get("error") {
+				return Err(anyhow::anyhow!(
+					"Solana RPC request failed for method '{}': {}",
+					RPC_METHOD_GET_SIGNATURES_FOR_ADDRESS,
+					error
+				));
+			}
+
+			let result = response
+				.get("result")
+				.with_context(|| "Missing 'result' field")?;
+			let page: Vec<RpcConfirmedTransactionStatusWithSignature> =
+				serde_json::from_value(result.clone())
+					.with_context(|| "Failed to parse signatures")?;
+
+			for status in &page {
+				if status.slot < start_slot {
+					signatures.reverse();
+					return Ok(signatures);
+				}
+				if status.slot <= end_slot {
+					signatures.push(
+						Signature::from_str(&status.signature)
+							.with_context(|| format!("Invalid signature {}", status.signature))?,
+					);
+				}
+			}
+
+			match page.last() {
+				Some(last) if page.len() == MAX_SIGNATURES_PER_REQUEST => {
+					before = Some(last.signature.clone());
+				}
+				_ => break,
+			}
+		}
+
+		signatures.reverse();
+		Ok(signatures)
+	}
+
+	/// Fetches each transaction returned by `get_signatures_for_address`
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if any RPC request fails
+	/// - Returns `anyhow::Error` if a response cannot be parsed or decoded
+	#[instrument(skip(self), fields(address = %address))]
+	async fn get_transactions_for_address(
+		&self,
+		address: &Pubkey,
+		start_slot: u64,
+		end_slot: u64,
+	) -> Result<Vec<SolanaTransaction>, anyhow::Error> {
+		let signatures = self
+			.get_signatures_for_address(address, start_slot, end_slot)
+			.await?;
+
+		let mut transactions = Vec::with_capacity(signatures.len());
+		for signature in &signatures {
+			match self.get_transaction(signature).await? {
+				Some(transaction) => transactions.push(transaction),
+				None => tracing::warn!("Transaction {} not found", signature),
+			}
+		}
+
+		Ok(transactions)
+	}
+
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	/// - Returns `anyhow::Error` if the response cannot be parsed
+	#[instrument(skip(self))]
+	async fn get_latest_slot(&self) -> Result<u64, anyhow::Error> {
+		let response = self
+			.send_request(
+				RPC_METHOD_GET_SLOT,
+				Some(json!([{ "commitment": "confirmed" }])),
+			)
+			.await
+			.with_context(|| "Failed to get latest slot")?;
+
+		if let Some(error) = response.get("error") {
+			return Err(anyhow::anyhow!(
+				"Solana RPC request failed for method '{}': {}",
+				RPC_METHOD_GET_SLOT,
+				error
+			));
+		}
+
+		response
+			.get("result")
+			.and_then(Value::as_u64)
+			.with_context(|| "Invalid slot in response")
+	}
+
+	/// Sets the `solana_slot_lag` gauge of the network to the lag
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the latest slot cannot be retrieved
+	#[instrument(skip(self))]
+	async fn record_slot_lag(&self, last_processed_slot: u64) -> Result<u64, anyhow::Error> {
+		let slot_lag = self
+			.get_latest_slot()
+			.await?
+			.saturating_sub(last_processed_slot);
+		SOLANA_SLOT_LAG
+			.with_label_values(&[self.network_slug.as_str()])
+			.set(slot_lag as f64);
+		Ok(slot_lag)
+	}
+
+	/// Fetches the parsed transactions from the enhanced transactions endpoint, if any
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the request fails or its response cannot be parsed
+	#[instrument(skip_all, fields(transactions = signatures.len()))]
+	async fn get_enhanced_transactions(
+		&self,
+		signatures: &[Signature],
+	) -> Result<HashMap<Signature, SolanaEnhancedTransaction>, anyhow::Error> {
+		let Some(enhanced_client) = &self.enhanced_client else {
+			return Ok(HashMap::new());
+		};
+		if signatures.is_empty() {
+			return Ok(HashMap::new());
+		}
+
+		Ok(enhanced_client
+			.get_transactions(signatures)
+			.await?
+			.into_iter()
+			.filter_map(|transaction| {
+				let signature = Signature::from_str(&transaction.signature).ok()?;
+				Some((signature, transaction))
+			})
+			.collect())
+	}
+
+	/// Retrieves the IDL account of the program, caching the parsed IDL
+	///
+	/// Programs whose IDL account is missing or cannot be parsed are cached as publishing
+	/// no IDL.
+	///
+	/// # Errors
+	/// - Returns `anyhow::Error` if the RPC request fails
+	#[instrument(skip(self), fields(program_id = %program_id))]
+	async fn get_idl(&self, program_id: &Pubkey) -> Result<Option<AnchorIdl>, anyhow::Error> {
+		if let Some(idl) = self
+			.idl_cache
+			.read()
+			.ok()
+			.and_then(|cache| cache.get(program_id).cloned())
+		{
+			return Ok(idl);
+		}
+
+		let Some(idl_address) = idl_account_address(program_id) else {
+			return Ok(None);
+		};
+		let account = self
+			.get_multiple_accounts(&[idl_address])
+			.await?
+			.into_iter()
+			.next()
+			.flatten();
+		let idl = account.and_then(|account| {
+			AnchorIdl::from_account_data(&account.data)
+				.map_err(|e| tracing::warn!("Invalid IDL account for {}: {}", program_id, e))
+				.ok()
+		});
+
+		if let Ok(mut cache) = self.idl_cache.write() {
+			cache.insert(*program_id, idl.clone());
+		}
+		Ok(idl)
+	}
+}
@@ -0,0 +1,129 @@
+//! Client of enhanced transaction APIs for Solana.
+//!
+//! Fetches transactions parsed by the Helius enhanced transactions endpoint
+//! (`POST /v0/transactions`), which classifies transactions and describes their transfers
+//! even for programs we do not ship a decoder for.
+
+use anyhow::Context;
+use reqwest::header::HeaderMap;
+use reqwest_middleware::ClientWithMiddleware;
+use serde_json::json;
+use solana_sdk::signature::Signature;
+use std::time::Duration;
+
+use crate::{
+	models::{Network, RpcUrl, SolanaEnhancedTransaction},
+	services::blockchain::transports::{rpc_url_headers, TransientErrorRetryStrategy},
+	utils::http::{create_retryable_http_client, HttpRetryConfig},
+};
+
+/// Type of the `rpc_urls` pointing to an enhanced transactions endpoint
+pub const ENHANCED_URL_TYPE: &str = "enhanced";
+
+/// Maximum number of transactions accepted by a single request
+const MAX_TRANSACTIONS_PER_REQUEST: usize = 100;
+
+/// A client fetching parsed transactions from an enhanced transactions endpoint
+#[derive(Clone, Debug)]
+pub struct SolanaEnhancedClient {
+	/// Retryable HTTP client for making requests
+	client: ClientWithMiddleware,
+	/// URL of the endpoint, including its API key when the provider expects it there
+	url: String,
+	/// Headers and authentication configured for the endpoint
+	headers: HeaderMap,
+}
+
+impl SolanaEnhancedClient {
+	/// Creates a new client for an enhanced transactions endpoint
+	///
+	/// # Arguments
+	/// * `rpc_url` - The endpoint configuration, with its headers and authentication
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new client, or an error if a header is invalid
+	pub async fn new(rpc_url: &RpcUrl) -> Result<Self, anyhow::Error> {
+		let base_client = reqwest::ClientBuilder::new()
+			.timeout(Duration::from_secs(30))
+			.build()
+			.context("Failed to create base HTTP client")?;
+
+		Ok(Self {
+			client: create_retryable_http_client(
+				&HttpRetryConfig::default(),
+				base_client,
+				Some(TransientErrorRetryStrategy),
+			),
+			url: rpc_url.url.as_ref().to_string(),
+			headers: rpc_url_headers(rpc_url).await?,
+		})
+	}
+
+	/// Creates a client for the enhanced transactions endpoint of a network, if any
+	///
+	/// The endpoint of type `enhanced` with the highest weight is used.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing the endpoints
+	pub async fn from_network(network: &Network) -> Result<Option<Self>, anyhow::Error> {
+		let rpc_url = network
+			.rpc_urls
+			.iter()
+			.filter(|rpc_url| rpc_url.type_ == ENHANCED_URL_TYPE && rpc_url.weight > 0)
+			.max_by_key(|rpc_url| rpc_url.weight);
+
+		match rpc_url {
+			Some(rpc_url) => Ok(Some(Self::new(rpc_url).await?)),
+			None => Ok(None),
+		}
+	}
+
+	/// Fetches the parsed transactions of the given signatures
+	///
+	/// Requests are sent in batches of at most 100 signatures. Transactions the API does not
+	/// return are omitted.
+	///
+	/// # Arguments
+	/// * `signatures` - Signatures of the transactions to fetch
+	///
+	/// # Returns
+	/// * `Result<Vec<SolanaEnhancedTransaction>, anyhow::Error>` - The parsed transactions
+	pub async fn get_transactions(
+		&self,
+		signatures: &[Signature],
+	) -> Result<Vec<SolanaEnhancedTransaction>, anyhow::Error> {
+		let mut transactions = Vec::with_capacity(signatures.len());
+
+		for chunk in signatures.chunks(MAX_TRANSACTIONS_PER_REQUEST) {
+			let body = json!({
+				"transactions": chunk.iter().map(ToString::to_string).collect::<Vec<_>>(),
+			});
+			let response = self
+				.client
+				.post(&self.url)
+				.headers(self.headers.clone())
+				.json(&body)
+				.send()
+				.await
+				.context("Failed to send enhanced transactions request")?;
+
+			let status = response.status();
+			if !status.is_success() {
+				let error_body = response.text().await.unwrap_or_default();
+				return Err(anyhow::anyhow!(
+					"Enhanced transactions request failed with status {}: {}",
+					status,
+					error_body
+				));
+			}
+
+			let parsed: Vec<SolanaEnhancedTransaction> = response
+				.json()
+				.await
+				.context("Failed to parse enhanced transactions response")?;
+			transactions.extend(parsed);
+		}
+
+		Ok(transactions)
+	}
+}
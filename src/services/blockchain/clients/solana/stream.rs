@@ -0,0 +1,294 @@
+//! Solana blocks pushed by a subscription.
+//!
+//! Networks configuring `geyser` or `ws` endpoints receive their blocks through a Yellowstone
+//! gRPC stream or `blockSubscribe` as they are confirmed, instead of polling `getBlock` for
+//! every slot. Received blocks are kept until the block watcher fetches them, and slots
+//! outside of the buffered range are fetched over HTTP.
+
+use solana_sdk::{commitment_config::CommitmentConfig, transaction::Transaction};
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, Mutex},
+};
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::{
+	models::{Network, SolanaBlock},
+	services::blockchain::transports::{
+		SolanaBlockUpdate, SolanaGeyserTransportClient, SolanaTransportClient,
+		SolanaWsTransportClient,
+	},
+};
+
+/// Maximum number of blocks kept until they are fetched (~4 minutes of slots)
+const MAX_BUFFERED_BLOCKS: usize = 600;
+
+/// Source pushing the blocks of a network
+#[derive(Clone, Debug)]
+pub enum SolanaBlockSource {
+	/// Yellowstone gRPC stream of the `geyser` endpoints
+	Geyser(SolanaGeyserTransportClient),
+	/// `blockSubscribe` over the `ws` endpoints, polling the `rpc` endpoints while none of
+	/// them is reachable
+	WebSocket(SolanaWsTransportClient),
+}
+
+impl SolanaBlockSource {
+	/// Selects the block source of a network from the types of its `rpc_urls`
+	///
+	/// `geyser` endpoints are preferred over `ws` endpoints, as they push blocks sooner.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and other network details
+	/// * `http_client` - HTTP transport client of the network, polled while the source is
+	///   unavailable
+	///
+	/// # Returns
+	/// * `Result<Option<Self>, anyhow::Error>` - The block source, or `None` if the blocks of
+	///   the network are fetched over HTTP only
+	pub fn from_network(
+		network: &Network,
+		http_client: &SolanaTransportClient,
+	) -> Result<Option<Self>, anyhow::Error> {
+		if has_rpc_url_type(network, "geyser") {
+			let geyser_client = SolanaGeyserTransportClient::new(network)?;
+			return Ok(Some(Self::Geyser(geyser_client)));
+		}
+
+		if has_rpc_url_type(network, "ws") {
+			let ws_client =
+				SolanaWsTransportClient::new_with_http_client(network, Some(http_client.clone()))?;
+			return Ok(Some(Self::WebSocket(ws_client)));
+		}
+
+		Ok(None)
+	}
+
+	/// Subscribes to the blocks of the source
+	///
+	/// Blocks are delivered in increasing slot order until the receiver is dropped.
+	pub fn subscribe_blocks(&self) -> mpsc::Receiver<SolanaBlockUpdate> {
+		match self {
+			Self::Geyser(geyser_client) => geyser_client.subscribe_blocks(),
+			Self::WebSocket(ws_client) => ws_client.subscribe_blocks(),
+		}
+	}
+}
+
+/// Returns whether a network has an enabled endpoint of the given type
+fn has_rpc_url_type(network: &Network, type_: &str) -> bool {
+	network
+		.rpc_urls
+		.iter()
+		.any(|rpc_url| rpc_url.type_ == type_ && rpc_url.weight > 0)
+}
+
+/// Blocks received from a block source, by slot
+#[derive(Debug, Default)]
+struct BufferedBlocks {
+	/// Blocks not fetched yet, by slot
+	blocks: BTreeMap<u64, SolanaBlockUpdate>,
+	/// First slot from which every block was received, slots without block being skipped
+	complete_from: Option<u64>,
+	/// Slot of the latest block received
+	latest_slot: Option<u64>,
+}
+
+impl BufferedBlocks {
+	/// Adds a block received from the source
+	///
+	/// A block whose parent was not received, e.g. after the source reconnected, starts the
+	/// buffer again, as the blocks in between were missed.
+	fn push(&mut self, block: SolanaBlockUpdate) {
+		match self.latest_slot {
+			Some(latest_slot) if block.slot <= latest_slot => return,
+			Some(latest_slot) if block.parent_slot <= latest_slot => {}
+			_ => {
+				self.blocks.clear();
+				self.complete_from = Some(block.parent_slot + 1);
+			}
+		}
+
+		self.latest_slot = Some(block.slot);
+		self.blocks.insert(block.slot, block);
+		while self.blocks.len() > MAX_BUFFERED_BLOCKS {
+			if let Some((slot, _)) = self.blocks.pop_first() {
+				self.complete_from = Some(slot + 1);
+			}
+		}
+	}
+
+	/// Returns the blocks of the slots from `start_slot` to `end_slot` (inclusive), or
+	/// `None` unless every block of the range was received
+	fn blocks(&self, start_slot: u64, end_slot: u64) -> Option<Vec<SolanaBlockUpdate>> {
+		if start_slot < self.complete_from? || end_slot > self.latest_slot? {
+			return None;
+		}
+
+		Some(
+			self.blocks
+				.range(start_slot..=end_slot)
+				.map(|(_, block)| block.clone())
+				.collect(),
+		)
+	}
+}
+
+/// Blocks pushed by a block source, kept until they are fetched
+///
+/// The subscription is dropped along with the stream.
+#[derive(Debug)]
+pub struct SolanaBlockStream {
+	/// Blocks received and not fetched yet
+	buffer: Arc<Mutex<BufferedBlocks>>,
+	/// Task receiving the blocks of the subscription
+	task: JoinHandle<()>,
+}
+
+impl SolanaBlockStream {
+	/// Keeps the blocks received on a subscription
+	///
+	/// # Arguments
+	/// * `receiver` - The blocks of the subscription, in increasing slot order
+	pub fn new(mut receiver: mpsc::Receiver<SolanaBlockUpdate>) -> Self {
+		let buffer = Arc::new(Mutex::new(BufferedBlocks::default()));
+		let task = tokio::spawn({
+			let buffer = buffer.clone();
+			async move {
+				while let Some(block) = receiver.recv().await {
+					buffer.lock().unwrap().push(block);
+				}
+			}
+		});
+
+		Self { buffer, task }
+	}
+
+	/// Returns the slot of the latest block received, if any
+	pub fn latest_slot(&self) -> Option<u64> {
+		self.buffer.lock().unwrap().latest_slot
+	}
+
+	/// Returns the blocks of the slots from `start_slot` to `end_slot` (inclusive)
+	///
+	/// # Returns
+	/// * `Option<Vec<SolanaBlock>>` - The blocks of the range, skipped slots having none, or
+	///   `None` unless every block of the range was received
+	pub fn blocks(&self, start_slot: u64, end_slot: u64) -> Option<Vec<SolanaBlock>> {
+		let blocks = self.buffer.lock().unwrap().blocks(start_slot, end_slot)?;
+		Some(blocks.into_iter().map(solana_block).collect())
+	}
+}
+
+impl Drop for SolanaBlockStream {
+	fn drop(&mut self) {
+		self.task.abort();
+	}
+}
+
+/// Converts a block received from a subscription or fetched over HTTP, keeping its
+/// transactions decoded with their status metadata
+pub(crate) fn solana_block(block: SolanaBlockUpdate) -> SolanaBlock {
+	let transactions = block
+		.transactions
+		.iter()
+		.cloned()
+		.map(Transaction::from)
+		.collect();
+
+	SolanaBlock::new(
+		block.slot,
+		block.blockhash,
+		block.parent_slot,
+		block.block_time,
+		block.block_height,
+		transactions,
+		None,
+		CommitmentConfig::confirmed(),
+	)
+	.with_decoded_transactions(block.transactions)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn block(slot: u64, parent_slot: u64) -> SolanaBlockUpdate {
+		SolanaBlockUpdate {
+			slot,
+			blockhash: format!("blockhash-{}", slot),
+			parent_slot,
+			block_height: Some(slot),
+			block_time: Some(1678901234),
+			transactions: vec![],
+		}
+	}
+
+	fn slots(blocks: Option<Vec<SolanaBlockUpdate>>) -> Option<Vec<u64>> {
+		blocks.map(|blocks| blocks.iter().map(|block| block.slot).collect())
+	}
+
+	#[test]
+	fn test_buffered_blocks_skip_slots_without_block() {
+		let mut buffer = BufferedBlocks::default();
+		assert_eq!(slots(buffer.blocks(100, 100)), None);
+
+		buffer.push(block(100, 98));
+		buffer.push(block(102, 100));
+		buffer.push(block(102, 100));
+
+		// Slot 99 was skipped, as the parent of the first block is slot 98
+		assert_eq!(slots(buffer.blocks(99, 102)), Some(vec![100, 102]));
+		assert_eq!(slots(buffer.blocks(101, 101)), Some(vec![]));
+		// Older slots and slots not received yet are not covered
+		assert_eq!(slots(buffer.blocks(98, 102)), None);
+		assert_eq!(slots(buffer.blocks(102, 103)), None);
+	}
+
+	#[test]
+	fn test_buffered_blocks_restart_after_missed_blocks() {
+		let mut buffer = BufferedBlocks::default();
+		buffer.push(block(100, 99));
+		buffer.push(block(101, 100));
+
+		// The blocks up to slot 104 were missed while the source reconnected
+		buffer.push(block(105, 104));
+
+		assert_eq!(slots(buffer.blocks(100, 105)), None);
+		assert_eq!(slots(buffer.blocks(105, 105)), Some(vec![105]));
+	}
+
+	#[test]
+	fn test_buffered_blocks_drop_oldest_blocks() {
+		let mut buffer = BufferedBlocks::default();
+		for slot in 1..=MAX_BUFFERED_BLOCKS as u64 + 10 {
+			buffer.push(block(slot, slot - 1));
+		}
+
+		assert_eq!(buffer.blocks.len(), MAX_BUFFERED_BLOCKS);
+		assert_eq!(slots(buffer.blocks(10, 10)), None);
+		assert_eq!(slots(buffer.blocks(11, 11)), Some(vec![11]));
+	}
+
+	#[tokio::test]
+	async fn test_block_stream_keeps_received_blocks() {
+		let (sender, receiver) = mpsc::channel(10);
+		let stream = SolanaBlockStream::new(receiver);
+		sender.send(block(100, 99)).await.unwrap();
+		sender.send(block(101, 100)).await.unwrap();
+		drop(sender);
+
+		for _ in 0..100 {
+			if stream.latest_slot() == Some(101) {
+				break;
+			}
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+
+		let blocks = stream.blocks(100, 101).unwrap();
+		assert_eq!(blocks.len(), 2);
+		assert_eq!(blocks[1].slot, 101);
+		assert_eq!(blocks[1].parent_slot, 100);
+		assert_eq!(blocks[1].blockhash, "blockhash-101");
+	}
+}
@@ -4,7 +4,7 @@
 //! different blockchain networks. Includes:
 //!
 //! - Generic blockchain client trait
-//! - EVM and Stellar specific clients
+//! - EVM, Stellar and Solana specific clients
 //! - Network transport implementations
 //! - Error handling for blockchain operations
 //! - Client pool for managing multiple clients
@@ -17,11 +17,14 @@ mod transports;
 
 pub use client::{BlockChainClient, BlockFilterFactory};
 pub use clients::{
-	EvmClient, EvmClientTrait, StellarClient, StellarClientError, StellarClientTrait,
+	EvmClient, EvmClientTrait, SolanaBlockSource, SolanaBlockStream, SolanaClient,
+	SolanaClientTrait, SolanaEnhancedClient, StellarClient, StellarClientError, StellarClientTrait,
 };
 pub use error::BlockChainError;
 pub use pool::{ClientPool, ClientPoolTrait};
 pub use transports::{
 	BlockchainTransport, EVMTransportClient, EndpointManager, HttpTransportClient,
-	RotatingTransport, StellarTransportClient, TransientErrorRetryStrategy, TransportError,
+	RotatingTransport, SolanaBlockUpdate, SolanaEndpointHealth, SolanaGeyserTransportClient,
+	SolanaTransportClient, SolanaWsTransportClient, StellarTransportClient,
+	TransientErrorRetryStrategy, TransportError,
 };
@@ -2,10 +2,11 @@
 //!
 //! Provides methods for rotating between multiple URLs and sending requests to the active endpoint
 //! with automatic fallback to other URLs on failure.
+use reqwest::header::HeaderMap;
 use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
 use serde_json::Value;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 
 use crate::services::blockchain::transports::{
@@ -22,12 +23,14 @@ use crate::services::blockchain::transports::{
 /// * `fallback_urls` - A list of fallback URLs to rotate to
 /// * `client` - The client to use for the endpoint manager
 /// * `rotation_lock` - A lock for managing the rotation process
+/// * `headers` - Additional headers sent to each URL, e.g. for authentication
 #[derive(Clone, Debug)]
 pub struct EndpointManager {
 	pub active_url: Arc<RwLock<String>>,
 	pub fallback_urls: Arc<RwLock<Vec<String>>>,
 	client: ClientWithMiddleware,
 	rotation_lock: Arc<tokio::sync::Mutex<()>>,
+	headers: Arc<HashMap<String, HeaderMap>>,
 }
 
 /// Represents the outcome of a `EndpointManager::attempt_request_on_url` method call
@@ -58,9 +61,35 @@ impl EndpointManager {
 			fallback_urls: Arc::new(RwLock::new(fallback_urls)),
 			rotation_lock: Arc::new(tokio::sync::Mutex::new(())),
 			client,
+			headers: Arc::new(HashMap::new()),
 		}
 	}
 
+	/// Sets the additional headers sent with the requests to each URL
+	///
+	/// # Arguments
+	/// * `headers` - The headers to send, keyed by URL
+	pub fn with_headers(mut self, headers: HashMap<String, HeaderMap>) -> Self {
+		self.headers = Arc::new(
+			headers
+				.into_iter()
+				.map(|(url, headers)| (url.trim_end_matches('/').to_string(), headers))
+				.collect(),
+		);
+		self
+	}
+
+	/// Returns the additional headers sent with the requests to a URL
+	///
+	/// # Arguments
+	/// * `url` - The URL the requests are sent to
+	pub fn headers(&self, url: &str) -> HeaderMap {
+		self.headers
+			.get(url.trim_end_matches('/'))
+			.cloned()
+			.unwrap_or_default()
+	}
+
 	/// Updates the client with a new client
 	///
 	/// Useful for updating the client with a new retry policy or strategy
@@ -172,30 +201,51 @@ impl EndpointManager {
 		Ok(new_url)
 	}
 
+	/// Makes a fallback URL the active one, moving the active URL to the fallbacks
+	///
+	/// Unlike `try_rotate_url`, the connection to the URL is not tested, so callers are
+	/// expected to have checked the endpoint beforehand.
+	///
+	/// # Arguments
+	/// * `url` - The fallback URL to make active
+	///
+	/// # Returns
+	/// * `Result<(), TransportError>` - An error if the URL is not one of the fallback URLs
+	pub async fn promote_url(&self, url: &str) -> Result<(), TransportError> {
+		let _guard = self.rotation_lock.lock().await;
+		let mut active_url_guard = self.active_url.write().await;
+		let mut fallback_urls_guard = self.fallback_urls.write().await;
+
+		let normalized_url = url.trim_end_matches('/');
+		let Some(position) = fallback_urls_guard
+			.iter()
+			.position(|fallback_url| fallback_url.trim_end_matches('/') == normalized_url)
+		else {
+			let msg = format!("URL '{}' is not a fallback URL", url);
+			return Err(TransportError::url_rotation(msg, None, None));
+		};
+
+		fallback_urls_guard.remove(position);
+		let previous_url = std::mem::replace(&mut *active_url_guard, normalized_url.to_string());
+		tracing::debug!("Promoted URL '{}' over '{}'", normalized_url, previous_url);
+		fallback_urls_guard.push(previous_url);
+		Ok(())
+	}
+
 	/// Attempts to send a request to the specified URL
 	/// # Arguments
 	/// * `url` - The URL to send the request to
-	/// * `transport` - The transport client implementing the RotatingTransport trait
-	/// * `method` - The HTTP method to use for the request (e.g., "POST")
-	/// * `params` - Optional parameters for the request, serialized to JSON
+	/// * `request_body` - The JSON-RPC request body, a single request or a batch of requests
 	///
 	/// # Returns
 	/// * `SingleRequestAttemptOutcome` - The outcome of the request attempt
-	async fn try_request_on_url<P>(
+	async fn try_request_on_url(
 		&self,
 		url: &str,
-		transport: &impl RotatingTransport,
-		method: &str,
-		params: Option<P>,
-	) -> SingleRequestAttemptOutcome
-	where
-		P: Into<Value> + Send + Clone + Serialize,
-	{
-		// Create the request body using the transport's customization method
-		let request_body = transport.customize_request(method, params).await;
-
+		request_body: &Value,
+	) -> SingleRequestAttemptOutcome {
 		// Serialize the request body to JSON
-		let request_body_str = match serde_json::to_string(&request_body) {
+		let request_body_str = match serde_json::to_string(request_body) {
 			Ok(body) => body,
 			Err(e) => {
 				tracing::error!("Failed to serialize request body: {}", e);
@@ -213,6 +263,7 @@ impl EndpointManager {
 		let response_result = self
 			.client
 			.post(url)
+			.headers(self.headers(url))
 			.header("Content-Type", "application/json")
 			.body(request_body_str)
 			.send()
@@ -228,6 +279,58 @@ impl EndpointManager {
 		}
 	}
 
+	/// Sends a raw request to a specific URL, without rotating the active URL on failure
+	///
+	/// # Arguments
+	/// * `url` - The URL to send the request to
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `method` - The RPC method name to call
+	/// * `params` - The parameters for the RPC method call as a JSON Value
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - The JSON response from the RPC endpoint or an error
+	pub async fn send_raw_request_on_url<
+		T: RotatingTransport,
+		P: Into<Value> + Send + Clone + Serialize,
+	>(
+		&self,
+		url: &str,
+		transport: &T,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError> {
+		let request_body = transport.customize_request(method, params).await;
+		match self.try_request_on_url(url, &request_body).await {
+			SingleRequestAttemptOutcome::Success(response) => {
+				let status = response.status();
+				if status.is_success() {
+					response.json().await.map_err(|e| {
+						TransportError::response_parse(
+							"Failed to parse JSON response".to_string(),
+							Some(Box::new(e)),
+							None,
+						)
+					})
+				} else {
+					let error_body = response.text().await.unwrap_or_default();
+					Err(TransportError::http(
+						status,
+						url.to_string(),
+						error_body,
+						None,
+						None,
+					))
+				}
+			}
+			SingleRequestAttemptOutcome::NetworkError(network_error) => Err(
+				TransportError::network(network_error.to_string(), None, None),
+			),
+			SingleRequestAttemptOutcome::SerializationError(serialization_error) => {
+				Err(serialization_error)
+			}
+		}
+	}
+
 	/// Sends a raw request to the blockchain RPC endpoint with automatic URL rotation on failure
 	///
 	/// # Arguments
@@ -251,6 +354,91 @@ impl EndpointManager {
 		transport: &T,
 		method: &str,
 		params: Option<P>,
+	) -> Result<Value, TransportError> {
+		let request_body = transport.customize_request(method, params).await;
+		self.send_request_body(transport, &request_body).await
+	}
+
+	/// Sends a batch of raw requests to the blockchain RPC endpoint in a single HTTP request,
+	/// with automatic URL rotation on failure
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `requests` - The RPC method names and parameters of the requests
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The JSON responses, in the order of `requests`
+	pub async fn send_batch_request<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		// Empty batches are rejected by JSON-RPC servers
+		if requests.is_empty() {
+			return Ok(vec![]);
+		}
+
+		let mut batch = Vec::with_capacity(requests.len());
+		for (id, (method, params)) in requests.iter().enumerate() {
+			let mut request_body = transport.customize_request(method, params.clone()).await;
+			// Responses of a batch may come in any order, and are matched by their id
+			request_body["id"] = Value::from(id);
+			batch.push(request_body);
+		}
+
+		let response = self
+			.send_request_body(transport, &Value::Array(batch))
+			.await?;
+		let Value::Array(responses) = response else {
+			return Err(TransportError::response_parse(
+				format!("Expected an array of responses, got: {}", response),
+				None,
+				None,
+			));
+		};
+
+		let mut ordered_responses = vec![None; requests.len()];
+		for response in responses {
+			let id = response.get("id").and_then(Value::as_u64);
+			match id.and_then(|id| ordered_responses.get_mut(id as usize)) {
+				Some(slot) => *slot = Some(response),
+				None => {
+					return Err(TransportError::response_parse(
+						format!("Unexpected response in batch: {}", response),
+						None,
+						None,
+					))
+				}
+			}
+		}
+
+		ordered_responses
+			.into_iter()
+			.enumerate()
+			.map(|(id, response)| {
+				response.ok_or_else(|| {
+					TransportError::response_parse(
+						format!("Missing response for request {} of batch", id),
+						None,
+						None,
+					)
+				})
+			})
+			.collect()
+	}
+
+	/// Sends a request body to the active URL, rotating to the fallback URLs on failure
+	///
+	/// # Arguments
+	/// * `transport` - The transport client implementing the RotatingTransport trait
+	/// * `request_body` - The JSON-RPC request body, a single request or a batch of requests
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - The JSON response from the RPC endpoint or an error
+	async fn send_request_body<T: RotatingTransport>(
+		&self,
+		transport: &T,
+		request_body: &Value,
 	) -> Result<Value, TransportError> {
 		loop {
 			let current_url_snapshot = self.active_url.read().await.clone();
@@ -262,7 +450,7 @@ impl EndpointManager {
 
 			// Attempt to send the request to the current active URL
 			let attempt_result = self
-				.try_request_on_url(&current_url_snapshot, transport, method, params.clone())
+				.try_request_on_url(&current_url_snapshot, request_body)
 				.await;
 
 			match attempt_result {
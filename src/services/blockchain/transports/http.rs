@@ -10,14 +10,16 @@
 
 use anyhow::Context;
 use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
 use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
 use serde_json::{json, Value};
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use url::Url;
 
 use crate::{
-	models::Network,
+	models::{Network, RpcAuth, RpcUrl},
 	services::blockchain::transports::{
 		BlockchainTransport, EndpointManager, RotatingTransport, TransientErrorRetryStrategy,
 		TransportError,
@@ -60,15 +62,44 @@ impl HttpTransportClient {
 	pub async fn new(
 		network: &Network,
 		test_connection_payload: Option<String>,
+	) -> Result<Self, anyhow::Error> {
+		Self::new_with_url_type(network, "rpc", test_connection_payload).await
+	}
+
+	/// Creates a new HTTP transport client over the endpoints of a given type
+	///
+	/// This allows dedicated endpoints, such as archive nodes, to be managed separately
+	/// from the `rpc` endpoints of the network.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs, weights, and other details
+	/// * `url_type` - Type of the `rpc_urls` to use (e.g. `rpc` or `archive`)
+	/// * `test_connection_payload` - Optional JSON RPC payload to test the connection (default is net_version)
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - New client instance or connection error
+	pub async fn new_with_url_type(
+		network: &Network,
+		url_type: &str,
+		test_connection_payload: Option<String>,
 	) -> Result<Self, anyhow::Error> {
 		let mut rpc_urls: Vec<_> = network
 			.rpc_urls
 			.iter()
-			.filter(|rpc_url| rpc_url.type_ == "rpc" && rpc_url.weight > 0)
+			.filter(|rpc_url| rpc_url.type_ == url_type && rpc_url.weight > 0)
 			.collect();
 
 		rpc_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
 
+		// Headers and authentication configured for each endpoint
+		let mut endpoint_headers = HashMap::new();
+		for rpc_url in rpc_urls.iter() {
+			endpoint_headers.insert(
+				rpc_url.url.as_ref().to_string(),
+				rpc_url_headers(rpc_url).await?,
+			);
+		}
+
 		// Create a retry policy with default settings
 		// Shared config for endpoint manager and test connection
 		let http_retry_config = HttpRetryConfig::default();
@@ -115,6 +146,7 @@ impl HttpTransportClient {
 			// Attempt to connect to the endpoint
 			let request_result = retryable_client
 				.post(url.clone())
+				.headers(endpoint_headers[rpc_url.url.as_ref()].clone())
 				.json(&test_request)
 				.send()
 				.await;
@@ -141,7 +173,8 @@ impl HttpTransportClient {
 							retryable_client,
 							rpc_url.url.as_ref(),
 							fallback_urls,
-						),
+						)
+						.with_headers(endpoint_headers),
 						test_connection_payload,
 					});
 				}
@@ -154,6 +187,109 @@ impl HttpTransportClient {
 
 		Err(anyhow::anyhow!("All RPC URLs failed to connect"))
 	}
+
+	/// Sends a JSON-RPC request to a specific endpoint instead of the active one
+	///
+	/// The active endpoint is left unchanged, and no rotation happens on failure.
+	///
+	/// # Arguments
+	/// * `url` - The URL of the endpoint to send the request to
+	/// * `method` - The JSON-RPC method name to call
+	/// * `params` - Optional parameters for the method call
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - JSON response or error with context
+	pub async fn send_raw_request_on_url<P>(
+		&self,
+		url: &str,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		self.endpoint_manager
+			.send_raw_request_on_url(url, self, method, params)
+			.await
+	}
+
+	/// Makes a fallback endpoint the active one
+	///
+	/// # Arguments
+	/// * `url` - The URL of the fallback endpoint to use for subsequent requests
+	///
+	/// # Returns
+	/// * `Result<(), TransportError>` - An error if the URL is not a fallback endpoint
+	pub async fn promote_url(&self, url: &str) -> Result<(), TransportError> {
+		self.endpoint_manager.promote_url(url).await
+	}
+
+	/// Rotates to the next fallback endpoint that accepts connections
+	///
+	/// # Returns
+	/// * `Result<String, TransportError>` - The URL of the new active endpoint, or an error if
+	///   no fallback endpoint is available
+	pub async fn rotate_url(&self) -> Result<String, TransportError> {
+		self.endpoint_manager.try_rotate_url(self).await
+	}
+}
+
+/// Builds the headers sent with each request to an RPC endpoint, from its configured
+/// headers and authentication
+///
+/// # Arguments
+/// * `rpc_url` - The RPC endpoint configuration
+///
+/// # Returns
+/// * `Result<HeaderMap, anyhow::Error>` - The headers, or an error if a secret cannot be
+///   resolved or a header is invalid
+pub(crate) async fn rpc_url_headers(rpc_url: &RpcUrl) -> Result<HeaderMap, anyhow::Error> {
+	let mut headers = HeaderMap::new();
+
+	for (name, value) in rpc_url.headers.iter().flatten() {
+		let name = HeaderName::from_bytes(name.as_bytes())
+			.with_context(|| format!("Invalid RPC header name: {}", name))?;
+		let value = value
+			.resolve()
+			.await
+			.with_context(|| format!("Failed to resolve RPC header '{}'", name))?;
+		headers.insert(name, sensitive_header_value(value.as_str())?);
+	}
+
+	let authorization = match &rpc_url.auth {
+		Some(RpcAuth::Bearer { token }) => {
+			let token = token
+				.resolve()
+				.await
+				.context("Failed to resolve RPC bearer token")?;
+			Some(format!("Bearer {}", token.as_str()))
+		}
+		Some(RpcAuth::Basic { username, password }) => {
+			let username = username
+				.resolve()
+				.await
+				.context("Failed to resolve RPC username")?;
+			let password = password
+				.resolve()
+				.await
+				.context("Failed to resolve RPC password")?;
+			let credentials = format!("{}:{}", username.as_str(), password.as_str());
+			Some(format!("Basic {}", STANDARD.encode(credentials)))
+		}
+		None => None,
+	};
+	if let Some(authorization) = authorization {
+		headers.insert(AUTHORIZATION, sensitive_header_value(&authorization)?);
+	}
+
+	Ok(headers)
+}
+
+/// Creates a header value that is redacted from debug output
+fn sensitive_header_value(value: &str) -> Result<HeaderValue, anyhow::Error> {
+	let mut value = HeaderValue::from_str(value).context("Invalid RPC header value")?;
+	value.set_sensitive(true);
+	Ok(value)
 }
 
 #[async_trait]
@@ -202,6 +338,22 @@ impl BlockchainTransport for HttpTransportClient {
 		Ok(response)
 	}
 
+	/// Sends a batch of JSON-RPC requests to the blockchain node in a single HTTP request
+	///
+	/// # Arguments
+	/// * `requests` - The JSON-RPC method names and parameters of the requests
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - JSON responses, in the order of `requests`
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		self.endpoint_manager
+			.send_batch_request(self, requests)
+			.await
+	}
+
 	/// Update endpoint manager with a new client
 	///
 	/// # Arguments
@@ -241,7 +393,11 @@ impl RotatingTransport for HttpTransportClient {
 			})
 		};
 
-		let request = self.client.post(url.clone()).json(&test_request);
+		let request = self
+			.client
+			.post(url.clone())
+			.headers(self.endpoint_manager.headers(url.as_str()))
+			.json(&test_request);
 
 		match request.send().await {
 			Ok(response) => {
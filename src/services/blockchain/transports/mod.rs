@@ -3,6 +3,8 @@
 //! Provides concrete implementations for different blockchain network protocols:
 //!
 //! - Generic HTTP transport for all chains
+//! - WebSocket slot and block subscriptions for Solana
+//! - Geyser gRPC block subscriptions for Solana
 
 mod evm {
 	pub mod http;
@@ -10,15 +12,31 @@ mod evm {
 mod stellar {
 	pub mod http;
 }
+mod solana {
+	pub mod geyser;
+	pub mod health;
+	pub mod http;
+	pub mod ws;
+}
 
 mod endpoint_manager;
 mod error;
 mod http;
+mod rate_limiter;
 
 pub use endpoint_manager::EndpointManager;
 pub use error::TransportError;
 pub use evm::http::EVMTransportClient;
+pub(crate) use http::rpc_url_headers;
 pub use http::HttpTransportClient;
+pub use rate_limiter::RateLimiter;
+pub(crate) use solana::ws::{block_requests, parse_block, BlockFetchError};
+pub use solana::{
+	geyser::SolanaGeyserTransportClient,
+	health::SolanaEndpointHealth,
+	http::SolanaTransportClient,
+	ws::{SolanaBlockUpdate, SolanaWsTransportClient},
+};
 pub use stellar::http::StellarTransportClient;
 
 use reqwest_middleware::ClientWithMiddleware;
@@ -47,6 +65,21 @@ pub trait BlockchainTransport: Send + Sync {
 	where
 		P: Into<Value> + Send + Clone + Serialize;
 
+	/// Send a batch of raw requests to the blockchain
+	///
+	/// Responses are returned in the order of the requests. By default, the requests are
+	/// sent one after the other.
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		let mut responses = Vec::with_capacity(requests.len());
+		for (method, params) in requests {
+			responses.push(self.send_raw_request(method, params.clone()).await?);
+		}
+		Ok(responses)
+	}
+
 	/// Customizes the request for specific blockchain requirements
 	async fn customize_request<P>(&self, method: &str, params: Option<P>) -> Value
 	where
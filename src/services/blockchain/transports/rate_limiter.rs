@@ -0,0 +1,145 @@
+//! Per-endpoint rate limiting for blockchain RPC endpoints
+//!
+//! Provides a token bucket for each rate limited RPC URL, so that the requests sent to an
+//! endpoint stay within its configured number of requests per second.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::models::Network;
+
+/// A token bucket refilled at a constant rate
+#[derive(Debug)]
+struct TokenBucket {
+	/// Maximum number of tokens, and number of tokens added per second
+	capacity: f64,
+	/// Tokens currently available
+	tokens: f64,
+	/// Last time the bucket was refilled
+	last_refill: Instant,
+}
+
+impl TokenBucket {
+	fn new(requests_per_second: u32) -> Self {
+		let capacity = f64::from(requests_per_second.max(1));
+		Self {
+			capacity,
+			tokens: capacity,
+			last_refill: Instant::now(),
+		}
+	}
+
+	fn refill(&mut self) {
+		let now = Instant::now();
+		let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.capacity).min(self.capacity);
+		self.last_refill = now;
+	}
+
+	/// Takes a token if one is available, otherwise returns how long until one is
+	fn try_take(&mut self) -> Result<(), Duration> {
+		self.refill();
+		if self.tokens >= 1.0 {
+			self.tokens -= 1.0;
+			Ok(())
+		} else {
+			Err(Duration::from_secs_f64((1.0 - self.tokens) / self.capacity))
+		}
+	}
+}
+
+/// Limits the number of requests per second sent to each RPC URL
+///
+/// URLs without a configured limit are never throttled.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+	buckets: Arc<HashMap<String, Mutex<TokenBucket>>>,
+}
+
+impl RateLimiter {
+	/// Creates a rate limiter from the `rate_limit` of the RPC URLs of a network
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and their rate limits
+	pub fn new(network: &Network) -> Self {
+		Self::from_limits(
+			network.rpc_urls.iter().filter_map(|rpc_url| {
+				Some((rpc_url.url.as_str().to_string(), rpc_url.rate_limit?))
+			}),
+		)
+	}
+
+	/// Creates a rate limiter from URLs and their number of requests per second
+	pub fn from_limits(limits: impl IntoIterator<Item = (String, u32)>) -> Self {
+		let buckets = limits
+			.into_iter()
+			.map(|(url, requests_per_second)| {
+				(
+					normalize_url(&url).to_string(),
+					Mutex::new(TokenBucket::new(requests_per_second)),
+				)
+			})
+			.collect();
+		Self {
+			buckets: Arc::new(buckets),
+		}
+	}
+
+	/// Returns whether no URL is rate limited
+	pub fn is_empty(&self) -> bool {
+		self.buckets.is_empty()
+	}
+
+	/// Takes a request from the budget of a URL without waiting
+	///
+	/// # Returns
+	/// * `bool` - Whether a request can be sent to the URL now
+	pub async fn try_acquire(&self, url: &str) -> bool {
+		match self.buckets.get(normalize_url(url)) {
+			Some(bucket) => bucket.lock().await.try_take().is_ok(),
+			None => true,
+		}
+	}
+
+	/// Takes a request from the budget of a URL, waiting until the budget allows it
+	pub async fn acquire(&self, url: &str) {
+		let Some(bucket) = self.buckets.get(normalize_url(url)) else {
+			return;
+		};
+		loop {
+			let wait = match bucket.lock().await.try_take() {
+				Ok(()) => return,
+				Err(wait) => wait,
+			};
+			tracing::debug!("Rate limit of {} reached, waiting {:?}", url, wait);
+			tokio::time::sleep(wait).await;
+		}
+	}
+}
+
+/// Normalizes a URL by trimming its trailing slash, as the endpoint manager does
+fn normalize_url(url: &str) -> &str {
+	url.trim_end_matches('/')
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_rate_limiter_refills_budget() {
+		let limiter = RateLimiter::from_limits([("https://limited.network/".to_string(), 10)]);
+
+		for _ in 0..10 {
+			assert!(limiter.try_acquire("https://limited.network").await);
+		}
+		assert!(!limiter.try_acquire("https://limited.network/").await);
+		assert!(limiter.try_acquire("https://unlimited.network").await);
+
+		// A token is added every 100ms
+		let start = Instant::now();
+		limiter.acquire("https://limited.network").await;
+		assert!(start.elapsed() >= Duration::from_millis(50));
+		assert!(!limiter.try_acquire("https://limited.network").await);
+	}
+}
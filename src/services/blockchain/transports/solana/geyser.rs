@@ -0,0 +1,320 @@
+//! Solana Geyser gRPC transport implementation for block subscriptions.
+//!
+//! This module provides a client that streams confirmed blocks from a Yellowstone gRPC
+//! (Dragon's Mouth) endpoint. Blocks are pushed by the validator plugin as soon as they are
+//! confirmed, with far lower latency than `blockSubscribe` and without the rate limits of
+//! `getBlock`. Blocks are delivered as the same `SolanaBlockUpdate`s as the WebSocket
+//! transport, and the subscription is re-established when the stream drops.
+
+use std::{collections::HashMap, time::Duration};
+
+use futures::{SinkExt, StreamExt};
+use solana_transaction_status::{
+	TransactionWithStatusMeta, UiTransactionStatusMeta, VersionedTransactionWithStatusMeta,
+};
+use tokio::sync::mpsc;
+use yellowstone_grpc_client::{ClientTlsConfig, GeyserGrpcClient};
+use yellowstone_grpc_proto::{
+	convert_from::create_tx_with_meta,
+	prelude::{
+		subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
+		SubscribeRequestFilterBlocks, SubscribeRequestPing, SubscribeUpdateBlock,
+		SubscribeUpdateTransactionInfo,
+	},
+};
+
+use crate::{
+	models::{Network, RpcUrl, SolanaTransaction},
+	services::blockchain::transports::SolanaBlockUpdate,
+};
+
+/// Header holding the access token of a Geyser endpoint
+const X_TOKEN_HEADER: &str = "x-token";
+
+/// Capacity of the channel blocks are delivered on
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 100;
+
+/// Identifier of the pings answering the keep-alive pings of the server
+const PING_ID: i32 = 1;
+
+/// A client streaming Solana blocks from Yellowstone gRPC endpoints
+///
+/// Endpoints are taken from the `rpc_urls` of type `geyser` and tried in order of weight.
+/// Their access token is read from the `x-token` entry of their `headers`.
+#[derive(Clone, Debug)]
+pub struct SolanaGeyserTransportClient {
+	/// Geyser endpoints, ordered by descending weight
+	endpoints: Vec<RpcUrl>,
+	/// Interval between two reconnection attempts once every endpoint failed
+	reconnect_interval: Duration,
+}
+
+impl SolanaGeyserTransportClient {
+	/// Creates a new Solana Geyser transport client
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and other network details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new client instance or configuration error
+	pub fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let mut endpoints = network
+			.rpc_urls
+			.iter()
+			.filter(|rpc_url| rpc_url.type_ == "geyser" && rpc_url.weight > 0)
+			.cloned()
+			.collect::<Vec<_>>();
+		endpoints.sort_by(|a, b| b.weight.cmp(&a.weight));
+
+		if endpoints.is_empty() {
+			return Err(anyhow::anyhow!(
+				"No Geyser URLs configured for network '{}'",
+				network.slug
+			));
+		}
+
+		Ok(Self {
+			endpoints,
+			reconnect_interval: Duration::from_millis(network.block_time_ms.max(1)) * 10,
+		})
+	}
+
+	/// Sets the interval between two reconnection attempts once every endpoint failed
+	pub fn with_reconnect_interval(mut self, reconnect_interval: Duration) -> Self {
+		self.reconnect_interval = reconnect_interval;
+		self
+	}
+
+	/// Returns the Geyser endpoints, in the order they are tried
+	pub fn geyser_urls(&self) -> Vec<&str> {
+		self.endpoints
+			.iter()
+			.map(|rpc_url| rpc_url.url.as_str())
+			.collect()
+	}
+
+	/// Subscribes to new confirmed blocks, including their full transactions
+	///
+	/// Blocks are delivered in increasing slot order until the receiver is dropped.
+	pub fn subscribe_blocks(&self) -> mpsc::Receiver<SolanaBlockUpdate> {
+		let (sender, receiver) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+		tokio::spawn(self.clone().run(sender));
+		receiver
+	}
+
+	/// Follows blocks until the subscriber goes away, moving to the next endpoint when a
+	/// stream fails
+	async fn run(self, sender: mpsc::Sender<SolanaBlockUpdate>) {
+		let mut last_slot = None;
+
+		loop {
+			for rpc_url in &self.endpoints {
+				match self.stream(rpc_url, &sender, &mut last_slot).await {
+					Ok(()) => return,
+					Err(e) => tracing::warn!(
+						"Solana Geyser subscription to {} failed: {}",
+						rpc_url.url,
+						e
+					),
+				}
+			}
+
+			tokio::select! {
+				_ = tokio::time::sleep(self.reconnect_interval) => {}
+				_ = sender.closed() => return,
+			}
+		}
+	}
+
+	/// Streams blocks from a Geyser endpoint
+	///
+	/// Returns `Ok` once the subscriber goes away, or an error when the connection
+	/// cannot be established or is lost.
+	async fn stream(
+		&self,
+		rpc_url: &RpcUrl,
+		sender: &mpsc::Sender<SolanaBlockUpdate>,
+		last_slot: &mut Option<u64>,
+	) -> Result<(), anyhow::Error> {
+		let url = rpc_url.url.resolve().await?;
+		let x_token = match rpc_url
+			.headers
+			.as_ref()
+			.and_then(|headers| headers.get(X_TOKEN_HEADER))
+		{
+			Some(token) => Some(token.resolve().await?.as_str().to_string()),
+			None => None,
+		};
+
+		let mut builder =
+			GeyserGrpcClient::build_from_shared(url.as_str().to_string())?.x_token(x_token)?;
+		if url.as_str().starts_with("https://") {
+			builder = builder.tls_config(ClientTlsConfig::new().with_native_roots())?;
+		}
+		let mut client = builder.connect().await?;
+		let (mut requests, mut updates) = client
+			.subscribe_with_request(Some(blocks_request()))
+			.await?;
+
+		tracing::debug!("Subscribed to Solana blocks over {}", rpc_url.url);
+
+		while let Some(update) = updates.next().await {
+			match update?.update_oneof {
+				Some(UpdateOneof::Block(block)) => {
+					if last_slot.is_some_and(|last| block.slot <= last) {
+						continue;
+					}
+					*last_slot = Some(block.slot);
+					if sender.send(block_update(block)).await.is_err() {
+						return Ok(());
+					}
+				}
+				// The server closes streams whose client does not answer its pings
+				Some(UpdateOneof::Ping(_)) => {
+					requests
+						.send(SubscribeRequest {
+							ping: Some(SubscribeRequestPing { id: PING_ID }),
+							..Default::default()
+						})
+						.await?;
+				}
+				_ => {}
+			}
+		}
+
+		Err(anyhow::anyhow!("Subscription closed by the server"))
+	}
+}
+
+/// Builds the request subscribing to confirmed blocks with their transactions
+fn blocks_request() -> SubscribeRequest {
+	SubscribeRequest {
+		blocks: HashMap::from([(
+			"blocks".to_string(),
+			SubscribeRequestFilterBlocks {
+				account_include: vec![],
+				include_transactions: Some(true),
+				include_accounts: Some(false),
+				include_entries: Some(false),
+			},
+		)]),
+		commitment: Some(CommitmentLevel::Confirmed as i32),
+		..Default::default()
+	}
+}
+
+/// Converts a block to an update, skipping transactions that cannot be decoded
+fn block_update(block: SubscribeUpdateBlock) -> SolanaBlockUpdate {
+	let slot = block.slot;
+	let block_time = block.block_time.map(|block_time| block_time.timestamp);
+	let transactions = block
+		.transactions
+		.into_iter()
+		.filter_map(
+			|transaction| match block_transaction(slot, block_time, transaction) {
+				Ok(transaction) => Some(transaction),
+				Err(e) => {
+					tracing::debug!("Skipping transaction of slot {}: {}", slot, e);
+					None
+				}
+			},
+		)
+		.collect();
+
+	SolanaBlockUpdate {
+		slot,
+		blockhash: block.blockhash,
+		parent_slot: block.parent_slot,
+		block_height: block
+			.block_height
+			.map(|block_height| block_height.block_height),
+		block_time,
+		transactions,
+	}
+}
+
+/// Converts a transaction of a block, with its status metadata
+fn block_transaction(
+	slot: u64,
+	block_time: Option<i64>,
+	transaction: SubscribeUpdateTransactionInfo,
+) -> Result<SolanaTransaction, anyhow::Error> {
+	let transaction = create_tx_with_meta(transaction)
+		.map_err(|e| anyhow::anyhow!("Invalid transaction: {}", e))?;
+	let TransactionWithStatusMeta::Complete(VersionedTransactionWithStatusMeta {
+		transaction,
+		meta,
+	}) = transaction
+	else {
+		return Err(anyhow::anyhow!("Transaction has no status metadata"));
+	};
+
+	Ok(SolanaTransaction::from_versioned(
+		slot,
+		block_time,
+		&transaction,
+		UiTransactionStatusMeta::from(meta).into(),
+	)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{models::BlockChainType, utils::tests::builders::network::NetworkBuilder};
+	use yellowstone_grpc_proto::prelude::UnixTimestamp;
+
+	#[test]
+	fn test_new_orders_geyser_urls_by_weight() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Solana)
+			.clear_rpc_urls()
+			.add_rpc_url("https://low.network", "geyser", 10)
+			.add_rpc_url("https://disabled.network", "geyser", 0)
+			.add_rpc_url("https://rpc.network", "rpc", 100)
+			.add_rpc_url("https://high.network", "geyser", 90)
+			.build();
+
+		let client = SolanaGeyserTransportClient::new(&network).unwrap();
+		assert_eq!(
+			client.geyser_urls(),
+			["https://high.network", "https://low.network"]
+		);
+	}
+
+	#[test]
+	fn test_new_requires_geyser_urls() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Solana)
+			.clear_rpc_urls()
+			.add_rpc_url("https://rpc.network", "rpc", 100)
+			.build();
+
+		let error = SolanaGeyserTransportClient::new(&network).unwrap_err();
+		assert!(error.to_string().contains("No Geyser URLs configured"));
+	}
+
+	#[test]
+	fn test_block_update_skips_invalid_transactions() {
+		let update = block_update(SubscribeUpdateBlock {
+			slot: 100,
+			block_time: Some(UnixTimestamp {
+				timestamp: 1678901234,
+			}),
+			transactions: vec![SubscribeUpdateTransactionInfo::default()],
+			..Default::default()
+		});
+
+		assert_eq!(update.slot, 100);
+		assert_eq!(update.block_time, Some(1678901234));
+		assert!(update.transactions.is_empty());
+	}
+
+	#[test]
+	fn test_blocks_request_includes_transactions() {
+		let request = blocks_request();
+		let filter = &request.blocks["blocks"];
+
+		assert_eq!(filter.include_transactions, Some(true));
+		assert_eq!(request.commitment, Some(CommitmentLevel::Confirmed as i32));
+	}
+}
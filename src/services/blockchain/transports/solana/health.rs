@@ -0,0 +1,197 @@
+//! Health checks of Solana RPC endpoints.
+//!
+//! Each endpoint is probed with `getHealth` and `getSlot`. Endpoints reporting themselves as
+//! unhealthy, or whose slot is too far behind the highest slot seen across the endpoints,
+//! are demoted in favour of the fastest endpoint that is keeping up.
+
+use serde_json::Value;
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::services::blockchain::transports::{BlockchainTransport, HttpTransportClient};
+
+/// Result of probing a Solana RPC endpoint
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolanaEndpointHealth {
+	/// URL of the endpoint
+	pub url: String,
+	/// Whether the endpoint answered `getHealth` with `ok`
+	pub healthy: bool,
+	/// Slot reported by the endpoint, if it answered `getSlot`
+	pub slot: Option<u64>,
+	/// Round-trip time of the `getSlot` request
+	pub latency: Option<Duration>,
+}
+
+impl SolanaEndpointHealth {
+	/// Returns whether the endpoint is healthy and at most `max_slot_lag` slots behind
+	/// `highest_slot`
+	pub fn is_keeping_up(&self, highest_slot: u64, max_slot_lag: u64) -> bool {
+		self.healthy
+			&& self
+				.slot
+				.is_some_and(|slot| slot.saturating_add(max_slot_lag) >= highest_slot)
+	}
+}
+
+/// Probes an endpoint with `getHealth` and `getSlot`
+///
+/// The requests are sent to the endpoint directly, without rotation nor rate limiting.
+///
+/// # Arguments
+/// * `http_client` - The HTTP transport client used to send the requests
+/// * `url` - The URL of the endpoint to probe
+pub(crate) async fn probe_endpoint(
+	http_client: &HttpTransportClient,
+	url: &str,
+) -> SolanaEndpointHealth {
+	let healthy = match http_client
+		.send_raw_request_on_url(url, "getHealth", None::<Value>)
+		.await
+	{
+		Ok(response) => response.get("result").and_then(Value::as_str) == Some("ok"),
+		Err(e) => {
+			tracing::debug!("Health check of {} failed: {}", url, e);
+			false
+		}
+	};
+
+	let start = Instant::now();
+	let (slot, latency) = match http_client
+		.send_raw_request_on_url(url, "getSlot", None::<Value>)
+		.await
+	{
+		Ok(response) => (
+			response.get("result").and_then(Value::as_u64),
+			Some(start.elapsed()),
+		),
+		Err(e) => {
+			tracing::debug!("Slot check of {} failed: {}", url, e);
+			(None, None)
+		}
+	};
+
+	SolanaEndpointHealth {
+		url: url.to_string(),
+		healthy,
+		slot,
+		latency,
+	}
+}
+
+/// Selects the endpoint to switch to, if the active one is not keeping up
+///
+/// # Arguments
+/// * `probes` - The health of every endpoint
+/// * `active_url` - The URL of the active endpoint
+/// * `max_slot_lag` - Number of slots an endpoint may be behind the highest slot
+///
+/// # Returns
+/// * `Option<&str>` - The fastest endpoint keeping up, or `None` to keep the active one
+pub(crate) fn select_endpoint<'a>(
+	probes: &'a [SolanaEndpointHealth],
+	active_url: &str,
+	max_slot_lag: u64,
+) -> Option<&'a str> {
+	let highest_slot = probes
+		.iter()
+		.filter(|probe| probe.healthy)
+		.filter_map(|probe| probe.slot)
+		.max()?;
+
+	let active_url = active_url.trim_end_matches('/');
+	let active_keeps_up = probes.iter().any(|probe| {
+		probe.url.trim_end_matches('/') == active_url
+			&& probe.is_keeping_up(highest_slot, max_slot_lag)
+	});
+	if active_keeps_up {
+		return None;
+	}
+
+	probes
+		.iter()
+		.filter(|probe| probe.is_keeping_up(highest_slot, max_slot_lag))
+		.min_by_key(|probe| probe.latency.unwrap_or(Duration::MAX))
+		.map(|probe| probe.url.as_str())
+}
+
+/// Probes every endpoint and makes the best one active if the active one is not keeping up
+///
+/// # Arguments
+/// * `http_client` - The HTTP transport client whose active endpoint is managed
+/// * `urls` - The URLs of the endpoints
+/// * `max_slot_lag` - Number of slots an endpoint may be behind the highest slot
+///
+/// # Returns
+/// * `Vec<SolanaEndpointHealth>` - The health of every endpoint
+pub(crate) async fn check_endpoints(
+	http_client: &HttpTransportClient,
+	urls: &[String],
+	max_slot_lag: u64,
+) -> Vec<SolanaEndpointHealth> {
+	let probes = futures::future::join_all(
+		urls.iter()
+			.map(|url| probe_endpoint(http_client, url.as_str())),
+	)
+	.await;
+
+	let active_url = http_client.get_current_url().await;
+	if let Some(url) = select_endpoint(&probes, &active_url, max_slot_lag) {
+		tracing::warn!(
+			"RPC endpoint {} is unhealthy or lagging, switching to {}",
+			active_url,
+			url
+		);
+		if let Err(e) = http_client.promote_url(url).await {
+			tracing::error!("Failed to switch to RPC endpoint {}: {}", url, e);
+		}
+	}
+
+	probes
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn probe(url: &str, healthy: bool, slot: Option<u64>, latency_ms: u64) -> SolanaEndpointHealth {
+		SolanaEndpointHealth {
+			url: url.to_string(),
+			healthy,
+			slot,
+			latency: slot.map(|_| Duration::from_millis(latency_ms)),
+		}
+	}
+
+	#[test]
+	fn test_select_endpoint() {
+		let probes = vec![
+			probe("https://a.network", true, Some(100), 10),
+			probe("https://b.network", true, Some(200), 50),
+			probe("https://c.network", true, Some(190), 20),
+			probe("https://d.network", false, Some(300), 1),
+		];
+
+		// The active endpoint keeps up, even if it is not the fastest one
+		assert_eq!(select_endpoint(&probes, "https://b.network/", 50), None);
+		// The lagging endpoint is demoted in favour of the fastest one keeping up
+		assert_eq!(
+			select_endpoint(&probes, "https://a.network", 50),
+			Some("https://c.network")
+		);
+		// Unhealthy endpoints are demoted and ignored for the highest slot
+		assert_eq!(
+			select_endpoint(&probes, "https://d.network", 5),
+			Some("https://b.network")
+		);
+		// Nothing to do without any healthy endpoint
+		assert_eq!(
+			select_endpoint(
+				&[probe("https://a.network", false, None, 0)],
+				"https://a.network",
+				50
+			),
+			None
+		);
+	}
+}
@@ -0,0 +1,472 @@
+//! Solana transport implementation for blockchain interactions.
+//!
+//! This module provides a client implementation for interacting with Solana nodes
+//! by wrapping the HttpTransportClient. This allows for consistent behavior with other
+//! transport implementations while providing specific Solana-focused functionality.
+//!
+//! Requests are kept within the `rate_limit` configured for each RPC URL. When the active
+//! endpoint has no budget left, requests spill over to the next endpoints by weight.
+//!
+//! Responses of idempotent calls about a given slot are kept in a small LRU cache, so that
+//! multiple monitors on the same network do not fetch the same block twice.
+//!
+//! When several RPC URLs are configured, the endpoints are periodically probed, and an active
+//! endpoint that is unhealthy or lagging behind the others is replaced by the fastest one.
+//! Requests answered by an endpoint reporting itself unhealthy are sent again to the next
+//! endpoint, as are requests spilled over to an endpoint that fails them.
+//!
+//! Endpoints of type `archive` are kept apart, and only used for old slots that the `rpc`
+//! endpoints no longer have in storage.
+
+use lru::LruCache;
+use reqwest_middleware::ClientWithMiddleware;
+use serde::Serialize;
+use serde_json::Value;
+use std::{num::NonZeroUsize, sync::Arc, time::Duration};
+use tokio::{sync::Mutex, task::JoinHandle};
+
+use crate::{
+	models::Network,
+	services::blockchain::transports::{
+		solana::health::{check_endpoints, SolanaEndpointHealth},
+		BlockchainTransport, HttpTransportClient, RateLimiter, RotatingTransport, TransportError,
+	},
+};
+
+/// RPC methods whose responses only depend on their parameters and can be cached
+const CACHED_METHODS: [&str; 2] = ["getBlock", "getBlockTime"];
+
+/// Maximum number of responses kept in the cache
+const RESPONSE_CACHE_SIZE: usize = 64;
+
+/// Interval between two health checks of the endpoints
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Number of slots an endpoint may be behind the others before being demoted (~1 minute)
+const DEFAULT_MAX_SLOT_LAG: u64 = 150;
+
+/// JSON-RPC error code of a node that is unhealthy, e.g. behind the cluster
+const NODE_UNHEALTHY_ERROR_CODE: i64 = -32005;
+
+/// Type of the `rpc_urls` pointing to archive nodes
+const ARCHIVE_URL_TYPE: &str = "archive";
+
+/// Payload used to test the connection to an endpoint
+const TEST_CONNECTION_PAYLOAD: &str =
+	r#"{"id":1,"jsonrpc":"2.0","method":"getHealth","params":[]}"#;
+
+/// Background health check task, aborted once the last client sharing it is dropped
+#[derive(Debug)]
+struct HealthCheckTask(JoinHandle<()>);
+
+impl Drop for HealthCheckTask {
+	fn drop(&mut self) {
+		self.0.abort();
+	}
+}
+
+/// A client for interacting with Solana blockchain nodes
+///
+/// This implementation wraps the HttpTransportClient to provide consistent
+/// behavior with other transport implementations while offering Solana-specific
+/// functionality. It handles connection management, request retries, and
+/// endpoint rotation for Solana networks.
+#[derive(Clone, Debug)]
+pub struct SolanaTransportClient {
+	/// The underlying HTTP transport client that handles actual RPC communications
+	http_client: HttpTransportClient,
+	/// Request budgets of the rate limited RPC URLs
+	rate_limiter: RateLimiter,
+	/// RPC URLs ordered by descending weight, used to spill over requests
+	urls: Vec<String>,
+	/// Responses of the cached methods, keyed by method and parameters
+	response_cache: Arc<Mutex<LruCache<String, Value>>>,
+	/// Periodic health checks of the endpoints, when there are several of them
+	_health_check: Option<Arc<HealthCheckTask>>,
+	/// Transport over the archive endpoints, if the network configures any
+	archive_client: Option<HttpTransportClient>,
+}
+
+impl SolanaTransportClient {
+	/// Creates a new Solana transport client by initializing an HTTP transport client
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and other network details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new client instance or connection error
+	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		let http_client =
+			HttpTransportClient::new(network, Some(TEST_CONNECTION_PAYLOAD.to_string())).await?;
+
+		let mut rpc_urls = network
+			.rpc_urls
+			.iter()
+			.filter(|rpc_url| rpc_url.type_ == "rpc" && rpc_url.weight > 0)
+			.collect::<Vec<_>>();
+		rpc_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
+		let urls = rpc_urls
+			.into_iter()
+			.map(|rpc_url| rpc_url.url.as_str().to_string())
+			.collect::<Vec<_>>();
+
+		let health_check = (urls.len() > 1).then(|| {
+			let http_client = http_client.clone();
+			let urls = urls.clone();
+			Arc::new(HealthCheckTask(tokio::spawn(async move {
+				let mut interval = tokio::time::interval_at(
+					tokio::time::Instant::now() + HEALTH_CHECK_INTERVAL,
+					HEALTH_CHECK_INTERVAL,
+				);
+				loop {
+					interval.tick().await;
+					check_endpoints(&http_client, &urls, DEFAULT_MAX_SLOT_LAG).await;
+				}
+			})))
+		});
+
+		Ok(Self {
+			http_client,
+			rate_limiter: RateLimiter::new(network),
+			urls,
+			response_cache: Arc::new(Mutex::new(LruCache::new(
+				NonZeroUsize::new(RESPONSE_CACHE_SIZE).expect("Cache size must not be zero"),
+			))),
+			_health_check: health_check,
+			archive_client: Self::connect_archive(network).await,
+		})
+	}
+
+	/// Connects to the archive endpoints of a network, if any
+	///
+	/// Archive endpoints that cannot be reached are logged and ignored, so that recent
+	/// slots can still be served by the `rpc` endpoints.
+	async fn connect_archive(network: &Network) -> Option<HttpTransportClient> {
+		if !network
+			.rpc_urls
+			.iter()
+			.any(|rpc_url| rpc_url.type_ == ARCHIVE_URL_TYPE)
+		{
+			return None;
+		}
+
+		match HttpTransportClient::new_with_url_type(
+			network,
+			ARCHIVE_URL_TYPE,
+			Some(TEST_CONNECTION_PAYLOAD.to_string()),
+		)
+		.await
+		{
+			Ok(client) => Some(client),
+			Err(e) => {
+				tracing::warn!(
+					"Archive endpoints unavailable for network '{}': {}",
+					network.slug,
+					e
+				);
+				None
+			}
+		}
+	}
+
+	/// Returns the transport over the archive endpoints, if the network configures any
+	///
+	/// Archive endpoints serve old slots that the `rpc` endpoints no longer have in
+	/// long-term storage.
+	pub fn archive_client(&self) -> Option<&HttpTransportClient> {
+		self.archive_client.as_ref()
+	}
+
+	/// Probes every endpoint with `getHealth` and `getSlot`
+	///
+	/// If the active endpoint is unhealthy or more than `max_slot_lag` slots behind the
+	/// highest slot reported by the healthy endpoints, the fastest endpoint keeping up becomes
+	/// the active one. This is done periodically in the background, using
+	/// `DEFAULT_MAX_SLOT_LAG`, when several endpoints are configured.
+	///
+	/// # Arguments
+	/// * `max_slot_lag` - Number of slots an endpoint may be behind the highest slot
+	///
+	/// # Returns
+	/// * `Vec<SolanaEndpointHealth>` - The health of every endpoint, by descending weight
+	pub async fn check_endpoints(&self, max_slot_lag: u64) -> Vec<SolanaEndpointHealth> {
+		check_endpoints(&self.http_client, &self.urls, max_slot_lag).await
+	}
+
+	/// Sends a request, within the rate limits of the endpoints
+	async fn send_rate_limited_request<P>(
+		&self,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		if !self.rate_limiter.is_empty() {
+			let active_url = self.http_client.get_current_url().await;
+			if !self.rate_limiter.try_acquire(&active_url).await {
+				if let Some(url) = self.spill_over_url(&active_url).await {
+					tracing::debug!(
+						"Rate limit of {} reached, sending request to {}",
+						active_url,
+						url
+					);
+					match self
+						.http_client
+						.send_raw_request_on_url(url, method, params.clone())
+						.await
+					{
+						Ok(response) if !is_node_unhealthy(&response) => return Ok(response),
+						Ok(_) => tracing::warn!(
+							"RPC endpoint {} is unhealthy, sending request to {}",
+							url,
+							active_url
+						),
+						Err(e) => tracing::warn!(
+							"Request to {} failed, sending request to {}: {}",
+							url,
+							active_url,
+							e
+						),
+					}
+				}
+				self.rate_limiter.acquire(&active_url).await;
+			}
+		}
+
+		let mut response = self
+			.http_client
+			.send_raw_request(method, params.clone())
+			.await?;
+		for _ in 1..self.urls.len() {
+			if !is_node_unhealthy(&response) || !self.rotate_unhealthy_endpoint().await {
+				break;
+			}
+			response = self
+				.http_client
+				.send_raw_request(method, params.clone())
+				.await?;
+		}
+
+		Ok(response)
+	}
+
+	/// Rotates away from the active endpoint after it reported itself unhealthy
+	///
+	/// # Returns
+	/// * `bool` - Whether another endpoint is now active
+	async fn rotate_unhealthy_endpoint(&self) -> bool {
+		let active_url = self.http_client.get_current_url().await;
+		match self.http_client.rotate_url().await {
+			Ok(url) => {
+				tracing::warn!(
+					"RPC endpoint {} is unhealthy, switching to {}",
+					active_url,
+					url
+				);
+				true
+			}
+			Err(e) => {
+				tracing::warn!("RPC endpoint {} is unhealthy: {}", active_url, e);
+				false
+			}
+		}
+	}
+
+	/// Finds an endpoint with budget left other than the active one
+	///
+	/// # Arguments
+	/// * `active_url` - The currently active RPC endpoint URL
+	///
+	/// # Returns
+	/// * `Option<&str>` - The endpoint with the highest weight that can take a request
+	async fn spill_over_url(&self, active_url: &str) -> Option<&str> {
+		for url in &self.urls {
+			if url.trim_end_matches('/') == active_url.trim_end_matches('/') {
+				continue;
+			}
+			if self.rate_limiter.try_acquire(url).await {
+				return Some(url);
+			}
+		}
+		None
+	}
+}
+
+/// Returns the cache key of a request, if its method is cached
+fn cache_key(method: &str, params: Option<Value>) -> Option<String> {
+	CACHED_METHODS
+		.contains(&method)
+		.then(|| format!("{}:{}", method, params.unwrap_or(Value::Null)))
+}
+
+/// Returns whether a response is the error of an unhealthy node
+fn is_node_unhealthy(response: &Value) -> bool {
+	response
+		.get("error")
+		.and_then(|error| error.get("code"))
+		.and_then(Value::as_i64)
+		== Some(NODE_UNHEALTHY_ERROR_CODE)
+}
+
+/// Returns whether a response can be cached
+///
+/// Errors and missing results, e.g. for blocks not produced yet, are not cached.
+fn is_cacheable(response: &Value) -> bool {
+	response.get("error").is_none()
+		&& response
+			.get("result")
+			.is_some_and(|result| !result.is_null())
+}
+
+#[async_trait::async_trait]
+impl BlockchainTransport for SolanaTransportClient {
+	/// Gets the current active RPC URL
+	///
+	/// # Returns
+	/// * `String` - The currently active RPC endpoint URL
+	async fn get_current_url(&self) -> String {
+		self.http_client.get_current_url().await
+	}
+
+	/// Sends a raw JSON-RPC request to the Solana node
+	///
+	/// Requests exceeding the rate limit of the active endpoint are sent to the next
+	/// endpoint with budget left, or wait for the budget of the active endpoint. Requests
+	/// answered by an unhealthy node are sent again to the next endpoint.
+	/// Responses of `getBlock` and `getBlockTime` are served from the cache when possible.
+	///
+	/// # Arguments
+	/// * `method` - The JSON-RPC method to call
+	/// * `params` - Optional parameters to pass with the request
+	///
+	/// # Returns
+	/// * `Result<Value, TransportError>` - The JSON response or error
+	async fn send_raw_request<P>(
+		&self,
+		method: &str,
+		params: Option<P>,
+	) -> Result<Value, TransportError>
+	where
+		P: Into<Value> + Send + Clone + Serialize,
+	{
+		let Some(key) = cache_key(method, params.clone().map(Into::into)) else {
+			return self.send_rate_limited_request(method, params).await;
+		};
+		if let Some(response) = self.response_cache.lock().await.get(&key) {
+			tracing::debug!("Using cached response for {}", key);
+			return Ok(response.clone());
+		}
+
+		let response = self.send_rate_limited_request(method, params).await?;
+		if is_cacheable(&response) {
+			self.response_cache.lock().await.put(key, response.clone());
+		}
+
+		Ok(response)
+	}
+
+	/// Sends a batch of JSON-RPC requests to the Solana node in a single HTTP request
+	///
+	/// Cached responses are not requested again. Each request of the batch is counted
+	/// against the rate limit of the active endpoint. The batch is sent again to the next
+	/// endpoint if the active one reports itself unhealthy.
+	///
+	/// # Arguments
+	/// * `requests` - The JSON-RPC methods and parameters of the requests
+	///
+	/// # Returns
+	/// * `Result<Vec<Value>, TransportError>` - The JSON responses, in the order of `requests`
+	async fn send_batch_request(
+		&self,
+		requests: &[(&str, Option<Value>)],
+	) -> Result<Vec<Value>, TransportError> {
+		let keys = requests
+			.iter()
+			.map(|(method, params)| cache_key(method, params.clone()))
+			.collect::<Vec<_>>();
+		let mut responses = {
+			let mut cache = self.response_cache.lock().await;
+			keys.iter()
+				.map(|key| key.as_ref().and_then(|key| cache.get(key).cloned()))
+				.collect::<Vec<_>>()
+		};
+
+		let pending = (0..requests.len())
+			.filter(|&index| responses[index].is_none())
+			.collect::<Vec<_>>();
+		if !pending.is_empty() {
+			let active_url = self.http_client.get_current_url().await;
+			for _ in &pending {
+				self.rate_limiter.acquire(&active_url).await;
+			}
+
+			let pending_requests = pending
+				.iter()
+				.map(|&index| requests[index].clone())
+				.collect::<Vec<_>>();
+			let mut fetched = self
+				.http_client
+				.send_batch_request(&pending_requests)
+				.await?;
+			for _ in 1..self.urls.len() {
+				if !fetched.iter().any(is_node_unhealthy) || !self.rotate_unhealthy_endpoint().await
+				{
+					break;
+				}
+				fetched = self
+					.http_client
+					.send_batch_request(&pending_requests)
+					.await?;
+			}
+
+			let mut cache = self.response_cache.lock().await;
+			for (index, response) in pending.into_iter().zip(fetched) {
+				if let Some(key) = &keys[index] {
+					if is_cacheable(&response) {
+						cache.put(key.clone(), response.clone());
+					}
+				}
+				responses[index] = Some(response);
+			}
+		}
+
+		Ok(responses.into_iter().flatten().collect())
+	}
+
+	/// Update endpoint manager with a new client
+	///
+	/// # Arguments
+	/// * `client` - The new client to use for the endpoint manager
+	fn update_endpoint_manager_client(
+		&mut self,
+		client: ClientWithMiddleware,
+	) -> Result<(), anyhow::Error> {
+		self.http_client.update_endpoint_manager_client(client)
+	}
+}
+
+#[async_trait::async_trait]
+impl RotatingTransport for SolanaTransportClient {
+	/// Tests connection to a specific URL
+	///
+	/// # Arguments
+	/// * `url` - The URL to test connection with
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error status
+	async fn try_connect(&self, url: &str) -> Result<(), anyhow::Error> {
+		self.http_client.try_connect(url).await
+	}
+
+	/// Updates the client to use a new URL
+	///
+	/// # Arguments
+	/// * `url` - The new URL to use for subsequent requests
+	///
+	/// # Returns
+	/// * `Result<(), anyhow::Error>` - Success or error status
+	async fn update_client(&self, url: &str) -> Result<(), anyhow::Error> {
+		self.http_client.update_client(url).await
+	}
+}
@@ -0,0 +1,769 @@
+//! Solana WebSocket transport implementation for slot and block subscriptions.
+//!
+//! This module provides a client that follows new slots and blocks through the
+//! `slotSubscribe` and `blockSubscribe` methods of a Solana node. Subscriptions are
+//! re-established automatically when a connection drops, and the client falls back
+//! to polling the HTTP RPC endpoints while no WebSocket endpoint is reachable.
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::{json, Value};
+use solana_client::{
+	nonblocking::pubsub_client::PubsubClient,
+	rpc_config::{RpcBlockSubscribeConfig, RpcBlockSubscribeFilter},
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_transaction_status::{
+	EncodedConfirmedTransactionWithStatusMeta, TransactionDetails, UiConfirmedBlock,
+	UiTransactionEncoding,
+};
+use tokio::sync::mpsc;
+
+use crate::{
+	models::{Network, SolanaTransaction},
+	services::blockchain::transports::{BlockchainTransport, SolanaTransportClient},
+};
+
+/// Solana RPC method constants used while polling over HTTP
+const RPC_METHOD_GET_SLOT: &str = "getSlot";
+const RPC_METHOD_GET_BLOCK: &str = "getBlock";
+const RPC_METHOD_GET_BLOCKS: &str = "getBlocks";
+
+/// JSON-RPC error codes returned by `getBlock` for slots missing from the long-term storage
+/// of a node, either because the slot was skipped or because the node no longer has it
+const LONG_TERM_STORAGE_ERROR_CODES: [i64; 2] = [-32007, -32009];
+
+/// Capacity of the channels subscriptions are delivered on
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 100;
+
+/// Maximum number of slots fetched by a single poll while catching up over HTTP
+const MAX_POLLED_SLOTS: u64 = 50;
+
+/// A block received from a `blockSubscribe` subscription or fetched while polling
+#[derive(Debug, Clone)]
+pub struct SolanaBlockUpdate {
+	/// The slot of the block
+	pub slot: u64,
+	/// The hash of the block
+	pub blockhash: String,
+	/// The slot of the parent block
+	pub parent_slot: u64,
+	/// The height of the block, if known
+	pub block_height: Option<u64>,
+	/// The Unix timestamp of when the block was produced
+	pub block_time: Option<i64>,
+	/// The transactions of the block, with their status metadata
+	pub transactions: Vec<SolanaTransaction>,
+}
+
+/// Reasons a block cannot be fetched while polling
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum BlockFetchError {
+	/// The slot was skipped by its leader and has no block
+	#[error("Slot {0} was skipped")]
+	SlotSkipped(u64),
+	/// The node does not have the slot in its long-term storage
+	#[error("Slot {0} is not available in long-term storage")]
+	NotInStorage(u64),
+	/// The block exists but could not be fetched or parsed
+	#[error("Failed to fetch block {slot}: {source}")]
+	Rpc {
+		slot: u64,
+		#[source]
+		source: anyhow::Error,
+	},
+}
+
+/// The kind of updates a subscription delivers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubscriptionKind {
+	Slots,
+	Blocks,
+}
+
+/// An update delivered to a subscriber
+enum Update {
+	Slot(u64),
+	Block(SolanaBlockUpdate),
+}
+
+impl Update {
+	fn slot(&self) -> u64 {
+		match self {
+			Update::Slot(slot) => *slot,
+			Update::Block(block) => block.slot,
+		}
+	}
+}
+
+/// A client following Solana slots and blocks over WebSocket subscriptions
+///
+/// WebSocket endpoints are taken from the `rpc_urls` of type `ws` and tried in order of
+/// weight. When none of them can be reached, new slots and blocks are polled from the
+/// `rpc` endpoints until a WebSocket connection can be established again.
+#[derive(Clone, Debug)]
+pub struct SolanaWsTransportClient {
+	/// WebSocket endpoints, ordered by descending weight
+	ws_urls: Vec<String>,
+	/// HTTP transport used to poll while no WebSocket endpoint is reachable
+	http_client: Option<SolanaTransportClient>,
+	/// Interval between two polls, and between two reconnection attempts
+	poll_interval: Duration,
+	/// Time spent polling over HTTP before WebSocket endpoints are retried
+	reconnect_interval: Duration,
+}
+
+impl SolanaWsTransportClient {
+	/// Creates a new Solana WebSocket transport client
+	///
+	/// The HTTP fallback is only available if the network also defines `rpc` endpoints
+	/// and one of them can be reached.
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and other network details
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new client instance or configuration error
+	pub async fn new(network: &Network) -> Result<Self, anyhow::Error> {
+		ws_urls(network)?;
+
+		let http_client = if network
+			.rpc_urls
+			.iter()
+			.any(|rpc_url| rpc_url.type_ == "rpc")
+		{
+			match SolanaTransportClient::new(network).await {
+				Ok(client) => Some(client),
+				Err(e) => {
+					tracing::warn!(
+						"HTTP polling fallback unavailable for network '{}': {}",
+						network.slug,
+						e
+					);
+					None
+				}
+			}
+		} else {
+			None
+		};
+
+		Self::new_with_http_client(network, http_client)
+	}
+
+	/// Creates a new Solana WebSocket transport client polling over an existing HTTP
+	/// transport client while no WebSocket endpoint is reachable
+	///
+	/// # Arguments
+	/// * `network` - Network configuration containing RPC URLs and other network details
+	/// * `http_client` - HTTP transport client of the network, if any
+	///
+	/// # Returns
+	/// * `Result<Self, anyhow::Error>` - A new client instance or configuration error
+	pub fn new_with_http_client(
+		network: &Network,
+		http_client: Option<SolanaTransportClient>,
+	) -> Result<Self, anyhow::Error> {
+		let poll_interval = Duration::from_millis(network.block_time_ms.max(1));
+		Ok(Self {
+			ws_urls: ws_urls(network)?,
+			http_client,
+			poll_interval,
+			reconnect_interval: poll_interval * 10,
+		})
+	}
+
+	/// Sets the time spent polling over HTTP before WebSocket endpoints are retried
+	pub fn with_reconnect_interval(mut self, reconnect_interval: Duration) -> Self {
+		self.reconnect_interval = reconnect_interval;
+		self
+	}
+
+	/// Returns the WebSocket endpoints, in the order they are tried
+	pub fn ws_urls(&self) -> &[String] {
+		&self.ws_urls
+	}
+
+	/// Returns whether new slots can be polled over HTTP when WebSockets are unavailable
+	pub fn has_http_fallback(&self) -> bool {
+		self.http_client.is_some()
+	}
+
+	/// Subscribes to new slots
+	///
+	/// Slots are delivered in increasing order until the receiver is dropped.
+	pub fn subscribe_slots(&self) -> mpsc::Receiver<u64> {
+		let (sender, receiver) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+		let (update_sender, mut update_receiver) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+		tokio::spawn(self.clone().run(SubscriptionKind::Slots, update_sender));
+		tokio::spawn(async move {
+			while let Some(update) = update_receiver.recv().await {
+				if sender.send(update.slot()).await.is_err() {
+					break;
+				}
+			}
+		});
+		receiver
+	}
+
+	/// Subscribes to new confirmed blocks, including their full transactions
+	///
+	/// Blocks are delivered in increasing slot order until the receiver is dropped.
+	pub fn subscribe_blocks(&self) -> mpsc::Receiver<SolanaBlockUpdate> {
+		let (sender, receiver) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+		let (update_sender, mut update_receiver) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+		tokio::spawn(self.clone().run(SubscriptionKind::Blocks, update_sender));
+		tokio::spawn(async move {
+			while let Some(update) = update_receiver.recv().await {
+				if let Update::Block(block) = update {
+					if sender.send(block).await.is_err() {
+						break;
+					}
+				}
+			}
+		});
+		receiver
+	}
+
+	/// Follows updates until the subscriber goes away, switching between WebSocket
+	/// endpoints and HTTP polling as they become unavailable
+	async fn run(self, kind: SubscriptionKind, sender: mpsc::Sender<Update>) {
+		let mut last_slot = None;
+
+		loop {
+			for url in &self.ws_urls {
+				match self.stream(url, kind, &sender, &mut last_slot).await {
+					Ok(()) => return,
+					Err(e) => {
+						tracing::warn!("Solana WebSocket subscription to {} failed: {}", url, e)
+					}
+				}
+			}
+
+			let closed = match &self.http_client {
+				Some(http_client) => {
+					tracing::info!(
+						"Falling back to HTTP polling until a WebSocket endpoint is reachable"
+					);
+					self.poll(http_client, kind, &sender, &mut last_slot).await
+				}
+				None => {
+					tokio::select! {
+						_ = tokio::time::sleep(self.poll_interval) => false,
+						_ = sender.closed() => true,
+					}
+				}
+			};
+			if closed {
+				return;
+			}
+		}
+	}
+
+	/// Streams updates from a WebSocket endpoint
+	///
+	/// Returns `Ok` once the subscriber goes away, or an error when the connection
+	/// cannot be established or is lost.
+	async fn stream(
+		&self,
+		url: &str,
+		kind: SubscriptionKind,
+		sender: &mpsc::Sender<Update>,
+		last_slot: &mut Option<u64>,
+	) -> Result<(), anyhow::Error> {
+		let client = PubsubClient::new(url).await?;
+
+		let (mut updates, unsubscribe) = match kind {
+			SubscriptionKind::Slots => {
+				let (stream, unsubscribe) = client.slot_subscribe().await?;
+				(
+					stream.map(|info| Some(Update::Slot(info.slot))).boxed(),
+					unsubscribe,
+				)
+			}
+			SubscriptionKind::Blocks => {
+				let config = RpcBlockSubscribeConfig {
+					commitment: Some(CommitmentConfig::confirmed()),
+					encoding: Some(UiTransactionEncoding::Base64),
+					transaction_details: Some(TransactionDetails::Full),
+					show_rewards: Some(false),
+					max_supported_transaction_version: Some(0),
+				};
+				let (stream, unsubscribe) = client
+					.block_subscribe(RpcBlockSubscribeFilter::All, Some(config))
+					.await?;
+				(
+					stream
+						.map(|response| {
+							let update = response.value;
+							if let Some(err) = update.err {
+								tracing::warn!(
+									"Solana block {} update failed: {:?}",
+									update.slot,
+									err
+								);
+								return None;
+							}
+							update
+								.block
+								.map(|block| Update::Block(block_update(update.slot, block)))
+						})
+						.boxed(),
+					unsubscribe,
+				)
+			}
+		};
+
+		tracing::debug!("Subscribed to Solana {:?} over {}", kind, url);
+
+		while let Some(update) = updates.next().await {
+			let Some(update) = update else {
+				continue;
+			};
+			if last_slot.is_some_and(|last| update.slot() <= last) {
+				continue;
+			}
+			*last_slot = Some(update.slot());
+			if sender.send(update).await.is_err() {
+				drop(updates);
+				unsubscribe().await;
+				return Ok(());
+			}
+		}
+
+		Err(anyhow::anyhow!("Subscription closed by the server"))
+	}
+
+	/// Polls updates over HTTP until it is time to retry WebSocket endpoints
+	///
+	/// Subscriptions only deliver new blocks, so polling goes on past the retry interval
+	/// until the blocks missed since `last_slot` have been caught up.
+	///
+	/// Returns whether the subscriber went away.
+	async fn poll(
+		&self,
+		http_client: &SolanaTransportClient,
+		kind: SubscriptionKind,
+		sender: &mpsc::Sender<Update>,
+		last_slot: &mut Option<u64>,
+	) -> bool {
+		let deadline = tokio::time::Instant::now() + self.reconnect_interval;
+		let mut caught_up = true;
+
+		while !caught_up || tokio::time::Instant::now() < deadline {
+			match poll_updates(http_client, kind, *last_slot).await {
+				Ok(polled) => {
+					for update in polled.updates {
+						*last_slot = Some(update.slot());
+						if sender.send(update).await.is_err() {
+							return true;
+						}
+					}
+					// Skipped slots after the last block do not need to be listed again
+					*last_slot = (*last_slot).max(polled.polled_slot);
+					caught_up = polled.caught_up;
+					if !caught_up {
+						continue;
+					}
+				}
+				Err(e) => tracing::warn!("Failed to poll Solana {:?} over HTTP: {}", kind, e),
+			}
+
+			tokio::select! {
+				_ = tokio::time::sleep(self.poll_interval) => {}
+				_ = sender.closed() => return true,
+			}
+		}
+
+		false
+	}
+}
+
+/// Returns the WebSocket endpoints of a network, ordered by descending weight
+fn ws_urls(network: &Network) -> Result<Vec<String>, anyhow::Error> {
+	let mut ws_urls = network
+		.rpc_urls
+		.iter()
+		.filter(|rpc_url| rpc_url.type_ == "ws" && rpc_url.weight > 0)
+		.collect::<Vec<_>>();
+	ws_urls.sort_by(|a, b| b.weight.cmp(&a.weight));
+
+	if ws_urls.is_empty() {
+		return Err(anyhow::anyhow!(
+			"No WebSocket URLs configured for network '{}'",
+			network.slug
+		));
+	}
+
+	Ok(ws_urls
+		.into_iter()
+		.map(|rpc_url| rpc_url.url.as_str().to_string())
+		.collect())
+}
+
+/// Updates fetched by a poll
+struct PolledUpdates {
+	/// Updates produced since the last poll, in slot order
+	updates: Vec<Update>,
+	/// Slot the updates were polled up to
+	polled_slot: Option<u64>,
+	/// Whether the updates reach the current slot of the node
+	caught_up: bool,
+}
+
+/// Fetches the updates produced since `last_slot`
+///
+/// Blocks are fetched at most `MAX_POLLED_SLOTS` slots at a time, so a poll that is behind
+/// the node returns the oldest missed blocks and reports that it has not caught up yet.
+async fn poll_updates(
+	http_client: &SolanaTransportClient,
+	kind: SubscriptionKind,
+	last_slot: Option<u64>,
+) -> Result<PolledUpdates, anyhow::Error> {
+	let params = json!([{ "commitment": "confirmed" }]);
+	let result = rpc_result(http_client, RPC_METHOD_GET_SLOT, params).await?;
+	let current_slot = result
+		.as_u64()
+		.ok_or_else(|| anyhow::anyhow!("Invalid slot in response: {}", result))?;
+
+	let Some((first_slot, end_slot)) = polled_range(last_slot, current_slot) else {
+		return Ok(PolledUpdates {
+			updates: vec![],
+			polled_slot: None,
+			caught_up: true,
+		});
+	};
+
+	match kind {
+		SubscriptionKind::Slots => Ok(PolledUpdates {
+			updates: vec![Update::Slot(current_slot)],
+			polled_slot: Some(current_slot),
+			caught_up: true,
+		}),
+		SubscriptionKind::Blocks => {
+			let slots = get_confirmed_slots(http_client, first_slot, end_slot).await?;
+			let mut updates = Vec::with_capacity(slots.len());
+			for block in get_blocks(http_client, &slots).await? {
+				match block {
+					Ok(block) => updates.push(Update::Block(block)),
+					Err(BlockFetchError::SlotSkipped(slot)) => {
+						tracing::debug!("Solana slot {} was skipped", slot)
+					}
+					Err(e) => return Err(e.into()),
+				}
+			}
+			Ok(PolledUpdates {
+				updates,
+				polled_slot: Some(end_slot),
+				caught_up: end_slot == current_slot,
+			})
+		}
+	}
+}
+
+/// Returns the range of slots (inclusive) to poll after `last_slot`, at most
+/// `MAX_POLLED_SLOTS` long, or `None` when there is nothing new
+///
+/// Polling starts at the current slot when no slot was seen yet.
+fn polled_range(last_slot: Option<u64>, current_slot: u64) -> Option<(u64, u64)> {
+	match last_slot {
+		Some(last) if last >= current_slot => None,
+		Some(last) => Some((last + 1, current_slot.min(last + MAX_POLLED_SLOTS))),
+		None => Some((current_slot, current_slot)),
+	}
+}
+
+/// Lists the slots between `start_slot` and `end_slot` (inclusive) that have a confirmed
+/// block, so that skipped slots are not requested
+async fn get_confirmed_slots(
+	http_client: &SolanaTransportClient,
+	start_slot: u64,
+	end_slot: u64,
+) -> Result<Vec<u64>, anyhow::Error> {
+	let params = json!([start_slot, end_slot, { "commitment": "confirmed" }]);
+	let result = rpc_result(http_client, RPC_METHOD_GET_BLOCKS, params).await?;
+	serde_json::from_value(result).map_err(|e| {
+		anyhow::anyhow!(
+			"Invalid slots in '{}' response: {}",
+			RPC_METHOD_GET_BLOCKS,
+			e
+		)
+	})
+}
+
+/// Fetches confirmed blocks in a single batched request
+///
+/// Blocks that the `rpc` endpoints no longer have in long-term storage are fetched from the
+/// archive endpoints instead, when the network configures any. Other failures are treated
+/// as transient, and the blocks are requested again on the next poll.
+///
+/// # Returns
+/// * `Result<Vec<Result<SolanaBlockUpdate, BlockFetchError>>, anyhow::Error>` - The block
+///   of each slot, in order, or an error if the batch itself failed
+async fn get_blocks(
+	http_client: &SolanaTransportClient,
+	slots: &[u64],
+) -> Result<Vec<Result<SolanaBlockUpdate, BlockFetchError>>, anyhow::Error> {
+	let responses = http_client
+		.send_batch_request(&block_requests(slots))
+		.await?;
+	let mut blocks = slots
+		.iter()
+		.zip(responses)
+		.map(|(&slot, response)| parse_block(slot, response))
+		.collect::<Vec<_>>();
+
+	let missing = blocks
+		.iter()
+		.enumerate()
+		.filter_map(|(index, block)| match block {
+			Err(BlockFetchError::NotInStorage(slot)) => Some((index, *slot)),
+			_ => None,
+		})
+		.collect::<Vec<_>>();
+	if missing.is_empty() {
+		return Ok(blocks);
+	}
+
+	let Some(archive_client) = http_client.archive_client() else {
+		for (index, slot) in missing {
+			tracing::warn!(
+				"Solana block {} is not available in long-term storage and no archive \
+				 endpoint is configured",
+				slot
+			);
+			blocks[index] = Err(BlockFetchError::SlotSkipped(slot));
+		}
+		return Ok(blocks);
+	};
+
+	let missing_slots = missing.iter().map(|(_, slot)| *slot).collect::<Vec<_>>();
+	tracing::debug!(
+		"Fetching Solana blocks {:?} from archive endpoints",
+		missing_slots
+	);
+	let responses = archive_client
+		.send_batch_request(&block_requests(&missing_slots))
+		.await?;
+	for ((index, slot), response) in missing.into_iter().zip(responses) {
+		blocks[index] = match parse_block(slot, response) {
+			// Archive nodes keep every block, so the slot was skipped
+			Err(BlockFetchError::NotInStorage(slot)) => Err(BlockFetchError::SlotSkipped(slot)),
+			block => block,
+		};
+	}
+
+	Ok(blocks)
+}
+
+/// Builds the `getBlock` requests of the given slots
+pub(crate) fn block_requests(slots: &[u64]) -> Vec<(&'static str, Option<Value>)> {
+	slots
+		.iter()
+		.map(|slot| {
+			let params = json!([
+				slot,
+				{
+					"encoding": "base64",
+					"commitment": "confirmed",
+					"transactionDetails": "full",
+					"rewards": false,
+					"maxSupportedTransactionVersion": 0
+				}
+			]);
+			(RPC_METHOD_GET_BLOCK, Some(params))
+		})
+		.collect()
+}
+
+/// Parses the response of a `getBlock` request
+pub(crate) fn parse_block(
+	slot: u64,
+	response: Value,
+) -> Result<SolanaBlockUpdate, BlockFetchError> {
+	let rpc_error = |source: anyhow::Error| BlockFetchError::Rpc { slot, source };
+	if let Some(error) = response.get("error") {
+		let code = error.get("code").and_then(Value::as_i64);
+		if code.is_some_and(|code| LONG_TERM_STORAGE_ERROR_CODES.contains(&code)) {
+			return Err(BlockFetchError::NotInStorage(slot));
+		}
+		return Err(rpc_error(anyhow::anyhow!(
+			"Solana RPC request failed for method '{}': {}",
+			RPC_METHOD_GET_BLOCK,
+			error
+		)));
+	}
+
+	let result = response
+		.get("result")
+		.cloned()
+		.ok_or_else(|| rpc_error(anyhow::anyhow!("Missing 'result' field")))?;
+	if result.is_null() {
+		return Err(BlockFetchError::SlotSkipped(slot));
+	}
+
+	let block: UiConfirmedBlock =
+		serde_json::from_value(result).map_err(|e| rpc_error(e.into()))?;
+	Ok(block_update(slot, block))
+}
+
+/// Sends a JSON-RPC request and extracts its result
+async fn rpc_result(
+	http_client: &SolanaTransportClient,
+	method: &str,
+	params: Value,
+) -> Result<Value, anyhow::Error> {
+	let mut response = http_client.send_raw_request(method, Some(params)).await?;
+
+	if let Some(error) = response.get("error") {
+		return Err(anyhow::anyhow!(
+			"Solana RPC request failed for method '{}': {}",
+			method,
+			error
+		));
+	}
+
+	response
+		.get_mut("result")
+		.map(Value::take)
+		.ok_or_else(|| anyhow::anyhow!("Missing 'result' field"))
+}
+
+/// Converts a block to an update, skipping transactions that cannot be decoded
+fn block_update(slot: u64, block: UiConfirmedBlock) -> SolanaBlockUpdate {
+	let block_time = block.block_time;
+	let transactions = block
+		.transactions
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(|transaction| {
+			SolanaTransaction::from_encoded(EncodedConfirmedTransactionWithStatusMeta {
+				slot,
+				transaction,
+				block_time,
+			})
+			.ok()
+		})
+		.collect();
+
+	SolanaBlockUpdate {
+		slot,
+		blockhash: block.blockhash,
+		parent_slot: block.parent_slot,
+		block_height: block.block_height,
+		block_time,
+		transactions,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{models::BlockChainType, utils::tests::builders::network::NetworkBuilder};
+	use solana_sdk::{
+		instruction::{Instruction, InstructionError},
+		pubkey::Pubkey,
+		signature::{Keypair, Signer},
+		transaction::{Transaction, TransactionError, VersionedTransaction},
+	};
+	use solana_transaction_status::Encodable;
+
+	fn encoded_transaction(meta: Value) -> Value {
+		let fee_payer = Keypair::new();
+		let mut transaction = Transaction::new_with_payer(
+			&[Instruction::new_with_bytes(
+				Pubkey::new_unique(),
+				&[1],
+				vec![],
+			)],
+			Some(&fee_payer.pubkey()),
+		);
+		transaction.sign(&[&fee_payer], Default::default());
+		json!({
+			"transaction": VersionedTransaction::from(transaction).encode(UiTransactionEncoding::Base64),
+			"meta": meta
+		})
+	}
+
+	#[tokio::test]
+	async fn test_new_orders_ws_urls_by_weight() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Solana)
+			.clear_rpc_urls()
+			.add_rpc_url("ws://low.network", "ws", 10)
+			.add_rpc_url("ws://disabled.network", "ws", 0)
+			.add_rpc_url("ws://high.network", "ws", 90)
+			.build();
+
+		let client = SolanaWsTransportClient::new(&network).await.unwrap();
+		assert_eq!(client.ws_urls(), ["ws://high.network", "ws://low.network"]);
+		assert!(!client.has_http_fallback());
+	}
+
+	#[tokio::test]
+	async fn test_new_requires_ws_urls() {
+		let network = NetworkBuilder::new()
+			.network_type(BlockChainType::Solana)
+			.clear_rpc_urls()
+			.build();
+
+		let error = SolanaWsTransportClient::new(&network).await.unwrap_err();
+		assert!(error.to_string().contains("No WebSocket URLs configured"));
+	}
+
+	#[test]
+	fn test_polled_range_catches_up_in_chunks() {
+		assert_eq!(polled_range(None, 100), Some((100, 100)));
+		assert_eq!(polled_range(Some(100), 100), None);
+		assert_eq!(polled_range(Some(100), 120), Some((101, 120)));
+
+		// A gap longer than a poll is covered by consecutive polls, without skipping slots
+		let mut last_slot = Some(100);
+		let mut ranges = Vec::new();
+		while let Some((first_slot, end_slot)) = polled_range(last_slot, 220) {
+			ranges.push((first_slot, end_slot));
+			last_slot = Some(end_slot);
+		}
+		assert_eq!(ranges, vec![(101, 150), (151, 200), (201, 220)]);
+	}
+
+	#[test]
+	fn test_block_update_keeps_failed_transactions() {
+		let block: UiConfirmedBlock = serde_json::from_value(json!({
+			"previousBlockhash": "11111111111111111111111111111111",
+			"blockhash": "11111111111111111111111111111111",
+			"parentSlot": 99,
+			"blockTime": 1678901234,
+			"blockHeight": 90,
+			"transactions": [
+				encoded_transaction(json!({
+					"err": null,
+					"status": { "Ok": null },
+					"fee": 5000,
+					"preBalances": [1_000_000, 1],
+					"postBalances": [995_000, 1]
+				})),
+				encoded_transaction(json!({
+					"err": { "InstructionError": [0, { "Custom": 1 }] },
+					"status": { "Err": { "InstructionError": [0, { "Custom": 1 }] } },
+					"fee": 5000,
+					"preBalances": [1_000_000, 1],
+					"postBalances": [995_000, 1]
+				}))
+			]
+		}))
+		.unwrap();
+
+		let update = block_update(100, block);
+
+		assert_eq!(update.transactions.len(), 2);
+		assert!(update.transactions[0].meta().status.is_ok());
+		assert_eq!(
+			update.transactions[1].meta().status,
+			Err(TransactionError::InstructionError(
+				0,
+				InstructionError::Custom(1)
+			))
+		);
+	}
+}
@@ -452,7 +452,10 @@ pub async fn process_new_blocks<
 				// Process blocks in order as long as we have the next expected block
 				while let Some(expected) = next_block_number {
 					if let Some(block) = pending_blocks.remove(&expected) {
-						(trigger_handler)(&block);
+						// Waits while the trigger queue is full
+						if let Err(e) = (trigger_handler)(&block).await {
+							tracing::error!("Trigger handling of block {} failed: {}", expected, e);
+						}
 						next_block_number = Some(expected + 1);
 					} else {
 						break;
@@ -463,7 +466,9 @@ pub async fn process_new_blocks<
 			// Process any remaining blocks in order after the channel is closed
 			while let Some(min_block) = pending_blocks.keys().next().copied() {
 				if let Some(block) = pending_blocks.remove(&min_block) {
-					(trigger_handler)(&block);
+					if let Err(e) = (trigger_handler)(&block).await {
+						tracing::error!("Trigger handling of block {} failed: {}", min_block, e);
+					}
 				}
 			}
 			Ok::<(), BlockWatcherError>(())
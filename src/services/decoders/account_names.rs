@@ -0,0 +1,775 @@
+//! Positional account names of the built-in instruction decoders.
+//!
+//! Carbon decoders arrange instruction accounts into typed structs, which cannot be
+//! inspected generically. These tables mirror the account order used by each
+//! `ArrangeAccounts` implementation so accounts can be exposed as named parameters.
+
+use crate::services::decoders::{
+	address_lookup_table::AddressLookupTableInstruction,
+	bpf_upgradeable_loader::BpfUpgradeableLoaderInstruction,
+	kamino_lending_decoder::instructions::KaminoLendingInstruction,
+	name_service::NameServiceInstruction, okx_dex_decoder::instructions::OkxDexInstruction,
+	sharky_decoder::instructions::SharkyInstruction, squads::SquadsInstruction,
+	token_program::TokenProgramInstruction,
+};
+
+/// Returns the account names of an Address Lookup Table instruction in positional order
+pub(crate) fn address_lookup_table_account_names(
+	instruction: &AddressLookupTableInstruction,
+) -> &'static [&'static str] {
+	match instruction {
+		AddressLookupTableInstruction::CreateLookupTable(_) => {
+			&["lookup_table", "authority", "payer", "system_program"]
+		}
+		AddressLookupTableInstruction::FreezeLookupTable(_)
+		| AddressLookupTableInstruction::DeactivateLookupTable(_) => &["lookup_table", "authority"],
+		AddressLookupTableInstruction::ExtendLookupTable(_) => {
+			&["lookup_table", "authority", "payer", "system_program"]
+		}
+		AddressLookupTableInstruction::CloseLookupTable(_) => {
+			&["lookup_table", "authority", "recipient"]
+		}
+	}
+}
+
+/// Returns the account names of a BPF Upgradeable Loader instruction in positional order
+pub(crate) fn bpf_upgradeable_loader_account_names(
+	instruction: &BpfUpgradeableLoaderInstruction,
+) -> &'static [&'static str] {
+	match instruction {
+		BpfUpgradeableLoaderInstruction::InitializeBuffer(_) => &["buffer", "authority"],
+		BpfUpgradeableLoaderInstruction::Write(_) => &["buffer", "authority"],
+		BpfUpgradeableLoaderInstruction::DeployWithMaxDataLen(_) => &[
+			"payer",
+			"program_data",
+			"program",
+			"buffer",
+			"rent",
+			"clock",
+			"system_program",
+			"authority",
+		],
+		BpfUpgradeableLoaderInstruction::Upgrade(_) => &[
+			"program_data",
+			"program",
+			"buffer",
+			"spill",
+			"rent",
+			"clock",
+			"authority",
+		],
+		BpfUpgradeableLoaderInstruction::SetAuthority(_)
+		| BpfUpgradeableLoaderInstruction::SetAuthorityChecked(_) => {
+			&["account", "authority", "new_authority"]
+		}
+		BpfUpgradeableLoaderInstruction::Close(_) => {
+			&["account", "recipient", "authority", "program"]
+		}
+		BpfUpgradeableLoaderInstruction::ExtendProgram(_) => {
+			&["program_data", "program", "system_program", "payer"]
+		}
+	}
+}
+
+/// Returns the account names of a Kamino Lending instruction in positional order
+pub(crate) fn kamino_lending_account_names(
+	instruction: &KaminoLendingInstruction,
+) -> &'static [&'static str] {
+	match instruction {
+		KaminoLendingInstruction::InitLendingMarket(_) => &[
+			"lending_market_owner",
+			"lending_market",
+			"lending_market_authority",
+			"system_program",
+			"rent",
+		],
+		KaminoLendingInstruction::UpdateLendingMarket(_) => {
+			&["lending_market_owner", "lending_market"]
+		}
+		KaminoLendingInstruction::UpdateLendingMarketOwner(_) => {
+			&["lending_market_owner_cached", "lending_market"]
+		}
+		KaminoLendingInstruction::InitReserve(_) => &[
+			"lending_market_owner",
+			"lending_market",
+			"lending_market_authority",
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_liquidity_supply",
+			"fee_receiver",
+			"reserve_collateral_mint",
+			"reserve_collateral_supply",
+			"rent",
+			"liquidity_token_program",
+			"collateral_token_program",
+			"system_program",
+		],
+		KaminoLendingInstruction::InitFarmsForReserve(_) => &[
+			"lending_market_owner",
+			"lending_market",
+			"lending_market_authority",
+			"reserve",
+			"farms_program",
+			"farms_global_config",
+			"farm_state",
+			"farms_vault_authority",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::UpdateReserveConfig(_) => {
+			&["lending_market_owner", "lending_market", "reserve"]
+		}
+		KaminoLendingInstruction::RedeemFees(_) => &[
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_liquidity_fee_receiver",
+			"reserve_supply_liquidity",
+			"lending_market",
+			"lending_market_authority",
+			"token_program",
+		],
+		KaminoLendingInstruction::WithdrawProtocolFee(_) => &[
+			"lending_market_owner",
+			"lending_market",
+			"reserve",
+			"reserve_liquidity_mint",
+			"lending_market_authority",
+			"fee_vault",
+			"lending_market_owner_ata",
+			"token_program",
+		],
+		KaminoLendingInstruction::SocializeLoss(_) => &[
+			"risk_council",
+			"obligation",
+			"lending_market",
+			"reserve",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::MarkObligationForDeleveraging(_) => {
+			&["risk_council", "obligation", "lending_market"]
+		}
+		KaminoLendingInstruction::RefreshReserve(_) => &[
+			"reserve",
+			"lending_market",
+			"pyth_oracle",
+			"switchboard_price_oracle",
+			"switchboard_twap_oracle",
+			"scope_prices",
+		],
+		KaminoLendingInstruction::RefreshReservesBatch(_) => &[],
+		KaminoLendingInstruction::DepositReserveLiquidity(_) => &[
+			"owner",
+			"reserve",
+			"lending_market",
+			"lending_market_authority",
+			"reserve_liquidity_mint",
+			"reserve_liquidity_supply",
+			"reserve_collateral_mint",
+			"user_source_liquidity",
+			"user_destination_collateral",
+			"collateral_token_program",
+			"liquidity_token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::RedeemReserveCollateral(_) => &[
+			"owner",
+			"lending_market",
+			"reserve",
+			"lending_market_authority",
+			"reserve_liquidity_mint",
+			"reserve_collateral_mint",
+			"reserve_liquidity_supply",
+			"user_source_collateral",
+			"user_destination_liquidity",
+			"collateral_token_program",
+			"liquidity_token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::InitObligation(_) => &[
+			"obligation_owner",
+			"fee_payer",
+			"obligation",
+			"lending_market",
+			"seed1_account",
+			"seed2_account",
+			"owner_user_metadata",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::InitObligationFarmsForReserve(_) => &[
+			"payer",
+			"owner",
+			"obligation",
+			"lending_market_authority",
+			"reserve",
+			"reserve_farm_state",
+			"obligation_farm",
+			"lending_market",
+			"farms_program",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::RefreshObligationFarmsForReserve(_) => &[
+			"crank",
+			"obligation",
+			"lending_market_authority",
+			"reserve",
+			"reserve_farm_state",
+			"obligation_farm_user_state",
+			"lending_market",
+			"farms_program",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::RefreshObligation(_) => &["lending_market", "obligation"],
+		KaminoLendingInstruction::DepositObligationCollateral(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"deposit_reserve",
+			"reserve_destination_collateral",
+			"user_source_collateral",
+			"token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::WithdrawObligationCollateral(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"lending_market_authority",
+			"withdraw_reserve",
+			"reserve_source_collateral",
+			"user_destination_collateral",
+			"token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::BorrowObligationLiquidity(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"lending_market_authority",
+			"borrow_reserve",
+			"borrow_reserve_liquidity_mint",
+			"reserve_source_liquidity",
+			"borrow_reserve_liquidity_fee_receiver",
+			"user_destination_liquidity",
+			"referrer_token_state",
+			"token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::RepayObligationLiquidity(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"repay_reserve",
+			"reserve_liquidity_mint",
+			"reserve_destination_liquidity",
+			"user_source_liquidity",
+			"token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::RepayAndWithdrawAndRedeem(_) => {
+			&["repay_accounts", "withdraw_accounts"]
+		}
+		KaminoLendingInstruction::DepositReserveLiquidityAndObligationCollateral(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"lending_market_authority",
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_liquidity_supply",
+			"reserve_collateral_mint",
+			"reserve_destination_deposit_collateral",
+			"user_source_liquidity",
+			"placeholder_user_destination_collateral",
+			"collateral_token_program",
+			"liquidity_token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::WithdrawObligationCollateralAndRedeemReserveCollateral(_) => &[
+			"owner",
+			"obligation",
+			"lending_market",
+			"lending_market_authority",
+			"withdraw_reserve",
+			"reserve_liquidity_mint",
+			"reserve_source_collateral",
+			"reserve_collateral_mint",
+			"reserve_liquidity_supply",
+			"user_destination_liquidity",
+			"placeholder_user_destination_collateral",
+			"collateral_token_program",
+			"liquidity_token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::LiquidateObligationAndRedeemReserveCollateral(_) => &[
+			"liquidator",
+			"obligation",
+			"lending_market",
+			"lending_market_authority",
+			"repay_reserve",
+			"repay_reserve_liquidity_mint",
+			"repay_reserve_liquidity_supply",
+			"withdraw_reserve",
+			"withdraw_reserve_liquidity_mint",
+			"withdraw_reserve_collateral_mint",
+			"withdraw_reserve_collateral_supply",
+			"withdraw_reserve_liquidity_supply",
+			"withdraw_reserve_liquidity_fee_receiver",
+			"user_source_liquidity",
+			"user_destination_collateral",
+			"user_destination_liquidity",
+			"collateral_token_program",
+			"repay_liquidity_token_program",
+			"withdraw_liquidity_token_program",
+			"instruction_sysvar_account",
+		],
+		KaminoLendingInstruction::FlashRepayReserveLiquidity(_) => &[
+			"user_transfer_authority",
+			"lending_market_authority",
+			"lending_market",
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_destination_liquidity",
+			"user_source_liquidity",
+			"reserve_liquidity_fee_receiver",
+			"referrer_token_state",
+			"referrer_account",
+			"sysvar_info",
+			"token_program",
+		],
+		KaminoLendingInstruction::FlashBorrowReserveLiquidity(_) => &[
+			"user_transfer_authority",
+			"lending_market_authority",
+			"lending_market",
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_source_liquidity",
+			"user_destination_liquidity",
+			"reserve_liquidity_fee_receiver",
+			"referrer_token_state",
+			"referrer_account",
+			"sysvar_info",
+			"token_program",
+		],
+		KaminoLendingInstruction::RequestElevationGroup(_) => {
+			&["owner", "obligation", "lending_market"]
+		}
+		KaminoLendingInstruction::InitReferrerTokenState(_) => &[
+			"payer",
+			"lending_market",
+			"reserve",
+			"referrer",
+			"referrer_token_state",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::InitUserMetadata(_) => &[
+			"owner",
+			"fee_payer",
+			"user_metadata",
+			"referrer_user_metadata",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::WithdrawReferrerFees(_) => &[
+			"referrer",
+			"referrer_token_state",
+			"reserve",
+			"reserve_liquidity_mint",
+			"reserve_supply_liquidity",
+			"referrer_token_account",
+			"lending_market",
+			"lending_market_authority",
+			"token_program",
+		],
+		KaminoLendingInstruction::InitReferrerStateAndShortUrl(_) => &[
+			"referrer",
+			"referrer_state",
+			"referrer_short_url",
+			"referrer_user_metadata",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::DeleteReferrerStateAndShortUrl(_) => &[
+			"referrer",
+			"referrer_state",
+			"short_url",
+			"rent",
+			"system_program",
+		],
+		KaminoLendingInstruction::IdlMissingTypes(_) => {
+			&["lending_market_owner", "lending_market", "reserve"]
+		}
+	}
+}
+
+/// Returns the account names of a Name Service instruction in positional order
+///
+/// Trailing optional accounts are listed as well, and are only exposed when present.
+pub(crate) fn name_service_account_names(
+	instruction: &NameServiceInstruction,
+) -> &'static [&'static str] {
+	match instruction {
+		NameServiceInstruction::Create(_) => &[
+			"system_program",
+			"payer",
+			"name_account",
+			"name_owner",
+			"name_class",
+			"parent_name",
+			"parent_name_owner",
+		],
+		NameServiceInstruction::Update(_) => &["name_account", "name_update_signer", "parent_name"],
+		NameServiceInstruction::Transfer(_) => {
+			&["name_account", "name_owner", "name_class", "parent_name"]
+		}
+		NameServiceInstruction::Delete(_) => &["name_account", "name_owner", "refund_target"],
+		NameServiceInstruction::Realloc(_) => {
+			&["system_program", "payer", "name_account", "name_owner"]
+		}
+	}
+}
+
+/// Returns the account names of an OKX DEX instruction in positional order
+pub(crate) fn okx_dex_account_names(instruction: &OkxDexInstruction) -> &'static [&'static str] {
+	match instruction {
+		OkxDexInstruction::CommissionSolFromSwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"bridge_program",
+			"associated_token_program",
+			"token_program",
+			"token_2022_program",
+			"system_program",
+			"commission_account",
+		],
+		OkxDexInstruction::CommissionSolProxySwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_account",
+			"sa_authority",
+			"source_token_sa",
+			"destination_token_sa",
+			"source_token_program",
+			"destination_token_program",
+			"associated_token_program",
+			"system_program",
+		],
+		OkxDexInstruction::CommissionSolSwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_account",
+			"system_program",
+		],
+		OkxDexInstruction::CommissionSolSwap2(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_account",
+			"system_program",
+		],
+		OkxDexInstruction::CommissionSplFromSwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"bridge_program",
+			"associated_token_program",
+			"token_program",
+			"token_2022_program",
+			"system_program",
+			"commission_token_account",
+		],
+		OkxDexInstruction::CommissionSplProxySwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_token_account",
+			"sa_authority",
+			"source_token_sa",
+			"destination_token_sa",
+			"source_token_program",
+			"destination_token_program",
+			"associated_token_program",
+			"system_program",
+		],
+		OkxDexInstruction::CommissionSplSwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_token_account",
+			"token_program",
+		],
+		OkxDexInstruction::CommissionSplSwap2(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"commission_token_account",
+			"token_program",
+		],
+		OkxDexInstruction::FromSwapLog(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"bridge_program",
+			"associated_token_program",
+			"token_program",
+			"token_2022_program",
+			"system_program",
+		],
+		OkxDexInstruction::ProxySwap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+			"sa_authority",
+			"source_token_sa",
+			"destination_token_sa",
+			"source_token_program",
+			"destination_token_program",
+			"associated_token_program",
+			"system_program",
+		],
+		OkxDexInstruction::Swap(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+		],
+		OkxDexInstruction::Swap2(_) => &[
+			"payer",
+			"source_token_account",
+			"destination_token_account",
+			"source_mint",
+			"destination_mint",
+		],
+		OkxDexInstruction::SwapEvent(_) => &[],
+	}
+}
+
+/// Returns the account names of a Sharky instruction in positional order
+pub(crate) fn sharky_account_names(instruction: &SharkyInstruction) -> &'static [&'static str] {
+	match instruction {
+		SharkyInstruction::CloseOrderBook(_) => &["order_book", "payer"],
+		SharkyInstruction::CreateOrderBook(_) => &["order_book", "payer", "system_program"],
+		SharkyInstruction::ExtendLoanV3(_) => &[
+			"loan",
+			"new_loan",
+			"borrower",
+			"lender",
+			"new_lender",
+			"escrow",
+			"new_escrow",
+			"collateral_mint",
+			"order_book",
+			"fee_authority",
+			"system_program",
+			"token_program",
+		],
+		SharkyInstruction::ForecloseLoanV3(_) => &[
+			"loan",
+			"escrow",
+			"lender",
+			"borrower",
+			"collateral_mint",
+			"escrow_collateral_token_account",
+			"lender_collateral_token_account",
+			"order_book",
+			"metadata",
+			"edition",
+			"system_program",
+			"token_program",
+			"associated_token_program",
+		],
+		SharkyInstruction::OfferLoan(_) => &[
+			"lender",
+			"lender_value_token_account",
+			"value_mint",
+			"loan",
+			"escrow",
+			"escrow_token_account",
+			"order_book",
+			"system_program",
+			"token_program",
+			"associated_token_program",
+			"rent",
+		],
+		SharkyInstruction::RepayLoanV3(_) => &[
+			"loan",
+			"borrower",
+			"lender",
+			"escrow",
+			"collateral_mint",
+			"borrower_collateral_token_account",
+			"escrow_collateral_token_account",
+			"order_book",
+			"fee_authority",
+			"metadata",
+			"edition",
+			"system_program",
+			"token_program",
+		],
+		SharkyInstruction::RescindLoan(_) => &[
+			"loan",
+			"lender_value_token_account",
+			"lender",
+			"value_mint",
+			"escrow",
+			"escrow_token_account",
+			"system_program",
+			"token_program",
+		],
+		SharkyInstruction::TakeLoanV3(_) => &[
+			"lender",
+			"borrower",
+			"borrower_value_token_account",
+			"borrower_collateral_token_account",
+			"collateral_mint",
+			"loan",
+			"escrow",
+			"escrow_collateral_token_account",
+			"order_book",
+			"metadata",
+			"edition",
+			"system_program",
+			"token_program",
+			"associated_token_program",
+			"rent",
+		],
+		SharkyInstruction::UpdateOrderBook(_) => &["order_book", "payer"],
+	}
+}
+
+/// Returns the account names of a Squads instruction in positional order
+pub(crate) fn squads_account_names(instruction: &SquadsInstruction) -> &'static [&'static str] {
+	match instruction {
+		SquadsInstruction::MultisigCreateV2(_) => &[
+			"program_config",
+			"treasury",
+			"multisig",
+			"create_key",
+			"creator",
+			"system_program",
+		],
+		SquadsInstruction::MultisigAddMember(_)
+		| SquadsInstruction::MultisigRemoveMember(_)
+		| SquadsInstruction::MultisigChangeThreshold(_) => &[
+			"multisig",
+			"config_authority",
+			"rent_payer",
+			"system_program",
+		],
+		SquadsInstruction::VaultTransactionCreate(_) => &[
+			"multisig",
+			"transaction",
+			"creator",
+			"rent_payer",
+			"system_program",
+		],
+		SquadsInstruction::ProposalCreate(_) => &[
+			"multisig",
+			"proposal",
+			"creator",
+			"rent_payer",
+			"system_program",
+		],
+		SquadsInstruction::ProposalActivate(_)
+		| SquadsInstruction::ProposalApprove(_)
+		| SquadsInstruction::ProposalReject(_)
+		| SquadsInstruction::ProposalCancel(_) => &["multisig", "member", "proposal"],
+		SquadsInstruction::VaultTransactionExecute(_) => {
+			&["multisig", "proposal", "transaction", "member"]
+		}
+		SquadsInstruction::ConfigTransactionExecute(_) => &[
+			"multisig",
+			"member",
+			"proposal",
+			"transaction",
+			"rent_payer",
+			"system_program",
+		],
+	}
+}
+
+/// Returns the account names of an SPL Token instruction in positional order
+///
+/// Multisig signers trailing the listed accounts are left unnamed.
+pub(crate) fn token_program_account_names(
+	instruction: &TokenProgramInstruction,
+) -> &'static [&'static str] {
+	match instruction {
+		TokenProgramInstruction::InitializeMint { .. } => &["mint", "rent"],
+		TokenProgramInstruction::InitializeAccount {} => &["account", "mint", "owner", "rent"],
+		TokenProgramInstruction::InitializeMultisig { .. } => &["multisig", "rent"],
+		TokenProgramInstruction::Transfer { .. } => &["source", "destination", "authority"],
+		TokenProgramInstruction::Approve { .. } => &["source", "delegate", "owner"],
+		TokenProgramInstruction::Revoke {} => &["source", "owner"],
+		TokenProgramInstruction::SetAuthority { .. } => &["account", "authority"],
+		TokenProgramInstruction::MintTo { .. } | TokenProgramInstruction::MintToChecked { .. } => {
+			&["mint", "destination", "authority"]
+		}
+		TokenProgramInstruction::Burn { .. } | TokenProgramInstruction::BurnChecked { .. } => {
+			&["account", "mint", "authority"]
+		}
+		TokenProgramInstruction::CloseAccount {} => &["account", "destination", "authority"],
+		TokenProgramInstruction::FreezeAccount {} | TokenProgramInstruction::ThawAccount {} => {
+			&["account", "mint", "authority"]
+		}
+		TokenProgramInstruction::TransferChecked { .. } => {
+			&["source", "mint", "destination", "authority"]
+		}
+		TokenProgramInstruction::ApproveChecked { .. } => &["source", "mint", "delegate", "owner"],
+		TokenProgramInstruction::InitializeAccount2 { .. } => &["account", "mint", "rent"],
+		TokenProgramInstruction::InitializeAccount3 { .. } => &["account", "mint"],
+		TokenProgramInstruction::SyncNative {}
+		| TokenProgramInstruction::InitializeImmutableOwner {} => &["account"],
+		TokenProgramInstruction::InitializeMultisig2 { .. } => &["multisig"],
+		TokenProgramInstruction::InitializeMint2 { .. }
+		| TokenProgramInstruction::GetAccountDataSize {}
+		| TokenProgramInstruction::AmountToUiAmount { .. }
+		| TokenProgramInstruction::UiAmountToAmount { .. } => &["mint"],
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::decoders::kamino_lending_decoder::instructions::deposit_reserve_liquidity::DepositReserveLiquidity;
+
+	#[test]
+	fn test_kamino_lending_account_names() {
+		let names = kamino_lending_account_names(
+			&KaminoLendingInstruction::DepositReserveLiquidity(DepositReserveLiquidity {
+				liquidity_amount: 1,
+			}),
+		);
+		assert_eq!(names.len(), 12);
+		assert_eq!(names[0], "owner");
+		assert_eq!(names[1], "reserve");
+		assert_eq!(names[2], "lending_market");
+	}
+}
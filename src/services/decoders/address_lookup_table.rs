@@ -0,0 +1,249 @@
+//! Decoder for the Address Lookup Table program.
+//!
+//! Lookup tables store addresses that versioned transactions reference by index. Tables
+//! are often created and extended right before complex exploits, so decoding their
+//! instructions allows monitors to alert on the addresses added to a table. Instructions
+//! are bincode encoded with a four byte instruction tag.
+
+use carbon_core::borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	serde_helpers::pubkey_base58_vec, variant_names, DecodedInstruction, InstructionDecoder,
+	InstructionType,
+};
+
+/// Address of the Address Lookup Table program
+pub const PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("AddressLookupTab1e1111111111111111111111111");
+
+/// Decoder for Address Lookup Table instructions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AddressLookupTableDecoder;
+
+/// Instructions of the Address Lookup Table program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressLookupTableInstruction {
+	CreateLookupTable(CreateLookupTable),
+	FreezeLookupTable(FreezeLookupTable),
+	ExtendLookupTable(ExtendLookupTable),
+	DeactivateLookupTable(DeactivateLookupTable),
+	CloseLookupTable(CloseLookupTable),
+}
+
+/// Creates a lookup table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateLookupTable {
+	/// Recent slot used to derive the table address
+	pub recent_slot: u64,
+	/// Bump seed of the table address
+	pub bump_seed: u8,
+}
+
+/// Makes a lookup table immutable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreezeLookupTable {}
+
+/// Appends addresses to a lookup table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtendLookupTable {
+	/// Addresses added to the table
+	#[serde(with = "pubkey_base58_vec")]
+	pub new_addresses: Vec<Pubkey>,
+}
+
+/// Deactivates a lookup table so it can be closed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeactivateLookupTable {}
+
+/// Closes a deactivated lookup table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloseLookupTable {}
+
+impl AddressLookupTableInstruction {
+	/// Decodes Address Lookup Table instruction data
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, starting with the instruction tag
+	///
+	/// # Returns
+	/// The decoded instruction, or `None` if the data is not a valid instruction
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let mut rest = data;
+		let tag: u32 = read(&mut rest)?;
+		let instruction = match tag {
+			0 => Self::CreateLookupTable(CreateLookupTable {
+				recent_slot: read(&mut rest)?,
+				bump_seed: read(&mut rest)?,
+			}),
+			1 => Self::FreezeLookupTable(FreezeLookupTable {}),
+			2 => {
+				let len: u64 = read(&mut rest)?;
+				let new_addresses = (0..len)
+					.map(|_| read(&mut rest).map(Pubkey::new_from_array))
+					.collect::<Option<_>>()?;
+				Self::ExtendLookupTable(ExtendLookupTable { new_addresses })
+			}
+			3 => Self::DeactivateLookupTable(DeactivateLookupTable {}),
+			4 => Self::CloseLookupTable(CloseLookupTable {}),
+			_ => return None,
+		};
+		Some(instruction)
+	}
+}
+
+/// Reads a little-endian value from the front of `data`
+fn read<T: BorshDeserialize>(data: &mut &[u8]) -> Option<T> {
+	T::deserialize(data).ok()
+}
+
+/// Size of the metadata preceding the addresses stored in a lookup table account
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Discriminant of initialized lookup table accounts
+const LOOKUP_TABLE_DISCRIMINANT: u32 = 1;
+
+/// Parses the addresses stored in a lookup table account
+///
+/// # Arguments
+/// * `data` - Data of the lookup table account
+///
+/// # Returns
+/// The addresses of the table, or `None` if the data is not an initialized lookup table
+pub fn parse_lookup_table_addresses(data: &[u8]) -> Option<Vec<Pubkey>> {
+	let discriminant = read::<u32>(&mut &data[..])?;
+	if discriminant != LOOKUP_TABLE_DISCRIMINANT || data.len() < LOOKUP_TABLE_META_SIZE {
+		return None;
+	}
+
+	let addresses = &data[LOOKUP_TABLE_META_SIZE..];
+	if addresses.len() % 32 != 0 {
+		return None;
+	}
+	addresses
+		.chunks_exact(32)
+		.map(|address| Pubkey::try_from(address).ok())
+		.collect()
+}
+
+impl InstructionDecoder<'_> for AddressLookupTableDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if instruction.program_id != PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::AddressLookupTable(AddressLookupTableInstruction::decode(
+				&instruction.data,
+			)?),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::ADDRESS_LOOKUP_TABLE_INSTRUCTION_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_lookup_table_addresses() {
+		let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+		let mut data = vec![0u8; LOOKUP_TABLE_META_SIZE];
+		data[..4].copy_from_slice(&LOOKUP_TABLE_DISCRIMINANT.to_le_bytes());
+		for address in &addresses {
+			data.extend_from_slice(address.as_ref());
+		}
+
+		assert_eq!(
+			parse_lookup_table_addresses(&data),
+			Some(addresses.to_vec())
+		);
+		// Uninitialized tables and truncated addresses are rejected
+		assert_eq!(
+			parse_lookup_table_addresses(&[0; LOOKUP_TABLE_META_SIZE]),
+			None
+		);
+		assert_eq!(parse_lookup_table_addresses(&data[..data.len() - 1]), None);
+	}
+
+	#[test]
+	fn test_decode_create_lookup_table() {
+		let mut data = 0u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&300_000_000u64.to_le_bytes());
+		data.push(254);
+
+		assert_eq!(
+			AddressLookupTableInstruction::decode(&data),
+			Some(AddressLookupTableInstruction::CreateLookupTable(
+				CreateLookupTable {
+					recent_slot: 300_000_000,
+					bump_seed: 254,
+				}
+			))
+		);
+	}
+
+	#[test]
+	fn test_decode_extend_lookup_table() {
+		let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+		let mut data = 2u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&(addresses.len() as u64).to_le_bytes());
+		for address in &addresses {
+			data.extend_from_slice(address.as_ref());
+		}
+
+		let decoded = AddressLookupTableInstruction::decode(&data).unwrap();
+		assert_eq!(
+			serde_json::to_value(&decoded).unwrap(),
+			serde_json::json!({
+				"ExtendLookupTable": {
+					"new_addresses": [addresses[0].to_string(), addresses[1].to_string()]
+				}
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_close_lookup_table() {
+		assert_eq!(
+			AddressLookupTableInstruction::decode(&4u32.to_le_bytes()),
+			Some(AddressLookupTableInstruction::CloseLookupTable(
+				CloseLookupTable {}
+			))
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_data() {
+		assert!(AddressLookupTableInstruction::decode(&[]).is_none());
+		assert!(AddressLookupTableInstruction::decode(&5u32.to_le_bytes()).is_none());
+
+		let mut data = 2u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&1u64.to_le_bytes());
+		data.extend_from_slice(&[1; 16]);
+		assert!(AddressLookupTableInstruction::decode(&data).is_none());
+	}
+
+	#[test]
+	fn test_decode_rejects_other_program() {
+		let instruction = Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data: 4u32.to_le_bytes().to_vec(),
+		};
+		assert!(AddressLookupTableDecoder
+			.decode_instruction(&instruction)
+			.is_none());
+	}
+}
@@ -0,0 +1,211 @@
+//! Extraction of the events emitted by Anchor programs.
+//!
+//! Anchor programs emit events in two ways. `emit!` logs the event as a base64 encoded
+//! `Program data:` line, attributed to the program currently executing. `emit_cpi!`
+//! invokes the program itself with an instruction whose data starts with the event
+//! instruction tag, which ends up in the inner instructions of the transaction. In both
+//! cases, the payload is the 8-byte event discriminator followed by the Borsh encoded
+//! event, which is decoded with the IDL of the program.
+
+use base64::Engine;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::models::{SolanaProgramLog, SolanaTransaction};
+
+/// Tag prefixing the data of self-CPI event instructions, `sha256("anchor:event")[..8]`
+/// in reverse byte order
+pub const EVENT_IX_TAG: [u8; 8] = 0x1d9acb512ea545e4u64.to_le_bytes();
+
+/// Prefix of the log lines carrying event data
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// Event emitted by a program, before decoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawEvent {
+	/// Program that emitted the event
+	pub program_id: Pubkey,
+	/// Event data, starting with the event discriminator
+	pub data: Vec<u8>,
+}
+
+/// Event decoded with the IDL of the program that emitted it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedEvent {
+	/// Program that emitted the event
+	pub program_id: Pubkey,
+	/// Name of the event type
+	pub name: String,
+	/// Decoded event fields
+	pub data: Value,
+}
+
+/// Returns the events emitted by a transaction, logged events first
+pub fn extract_events(transaction: &SolanaTransaction) -> Vec<RawEvent> {
+	let mut events = transaction
+		.meta()
+		.log_messages
+		.as_deref()
+		.map(events_from_logs)
+		.unwrap_or_default();
+	events.extend(events_from_inner_instructions(transaction));
+	events
+}
+
+/// Returns the events logged as `Program data:` lines
+///
+/// Each event is attributed to the program executing when it was logged. Lines that are
+/// not valid base64 are skipped.
+pub fn events_from_logs(log_messages: &[String]) -> Vec<RawEvent> {
+	SolanaProgramLog::from_log_messages(log_messages)
+		.into_iter()
+		.filter_map(|log| {
+			let data = log.message.strip_prefix(PROGRAM_DATA_LOG_PREFIX)?;
+			Some(RawEvent {
+				program_id: log.program_id?,
+				data: base64::engine::general_purpose::STANDARD
+					.decode(data.trim())
+					.ok()?,
+			})
+		})
+		.collect()
+}
+
+/// Returns the events emitted through self-CPI event instructions
+pub fn events_from_inner_instructions(transaction: &SolanaTransaction) -> Vec<RawEvent> {
+	let Some(inner_instructions) = &transaction.meta().inner_instructions else {
+		return Vec::new();
+	};
+	let account_keys = transaction.account_keys();
+
+	inner_instructions
+		.iter()
+		.flat_map(|inner| &inner.instructions)
+		.filter_map(|inner| {
+			let instruction = &inner.instruction;
+			let data = instruction.data.strip_prefix(&EVENT_IX_TAG)?;
+			Some(RawEvent {
+				program_id: *account_keys.get(instruction.program_id_index as usize)?,
+				data: data.to_vec(),
+			})
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::models::{SolanaTransactionMetadata, SolanaTransactionStatusMeta};
+	use sha2::{Digest, Sha256};
+	use solana_sdk::{
+		instruction::CompiledInstruction,
+		message::{Message, VersionedMessage},
+	};
+	use solana_transaction_status::{InnerInstruction, InnerInstructions};
+
+	fn encode(data: &[u8]) -> String {
+		base64::engine::general_purpose::STANDARD.encode(data)
+	}
+
+	#[test]
+	fn test_event_ix_tag() {
+		let mut tag = Sha256::digest(b"anchor:event")[..8].to_vec();
+		tag.reverse();
+		assert_eq!(EVENT_IX_TAG.to_vec(), tag);
+	}
+
+	#[test]
+	fn test_events_from_logs_are_attributed_to_the_emitting_program() {
+		let outer = Pubkey::new_unique();
+		let inner = Pubkey::new_unique();
+		let logs = vec![
+			format!("Program {} invoke [1]", outer),
+			format!("Program data: {}", encode(&[1, 2])),
+			format!("Program {} invoke [2]", inner),
+			"Program log: Instruction: Deposit".to_string(),
+			format!("Program data: {}", encode(&[3, 4])),
+			format!("Program {} consumed 1000 of 200000 compute units", inner),
+			format!("Program {} success", inner),
+			"Program data: not base64!".to_string(),
+			format!("Program data: {}", encode(&[5, 6])),
+			format!("Program {} success", outer),
+			// Data logged outside of any invocation is ignored
+			format!("Program data: {}", encode(&[7, 8])),
+		];
+
+		assert_eq!(
+			events_from_logs(&logs),
+			vec![
+				RawEvent {
+					program_id: outer,
+					data: vec![1, 2],
+				},
+				RawEvent {
+					program_id: inner,
+					data: vec![3, 4],
+				},
+				RawEvent {
+					program_id: outer,
+					data: vec![5, 6],
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn test_extract_events_from_self_cpi() {
+		let payer = Pubkey::new_unique();
+		let program_id = Pubkey::new_unique();
+		let message = Message::new_with_compiled_instructions(
+			1,
+			0,
+			1,
+			vec![payer, program_id],
+			Default::default(),
+			vec![CompiledInstruction::new_from_raw_parts(1, vec![], vec![])],
+		);
+		let inner_instruction = |data: Vec<u8>| InnerInstruction {
+			instruction: CompiledInstruction::new_from_raw_parts(1, data, vec![]),
+			stack_height: Some(2),
+		};
+		let transaction = SolanaTransaction {
+			metadata: SolanaTransactionMetadata {
+				slot: 1,
+				signature: Default::default(),
+				fee_payer: payer,
+				meta: SolanaTransactionStatusMeta {
+					inner_instructions: Some(vec![InnerInstructions {
+						index: 0,
+						instructions: vec![
+							inner_instruction([EVENT_IX_TAG.to_vec(), vec![9, 9]].concat()),
+							inner_instruction(vec![1, 2, 3]),
+						],
+					}]),
+					log_messages: Some(vec![
+						format!("Program {} invoke [1]", program_id),
+						format!("Program data: {}", encode(&[1, 1])),
+						format!("Program {} success", program_id),
+					]),
+					..Default::default()
+				},
+				message: VersionedMessage::Legacy(message),
+				block_time: None,
+			},
+			instructions: vec![],
+		};
+
+		assert_eq!(
+			extract_events(&transaction),
+			vec![
+				RawEvent {
+					program_id,
+					data: vec![1, 1],
+				},
+				RawEvent {
+					program_id,
+					data: vec![9, 9],
+				},
+			]
+		);
+	}
+}
@@ -0,0 +1,254 @@
+//! Decoder for the BPF Upgradeable Loader program.
+//!
+//! The loader deploys and upgrades programs, and manages the authority allowed to upgrade
+//! them. Its instructions are bincode encoded with a four byte instruction tag. Decoding
+//! them allows monitors to alert when the code or the upgrade authority of a monitored
+//! program changes.
+
+use carbon_core::borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	serde_helpers::hex_bytes, variant_names, DecodedInstruction, InstructionDecoder,
+	InstructionType,
+};
+
+/// Address of the BPF Upgradeable Loader program
+pub const PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Decoder for BPF Upgradeable Loader instructions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BpfUpgradeableLoaderDecoder;
+
+/// Instructions of the BPF Upgradeable Loader program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BpfUpgradeableLoaderInstruction {
+	InitializeBuffer(InitializeBuffer),
+	Write(Write),
+	DeployWithMaxDataLen(DeployWithMaxDataLen),
+	Upgrade(Upgrade),
+	SetAuthority(SetAuthority),
+	Close(Close),
+	ExtendProgram(ExtendProgram),
+	SetAuthorityChecked(SetAuthorityChecked),
+}
+
+/// Initializes a buffer account holding program data to deploy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InitializeBuffer {}
+
+/// Writes program data into a buffer account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Write {
+	/// Offset at which the data is written
+	pub offset: u32,
+	/// Program data written to the buffer
+	#[serde(with = "hex_bytes")]
+	pub bytes: Vec<u8>,
+}
+
+/// Deploys a program from a buffer account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployWithMaxDataLen {
+	/// Maximum length the program data can grow to
+	pub max_data_len: u64,
+}
+
+/// Upgrades a program with the data of a buffer account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Upgrade {}
+
+/// Sets a new authority of a buffer or a program, or makes it immutable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetAuthority {}
+
+/// Closes a buffer, program or program data account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Close {}
+
+/// Extends the program data account of a program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtendProgram {
+	/// Number of bytes added to the program data account
+	pub additional_bytes: u32,
+}
+
+/// Sets a new authority of a buffer or a program, requiring its signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetAuthorityChecked {}
+
+impl BpfUpgradeableLoaderInstruction {
+	/// Decodes BPF Upgradeable Loader instruction data
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, starting with the instruction tag
+	///
+	/// # Returns
+	/// The decoded instruction, or `None` if the data is not a valid instruction
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let mut rest = data;
+		let tag: u32 = read(&mut rest)?;
+		let instruction = match tag {
+			0 => Self::InitializeBuffer(InitializeBuffer {}),
+			1 => {
+				let offset = read(&mut rest)?;
+				let len = usize::try_from(read::<u64>(&mut rest)?).ok()?;
+				Self::Write(Write {
+					offset,
+					bytes: rest.get(..len)?.to_vec(),
+				})
+			}
+			2 => Self::DeployWithMaxDataLen(DeployWithMaxDataLen {
+				max_data_len: read(&mut rest)?,
+			}),
+			3 => Self::Upgrade(Upgrade {}),
+			4 => Self::SetAuthority(SetAuthority {}),
+			5 => Self::Close(Close {}),
+			6 => Self::ExtendProgram(ExtendProgram {
+				additional_bytes: read(&mut rest)?,
+			}),
+			7 => Self::SetAuthorityChecked(SetAuthorityChecked {}),
+			_ => return None,
+		};
+		Some(instruction)
+	}
+}
+
+/// Reads a little-endian value from the front of `data`
+fn read<T: BorshDeserialize>(data: &mut &[u8]) -> Option<T> {
+	T::deserialize(data).ok()
+}
+
+/// Returns the address of the program data account of an upgradeable program
+pub fn programdata_address(program: &Pubkey) -> Pubkey {
+	Pubkey::find_program_address(&[program.as_ref()], &PROGRAM_ID).0
+}
+
+/// Returns true if a loader instruction acts on the given program
+///
+/// Instructions reference either the program itself (e.g. `Upgrade`) or only its program
+/// data account (e.g. `SetAuthority`), so both addresses are checked.
+///
+/// # Arguments
+/// * `instruction` - Instruction of the BPF Upgradeable Loader
+/// * `program` - Address of the upgradeable program
+pub fn targets_program(instruction: &Instruction, program: &Pubkey) -> bool {
+	if instruction.program_id != PROGRAM_ID {
+		return false;
+	}
+	let programdata = programdata_address(program);
+	instruction
+		.accounts
+		.iter()
+		.any(|account| account.pubkey == *program || account.pubkey == programdata)
+}
+
+impl InstructionDecoder<'_> for BpfUpgradeableLoaderDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if instruction.program_id != PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::BpfUpgradeableLoader(BpfUpgradeableLoaderInstruction::decode(
+				&instruction.data,
+			)?),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::BPF_UPGRADEABLE_LOADER_INSTRUCTION_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use solana_sdk::instruction::AccountMeta;
+
+	#[test]
+	fn test_decode_upgrade_and_set_authority() {
+		assert_eq!(
+			BpfUpgradeableLoaderInstruction::decode(&3u32.to_le_bytes()),
+			Some(BpfUpgradeableLoaderInstruction::Upgrade(Upgrade {}))
+		);
+		assert_eq!(
+			BpfUpgradeableLoaderInstruction::decode(&4u32.to_le_bytes()),
+			Some(BpfUpgradeableLoaderInstruction::SetAuthority(
+				SetAuthority {}
+			))
+		);
+		assert_eq!(
+			BpfUpgradeableLoaderInstruction::decode(&7u32.to_le_bytes()),
+			Some(BpfUpgradeableLoaderInstruction::SetAuthorityChecked(
+				SetAuthorityChecked {}
+			))
+		);
+	}
+
+	#[test]
+	fn test_decode_write_and_deploy() {
+		let mut data = 1u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&16u32.to_le_bytes());
+		data.extend_from_slice(&2u64.to_le_bytes());
+		data.extend_from_slice(&[0xbe, 0xef]);
+		assert_eq!(
+			BpfUpgradeableLoaderInstruction::decode(&data),
+			Some(BpfUpgradeableLoaderInstruction::Write(Write {
+				offset: 16,
+				bytes: vec![0xbe, 0xef],
+			}))
+		);
+
+		let mut data = 2u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&4096u64.to_le_bytes());
+		assert_eq!(
+			BpfUpgradeableLoaderInstruction::decode(&data),
+			Some(BpfUpgradeableLoaderInstruction::DeployWithMaxDataLen(
+				DeployWithMaxDataLen { max_data_len: 4096 }
+			))
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_data() {
+		assert!(BpfUpgradeableLoaderInstruction::decode(&[]).is_none());
+		assert!(BpfUpgradeableLoaderInstruction::decode(&[3]).is_none());
+		assert!(BpfUpgradeableLoaderInstruction::decode(&9u32.to_le_bytes()).is_none());
+
+		let mut data = 1u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data.extend_from_slice(&8u64.to_le_bytes());
+		assert!(BpfUpgradeableLoaderInstruction::decode(&data).is_none());
+	}
+
+	#[test]
+	fn test_targets_program() {
+		let program = Pubkey::new_unique();
+		let set_authority = Instruction {
+			program_id: PROGRAM_ID,
+			accounts: vec![
+				AccountMeta::new(programdata_address(&program), false),
+				AccountMeta::new_readonly(Pubkey::new_unique(), true),
+			],
+			data: 4u32.to_le_bytes().to_vec(),
+		};
+		assert!(targets_program(&set_authority, &program));
+		assert!(!targets_program(&set_authority, &Pubkey::new_unique()));
+
+		let other_program = Instruction {
+			program_id: Pubkey::new_unique(),
+			..set_authority
+		};
+		assert!(!targets_program(&other_program, &program));
+	}
+}
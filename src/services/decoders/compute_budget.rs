@@ -0,0 +1,150 @@
+//! Parser for the Compute Budget program.
+//!
+//! Transactions set their compute unit limit and price through Compute Budget
+//! instructions, which together determine the priority fee paid on top of the base fee.
+//! Instructions are borsh encoded with a one byte instruction tag.
+
+use carbon_core::borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::models::SolanaDecodedInstruction;
+
+/// Address of the Compute Budget program
+pub const PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("ComputeBudget111111111111111111111111111111");
+
+/// Compute units granted to each instruction when the transaction sets no limit
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// Maximum number of compute units of a transaction
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// Number of micro-lamports in a lamport
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Compute budget requested by a transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ComputeBudget {
+	/// Price of a compute unit in micro-lamports, if set
+	pub compute_unit_price: Option<u64>,
+	/// Maximum number of compute units the transaction may consume, if set
+	pub compute_unit_limit: Option<u32>,
+	/// Number of instructions not targeting the Compute Budget program
+	instruction_count: u64,
+}
+
+impl ComputeBudget {
+	/// Parses the Compute Budget instructions of a transaction
+	///
+	/// When an instruction is repeated, the last one wins. Invalid instructions are ignored.
+	///
+	/// # Arguments
+	/// * `instructions` - The top-level instructions of the transaction
+	pub fn from_instructions(instructions: &[SolanaDecodedInstruction<Vec<u8>>]) -> Self {
+		let mut budget = Self::default();
+		for instruction in instructions {
+			if instruction.program_id != PROGRAM_ID {
+				budget.instruction_count += 1;
+				continue;
+			}
+
+			let mut rest = instruction.data.as_slice();
+			match read::<u8>(&mut rest) {
+				Some(2) => {
+					if let Some(limit) = read(&mut rest) {
+						budget.compute_unit_limit = Some(limit);
+					}
+				}
+				Some(3) => {
+					if let Some(price) = read(&mut rest) {
+						budget.compute_unit_price = Some(price);
+					}
+				}
+				_ => {}
+			}
+		}
+		budget
+	}
+
+	/// Returns the compute unit limit, defaulting to 200,000 units per instruction
+	pub fn effective_compute_unit_limit(&self) -> u64 {
+		self.compute_unit_limit
+			.map(u64::from)
+			.unwrap_or(self.instruction_count * DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT)
+			.min(MAX_COMPUTE_UNIT_LIMIT)
+	}
+
+	/// Returns the priority fee in lamports, rounded up like the runtime does
+	pub fn priority_fee_lamports(&self) -> u64 {
+		let micro_lamports = u128::from(self.compute_unit_price.unwrap_or_default())
+			* u128::from(self.effective_compute_unit_limit());
+		micro_lamports
+			.div_ceil(MICRO_LAMPORTS_PER_LAMPORT)
+			.try_into()
+			.unwrap_or(u64::MAX)
+	}
+}
+
+/// Reads a little-endian value from the front of `data`
+fn read<T: BorshDeserialize>(data: &mut &[u8]) -> Option<T> {
+	T::deserialize(data).ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn instruction(program_id: Pubkey, data: Vec<u8>) -> SolanaDecodedInstruction<Vec<u8>> {
+		SolanaDecodedInstruction {
+			program_id,
+			data,
+			accounts: vec![],
+		}
+	}
+
+	#[test]
+	fn test_compute_budget_from_instructions() {
+		let program_id = Pubkey::new_unique();
+		let budget = ComputeBudget::from_instructions(&[
+			instruction(
+				PROGRAM_ID,
+				[vec![2], 300_000u32.to_le_bytes().to_vec()].concat(),
+			),
+			instruction(
+				PROGRAM_ID,
+				[vec![3], 2_500u64.to_le_bytes().to_vec()].concat(),
+			),
+			instruction(program_id, vec![1, 2, 3]),
+		]);
+
+		assert_eq!(budget.compute_unit_limit, Some(300_000));
+		assert_eq!(budget.compute_unit_price, Some(2_500));
+		assert_eq!(budget.effective_compute_unit_limit(), 300_000);
+		assert_eq!(budget.priority_fee_lamports(), 750);
+	}
+
+	#[test]
+	fn test_compute_budget_defaults() {
+		let program_id = Pubkey::new_unique();
+		let budget = ComputeBudget::from_instructions(&[
+			instruction(
+				PROGRAM_ID,
+				[vec![3], 1_001u64.to_le_bytes().to_vec()].concat(),
+			),
+			instruction(program_id, vec![]),
+			instruction(program_id, vec![]),
+			// Truncated instructions are ignored
+			instruction(PROGRAM_ID, vec![2, 1]),
+		]);
+
+		assert_eq!(budget.compute_unit_limit, None);
+		assert_eq!(budget.effective_compute_unit_limit(), 400_000);
+		// 1,001 * 400,000 micro-lamports, rounded up
+		assert_eq!(budget.priority_fee_lamports(), 401);
+
+		assert_eq!(
+			ComputeBudget::from_instructions(&[]).priority_fee_lamports(),
+			0
+		);
+	}
+}
@@ -0,0 +1,1061 @@
+//! Anchor IDL based instruction decoding.
+//!
+//! Provides a generic decoder for Anchor programs that are not compiled into the crate.
+//! Instructions are identified by their 8-byte discriminator and their arguments are
+//! decoded with Borsh according to the types described in the IDL. Program accounts are
+//! decoded the same way, using the account discriminators of the IDL, and so are the
+//! events emitted by the program, using the event discriminators.
+//!
+//! IDLs are either provided in the contract spec of a program or read from the account
+//! in which Anchor publishes them on-chain.
+
+use std::{collections::HashMap, io::Read};
+
+use flate2::read::ZlibDecoder;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use solana_account::Account;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	AccountDecoder, AccountType, CustomAccount, CustomInstruction, DecodedAccount,
+	DecodedInstruction, DecoderError, InstructionDecoder, InstructionType,
+};
+
+/// Size of the Anchor instruction discriminator in bytes
+pub const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
+
+/// Seed of the account in which Anchor publishes the IDL of a program
+pub const IDL_ACCOUNT_SEED: &str = "anchor:idl";
+
+/// Size of the header of an IDL account: discriminator, authority and data length
+const IDL_ACCOUNT_HEADER_LEN: usize = ANCHOR_DISCRIMINATOR_LEN + 32 + 4;
+
+/// Returns the address of the account in which Anchor publishes the IDL of a program
+///
+/// As done by `anchor idl init`, the account is derived with `create_with_seed` from the
+/// program address found without seeds.
+pub fn idl_account_address(program_id: &Pubkey) -> Option<Pubkey> {
+	let (base, _) = Pubkey::find_program_address(&[], program_id);
+	Pubkey::create_with_seed(&base, IDL_ACCOUNT_SEED, program_id).ok()
+}
+
+/// Anchor IDL describing the instructions and types of a program
+///
+/// Both the current (0.30+) and the legacy IDL formats are supported. For legacy
+/// IDLs, missing instruction discriminators are derived from the instruction name.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnchorIdl {
+	/// Program address, if present in the IDL
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub address: Option<String>,
+	/// Program name (legacy IDL format)
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Program metadata
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub metadata: Option<IdlMetadata>,
+	/// Instructions exposed by the program
+	#[serde(default)]
+	pub instructions: Vec<IdlInstruction>,
+	/// Accounts owned by the program
+	#[serde(default)]
+	pub accounts: Vec<IdlAccountDef>,
+	/// Events emitted by the program
+	#[serde(default)]
+	pub events: Vec<IdlEvent>,
+	/// User defined types referenced by instructions
+	#[serde(default)]
+	pub types: Vec<IdlTypeDef>,
+}
+
+/// Program metadata section of an Anchor IDL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlMetadata {
+	/// Program name
+	pub name: String,
+	/// Program version
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub version: Option<String>,
+}
+
+/// Instruction definition in an Anchor IDL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlInstruction {
+	/// Instruction name
+	pub name: String,
+	/// Instruction discriminator, derived from the name when missing
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub discriminator: Option<Vec<u8>>,
+	/// Accounts expected by the instruction, in order
+	#[serde(default)]
+	pub accounts: Vec<IdlInstructionAccount>,
+	/// Instruction arguments, in order
+	#[serde(default)]
+	pub args: Vec<IdlField>,
+}
+
+/// Account definition in an Anchor IDL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlAccountDef {
+	/// Account type name
+	pub name: String,
+	/// Account discriminator, derived from the name when missing
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub discriminator: Option<Vec<u8>>,
+	/// Inline account type (legacy IDL format). In the current format the type is
+	/// declared in `types` under the same name.
+	#[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+	pub ty: Option<IdlTypeDefTy>,
+}
+
+/// Event definition in an Anchor IDL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlEvent {
+	/// Event type name
+	pub name: String,
+	/// Event discriminator, derived from the name when missing
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub discriminator: Option<Vec<u8>>,
+	/// Inline event fields (legacy IDL format). In the current format the type is
+	/// declared in `types` under the same name.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub fields: Option<Vec<IdlField>>,
+}
+
+/// Account (or group of accounts) expected by an instruction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlInstructionAccount {
+	/// Account name
+	pub name: String,
+	/// Nested accounts when the entry is a composite account group
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub accounts: Vec<IdlInstructionAccount>,
+}
+
+/// Named field of an instruction or struct
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlField {
+	/// Field name
+	pub name: String,
+	/// Field type
+	#[serde(rename = "type")]
+	pub ty: IdlType,
+}
+
+/// User defined type in an Anchor IDL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlTypeDef {
+	/// Type name
+	pub name: String,
+	/// Type definition
+	#[serde(rename = "type")]
+	pub ty: IdlTypeDefTy,
+}
+
+/// Body of a user defined type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum IdlTypeDefTy {
+	/// Struct with named or tuple fields
+	Struct {
+		#[serde(default)]
+		fields: IdlDefinedFields,
+	},
+	/// Enum with optional variant payloads
+	Enum { variants: Vec<IdlEnumVariant> },
+	/// Type alias
+	Type { alias: IdlType },
+}
+
+/// Fields of a struct or enum variant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefinedFields {
+	/// Named fields
+	Named(Vec<IdlField>),
+	/// Tuple fields
+	Tuple(Vec<IdlType>),
+}
+
+impl Default for IdlDefinedFields {
+	fn default() -> Self {
+		Self::Named(Vec::new())
+	}
+}
+
+/// Variant of a user defined enum
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IdlEnumVariant {
+	/// Variant name
+	pub name: String,
+	/// Variant payload
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub fields: Option<IdlDefinedFields>,
+}
+
+/// Type of a field, using the Anchor IDL notation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdlType {
+	/// Primitive type (e.g. `"u64"`, `"pubkey"`)
+	Primitive(IdlPrimitiveType),
+	/// Composite type (e.g. `{"vec": "u8"}`)
+	Composite(IdlCompositeType),
+}
+
+/// Primitive Borsh types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdlPrimitiveType {
+	Bool,
+	U8,
+	I8,
+	U16,
+	I16,
+	U32,
+	I32,
+	U64,
+	I64,
+	U128,
+	I128,
+	F32,
+	F64,
+	Bytes,
+	String,
+	#[serde(alias = "publicKey")]
+	Pubkey,
+}
+
+/// Composite Borsh types
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IdlCompositeType {
+	/// Length-prefixed vector
+	Vec(Box<IdlType>),
+	/// Optional value prefixed with a presence byte
+	Option(Box<IdlType>),
+	/// Fixed-size array
+	Array(Box<IdlType>, usize),
+	/// Reference to a user defined type
+	Defined(IdlDefinedTypeRef),
+}
+
+/// Reference to a user defined type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IdlDefinedTypeRef {
+	/// Legacy format: `{"defined": "Name"}`
+	Name(String),
+	/// Current format: `{"defined": {"name": "Name"}}`
+	Object { name: String },
+}
+
+impl IdlDefinedTypeRef {
+	/// Returns the name of the referenced type
+	pub fn name(&self) -> &str {
+		match self {
+			Self::Name(name) => name,
+			Self::Object { name } => name,
+		}
+	}
+}
+
+/// Derives the discriminator of an Anchor instruction from its name
+///
+/// Anchor programs prefix instruction data with `sha256("global:<snake_case_name>")[..8]`.
+pub fn anchor_instruction_discriminator(name: &str) -> Vec<u8> {
+	let preimage = format!("global:{}", to_snake_case(name));
+	Sha256::digest(preimage.as_bytes())[..ANCHOR_DISCRIMINATOR_LEN].to_vec()
+}
+
+impl IdlInstruction {
+	/// Returns the instruction discriminator
+	///
+	/// Falls back to the Anchor convention `sha256("global:<snake_case_name>")[..8]`
+	/// when the IDL does not specify it.
+	pub fn discriminator(&self) -> Vec<u8> {
+		match &self.discriminator {
+			Some(discriminator) => discriminator.clone(),
+			None => anchor_instruction_discriminator(&self.name),
+		}
+	}
+
+	/// Returns the names of the instruction accounts in positional order
+	///
+	/// Nested account groups are flattened.
+	pub fn account_names(&self) -> Vec<String> {
+		fn flatten(accounts: &[IdlInstructionAccount], names: &mut Vec<String>) {
+			for account in accounts {
+				if account.accounts.is_empty() {
+					names.push(account.name.clone());
+				} else {
+					flatten(&account.accounts, names);
+				}
+			}
+		}
+
+		let mut names = Vec::new();
+		flatten(&self.accounts, &mut names);
+		names
+	}
+}
+
+impl IdlAccountDef {
+	/// Returns the account discriminator
+	///
+	/// Falls back to the Anchor convention `sha256("account:<Name>")[..8]` when the IDL
+	/// does not specify it.
+	pub fn discriminator(&self) -> Vec<u8> {
+		match &self.discriminator {
+			Some(discriminator) => discriminator.clone(),
+			None => {
+				let preimage = format!("account:{}", self.name);
+				Sha256::digest(preimage.as_bytes())[..ANCHOR_DISCRIMINATOR_LEN].to_vec()
+			}
+		}
+	}
+}
+
+impl IdlEvent {
+	/// Returns the event discriminator
+	///
+	/// Falls back to the Anchor convention `sha256("event:<Name>")[..8]` when the IDL
+	/// does not specify it.
+	pub fn discriminator(&self) -> Vec<u8> {
+		match &self.discriminator {
+			Some(discriminator) => discriminator.clone(),
+			None => {
+				let preimage = format!("event:{}", self.name);
+				Sha256::digest(preimage.as_bytes())[..ANCHOR_DISCRIMINATOR_LEN].to_vec()
+			}
+		}
+	}
+}
+
+impl AnchorIdl {
+	/// Parses the IDL published on-chain by Anchor
+	///
+	/// The IDL account holds its discriminator, the IDL authority, the length of the IDL
+	/// and the IDL JSON compressed with zlib. Missing discriminators are resolved.
+	///
+	/// # Arguments
+	/// * `data` - Data of the IDL account
+	pub fn from_account_data(data: &[u8]) -> Result<Self, DecoderError> {
+		let header = data.get(..IDL_ACCOUNT_HEADER_LEN).ok_or_else(|| {
+			DecoderError::InvalidData("IDL account data is too short".to_string())
+		})?;
+		let mut len_bytes = [0u8; 4];
+		len_bytes.copy_from_slice(&header[IDL_ACCOUNT_HEADER_LEN - 4..]);
+		let len = u32::from_le_bytes(len_bytes) as usize;
+		let compressed = data
+			.get(IDL_ACCOUNT_HEADER_LEN..IDL_ACCOUNT_HEADER_LEN + len)
+			.ok_or_else(|| {
+				DecoderError::InvalidData(format!("IDL account data is shorter than {}", len))
+			})?;
+
+		let mut json = Vec::new();
+		ZlibDecoder::new(compressed)
+			.read_to_end(&mut json)
+			.map_err(|e| DecoderError::InvalidData(format!("Failed to decompress IDL: {}", e)))?;
+		let mut idl: Self = serde_json::from_slice(&json)
+			.map_err(|e| DecoderError::InvalidData(format!("Failed to parse IDL: {}", e)))?;
+		idl.resolve_discriminators();
+		Ok(idl)
+	}
+
+	/// Returns the program id declared in the IDL, if any
+	pub fn program_id(&self) -> Option<Pubkey> {
+		self.address
+			.as_ref()
+			.and_then(|address| address.parse().ok())
+	}
+
+	/// Stores the discriminators the IDL does not specify
+	///
+	/// Legacy IDLs omit them, so they would otherwise be derived from the names on every
+	/// lookup. Called once when the IDL is loaded.
+	pub fn resolve_discriminators(&mut self) {
+		for instruction in &mut self.instructions {
+			instruction.discriminator = Some(instruction.discriminator());
+		}
+		for account in &mut self.accounts {
+			account.discriminator = Some(account.discriminator());
+		}
+		for event in &mut self.events {
+			event.discriminator = Some(event.discriminator());
+		}
+	}
+
+	/// Finds the instruction whose discriminator prefixes the given data
+	pub fn find_instruction(&self, data: &[u8]) -> Option<&IdlInstruction> {
+		self.instructions.iter().find(|instruction| {
+			let discriminator = instruction.discriminator();
+			!discriminator.is_empty() && data.starts_with(&discriminator)
+		})
+	}
+
+	/// Decodes instruction data into a JSON object of named arguments
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, including the discriminator
+	///
+	/// # Returns
+	/// The matched instruction definition and its decoded arguments
+	pub fn decode_instruction_data(
+		&self,
+		data: &[u8],
+	) -> Result<(&IdlInstruction, Value), DecoderError> {
+		let instruction = self.find_instruction(data).ok_or_else(|| {
+			DecoderError::InvalidData("No instruction matches the discriminator".to_string())
+		})?;
+		let mut reader = BorshReader::new(
+			&data[instruction.discriminator().len()..],
+			self.type_definitions(),
+		);
+		let args = reader.read_named_fields(&instruction.args)?;
+		Ok((instruction, args))
+	}
+
+	/// Finds the account definition whose discriminator prefixes the given data
+	pub fn find_account(&self, data: &[u8]) -> Option<&IdlAccountDef> {
+		self.accounts.iter().find(|account| {
+			let discriminator = account.discriminator();
+			!discriminator.is_empty() && data.starts_with(&discriminator)
+		})
+	}
+
+	/// Decodes account data into a JSON object of named fields
+	///
+	/// # Arguments
+	/// * `data` - Raw account data, including the discriminator
+	///
+	/// # Returns
+	/// The matched account definition and its decoded fields
+	pub fn decode_account_data(
+		&self,
+		data: &[u8],
+	) -> Result<(&IdlAccountDef, Value), DecoderError> {
+		let account = self.find_account(data).ok_or_else(|| {
+			DecoderError::InvalidData("No account matches the discriminator".to_string())
+		})?;
+		let types = self.type_definitions();
+		let type_def = match &account.ty {
+			Some(ty) => IdlTypeDef {
+				name: account.name.clone(),
+				ty: ty.clone(),
+			},
+			None => (*types.get(account.name.as_str()).ok_or_else(|| {
+				DecoderError::InvalidData(format!("Unknown account type: {}", account.name))
+			})?)
+			.clone(),
+		};
+		let mut reader = BorshReader::new(&data[account.discriminator().len()..], types);
+		let fields = reader.read_type_def(&type_def)?;
+		Ok((account, fields))
+	}
+
+	/// Finds the event definition whose discriminator prefixes the given data
+	pub fn find_event(&self, data: &[u8]) -> Option<&IdlEvent> {
+		self.events.iter().find(|event| {
+			let discriminator = event.discriminator();
+			!discriminator.is_empty() && data.starts_with(&discriminator)
+		})
+	}
+
+	/// Decodes event data into a JSON object of named fields
+	///
+	/// # Arguments
+	/// * `data` - Raw event data, including the discriminator
+	///
+	/// # Returns
+	/// The matched event definition and its decoded fields
+	pub fn decode_event_data(&self, data: &[u8]) -> Result<(&IdlEvent, Value), DecoderError> {
+		let event = self.find_event(data).ok_or_else(|| {
+			DecoderError::InvalidData("No event matches the discriminator".to_string())
+		})?;
+		let types = self.type_definitions();
+		let type_def = match &event.fields {
+			Some(fields) => IdlTypeDef {
+				name: event.name.clone(),
+				ty: IdlTypeDefTy::Struct {
+					fields: IdlDefinedFields::Named(fields.clone()),
+				},
+			},
+			None => (*types.get(event.name.as_str()).ok_or_else(|| {
+				DecoderError::InvalidData(format!("Unknown event type: {}", event.name))
+			})?)
+			.clone(),
+		};
+		let mut reader = BorshReader::new(&data[event.discriminator().len()..], types);
+		let fields = reader.read_type_def(&type_def)?;
+		Ok((event, fields))
+	}
+
+	fn type_definitions(&self) -> HashMap<&str, &IdlTypeDef> {
+		self.types
+			.iter()
+			.map(|type_def| (type_def.name.as_str(), type_def))
+			.collect()
+	}
+}
+
+impl InstructionDecoder<'_> for AnchorIdl {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if let Some(program_id) = self.program_id() {
+			if program_id != instruction.program_id {
+				return None;
+			}
+		}
+
+		let (definition, args) = self.decode_instruction_data(&instruction.data).ok()?;
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::Custom(CustomInstruction {
+				name: definition.name.clone(),
+				args,
+				accounts: definition.account_names(),
+			}),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+}
+
+impl AccountDecoder<'_> for AnchorIdl {
+	type AccountType = AccountType;
+
+	fn decode_account(&self, account: &Account) -> Option<DecodedAccount<Self::AccountType>> {
+		if let Some(program_id) = self.program_id() {
+			if program_id != account.owner {
+				return None;
+			}
+		}
+
+		let (definition, data) = self.decode_account_data(&account.data).ok()?;
+		Some(DecodedAccount {
+			lamports: account.lamports,
+			data: AccountType::Custom(CustomAccount {
+				name: definition.name.clone(),
+				data,
+			}),
+			owner: account.owner,
+			executable: account.executable,
+			rent_epoch: account.rent_epoch,
+		})
+	}
+}
+
+/// Sequential Borsh reader producing JSON values
+pub(crate) struct BorshReader<'a> {
+	data: &'a [u8],
+	types: HashMap<&'a str, &'a IdlTypeDef>,
+}
+
+impl<'a> BorshReader<'a> {
+	/// Creates a reader over the given data using the provided type definitions
+	pub(crate) fn new(data: &'a [u8], types: HashMap<&'a str, &'a IdlTypeDef>) -> Self {
+		Self { data, types }
+	}
+
+	fn take(&mut self, len: usize) -> Result<&'a [u8], DecoderError> {
+		if self.data.len() < len {
+			return Err(DecoderError::InvalidData(format!(
+				"Unexpected end of data: expected {} bytes, found {}",
+				len,
+				self.data.len()
+			)));
+		}
+		let (head, tail) = self.data.split_at(len);
+		self.data = tail;
+		Ok(head)
+	}
+
+	fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecoderError> {
+		let mut array = [0u8; N];
+		array.copy_from_slice(self.take(N)?);
+		Ok(array)
+	}
+
+	fn read_len(&mut self) -> Result<usize, DecoderError> {
+		Ok(u32::from_le_bytes(self.take_array()?) as usize)
+	}
+
+	/// Reads a sequence of named fields into a JSON object
+	pub(crate) fn read_named_fields(&mut self, fields: &[IdlField]) -> Result<Value, DecoderError> {
+		let mut object = Map::new();
+		for field in fields {
+			object.insert(field.name.clone(), self.read(&field.ty)?);
+		}
+		Ok(Value::Object(object))
+	}
+
+	fn read_defined_fields(&mut self, fields: &IdlDefinedFields) -> Result<Value, DecoderError> {
+		match fields {
+			IdlDefinedFields::Named(fields) => self.read_named_fields(fields),
+			IdlDefinedFields::Tuple(types) => Ok(Value::Array(
+				types
+					.iter()
+					.map(|ty| self.read(ty))
+					.collect::<Result<_, _>>()?,
+			)),
+		}
+	}
+
+	/// Reads a single value of the given type
+	pub(crate) fn read(&mut self, ty: &IdlType) -> Result<Value, DecoderError> {
+		match ty {
+			IdlType::Primitive(primitive) => self.read_primitive(*primitive),
+			IdlType::Composite(IdlCompositeType::Vec(inner)) => {
+				let len = self.read_len()?;
+				let items = (0..len)
+					.map(|_| self.read(inner))
+					.collect::<Result<_, _>>()?;
+				Ok(Value::Array(items))
+			}
+			IdlType::Composite(IdlCompositeType::Option(inner)) => match self.take(1)?[0] {
+				0 => Ok(Value::Null),
+				1 => self.read(inner),
+				tag => Err(DecoderError::InvalidData(format!(
+					"Invalid option tag: {}",
+					tag
+				))),
+			},
+			IdlType::Composite(IdlCompositeType::Array(inner, len)) => {
+				let items = (0..*len)
+					.map(|_| self.read(inner))
+					.collect::<Result<_, _>>()?;
+				Ok(Value::Array(items))
+			}
+			IdlType::Composite(IdlCompositeType::Defined(reference)) => {
+				let type_def = *self.types.get(reference.name()).ok_or_else(|| {
+					DecoderError::InvalidData(format!("Unknown type: {}", reference.name()))
+				})?;
+				self.read_type_def(type_def)
+			}
+		}
+	}
+
+	fn read_type_def(&mut self, type_def: &IdlTypeDef) -> Result<Value, DecoderError> {
+		match &type_def.ty {
+			IdlTypeDefTy::Struct { fields } => self.read_defined_fields(fields),
+			IdlTypeDefTy::Type { alias } => self.read(alias),
+			IdlTypeDefTy::Enum { variants } => {
+				let index = self.take(1)?[0] as usize;
+				let variant = variants.get(index).ok_or_else(|| {
+					DecoderError::InvalidData(format!(
+						"Invalid variant index {} for enum {}",
+						index, type_def.name
+					))
+				})?;
+				match &variant.fields {
+					None => Ok(Value::String(variant.name.clone())),
+					Some(fields) => {
+						let mut object = Map::new();
+						object.insert(variant.name.clone(), self.read_defined_fields(fields)?);
+						Ok(Value::Object(object))
+					}
+				}
+			}
+		}
+	}
+
+	fn read_primitive(&mut self, primitive: IdlPrimitiveType) -> Result<Value, DecoderError> {
+		let value = match primitive {
+			IdlPrimitiveType::Bool => Value::Bool(self.take(1)?[0] != 0),
+			IdlPrimitiveType::U8 => Value::from(self.take(1)?[0]),
+			IdlPrimitiveType::I8 => Value::from(self.take(1)?[0] as i8),
+			IdlPrimitiveType::U16 => Value::from(u16::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::I16 => Value::from(i16::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::U32 => Value::from(u32::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::I32 => Value::from(i32::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::U64 => Value::from(u64::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::I64 => Value::from(i64::from_le_bytes(self.take_array()?)),
+			// 128-bit integers do not fit in JSON numbers and are kept as strings
+			IdlPrimitiveType::U128 => {
+				Value::String(u128::from_le_bytes(self.take_array()?).to_string())
+			}
+			IdlPrimitiveType::I128 => {
+				Value::String(i128::from_le_bytes(self.take_array()?).to_string())
+			}
+			IdlPrimitiveType::F32 => Value::from(f32::from_le_bytes(self.take_array()?) as f64),
+			IdlPrimitiveType::F64 => Value::from(f64::from_le_bytes(self.take_array()?)),
+			IdlPrimitiveType::Bytes => {
+				let len = self.read_len()?;
+				Value::String(format!("0x{}", hex::encode(self.take(len)?)))
+			}
+			IdlPrimitiveType::String => {
+				let len = self.read_len()?;
+				let bytes = self.take(len)?;
+				Value::String(String::from_utf8(bytes.to_vec()).map_err(|e| {
+					DecoderError::InvalidData(format!("Invalid UTF-8 string: {}", e))
+				})?)
+			}
+			IdlPrimitiveType::Pubkey => {
+				Value::String(Pubkey::new_from_array(self.take_array()?).to_string())
+			}
+		};
+		Ok(value)
+	}
+}
+
+/// Converts a camelCase or PascalCase name into snake_case
+fn to_snake_case(name: &str) -> String {
+	let mut result = String::with_capacity(name.len() + 4);
+	for (i, c) in name.chars().enumerate() {
+		if c.is_uppercase() {
+			if i > 0 {
+				result.push('_');
+			}
+			result.extend(c.to_lowercase());
+		} else {
+			result.push(c);
+		}
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+	use solana_sdk::instruction::AccountMeta;
+
+	fn create_test_idl() -> AnchorIdl {
+		serde_json::from_value(json!({
+			"address": "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD",
+			"metadata": { "name": "test_program", "version": "0.1.0" },
+			"instructions": [
+				{
+					"name": "deposit",
+					"discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+					"accounts": [
+						{ "name": "owner", "writable": true, "signer": true },
+						{ "name": "reserve", "writable": true }
+					],
+					"args": [
+						{ "name": "amount", "type": "u64" },
+						{ "name": "memo", "type": { "option": "string" } },
+						{ "name": "config", "type": { "defined": { "name": "Config" } } }
+					]
+				}
+			],
+			"accounts": [
+				{ "name": "Vault", "discriminator": [9, 8, 7, 6, 5, 4, 3, 2] }
+			],
+			"events": [
+				{ "name": "Deposited", "discriminator": [5, 5, 5, 5, 5, 5, 5, 5] }
+			],
+			"types": [
+				{
+					"name": "Deposited",
+					"type": {
+						"kind": "struct",
+						"fields": [
+							{ "name": "owner", "type": "pubkey" },
+							{ "name": "amount", "type": "u64" }
+						]
+					}
+				},
+				{
+					"name": "Vault",
+					"type": {
+						"kind": "struct",
+						"fields": [
+							{ "name": "owner", "type": "pubkey" },
+							{ "name": "balance", "type": "u64" }
+						]
+					}
+				},
+				{
+					"name": "Config",
+					"type": {
+						"kind": "struct",
+						"fields": [
+							{ "name": "mode", "type": { "defined": { "name": "Mode" } } },
+							{ "name": "authority", "type": "pubkey" }
+						]
+					}
+				},
+				{
+					"name": "Mode",
+					"type": {
+						"kind": "enum",
+						"variants": [{ "name": "Fixed" }, { "name": "Floating" }]
+					}
+				}
+			]
+		}))
+		.unwrap()
+	}
+
+	#[test]
+	fn test_legacy_discriminator_is_derived_from_name() {
+		let instruction = IdlInstruction {
+			name: "initializeMarket".to_string(),
+			discriminator: None,
+			accounts: vec![],
+			args: vec![],
+		};
+		let expected = Sha256::digest(b"global:initialize_market")[..8].to_vec();
+		assert_eq!(instruction.discriminator(), expected);
+	}
+
+	#[test]
+	fn test_from_account_data() {
+		use flate2::{write::ZlibEncoder, Compression};
+		use std::io::Write;
+
+		let json = json!({
+			"name": "program",
+			"instructions": [{ "name": "deposit", "accounts": [], "args": [] }]
+		});
+		let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+		encoder.write_all(json.to_string().as_bytes()).unwrap();
+		let compressed = encoder.finish().unwrap();
+
+		let mut data = vec![0u8; ANCHOR_DISCRIMINATOR_LEN];
+		data.extend_from_slice(Pubkey::new_unique().as_ref());
+		data.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+		data.extend_from_slice(&compressed);
+		// Trailing bytes of the account beyond the IDL are ignored
+		data.extend_from_slice(&[0u8; 16]);
+
+		let idl = AnchorIdl::from_account_data(&data).unwrap();
+		assert_eq!(idl.instructions[0].name, "deposit");
+		assert_eq!(
+			idl.instructions[0].discriminator,
+			Some(anchor_instruction_discriminator("deposit"))
+		);
+
+		assert!(AnchorIdl::from_account_data(&data[..IDL_ACCOUNT_HEADER_LEN + 4]).is_err());
+		assert!(AnchorIdl::from_account_data(&[0u8; 4]).is_err());
+	}
+
+	#[test]
+	fn test_resolve_discriminators_keeps_declared_ones() {
+		let mut idl = create_test_idl();
+		idl.instructions.push(IdlInstruction {
+			name: "withdraw".to_string(),
+			discriminator: None,
+			accounts: vec![],
+			args: vec![],
+		});
+
+		idl.resolve_discriminators();
+
+		assert_eq!(
+			idl.instructions[0].discriminator,
+			Some(vec![1, 2, 3, 4, 5, 6, 7, 8])
+		);
+		assert_eq!(
+			idl.instructions[1].discriminator,
+			Some(Sha256::digest(b"global:withdraw")[..8].to_vec())
+		);
+		assert_eq!(
+			idl.accounts[0].discriminator,
+			Some(vec![9, 8, 7, 6, 5, 4, 3, 2])
+		);
+	}
+
+	#[test]
+	fn test_legacy_idl_types_parse() {
+		let ty: IdlType = serde_json::from_value(json!({ "defined": "Config" })).unwrap();
+		assert_eq!(
+			ty,
+			IdlType::Composite(IdlCompositeType::Defined(IdlDefinedTypeRef::Name(
+				"Config".to_string()
+			)))
+		);
+		let ty: IdlType = serde_json::from_value(json!("publicKey")).unwrap();
+		assert_eq!(ty, IdlType::Primitive(IdlPrimitiveType::Pubkey));
+		let ty: IdlType = serde_json::from_value(json!({ "array": ["u8", 32] })).unwrap();
+		assert_eq!(
+			ty,
+			IdlType::Composite(IdlCompositeType::Array(
+				Box::new(IdlType::Primitive(IdlPrimitiveType::U8)),
+				32
+			))
+		);
+	}
+
+	#[test]
+	fn test_decode_instruction_with_idl() {
+		let idl = create_test_idl();
+		let authority = Pubkey::new_unique();
+
+		let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+		data.extend_from_slice(&500u64.to_le_bytes());
+		data.push(1);
+		data.extend_from_slice(&4u32.to_le_bytes());
+		data.extend_from_slice(b"test");
+		data.push(1);
+		data.extend_from_slice(authority.as_ref());
+
+		let instruction = Instruction {
+			program_id: idl.program_id().unwrap(),
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			data,
+		};
+
+		let decoded = idl.decode_instruction(&instruction).unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::Custom(CustomInstruction {
+				name: "deposit".to_string(),
+				args: json!({
+					"amount": 500,
+					"memo": "test",
+					"config": { "mode": "Floating", "authority": authority.to_string() }
+				}),
+				accounts: vec!["owner".to_string(), "reserve".to_string()],
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_rejects_other_program() {
+		let idl = create_test_idl();
+		let instruction = Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data: vec![1, 2, 3, 4, 5, 6, 7, 8],
+		};
+		assert!(idl.decode_instruction(&instruction).is_none());
+	}
+
+	#[test]
+	fn test_decode_unknown_discriminator_fails() {
+		let idl = create_test_idl();
+		assert!(idl
+			.decode_instruction_data(&[9, 9, 9, 9, 9, 9, 9, 9])
+			.is_err());
+	}
+
+	#[test]
+	fn test_decode_truncated_data_fails() {
+		let idl = create_test_idl();
+		let result = idl.decode_instruction_data(&[1, 2, 3, 4, 5, 6, 7, 8, 1, 0]);
+		assert!(matches!(result, Err(DecoderError::InvalidData(_))));
+	}
+
+	#[test]
+	fn test_decode_account_with_idl() {
+		let idl = create_test_idl();
+		let owner = Pubkey::new_unique();
+		let mut data = vec![9, 8, 7, 6, 5, 4, 3, 2];
+		data.extend_from_slice(owner.as_ref());
+		data.extend_from_slice(&42u64.to_le_bytes());
+		let account = Account {
+			lamports: 1_000,
+			data,
+			owner: idl.program_id().unwrap(),
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let decoded = idl.decode_account(&account).unwrap();
+		assert_eq!(decoded.lamports, 1_000);
+		assert_eq!(
+			decoded.data,
+			AccountType::Custom(CustomAccount {
+				name: "Vault".to_string(),
+				data: json!({ "owner": owner.to_string(), "balance": 42 }),
+			})
+		);
+
+		let other_owner = Account {
+			owner: Pubkey::new_unique(),
+			..account
+		};
+		assert!(idl.decode_account(&other_owner).is_none());
+	}
+
+	#[test]
+	fn test_decode_legacy_inline_account() {
+		let idl: AnchorIdl = serde_json::from_value(json!({
+			"accounts": [
+				{
+					"name": "Counter",
+					"type": { "kind": "struct", "fields": [{ "name": "count", "type": "u32" }] }
+				}
+			]
+		}))
+		.unwrap();
+		let mut data = Sha256::digest(b"account:Counter")[..8].to_vec();
+		data.extend_from_slice(&7u32.to_le_bytes());
+
+		let (definition, fields) = idl.decode_account_data(&data).unwrap();
+		assert_eq!(definition.name, "Counter");
+		assert_eq!(fields, json!({ "count": 7 }));
+	}
+
+	#[test]
+	fn test_decode_event_with_idl() {
+		let idl = create_test_idl();
+		let owner = Pubkey::new_unique();
+		let mut data = vec![5, 5, 5, 5, 5, 5, 5, 5];
+		data.extend_from_slice(owner.as_ref());
+		data.extend_from_slice(&42u64.to_le_bytes());
+
+		let (definition, fields) = idl.decode_event_data(&data).unwrap();
+		assert_eq!(definition.name, "Deposited");
+		assert_eq!(fields, json!({ "owner": owner.to_string(), "amount": 42 }));
+		assert!(idl.decode_event_data(&[1, 2, 3, 4, 5, 6, 7, 8]).is_err());
+	}
+
+	#[test]
+	fn test_decode_legacy_inline_event() {
+		let idl: AnchorIdl = serde_json::from_value(json!({
+			"events": [
+				{
+					"name": "Transferred",
+					"fields": [{ "name": "amount", "type": "u32", "index": false }]
+				}
+			]
+		}))
+		.unwrap();
+		let mut data = Sha256::digest(b"event:Transferred")[..8].to_vec();
+		data.extend_from_slice(&7u32.to_le_bytes());
+
+		let (definition, fields) = idl.decode_event_data(&data).unwrap();
+		assert_eq!(definition.name, "Transferred");
+		assert_eq!(fields, json!({ "amount": 7 }));
+	}
+
+	#[test]
+	fn test_account_names_flatten_groups() {
+		let instruction: IdlInstruction = serde_json::from_value(json!({
+			"name": "swap",
+			"accounts": [
+				{ "name": "user" },
+				{ "name": "pool", "accounts": [{ "name": "vault_a" }, { "name": "vault_b" }] }
+			],
+			"args": []
+		}))
+		.unwrap();
+		assert_eq!(
+			instruction.account_names(),
+			vec![
+				"user".to_string(),
+				"vault_a".to_string(),
+				"vault_b".to_string()
+			]
+		);
+	}
+
+	#[test]
+	fn test_to_snake_case() {
+		assert_eq!(
+			to_snake_case("depositReserveLiquidity"),
+			"deposit_reserve_liquidity"
+		);
+		assert_eq!(to_snake_case("already_snake"), "already_snake");
+	}
+}
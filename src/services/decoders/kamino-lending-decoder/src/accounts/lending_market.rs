@@ -1,9 +1,12 @@
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
 #[carbon(discriminator = "0xf6723262489d1c78")]
 pub struct LendingMarket {
     pub version: u64,
@@ -25,9 +28,11 @@ pub struct LendingMarket {
     pub risk_council: solana_pubkey::Pubkey,
     pub reserved1: [u8; 8],
     pub elevation_groups: [ElevationGroup; 32],
+    #[serde(with = "BigArray")]
     pub elevation_group_padding: [u64; 90],
     pub min_net_value_in_obligation_sf: u128,
     pub min_value_skip_liquidation_ltv_bf_checks: u64,
     pub name: [u8; 32],
+    #[serde(with = "BigArray")]
     pub padding1: [u64; 173],
 }
@@ -1,6 +1,6 @@
 use {
     super::KaminoLendingDecoder,
-    crate::PROGRAM_ID,
+    super::PROGRAM_ID,
     carbon_core::{account::AccountDecoder, deserialize::CarbonDeserialize},
 };
 pub mod lending_market;
@@ -12,6 +12,9 @@ pub mod short_url;
 pub mod user_metadata;
 pub mod user_state;
 
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub enum KaminoLendingAccount {
     UserState(Box<user_state::UserState>),
     LendingMarket(Box<lending_market::LendingMarket>),
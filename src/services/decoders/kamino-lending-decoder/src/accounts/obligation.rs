@@ -1,9 +1,12 @@
 use {
     super::super::types::*,
     carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
 };
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
 #[carbon(discriminator = "0xa8ce8d6a584caca7")]
 pub struct Obligation {
     pub tag: u64,
@@ -27,5 +30,6 @@ pub struct Obligation {
     pub borrowing_disabled: u8,
     pub reserved: [u8; 7],
     pub highest_borrow_factor_pct: u64,
+    #[serde(with = "BigArray")]
     pub padding3: [u64; 126],
 }
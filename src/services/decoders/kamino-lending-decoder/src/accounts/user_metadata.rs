@@ -1,12 +1,19 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
+};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
 #[carbon(discriminator = "0x9dd6dceb6287ab1c")]
 pub struct UserMetadata {
     pub referrer: solana_pubkey::Pubkey,
     pub bump: u64,
     pub user_lookup_table: solana_pubkey::Pubkey,
     pub owner: solana_pubkey::Pubkey,
+    #[serde(with = "BigArray")]
     pub padding1: [u64; 51],
+    #[serde(with = "BigArray")]
     pub padding2: [u64; 64],
 }
@@ -1,6 +1,11 @@
-use carbon_core::{borsh, CarbonDeserialize};
+use {
+    carbon_core::{borsh, CarbonDeserialize},
+    serde_big_array::BigArray,
+};
 
-#[derive(CarbonDeserialize, Debug)]
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
 #[carbon(discriminator = "0x48b155f94ca7ba7e")]
 pub struct UserState {
     pub user_id: u64,
@@ -19,5 +24,6 @@ pub struct UserState {
     pub bump: u64,
     pub delegatee: solana_pubkey::Pubkey,
     pub last_stake_ts: u64,
+    #[serde(with = "BigArray")]
     pub padding1: [u64; 50],
 }
@@ -1,4 +1,4 @@
-use crate::PROGRAM_ID;
+use super::PROGRAM_ID;
 
 use super::KaminoLendingDecoder;
 pub mod borrow_obligation_liquidity;
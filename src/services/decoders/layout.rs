@@ -0,0 +1,212 @@
+//! Declarative Borsh layouts for non-Anchor programs.
+//!
+//! A layout lists, for each instruction of a program, the discriminator prefix and
+//! the Borsh fields that follow it. This allows programs without an Anchor IDL to be
+//! decoded from the contract spec, similarly to how ABIs are used for EVM contracts.
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	idl::{BorshReader, IdlField, IdlTypeDef},
+	CustomInstruction, DecodedInstruction, DecoderError, InstructionDecoder, InstructionType,
+};
+
+/// Borsh layout of the instructions of a program
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgramLayout {
+	/// Program address the layout applies to, if restricted
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub program_id: Option<String>,
+	/// Instruction layouts
+	#[serde(default)]
+	pub instructions: Vec<InstructionLayout>,
+	/// User defined types referenced by instruction fields
+	#[serde(default)]
+	pub types: Vec<IdlTypeDef>,
+}
+
+/// Borsh layout of a single instruction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstructionLayout {
+	/// Instruction name
+	pub name: String,
+	/// Bytes that prefix the instruction data (e.g. a one byte instruction tag)
+	#[serde(default)]
+	pub discriminator: Vec<u8>,
+	/// Names of the instruction accounts, in order
+	#[serde(default)]
+	pub accounts: Vec<String>,
+	/// Fields encoded after the discriminator, in order
+	#[serde(default)]
+	pub fields: Vec<IdlField>,
+}
+
+impl ProgramLayout {
+	/// Returns the program id the layout is restricted to, if any
+	pub fn program_id(&self) -> Option<Pubkey> {
+		self.program_id
+			.as_ref()
+			.and_then(|address| address.parse().ok())
+	}
+
+	/// Finds the instruction layout matching the given data
+	///
+	/// When several discriminators prefix the data, the longest one wins.
+	pub fn find_instruction(&self, data: &[u8]) -> Option<&InstructionLayout> {
+		self.instructions
+			.iter()
+			.filter(|instruction| data.starts_with(&instruction.discriminator))
+			.max_by_key(|instruction| instruction.discriminator.len())
+	}
+
+	/// Decodes instruction data into a JSON object of named fields
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, including the discriminator
+	///
+	/// # Returns
+	/// The matched instruction layout and its decoded fields
+	pub fn decode_instruction_data(
+		&self,
+		data: &[u8],
+	) -> Result<(&InstructionLayout, serde_json::Value), DecoderError> {
+		let instruction = self.find_instruction(data).ok_or_else(|| {
+			DecoderError::InvalidData("No instruction layout matches the data".to_string())
+		})?;
+		let types = self
+			.types
+			.iter()
+			.map(|type_def| (type_def.name.as_str(), type_def))
+			.collect();
+		let mut reader = BorshReader::new(&data[instruction.discriminator.len()..], types);
+		let fields = reader.read_named_fields(&instruction.fields)?;
+		Ok((instruction, fields))
+	}
+}
+
+impl InstructionDecoder<'_> for ProgramLayout {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if let Some(program_id) = self.program_id() {
+			if program_id != instruction.program_id {
+				return None;
+			}
+		}
+
+		let (layout, args) = self.decode_instruction_data(&instruction.data).ok()?;
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::Custom(CustomInstruction {
+				name: layout.name.clone(),
+				args,
+				accounts: layout.accounts.clone(),
+			}),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn create_test_layout() -> ProgramLayout {
+		serde_json::from_value(json!({
+			"instructions": [
+				{
+					"name": "transfer",
+					"discriminator": [3],
+					"accounts": ["source", "destination", "authority"],
+					"fields": [{ "name": "amount", "type": "u64" }]
+				},
+				{
+					"name": "transfer_checked",
+					"discriminator": [3, 1],
+					"fields": [
+						{ "name": "amount", "type": "u64" },
+						{ "name": "decimals", "type": "u8" }
+					]
+				},
+				{
+					"name": "set_authority",
+					"discriminator": [6],
+					"fields": [{ "name": "new_authority", "type": { "option": "pubkey" } }]
+				}
+			]
+		}))
+		.unwrap()
+	}
+
+	fn instruction_with_data(data: Vec<u8>) -> Instruction {
+		Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data,
+		}
+	}
+
+	#[test]
+	fn test_decode_instruction_with_layout() {
+		let layout = create_test_layout();
+		let mut data = vec![3];
+		data.extend_from_slice(&250u64.to_le_bytes());
+
+		let decoded = layout
+			.decode_instruction(&instruction_with_data(data))
+			.unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::Custom(CustomInstruction {
+				name: "transfer".to_string(),
+				args: json!({ "amount": 250 }),
+				accounts: vec![
+					"source".to_string(),
+					"destination".to_string(),
+					"authority".to_string(),
+				],
+			})
+		);
+	}
+
+	#[test]
+	fn test_longest_discriminator_wins() {
+		let layout = create_test_layout();
+		let mut data = vec![3, 1];
+		data.extend_from_slice(&10u64.to_le_bytes());
+		data.push(6);
+
+		let (instruction, args) = layout.decode_instruction_data(&data).unwrap();
+		assert_eq!(instruction.name, "transfer_checked");
+		assert_eq!(args, json!({ "amount": 10, "decimals": 6 }));
+	}
+
+	#[test]
+	fn test_decode_optional_pubkey() {
+		let layout = create_test_layout();
+		let (_, args) = layout.decode_instruction_data(&[6, 0]).unwrap();
+		assert_eq!(args, json!({ "new_authority": null }));
+	}
+
+	#[test]
+	fn test_layout_restricted_to_program() {
+		let mut layout = create_test_layout();
+		layout.program_id = Some(Pubkey::new_unique().to_string());
+		let mut data = vec![3];
+		data.extend_from_slice(&1u64.to_le_bytes());
+		assert!(layout
+			.decode_instruction(&instruction_with_data(data))
+			.is_none());
+	}
+
+	#[test]
+	fn test_unknown_discriminator_fails() {
+		let layout = create_test_layout();
+		assert!(layout.decode_instruction_data(&[9, 0, 0]).is_err());
+	}
+}
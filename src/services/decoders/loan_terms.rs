@@ -0,0 +1,113 @@
+//! Loan terms carried by the instructions of the built-in lending decoders.
+//!
+//! Loan terms are spread across nested enums in the decoded instruction arguments
+//! (e.g. Sharky's `APY::Fixed { apy }` or `LoanTermsSpec::Time { duration }`). This
+//! module extracts them into a flat representation so they can be exposed as top-level
+//! parameters and compared directly in expressions.
+
+use serde::Serialize;
+
+use crate::services::decoders::sharky_decoder::{
+	instructions::SharkyInstruction,
+	types::{BookLoanTerms, LoanTermsSpec, APY},
+};
+
+/// Loan terms extracted from a decoded instruction
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct LoanTerms {
+	/// Principal of the loan in lamports
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub principal_lamports: Option<u64>,
+	/// Annual percentage yield in thousandths of a percent
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub apy: Option<u32>,
+	/// Duration of the loan in seconds
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub duration: Option<u64>,
+}
+
+impl LoanTerms {
+	/// Returns true if no loan term is known
+	pub fn is_empty(&self) -> bool {
+		self.principal_lamports.is_none() && self.apy.is_none() && self.duration.is_none()
+	}
+}
+
+fn apy_value(apy: &APY) -> u32 {
+	match apy {
+		APY::Fixed { apy } => *apy,
+	}
+}
+
+fn spec_duration(spec: &LoanTermsSpec) -> u64 {
+	match spec {
+		LoanTermsSpec::Time { duration } => *duration,
+	}
+}
+
+fn book_duration(terms: &BookLoanTerms) -> Option<u64> {
+	match terms {
+		BookLoanTerms::Fixed { terms } => Some(spec_duration(terms)),
+		BookLoanTerms::LenderChooses => None,
+	}
+}
+
+/// Returns the loan terms carried by a Sharky instruction
+pub(crate) fn sharky_loan_terms(instruction: &SharkyInstruction) -> LoanTerms {
+	match instruction {
+		SharkyInstruction::CreateOrderBook(create) => LoanTerms {
+			apy: Some(apy_value(&create.apy)),
+			duration: book_duration(&create.loan_terms),
+			..Default::default()
+		},
+		SharkyInstruction::UpdateOrderBook(update) => LoanTerms {
+			apy: Some(apy_value(&update.apy)),
+			duration: book_duration(&update.loan_terms),
+			..Default::default()
+		},
+		SharkyInstruction::OfferLoan(offer) => LoanTerms {
+			principal_lamports: Some(offer.principal_lamports),
+			duration: offer.terms_choice.as_ref().map(spec_duration),
+			..Default::default()
+		},
+		_ => LoanTerms::default(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::decoders::sharky_decoder::{
+		instructions::{create_order_book::CreateOrderBook, offer_loan::OfferLoan},
+		types::BookType,
+	};
+	use solana_sdk::pubkey::Pubkey;
+
+	#[test]
+	fn test_sharky_offer_loan_terms() {
+		let terms = sharky_loan_terms(&SharkyInstruction::OfferLoan(OfferLoan {
+			escrow_bump: 255,
+			principal_lamports: 5_000_000_000,
+			terms_choice: Some(LoanTermsSpec::Time { duration: 604_800 }),
+		}));
+		assert_eq!(terms.principal_lamports, Some(5_000_000_000));
+		assert_eq!(terms.duration, Some(604_800));
+		assert_eq!(terms.apy, None);
+	}
+
+	#[test]
+	fn test_sharky_order_book_terms() {
+		let terms = sharky_loan_terms(&SharkyInstruction::CreateOrderBook(CreateOrderBook {
+			book_type: BookType::Collection {
+				collection_key: Pubkey::new_unique(),
+			},
+			apy: APY::Fixed { apy: 120_000 },
+			loan_terms: BookLoanTerms::LenderChooses,
+			fee_permillicentage: 100,
+			fee_authority: Pubkey::new_unique(),
+		}));
+		assert_eq!(terms.apy, Some(120_000));
+		assert_eq!(terms.duration, None);
+		assert!(!terms.is_empty());
+	}
+}
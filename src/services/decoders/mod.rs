@@ -3,21 +3,55 @@
 //! This module provides traits and implementations for decoding Solana program
 //! instructions and account data.
 
-use crate::models::SolanaDecodedInstruction;
-use crate::services::decoders::kamino_lending_decoder::src::{
-	accounts::KaminoLendingAccount, instructions::KaminoLendingInstruction,
+use crate::services::decoders::{
+	address_lookup_table::AddressLookupTableInstruction,
+	bpf_upgradeable_loader::BpfUpgradeableLoaderInstruction,
+	kamino_lending_decoder::{
+		accounts::KaminoLendingAccount, instructions::KaminoLendingInstruction,
+	},
+	name_service::NameServiceInstruction, okx_dex_decoder::instructions::OkxDexInstruction,
+	sharky_decoder::instructions::SharkyInstruction,
+	squads::{SquadsAccount, SquadsInstruction},
+	token_program::TokenProgramInstruction,
 };
 use serde::{Deserialize, Serialize};
-use solana_sdk::{
-	account_info::AccountInfo,
-	instruction::{AccountMeta, Instruction},
-	pubkey::Pubkey,
-};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
 use std::fmt::Debug;
 
+mod account_names;
+pub mod address_lookup_table;
+pub mod anchor_event;
+pub mod bpf_upgradeable_loader;
+pub mod compute_budget;
+pub mod idl;
+#[path = "kamino-lending-decoder/src/lib.rs"]
+pub mod kamino_lending_decoder;
+pub mod layout;
+mod loan_terms;
+pub mod name_service;
+#[path = "okx-dex-decoder/src/lib.rs"]
+pub mod okx_dex_decoder;
+mod registry;
+pub(crate) mod serde_helpers;
+#[path = "sharky-decoder/src/lib.rs"]
+pub mod sharky_decoder;
+pub mod squads;
+pub mod token_account;
+pub mod token_program;
+mod variant_names;
+
+pub use anchor_event::{DecodedEvent, RawEvent};
+pub use idl::AnchorIdl;
+pub use layout::ProgramLayout;
+pub use loan_terms::LoanTerms;
+pub use registry::{BoxedAccountDecoder, BoxedInstructionDecoder, DecoderRegistry};
+pub use token_account::TokenAccount;
+
 /// Enum representing different types of Solana accounts that can be decoded
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AccountType {
+	/// Account decoded by a decoder registered at runtime
+	Custom(CustomAccount),
 	AssociatedTokenAccount,
 	KaminoLendingAccount(KaminoLendingAccount),
 	KaminoFarms,
@@ -48,21 +82,28 @@ pub enum AccountType {
 	RaydiumLaunchpad,
 	RaydiumLiquidityLocking,
 	Sharky,
+	Squads(SquadsAccount),
 	SolayerRestaking,
 	StabbleStableSwap,
 	StabbleWeightedSwap,
 	StakeProgram,
 	Token2022,
 	TokenProgram,
+	/// Token account of the SPL Token or Token-2022 program
+	TokenAccount(TokenAccount),
 	SystemProgram,
 	Virtuals,
 	Zeta,
 }
 
 /// Enum representing different types of Solana instructions that can be decoded
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InstructionType {
+	/// Instruction decoded by a decoder registered at runtime
+	Custom(CustomInstruction),
+	AddressLookupTable(AddressLookupTableInstruction),
 	AssociatedTokenAccount,
+	BpfUpgradeableLoader(BpfUpgradeableLoaderInstruction),
 	KaminoLendingInstruction(KaminoLendingInstruction),
 	KaminoFarms,
 	KaminoLimitOrder,
@@ -79,8 +120,8 @@ pub enum InstructionType {
 	MemoProgram,
 	MPLTokenMetadata,
 	MPLCore,
-	NameService,
-	OKXDex,
+	NameService(NameServiceInstruction),
+	OKXDex(OkxDexInstruction),
 	OpenbookV2,
 	OrcaWhirlpool,
 	PhoenixV1,
@@ -91,18 +132,134 @@ pub enum InstructionType {
 	RaydiumCPMM,
 	RaydiumLaunchpad,
 	RaydiumLiquidityLocking,
-	Sharky,
+	Sharky(SharkyInstruction),
+	Squads(SquadsInstruction),
 	SolayerRestaking,
 	StabbleStableSwap,
 	StabbleWeightedSwap,
 	StakeProgram,
 	Token2022,
-	TokenProgram,
+	TokenProgram(TokenProgramInstruction),
 	SystemProgram,
 	Virtuals,
 	Zeta,
 }
 
+/// Generic representation of an instruction decoded by a decoder that is not
+/// compiled into the crate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomInstruction {
+	/// Name of the decoded instruction
+	pub name: String,
+	/// Decoded instruction arguments
+	pub args: serde_json::Value,
+	/// Names of the instruction accounts, in positional order
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub accounts: Vec<String>,
+}
+
+/// Generic representation of an account decoded by a decoder that is not compiled
+/// into the crate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomAccount {
+	/// Name of the decoded account type
+	pub name: String,
+	/// Decoded account fields
+	pub data: serde_json::Value,
+}
+
+impl AccountType {
+	/// Returns the name of the account variant, e.g. `Obligation`, as exposed in the
+	/// signature of account matches
+	///
+	/// Account types that carry no decoded account return their own name.
+	pub fn variant_name(&self) -> Option<String> {
+		let serialized = match self {
+			AccountType::Custom(custom) => return Some(custom.name.clone()),
+			AccountType::KaminoLendingAccount(account) => serde_json::to_value(account),
+			AccountType::Squads(account) => serde_json::to_value(account),
+			account_type => serde_json::to_value(account_type),
+		};
+
+		// Accounts serialize as `{ "<Variant>": { <fields> } }`, or as `"<Variant>"` for
+		// variants without fields
+		match serialized.ok()? {
+			serde_json::Value::Object(map) => map.into_iter().next().map(|(name, _)| name),
+			serde_json::Value::String(name) => Some(name),
+			_ => None,
+		}
+	}
+}
+
+impl InstructionType {
+	/// Returns the names of the instruction accounts in positional order
+	///
+	/// The names line up with the `accounts` of the decoded instruction. Instruction
+	/// types without known account names return an empty list.
+	pub fn account_names(&self) -> Vec<&str> {
+		match self {
+			InstructionType::Custom(custom) => custom.accounts.iter().map(String::as_str).collect(),
+			InstructionType::AddressLookupTable(instruction) => {
+				account_names::address_lookup_table_account_names(instruction).to_vec()
+			}
+			InstructionType::BpfUpgradeableLoader(instruction) => {
+				account_names::bpf_upgradeable_loader_account_names(instruction).to_vec()
+			}
+			InstructionType::KaminoLendingInstruction(instruction) => {
+				account_names::kamino_lending_account_names(instruction).to_vec()
+			}
+			InstructionType::NameService(instruction) => {
+				account_names::name_service_account_names(instruction).to_vec()
+			}
+			InstructionType::OKXDex(instruction) => {
+				account_names::okx_dex_account_names(instruction).to_vec()
+			}
+			InstructionType::Sharky(instruction) => {
+				account_names::sharky_account_names(instruction).to_vec()
+			}
+			InstructionType::Squads(instruction) => {
+				account_names::squads_account_names(instruction).to_vec()
+			}
+			InstructionType::TokenProgram(instruction) => {
+				account_names::token_program_account_names(instruction).to_vec()
+			}
+			_ => Vec::new(),
+		}
+	}
+
+	/// Returns the loan terms carried by the instruction
+	///
+	/// Instruction types that do not originate or update loans return empty terms.
+	pub fn loan_terms(&self) -> LoanTerms {
+		match self {
+			InstructionType::Sharky(instruction) => loan_terms::sharky_loan_terms(instruction),
+			_ => LoanTerms::default(),
+		}
+	}
+
+	/// Returns the name of the instruction variant, e.g. `DepositReserveLiquidity`
+	///
+	/// Instruction types that carry no decoded instruction return `None`.
+	pub fn variant_name(&self) -> Option<String> {
+		if let InstructionType::Custom(custom) = self {
+			return Some(custom.name.clone());
+		}
+
+		// Instructions serialize as `{ "<Program>": { "<Variant>": { <fields> } } }`, or
+		// as `{ "<Program>": "<Variant>" }` for variants without fields
+		match serde_json::to_value(self).ok()? {
+			serde_json::Value::Object(map) => match map.into_iter().next()?.1 {
+				serde_json::Value::Object(variant) => {
+					variant.into_iter().next().map(|(name, _)| name)
+				}
+				serde_json::Value::String(name) => Some(name),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct DecodedAccount<T> {
 	pub lamports: u64,
@@ -119,6 +276,13 @@ pub trait AccountDecoder<'a> {
 		&self,
 		account: &'a solana_account::Account,
 	) -> Option<DecodedAccount<Self::AccountType>>;
+
+	/// Returns the names of the account variants the decoder can decode
+	///
+	/// An empty list means the names are not known up front.
+	fn account_names(&self) -> &[&str] {
+		&[]
+	}
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -135,6 +299,13 @@ pub trait InstructionDecoder<'a> {
 		&self,
 		instruction: &'a solana_instruction::Instruction,
 	) -> Option<DecodedInstruction<Self::InstructionType>>;
+
+	/// Returns the names of the instruction variants the decoder can decode
+	///
+	/// An empty list means the names are not known up front.
+	fn instruction_names(&self) -> &[&str] {
+		&[]
+	}
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,222 @@
+//! Decoder for the SPL Name Service program.
+//!
+//! The Name Service program owns the registry accounts behind `.sol` domains. Its
+//! instructions are Borsh encoded with a one byte instruction tag, which allows
+//! monitors to match registrations, transfers and updates of domains.
+
+use carbon_core::borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	serde_helpers::{hex_bytes, pubkey_base58},
+	variant_names, DecodedInstruction, InstructionDecoder, InstructionType,
+};
+
+/// Address of the SPL Name Service program
+pub const PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+
+/// Decoder for Name Service instructions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NameServiceDecoder;
+
+/// Instructions of the Name Service program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NameServiceInstruction {
+	Create(Create),
+	Update(Update),
+	Transfer(Transfer),
+	Delete(Delete),
+	Realloc(Realloc),
+}
+
+/// Creates a name registry account (e.g. registers a domain)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Create {
+	/// Hash of the domain name
+	#[serde(with = "hex_bytes")]
+	pub hashed_name: Vec<u8>,
+	/// Lamports transferred to the name account
+	pub lamports: u64,
+	/// Space allocated for the name record data
+	pub space: u32,
+}
+
+/// Updates the data stored in a name registry account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Update {
+	/// Offset at which the data is written
+	pub offset: u32,
+	/// Data written to the name record
+	#[serde(with = "hex_bytes")]
+	pub data: Vec<u8>,
+}
+
+/// Transfers ownership of a name registry account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transfer {
+	/// New owner of the name
+	#[serde(with = "pubkey_base58")]
+	pub new_owner: Pubkey,
+}
+
+/// Deletes a name registry account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Delete {}
+
+/// Resizes a name registry account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Realloc {
+	/// New space of the name record data
+	pub space: u32,
+}
+
+impl NameServiceInstruction {
+	/// Decodes Name Service instruction data
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, starting with the instruction tag
+	///
+	/// # Returns
+	/// The decoded instruction, or `None` if the data is not a valid instruction
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let (tag, mut rest) = data.split_first()?;
+		let instruction = match tag {
+			0 => Self::Create(Create {
+				hashed_name: read(&mut rest)?,
+				lamports: read(&mut rest)?,
+				space: read(&mut rest)?,
+			}),
+			1 => Self::Update(Update {
+				offset: read(&mut rest)?,
+				data: read(&mut rest)?,
+			}),
+			2 => Self::Transfer(Transfer {
+				new_owner: Pubkey::new_from_array(read(&mut rest)?),
+			}),
+			3 => Self::Delete(Delete {}),
+			4 => Self::Realloc(Realloc {
+				space: read(&mut rest)?,
+			}),
+			_ => return None,
+		};
+		Some(instruction)
+	}
+}
+
+/// Reads a Borsh encoded value from the front of `data`
+fn read<T: BorshDeserialize>(data: &mut &[u8]) -> Option<T> {
+	T::deserialize(data).ok()
+}
+
+impl InstructionDecoder<'_> for NameServiceDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if instruction.program_id != PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::NameService(NameServiceInstruction::decode(&instruction.data)?),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::NAME_SERVICE_INSTRUCTION_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_instruction(data: Vec<u8>) -> Instruction {
+		Instruction {
+			program_id: PROGRAM_ID,
+			accounts: vec![],
+			data,
+		}
+	}
+
+	#[test]
+	fn test_decode_create() {
+		let mut data = vec![0];
+		data.extend_from_slice(&2u32.to_le_bytes());
+		data.extend_from_slice(&[0xab, 0xcd]);
+		data.extend_from_slice(&1_000u64.to_le_bytes());
+		data.extend_from_slice(&96u32.to_le_bytes());
+
+		let decoded = NameServiceDecoder
+			.decode_instruction(&create_instruction(data))
+			.unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::NameService(NameServiceInstruction::Create(Create {
+				hashed_name: vec![0xab, 0xcd],
+				lamports: 1_000,
+				space: 96,
+			}))
+		);
+	}
+
+	#[test]
+	fn test_decode_transfer() {
+		let new_owner = Pubkey::new_unique();
+		let mut data = vec![2];
+		data.extend_from_slice(new_owner.as_ref());
+
+		assert_eq!(
+			NameServiceInstruction::decode(&data),
+			Some(NameServiceInstruction::Transfer(Transfer { new_owner }))
+		);
+		assert_eq!(
+			serde_json::to_value(NameServiceInstruction::decode(&data).unwrap()).unwrap(),
+			serde_json::json!({ "Transfer": { "new_owner": new_owner.to_string() } })
+		);
+	}
+
+	#[test]
+	fn test_decode_update_and_delete() {
+		let mut data = vec![1];
+		data.extend_from_slice(&4u32.to_le_bytes());
+		data.extend_from_slice(&1u32.to_le_bytes());
+		data.push(7);
+		assert_eq!(
+			NameServiceInstruction::decode(&data),
+			Some(NameServiceInstruction::Update(Update {
+				offset: 4,
+				data: vec![7],
+			}))
+		);
+		assert_eq!(
+			NameServiceInstruction::decode(&[3]),
+			Some(NameServiceInstruction::Delete(Delete {}))
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_data() {
+		assert!(NameServiceInstruction::decode(&[]).is_none());
+		assert!(NameServiceInstruction::decode(&[9]).is_none());
+		assert!(NameServiceInstruction::decode(&[2, 1, 2, 3]).is_none());
+	}
+
+	#[test]
+	fn test_decode_rejects_other_program() {
+		let instruction = Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data: vec![3],
+		};
+		assert!(NameServiceDecoder
+			.decode_instruction(&instruction)
+			.is_none());
+	}
+}
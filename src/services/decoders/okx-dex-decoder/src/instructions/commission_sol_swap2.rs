@@ -0,0 +1,46 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x71841f4a63a93992")]
+pub struct CommissionSolSwap2 {
+    pub data: CommissionSwapArgs,
+    pub order_id: u64,
+}
+
+pub struct CommissionSolSwap2InstructionAccounts {
+    pub payer: solana_pubkey::Pubkey,
+    pub source_token_account: solana_pubkey::Pubkey,
+    pub destination_token_account: solana_pubkey::Pubkey,
+    pub source_mint: solana_pubkey::Pubkey,
+    pub destination_mint: solana_pubkey::Pubkey,
+    pub commission_account: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for CommissionSolSwap2 {
+    type ArrangedAccounts = CommissionSolSwap2InstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [payer, source_token_account, destination_token_account, source_mint, destination_mint, commission_account, system_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(CommissionSolSwap2InstructionAccounts {
+            payer: payer.pubkey,
+            source_token_account: source_token_account.pubkey,
+            destination_token_account: destination_token_account.pubkey,
+            source_mint: source_mint.pubkey,
+            destination_mint: destination_mint.pubkey,
+            commission_account: commission_account.pubkey,
+            system_program: system_program.pubkey,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+pub mod adaptor_id;
+pub use adaptor_id::*;
+pub mod bridge_to_args;
+pub use bridge_to_args::*;
+pub mod commission_swap_args;
+pub use commission_swap_args::*;
+pub mod dex;
+pub use dex::*;
+pub mod route;
+pub use route::*;
+pub mod swap_args;
+pub use swap_args::*;
+pub mod swap_event;
+pub use swap_event::*;
+pub mod swap_type;
+pub use swap_type::*;
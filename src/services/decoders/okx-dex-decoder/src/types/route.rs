@@ -0,0 +1,11 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub struct Route {
+    pub dexes: Vec<Dex>,
+    pub weights: Vec<u8>,
+}
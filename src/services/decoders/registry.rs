@@ -0,0 +1,762 @@
+//! Registry of Solana program decoders keyed by program id.
+//!
+//! The registry allows decoders to be plugged in at runtime instead of being hard-coded
+//! into the filter. Built-in decoders are registered by [`DecoderRegistry::with_builtin_decoders`],
+//! and additional decoders (e.g. for private Anchor programs) can be registered by the binary
+//! at startup or by external crates through [`DecoderRegistry::register_instruction_decoder`].
+//! Programs without a registered decoder can still be decoded when their contract spec
+//! carries an Anchor IDL or a Borsh layout. Account decoders are keyed by the program
+//! owning the accounts they decode.
+
+use std::{collections::HashMap, fmt};
+
+use carbon_core::{
+	account::AccountDecoder as CarbonAccountDecoder,
+	instruction::InstructionDecoder as CarbonInstructionDecoder,
+};
+use solana_account::Account;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{
+	models::{SolanaContractSpec, SolanaDecoderType},
+	services::decoders::{
+		address_lookup_table::{
+			AddressLookupTableDecoder, PROGRAM_ID as ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+		},
+		bpf_upgradeable_loader::{
+			BpfUpgradeableLoaderDecoder, PROGRAM_ID as BPF_UPGRADEABLE_LOADER_PROGRAM_ID,
+		},
+		kamino_lending_decoder::{KaminoLendingDecoder, PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID},
+		name_service::{NameServiceDecoder, PROGRAM_ID as NAME_SERVICE_PROGRAM_ID},
+		okx_dex_decoder::{OkxDexDecoder, PROGRAM_ID as OKX_DEX_PROGRAM_ID},
+		sharky_decoder::{SharkyDecoder, PROGRAM_ID as SHARKY_PROGRAM_ID},
+		squads::{SquadsDecoder, PROGRAM_ID as SQUADS_PROGRAM_ID},
+		token_account::{TokenAccountDecoder, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+		token_program::TokenProgramDecoder,
+		variant_names, AccountDecoder, AccountType, DecodedAccount, DecodedEvent,
+		DecodedInstruction, InstructionDecoder, InstructionType, RawEvent,
+	},
+};
+
+/// Type-erased instruction decoder stored in the [`DecoderRegistry`]
+pub type BoxedInstructionDecoder =
+	Box<dyn for<'a> InstructionDecoder<'a, InstructionType = InstructionType> + Send + Sync>;
+
+/// Type-erased account decoder stored in the [`DecoderRegistry`]
+pub type BoxedAccountDecoder =
+	Box<dyn for<'a> AccountDecoder<'a, AccountType = AccountType> + Send + Sync>;
+
+/// Registry of instruction and account decoders keyed by the program id they decode
+#[derive(Default)]
+pub struct DecoderRegistry {
+	instruction_decoders: HashMap<Pubkey, BoxedInstructionDecoder>,
+	account_decoders: HashMap<Pubkey, BoxedAccountDecoder>,
+}
+
+impl DecoderRegistry {
+	/// Creates an empty registry without any decoders
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a registry containing all decoders compiled into the crate
+	pub fn with_builtin_decoders() -> Self {
+		let mut registry = Self::new();
+		registry.register_instruction_decoder(
+			ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+			AddressLookupTableDecoder,
+		);
+		registry.register_instruction_decoder(
+			BPF_UPGRADEABLE_LOADER_PROGRAM_ID,
+			BpfUpgradeableLoaderDecoder,
+		);
+		registry.register_instruction_decoder(KAMINO_LENDING_PROGRAM_ID, KaminoLendingDecoder);
+		registry.register_instruction_decoder(NAME_SERVICE_PROGRAM_ID, NameServiceDecoder);
+		registry.register_instruction_decoder(OKX_DEX_PROGRAM_ID, OkxDexDecoder);
+		registry.register_instruction_decoder(SHARKY_PROGRAM_ID, SharkyDecoder);
+		registry.register_instruction_decoder(SQUADS_PROGRAM_ID, SquadsDecoder);
+		registry.register_instruction_decoder(TOKEN_PROGRAM_ID, TokenProgramDecoder);
+		registry.register_account_decoder(KAMINO_LENDING_PROGRAM_ID, KaminoLendingDecoder);
+		registry.register_account_decoder(SQUADS_PROGRAM_ID, SquadsDecoder);
+		registry.register_account_decoder(TOKEN_PROGRAM_ID, TokenAccountDecoder);
+		registry.register_account_decoder(TOKEN_2022_PROGRAM_ID, TokenAccountDecoder);
+		registry
+	}
+
+	/// Registers an instruction decoder for the given program id
+	///
+	/// # Arguments
+	/// * `program_id` - Program whose instructions the decoder handles
+	/// * `decoder` - Decoder implementation
+	///
+	/// # Returns
+	/// The previously registered decoder for the program id, if any
+	pub fn register_instruction_decoder<D>(
+		&mut self,
+		program_id: Pubkey,
+		decoder: D,
+	) -> Option<BoxedInstructionDecoder>
+	where
+		D: for<'a> InstructionDecoder<'a, InstructionType = InstructionType>
+			+ Send
+			+ Sync
+			+ 'static,
+	{
+		self.instruction_decoders
+			.insert(program_id, Box::new(decoder))
+	}
+
+	/// Removes the instruction decoder registered for the given program id
+	///
+	/// # Returns
+	/// The removed decoder, if any
+	pub fn unregister_instruction_decoder(
+		&mut self,
+		program_id: &Pubkey,
+	) -> Option<BoxedInstructionDecoder> {
+		self.instruction_decoders.remove(program_id)
+	}
+
+	/// Returns the instruction decoder registered for the given program id
+	pub fn get_instruction_decoder(
+		&self,
+		program_id: &Pubkey,
+	) -> Option<
+		&(dyn for<'a> InstructionDecoder<'a, InstructionType = InstructionType> + Send + Sync),
+	> {
+		self.instruction_decoders
+			.get(program_id)
+			.map(|decoder| decoder.as_ref())
+	}
+
+	/// Returns true if a decoder is registered for the given program id
+	pub fn has_instruction_decoder(&self, program_id: &Pubkey) -> bool {
+		self.instruction_decoders.contains_key(program_id)
+	}
+
+	/// Returns the program ids that have a registered instruction decoder
+	pub fn program_ids(&self) -> impl Iterator<Item = &Pubkey> {
+		self.instruction_decoders.keys()
+	}
+
+	/// Returns the names of the instruction variants the decoder registered for the given
+	/// program id can decode
+	///
+	/// # Returns
+	/// `None` if no decoder is registered for the program, or an empty list if the
+	/// decoder does not know its instruction names up front
+	pub fn instruction_names(&self, program_id: &Pubkey) -> Option<&[&str]> {
+		self.get_instruction_decoder(program_id)
+			.map(|decoder| decoder.instruction_names())
+	}
+
+	/// Decodes an instruction using the decoder registered for its program id
+	///
+	/// # Returns
+	/// `None` if no decoder is registered for the program or the decoder could not
+	/// decode the instruction
+	pub fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<InstructionType>> {
+		self.get_instruction_decoder(&instruction.program_id)?
+			.decode_instruction(instruction)
+	}
+
+	/// Decodes an instruction, falling back to the contract spec when no registered
+	/// decoder can handle it
+	///
+	/// When the contract spec carries an Anchor IDL, the instruction is matched by its
+	/// 8-byte discriminator and its arguments are decoded with Borsh. When it carries a
+	/// layout, the instruction is decoded according to the declared Borsh fields.
+	///
+	/// # Arguments
+	/// * `instruction` - Instruction to decode
+	/// * `contract_spec` - Contract spec of the program the instruction belongs to
+	pub fn decode_instruction_with_spec(
+		&self,
+		instruction: &Instruction,
+		contract_spec: Option<&SolanaContractSpec>,
+	) -> Option<DecodedInstruction<InstructionType>> {
+		self.decode_instruction(instruction)
+			.or_else(|| Self::decode_instruction_from_spec(instruction, contract_spec?))
+	}
+
+	/// Decodes an instruction with the Anchor IDL or the layout of a contract spec only
+	///
+	/// # Arguments
+	/// * `instruction` - Instruction to decode
+	/// * `contract_spec` - Contract spec of the program the instruction belongs to
+	pub fn decode_instruction_from_spec(
+		instruction: &Instruction,
+		contract_spec: &SolanaContractSpec,
+	) -> Option<DecodedInstruction<InstructionType>> {
+		match contract_spec.decoder_type() {
+			SolanaDecoderType::Idl(idl) => idl.decode_instruction(instruction),
+			SolanaDecoderType::Layout(layout) => layout.decode_instruction(instruction),
+			_ => None,
+		}
+	}
+
+	/// Registers an account decoder for the accounts owned by the given program id
+	///
+	/// # Arguments
+	/// * `program_id` - Program owning the accounts the decoder handles
+	/// * `decoder` - Decoder implementation
+	///
+	/// # Returns
+	/// The previously registered decoder for the program id, if any
+	pub fn register_account_decoder<D>(
+		&mut self,
+		program_id: Pubkey,
+		decoder: D,
+	) -> Option<BoxedAccountDecoder>
+	where
+		D: for<'a> AccountDecoder<'a, AccountType = AccountType> + Send + Sync + 'static,
+	{
+		self.account_decoders.insert(program_id, Box::new(decoder))
+	}
+
+	/// Returns true if an account decoder is registered for the given program id
+	pub fn has_account_decoder(&self, program_id: &Pubkey) -> bool {
+		self.account_decoders.contains_key(program_id)
+	}
+
+	/// Returns the names of the account variants the registered account decoders can
+	/// decode, sorted and without duplicates
+	pub fn account_names(&self) -> Vec<&str> {
+		let mut names: Vec<&str> = self
+			.account_decoders
+			.values()
+			.flat_map(|decoder| decoder.account_names().iter().copied())
+			.collect();
+		names.sort_unstable();
+		names.dedup();
+		names
+	}
+
+	/// Decodes an account using the decoder registered for its owner program
+	///
+	/// # Returns
+	/// `None` if no decoder is registered for the owner or the decoder could not
+	/// decode the account data
+	pub fn decode_account(&self, account: &Account) -> Option<DecodedAccount<AccountType>> {
+		self.account_decoders
+			.get(&account.owner)?
+			.decode_account(account)
+	}
+
+	/// Decodes an account, falling back to the contract spec of its owner program when
+	/// no registered decoder can handle it
+	///
+	/// # Arguments
+	/// * `account` - Account to decode
+	/// * `contract_spec` - Contract spec of the program owning the account
+	pub fn decode_account_with_spec(
+		&self,
+		account: &Account,
+		contract_spec: Option<&SolanaContractSpec>,
+	) -> Option<DecodedAccount<AccountType>> {
+		self.decode_account(account)
+			.or_else(|| match contract_spec?.decoder_type() {
+				SolanaDecoderType::Idl(idl) => idl.decode_account(account),
+				_ => None,
+			})
+	}
+
+	/// Decodes an event with the contract spec of the program that emitted it
+	///
+	/// Only contract specs carrying an Anchor IDL describe events.
+	///
+	/// # Arguments
+	/// * `event` - Event to decode
+	/// * `contract_spec` - Contract spec of the program that emitted the event
+	pub fn decode_event_with_spec(
+		&self,
+		event: &RawEvent,
+		contract_spec: Option<&SolanaContractSpec>,
+	) -> Option<DecodedEvent> {
+		match contract_spec?.decoder_type() {
+			SolanaDecoderType::Idl(idl) => {
+				if idl
+					.program_id()
+					.is_some_and(|program_id| program_id != event.program_id)
+				{
+					return None;
+				}
+				let (definition, data) = idl.decode_event_data(&event.data).ok()?;
+				Some(DecodedEvent {
+					program_id: event.program_id,
+					name: definition.name.clone(),
+					data,
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Debug for DecoderRegistry {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("DecoderRegistry")
+			.field(
+				"instruction_decoders",
+				&self.instruction_decoders.keys().collect::<Vec<_>>(),
+			)
+			.field(
+				"account_decoders",
+				&self.account_decoders.keys().collect::<Vec<_>>(),
+			)
+			.finish()
+	}
+}
+
+impl InstructionDecoder<'_> for KaminoLendingDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		CarbonInstructionDecoder::decode_instruction(self, instruction).map(|decoded| {
+			DecodedInstruction {
+				program_id: decoded.program_id,
+				data: InstructionType::KaminoLendingInstruction(decoded.data),
+				accounts: decoded.accounts,
+			}
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::KAMINO_LENDING_INSTRUCTION_NAMES
+	}
+}
+
+impl AccountDecoder<'_> for KaminoLendingDecoder {
+	type AccountType = AccountType;
+
+	fn decode_account(&self, account: &Account) -> Option<DecodedAccount<Self::AccountType>> {
+		CarbonAccountDecoder::decode_account(self, account).map(|decoded| DecodedAccount {
+			lamports: decoded.lamports,
+			data: AccountType::KaminoLendingAccount(decoded.data),
+			owner: decoded.owner,
+			executable: decoded.executable,
+			rent_epoch: decoded.rent_epoch,
+		})
+	}
+
+	fn account_names(&self) -> &[&str] {
+		variant_names::KAMINO_LENDING_ACCOUNT_NAMES
+	}
+}
+
+impl InstructionDecoder<'_> for OkxDexDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		CarbonInstructionDecoder::decode_instruction(self, instruction).map(|decoded| {
+			DecodedInstruction {
+				program_id: decoded.program_id,
+				data: InstructionType::OKXDex(decoded.data),
+				accounts: decoded.accounts,
+			}
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::OKX_DEX_INSTRUCTION_NAMES
+	}
+}
+
+impl InstructionDecoder<'_> for SharkyDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		CarbonInstructionDecoder::decode_instruction(self, instruction).map(|decoded| {
+			DecodedInstruction {
+				program_id: decoded.program_id,
+				data: InstructionType::Sharky(decoded.data),
+				accounts: decoded.accounts,
+			}
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::SHARKY_INSTRUCTION_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::decoders::{
+		kamino_lending_decoder::{
+			accounts::{short_url::ShortUrl, KaminoLendingAccount},
+			instructions::KaminoLendingInstruction,
+		},
+		AnchorIdl, CustomAccount, CustomInstruction, ProgramLayout,
+	};
+	use solana_sdk::instruction::AccountMeta;
+
+	struct TestDecoder;
+
+	impl InstructionDecoder<'_> for TestDecoder {
+		type InstructionType = InstructionType;
+
+		fn decode_instruction(
+			&self,
+			instruction: &Instruction,
+		) -> Option<DecodedInstruction<Self::InstructionType>> {
+			let amount = u64::from_le_bytes(instruction.data.get(..8)?.try_into().ok()?);
+			Some(DecodedInstruction {
+				program_id: instruction.program_id,
+				data: InstructionType::Custom(CustomInstruction {
+					name: "transfer".to_string(),
+					args: serde_json::json!({ "amount": amount }),
+					accounts: vec![],
+				}),
+				accounts: instruction.accounts.clone(),
+			})
+		}
+	}
+
+	fn create_kamino_deposit_instruction(amount: u64) -> Instruction {
+		let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+		data.extend_from_slice(&amount.to_le_bytes());
+		Instruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			data,
+		}
+	}
+
+	#[test]
+	fn test_new_registry_is_empty() {
+		let registry = DecoderRegistry::new();
+		assert_eq!(registry.program_ids().count(), 0);
+		assert!(!registry.has_instruction_decoder(&KAMINO_LENDING_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_address_lookup_table() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&ADDRESS_LOOKUP_TABLE_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_bpf_upgradeable_loader() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&BPF_UPGRADEABLE_LOADER_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_kamino_lending() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&KAMINO_LENDING_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_kamino_lending_accounts() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_account_decoder(&KAMINO_LENDING_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_instruction_and_account_names() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let decoded = registry
+			.decode_instruction(&create_kamino_deposit_instruction(100))
+			.unwrap();
+		let name = decoded.data.variant_name().unwrap();
+		assert!(registry
+			.instruction_names(&KAMINO_LENDING_PROGRAM_ID)
+			.unwrap()
+			.contains(&name.as_str()));
+		assert!(registry.instruction_names(&TOKEN_2022_PROGRAM_ID).is_none());
+
+		let account_names = registry.account_names();
+		assert!(account_names.contains(&"Obligation"));
+		assert!(account_names.contains(&"Multisig"));
+		assert_eq!(
+			account_names
+				.iter()
+				.filter(|name| **name == "TokenAccount")
+				.count(),
+			1
+		);
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_token_accounts() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_account_decoder(&TOKEN_PROGRAM_ID));
+		assert!(registry.has_account_decoder(&TOKEN_2022_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_decode_builtin_account() {
+		let referrer = Pubkey::new_unique();
+		let mut data = vec![0x1c, 0x59, 0xae, 0x19, 0xe2, 0x7c, 0x7e, 0xd4];
+		data.extend_from_slice(referrer.as_ref());
+		data.extend_from_slice(&5u32.to_le_bytes());
+		data.extend_from_slice(b"kamin");
+		let account = Account {
+			lamports: 1_000_000,
+			data,
+			owner: KAMINO_LENDING_PROGRAM_ID,
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let decoded = registry.decode_account(&account).unwrap();
+		assert_eq!(decoded.lamports, 1_000_000);
+		assert_eq!(
+			decoded.data,
+			AccountType::KaminoLendingAccount(KaminoLendingAccount::ShortUrl(ShortUrl {
+				referrer,
+				short_url: "kamin".to_string(),
+			}))
+		);
+
+		let value = serde_json::to_value(&decoded.data).unwrap();
+		assert_eq!(
+			serde_json::from_value::<AccountType>(value).unwrap(),
+			decoded.data
+		);
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_name_service() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&NAME_SERVICE_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_okx_dex() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&OKX_DEX_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_sharky() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&SHARKY_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_builtin_decoders_include_squads() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.has_instruction_decoder(&SQUADS_PROGRAM_ID));
+		assert!(registry.has_account_decoder(&SQUADS_PROGRAM_ID));
+	}
+
+	#[test]
+	fn test_decode_builtin_instruction() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let decoded = registry
+			.decode_instruction(&create_kamino_deposit_instruction(1000))
+			.unwrap();
+
+		assert_eq!(decoded.program_id, KAMINO_LENDING_PROGRAM_ID);
+		assert_eq!(decoded.accounts.len(), 1);
+		match decoded.data {
+			InstructionType::KaminoLendingInstruction(
+				KaminoLendingInstruction::DepositReserveLiquidity(deposit),
+			) => assert_eq!(deposit.liquidity_amount, 1000),
+			other => panic!("Unexpected instruction: {:?}", other),
+		}
+	}
+
+	#[test]
+	fn test_register_custom_decoder() {
+		let program_id = Pubkey::new_unique();
+		let mut registry = DecoderRegistry::new();
+		assert!(registry
+			.register_instruction_decoder(program_id, TestDecoder)
+			.is_none());
+
+		let instruction = Instruction {
+			program_id,
+			accounts: vec![],
+			data: 42u64.to_le_bytes().to_vec(),
+		};
+		let decoded = registry.decode_instruction(&instruction).unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::Custom(CustomInstruction {
+				name: "transfer".to_string(),
+				args: serde_json::json!({ "amount": 42 }),
+				accounts: vec![],
+			})
+		);
+	}
+
+	#[test]
+	fn test_register_replaces_existing_decoder() {
+		let mut registry = DecoderRegistry::with_builtin_decoders();
+		let previous =
+			registry.register_instruction_decoder(KAMINO_LENDING_PROGRAM_ID, TestDecoder);
+		assert!(previous.is_some());
+
+		let decoded = registry
+			.decode_instruction(&create_kamino_deposit_instruction(7))
+			.unwrap();
+		assert!(matches!(decoded.data, InstructionType::Custom(_)));
+	}
+
+	#[test]
+	fn test_unregister_decoder() {
+		let mut registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry
+			.unregister_instruction_decoder(&KAMINO_LENDING_PROGRAM_ID)
+			.is_some());
+		assert!(registry
+			.decode_instruction(&create_kamino_deposit_instruction(1))
+			.is_none());
+	}
+
+	#[test]
+	fn test_decode_unknown_program_returns_none() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let instruction = Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data: vec![1, 2, 3],
+		};
+		assert!(registry.decode_instruction(&instruction).is_none());
+	}
+
+	#[test]
+	fn test_decode_with_spec_falls_back_to_idl() {
+		let program_id = Pubkey::new_unique();
+		let idl: AnchorIdl = serde_json::from_value(serde_json::json!({
+			"instructions": [{
+				"name": "stake",
+				"discriminator": [1, 1, 1, 1, 1, 1, 1, 1],
+				"accounts": [],
+				"args": [{ "name": "amount", "type": "u32" }]
+			}]
+		}))
+		.unwrap();
+		let spec = SolanaContractSpec::new(SolanaDecoderType::Idl(idl));
+
+		let mut data = vec![1; 8];
+		data.extend_from_slice(&9u32.to_le_bytes());
+		let instruction = Instruction {
+			program_id,
+			accounts: vec![],
+			data,
+		};
+
+		let registry = DecoderRegistry::with_builtin_decoders();
+		assert!(registry.decode_instruction(&instruction).is_none());
+		assert!(registry
+			.decode_instruction_with_spec(&instruction, None)
+			.is_none());
+
+		let decoded = registry
+			.decode_instruction_with_spec(&instruction, Some(&spec))
+			.unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::Custom(CustomInstruction {
+				name: "stake".to_string(),
+				args: serde_json::json!({ "amount": 9 }),
+				accounts: vec![],
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_with_spec_prefers_registered_decoder() {
+		let registry = DecoderRegistry::with_builtin_decoders();
+		let spec = SolanaContractSpec::new(SolanaDecoderType::Idl(AnchorIdl::default()));
+		let decoded = registry
+			.decode_instruction_with_spec(&create_kamino_deposit_instruction(5), Some(&spec))
+			.unwrap();
+		assert!(matches!(
+			decoded.data,
+			InstructionType::KaminoLendingInstruction(_)
+		));
+	}
+
+	#[test]
+	fn test_decode_with_spec_falls_back_to_layout() {
+		let layout: ProgramLayout = serde_json::from_value(serde_json::json!({
+			"instructions": [{
+				"name": "withdraw",
+				"discriminator": [2],
+				"fields": [{ "name": "amount", "type": "u16" }]
+			}]
+		}))
+		.unwrap();
+		let spec = SolanaContractSpec::new(SolanaDecoderType::Layout(layout));
+		let instruction = Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![],
+			data: vec![2, 7, 0],
+		};
+
+		let decoded = DecoderRegistry::new()
+			.decode_instruction_with_spec(&instruction, Some(&spec))
+			.unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::Custom(CustomInstruction {
+				name: "withdraw".to_string(),
+				args: serde_json::json!({ "amount": 7 }),
+				accounts: vec![],
+			})
+		);
+	}
+
+	#[test]
+	fn test_register_account_decoder() {
+		let program_id = Pubkey::new_unique();
+		let idl: AnchorIdl = serde_json::from_value(serde_json::json!({
+			"accounts": [{
+				"name": "Counter",
+				"discriminator": [3, 3, 3, 3, 3, 3, 3, 3],
+				"type": { "kind": "struct", "fields": [{ "name": "count", "type": "u8" }] }
+			}]
+		}))
+		.unwrap();
+		let account = Account {
+			lamports: 10,
+			data: vec![3, 3, 3, 3, 3, 3, 3, 3, 5],
+			owner: program_id,
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let mut registry = DecoderRegistry::new();
+		assert!(registry.decode_account(&account).is_none());
+		assert!(registry
+			.register_account_decoder(program_id, idl.clone())
+			.is_none());
+		assert!(registry.has_account_decoder(&program_id));
+
+		let decoded = registry.decode_account(&account).unwrap();
+		assert_eq!(decoded.lamports, 10);
+		assert_eq!(
+			decoded.data,
+			AccountType::Custom(CustomAccount {
+				name: "Counter".to_string(),
+				data: serde_json::json!({ "count": 5 }),
+			})
+		);
+
+		let spec = SolanaContractSpec::new(SolanaDecoderType::Idl(idl));
+		let decoded = DecoderRegistry::new()
+			.decode_account_with_spec(&account, Some(&spec))
+			.unwrap();
+		assert!(matches!(decoded.data, AccountType::Custom(_)));
+	}
+}
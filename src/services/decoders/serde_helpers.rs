@@ -0,0 +1,124 @@
+//! Serde helpers shared by the built-in instruction and account decoders.
+//!
+//! Decoded instructions and accounts are serialized to JSON to build match parameters,
+//! so public keys and raw bytes are serialized in the same textual form used everywhere
+//! else (base58 for public keys, `0x` prefixed hex for bytes).
+
+/// Serializes a [`Pubkey`](solana_sdk::pubkey::Pubkey) as a base58 string
+pub(crate) mod pubkey_base58 {
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+	use solana_sdk::pubkey::Pubkey;
+
+	pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&pubkey.to_string())
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		value.parse().map_err(D::Error::custom)
+	}
+}
+
+/// Serializes an optional [`Pubkey`](solana_sdk::pubkey::Pubkey) as a base58 string or null
+pub(crate) mod option_pubkey_base58 {
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+	use solana_sdk::pubkey::Pubkey;
+
+	pub fn serialize<S: Serializer>(
+		pubkey: &Option<Pubkey>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		match pubkey {
+			Some(pubkey) => serializer.serialize_some(&pubkey.to_string()),
+			None => serializer.serialize_none(),
+		}
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Option<Pubkey>, D::Error> {
+		Option::<String>::deserialize(deserializer)?
+			.map(|value| value.parse().map_err(D::Error::custom))
+			.transpose()
+	}
+}
+
+/// Serializes a list of [`Pubkey`](solana_sdk::pubkey::Pubkey) as base58 strings
+pub(crate) mod pubkey_base58_vec {
+	use serde::{de::Error, ser::SerializeSeq, Deserialize, Deserializer, Serializer};
+	use solana_sdk::pubkey::Pubkey;
+
+	pub fn serialize<S: Serializer>(pubkeys: &[Pubkey], serializer: S) -> Result<S::Ok, S::Error> {
+		let mut seq = serializer.serialize_seq(Some(pubkeys.len()))?;
+		for pubkey in pubkeys {
+			seq.serialize_element(&pubkey.to_string())?;
+		}
+		seq.end()
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Vec<Pubkey>, D::Error> {
+		Vec::<String>::deserialize(deserializer)?
+			.iter()
+			.map(|value| value.parse().map_err(D::Error::custom))
+			.collect()
+	}
+}
+
+/// Serializes bytes as a `0x` prefixed hex string
+pub(crate) mod hex_bytes {
+	use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+		let value = String::deserialize(deserializer)?;
+		hex::decode(value.trim_start_matches("0x")).map_err(D::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+	use solana_sdk::pubkey::Pubkey;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Wrapper {
+		#[serde(with = "super::pubkey_base58")]
+		pubkey: Pubkey,
+		#[serde(with = "super::hex_bytes")]
+		bytes: Vec<u8>,
+		#[serde(with = "super::option_pubkey_base58")]
+		delegate: Option<Pubkey>,
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let wrapper = Wrapper {
+			pubkey: Pubkey::new_unique(),
+			bytes: vec![0xde, 0xad],
+			delegate: None,
+		};
+		let value = serde_json::to_value(&wrapper).unwrap();
+		assert_eq!(
+			value,
+			json!({ "pubkey": wrapper.pubkey.to_string(), "bytes": "0xdead", "delegate": null })
+		);
+		assert_eq!(serde_json::from_value::<Wrapper>(value).unwrap(), wrapper);
+
+		let wrapper = Wrapper {
+			delegate: Some(Pubkey::new_unique()),
+			..wrapper
+		};
+		let value = serde_json::to_value(&wrapper).unwrap();
+		assert_eq!(
+			value["delegate"],
+			json!(wrapper.delegate.unwrap().to_string())
+		);
+		assert_eq!(serde_json::from_value::<Wrapper>(value).unwrap(), wrapper);
+	}
+}
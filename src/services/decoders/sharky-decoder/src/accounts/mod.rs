@@ -0,0 +1,15 @@
+use carbon_core::account::AccountDecoder;
+
+use super::SharkyDecoder;
+
+pub enum SharkyAccount {}
+
+impl AccountDecoder<'_> for SharkyDecoder {
+    type AccountType = SharkyAccount;
+    fn decode_account(
+        &self,
+        _account: &solana_account::Account,
+    ) -> Option<carbon_core::account::DecodedAccount<Self::AccountType>> {
+        None
+    }
+}
@@ -0,0 +1,53 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x471b11834e493e5c")]
+pub struct ExtendLoanV3 {
+    pub expected_loan: solana_pubkey::Pubkey,
+}
+
+pub struct ExtendLoanV3InstructionAccounts {
+    pub loan: solana_pubkey::Pubkey,
+    pub new_loan: solana_pubkey::Pubkey,
+    pub borrower: solana_pubkey::Pubkey,
+    pub lender: solana_pubkey::Pubkey,
+    pub new_lender: solana_pubkey::Pubkey,
+    pub escrow: solana_pubkey::Pubkey,
+    pub new_escrow: solana_pubkey::Pubkey,
+    pub collateral_mint: solana_pubkey::Pubkey,
+    pub order_book: solana_pubkey::Pubkey,
+    pub fee_authority: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for ExtendLoanV3 {
+    type ArrangedAccounts = ExtendLoanV3InstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [loan, new_loan, borrower, lender, new_lender, escrow, new_escrow, collateral_mint, order_book, fee_authority, system_program, token_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ExtendLoanV3InstructionAccounts {
+            loan: loan.pubkey,
+            new_loan: new_loan.pubkey,
+            borrower: borrower.pubkey,
+            lender: lender.pubkey,
+            new_lender: new_lender.pubkey,
+            escrow: escrow.pubkey,
+            new_escrow: new_escrow.pubkey,
+            collateral_mint: collateral_mint.pubkey,
+            order_book: order_book.pubkey,
+            fee_authority: fee_authority.pubkey,
+            system_program: system_program.pubkey,
+            token_program: token_program.pubkey,
+        })
+    }
+}
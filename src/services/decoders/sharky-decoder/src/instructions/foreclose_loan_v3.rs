@@ -0,0 +1,53 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x88b8323ab75c3fd8")]
+pub struct ForecloseLoanV3 {}
+
+pub struct ForecloseLoanV3InstructionAccounts {
+    pub loan: solana_pubkey::Pubkey,
+    pub escrow: solana_pubkey::Pubkey,
+    pub lender: solana_pubkey::Pubkey,
+    pub borrower: solana_pubkey::Pubkey,
+    pub collateral_mint: solana_pubkey::Pubkey,
+    pub escrow_collateral_token_account: solana_pubkey::Pubkey,
+    pub lender_collateral_token_account: solana_pubkey::Pubkey,
+    pub order_book: solana_pubkey::Pubkey,
+    pub metadata: solana_pubkey::Pubkey,
+    pub edition: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+    pub associated_token_program: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for ForecloseLoanV3 {
+    type ArrangedAccounts = ForecloseLoanV3InstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [loan, escrow, lender, borrower, collateral_mint, escrow_collateral_token_account, lender_collateral_token_account, order_book, metadata, edition, system_program, token_program, associated_token_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(ForecloseLoanV3InstructionAccounts {
+            loan: loan.pubkey,
+            escrow: escrow.pubkey,
+            lender: lender.pubkey,
+            borrower: borrower.pubkey,
+            collateral_mint: collateral_mint.pubkey,
+            escrow_collateral_token_account: escrow_collateral_token_account.pubkey,
+            lender_collateral_token_account: lender_collateral_token_account.pubkey,
+            order_book: order_book.pubkey,
+            metadata: metadata.pubkey,
+            edition: edition.pubkey,
+            system_program: system_program.pubkey,
+            token_program: token_program.pubkey,
+            associated_token_program: associated_token_program.pubkey,
+        })
+    }
+}
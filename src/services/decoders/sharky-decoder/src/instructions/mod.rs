@@ -0,0 +1,59 @@
+use super::PROGRAM_ID;
+
+use super::SharkyDecoder;
+pub mod close_order_book;
+pub mod create_order_book;
+pub mod extend_loan_v3;
+pub mod foreclose_loan_v3;
+pub mod offer_loan;
+pub mod repay_loan_v3;
+pub mod rescind_loan;
+pub mod take_loan_v3;
+pub mod update_order_book;
+
+#[derive(
+    carbon_core::InstructionType,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    Debug,
+    Clone,
+    Hash,
+)]
+pub enum SharkyInstruction {
+    CloseOrderBook(close_order_book::CloseOrderBook),
+    CreateOrderBook(create_order_book::CreateOrderBook),
+    ExtendLoanV3(extend_loan_v3::ExtendLoanV3),
+    ForecloseLoanV3(foreclose_loan_v3::ForecloseLoanV3),
+    OfferLoan(offer_loan::OfferLoan),
+    RepayLoanV3(repay_loan_v3::RepayLoanV3),
+    RescindLoan(rescind_loan::RescindLoan),
+    TakeLoanV3(take_loan_v3::TakeLoanV3),
+    UpdateOrderBook(update_order_book::UpdateOrderBook),
+}
+
+impl carbon_core::instruction::InstructionDecoder<'_> for SharkyDecoder {
+    type InstructionType = SharkyInstruction;
+
+    fn decode_instruction(
+        &self,
+        instruction: &solana_instruction::Instruction,
+    ) -> Option<carbon_core::instruction::DecodedInstruction<Self::InstructionType>> {
+        if !instruction.program_id.eq(&PROGRAM_ID) {
+            return None;
+        }
+
+        carbon_core::try_decode_instructions!(instruction,
+            SharkyInstruction::CloseOrderBook => close_order_book::CloseOrderBook,
+            SharkyInstruction::CreateOrderBook => create_order_book::CreateOrderBook,
+            SharkyInstruction::ExtendLoanV3 => extend_loan_v3::ExtendLoanV3,
+            SharkyInstruction::ForecloseLoanV3 => foreclose_loan_v3::ForecloseLoanV3,
+            SharkyInstruction::OfferLoan => offer_loan::OfferLoan,
+            SharkyInstruction::RepayLoanV3 => repay_loan_v3::RepayLoanV3,
+            SharkyInstruction::RescindLoan => rescind_loan::RescindLoan,
+            SharkyInstruction::TakeLoanV3 => take_loan_v3::TakeLoanV3,
+            SharkyInstruction::UpdateOrderBook => update_order_book::UpdateOrderBook,
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x617b55364c103d9d")]
+pub struct RepayLoanV3 {}
+
+pub struct RepayLoanV3InstructionAccounts {
+    pub loan: solana_pubkey::Pubkey,
+    pub borrower: solana_pubkey::Pubkey,
+    pub lender: solana_pubkey::Pubkey,
+    pub escrow: solana_pubkey::Pubkey,
+    pub collateral_mint: solana_pubkey::Pubkey,
+    pub borrower_collateral_token_account: solana_pubkey::Pubkey,
+    pub escrow_collateral_token_account: solana_pubkey::Pubkey,
+    pub order_book: solana_pubkey::Pubkey,
+    pub fee_authority: solana_pubkey::Pubkey,
+    pub metadata: solana_pubkey::Pubkey,
+    pub edition: solana_pubkey::Pubkey,
+    pub system_program: solana_pubkey::Pubkey,
+    pub token_program: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for RepayLoanV3 {
+    type ArrangedAccounts = RepayLoanV3InstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [loan, borrower, lender, escrow, collateral_mint, borrower_collateral_token_account, escrow_collateral_token_account, order_book, fee_authority, metadata, edition, system_program, token_program, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(RepayLoanV3InstructionAccounts {
+            loan: loan.pubkey,
+            borrower: borrower.pubkey,
+            lender: lender.pubkey,
+            escrow: escrow.pubkey,
+            collateral_mint: collateral_mint.pubkey,
+            borrower_collateral_token_account: borrower_collateral_token_account.pubkey,
+            escrow_collateral_token_account: escrow_collateral_token_account.pubkey,
+            order_book: order_book.pubkey,
+            fee_authority: fee_authority.pubkey,
+            metadata: metadata.pubkey,
+            edition: edition.pubkey,
+            system_program: system_program.pubkey,
+            token_program: token_program.pubkey,
+        })
+    }
+}
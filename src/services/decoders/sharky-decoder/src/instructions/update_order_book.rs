@@ -0,0 +1,39 @@
+use super::super::types::*;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+#[carbon(discriminator = "0x1f489fe8dc995a6d")]
+pub struct UpdateOrderBook {
+    pub book_type: BookType,
+    pub apy: APY,
+    pub loan_terms: BookLoanTerms,
+    pub fee_permillicentage: u16,
+    pub fee_authority: solana_pubkey::Pubkey,
+}
+
+pub struct UpdateOrderBookInstructionAccounts {
+    pub order_book: solana_pubkey::Pubkey,
+    pub payer: solana_pubkey::Pubkey,
+}
+
+impl carbon_core::deserialize::ArrangeAccounts for UpdateOrderBook {
+    type ArrangedAccounts = UpdateOrderBookInstructionAccounts;
+
+    fn arrange_accounts(
+        accounts: &[solana_instruction::AccountMeta],
+    ) -> Option<Self::ArrangedAccounts> {
+        let [order_book, payer, _remaining @ ..] =
+            accounts
+        else {
+            return None;
+        };
+
+        Some(UpdateOrderBookInstructionAccounts {
+            order_book: order_book.pubkey,
+            payer: payer.pubkey,
+        })
+    }
+}
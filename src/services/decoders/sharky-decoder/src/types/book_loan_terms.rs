@@ -0,0 +1,11 @@
+use super::*;
+
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum BookLoanTerms {
+    Fixed { terms: LoanTermsSpec },
+    LenderChooses,
+}
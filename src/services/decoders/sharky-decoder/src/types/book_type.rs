@@ -0,0 +1,9 @@
+use carbon_core::{borsh, CarbonDeserialize};
+
+#[derive(
+    CarbonDeserialize, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Hash,
+)]
+pub enum BookType {
+    NFTList { list_account: solana_pubkey::Pubkey },
+    Collection { collection_key: solana_pubkey::Pubkey },
+}
@@ -0,0 +1,8 @@
+pub mod apy;
+pub use apy::*;
+pub mod book_loan_terms;
+pub use book_loan_terms::*;
+pub mod book_type;
+pub use book_type::*;
+pub mod loan_terms_spec;
+pub use loan_terms_spec::*;
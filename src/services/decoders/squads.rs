@@ -0,0 +1,588 @@
+//! Decoder for the Squads v4 multisig program.
+//!
+//! Squads multisigs hold treasuries and program authorities. Transactions are proposed,
+//! voted on by the members and executed once the approval threshold is reached. Decoding
+//! the proposal instructions and the `Proposal` and `Multisig` accounts allows monitors to
+//! alert when a proposal is created, approved or executed, or when the members or the
+//! threshold of a multisig change.
+//!
+//! The program is an Anchor program, so instructions and accounts are identified by their
+//! 8-byte discriminator and their fields are Borsh encoded.
+
+use carbon_core::borsh::BorshDeserialize;
+use serde::{Deserialize, Serialize};
+use solana_account::Account;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::services::decoders::{
+	serde_helpers::{hex_bytes, option_pubkey_base58, pubkey_base58, pubkey_base58_vec},
+	variant_names, AccountDecoder, AccountType, DecodedAccount, DecodedInstruction,
+	InstructionDecoder, InstructionType,
+};
+
+/// Address of the Squads v4 program
+pub const PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("SQDS4ep65T869zMMBKyuUq6SqBHCqvtQGDUn7JMPFVo");
+
+const MULTISIG_CREATE_V2: [u8; 8] = [0x32, 0xdd, 0xc7, 0x5d, 0x28, 0xf5, 0x8b, 0xe9];
+const MULTISIG_ADD_MEMBER: [u8; 8] = [0x01, 0xdb, 0xd7, 0x6c, 0xb8, 0xe5, 0xd6, 0x08];
+const MULTISIG_REMOVE_MEMBER: [u8; 8] = [0xd9, 0x75, 0xb1, 0xd2, 0xb6, 0x91, 0xda, 0x48];
+const MULTISIG_CHANGE_THRESHOLD: [u8; 8] = [0x8d, 0x2a, 0x0f, 0x7e, 0xa9, 0x5c, 0x3e, 0xb5];
+const VAULT_TRANSACTION_CREATE: [u8; 8] = [0x30, 0xfa, 0x4e, 0xa8, 0xd0, 0xe2, 0xda, 0xd3];
+const PROPOSAL_CREATE: [u8; 8] = [0xdc, 0x3c, 0x49, 0xe0, 0x1e, 0x6c, 0x4f, 0x9f];
+const PROPOSAL_ACTIVATE: [u8; 8] = [0x0b, 0x22, 0x5c, 0xf8, 0x9a, 0x1b, 0x33, 0x6a];
+const PROPOSAL_APPROVE: [u8; 8] = [0x90, 0x25, 0xa4, 0x88, 0xbc, 0xd8, 0x2a, 0xf8];
+const PROPOSAL_REJECT: [u8; 8] = [0xf3, 0x3e, 0x86, 0x9c, 0xe6, 0x6a, 0xf6, 0x87];
+const PROPOSAL_CANCEL: [u8; 8] = [0x1b, 0x2a, 0x7f, 0xed, 0x26, 0xa3, 0x54, 0xcb];
+const VAULT_TRANSACTION_EXECUTE: [u8; 8] = [0xc2, 0x08, 0xa1, 0x57, 0x99, 0xa4, 0x19, 0xab];
+const CONFIG_TRANSACTION_EXECUTE: [u8; 8] = [0x72, 0x92, 0xf4, 0xbd, 0xfc, 0x8c, 0x24, 0x28];
+
+const MULTISIG_ACCOUNT: [u8; 8] = [0xe0, 0x74, 0x79, 0xba, 0x44, 0xa1, 0x4f, 0xec];
+const PROPOSAL_ACCOUNT: [u8; 8] = [0x1a, 0x5e, 0xbd, 0xbb, 0x74, 0x88, 0x35, 0x21];
+
+/// Decoder for Squads v4 instructions and accounts
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SquadsDecoder;
+
+/// Instructions of the Squads v4 program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SquadsInstruction {
+	MultisigCreateV2(MultisigCreateV2),
+	MultisigAddMember(MultisigAddMember),
+	MultisigRemoveMember(MultisigRemoveMember),
+	MultisigChangeThreshold(MultisigChangeThreshold),
+	VaultTransactionCreate(VaultTransactionCreate),
+	ProposalCreate(ProposalCreate),
+	ProposalActivate(ProposalActivate),
+	ProposalApprove(ProposalVote),
+	ProposalReject(ProposalVote),
+	ProposalCancel(ProposalVote),
+	VaultTransactionExecute(VaultTransactionExecute),
+	ConfigTransactionExecute(ConfigTransactionExecute),
+}
+
+/// Member of a multisig and the permissions it holds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+	/// Address of the member
+	#[serde(with = "pubkey_base58")]
+	pub key: Pubkey,
+	/// Bit mask of the member permissions (1 = initiate, 2 = vote, 4 = execute)
+	pub permissions: u8,
+}
+
+/// Creates a new multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigCreateV2 {
+	/// Authority allowed to change the configuration without a proposal
+	#[serde(with = "option_pubkey_base58")]
+	pub config_authority: Option<Pubkey>,
+	/// Number of approvals required to execute a transaction
+	pub threshold: u16,
+	/// Members of the multisig
+	pub members: Vec<Member>,
+	/// Seconds between the approval and the execution of a transaction
+	pub time_lock: u32,
+	/// Account receiving the rent of closed accounts
+	#[serde(with = "option_pubkey_base58")]
+	pub rent_collector: Option<Pubkey>,
+	pub memo: Option<String>,
+}
+
+/// Adds a member to a controlled multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigAddMember {
+	/// Member added to the multisig
+	pub new_member: Member,
+	pub memo: Option<String>,
+}
+
+/// Removes a member from a controlled multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigRemoveMember {
+	/// Address of the removed member
+	#[serde(with = "pubkey_base58")]
+	pub old_member: Pubkey,
+	pub memo: Option<String>,
+}
+
+/// Changes the approval threshold of a controlled multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultisigChangeThreshold {
+	/// New number of approvals required to execute a transaction
+	pub new_threshold: u16,
+	pub memo: Option<String>,
+}
+
+/// Creates a transaction to be executed by a vault of the multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultTransactionCreate {
+	/// Index of the vault executing the transaction
+	pub vault_index: u8,
+	/// Number of ephemeral signers used by the transaction
+	pub ephemeral_signers: u8,
+	/// Serialized transaction message
+	#[serde(with = "hex_bytes")]
+	pub transaction_message: Vec<u8>,
+	pub memo: Option<String>,
+}
+
+/// Creates a proposal for a transaction of the multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalCreate {
+	/// Index of the transaction the proposal is for
+	pub transaction_index: u64,
+	/// Whether the proposal is created as a draft
+	pub draft: bool,
+}
+
+/// Activates a draft proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalActivate {}
+
+/// Approves, rejects or cancels a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProposalVote {
+	pub memo: Option<String>,
+}
+
+/// Executes an approved vault transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultTransactionExecute {}
+
+/// Executes an approved configuration transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigTransactionExecute {}
+
+/// Accounts of the Squads v4 program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SquadsAccount {
+	Multisig(Multisig),
+	Proposal(Proposal),
+}
+
+/// Configuration of a multisig
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Multisig {
+	/// Key used to derive the multisig address
+	#[serde(with = "pubkey_base58")]
+	pub create_key: Pubkey,
+	/// Authority allowed to change the configuration without a proposal, or the default
+	/// address for autonomous multisigs
+	#[serde(with = "pubkey_base58")]
+	pub config_authority: Pubkey,
+	/// Number of approvals required to execute a transaction
+	pub threshold: u16,
+	/// Seconds between the approval and the execution of a transaction
+	pub time_lock: u32,
+	/// Index of the last created transaction
+	pub transaction_index: u64,
+	/// Index of the last transaction made stale by a configuration change
+	pub stale_transaction_index: u64,
+	/// Account receiving the rent of closed accounts
+	#[serde(with = "option_pubkey_base58")]
+	pub rent_collector: Option<Pubkey>,
+	pub bump: u8,
+	/// Members of the multisig
+	pub members: Vec<Member>,
+}
+
+/// Status of a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalStatus {
+	Draft,
+	Active,
+	Rejected,
+	Approved,
+	Executing,
+	Executed,
+	Cancelled,
+}
+
+/// Votes and status of a proposal
+///
+/// The status is flattened into `status` and `status_timestamp`, so that the status can
+/// be compared directly in expressions (e.g. `new_status == 'Approved'`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proposal {
+	/// Multisig the proposal belongs to
+	#[serde(with = "pubkey_base58")]
+	pub multisig: Pubkey,
+	/// Index of the transaction the proposal is for
+	pub transaction_index: u64,
+	/// Status of the proposal
+	pub status: ProposalStatus,
+	/// Unix timestamp at which the status was reached
+	pub status_timestamp: Option<i64>,
+	pub bump: u8,
+	/// Members that approved the proposal
+	#[serde(with = "pubkey_base58_vec")]
+	pub approved: Vec<Pubkey>,
+	/// Members that rejected the proposal
+	#[serde(with = "pubkey_base58_vec")]
+	pub rejected: Vec<Pubkey>,
+	/// Members that cancelled the approved proposal
+	#[serde(with = "pubkey_base58_vec")]
+	pub cancelled: Vec<Pubkey>,
+}
+
+impl SquadsInstruction {
+	/// Decodes Squads instruction data
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, starting with the 8-byte discriminator
+	///
+	/// # Returns
+	/// The decoded instruction, or `None` if the data is not a supported instruction
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let (discriminator, mut rest) = data.split_at_checked(8)?;
+		let instruction = match <[u8; 8]>::try_from(discriminator).ok()? {
+			MULTISIG_CREATE_V2 => Self::MultisigCreateV2(MultisigCreateV2 {
+				config_authority: read_option_pubkey(&mut rest)?,
+				threshold: read(&mut rest)?,
+				members: read_members(&mut rest)?,
+				time_lock: read(&mut rest)?,
+				rent_collector: read_option_pubkey(&mut rest)?,
+				memo: read(&mut rest)?,
+			}),
+			MULTISIG_ADD_MEMBER => Self::MultisigAddMember(MultisigAddMember {
+				new_member: read_member(&mut rest)?,
+				memo: read(&mut rest)?,
+			}),
+			MULTISIG_REMOVE_MEMBER => Self::MultisigRemoveMember(MultisigRemoveMember {
+				old_member: read_pubkey(&mut rest)?,
+				memo: read(&mut rest)?,
+			}),
+			MULTISIG_CHANGE_THRESHOLD => Self::MultisigChangeThreshold(MultisigChangeThreshold {
+				new_threshold: read(&mut rest)?,
+				memo: read(&mut rest)?,
+			}),
+			VAULT_TRANSACTION_CREATE => Self::VaultTransactionCreate(VaultTransactionCreate {
+				vault_index: read(&mut rest)?,
+				ephemeral_signers: read(&mut rest)?,
+				transaction_message: read(&mut rest)?,
+				memo: read(&mut rest)?,
+			}),
+			PROPOSAL_CREATE => Self::ProposalCreate(ProposalCreate {
+				transaction_index: read(&mut rest)?,
+				draft: read(&mut rest)?,
+			}),
+			PROPOSAL_ACTIVATE => Self::ProposalActivate(ProposalActivate {}),
+			PROPOSAL_APPROVE => Self::ProposalApprove(ProposalVote {
+				memo: read(&mut rest)?,
+			}),
+			PROPOSAL_REJECT => Self::ProposalReject(ProposalVote {
+				memo: read(&mut rest)?,
+			}),
+			PROPOSAL_CANCEL => Self::ProposalCancel(ProposalVote {
+				memo: read(&mut rest)?,
+			}),
+			VAULT_TRANSACTION_EXECUTE => Self::VaultTransactionExecute(VaultTransactionExecute {}),
+			CONFIG_TRANSACTION_EXECUTE => {
+				Self::ConfigTransactionExecute(ConfigTransactionExecute {})
+			}
+			_ => return None,
+		};
+		Some(instruction)
+	}
+}
+
+impl SquadsAccount {
+	/// Decodes Squads account data
+	///
+	/// # Arguments
+	/// * `data` - Raw account data, starting with the 8-byte discriminator
+	///
+	/// # Returns
+	/// The decoded account, or `None` if the data is not a supported account
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let (discriminator, mut rest) = data.split_at_checked(8)?;
+		let account = match <[u8; 8]>::try_from(discriminator).ok()? {
+			MULTISIG_ACCOUNT => Self::Multisig(Multisig {
+				create_key: read_pubkey(&mut rest)?,
+				config_authority: read_pubkey(&mut rest)?,
+				threshold: read(&mut rest)?,
+				time_lock: read(&mut rest)?,
+				transaction_index: read(&mut rest)?,
+				stale_transaction_index: read(&mut rest)?,
+				rent_collector: read_option_pubkey(&mut rest)?,
+				bump: read(&mut rest)?,
+				members: read_members(&mut rest)?,
+			}),
+			PROPOSAL_ACCOUNT => {
+				let multisig = read_pubkey(&mut rest)?;
+				let transaction_index = read(&mut rest)?;
+				let (status, status_timestamp) = read_proposal_status(&mut rest)?;
+				Self::Proposal(Proposal {
+					multisig,
+					transaction_index,
+					status,
+					status_timestamp,
+					bump: read(&mut rest)?,
+					approved: read_pubkeys(&mut rest)?,
+					rejected: read_pubkeys(&mut rest)?,
+					cancelled: read_pubkeys(&mut rest)?,
+				})
+			}
+			_ => return None,
+		};
+		Some(account)
+	}
+}
+
+/// Reads a Borsh encoded value from the front of `data`
+fn read<T: BorshDeserialize>(data: &mut &[u8]) -> Option<T> {
+	T::deserialize(data).ok()
+}
+
+fn read_pubkey(data: &mut &[u8]) -> Option<Pubkey> {
+	read(data).map(Pubkey::new_from_array)
+}
+
+fn read_option_pubkey(data: &mut &[u8]) -> Option<Option<Pubkey>> {
+	read::<Option<[u8; 32]>>(data).map(|pubkey| pubkey.map(Pubkey::new_from_array))
+}
+
+fn read_pubkeys(data: &mut &[u8]) -> Option<Vec<Pubkey>> {
+	read::<Vec<[u8; 32]>>(data)
+		.map(|pubkeys| pubkeys.into_iter().map(Pubkey::new_from_array).collect())
+}
+
+fn read_member(data: &mut &[u8]) -> Option<Member> {
+	Some(Member {
+		key: read_pubkey(data)?,
+		permissions: read(data)?,
+	})
+}
+
+fn read_members(data: &mut &[u8]) -> Option<Vec<Member>> {
+	let len: u32 = read(data)?;
+	(0..len).map(|_| read_member(data)).collect()
+}
+
+fn read_proposal_status(data: &mut &[u8]) -> Option<(ProposalStatus, Option<i64>)> {
+	let tag: u8 = read(data)?;
+	let status = match tag {
+		0 => ProposalStatus::Draft,
+		1 => ProposalStatus::Active,
+		2 => ProposalStatus::Rejected,
+		3 => ProposalStatus::Approved,
+		4 => return Some((ProposalStatus::Executing, None)),
+		5 => ProposalStatus::Executed,
+		6 => ProposalStatus::Cancelled,
+		_ => return None,
+	};
+	Some((status, Some(read(data)?)))
+}
+
+impl InstructionDecoder<'_> for SquadsDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if instruction.program_id != PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::Squads(SquadsInstruction::decode(&instruction.data)?),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::SQUADS_INSTRUCTION_NAMES
+	}
+}
+
+impl AccountDecoder<'_> for SquadsDecoder {
+	type AccountType = AccountType;
+
+	fn decode_account(&self, account: &Account) -> Option<DecodedAccount<Self::AccountType>> {
+		if account.owner != PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedAccount {
+			lamports: account.lamports,
+			data: AccountType::Squads(SquadsAccount::decode(&account.data)?),
+			owner: account.owner,
+			executable: account.executable,
+			rent_epoch: account.rent_epoch,
+		})
+	}
+
+	fn account_names(&self) -> &[&str] {
+		variant_names::SQUADS_ACCOUNT_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn encode_proposal(status_tag: u8, approved: &[Pubkey]) -> Vec<u8> {
+		let mut data = PROPOSAL_ACCOUNT.to_vec();
+		data.extend_from_slice(Pubkey::new_unique().as_ref());
+		data.extend_from_slice(&7u64.to_le_bytes());
+		data.push(status_tag);
+		if status_tag != 4 {
+			data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+		}
+		data.push(255);
+		data.extend_from_slice(&(approved.len() as u32).to_le_bytes());
+		for member in approved {
+			data.extend_from_slice(member.as_ref());
+		}
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data
+	}
+
+	#[test]
+	fn test_decode_proposal_create() {
+		let mut data = PROPOSAL_CREATE.to_vec();
+		data.extend_from_slice(&42u64.to_le_bytes());
+		data.push(0);
+
+		assert_eq!(
+			SquadsInstruction::decode(&data),
+			Some(SquadsInstruction::ProposalCreate(ProposalCreate {
+				transaction_index: 42,
+				draft: false,
+			}))
+		);
+	}
+
+	#[test]
+	fn test_decode_proposal_votes() {
+		let mut data = PROPOSAL_APPROVE.to_vec();
+		data.push(1);
+		data.extend_from_slice(&2u32.to_le_bytes());
+		data.extend_from_slice(b"ok");
+		assert_eq!(
+			SquadsInstruction::decode(&data),
+			Some(SquadsInstruction::ProposalApprove(ProposalVote {
+				memo: Some("ok".to_string()),
+			}))
+		);
+
+		let mut data = PROPOSAL_REJECT.to_vec();
+		data.push(0);
+		assert_eq!(
+			SquadsInstruction::decode(&data),
+			Some(SquadsInstruction::ProposalReject(ProposalVote {
+				memo: None
+			}))
+		);
+	}
+
+	#[test]
+	fn test_decode_multisig_add_member() {
+		let member = Pubkey::new_unique();
+		let mut data = MULTISIG_ADD_MEMBER.to_vec();
+		data.extend_from_slice(member.as_ref());
+		data.push(7);
+		data.push(0);
+
+		let decoded = SquadsInstruction::decode(&data).unwrap();
+		assert_eq!(
+			serde_json::to_value(&decoded).unwrap(),
+			serde_json::json!({
+				"MultisigAddMember": {
+					"new_member": { "key": member.to_string(), "permissions": 7 },
+					"memo": null
+				}
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_instruction() {
+		assert!(SquadsInstruction::decode(&[]).is_none());
+		assert!(SquadsInstruction::decode(&[0; 8]).is_none());
+		assert!(SquadsInstruction::decode(&PROPOSAL_CREATE).is_none());
+	}
+
+	#[test]
+	fn test_decode_proposal_account() {
+		let member = Pubkey::new_unique();
+		let account = Account {
+			lamports: 1,
+			data: encode_proposal(3, &[member]),
+			owner: PROGRAM_ID,
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let decoded = SquadsDecoder.decode_account(&account).unwrap();
+		let AccountType::Squads(SquadsAccount::Proposal(proposal)) = decoded.data else {
+			panic!("Unexpected account: {:?}", decoded.data);
+		};
+		assert_eq!(proposal.transaction_index, 7);
+		assert_eq!(proposal.status, ProposalStatus::Approved);
+		assert_eq!(proposal.status_timestamp, Some(1_700_000_000));
+		assert_eq!(proposal.approved, vec![member]);
+
+		let value = serde_json::to_value(&proposal).unwrap();
+		assert_eq!(value["status"], "Approved");
+		assert_eq!(value["approved"], serde_json::json!([member.to_string()]));
+	}
+
+	#[test]
+	fn test_decode_executing_proposal_without_timestamp() {
+		let Some(SquadsAccount::Proposal(proposal)) =
+			SquadsAccount::decode(&encode_proposal(4, &[]))
+		else {
+			panic!("Failed to decode proposal");
+		};
+		assert_eq!(proposal.status, ProposalStatus::Executing);
+		assert_eq!(proposal.status_timestamp, None);
+	}
+
+	#[test]
+	fn test_decode_multisig_account() {
+		let member = Pubkey::new_unique();
+		let mut data = MULTISIG_ACCOUNT.to_vec();
+		data.extend_from_slice(Pubkey::new_unique().as_ref());
+		data.extend_from_slice(Pubkey::default().as_ref());
+		data.extend_from_slice(&2u16.to_le_bytes());
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data.extend_from_slice(&12u64.to_le_bytes());
+		data.extend_from_slice(&3u64.to_le_bytes());
+		data.push(0);
+		data.push(254);
+		data.extend_from_slice(&1u32.to_le_bytes());
+		data.extend_from_slice(member.as_ref());
+		data.push(7);
+
+		let Some(SquadsAccount::Multisig(multisig)) = SquadsAccount::decode(&data) else {
+			panic!("Failed to decode multisig");
+		};
+		assert_eq!(multisig.threshold, 2);
+		assert_eq!(multisig.transaction_index, 12);
+		assert_eq!(multisig.rent_collector, None);
+		assert_eq!(
+			multisig.members,
+			vec![Member {
+				key: member,
+				permissions: 7,
+			}]
+		);
+	}
+
+	#[test]
+	fn test_decode_rejects_other_program() {
+		let account = Account {
+			lamports: 1,
+			data: encode_proposal(1, &[]),
+			owner: Pubkey::new_unique(),
+			executable: false,
+			rent_epoch: 0,
+		};
+		assert!(SquadsDecoder.decode_account(&account).is_none());
+	}
+}
@@ -0,0 +1,210 @@
+//! Decoder for the accounts of the SPL Token and Token-2022 programs.
+//!
+//! Token accounts hold the balance of a mint for an owner, and carry the authorities
+//! allowed to move or close them. Decoding them allows monitors to watch the owner,
+//! delegate and close authority of token accounts, which drainers commonly take over.
+
+use serde::{Deserialize, Serialize};
+use solana_account::Account;
+use solana_program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_token::state::{Account as SplTokenAccount, AccountState};
+
+use crate::services::decoders::{
+	serde_helpers::{option_pubkey_base58, pubkey_base58},
+	variant_names, AccountDecoder, AccountType, DecodedAccount,
+};
+
+/// Address of the SPL Token program
+pub const TOKEN_PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Address of the SPL Token-2022 program
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+	Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Account type tag of Token-2022 token accounts carrying extensions
+const TOKEN_2022_ACCOUNT_TYPE: u8 = 2;
+
+/// Decoder for token accounts of the SPL Token and Token-2022 programs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenAccountDecoder;
+
+/// State of a token account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenAccountState {
+	Initialized,
+	Frozen,
+}
+
+/// Decoded token account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenAccount {
+	/// Mint of the tokens held by the account
+	#[serde(with = "pubkey_base58")]
+	pub mint: Pubkey,
+	/// Owner of the tokens, exposed as `token_owner` since `owner` is the program owning
+	/// the account
+	#[serde(rename = "token_owner", with = "pubkey_base58")]
+	pub owner: Pubkey,
+	/// Amount of tokens held by the account
+	pub amount: u64,
+	/// Delegate allowed to transfer `delegated_amount` tokens
+	#[serde(with = "option_pubkey_base58")]
+	pub delegate: Option<Pubkey>,
+	/// State of the account
+	pub state: TokenAccountState,
+	/// Rent-exempt reserve of wrapped SOL accounts
+	pub is_native: Option<u64>,
+	/// Amount of tokens the delegate is allowed to transfer
+	pub delegated_amount: u64,
+	/// Authority allowed to close the account
+	#[serde(with = "option_pubkey_base58")]
+	pub close_authority: Option<Pubkey>,
+}
+
+impl TokenAccount {
+	/// Decodes token account data
+	///
+	/// Token-2022 accounts are decoded from their base state, ignoring extensions.
+	///
+	/// # Arguments
+	/// * `data` - Raw account data
+	///
+	/// # Returns
+	/// The decoded token account, or `None` if the data is not an initialized token account
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let base = data.get(..SplTokenAccount::LEN)?;
+		if data.len() > SplTokenAccount::LEN
+			&& data.get(SplTokenAccount::LEN) != Some(&TOKEN_2022_ACCOUNT_TYPE)
+		{
+			return None;
+		}
+
+		let account = SplTokenAccount::unpack_from_slice(base).ok()?;
+		let state = match account.state {
+			AccountState::Uninitialized => return None,
+			AccountState::Initialized => TokenAccountState::Initialized,
+			AccountState::Frozen => TokenAccountState::Frozen,
+		};
+		Some(Self {
+			mint: account.mint,
+			owner: account.owner,
+			amount: account.amount,
+			delegate: account.delegate.into(),
+			state,
+			is_native: account.is_native.into(),
+			delegated_amount: account.delegated_amount,
+			close_authority: account.close_authority.into(),
+		})
+	}
+}
+
+impl AccountDecoder<'_> for TokenAccountDecoder {
+	type AccountType = AccountType;
+
+	fn decode_account(&self, account: &Account) -> Option<DecodedAccount<Self::AccountType>> {
+		if account.owner != TOKEN_PROGRAM_ID && account.owner != TOKEN_2022_PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedAccount {
+			lamports: account.lamports,
+			data: AccountType::TokenAccount(TokenAccount::decode(&account.data)?),
+			owner: account.owner,
+			executable: account.executable,
+			rent_epoch: account.rent_epoch,
+		})
+	}
+
+	fn account_names(&self) -> &[&str] {
+		variant_names::TOKEN_ACCOUNT_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use solana_program::program_option::COption;
+
+	fn create_token_account(
+		owner: Pubkey,
+		delegate: Option<Pubkey>,
+		close_authority: Option<Pubkey>,
+	) -> SplTokenAccount {
+		SplTokenAccount {
+			mint: Pubkey::new_unique(),
+			owner,
+			amount: 1_000,
+			delegate: delegate.into(),
+			state: AccountState::Initialized,
+			is_native: COption::None,
+			delegated_amount: 0,
+			close_authority: close_authority.into(),
+		}
+	}
+
+	fn pack(account: SplTokenAccount) -> Vec<u8> {
+		let mut data = vec![0; SplTokenAccount::LEN];
+		account.pack_into_slice(&mut data);
+		data
+	}
+
+	#[test]
+	fn test_decode_token_account() {
+		let owner = Pubkey::new_unique();
+		let close_authority = Pubkey::new_unique();
+		let account = create_token_account(owner, None, Some(close_authority));
+
+		let decoded = TokenAccount::decode(&pack(account)).unwrap();
+		assert_eq!(decoded.mint, account.mint);
+		assert_eq!(decoded.owner, owner);
+		assert_eq!(decoded.amount, 1_000);
+		assert_eq!(decoded.delegate, None);
+		assert_eq!(decoded.state, TokenAccountState::Initialized);
+		assert_eq!(decoded.close_authority, Some(close_authority));
+
+		let value = serde_json::to_value(&decoded).unwrap();
+		assert_eq!(value["token_owner"], owner.to_string());
+		assert_eq!(value["delegate"], serde_json::Value::Null);
+		assert_eq!(value["close_authority"], close_authority.to_string());
+	}
+
+	#[test]
+	fn test_decode_token_2022_account_with_extensions() {
+		let account = create_token_account(Pubkey::new_unique(), None, None);
+		let mut data = pack(account);
+		data.extend_from_slice(&[TOKEN_2022_ACCOUNT_TYPE, 0, 0]);
+		assert!(TokenAccount::decode(&data).is_some());
+
+		data[SplTokenAccount::LEN] = 1;
+		assert!(TokenAccount::decode(&data).is_none());
+	}
+
+	#[test]
+	fn test_decode_invalid_data() {
+		assert!(TokenAccount::decode(&[0; 82]).is_none());
+		assert!(TokenAccount::decode(&[0; SplTokenAccount::LEN]).is_none());
+	}
+
+	#[test]
+	fn test_decode_rejects_other_program() {
+		let account = Account {
+			lamports: 1,
+			data: pack(create_token_account(Pubkey::new_unique(), None, None)),
+			owner: Pubkey::new_unique(),
+			executable: false,
+			rent_epoch: 0,
+		};
+		assert!(TokenAccountDecoder.decode_account(&account).is_none());
+
+		let account = Account {
+			owner: TOKEN_PROGRAM_ID,
+			..account
+		};
+		assert!(matches!(
+			TokenAccountDecoder.decode_account(&account).unwrap().data,
+			AccountType::TokenAccount(_)
+		));
+	}
+}
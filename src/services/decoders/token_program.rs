@@ -0,0 +1,290 @@
+//! Decoder for the instructions of the SPL Token program.
+//!
+//! Token program instructions move, mint and burn tokens, and hand over the authorities
+//! of mints and token accounts. Decoding them allows monitors to match transfers of a
+//! mint or delegations of token accounts without declaring a contract spec.
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_token::instruction::{AuthorityType, TokenInstruction};
+
+use crate::services::decoders::{
+	serde_helpers::{option_pubkey_base58, pubkey_base58},
+	token_account::TOKEN_PROGRAM_ID,
+	variant_names, DecodedInstruction, InstructionDecoder, InstructionType,
+};
+
+/// Decoder for SPL Token program instructions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenProgramDecoder;
+
+/// Authority of a mint or token account changed by `SetAuthority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenAuthorityType {
+	MintTokens,
+	FreezeAccount,
+	AccountOwner,
+	CloseAccount,
+}
+
+/// Instructions of the SPL Token program
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenProgramInstruction {
+	InitializeMint {
+		decimals: u8,
+		#[serde(with = "pubkey_base58")]
+		mint_authority: Pubkey,
+		#[serde(with = "option_pubkey_base58")]
+		freeze_authority: Option<Pubkey>,
+	},
+	InitializeAccount {},
+	InitializeMultisig {
+		m: u8,
+	},
+	Transfer {
+		amount: u64,
+	},
+	Approve {
+		amount: u64,
+	},
+	Revoke {},
+	SetAuthority {
+		authority_type: TokenAuthorityType,
+		#[serde(with = "option_pubkey_base58")]
+		new_authority: Option<Pubkey>,
+	},
+	MintTo {
+		amount: u64,
+	},
+	Burn {
+		amount: u64,
+	},
+	CloseAccount {},
+	FreezeAccount {},
+	ThawAccount {},
+	TransferChecked {
+		amount: u64,
+		decimals: u8,
+	},
+	ApproveChecked {
+		amount: u64,
+		decimals: u8,
+	},
+	MintToChecked {
+		amount: u64,
+		decimals: u8,
+	},
+	BurnChecked {
+		amount: u64,
+		decimals: u8,
+	},
+	InitializeAccount2 {
+		#[serde(with = "pubkey_base58")]
+		owner: Pubkey,
+	},
+	SyncNative {},
+	InitializeAccount3 {
+		#[serde(with = "pubkey_base58")]
+		owner: Pubkey,
+	},
+	InitializeMultisig2 {
+		m: u8,
+	},
+	InitializeMint2 {
+		decimals: u8,
+		#[serde(with = "pubkey_base58")]
+		mint_authority: Pubkey,
+		#[serde(with = "option_pubkey_base58")]
+		freeze_authority: Option<Pubkey>,
+	},
+	GetAccountDataSize {},
+	InitializeImmutableOwner {},
+	AmountToUiAmount {
+		amount: u64,
+	},
+	UiAmountToAmount {
+		ui_amount: String,
+	},
+}
+
+impl From<AuthorityType> for TokenAuthorityType {
+	fn from(authority_type: AuthorityType) -> Self {
+		match authority_type {
+			AuthorityType::MintTokens => Self::MintTokens,
+			AuthorityType::FreezeAccount => Self::FreezeAccount,
+			AuthorityType::AccountOwner => Self::AccountOwner,
+			AuthorityType::CloseAccount => Self::CloseAccount,
+		}
+	}
+}
+
+impl TokenProgramInstruction {
+	/// Decodes SPL Token instruction data
+	///
+	/// # Arguments
+	/// * `data` - Raw instruction data, starting with the instruction tag
+	///
+	/// # Returns
+	/// The decoded instruction, or `None` if the data is not a valid instruction
+	pub fn decode(data: &[u8]) -> Option<Self> {
+		let instruction = match TokenInstruction::unpack(data).ok()? {
+			TokenInstruction::InitializeMint {
+				decimals,
+				mint_authority,
+				freeze_authority,
+			} => Self::InitializeMint {
+				decimals,
+				mint_authority,
+				freeze_authority: freeze_authority.into(),
+			},
+			TokenInstruction::InitializeAccount => Self::InitializeAccount {},
+			TokenInstruction::InitializeMultisig { m } => Self::InitializeMultisig { m },
+			TokenInstruction::Transfer { amount } => Self::Transfer { amount },
+			TokenInstruction::Approve { amount } => Self::Approve { amount },
+			TokenInstruction::Revoke => Self::Revoke {},
+			TokenInstruction::SetAuthority {
+				authority_type,
+				new_authority,
+			} => Self::SetAuthority {
+				authority_type: authority_type.into(),
+				new_authority: new_authority.into(),
+			},
+			TokenInstruction::MintTo { amount } => Self::MintTo { amount },
+			TokenInstruction::Burn { amount } => Self::Burn { amount },
+			TokenInstruction::CloseAccount => Self::CloseAccount {},
+			TokenInstruction::FreezeAccount => Self::FreezeAccount {},
+			TokenInstruction::ThawAccount => Self::ThawAccount {},
+			TokenInstruction::TransferChecked { amount, decimals } => {
+				Self::TransferChecked { amount, decimals }
+			}
+			TokenInstruction::ApproveChecked { amount, decimals } => {
+				Self::ApproveChecked { amount, decimals }
+			}
+			TokenInstruction::MintToChecked { amount, decimals } => {
+				Self::MintToChecked { amount, decimals }
+			}
+			TokenInstruction::BurnChecked { amount, decimals } => {
+				Self::BurnChecked { amount, decimals }
+			}
+			TokenInstruction::InitializeAccount2 { owner } => Self::InitializeAccount2 { owner },
+			TokenInstruction::SyncNative => Self::SyncNative {},
+			TokenInstruction::InitializeAccount3 { owner } => Self::InitializeAccount3 { owner },
+			TokenInstruction::InitializeMultisig2 { m } => Self::InitializeMultisig2 { m },
+			TokenInstruction::InitializeMint2 {
+				decimals,
+				mint_authority,
+				freeze_authority,
+			} => Self::InitializeMint2 {
+				decimals,
+				mint_authority,
+				freeze_authority: freeze_authority.into(),
+			},
+			TokenInstruction::GetAccountDataSize => Self::GetAccountDataSize {},
+			TokenInstruction::InitializeImmutableOwner => Self::InitializeImmutableOwner {},
+			TokenInstruction::AmountToUiAmount { amount } => Self::AmountToUiAmount { amount },
+			TokenInstruction::UiAmountToAmount { ui_amount } => Self::UiAmountToAmount {
+				ui_amount: ui_amount.to_string(),
+			},
+		};
+		Some(instruction)
+	}
+}
+
+impl InstructionDecoder<'_> for TokenProgramDecoder {
+	type InstructionType = InstructionType;
+
+	fn decode_instruction(
+		&self,
+		instruction: &Instruction,
+	) -> Option<DecodedInstruction<Self::InstructionType>> {
+		if instruction.program_id != TOKEN_PROGRAM_ID {
+			return None;
+		}
+
+		Some(DecodedInstruction {
+			program_id: instruction.program_id,
+			data: InstructionType::TokenProgram(TokenProgramInstruction::decode(
+				&instruction.data,
+			)?),
+			accounts: instruction.accounts.clone(),
+		})
+	}
+
+	fn instruction_names(&self) -> &[&str] {
+		variant_names::TOKEN_PROGRAM_INSTRUCTION_NAMES
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn create_instruction(program_id: Pubkey, data: Vec<u8>) -> Instruction {
+		Instruction {
+			program_id,
+			accounts: vec![],
+			data,
+		}
+	}
+
+	#[test]
+	fn test_decode_transfer_checked() {
+		let mut data = vec![12];
+		data.extend_from_slice(&1_500u64.to_le_bytes());
+		data.push(6);
+
+		let decoded = TokenProgramDecoder
+			.decode_instruction(&create_instruction(TOKEN_PROGRAM_ID, data))
+			.unwrap();
+		assert_eq!(
+			decoded.data,
+			InstructionType::TokenProgram(TokenProgramInstruction::TransferChecked {
+				amount: 1_500,
+				decimals: 6,
+			})
+		);
+		assert_eq!(
+			decoded.data.variant_name(),
+			Some("TransferChecked".to_string())
+		);
+		assert_eq!(
+			decoded.data.account_names(),
+			vec!["source", "mint", "destination", "authority"]
+		);
+	}
+
+	#[test]
+	fn test_decode_set_authority() {
+		let new_authority = Pubkey::new_unique();
+		let mut data = vec![6, 2, 1];
+		data.extend_from_slice(new_authority.as_ref());
+
+		let instruction = TokenProgramInstruction::decode(&data).unwrap();
+		assert_eq!(
+			instruction,
+			TokenProgramInstruction::SetAuthority {
+				authority_type: TokenAuthorityType::AccountOwner,
+				new_authority: Some(new_authority),
+			}
+		);
+		assert_eq!(
+			serde_json::to_value(instruction).unwrap(),
+			serde_json::json!({
+				"SetAuthority": {
+					"authority_type": "AccountOwner",
+					"new_authority": new_authority.to_string(),
+				}
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_invalid_data() {
+		assert_eq!(TokenProgramInstruction::decode(&[]), None);
+		assert_eq!(TokenProgramInstruction::decode(&[3, 1]), None);
+		assert_eq!(TokenProgramInstruction::decode(&[255]), None);
+		assert!(TokenProgramDecoder
+			.decode_instruction(&create_instruction(Pubkey::new_unique(), vec![9]))
+			.is_none());
+	}
+}
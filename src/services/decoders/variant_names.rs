@@ -0,0 +1,162 @@
+//! Variant names of the instructions and accounts decoded by the built-in decoders.
+//!
+//! Decoded instructions and accounts expose their variant name as the signature that
+//! monitor conditions match against. These tables list the variants of each decoder so
+//! monitor configurations can be checked against them before any block is processed.
+
+/// Instructions decoded by the Address Lookup Table decoder
+pub(crate) const ADDRESS_LOOKUP_TABLE_INSTRUCTION_NAMES: &[&str] = &[
+	"CreateLookupTable",
+	"FreezeLookupTable",
+	"ExtendLookupTable",
+	"DeactivateLookupTable",
+	"CloseLookupTable",
+];
+
+/// Instructions decoded by the BPF Upgradeable Loader decoder
+pub(crate) const BPF_UPGRADEABLE_LOADER_INSTRUCTION_NAMES: &[&str] = &[
+	"InitializeBuffer",
+	"Write",
+	"DeployWithMaxDataLen",
+	"Upgrade",
+	"SetAuthority",
+	"Close",
+	"ExtendProgram",
+	"SetAuthorityChecked",
+];
+
+/// Instructions decoded by the Kamino Lending decoder
+pub(crate) const KAMINO_LENDING_INSTRUCTION_NAMES: &[&str] = &[
+	"InitLendingMarket",
+	"UpdateLendingMarket",
+	"UpdateLendingMarketOwner",
+	"InitReserve",
+	"InitFarmsForReserve",
+	"UpdateReserveConfig",
+	"RedeemFees",
+	"WithdrawProtocolFee",
+	"SocializeLoss",
+	"MarkObligationForDeleveraging",
+	"RefreshReserve",
+	"RefreshReservesBatch",
+	"DepositReserveLiquidity",
+	"RedeemReserveCollateral",
+	"InitObligation",
+	"InitObligationFarmsForReserve",
+	"RefreshObligationFarmsForReserve",
+	"RefreshObligation",
+	"DepositObligationCollateral",
+	"WithdrawObligationCollateral",
+	"BorrowObligationLiquidity",
+	"RepayObligationLiquidity",
+	"RepayAndWithdrawAndRedeem",
+	"DepositReserveLiquidityAndObligationCollateral",
+	"WithdrawObligationCollateralAndRedeemReserveCollateral",
+	"LiquidateObligationAndRedeemReserveCollateral",
+	"FlashRepayReserveLiquidity",
+	"FlashBorrowReserveLiquidity",
+	"RequestElevationGroup",
+	"InitReferrerTokenState",
+	"InitUserMetadata",
+	"WithdrawReferrerFees",
+	"InitReferrerStateAndShortUrl",
+	"DeleteReferrerStateAndShortUrl",
+	"IdlMissingTypes",
+];
+
+/// Accounts decoded by the Kamino Lending decoder
+pub(crate) const KAMINO_LENDING_ACCOUNT_NAMES: &[&str] = &[
+	"UserState",
+	"LendingMarket",
+	"Obligation",
+	"ReferrerState",
+	"ReferrerTokenState",
+	"ShortUrl",
+	"UserMetadata",
+	"Reserve",
+];
+
+/// Instructions decoded by the Name Service decoder
+pub(crate) const NAME_SERVICE_INSTRUCTION_NAMES: &[&str] =
+	&["Create", "Update", "Transfer", "Delete", "Realloc"];
+
+/// Instructions decoded by the OKX DEX decoder
+pub(crate) const OKX_DEX_INSTRUCTION_NAMES: &[&str] = &[
+	"CommissionSolFromSwap",
+	"CommissionSolProxySwap",
+	"CommissionSolSwap",
+	"CommissionSolSwap2",
+	"CommissionSplFromSwap",
+	"CommissionSplProxySwap",
+	"CommissionSplSwap",
+	"CommissionSplSwap2",
+	"FromSwapLog",
+	"ProxySwap",
+	"Swap",
+	"Swap2",
+	"SwapEvent",
+];
+
+/// Instructions decoded by the Sharky decoder
+pub(crate) const SHARKY_INSTRUCTION_NAMES: &[&str] = &[
+	"CloseOrderBook",
+	"CreateOrderBook",
+	"ExtendLoanV3",
+	"ForecloseLoanV3",
+	"OfferLoan",
+	"RepayLoanV3",
+	"RescindLoan",
+	"TakeLoanV3",
+	"UpdateOrderBook",
+];
+
+/// Instructions decoded by the Squads decoder
+pub(crate) const SQUADS_INSTRUCTION_NAMES: &[&str] = &[
+	"MultisigCreateV2",
+	"MultisigAddMember",
+	"MultisigRemoveMember",
+	"MultisigChangeThreshold",
+	"VaultTransactionCreate",
+	"ProposalCreate",
+	"ProposalActivate",
+	"ProposalApprove",
+	"ProposalReject",
+	"ProposalCancel",
+	"VaultTransactionExecute",
+	"ConfigTransactionExecute",
+];
+
+/// Accounts decoded by the Squads decoder
+pub(crate) const SQUADS_ACCOUNT_NAMES: &[&str] = &["Multisig", "Proposal"];
+
+/// Instructions decoded by the SPL Token decoder
+pub(crate) const TOKEN_PROGRAM_INSTRUCTION_NAMES: &[&str] = &[
+	"InitializeMint",
+	"InitializeAccount",
+	"InitializeMultisig",
+	"Transfer",
+	"Approve",
+	"Revoke",
+	"SetAuthority",
+	"MintTo",
+	"Burn",
+	"CloseAccount",
+	"FreezeAccount",
+	"ThawAccount",
+	"TransferChecked",
+	"ApproveChecked",
+	"MintToChecked",
+	"BurnChecked",
+	"InitializeAccount2",
+	"SyncNative",
+	"InitializeAccount3",
+	"InitializeMultisig2",
+	"InitializeMint2",
+	"GetAccountDataSize",
+	"InitializeImmutableOwner",
+	"AmountToUiAmount",
+	"UiAmountToAmount",
+];
+
+/// Accounts decoded by the token account decoder
+pub(crate) const TOKEN_ACCOUNT_NAMES: &[&str] = &["TokenAccount"];
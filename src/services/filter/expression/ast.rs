@@ -18,6 +18,19 @@ pub enum LiteralValue<'a> {
 	/// Store as string slice to preserve original form until evaluation phase.
 	/// Conversion to specific type is done within chain context during evaluation.
 	Number(&'a str),
+	/// A list of literal values, used with the `in` operator. e.g., "['abc', 'def']", "[1, 2, 3]"
+	List(Vec<LiteralValue<'a>>),
+	/// The evaluation time as seconds since the Unix epoch, shifted by an offset in seconds.
+	/// e.g., "now()", "now() - 1h", "now() - 1d + 30m"
+	/// Resolved to a `Number` when the expression is evaluated.
+	Now(i64),
+	/// A duration literal in seconds. e.g., "30s", "15m", "1h", "7d", "2w"
+	/// Resolved to a `Number` when the expression is evaluated.
+	Duration(i64),
+	/// Raw bytes written in base58. e.g., "b58'3URvB'"
+	/// Resolved to the hex `Str` of the bytes (e.g., "0x01ab03c4") when the expression is
+	/// evaluated.
+	Base58(&'a str),
 }
 
 /// Represents the possible comparison operators that can be used in filter expressions.
@@ -42,6 +55,16 @@ pub enum ComparisonOperator {
 	EndsWith,
 	/// - Contains: Checks if the string/collection contains a given item.
 	Contains,
+	/// Regex operator (matches): Checks if the string matches a regular expression.
+	Matches,
+	/// List membership operator (in): Checks if the value equals any item of a list.
+	In,
+	/// Null check operator (is null): Checks if the value is absent, e.g. an unset `Option`.
+	/// The right-hand side of the condition is always `LiteralValue::Str("null")`.
+	IsNull,
+	/// Negated null check operator (is not null): Checks if the value is present.
+	/// The right-hand side of the condition is always `LiteralValue::Str("null")`.
+	IsNotNull,
 }
 
 /// Represents the possible logical operators that can be used in filter expressions.
@@ -53,6 +76,30 @@ pub enum LogicalOperator {
 	Or,
 }
 
+/// Represents the possible arithmetic operators that can be applied to the left side of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticOperator {
+	/// Addition operator (+)
+	Add,
+	/// Subtraction operator (-)
+	Sub,
+	/// Multiplication operator (*)
+	Mul,
+	/// Division operator (/)
+	Div,
+}
+
+/// Represents the aggregate functions that can be applied to an array on the left side of a condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFunction {
+	/// Checks if the condition holds for any item (any)
+	Any,
+	/// Checks if the condition holds for all items (all)
+	All,
+	/// Number of items of an array or entries of an object (len)
+	Len,
+}
+
 /// Represents the possible accessors that can be used in filter expressions.
 /// Accessors are used to access elements in collections or properties in objects.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -78,6 +125,31 @@ pub enum ConditionLeft<'a> {
 	Simple(&'a str),
 	/// A sequence of accessors that form a path to a variable (e.g., "person.name", "person[0].age", etc.)
 	Path(VariablePath<'a>),
+	/// A variable or path with arithmetic applied to it (e.g., "amount / 1e9", "fee * 2 + 10")
+	Arithmetic(ArithmeticExpression<'a>),
+	/// An aggregate function applied to an array variable or path (e.g., "any(hops.amount_in)", "len(hops)")
+	Aggregate(AggregateExpression<'a>),
+}
+
+/// Represents an aggregate function applied to an array on the left side of a condition.
+/// For `any` and `all`, key accessors applied to an array are applied to each of its items,
+/// so "any(hops.amount_in)" aggregates the `amount_in` of every hop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregateExpression<'a> {
+	/// The aggregate function
+	pub function: AggregateFunction,
+	/// The variable or path the function is applied to
+	pub operand: Box<ConditionLeft<'a>>,
+}
+
+/// Represents arithmetic applied to a numeric variable on the left side of a condition.
+/// Operations are evaluated with the usual precedence: `*` and `/` before `+` and `-`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArithmeticExpression<'a> {
+	/// The variable or path the arithmetic is applied to
+	pub operand: Box<ConditionLeft<'a>>,
+	/// The operators and numeric literal operands, in the order they appear (e.g., "1000000000", "1e9")
+	pub operations: Vec<(ArithmeticOperator, &'a str)>,
 }
 
 impl<'a> ConditionLeft<'a> {
@@ -86,6 +158,8 @@ impl<'a> ConditionLeft<'a> {
 		match self {
 			ConditionLeft::Simple(name) => name,
 			ConditionLeft::Path(path) => path.base,
+			ConditionLeft::Arithmetic(arithmetic) => arithmetic.operand.base_name(),
+			ConditionLeft::Aggregate(aggregate) => aggregate.operand.base_name(),
 		}
 	}
 
@@ -97,6 +171,17 @@ impl<'a> ConditionLeft<'a> {
 		match self {
 			ConditionLeft::Simple(_) => &[],
 			ConditionLeft::Path(path) => &path.accessors,
+			ConditionLeft::Arithmetic(arithmetic) => arithmetic.operand.accessors(),
+			ConditionLeft::Aggregate(aggregate) => aggregate.operand.accessors(),
+		}
+	}
+
+	/// Helper method to get the arithmetic operations applied to the variable.
+	/// Returns an empty slice if no arithmetic is applied.
+	pub fn arithmetic(&self) -> &[(ArithmeticOperator, &'a str)] {
+		match self {
+			ConditionLeft::Arithmetic(arithmetic) => &arithmetic.operations,
+			_ => &[],
 		}
 	}
 }
@@ -130,4 +215,6 @@ pub enum Expression<'a> {
 		/// The right side sub-expression.
 		right: Box<Expression<'a>>,
 	},
+	/// A negated expression (e.g., "NOT (age > 30)")
+	Not(Box<Expression<'a>>),
 }
@@ -0,0 +1,127 @@
+//! Cache of the parsed expressions of monitors.
+//!
+//! Expressions are evaluated for every matching transaction of every block, so each
+//! expression is parsed once and its AST is kept in a cache shared by all filters.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+
+use super::{
+	ast::Expression, error::EvaluationError, evaluation::ConditionEvaluator, helpers::evaluate,
+	parsing::parse,
+};
+
+lazy_static! {
+	static ref SHARED_EXPRESSION_CACHE: Arc<ExpressionCache> = Arc::new(ExpressionCache::default());
+}
+
+/// Parsed expressions, keyed by their source
+///
+/// The AST borrows from its source, so the source of each cached expression is leaked
+/// once. Monitor expressions live for the whole process, which bounds the leaked memory
+/// by the set of distinct expressions.
+#[derive(Debug, Default)]
+pub struct ExpressionCache {
+	expressions: RwLock<HashMap<String, Arc<Expression<'static>>>>,
+}
+
+impl ExpressionCache {
+	/// Returns the cache shared by all filters
+	pub fn shared() -> Arc<Self> {
+		SHARED_EXPRESSION_CACHE.clone()
+	}
+
+	/// Returns the AST of an expression, parsing it on first use
+	///
+	/// Expressions that fail to parse are not cached, the error describes the failure.
+	pub fn get_or_parse(&self, expression: &str) -> Result<Arc<Expression<'static>>, String> {
+		if let Some(parsed) = self
+			.expressions
+			.read()
+			.ok()
+			.and_then(|expressions| expressions.get(expression).cloned())
+		{
+			return Ok(parsed);
+		}
+
+		// Only the source of valid expressions is leaked
+		parse(expression).map_err(|e| e.to_string())?;
+		let source: &'static str = Box::leak(expression.to_string().into_boxed_str());
+		let parsed = Arc::new(parse(source).map_err(|e| e.to_string())?);
+
+		if let Ok(mut expressions) = self.expressions.write() {
+			return Ok(expressions
+				.entry(expression.to_string())
+				.or_insert(parsed)
+				.clone());
+		}
+		Ok(parsed)
+	}
+
+	/// Parses an expression, or reuses its cached AST, and evaluates it with a
+	/// chain-specific evaluator
+	///
+	/// Returns a `ParseError` if the expression is empty or cannot be parsed
+	pub fn evaluate(
+		&self,
+		expression: &str,
+		evaluator: &impl ConditionEvaluator,
+	) -> Result<bool, EvaluationError> {
+		if expression.trim().is_empty() {
+			tracing::error!("Empty expression provided for evaluation");
+			return Err(EvaluationError::parse_error(
+				"Expression cannot be empty".to_string(),
+				None,
+				None,
+			));
+		}
+
+		let parsed_ast = self.get_or_parse(expression).map_err(|e| {
+			tracing::error!("Failed to parse expression '{}': {}", expression, e);
+			let msg = format!("Failed to parse expression '{}': {}", expression, e);
+			EvaluationError::parse_error(msg, None, None)
+		})?;
+		tracing::debug!("Parsed AST for '{}': {:?}", expression, parsed_ast);
+
+		evaluate(&parsed_ast, evaluator)
+	}
+
+	/// Returns the number of cached expressions
+	pub fn len(&self) -> usize {
+		self.expressions
+			.read()
+			.map(|expressions| expressions.len())
+			.unwrap_or(0)
+	}
+
+	/// Returns true if no expression is cached
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_get_or_parse_caches_valid_expressions() {
+		let cache = ExpressionCache::default();
+
+		let first = cache
+			.get_or_parse("amount > 100 AND mint == 'abc'")
+			.unwrap();
+		let second = cache
+			.get_or_parse("amount > 100 AND mint == 'abc'")
+			.unwrap();
+		assert!(Arc::ptr_eq(&first, &second));
+		assert_eq!(cache.len(), 1);
+
+		assert!(cache.get_or_parse("amount >").is_err());
+		assert_eq!(cache.len(), 1);
+	}
+}
@@ -1,7 +1,14 @@
 //! Utility functions for evaluating expressions and resolving JSON paths
 
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
 use super::{
-	ast::{Accessor, ComparisonOperator, ConditionLeft, Expression, LogicalOperator},
+	ast::{
+		Accessor, AggregateExpression, AggregateFunction, ArithmeticOperator, ComparisonOperator,
+		ConditionLeft, Expression, LiteralValue, LogicalOperator,
+	},
 	error::EvaluationError,
 	evaluation::ConditionEvaluator,
 };
@@ -15,6 +22,25 @@ pub fn evaluate(
 ) -> Result<bool, EvaluationError> {
 	match expression {
 		Expression::Condition(condition) => {
+			// Time, duration and base58 literals are resolved to plain numbers and hex
+			// strings, so chain evaluators only ever compare against regular literals
+			let resolved = resolve_literal(&condition.right)?;
+			let resolved_right;
+			let right = match &resolved {
+				Some(value) => {
+					resolved_right = match condition.right {
+						LiteralValue::Base58(_) => LiteralValue::Str(value),
+						_ => LiteralValue::Number(value),
+					};
+					&resolved_right
+				}
+				None => &condition.right,
+			};
+
+			if let ConditionLeft::Aggregate(aggregate) = &condition.left {
+				return evaluate_aggregate(evaluator, aggregate, &condition.operator, right);
+			}
+
 			let base_name = condition.left.base_name();
 			let accessors = condition.left.accessors();
 			let (base_value_str, base_kind_str) = evaluator.get_base_param(base_name)?;
@@ -39,23 +65,22 @@ pub fn evaluate(
 				final_left_kind = evaluator.get_kind_from_json_value(&resolved_value);
 
 				// Convert the resolved JSON value to a string representation
-				final_left_value_str = match resolved_value {
-					serde_json::Value::String(s) => s,
-					serde_json::Value::Number(n) => n.to_string(),
-					serde_json::Value::Bool(b) => b.to_string(),
-					serde_json::Value::Null => "null".to_string(),
-					serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-						// If the resolved value is an array or object, we need to convert it to a string
-						resolved_value.to_string()
-					}
-				};
+				final_left_value_str = json_value_to_string(resolved_value);
 			}
 
-			evaluator.compare_final_values(
+			let operations = condition.left.arithmetic();
+			if !operations.is_empty() {
+				// Arithmetic results are chain independent decimals
+				let result = apply_arithmetic(&final_left_value_str, operations)?;
+				return compare_decimal_result(&result, &condition.operator, right);
+			}
+
+			compare_value(
+				evaluator,
 				&final_left_kind,
 				&final_left_value_str,
 				&condition.operator,
-				&condition.right,
+				right,
 			)
 		}
 		Expression::Logical {
@@ -81,6 +106,33 @@ pub fn evaluate(
 				}
 			}
 		}
+		Expression::Not(inner) => Ok(!evaluate(inner, evaluator)?),
+	}
+}
+
+/// Resolves `now()` and duration literals to their number of seconds, and base58 literals
+/// to the hex string of their bytes
+/// Returns None for any other literal, which is compared as written
+/// Returns an error if the resolved time overflows or the base58 literal is invalid
+fn resolve_literal(literal: &LiteralValue<'_>) -> Result<Option<String>, EvaluationError> {
+	match literal {
+		LiteralValue::Now(offset) => chrono::Utc::now()
+			.timestamp()
+			.checked_add(*offset)
+			.map(|seconds| Some(seconds.to_string()))
+			.ok_or_else(|| {
+				let msg = format!("Time offset of {} seconds is out of range", offset);
+				EvaluationError::type_mismatch(msg, None, None)
+			}),
+		LiteralValue::Duration(seconds) => Ok(Some(seconds.to_string())),
+		LiteralValue::Base58(encoded) => solana_sdk::bs58::decode(encoded)
+			.into_vec()
+			.map(|bytes| Some(format!("0x{}", hex::encode(bytes))))
+			.map_err(|e| {
+				let msg = format!("Invalid base58 literal '{}'", encoded);
+				EvaluationError::parse_error(msg, Some(e.into()), None)
+			}),
+		_ => Ok(None),
 	}
 }
 
@@ -111,6 +163,237 @@ pub fn compare_ordered_values<T: Ord>(
 	}
 }
 
+/// Converts a resolved JSON value to the string representation used for comparisons
+fn json_value_to_string(value: serde_json::Value) -> String {
+	match value {
+		serde_json::Value::String(s) => s,
+		serde_json::Value::Number(n) => n.to_string(),
+		serde_json::Value::Bool(b) => b.to_string(),
+		serde_json::Value::Null => "null".to_string(),
+		serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+			// If the resolved value is an array or object, we need to convert it to a string
+			value.to_string()
+		}
+	}
+}
+
+/// Compares a resolved value with a literal or, for the `in` operator, a list of literals
+/// Null checks only depend on the kind of the value
+fn compare_value(
+	evaluator: &impl ConditionEvaluator,
+	left_kind: &str,
+	left_value: &str,
+	operator: &ComparisonOperator,
+	right: &LiteralValue<'_>,
+) -> Result<bool, EvaluationError> {
+	match operator {
+		ComparisonOperator::IsNull => return Ok(is_null_kind(left_kind)),
+		ComparisonOperator::IsNotNull => return Ok(!is_null_kind(left_kind)),
+		_ => {}
+	}
+
+	if let LiteralValue::List(items) = right {
+		return evaluate_in_list(evaluator, left_kind, left_value, operator, items);
+	}
+
+	evaluator.compare_final_values(left_kind, left_value, operator, right)
+}
+
+/// Returns true if the kind is the one chains give to absent values
+/// (`null` on EVM and Solana, `Null` or `Void` on Stellar)
+fn is_null_kind(kind: &str) -> bool {
+	kind.eq_ignore_ascii_case("null") || kind.eq_ignore_ascii_case("void")
+}
+
+/// Evaluates an aggregate function applied to an array and compares its result
+/// `len` compares the number of items, `any` and `all` compare each item with the literal
+/// Returns an error if the path does not resolve to an array, or an item cannot be compared
+fn evaluate_aggregate(
+	evaluator: &impl ConditionEvaluator,
+	aggregate: &AggregateExpression<'_>,
+	operator: &ComparisonOperator,
+	right: &LiteralValue<'_>,
+) -> Result<bool, EvaluationError> {
+	let operand = aggregate.operand.as_ref();
+	let base_name = operand.base_name();
+	let (base_value_str, base_kind_str) = evaluator.get_base_param(base_name)?;
+	let base_value = parse_base_value(base_value_str, base_kind_str, base_name, operand)?;
+	let path_segments = build_path_segments(base_name, operand.accessors());
+
+	if aggregate.function == AggregateFunction::Len {
+		let mut value = base_value;
+		for (accessor, path_segment) in operand.accessors().iter().zip(&path_segments) {
+			value = access_json_value(value, accessor, path_segment)?;
+		}
+
+		let len = match &value {
+			serde_json::Value::Array(items) => items.len(),
+			serde_json::Value::Object(map) => map.len(),
+			_ => {
+				let msg = format!("len() requires an array or object, found: {}", value);
+				return Err(EvaluationError::type_mismatch(msg, None, None));
+			}
+		};
+		return compare_decimal_result(&Decimal::from(len), operator, right);
+	}
+
+	let items = collect_aggregate_items(base_value, operand.accessors(), &path_segments)?;
+	for item in items {
+		let kind = evaluator.get_kind_from_json_value(&item);
+		let matches = compare_value(
+			evaluator,
+			&kind,
+			&json_value_to_string(item),
+			operator,
+			right,
+		)?;
+
+		match aggregate.function {
+			AggregateFunction::Any if matches => return Ok(true),
+			AggregateFunction::All if !matches => return Ok(false),
+			_ => {}
+		}
+	}
+
+	// No item matched for `any`, or every item matched for `all`
+	Ok(aggregate.function == AggregateFunction::All)
+}
+
+/// Collects the items aggregated by `any` and `all`
+/// Key accessors applied to an array are applied to each of its items, and the items of
+/// the arrays the path resolves to are aggregated
+fn collect_aggregate_items(
+	base_value: serde_json::Value,
+	accessors: &[Accessor],
+	path_segments: &[String],
+) -> Result<Vec<serde_json::Value>, EvaluationError> {
+	let mut values = vec![base_value];
+
+	for (accessor, path_segment) in accessors.iter().zip(path_segments) {
+		let mut next_values = Vec::with_capacity(values.len());
+		for value in values {
+			match (accessor, value) {
+				(Accessor::Key(key), serde_json::Value::Array(items))
+					if key.parse::<usize>().is_err() =>
+				{
+					for item in items {
+						next_values.push(access_json_value(item, accessor, path_segment)?);
+					}
+				}
+				(_, value) => next_values.push(access_json_value(value, accessor, path_segment)?),
+			}
+		}
+		values = next_values;
+	}
+
+	Ok(values
+		.into_iter()
+		.flat_map(|value| match value {
+			serde_json::Value::Array(items) => items,
+			other => vec![other],
+		})
+		.collect())
+}
+
+/// Evaluates list membership by comparing the value for equality with each item of the list
+/// Equality follows the chain-specific comparison of the value's kind
+/// Returns an error if the operator is not `in` or an item cannot be compared with the value
+fn evaluate_in_list(
+	evaluator: &impl ConditionEvaluator,
+	left_kind: &str,
+	left_value: &str,
+	operator: &ComparisonOperator,
+	items: &[LiteralValue<'_>],
+) -> Result<bool, EvaluationError> {
+	if *operator != ComparisonOperator::In {
+		let msg = format!("Operator {:?} not supported for list values", operator);
+		return Err(EvaluationError::unsupported_operator(msg, None, None));
+	}
+
+	for item in items {
+		if evaluator.compare_final_values(left_kind, left_value, &ComparisonOperator::Eq, item)? {
+			return Ok(true);
+		}
+	}
+	Ok(false)
+}
+
+/// Parses a decimal from plain (e.g., "1.5") or power-of-ten (e.g., "1e9") notation
+fn parse_decimal(value: &str) -> Result<Decimal, EvaluationError> {
+	Decimal::from_str(value)
+		.or_else(|_| Decimal::from_scientific(value))
+		.map_err(|e| {
+			let msg = format!("Failed to parse '{}' as a number for arithmetic", value);
+			EvaluationError::parse_error(msg, Some(e.into()), None)
+		})
+}
+
+/// Applies arithmetic operations to a numeric value
+/// Multiplication and division are applied before addition and subtraction
+/// Returns an error if a value is not numeric, on overflow or on division by zero
+fn apply_arithmetic(
+	value: &str,
+	operations: &[(ArithmeticOperator, &str)],
+) -> Result<Decimal, EvaluationError> {
+	let overflow = |operator: &ArithmeticOperator, operand: &str| {
+		let msg = format!(
+			"Arithmetic {:?} with '{}' on value '{}' overflowed or divided by zero",
+			operator, operand, value
+		);
+		EvaluationError::type_mismatch(msg, None, None)
+	};
+
+	let mut sum = Decimal::ZERO;
+	let mut term = parse_decimal(value)?;
+	for (operator, operand_str) in operations {
+		let operand = parse_decimal(operand_str)?;
+		match operator {
+			ArithmeticOperator::Mul => {
+				term = term
+					.checked_mul(operand)
+					.ok_or_else(|| overflow(operator, operand_str))?;
+			}
+			ArithmeticOperator::Div => {
+				term = term
+					.checked_div(operand)
+					.ok_or_else(|| overflow(operator, operand_str))?;
+			}
+			ArithmeticOperator::Add | ArithmeticOperator::Sub => {
+				sum = sum
+					.checked_add(term)
+					.ok_or_else(|| overflow(operator, operand_str))?;
+				term = if *operator == ArithmeticOperator::Add {
+					operand
+				} else {
+					-operand
+				};
+			}
+		}
+	}
+
+	sum.checked_add(term).ok_or_else(|| {
+		let msg = format!("Arithmetic on value '{}' overflowed", value);
+		EvaluationError::type_mismatch(msg, None, None)
+	})
+}
+
+/// Compares the decimal result of arithmetic with a number literal
+fn compare_decimal_result(
+	result: &Decimal,
+	operator: &ComparisonOperator,
+	right: &LiteralValue<'_>,
+) -> Result<bool, EvaluationError> {
+	let LiteralValue::Number(right_str) = right else {
+		let msg = format!(
+			"Expected number literal for arithmetic comparison, found: {:?}",
+			right
+		);
+		return Err(EvaluationError::type_mismatch(msg, None, None));
+	};
+
+	compare_ordered_values(result, operator, &parse_decimal(right_str)?)
+}
+
 /// Resolves a JSON path from a base variable name and accessors
 /// Returns the resolved JSON value
 /// Returns an error if the traversal fails
@@ -197,6 +480,11 @@ fn access_json_value(
 			})
 		}
 		Accessor::Key(key) => {
+			// A numeric key on an array is an index, so `params.0` equals `params[0]`
+			if let (true, Ok(idx)) = (current_json.is_array(), key.parse::<usize>()) {
+				return access_json_value(current_json, &Accessor::Index(idx), path_segment);
+			}
+
 			let obj = current_json.as_object().ok_or_else(|| {
 				let msg = format!("Key access on non-object at '{}'", path_segment);
 				EvaluationError::type_mismatch(msg, None, None)
@@ -216,6 +504,56 @@ mod tests {
 	use crate::services::filter::expression::ast::{ComparisonOperator, VariablePath};
 	use serde_json::json;
 
+	// --- Tests for arithmetic ---
+	#[test]
+	fn test_apply_arithmetic() {
+		let result = apply_arithmetic("50000000000", &[(ArithmeticOperator::Div, "1e9")]).unwrap();
+		assert_eq!(result, Decimal::from(50));
+
+		// Multiplication and division before addition and subtraction
+		let result = apply_arithmetic(
+			"10",
+			&[
+				(ArithmeticOperator::Add, "2"),
+				(ArithmeticOperator::Mul, "3"),
+				(ArithmeticOperator::Sub, "8"),
+				(ArithmeticOperator::Div, "4"),
+			],
+		)
+		.unwrap();
+		assert_eq!(result, Decimal::from(14));
+
+		let result = apply_arithmetic("1", &[(ArithmeticOperator::Div, "4")]).unwrap();
+		assert_eq!(result, Decimal::from_str("0.25").unwrap());
+	}
+
+	#[test]
+	fn test_apply_arithmetic_errors() {
+		assert!(matches!(
+			apply_arithmetic("abc", &[(ArithmeticOperator::Add, "1")]),
+			Err(EvaluationError::ParseError(_))
+		));
+		assert!(matches!(
+			apply_arithmetic("1", &[(ArithmeticOperator::Div, "0")]),
+			Err(EvaluationError::TypeMismatch(_))
+		));
+	}
+
+	#[test]
+	fn test_compare_decimal_result() {
+		let result = Decimal::from_str("50.5").unwrap();
+		assert!(compare_decimal_result(
+			&result,
+			&ComparisonOperator::Gte,
+			&LiteralValue::Number("50")
+		)
+		.unwrap());
+		assert!(
+			compare_decimal_result(&result, &ComparisonOperator::Eq, &LiteralValue::Str("50"))
+				.is_err()
+		);
+	}
+
 	// --- Tests for `compare_ordered_values` ---
 	#[test]
 	fn test_compare_ordered_values_integers() {
@@ -285,6 +623,10 @@ mod tests {
 		let res3 = access_json_value(arr.clone(), &Accessor::Key("key"), "arr.key");
 		assert!(matches!(res3, Err(EvaluationError::TypeMismatch(_))));
 
+		// Numeric key access on array out of bounds
+		let res5 = access_json_value(arr.clone(), &Accessor::Key("3"), "arr.3");
+		assert!(matches!(res5, Err(EvaluationError::IndexOutOfBounds(_))));
+
 		// Type mismatch (index access on object)
 		let res4 = access_json_value(obj.clone(), &Accessor::Index(0), "obj[0]");
 		assert!(matches!(res4, Err(EvaluationError::TypeMismatch(_))));
@@ -334,6 +676,100 @@ mod tests {
 		assert_eq!(resolved, json!(2));
 	}
 
+	#[test]
+	fn test_resolve_path_numeric_key_on_array() {
+		let base_val_str = r#"[{"mint": "a", "hops": [{"amount": 5}]}, {"mint": "b"}]"#;
+		let accessors = vec![
+			Accessor::Key("0"),
+			Accessor::Key("hops"),
+			Accessor::Key("0"),
+			Accessor::Key("amount"),
+		];
+		let lhs = ConditionLeft::Path(VariablePath {
+			base: "params",
+			accessors: accessors.clone(),
+		});
+		let resolved =
+			resolve_path_to_json_value(base_val_str, "vec", &accessors, "params", &lhs).unwrap();
+		assert_eq!(resolved, json!(5));
+
+		// Numeric keys of objects are still keys
+		let base_val_str = r#"{"0": "zero"}"#;
+		let accessors = vec![Accessor::Key("0")];
+		let resolved =
+			resolve_path_to_json_value(base_val_str, "map", &accessors, "params", &lhs).unwrap();
+		assert_eq!(resolved, json!("zero"));
+	}
+
+	// --- Tests for `collect_aggregate_items` ---
+	#[test]
+	fn test_collect_aggregate_items() {
+		let route = json!({
+			"hops": [
+				{ "amount_in": 10, "mints": ["a", "b"] },
+				{ "amount_in": 20, "mints": ["c"] }
+			]
+		});
+		let collect = |accessors: Vec<Accessor>| {
+			let segments = build_path_segments("route", &accessors);
+			collect_aggregate_items(route.clone(), &accessors, &segments)
+		};
+
+		assert_eq!(
+			collect(vec![Accessor::Key("hops"), Accessor::Key("amount_in")]).unwrap(),
+			vec![json!(10), json!(20)]
+		);
+		assert_eq!(
+			collect(vec![Accessor::Key("hops"), Accessor::Key("mints")]).unwrap(),
+			vec![json!("a"), json!("b"), json!("c")]
+		);
+		assert_eq!(
+			collect(vec![
+				Accessor::Key("hops"),
+				Accessor::Key("1"),
+				Accessor::Key("mints")
+			])
+			.unwrap(),
+			vec![json!("c")]
+		);
+		assert_eq!(collect(vec![Accessor::Key("hops")]).unwrap().len(), 2);
+		assert!(matches!(
+			collect(vec![Accessor::Key("hops"), Accessor::Key("fee")]),
+			Err(EvaluationError::FieldNotFound(_))
+		));
+	}
+
+	// --- Tests for resolved literals ---
+	#[test]
+	fn test_resolve_literal() {
+		let before = chrono::Utc::now().timestamp();
+		let resolved = resolve_literal(&LiteralValue::Now(-3_600))
+			.unwrap()
+			.unwrap();
+		let after = chrono::Utc::now().timestamp();
+		let resolved = resolved.parse::<i64>().unwrap();
+		assert!(resolved >= before - 3_600 && resolved <= after - 3_600);
+
+		assert_eq!(
+			resolve_literal(&LiteralValue::Duration(900)).unwrap(),
+			Some("900".to_string())
+		);
+		assert_eq!(resolve_literal(&LiteralValue::Number("1")).unwrap(), None);
+		assert!(matches!(
+			resolve_literal(&LiteralValue::Now(i64::MAX)),
+			Err(EvaluationError::TypeMismatch(_))
+		));
+
+		assert_eq!(
+			resolve_literal(&LiteralValue::Base58("3URvB")).unwrap(),
+			Some("0x01ab03c4".to_string())
+		);
+		assert!(matches!(
+			resolve_literal(&LiteralValue::Base58("0OIl")),
+			Err(EvaluationError::ParseError(_))
+		));
+	}
+
 	// --- Tests for `build_path_segments` ---
 	#[test]
 	fn test_build_path_segments_formatting() {
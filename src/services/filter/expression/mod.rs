@@ -1,13 +1,16 @@
 //! Shared logic for parsing and evaluating expressions
 
 mod ast;
+mod cache;
 mod error;
 mod evaluation;
 mod helpers;
+mod params;
 mod parsing;
 
 pub use ast::{ComparisonOperator, LiteralValue};
+pub use cache::ExpressionCache;
 pub use error::EvaluationError;
 pub use evaluation::ConditionEvaluator;
-pub use helpers::{compare_ordered_values, evaluate};
-pub use parsing::parse;
+pub use helpers::compare_ordered_values;
+pub use params::{evaluate_expression, get_base_param, ParamLookup};
@@ -0,0 +1,165 @@
+//! Lookup of the parameters expressions are evaluated against, and the entry point used by
+//! the filters of every chain to evaluate an expression.
+
+use crate::models::{EVMMatchParamEntry, SolanaMatchParamEntry, StellarMatchParamEntry};
+
+use super::{cache::ExpressionCache, error::EvaluationError, evaluation::ConditionEvaluator};
+
+/// The `ParamLookup` trait resolves the base variables of an expression to the parameters
+/// extracted by a chain filter.
+pub trait ParamLookup {
+	/// Gets the raw string value and kind of a parameter, if present
+	fn lookup(&self, name: &str) -> Option<(&str, &str)>;
+}
+
+impl ParamLookup for [EVMMatchParamEntry] {
+	fn lookup(&self, name: &str) -> Option<(&str, &str)> {
+		self.iter()
+			.find(|entry| entry.name == name)
+			.map(|entry| (entry.value.as_str(), entry.kind.as_str()))
+	}
+}
+
+impl ParamLookup for [StellarMatchParamEntry] {
+	fn lookup(&self, name: &str) -> Option<(&str, &str)> {
+		self.iter()
+			.find(|entry| entry.name == name)
+			.map(|entry| (entry.value.as_str(), entry.kind.as_str()))
+	}
+}
+
+impl ParamLookup for [SolanaMatchParamEntry] {
+	fn lookup(&self, name: &str) -> Option<(&str, &str)> {
+		self.iter()
+			.find(|entry| entry.name == name)
+			.map(|entry| (entry.value.as_str(), entry.kind.as_str()))
+	}
+}
+
+/// Gets the raw string value and kind of a base variable
+///
+/// Returns a `VariableNotFound` error if no parameter has this name
+pub fn get_base_param<'a, P: ParamLookup + ?Sized>(
+	params: &'a P,
+	name: &str,
+) -> Result<(&'a str, &'a str), EvaluationError> {
+	params.lookup(name).ok_or_else(|| {
+		let msg = format!("Base parameter not found: {}", name);
+		EvaluationError::variable_not_found(msg, None, None)
+	})
+}
+
+/// Parses an expression and evaluates it with a chain-specific evaluator
+/// Each expression is parsed once, its AST is kept in the shared `ExpressionCache`
+///
+/// Returns a `ParseError` if the expression is empty or cannot be parsed
+pub fn evaluate_expression(
+	expression: &str,
+	evaluator: &impl ConditionEvaluator,
+) -> Result<bool, EvaluationError> {
+	ExpressionCache::shared().evaluate(expression, evaluator)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::services::filter::expression::ast::{ComparisonOperator, LiteralValue};
+
+	/// Minimal evaluator comparing raw strings, used to exercise the shared entry point
+	struct StringEvaluator<'a> {
+		params: &'a [SolanaMatchParamEntry],
+	}
+
+	impl ConditionEvaluator for StringEvaluator<'_> {
+		fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError> {
+			get_base_param(self.params, name)
+		}
+
+		fn compare_final_values(
+			&self,
+			_left_kind: &str,
+			left_resolved_value: &str,
+			operator: &ComparisonOperator,
+			right_literal: &LiteralValue,
+		) -> Result<bool, EvaluationError> {
+			let right = match right_literal {
+				LiteralValue::Str(value) | LiteralValue::Number(value) => value,
+				_ => return Ok(false),
+			};
+			match operator {
+				ComparisonOperator::Eq => Ok(left_resolved_value == *right),
+				ComparisonOperator::Ne => Ok(left_resolved_value != *right),
+				_ => Err(EvaluationError::unsupported_operator(
+					format!("Unsupported operator: {:?}", operator),
+					None,
+					None,
+				)),
+			}
+		}
+
+		fn get_kind_from_json_value(&self, _value: &serde_json::Value) -> String {
+			"string".to_string()
+		}
+	}
+
+	fn entry(name: &str, value: &str, kind: &str) -> SolanaMatchParamEntry {
+		SolanaMatchParamEntry {
+			name: name.to_string(),
+			value: value.to_string(),
+			kind: kind.to_string(),
+			indexed: false,
+		}
+	}
+
+	#[test]
+	fn test_lookup_for_each_chain() {
+		let solana = vec![entry("amount", "100", "u64")];
+		assert_eq!(solana.lookup("amount"), Some(("100", "u64")));
+		assert_eq!(solana.lookup("missing"), None);
+
+		let evm = [EVMMatchParamEntry {
+			name: "to".to_string(),
+			value: "0x01".to_string(),
+			indexed: true,
+			kind: "address".to_string(),
+		}];
+		assert_eq!(evm.lookup("to"), Some(("0x01", "address")));
+
+		let stellar = [StellarMatchParamEntry {
+			name: "0".to_string(),
+			value: "GABC".to_string(),
+			kind: "Address".to_string(),
+			indexed: false,
+		}];
+		assert_eq!(stellar.lookup("0"), Some(("GABC", "Address")));
+
+		assert!(matches!(
+			get_base_param(solana.as_slice(), "missing"),
+			Err(EvaluationError::VariableNotFound(_))
+		));
+	}
+
+	#[test]
+	fn test_evaluate_expression() {
+		let params = vec![
+			entry("name", "deposit", "string"),
+			entry("slot", "7", "u64"),
+		];
+		let evaluator = StringEvaluator { params: &params };
+
+		assert!(evaluate_expression("name == 'deposit' AND slot == 7", &evaluator).unwrap());
+		assert!(!evaluate_expression("name != 'deposit' OR slot == 8", &evaluator).unwrap());
+		assert!(matches!(
+			evaluate_expression("unknown == 1", &evaluator),
+			Err(EvaluationError::VariableNotFound(_))
+		));
+		assert!(matches!(
+			evaluate_expression("  ", &evaluator),
+			Err(EvaluationError::ParseError(_))
+		));
+		assert!(matches!(
+			evaluate_expression("name ==", &evaluator),
+			Err(EvaluationError::ParseError(_))
+		));
+	}
+}
@@ -3,15 +3,16 @@
 //! The parser converts the input string into an abstract syntax tree (AST) representation of the expression.
 
 use super::ast::{
-	Accessor, ComparisonOperator, Condition, ConditionLeft, Expression, LiteralValue,
-	LogicalOperator, VariablePath,
+	Accessor, AggregateExpression, AggregateFunction, ArithmeticExpression, ArithmeticOperator,
+	ComparisonOperator, Condition, ConditionLeft, Expression, LiteralValue, LogicalOperator,
+	VariablePath,
 };
 use winnow::{
 	ascii::{digit1, space0, space1, Caseless},
-	combinator::{alt, delimited, eof, opt, peek, repeat, Repeat},
+	combinator::{alt, delimited, eof, opt, peek, repeat, separated, Repeat},
 	error::{ContextError, ErrMode, ParseError, StrContext, StrContextValue},
 	prelude::*,
-	token::{literal, one_of, take_while},
+	token::{any, literal, one_of, take_while},
 };
 
 /// --- Helper aliases ---
@@ -19,13 +20,28 @@ type Input<'a> = &'a str;
 /// Result for internal parser functions
 type ParserResult<T> = winnow::Result<T, ErrMode<ContextError>>;
 
+/// Keywords of the expression language, matched case-insensitively
+const KEYWORDS: [&str; 12] = [
+	"true",
+	"false",
+	"and",
+	"or",
+	"not",
+	"in",
+	"is",
+	"between",
+	"contains",
+	"matches",
+	"starts_with",
+	"ends_with",
+];
+
 // Helper to check for keywords
 // These words cannot be used as unquoted string literals or variable names
 fn is_keyword(ident: &str) -> bool {
-	matches!(
-		ident.to_ascii_lowercase().as_str(),
-		"true" | "false" | "and" | "or" | "contains" | "starts_with" | "ends_with"
-	)
+	KEYWORDS
+		.iter()
+		.any(|keyword| ident.eq_ignore_ascii_case(keyword))
 }
 
 /// Common delimiters that can follow a literal value
@@ -102,13 +118,38 @@ fn parse_hex_string<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>>
 		.parse_next(input)
 }
 
+/// Parses base58 byte literals (e.g., "b58'3URvB'") into `LiteralValue::Base58`
+/// The prefix is case-insensitive, and the content must only use base58 characters
+fn parse_base58_bytes<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	literal(Caseless("b58")).parse_next(input)?;
+	let open_quote: char = one_of(['\'', '"']).parse_next(input)?;
+
+	let content = take_while(1.., |c: char| {
+		c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l')
+	})
+	.context(StrContext::Expected(StrContextValue::Description(
+		"base58 characters",
+	)))
+	.parse_next(input)?;
+
+	literal(open_quote)
+		.context(StrContext::Expected(StrContextValue::Description(
+			"closing quote of base58 literal",
+		)))
+		.parse_next(input)?;
+
+	Ok(LiteralValue::Base58(content))
+}
+
 /// Parses string literals enclosed in single or double quotes into `LiteralValue::Str`
+/// Escape sequences are kept as written, so patterns of the `matches` operator can use
+/// regular expression escapes (e.g., '\d+')
 fn parse_quoted_string<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
 	// Match and consume opening quote, remember which one it was.
 	let open_quote: char = one_of(['\'', '"']).parse_next(input)?;
 
 	let character_or_escape_sequence = alt((
-		(literal("\\"), one_of([open_quote, '\\'])).void(),
+		(literal("\\"), any).void(),
 		take_while(1.., move |c: char| c != open_quote && c != '\\').void(),
 	));
 
@@ -182,6 +223,7 @@ fn parse_accessor<'a>(input: &mut Input<'a>) -> ParserResult<Accessor<'a>> {
 			literal("[").value(()),                           // start of index accessor
 			literal(".").value(()),                           // start of another key accessor
 			one_of(['=', '!', '>', '<', ')', '(']).value(()), // Operators or delimiters
+			(parse_arithmetic_operator, digit1).value(()),    // Arithmetic on a number
 		))),
 	)
 		.map(|(_, key_slice, _): (_, &str, _)| Accessor::Key(key_slice))
@@ -227,7 +269,42 @@ fn parse_base_variable_name<'a>(input: &mut Input<'a>) -> ParserResult<&'a str>
 	.parse_next(input)
 }
 
-fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+/// Parses an arithmetic operator (e.g., +, -, *, /)
+/// Handles optional whitespace around the operator
+fn parse_arithmetic_operator(input: &mut Input<'_>) -> ParserResult<ArithmeticOperator> {
+	delimited(
+		space0,
+		alt((
+			literal("+").value(ArithmeticOperator::Add),
+			literal("-").value(ArithmeticOperator::Sub),
+			literal("*").value(ArithmeticOperator::Mul),
+			literal("/").value(ArithmeticOperator::Div),
+		)),
+		space0,
+	)
+	.context(StrContext::Expected(StrContextValue::Description(
+		"arithmetic operator (e.g., +, -, *, /)",
+	)))
+	.parse_next(input)
+}
+
+/// Parses an unsigned numeric operand of an arithmetic operation.
+/// Supports integers, fixed-point and power-of-ten notation (e.g., "1000", "0.5", "1e9")
+fn parse_arithmetic_operand<'a>(input: &mut Input<'a>) -> ParserResult<&'a str> {
+	(
+		digit1,
+		opt((literal("."), digit1)),
+		opt((one_of(['e', 'E']), opt(one_of(['+', '-'])), digit1)),
+	)
+		.take()
+		.context(StrContext::Expected(StrContextValue::Description(
+			"numeric arithmetic operand (e.g., 1000, 0.5, 1e9)",
+		)))
+		.parse_next(input)
+}
+
+/// Parses a variable name with its accessors (e.g., "a", "a.b[0]")
+fn parse_variable<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
 	// Parse the base variable name
 	let base = parse_base_variable_name.parse_next(input)?;
 
@@ -241,6 +318,121 @@ fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<
 	}
 }
 
+/// Parses an aggregate function applied to a variable (e.g., "any(hops.amount_in)", "len(hops)")
+/// Function names are case-insensitive and must be directly followed by the opening parenthesis
+fn parse_aggregate<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+	let function = alt((
+		literal(Caseless("any(")).value(AggregateFunction::Any),
+		literal(Caseless("all(")).value(AggregateFunction::All),
+		literal(Caseless("len(")).value(AggregateFunction::Len),
+	))
+	.parse_next(input)?;
+
+	let operand = delimited(
+		space0,
+		parse_variable,
+		(
+			space0,
+			literal(")").context(StrContext::Expected(StrContextValue::Description(
+				"closing parenthesis ')' of aggregate function",
+			))),
+		),
+	)
+	.parse_next(input)?;
+
+	Ok(ConditionLeft::Aggregate(AggregateExpression {
+		function,
+		operand: Box::new(operand),
+	}))
+}
+
+fn parse_condition_lhs<'a>(input: &mut Input<'a>) -> ParserResult<ConditionLeft<'a>> {
+	if let Some(aggregate) = opt(parse_aggregate).parse_next(input)? {
+		return Ok(aggregate);
+	}
+
+	let variable = parse_variable.parse_next(input)?;
+
+	// Parse any arithmetic applied to the variable (e.g., / 1e9)
+	let operations: Vec<(ArithmeticOperator, &str)> =
+		repeat(0.., (parse_arithmetic_operator, parse_arithmetic_operand)).parse_next(input)?;
+
+	if operations.is_empty() {
+		Ok(variable)
+	} else {
+		Ok(ConditionLeft::Arithmetic(ArithmeticExpression {
+			operand: Box::new(variable),
+			operations,
+		}))
+	}
+}
+
+/// Parses a duration literal (e.g., "30s", "15m", "1h", "7d", "2w") into its number of seconds
+fn parse_duration_seconds(input: &mut Input<'_>) -> ParserResult<i64> {
+	(
+		digit1,
+		one_of(['s', 'm', 'h', 'd', 'w']),
+		peek(alt((
+			space1.value(()),
+			eof.value(()),
+			one_of(COMMON_DELIMITERS).value(()),
+			one_of(['+', '-']).value(()),
+		))),
+	)
+		.verify_map(|(amount, unit, _): (&str, char, ())| {
+			let unit_seconds = match unit {
+				's' => 1,
+				'm' => 60,
+				'h' => 3_600,
+				'd' => 86_400,
+				_ => 604_800,
+			};
+			amount.parse::<i64>().ok()?.checked_mul(unit_seconds)
+		})
+		.context(StrContext::Expected(StrContextValue::Description(
+			"duration literal (e.g., 30s, 15m, 1h, 7d, 2w)",
+		)))
+		.parse_next(input)
+}
+
+/// Parses a duration literal into `LiteralValue::Duration`
+fn parse_duration<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	parse_duration_seconds
+		.map(LiteralValue::Duration)
+		.parse_next(input)
+}
+
+/// Parses the evaluation time, optionally shifted by durations (e.g., "now()", "now() - 1h")
+/// into `LiteralValue::Now`. The function name is case-insensitive.
+fn parse_now<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	literal(Caseless("now()")).parse_next(input)?;
+
+	let offsets: Vec<(ArithmeticOperator, i64)> = repeat(
+		0..,
+		(
+			delimited(
+				space0,
+				alt((
+					literal("+").value(ArithmeticOperator::Add),
+					literal("-").value(ArithmeticOperator::Sub),
+				)),
+				space0,
+			),
+			parse_duration_seconds,
+		),
+	)
+	.parse_next(input)?;
+
+	offsets
+		.into_iter()
+		.try_fold(0i64, |offset, (operator, seconds)| match operator {
+			ArithmeticOperator::Sub => offset.checked_sub(seconds),
+			_ => offset.checked_add(seconds),
+		})
+		.map(LiteralValue::Now)
+		.ok_or_else(|| ErrMode::Cut(ContextError::new()))
+}
+
 /// Parses any valid LiteralValue (boolean, number, string, or variable)
 /// Handles optional whitespace around the value
 fn parse_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
@@ -261,6 +453,41 @@ fn parse_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
 	.parse_next(input)
 }
 
+/// Parses the right-hand side value of a comparison, which may also be a time, a duration
+/// or base58 bytes. These literals are not allowed inside lists
+fn parse_comparison_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	alt((
+		delimited(
+			space0,
+			alt((parse_now, parse_duration, parse_base58_bytes)),
+			space0,
+		),
+		parse_value,
+	))
+	.parse_next(input)
+}
+
+/// Parses a bracketed, comma separated list of values (e.g., "['a', 'b']", "[1, 2]")
+/// Handles optional whitespace around the list and its items
+fn parse_list_value<'a>(input: &mut Input<'a>) -> ParserResult<LiteralValue<'a>> {
+	delimited(
+		(space0, literal("["), space0),
+		separated(1.., parse_value, literal(",")),
+		(
+			space0,
+			literal("]").context(StrContext::Expected(StrContextValue::Description(
+				"closing bracket ']'",
+			))),
+			space0,
+		),
+	)
+	.map(LiteralValue::List)
+	.context(StrContext::Expected(StrContextValue::Description(
+		"list of values (e.g., ['a', 'b'])",
+	)))
+	.parse_next(input)
+}
+
 /// Parses a comparison operator (e.g., ==, !=, >, >=, <, <=)
 /// Handles optional whitespace around the operator
 fn parse_comparison_operator(input: &mut Input<'_>) -> ParserResult<ComparisonOperator> {
@@ -268,14 +495,18 @@ fn parse_comparison_operator(input: &mut Input<'_>) -> ParserResult<ComparisonOp
 		space0,
 		alt((
 			literal(Caseless("contains")).map(|_| ComparisonOperator::Contains),
+			literal(Caseless("matches")).map(|_| ComparisonOperator::Matches),
 			literal(Caseless("starts_with")).map(|_| ComparisonOperator::StartsWith),
 			literal(Caseless("ends_with")).map(|_| ComparisonOperator::EndsWith),
+			parse_null_check,
 			literal(">=").map(|_| ComparisonOperator::Gte),
 			literal("<=").map(|_| ComparisonOperator::Lte),
 			literal("==").map(|_| ComparisonOperator::Eq),
 			literal("!=").map(|_| ComparisonOperator::Ne),
 			literal(">").map(|_| ComparisonOperator::Gt),
 			literal("<").map(|_| ComparisonOperator::Lt),
+			// Ensure "in" is a standalone keyword followed by a list
+			(literal(Caseless("in")), peek((space0, literal("[")))).map(|_| ComparisonOperator::In),
 		)),
 		space0,
 	)
@@ -285,14 +516,84 @@ fn parse_comparison_operator(input: &mut Input<'_>) -> ParserResult<ComparisonOp
 	.parse_next(input)
 }
 
+/// Parses the "is null" and "is not null" operators
+/// The operators carry their own right-hand side, so they must be followed by a delimiter
+fn parse_null_check(input: &mut Input<'_>) -> ParserResult<ComparisonOperator> {
+	(
+		literal(Caseless("is")),
+		space1,
+		opt((literal(Caseless("not")), space1)),
+		literal(Caseless("null")),
+		peek(alt((
+			space1.value(()),
+			eof.value(()),
+			one_of(COMMON_DELIMITERS).value(()),
+		))),
+	)
+		.map(|(_, _, not, _, _)| match not {
+			Some(_) => ComparisonOperator::IsNotNull,
+			None => ComparisonOperator::IsNull,
+		})
+		.parse_next(input)
+}
+
+/// Parses the inclusive bounds of the "between" operator (e.g., "between 1 and 10")
+fn parse_between_bounds<'a>(
+	input: &mut Input<'a>,
+) -> ParserResult<(LiteralValue<'a>, LiteralValue<'a>)> {
+	(
+		delimited(space0, literal(Caseless("between")), space1),
+		parse_comparison_value,
+		(space0, literal(Caseless("and")), space1).context(StrContext::Expected(
+			StrContextValue::Description("AND between the bounds"),
+		)),
+		parse_comparison_value,
+	)
+		.map(|(_, low, _, high)| (low, high))
+		.parse_next(input)
+}
+
 /// Parses a condition expression (e.g., "a == 1") into an `Expression::Condition`
+///
+/// A range condition (e.g., "a between 1 and 10") is parsed into the equivalent
+/// "a >= 1 AND a <= 10" expression.
 fn parse_condition<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
-	let (left, operator, right) = (parse_condition_lhs, parse_comparison_operator, parse_value)
+	let left = parse_condition_lhs
 		.context(StrContext::Expected(StrContextValue::Description(
 			"condition expression (e.g., variable == value)",
 		)))
 		.parse_next(input)?;
 
+	if let Some((low, high)) = opt(parse_between_bounds).parse_next(input)? {
+		return Ok(Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: left.clone(),
+				operator: ComparisonOperator::Gte,
+				right: low,
+			})),
+			operator: LogicalOperator::And,
+			right: Box::new(Expression::Condition(Condition {
+				left,
+				operator: ComparisonOperator::Lte,
+				right: high,
+			})),
+		});
+	}
+
+	let operator = parse_comparison_operator
+		.context(StrContext::Expected(StrContextValue::Description(
+			"condition expression (e.g., variable == value)",
+		)))
+		.parse_next(input)?;
+
+	// The `in` operator is the only one comparing against a list of values, and null
+	// checks have no right-hand side
+	let right = match operator {
+		ComparisonOperator::In => parse_list_value.parse_next(input)?,
+		ComparisonOperator::IsNull | ComparisonOperator::IsNotNull => LiteralValue::Str("null"),
+		_ => parse_comparison_value.parse_next(input)?,
+	};
+
 	let condition = Condition {
 		left,
 		operator,
@@ -302,7 +603,23 @@ fn parse_condition<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
 	Ok(Expression::Condition(condition))
 }
 
-/// Parses the highest precedence components: conditions and parenthesized expressions
+/// Parses a negated term (e.g., "NOT a == 1" or "NOT (a == 1 OR b == 2)")
+/// NOT binds tighter than AND and OR, so it applies to the following term only
+fn parse_not_expression<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
+	(
+		literal(Caseless("NOT")),
+		// Ensure "NOT" is a standalone keyword and not the prefix of a variable name
+		alt((space1.void(), peek(literal("(")).void())),
+		parse_term,
+	)
+		.map(|(_, _, inner)| Expression::Not(Box::new(inner)))
+		.context(StrContext::Expected(StrContextValue::Description(
+			"NOT expression",
+		)))
+		.parse_next(input)
+}
+
+/// Parses the highest precedence components: conditions, negations and parenthesized expressions
 fn parse_term<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
 	delimited(
 		space0,
@@ -315,6 +632,8 @@ fn parse_term<'a>(input: &mut Input<'a>) -> ParserResult<Expression<'a>> {
 					"closing parenthesis ')'",
 				))),
 			),
+			// Parse a negated term
+			parse_not_expression,
 			// Parse a condition
 			parse_condition,
 		)),
@@ -581,6 +900,13 @@ mod tests {
 			"",
 		);
 		assert_parses_ok(parse_quoted_string, "'_'", LiteralValue::Str("_"), "");
+		// Other escapes are kept as written, e.g. for regular expressions
+		assert_parses_ok(
+			parse_quoted_string,
+			"'^\\d+\\.\\d*$'",
+			LiteralValue::Str("^\\d+\\.\\d*$"),
+			"",
+		);
 
 		// Failures
 		assert_parse_fails(parse_quoted_string, "'hello"); // Missing closing quote
@@ -633,6 +959,7 @@ mod tests {
 		assert!(is_keyword("true"));
 		assert!(is_keyword("FALSE"));
 		assert!(is_keyword("AnD"));
+		assert!(is_keyword("Not"));
 		assert!(is_keyword("cOnTaiNs"));
 		// Failures
 		assert!(!is_keyword("trueish"));
@@ -801,6 +1128,12 @@ mod tests {
 			ComparisonOperator::StartsWith,
 			"",
 		);
+		assert_parses_ok(
+			parse_comparison_operator,
+			" MATCHES ",
+			ComparisonOperator::Matches,
+			"",
+		);
 	}
 
 	#[test]
@@ -950,6 +1283,405 @@ mod tests {
 		assert_eq!(parse(expr_parens).unwrap(), expected_parens);
 	}
 
+	#[test]
+	fn test_parse_nested_and_quoted_expressions() {
+		// Logical keywords and parentheses inside quotes are part of the string
+		let expr_quoted = "memo == 'a AND (b OR c)'";
+		let expected_quoted = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("memo"),
+			operator: ComparisonOperator::Eq,
+			right: LiteralValue::Str("a AND (b OR c)"),
+		});
+		assert_eq!(parse(expr_quoted).unwrap(), expected_quoted);
+
+		// Redundant nesting collapses to the inner condition
+		let expr_nested = "((( a == 1 )))";
+		let expected_nested = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("a"),
+			operator: ComparisonOperator::Eq,
+			right: LiteralValue::Number("1"),
+		});
+		assert_eq!(parse(expr_nested).unwrap(), expected_nested);
+
+		// Unbalanced parentheses and quotes are rejected
+		assert!(parse("((a == 1) AND b == 2").is_err());
+		assert!(parse("a == 1)").is_err());
+		assert!(parse("memo == 'unterminated").is_err());
+	}
+
+	#[test]
+	fn test_parse_not_expressions() {
+		let cond = |name, value| {
+			Expression::Condition(Condition {
+				left: ConditionLeft::Simple(name),
+				operator: ComparisonOperator::Eq,
+				right: LiteralValue::Str(value),
+			})
+		};
+
+		assert_eq!(
+			parse("NOT owner == 'Y'").unwrap(),
+			Expression::Not(Box::new(cond("owner", "Y")))
+		);
+		assert_eq!(
+			parse("not(owner == 'Y')").unwrap(),
+			Expression::Not(Box::new(cond("owner", "Y")))
+		);
+
+		// NOT applies to the following term only
+		let expr = "(amount > 100 AND mint == 'X') OR NOT (owner == 'Y')";
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Logical {
+				left: Box::new(Expression::Condition(Condition {
+					left: ConditionLeft::Simple("amount"),
+					operator: ComparisonOperator::Gt,
+					right: LiteralValue::Number("100"),
+				})),
+				operator: LogicalOperator::And,
+				right: Box::new(cond("mint", "X")),
+			}),
+			operator: LogicalOperator::Or,
+			right: Box::new(Expression::Not(Box::new(cond("owner", "Y")))),
+		};
+		assert_eq!(parse(expr).unwrap(), expected);
+
+		let expr_and = "NOT a == 'x' AND b == 'y'";
+		let expected_and = Expression::Logical {
+			left: Box::new(Expression::Not(Box::new(cond("a", "x")))),
+			operator: LogicalOperator::And,
+			right: Box::new(cond("b", "y")),
+		};
+		assert_eq!(parse(expr_and).unwrap(), expected_and);
+
+		assert_eq!(
+			parse("NOT NOT a == 'x'").unwrap(),
+			Expression::Not(Box::new(Expression::Not(Box::new(cond("a", "x")))))
+		);
+
+		// Variables starting with "not" are not negations
+		assert_eq!(parse("notes == 'x'").unwrap(), cond("notes", "x"));
+		assert!(parse("NOT").is_err());
+		assert!(parse("not == 1").is_err());
+	}
+
+	#[test]
+	fn test_parse_arithmetic() {
+		let expr = "in_amount / 1000000000 >= 50";
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Arithmetic(ArithmeticExpression {
+				operand: Box::new(ConditionLeft::Simple("in_amount")),
+				operations: vec![(ArithmeticOperator::Div, "1000000000")],
+			}),
+			operator: ComparisonOperator::Gte,
+			right: LiteralValue::Number("50"),
+		});
+		assert_eq!(parse(expr).unwrap(), expected);
+
+		let expr_path = "pool.amounts[0]*2+1.5e3 < 10";
+		let expected_path = Expression::Condition(Condition {
+			left: ConditionLeft::Arithmetic(ArithmeticExpression {
+				operand: Box::new(ConditionLeft::Path(VariablePath {
+					base: "pool",
+					accessors: vec![Accessor::Key("amounts"), Accessor::Index(0)],
+				})),
+				operations: vec![
+					(ArithmeticOperator::Mul, "2"),
+					(ArithmeticOperator::Add, "1.5e3"),
+				],
+			}),
+			operator: ComparisonOperator::Lt,
+			right: LiteralValue::Number("10"),
+		});
+		assert_eq!(parse(expr_path).unwrap(), expected_path);
+
+		assert!(parse("amount / > 5").is_err());
+		assert!(parse("amount / other > 5").is_err());
+	}
+
+	#[test]
+	fn test_parse_aggregate() {
+		let expr = "ANY( route.hops.amount_in ) > 100";
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Aggregate(AggregateExpression {
+				function: AggregateFunction::Any,
+				operand: Box::new(ConditionLeft::Path(VariablePath {
+					base: "route",
+					accessors: vec![Accessor::Key("hops"), Accessor::Key("amount_in")],
+				})),
+			}),
+			operator: ComparisonOperator::Gt,
+			right: LiteralValue::Number("100"),
+		});
+		assert_eq!(parse(expr).unwrap(), expected);
+
+		let expr = "len(hops) >= 3 AND all(hops[0].dexes) != 'Phoenix'";
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Aggregate(AggregateExpression {
+					function: AggregateFunction::Len,
+					operand: Box::new(ConditionLeft::Simple("hops")),
+				}),
+				operator: ComparisonOperator::Gte,
+				right: LiteralValue::Number("3"),
+			})),
+			operator: LogicalOperator::And,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Aggregate(AggregateExpression {
+					function: AggregateFunction::All,
+					operand: Box::new(ConditionLeft::Path(VariablePath {
+						base: "hops",
+						accessors: vec![Accessor::Index(0), Accessor::Key("dexes")],
+					})),
+				}),
+				operator: ComparisonOperator::Ne,
+				right: LiteralValue::Str("Phoenix"),
+			})),
+		};
+		assert_eq!(parse(expr).unwrap(), expected);
+
+		// Variables may still be named like aggregate functions
+		assert_eq!(
+			parse("len == 1").unwrap(),
+			Expression::Condition(Condition {
+				left: ConditionLeft::Simple("len"),
+				operator: ComparisonOperator::Eq,
+				right: LiteralValue::Number("1"),
+			})
+		);
+
+		assert!(parse("any(hops > 1").is_err());
+		assert!(parse("any() > 1").is_err());
+		assert!(parse("sum(hops) > 1").is_err());
+		assert!(parse("NOT any(hops.paused) == true").is_ok());
+	}
+
+	#[test]
+	fn test_parse_in_list() {
+		let expr = "mint in ['So11111111111111111111111111111111111111112', \"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v\"]";
+		let expected = Expression::Condition(Condition {
+			left: ConditionLeft::Simple("mint"),
+			operator: ComparisonOperator::In,
+			right: LiteralValue::List(vec![
+				LiteralValue::Str("So11111111111111111111111111111111111111112"),
+				LiteralValue::Str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+			]),
+		});
+		assert_eq!(parse(expr).unwrap(), expected);
+
+		let expr_numbers = "amount IN[1, -2,3.5] AND b == 1";
+		let expected_numbers = Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("amount"),
+				operator: ComparisonOperator::In,
+				right: LiteralValue::List(vec![
+					LiteralValue::Number("1"),
+					LiteralValue::Number("-2"),
+					LiteralValue::Number("3.5"),
+				]),
+			})),
+			operator: LogicalOperator::And,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("b"),
+				operator: ComparisonOperator::Eq,
+				right: LiteralValue::Number("1"),
+			})),
+		};
+		assert_eq!(parse(expr_numbers).unwrap(), expected_numbers);
+
+		assert!(parse("mint in []").is_err());
+		assert!(parse("mint in ['a', 'b'").is_err());
+		assert!(parse("mint in 'a'").is_err());
+		assert!(parse("mint == ['a']").is_err());
+	}
+
+	#[test]
+	fn test_parse_duration() {
+		assert_parses_ok(parse_duration, "30s", LiteralValue::Duration(30), "");
+		assert_parses_ok(parse_duration, "15m)", LiteralValue::Duration(900), ")");
+		assert_parses_ok(parse_duration, "1h ", LiteralValue::Duration(3_600), " ");
+		assert_parses_ok(parse_duration, "7d", LiteralValue::Duration(604_800), "");
+		assert_parses_ok(parse_duration, "2w", LiteralValue::Duration(1_209_600), "");
+
+		assert!(parse_duration.parse_peek("1x").is_err());
+		assert!(parse_duration.parse_peek("1hour").is_err());
+		assert!(parse_duration.parse_peek("h").is_err());
+		assert!(parse_duration.parse_peek("99999999999999999999w").is_err());
+	}
+
+	#[test]
+	fn test_parse_now() {
+		assert_parses_ok(parse_now, "now()", LiteralValue::Now(0), "");
+		assert_parses_ok(parse_now, "NOW() - 1h", LiteralValue::Now(-3_600), "");
+		assert_parses_ok(parse_now, "now()+1d-30m ", LiteralValue::Now(84_600), " ");
+		assert_parses_ok(
+			parse_now,
+			"now() - 1h AND a == 1",
+			LiteralValue::Now(-3_600),
+			" AND a == 1",
+		);
+
+		assert!(parse_now.parse_peek("now").is_err());
+		assert!(parse_now
+			.parse_peek("now() + 9223372036854775807s + 1s")
+			.is_err());
+	}
+
+	#[test]
+	fn test_parse_time_conditions() {
+		assert_parses_ok(
+			parse_comparison_value,
+			" 1h ",
+			LiteralValue::Duration(3_600),
+			"",
+		);
+		assert_parses_ok(parse_comparison_value, " now() ", LiteralValue::Now(0), "");
+		assert_parses_ok(
+			parse_comparison_value,
+			" 123 ",
+			LiteralValue::Number("123"),
+			"",
+		);
+
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("block_time"),
+				operator: ComparisonOperator::Gt,
+				right: LiteralValue::Now(-3_600),
+			})),
+			operator: LogicalOperator::And,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("lock_period"),
+				operator: ComparisonOperator::Gte,
+				right: LiteralValue::Duration(604_800),
+			})),
+		};
+		assert_eq!(
+			parse("block_time > now() - 1h AND lock_period >= 7d").unwrap(),
+			expected
+		);
+
+		// Durations are not parsed inside lists
+		assert!(!matches!(
+			parse("a in [1h, 2h]"),
+			Ok(Expression::Condition(Condition {
+				right: LiteralValue::List(ref items),
+				..
+			})) if items.contains(&LiteralValue::Duration(3_600))
+		));
+	}
+
+	#[test]
+	fn test_parse_base58_bytes() {
+		assert_parses_ok(
+			parse_base58_bytes,
+			"b58'3URvB'",
+			LiteralValue::Base58("3URvB"),
+			"",
+		);
+		assert_parses_ok(
+			parse_base58_bytes,
+			"B58\"3URvB\") ",
+			LiteralValue::Base58("3URvB"),
+			") ",
+		);
+
+		assert!(parse_base58_bytes.parse_peek("b58''").is_err());
+		assert!(parse_base58_bytes.parse_peek("b58'0OIl'").is_err());
+		assert!(parse_base58_bytes.parse_peek("b58'3URvB\"").is_err());
+		assert!(parse_base58_bytes.parse_peek("'3URvB'").is_err());
+	}
+
+	#[test]
+	fn test_parse_raw_data_conditions() {
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("data"),
+				operator: ComparisonOperator::StartsWith,
+				right: LiteralValue::Str("0x01ab"),
+			})),
+			operator: LogicalOperator::Or,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("data"),
+				operator: ComparisonOperator::StartsWith,
+				right: LiteralValue::Base58("3URvB"),
+			})),
+		};
+		assert_eq!(
+			parse("data starts_with 0x01ab OR data starts_with b58'3URvB'").unwrap(),
+			expected
+		);
+	}
+
+	#[test]
+	fn test_parse_null_checks() {
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("referrer"),
+				operator: ComparisonOperator::IsNull,
+				right: LiteralValue::Str("null"),
+			})),
+			operator: LogicalOperator::Or,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Path(VariablePath {
+					base: "config",
+					accessors: vec![Accessor::Key("fee")],
+				}),
+				operator: ComparisonOperator::IsNotNull,
+				right: LiteralValue::Str("null"),
+			})),
+		};
+		assert_eq!(
+			parse("referrer is null OR config.fee IS NOT NULL").unwrap(),
+			expected
+		);
+		assert!(parse("(referrer is null)").is_ok());
+
+		assert!(parse("referrer is nullable").is_err());
+		assert!(parse("referrer is not").is_err());
+		assert!(parse("referrer is null 1").is_err());
+		assert!(parse("referrer isnull").is_err());
+		assert!(parse("is == 1").is_err());
+	}
+
+	#[test]
+	fn test_parse_between() {
+		let expected = Expression::Logical {
+			left: Box::new(Expression::Logical {
+				left: Box::new(Expression::Condition(Condition {
+					left: ConditionLeft::Simple("slot"),
+					operator: ComparisonOperator::Gte,
+					right: LiteralValue::Number("100"),
+				})),
+				operator: LogicalOperator::And,
+				right: Box::new(Expression::Condition(Condition {
+					left: ConditionLeft::Simple("slot"),
+					operator: ComparisonOperator::Lte,
+					right: LiteralValue::Number("200"),
+				})),
+			}),
+			operator: LogicalOperator::And,
+			right: Box::new(Expression::Condition(Condition {
+				left: ConditionLeft::Simple("fee"),
+				operator: ComparisonOperator::Gt,
+				right: LiteralValue::Number("0"),
+			})),
+		};
+		assert_eq!(
+			parse("slot between 100 and 200 AND fee > 0").unwrap(),
+			expected
+		);
+		assert_eq!(
+			parse("slot BETWEEN 100 AND 200 AND fee > 0").unwrap(),
+			expected
+		);
+		assert!(parse("NOT (block_time between now() - 1h and now())").is_ok());
+
+		assert!(parse("slot between 100").is_err());
+		assert!(parse("slot between 100 or 200").is_err());
+		assert!(parse("slot between and 200").is_err());
+		assert!(parse("between == 1").is_err());
+	}
+
 	#[test]
 	fn test_full_parse_with_eof() {
 		assert!(parse("var == 123").is_ok());
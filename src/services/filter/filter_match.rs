@@ -12,16 +12,25 @@ use alloy::primitives::Address;
 use serde_json::{json, Value as JsonValue};
 
 use crate::{
-	models::{MonitorMatch, ScriptLanguage},
+	models::{
+		EVMMatchParamEntry, MonitorMatch, ScriptLanguage, SolanaMatchArguments,
+		SolanaMatchParamEntry, SolanaMatchParamsMap, SolanaMonitorMatch, StellarMatchParamEntry,
+	},
 	services::{
 		filter::{
+			evaluate_expression,
 			evm_helpers::{b256_to_string, h160_to_string},
-			FilterError,
+			EVMConditionEvaluator, FilterError, SolanaConditionEvaluator,
+			StellarConditionEvaluator,
 		},
+		notification::explorer_url,
 		trigger::TriggerExecutionServiceTrait,
 	},
 };
 
+/// Rendering of the `args` variable of Solana matches without decoded arguments
+const SOLANA_NO_ARGS: &str = "No arguments";
+
 /// Process a monitor match by executing associated triggers.
 ///
 /// Takes a matched monitor event and processes it through the appropriate trigger service.
@@ -40,6 +49,7 @@ use crate::{
 /// The function converts blockchain data into template variables like:
 /// ```text
 /// "monitor.name": "Transfer USDT Token"
+/// "monitor.severity": "info"
 /// "transaction.hash": "0x99139c8f64b9b939678e261e1553660b502d9fd01c2ab1516e699ee6c8cc5791"
 /// "transaction.from": "0xf401346fd255e034a2e43151efe1d68c1e0f8ca5"
 /// "transaction.to": "0x0000000000001ff3684f28c67538d4d072c22734"
@@ -55,7 +65,18 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 	trigger_scripts: &HashMap<String, (ScriptLanguage, String)>,
 ) -> Result<(), FilterError> {
 	match &matching_monitor {
-		MonitorMatch::Solana(solana_monitor_match) => todo!(),
+		MonitorMatch::Solana(solana_monitor_match) => {
+			// Swallow any errors since it's logged in the trigger service and we want to continue
+			// processing other matches
+			let _ = trigger_service
+				.execute(
+					&match_triggers(&matching_monitor),
+					solana_match_variables(solana_monitor_match),
+					&matching_monitor,
+					trigger_scripts,
+				)
+				.await;
+		}
 
 		MonitorMatch::EVM(evm_monitor_match) => {
 			let transaction = evm_monitor_match.transaction.clone();
@@ -66,6 +87,7 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 			let mut data_json = json!({
 				"monitor": {
 					"name": evm_monitor_match.monitor.name.clone(),
+					"severity": evm_monitor_match.monitor.severity().to_string(),
 				},
 				"transaction": {
 					"hash": b256_to_string(*transaction.hash()),
@@ -81,6 +103,14 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				data_json["transaction"]["to"] = json!(h160_to_string(*to));
 			}
 
+			// Add the explorer link of the transaction if the network has an explorer
+			if let Some(url) = explorer_url(
+				&evm_monitor_match.network_slug,
+				&b256_to_string(*transaction.hash()),
+			) {
+				data_json["explorer_url"] = json!(url);
+			}
+
 			// Process matched functions
 			let functions = data_json["functions"].as_array_mut().unwrap();
 			for func in evm_monitor_match.matched_on.functions.iter() {
@@ -139,12 +169,7 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 			// processing other matches
 			let _ = trigger_service
 				.execute(
-					&evm_monitor_match
-						.monitor
-						.triggers
-						.iter()
-						.map(|s| s.to_string())
-						.collect::<Vec<_>>(),
+					&match_triggers(&matching_monitor),
 					json_to_hashmap(&data_json),
 					&matching_monitor,
 					trigger_scripts,
@@ -158,6 +183,7 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 			let mut data_json = json!({
 				"monitor": {
 					"name": stellar_monitor_match.monitor.name.clone(),
+					"severity": stellar_monitor_match.monitor.severity().to_string(),
 				},
 				"transaction": {
 					"hash": transaction.hash().to_string(),
@@ -166,6 +192,12 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 				"events": []
 			});
 
+			// Add the explorer link of the transaction if the network has an explorer
+			if let Some(url) = explorer_url(&stellar_monitor_match.network_slug, transaction.hash())
+			{
+				data_json["explorer_url"] = json!(url);
+			}
+
 			// Process matched functions
 			let functions = data_json["functions"].as_array_mut().unwrap();
 			for func in stellar_monitor_match.matched_on.functions.iter() {
@@ -224,12 +256,7 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 			// processing other matches
 			let _ = trigger_service
 				.execute(
-					&stellar_monitor_match
-						.monitor
-						.triggers
-						.iter()
-						.map(|s| s.to_string())
-						.collect::<Vec<_>>(),
+					&match_triggers(&matching_monitor),
 					json_to_hashmap(&data_json),
 					&matching_monitor,
 					trigger_scripts,
@@ -240,6 +267,228 @@ pub async fn handle_match<T: TriggerExecutionServiceTrait>(
 	Ok(())
 }
 
+/// Returns the triggers to execute for a match
+///
+/// These are the triggers of the monitor, followed by the triggers of the routes whose
+/// expression holds for the matched arguments. Each trigger is returned once.
+fn match_triggers(monitor_match: &MonitorMatch) -> Vec<String> {
+	let monitor = match monitor_match {
+		MonitorMatch::EVM(evm_monitor_match) => &evm_monitor_match.monitor,
+		MonitorMatch::Stellar(stellar_monitor_match) => &stellar_monitor_match.monitor,
+		MonitorMatch::Solana(solana_monitor_match) => &solana_monitor_match.monitor,
+	};
+
+	let mut triggers = monitor.triggers.clone();
+	for route in &monitor.trigger_routes {
+		if !route_holds(monitor_match, &route.expression) {
+			continue;
+		}
+		for trigger in &route.triggers {
+			if !triggers.contains(trigger) {
+				triggers.push(trigger.clone());
+			}
+		}
+	}
+	triggers
+}
+
+/// Evaluates the expression of a trigger route against the arguments of a match
+///
+/// The arguments of all the matched functions, events and, for Solana, instructions,
+/// accounts, logs, sequences and blocks are available to the expression. Expressions that
+/// cannot be evaluated, e.g. because they reference an argument the match does not have,
+/// do not hold.
+fn route_holds(monitor_match: &MonitorMatch, expression: &str) -> bool {
+	let result = match monitor_match {
+		MonitorMatch::EVM(evm_monitor_match) => {
+			let args: Vec<EVMMatchParamEntry> = evm_monitor_match
+				.matched_on_args
+				.iter()
+				.flat_map(|args| [&args.functions, &args.events])
+				.flatten()
+				.flatten()
+				.flat_map(|params| params.args.iter().flatten())
+				.cloned()
+				.collect();
+			evaluate_expression(expression, &EVMConditionEvaluator::new(&args))
+		}
+		MonitorMatch::Stellar(stellar_monitor_match) => {
+			let args: Vec<StellarMatchParamEntry> = stellar_monitor_match
+				.matched_on_args
+				.iter()
+				.flat_map(|args| [&args.functions, &args.events])
+				.flatten()
+				.flatten()
+				.flat_map(|params| params.args.iter().flatten())
+				.cloned()
+				.collect();
+			evaluate_expression(expression, &StellarConditionEvaluator::new(&args))
+		}
+		MonitorMatch::Solana(solana_monitor_match) => {
+			let args: Vec<SolanaMatchParamEntry> = solana_monitor_match
+				.matched_on_args()
+				.into_iter()
+				.flat_map(|args| {
+					[
+						&args.instructions,
+						&args.accounts,
+						&args.balance_changes,
+						&args.events,
+						&args.logs,
+						&args.sequences,
+						&args.blocks,
+					]
+				})
+				.flatten()
+				.flatten()
+				.flat_map(|params| params.args.iter().flatten())
+				.cloned()
+				.collect();
+			evaluate_expression(expression, &SolanaConditionEvaluator::new(&args))
+		}
+	};
+
+	result.unwrap_or_else(|e| {
+		tracing::debug!("Trigger route '{}' not taken: {}", expression, e);
+		false
+	})
+}
+
+/// Converts a Solana match into template variables
+///
+/// Besides the monitor and transaction, the variables include:
+/// ```text
+/// "signature": "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"
+/// "slot": "123456789"
+/// "network": "solana_mainnet"
+/// "instruction.name": "transfer"
+/// "args": "amount: 1000, authority: 9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+/// "args.amount": "1000"
+/// "explorer_url": "https://explorer.solana.com/tx/5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"
+/// "instructions.0.args.amount": "1000"
+/// ```
+fn solana_match_variables(solana_monitor_match: &SolanaMonitorMatch) -> HashMap<String, String> {
+	let signature = solana_monitor_match.signature().to_string();
+	let slot = solana_monitor_match.slot();
+	let args = solana_monitor_match.matched_on_args();
+
+	// Create structured JSON data
+	let mut data_json = json!({
+		"monitor": {
+			"name": solana_monitor_match.monitor.name.clone(),
+			"severity": solana_monitor_match.monitor.severity().to_string(),
+		},
+		"signature": signature.clone(),
+		"slot": slot,
+		"network": solana_monitor_match.network_slug.clone(),
+		"transaction": {
+			"signature": signature.clone(),
+			"slot": slot,
+			"fee_payer": solana_monitor_match.transaction.fee_payer().to_string(),
+		},
+		"instruction": {
+			"name": solana_instruction_name(solana_monitor_match),
+		},
+		"instructions": solana_params_json(args.and_then(|args| args.instructions.as_ref())),
+		"accounts": solana_params_json(args.and_then(|args| args.accounts.as_ref())),
+		"balance_changes": solana_params_json(
+			args.and_then(|args| args.balance_changes.as_ref())
+		),
+		"events": solana_params_json(args.and_then(|args| args.events.as_ref())),
+		"logs": solana_params_json(args.and_then(|args| args.logs.as_ref())),
+		"sequences": solana_params_json(args.and_then(|args| args.sequences.as_ref())),
+		"blocks": solana_params_json(args.and_then(|args| args.blocks.as_ref())),
+	});
+
+	// Add the explorer link of the transaction if the network has an explorer
+	if let Some(url) = explorer_url(&solana_monitor_match.network_slug, &signature) {
+		data_json["explorer_url"] = json!(url);
+	}
+
+	// Expose the arguments of the first matched parameters as `args.<field>`, with
+	// `args` rendering all of them, or a fallback when no argument was decoded
+	let primary_args = args
+		.and_then(solana_primary_params)
+		.and_then(|params| params.args.as_ref())
+		.filter(|entries| !entries.is_empty());
+	data_json["args"] = json!(primary_args.map_or(SOLANA_NO_ARGS.to_string(), |entries| {
+		entries
+			.iter()
+			.map(|arg| format!("{}: {}", arg.name, arg.value))
+			.collect::<Vec<_>>()
+			.join(", ")
+	}));
+	let mut variables = json_to_hashmap(&data_json);
+	for arg in primary_args.into_iter().flatten() {
+		variables.insert(format!("args.{}", arg.name), arg.value.clone());
+	}
+	variables
+}
+
+/// Returns the parameters whose arguments are exposed as `args.<field>` for a Solana match
+///
+/// The first matched instruction is preferred, then the first matched sequence step, event,
+/// log, account, balance change and block.
+fn solana_primary_params(args: &SolanaMatchArguments) -> Option<&SolanaMatchParamsMap> {
+	[
+		&args.instructions,
+		&args.sequences,
+		&args.events,
+		&args.logs,
+		&args.accounts,
+		&args.balance_changes,
+		&args.blocks,
+	]
+	.into_iter()
+	.flatten()
+	.find_map(|params| params.first())
+}
+
+/// Returns the name of the instruction that matched a Solana monitor
+///
+/// Falls back to the signature of the first function condition of the monitor when no
+/// instruction was decoded, and to "unknown" for matches of other conditions.
+fn solana_instruction_name(monitor_match: &SolanaMonitorMatch) -> String {
+	monitor_match
+		.matched_on_args()
+		.and_then(|args| {
+			[&args.instructions, &args.sequences]
+				.into_iter()
+				.flatten()
+				.find_map(|params| params.first())
+		})
+		.map(|params| params.signature.clone())
+		.or_else(|| {
+			monitor_match
+				.matched_on()
+				.functions
+				.first()
+				.map(|function| function.signature.clone())
+		})
+		.unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Converts matched Solana parameters to template data, as signatures and arguments by name
+fn solana_params_json(params: Option<&Vec<SolanaMatchParamsMap>>) -> JsonValue {
+	JsonValue::Array(
+		params
+			.into_iter()
+			.flatten()
+			.map(|params| {
+				json!({
+					"signature": params.signature.clone(),
+					"args": params
+						.args
+						.iter()
+						.flatten()
+						.map(|arg| (arg.name.clone(), json!(arg.value.clone())))
+						.collect::<serde_json::Map<_, _>>(),
+				})
+			})
+			.collect(),
+	)
+}
+
 /// Converts a JsonValue to a flattened HashMap with dotted path notation
 fn json_to_hashmap(json: &JsonValue) -> HashMap<String, String> {
 	let mut result = HashMap::new();
@@ -286,7 +535,148 @@ fn insert_primitive<T: ToString>(prefix: &str, result: &mut HashMap<String, Stri
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::{
+		models::{FunctionCondition, MatchConditions},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
 	use serde_json::json;
+	use solana_sdk::signature::Signature;
+
+	fn create_solana_match(
+		matched_on: MatchConditions,
+		matched_on_args: Option<SolanaMatchArguments>,
+	) -> SolanaMonitorMatch {
+		SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("Token Transfers").build(),
+			"solana_mainnet".to_string(),
+			matched_on,
+			matched_on_args,
+			TransactionBuilder::new()
+				.signature(Signature::from([7; 64]))
+				.slot(123)
+				.build(),
+		)
+	}
+
+	fn create_instruction_args(args: Option<Vec<SolanaMatchParamEntry>>) -> SolanaMatchArguments {
+		SolanaMatchArguments {
+			instructions: Some(vec![SolanaMatchParamsMap {
+				signature: "transfer".to_string(),
+				args,
+				hex_signature: None,
+			}]),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		}
+	}
+
+	#[test]
+	fn test_solana_match_variables() {
+		let monitor_match = create_solana_match(
+			MatchConditions::default(),
+			Some(create_instruction_args(Some(vec![
+				SolanaMatchParamEntry {
+					name: "amount".to_string(),
+					value: "1000".to_string(),
+					kind: "u64".to_string(),
+					indexed: false,
+				},
+				SolanaMatchParamEntry {
+					name: "authority".to_string(),
+					value: "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string(),
+					kind: "pubkey".to_string(),
+					indexed: false,
+				},
+			]))),
+		);
+
+		let variables = solana_match_variables(&monitor_match);
+		let signature = Signature::from([7; 64]).to_string();
+		assert_eq!(variables["monitor.name"], "Token Transfers");
+		assert_eq!(variables["monitor.severity"], "info");
+		assert_eq!(variables["signature"], signature);
+		assert_eq!(variables["transaction.signature"], signature);
+		assert_eq!(variables["slot"], "123");
+		assert_eq!(variables["network"], "solana_mainnet");
+		assert_eq!(
+			variables["explorer_url"],
+			format!("https://explorer.solana.com/tx/{}", signature)
+		);
+		assert_eq!(variables["instruction.name"], "transfer");
+		assert_eq!(variables["args.amount"], "1000");
+		assert_eq!(
+			variables["args"],
+			"amount: 1000, authority: 9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"
+		);
+		assert_eq!(variables["instructions.0.signature"], "transfer");
+		assert_eq!(variables["instructions.0.args.amount"], "1000");
+	}
+
+	#[test]
+	fn test_solana_match_variables_without_args() {
+		// Instruction matched without decoded arguments
+		let variables = solana_match_variables(&create_solana_match(
+			MatchConditions::default(),
+			Some(create_instruction_args(None)),
+		));
+		assert_eq!(variables["instruction.name"], "transfer");
+		assert_eq!(variables["args"], "No arguments");
+		assert!(!variables.keys().any(|key| key.starts_with("args.")));
+
+		// Falls back to the function condition of the monitor
+		let matched_on = MatchConditions {
+			functions: vec![FunctionCondition {
+				signature: "deposit".to_string(),
+				expression: None,
+			}],
+			..MatchConditions::default()
+		};
+		let variables = solana_match_variables(&create_solana_match(matched_on, None));
+		assert_eq!(variables["instruction.name"], "deposit");
+		assert_eq!(variables["args"], "No arguments");
+
+		let variables =
+			solana_match_variables(&create_solana_match(MatchConditions::default(), None));
+		assert_eq!(variables["instruction.name"], "unknown");
+	}
+
+	#[test]
+	fn test_match_triggers_routes() {
+		let monitor = MonitorBuilder::new()
+			.triggers(vec!["slack".to_string()])
+			.trigger_route("amount >= 1000000", vec!["pagerduty", "slack"])
+			.trigger_route("amount < 10", vec!["email"])
+			.trigger_route("missing > 0", vec!["discord"])
+			.build();
+		let create_match = |amount: &str| {
+			MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+				monitor.clone(),
+				"solana_mainnet".to_string(),
+				MatchConditions::default(),
+				Some(create_instruction_args(Some(vec![SolanaMatchParamEntry {
+					name: "amount".to_string(),
+					value: amount.to_string(),
+					kind: "u64".to_string(),
+					indexed: false,
+				}]))),
+				TransactionBuilder::new().build(),
+			)))
+		};
+
+		// Matches satisfying no route only go through the triggers of the monitor
+		assert_eq!(match_triggers(&create_match("1000")), vec!["slack"]);
+
+		// Large matches are also routed to PagerDuty, each trigger being executed once
+		assert_eq!(
+			match_triggers(&create_match("5000000")),
+			vec!["slack", "pagerduty"]
+		);
+		assert_eq!(match_triggers(&create_match("5")), vec!["slack", "email"]);
+	}
 
 	#[test]
 	fn test_json_to_hashmap() {
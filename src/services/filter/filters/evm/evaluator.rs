@@ -5,7 +5,7 @@ use super::helpers::{are_same_address, string_to_i256, string_to_u256};
 use crate::{
 	models::EVMMatchParamEntry,
 	services::filter::expression::{
-		compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
+		self, compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
 		LiteralValue,
 	},
 };
@@ -603,14 +603,7 @@ impl ConditionEvaluator for EVMConditionEvaluator<'_> {
 	/// Returns:
 	/// - The base parameter.
 	fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError> {
-		self.args
-			.iter()
-			.find(|p| p.name == name)
-			.map(|p| (p.value.as_str(), p.kind.as_str()))
-			.ok_or_else(|| {
-				let msg = format!("Base parameter not found: {}", name);
-				EvaluationError::variable_not_found(msg, None, None)
-			})
+		expression::get_base_param(self.args, name)
 	}
 
 	/// This method is used to compare the final values of the EVM condition evaluator.
@@ -16,10 +16,10 @@ use tracing::instrument;
 
 use crate::{
 	models::{
-		AddressWithSpec, BlockType, ContractSpec, EVMContractSpec, EVMMatchArguments,
-		EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EVMReceiptLog, EVMTransaction,
-		EVMTransactionReceipt, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, TransactionCondition, TransactionStatus,
+		AddressWithSpec, BlockType, ConditionLogic, ContractSpec, EVMContractSpec,
+		EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch, EVMReceiptLog,
+		EVMTransaction, EVMTransactionReceipt, EventCondition, FunctionCondition, MatchConditions,
+		Monitor, MonitorMatch, Network, TransactionCondition, TransactionStatus,
 	},
 	services::{
 		blockchain::{BlockChainClient, EvmClientTrait},
@@ -454,28 +454,7 @@ impl<T> EVMBlockFilter<T> {
 		expression: &str,
 		args: &[EVMMatchParamEntry],
 	) -> Result<bool, EvaluationError> {
-		// Check if the expression is empty
-		if expression.trim().is_empty() {
-			tracing::error!("Empty expression provided for evaluation");
-			return Err(EvaluationError::parse_error(
-				"Expression cannot be empty".to_string(),
-				None,
-				None,
-			));
-		}
-
-		let evaluator = EVMConditionEvaluator::new(args);
-
-		// Parse the expression
-		let parsed_ast = expression::parse(expression).map_err(|e| {
-			tracing::error!("Failed to parse expression '{}': {}", expression, e);
-			let msg = format!("Failed to parse expression '{}': {}", expression, e);
-			EvaluationError::parse_error(msg, None, None)
-		})?;
-		tracing::debug!("Parsed AST for '{}': {:?}", expression, parsed_ast);
-
-		// Evaluate the expression
-		expression::evaluate(&parsed_ast, &evaluator)
+		expression::evaluate_expression(expression, &EVMConditionEvaluator::new(args))
 	}
 
 	/// Decodes event logs using the provided ABI.
@@ -810,6 +789,12 @@ impl<T: BlockChainClient + EvmClientTrait> BlockFilter for EVMBlockFilter<T> {
 									.into_iter()
 									.filter(|_| has_transaction_match)
 									.collect(),
+								accounts: vec![],
+								balance_changes: vec![],
+								logs: vec![],
+								sequences: vec![],
+								blocks: vec![],
+								condition_logic: ConditionLogic::All,
 							},
 							matched_on_args: Some(EVMMatchArguments {
 								events: if has_event_match {
@@ -868,6 +853,12 @@ mod tests {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			})
 			.addresses_with_spec(
 				addresses
@@ -936,6 +927,7 @@ mod tests {
 		AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: spec,
+			match_conditions: None,
 		}
 	}
 
@@ -1872,6 +1864,12 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			})
 			.addresses_with_spec(vec![(
 				contract_with_spec.0.clone(),
@@ -18,8 +18,12 @@ pub mod stellar {
 	pub mod helpers;
 }
 pub mod solana {
+	pub mod account_state;
+	pub mod decoded_transaction;
+	pub mod evaluator;
 	pub mod filter;
 	pub mod helpers;
+	pub mod regex_cache;
 }
 
 use async_trait::async_trait;
@@ -30,8 +34,11 @@ use crate::{
 };
 pub use evm::evaluator::{EVMArgs, EVMConditionEvaluator};
 pub use evm::filter::EVMBlockFilter;
-pub use solana::filter::SolanaBlockFilter;
-pub use stellar::filter::StellarBlockFilter;
+pub use solana::decoded_transaction::DecodedTransaction as SolanaDecodedTransaction;
+pub use solana::evaluator::{SolanaArgs, SolanaConditionEvaluator};
+pub use solana::filter::{SolanaBlockFilter, RAW_INSTRUCTION_NAME as SOLANA_RAW_INSTRUCTION_NAME};
+pub use stellar::evaluator::{StellarArgs, StellarConditionEvaluator};
+pub use stellar::filter::{EventMap, StellarBlockFilter};
 
 /// Trait for filtering blockchain data
 ///
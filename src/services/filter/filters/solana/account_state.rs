@@ -0,0 +1,223 @@
+//! Cache of the decoded state of monitored Solana accounts.
+//!
+//! Filters are created for every processed block, so the state observed in the previous
+//! block is kept in a cache shared by all filters. It is used to expose `old_<field>` and
+//! `new_<field>` parameters that allow matching on changes of an account between slots,
+//! and to detect ownership changes of token accounts.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::models::SolanaMatchParamEntry;
+
+/// Parameters of an account state, keyed by account address
+pub type AccountStates = HashMap<Pubkey, Vec<SolanaMatchParamEntry>>;
+
+lazy_static! {
+	static ref SHARED_ACCOUNT_STATES: Arc<AccountStateCache> =
+		Arc::new(AccountStateCache::default());
+}
+
+/// Last observed state of the monitored accounts of each network
+#[derive(Debug, Default)]
+pub struct AccountStateCache {
+	states: RwLock<HashMap<String, AccountStates>>,
+}
+
+impl AccountStateCache {
+	/// Returns the cache shared by all Solana filters
+	pub fn shared() -> Arc<Self> {
+		SHARED_ACCOUNT_STATES.clone()
+	}
+
+	/// Returns the last observed account states of a network
+	pub fn get(&self, network_slug: &str) -> AccountStates {
+		self.states
+			.read()
+			.ok()
+			.and_then(|states| states.get(network_slug).cloned())
+			.unwrap_or_default()
+	}
+
+	/// Records the account states observed in the latest block of a network
+	///
+	/// States of accounts that were not observed are kept.
+	pub fn update(&self, network_slug: &str, observed: AccountStates) {
+		if observed.is_empty() {
+			return;
+		}
+		if let Ok(mut states) = self.states.write() {
+			states
+				.entry(network_slug.to_string())
+				.or_default()
+				.extend(observed);
+		}
+	}
+}
+
+/// Builds the `old_<field>` and `new_<field>` parameters of an account state
+///
+/// Every parameter except the account address is diffed. When no previous state is known,
+/// or a field did not exist in it, the old value is the current value.
+///
+/// # Arguments
+/// * `current` - Parameters of the current account state
+/// * `previous` - Parameters of the previously observed account state
+pub fn state_diff_params(
+	current: &[SolanaMatchParamEntry],
+	previous: Option<&[SolanaMatchParamEntry]>,
+) -> Vec<SolanaMatchParamEntry> {
+	current
+		.iter()
+		.filter(|entry| entry.name != "address")
+		.flat_map(|entry| {
+			let old = previous
+				.and_then(|previous| previous.iter().find(|old| old.name == entry.name))
+				.unwrap_or(entry);
+			[
+				SolanaMatchParamEntry {
+					name: format!("old_{}", entry.name),
+					..old.clone()
+				},
+				SolanaMatchParamEntry {
+					name: format!("new_{}", entry.name),
+					..entry.clone()
+				},
+			]
+		})
+		.collect()
+}
+
+/// Parameters whose change transfers control over the tokens of a token account
+const OWNERSHIP_PARAMS: [&str; 2] = ["token_owner", "delegate"];
+
+/// Parameter holding the close authority of a token account
+const CLOSE_AUTHORITY_PARAM: &str = "close_authority";
+
+/// Returns true if the ownership of an account changed since the previous state
+///
+/// Ownership changed when the token owner or the delegate differ from the previous
+/// state, or when a close authority is set. Without a previous state nothing changed.
+///
+/// # Arguments
+/// * `current` - Parameters of the current account state
+/// * `previous` - Parameters of the previously observed account state
+pub fn ownership_changed(
+	current: &[SolanaMatchParamEntry],
+	previous: Option<&[SolanaMatchParamEntry]>,
+) -> bool {
+	let Some(previous) = previous else {
+		return false;
+	};
+	let value = |params: &[SolanaMatchParamEntry], name: &str| {
+		params
+			.iter()
+			.find(|entry| entry.name == name)
+			.map(|entry| entry.value.clone())
+	};
+
+	let ownership_changed = OWNERSHIP_PARAMS
+		.iter()
+		.any(|name| value(current, name) != value(previous, name));
+	let close_authority = value(current, CLOSE_AUTHORITY_PARAM);
+	let close_authority_set = close_authority.is_some()
+		&& close_authority.as_deref() != Some("null")
+		&& close_authority != value(previous, CLOSE_AUTHORITY_PARAM);
+
+	ownership_changed || close_authority_set
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn entry(name: &str, value: &str) -> SolanaMatchParamEntry {
+		SolanaMatchParamEntry {
+			name: name.to_string(),
+			value: value.to_string(),
+			kind: "u64".to_string(),
+			indexed: false,
+		}
+	}
+
+	#[test]
+	fn test_state_diff_params_with_previous_state() {
+		let current = vec![entry("address", "a"), entry("balance", "10")];
+		let previous = vec![entry("address", "a"), entry("balance", "7")];
+
+		let params = state_diff_params(&current, Some(&previous));
+		assert_eq!(
+			params,
+			vec![entry("old_balance", "7"), entry("new_balance", "10")]
+		);
+	}
+
+	#[test]
+	fn test_state_diff_params_without_previous_state() {
+		let current = vec![entry("balance", "10")];
+
+		let params = state_diff_params(&current, None);
+		assert_eq!(
+			params,
+			vec![entry("old_balance", "10"), entry("new_balance", "10")]
+		);
+	}
+
+	#[test]
+	fn test_ownership_changed() {
+		let state = |owner: &str, delegate: &str, close_authority: &str| {
+			vec![
+				entry("token_owner", owner),
+				entry("delegate", delegate),
+				entry("close_authority", close_authority),
+			]
+		};
+		let previous = state("alice", "null", "null");
+
+		assert!(!ownership_changed(&previous, None));
+		assert!(!ownership_changed(&previous, Some(&previous)));
+		assert!(ownership_changed(
+			&state("mallory", "null", "null"),
+			Some(&previous)
+		));
+		assert!(ownership_changed(
+			&state("alice", "mallory", "null"),
+			Some(&previous)
+		));
+		assert!(ownership_changed(
+			&state("alice", "null", "mallory"),
+			Some(&previous)
+		));
+		// Removing the close authority does not hand over the account
+		assert!(!ownership_changed(
+			&previous,
+			Some(&state("alice", "null", "mallory"))
+		));
+	}
+
+	#[test]
+	fn test_cache_update_keeps_unobserved_accounts() {
+		let cache = AccountStateCache::default();
+		let first = Pubkey::new_unique();
+		let second = Pubkey::new_unique();
+
+		cache.update(
+			"solana_mainnet",
+			HashMap::from([(first, vec![entry("balance", "1")])]),
+		);
+		cache.update(
+			"solana_mainnet",
+			HashMap::from([(second, vec![entry("balance", "2")])]),
+		);
+
+		let states = cache.get("solana_mainnet");
+		assert_eq!(states.len(), 2);
+		assert_eq!(states[&first], vec![entry("balance", "1")]);
+		assert!(cache.get("solana_devnet").is_empty());
+	}
+}
@@ -0,0 +1,111 @@
+//! Instructions of a transaction decoded once for all monitors.
+//!
+//! Every monitor evaluated against a block needs the decoded instructions of each
+//! transaction. The registered decoders do not depend on the monitor, so the instruction
+//! tree of a transaction is flattened and decoded once per block and shared by all
+//! monitors. Only the fallback to the contract spec of a monitor is done per monitor.
+
+use solana_sdk::instruction::Instruction;
+
+use crate::{
+	models::{SolanaNestedInstruction, SolanaTransaction},
+	services::decoders::{DecodedInstruction, DecoderRegistry, InstructionType},
+};
+
+/// Nested instructions of a transaction with their decoding by the registered decoders
+#[derive(Debug)]
+pub struct DecodedTransaction {
+	/// Instructions of the transaction in execution order, without their inner instructions
+	instructions: Vec<SolanaNestedInstruction>,
+	/// Decoding of each instruction, `None` when no registered decoder understands it
+	decoded: Vec<Option<DecodedInstruction<InstructionType>>>,
+}
+
+impl DecodedTransaction {
+	/// Flattens the instruction tree of a transaction and decodes every instruction
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to decode
+	/// * `decoder_registry` - Registry holding the decoders of known programs
+	pub fn new(transaction: &SolanaTransaction, decoder_registry: &DecoderRegistry) -> Self {
+		let instructions = SolanaNestedInstruction::from_transaction(transaction)
+			.into_iter()
+			.flat_map(SolanaNestedInstruction::flatten)
+			.collect::<Vec<_>>();
+		let decoded = instructions
+			.iter()
+			.map(|nested| decoder_registry.decode_instruction(&nested.instruction))
+			.collect();
+
+		Self {
+			instructions,
+			decoded,
+		}
+	}
+
+	/// Returns the instructions in execution order, each top-level instruction being
+	/// followed by the instructions it invoked, along with their decoding
+	pub fn instructions(
+		&self,
+	) -> impl Iterator<
+		Item = (
+			&SolanaNestedInstruction,
+			Option<&DecodedInstruction<InstructionType>>,
+		),
+	> {
+		self.instructions
+			.iter()
+			.zip(self.decoded.iter().map(Option::as_ref))
+	}
+
+	/// Returns the top-level instructions, in transaction order, along with their decoding
+	pub fn top_level_instructions(
+		&self,
+	) -> impl Iterator<Item = (&Instruction, Option<&DecodedInstruction<InstructionType>>)> {
+		self.instructions()
+			.filter(|(nested, _)| nested.metadata.stack_height == 0)
+			.map(|(nested, decoded)| (&nested.instruction, decoded))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::SolanaDecodedInstruction,
+		services::decoders::kamino_lending_decoder::PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID,
+		utils::tests::solana::transaction::TransactionBuilder,
+	};
+	use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+	#[test]
+	fn test_decodes_every_instruction_once() {
+		let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+		data.extend_from_slice(&5000u64.to_le_bytes());
+		let unknown_program = Pubkey::new_unique();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: unknown_program,
+				data: vec![0x01],
+				accounts: vec![],
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data,
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.build();
+
+		let decoded =
+			DecodedTransaction::new(&transaction, &DecoderRegistry::with_builtin_decoders());
+
+		let top_level = decoded.top_level_instructions().collect::<Vec<_>>();
+		assert_eq!(top_level.len(), 2);
+		assert_eq!(top_level[0].0.program_id, unknown_program);
+		assert!(top_level[0].1.is_none());
+		assert_eq!(
+			top_level[1].1.map(|decoded| decoded.program_id),
+			Some(KAMINO_LENDING_PROGRAM_ID)
+		);
+	}
+}
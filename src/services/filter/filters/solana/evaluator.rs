@@ -0,0 +1,851 @@
+//! This module provides the `SolanaConditionEvaluator` struct, which implements
+//! the `ConditionEvaluator` trait for evaluating conditions on decoded Solana instructions.
+
+use std::str::FromStr;
+
+use alloy::primitives::I256;
+use regex::Regex;
+use rust_decimal::Decimal;
+use serde_json::Value as JsonValue;
+
+use super::{helpers, regex_cache::RegexCache};
+use crate::{
+	models::SolanaMatchParamEntry,
+	services::filter::expression::{
+		self, compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
+		LiteralValue,
+	},
+};
+
+pub type SolanaArgs = [SolanaMatchParamEntry];
+
+pub struct SolanaConditionEvaluator<'a> {
+	args: &'a SolanaArgs,
+	regex_cache: Option<&'a RegexCache>,
+}
+
+impl<'a> SolanaConditionEvaluator<'a> {
+	pub fn new(args: &'a SolanaArgs) -> Self {
+		Self {
+			args,
+			regex_cache: None,
+		}
+	}
+
+	/// Uses the given cache for the patterns of the `matches` operator, instead of
+	/// compiling them on every comparison.
+	pub fn with_regex_cache(mut self, regex_cache: &'a RegexCache) -> Self {
+		self.regex_cache = Some(regex_cache);
+		self
+	}
+
+	/// Checks if a value matches a regular expression pattern.
+	///
+	/// Arguments:
+	/// - value: The value to match.
+	/// - pattern: The regular expression pattern.
+	///
+	/// Returns:
+	/// - true if the value matches the pattern, false otherwise.
+	fn is_match(&self, value: &str, pattern: &str) -> Result<bool, EvaluationError> {
+		let regex = match self.regex_cache {
+			Some(cache) => cache.get_or_compile(pattern),
+			None => Regex::new(pattern),
+		}
+		.map_err(|e| {
+			let msg = format!("Failed to compile regular expression '{}'", pattern);
+			EvaluationError::parse_error(msg, Some(e.into()), None)
+		})?;
+
+		Ok(regex.is_match(value))
+	}
+
+	/// Compares two boolean values (true/false) using the specified operator.
+	/// Both sides are parsed case-insensitively, and the right-hand side may also be a
+	/// quoted string (e.g., 'true'), as decoders do not always agree on the casing.
+	///
+	/// Arguments:
+	/// - lhs_str: The left-hand side value as a string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	pub fn compare_boolean(
+		&self,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let parse_bool = |value: &str| value.trim().to_ascii_lowercase().parse::<bool>().ok();
+
+		let Some(left) = parse_bool(lhs_str) else {
+			let msg = format!("Failed to parse bool parameter value: {}", lhs_str);
+			return Err(EvaluationError::parse_error(msg, None, None));
+		};
+
+		let right = match rhs_literal {
+			LiteralValue::Bool(b) => Some(*b),
+			LiteralValue::Str(s) => parse_bool(s),
+			_ => None,
+		};
+		let Some(right) = right else {
+			let msg = format!(
+				"Expected bool literal for comparison, found: {:?}",
+				rhs_literal
+			);
+			return Err(EvaluationError::type_mismatch(msg, None, None));
+		};
+
+		match operator {
+			ComparisonOperator::Eq => Ok(left == right),
+			ComparisonOperator::Ne => Ok(left != right),
+			_ => {
+				let msg = format!(
+					"Unsupported operator {:?} for Solana bool comparison",
+					operator
+				);
+				Err(EvaluationError::unsupported_operator(msg, None, None))
+			}
+		}
+	}
+
+	/// Compares an integer value with a number literal using the specified operator.
+	/// Both sides are compared as 256-bit signed integers, so values of any Solana integer
+	/// kind (up to u128/i128) compare correctly against negative or wider literals.
+	/// Literals with a fractional part (e.g., 1.5) are compared as decimals.
+	/// A value that is not an integer does not match.
+	///
+	/// Arguments:
+	/// - lhs_kind: The kind of the left-hand side value.
+	/// - lhs_str: The left-hand side value as a string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	fn compare_integer(
+		&self,
+		lhs_kind: &str,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let LiteralValue::Number(rhs_str) = rhs_literal else {
+			let msg = format!(
+				"Expected number literal for {} comparison, found: {:?}",
+				lhs_kind, rhs_literal
+			);
+			return Err(EvaluationError::type_mismatch(msg, None, None));
+		};
+
+		let Some(left) = parse_integer(lhs_str) else {
+			tracing::debug!(
+				"Failed to parse {} parameter value '{}', treating as no match",
+				lhs_kind,
+				lhs_str
+			);
+			return Ok(false);
+		};
+
+		if let Some(right) = parse_integer(rhs_str) {
+			return compare_ordered_values(&left, operator, &right);
+		}
+
+		let right = parse_decimal_literal(rhs_str)?;
+		let left = Decimal::from_str(&left.to_string()).map_err(|e| {
+			let msg = format!(
+				"Value '{}' is out of range for comparison with '{}'",
+				lhs_str, rhs_str
+			);
+			EvaluationError::type_mismatch(msg, Some(e.into()), None)
+		})?;
+
+		compare_ordered_values(&left, operator, &right)
+	}
+
+	/// Compares a floating point value as a Decimal with a number literal.
+	///
+	/// Arguments:
+	/// - lhs_str: The left-hand side value as a string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	fn compare_decimal(
+		&self,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let Ok(left) = Decimal::from_str(lhs_str) else {
+			tracing::debug!(
+				"Failed to parse decimal parameter value '{}', treating as no match",
+				lhs_str
+			);
+			return Ok(false);
+		};
+
+		let right = match rhs_literal {
+			LiteralValue::Number(s) => parse_decimal_literal(s)?,
+			_ => {
+				let msg = format!(
+					"Expected number literal for decimal comparison, found: {:?}",
+					rhs_literal
+				);
+				return Err(EvaluationError::type_mismatch(msg, None, None));
+			}
+		};
+
+		compare_ordered_values(&left, operator, &right)
+	}
+
+	/// Compares an absent value ("null") with a literal value.
+	/// The value only equals the unquoted or quoted `null` literal, and never satisfies
+	/// an ordering or pattern operator.
+	///
+	/// Arguments:
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	pub fn compare_null(
+		&self,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let is_null = matches!(rhs_literal, LiteralValue::Str(s) if s.eq_ignore_ascii_case("null"));
+		match operator {
+			ComparisonOperator::Eq => Ok(is_null),
+			ComparisonOperator::Ne => Ok(!is_null),
+			_ => Ok(false),
+		}
+	}
+
+	/// Compares two strings (string/pubkey/bytes) using the specified operator.
+	/// Public keys are normalized to their base58 form and, as base58 is case-sensitive,
+	/// compared case-sensitively. Other kinds are compared case-insensitively.
+	/// Regular expressions of the `matches` operator are applied to the original value,
+	/// case-insensitive matching can be enabled with the `(?i)` flag.
+	///
+	/// Arguments:
+	/// - lhs_kind: The kind of the left-hand side value.
+	/// - lhs_str: The left-hand side value as a string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	pub fn compare_string(
+		&self,
+		lhs_kind: &str,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let right_str = match rhs_literal {
+			LiteralValue::Str(s) => *s,
+			_ => {
+				let msg = format!(
+					"Expected string literal for {} comparison, found: {:?}",
+					lhs_kind, rhs_literal
+				);
+				return Err(EvaluationError::type_mismatch(msg, None, None));
+			}
+		};
+
+		if *operator == ComparisonOperator::Matches {
+			return self.is_match(lhs_str, right_str);
+		}
+
+		let (left, right) = if lhs_kind == "pubkey" {
+			(
+				helpers::normalize_pubkey(lhs_str),
+				helpers::normalize_pubkey(right_str),
+			)
+		} else {
+			(lhs_str.to_lowercase(), right_str.to_lowercase())
+		};
+
+		match operator {
+			ComparisonOperator::Eq => Ok(left == right),
+			ComparisonOperator::Ne => Ok(left != right),
+			ComparisonOperator::StartsWith => Ok(left.starts_with(&right)),
+			ComparisonOperator::EndsWith => Ok(left.ends_with(&right)),
+			ComparisonOperator::Contains => Ok(left.contains(&right)),
+			_ => {
+				let msg = format!(
+					"Operator {:?} not supported for type {}",
+					operator, lhs_kind
+				);
+				Err(EvaluationError::unsupported_operator(msg, None, None))
+			}
+		}
+	}
+
+	/// Compares a JSON collection ("vec" or "map") with a literal value.
+	/// Supports "Eq", "Ne" (semantic JSON equality) and "Contains" (any element or
+	/// field value matching the literal).
+	///
+	/// Arguments:
+	/// - lhs_kind: The kind of the left-hand side value.
+	/// - lhs_str: The left-hand side value as a JSON string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	///
+	/// Returns:
+	/// - true if the comparison is true, false otherwise.
+	pub fn compare_collection(
+		&self,
+		lhs_kind: &str,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		let rhs_str = match rhs_literal {
+			LiteralValue::Str(s) | LiteralValue::Number(s) => *s,
+			_ => {
+				let msg = format!(
+					"Expected string or number literal for '{}' comparison, found: {:?}",
+					lhs_kind, rhs_literal
+				);
+				return Err(EvaluationError::type_mismatch(msg, None, None));
+			}
+		};
+
+		let lhs_json = serde_json::from_str::<JsonValue>(lhs_str).map_err(|e| {
+			let msg = format!("Failed to parse LHS value '{}' as JSON", lhs_str);
+			EvaluationError::parse_error(msg, Some(e.into()), None)
+		})?;
+
+		match operator {
+			ComparisonOperator::Eq | ComparisonOperator::Ne => {
+				let rhs_json = serde_json::from_str::<JsonValue>(rhs_str).map_err(|e| {
+					let msg = format!("Failed to parse RHS value '{}' as JSON", rhs_str);
+					EvaluationError::parse_error(msg, Some(e.into()), None)
+				})?;
+				let are_equal = lhs_json == rhs_json;
+				Ok(if *operator == ComparisonOperator::Eq {
+					are_equal
+				} else {
+					!are_equal
+				})
+			}
+			ComparisonOperator::Contains => {
+				let matches = |value: &JsonValue| match value {
+					JsonValue::String(s) => s == rhs_str,
+					_ => serde_json::from_str::<JsonValue>(rhs_str).is_ok_and(|rhs| rhs == *value),
+				};
+				Ok(match &lhs_json {
+					JsonValue::Array(items) => items.iter().any(matches),
+					JsonValue::Object(map) => map.values().any(matches),
+					other => matches(other),
+				})
+			}
+			_ => {
+				let msg = format!(
+					"Operator {:?} not supported for '{}' type. Supported: Eq, Ne, Contains.",
+					operator, lhs_kind
+				);
+				Err(EvaluationError::unsupported_operator(msg, None, None))
+			}
+		}
+	}
+}
+
+/// Parses a decimal integer as a 256-bit signed integer
+/// Returns None for empty or non-integer values, which would otherwise parse as zero
+fn parse_integer(value: &str) -> Option<I256> {
+	let trimmed = value.trim();
+	if trimmed.is_empty() {
+		return None;
+	}
+	I256::from_dec_str(trimmed).ok()
+}
+
+/// Parses a number literal (e.g., "-5", "1.5") as a Decimal
+fn parse_decimal_literal(value: &str) -> Result<Decimal, EvaluationError> {
+	Decimal::from_str(value).map_err(|e| {
+		let msg = format!("Failed to parse RHS value '{}' as Decimal", value);
+		EvaluationError::parse_error(msg, Some(e.into()), None)
+	})
+}
+
+impl ConditionEvaluator for SolanaConditionEvaluator<'_> {
+	/// This method is used to get the base parameter of the Solana condition evaluator.
+	///
+	/// Arguments:
+	/// - name: The name of the parameter to get.
+	///
+	/// Returns:
+	/// - The base parameter.
+	fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError> {
+		expression::get_base_param(self.args, name)
+	}
+
+	/// This method is used to get the kind of the value from the JSON value.
+	///
+	/// Arguments:
+	/// - value: The JSON value to get the kind from.
+	///
+	/// Returns:
+	/// - The kind of the value.
+	fn get_kind_from_json_value(&self, value: &JsonValue) -> String {
+		helpers::get_kind_from_value(value)
+	}
+
+	/// This method is used to compare the final values of the Solana condition evaluator.
+	///
+	/// Arguments:
+	/// - lhs_kind: The kind of the left-hand side value.
+	/// - lhs_str: The left-hand side value as a string.
+	/// - operator: The operator to use for the comparison.
+	/// - rhs_literal: The right-hand side value.
+	fn compare_final_values(
+		&self,
+		lhs_kind: &str,
+		lhs_str: &str,
+		operator: &ComparisonOperator,
+		rhs_literal: &LiteralValue<'_>,
+	) -> Result<bool, EvaluationError> {
+		match lhs_kind.to_lowercase().as_str() {
+			"bool" => self.compare_boolean(lhs_str, operator, rhs_literal),
+			"u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128" => self
+				.compare_integer(
+					lhs_kind.to_ascii_lowercase().as_str(),
+					lhs_str,
+					operator,
+					rhs_literal,
+				),
+			"f32" | "f64" => self.compare_decimal(lhs_str, operator, rhs_literal),
+			"string" | "pubkey" | "bytes" => self.compare_string(
+				lhs_kind.to_ascii_lowercase().as_str(),
+				lhs_str,
+				operator,
+				rhs_literal,
+			),
+			"vec" | "map" => self.compare_collection(
+				lhs_kind.to_ascii_lowercase().as_str(),
+				lhs_str,
+				operator,
+				rhs_literal,
+			),
+			"null" => self.compare_null(operator, rhs_literal),
+			unknown_type => {
+				let msg = format!("Unknown parameter type: {}", unknown_type);
+				Err(EvaluationError::type_mismatch(msg, None, None))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn create_evaluator() -> SolanaConditionEvaluator<'static> {
+		SolanaConditionEvaluator::new(&[])
+	}
+
+	#[test]
+	fn test_compare_bool() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_boolean("true", &ComparisonOperator::Eq, &LiteralValue::Bool(true))
+			.unwrap());
+		assert!(evaluator
+			.compare_boolean("false", &ComparisonOperator::Ne, &LiteralValue::Bool(true))
+			.unwrap());
+		assert!(evaluator
+			.compare_boolean("true", &ComparisonOperator::Gt, &LiteralValue::Bool(true))
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_bool_is_case_insensitive() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"bool",
+				"True",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Bool(true)
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_boolean(
+				"false",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("FALSE")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_boolean("true", &ComparisonOperator::Eq, &LiteralValue::Str("yes"))
+			.is_err());
+		assert!(evaluator
+			.compare_boolean("true", &ComparisonOperator::Eq, &LiteralValue::Number("1"))
+			.is_err());
+		assert!(evaluator
+			.compare_boolean("1", &ComparisonOperator::Eq, &LiteralValue::Bool(true))
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_numeric() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"u64",
+				"1000",
+				&ComparisonOperator::Gt,
+				&LiteralValue::Number("100")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"i64",
+				"-5",
+				&ComparisonOperator::Lt,
+				&LiteralValue::Number("0")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"u128",
+				"340282366920938463463374607431768211455",
+				&ComparisonOperator::Gte,
+				&LiteralValue::Number("18446744073709551616")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"u64",
+				"1000",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("1000")
+			)
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_integer_across_widths() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"u64",
+				"0",
+				&ComparisonOperator::Gt,
+				&LiteralValue::Number("-1")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"u128",
+				"340282366920938463463374607431768211455",
+				&ComparisonOperator::Gt,
+				&LiteralValue::Number("170141183460469231731687303715884105727")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"i128",
+				"-170141183460469231731687303715884105728",
+				&ComparisonOperator::Lt,
+				&LiteralValue::Number("340282366920938463463374607431768211455")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"u64",
+				"1500000000",
+				&ComparisonOperator::Gte,
+				&LiteralValue::Number("1499999999.5")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"u128",
+				"340282366920938463463374607431768211455",
+				&ComparisonOperator::Gt,
+				&LiteralValue::Number("0.5")
+			)
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_negative_signed_integers() {
+		let evaluator = create_evaluator();
+		let compare = |kind, value, operator, literal| {
+			evaluator
+				.compare_final_values(kind, value, &operator, &LiteralValue::Number(literal))
+				.unwrap()
+		};
+
+		assert!(compare("i64", "-5", ComparisonOperator::Gt, "-6"));
+		assert!(compare("i64", "-5", ComparisonOperator::Lt, "-4"));
+		assert!(compare("i64", "-5", ComparisonOperator::Lte, "-5"));
+		assert!(compare("i64", "-5", ComparisonOperator::Eq, "-5"));
+		assert!(compare("i64", "-5", ComparisonOperator::Ne, "5"));
+		assert!(!compare("i64", "-1", ComparisonOperator::Gt, "0"));
+		assert!(compare(
+			"i64",
+			"-9223372036854775808",
+			ComparisonOperator::Lt,
+			"9223372036854775807"
+		));
+		assert!(compare("i32", "-2147483648", ComparisonOperator::Lt, "-1"));
+		assert!(compare("i8", "-128", ComparisonOperator::Gte, "-128.5"));
+	}
+
+	#[test]
+	fn test_unparsable_numeric_value_does_not_match() {
+		let evaluator = create_evaluator();
+		for operator in [ComparisonOperator::Lt, ComparisonOperator::Ne] {
+			assert!(!evaluator
+				.compare_final_values("u64", "", &operator, &LiteralValue::Number("10"))
+				.unwrap());
+			assert!(!evaluator
+				.compare_final_values("f64", "n/a", &operator, &LiteralValue::Number("1.5"))
+				.unwrap());
+		}
+		assert!(evaluator
+			.compare_final_values(
+				"u64",
+				"10",
+				&ComparisonOperator::Lt,
+				&LiteralValue::Number("1.2.3")
+			)
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_decimal() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"f64",
+				"1.5",
+				&ComparisonOperator::Gt,
+				&LiteralValue::Number("1.25")
+			)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_compare_pubkey_is_case_sensitive() {
+		let evaluator = create_evaluator();
+		let key = "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD";
+		assert!(evaluator
+			.compare_final_values(
+				"pubkey",
+				key,
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str(key)
+			)
+			.unwrap());
+		assert!(!evaluator
+			.compare_final_values(
+				"pubkey",
+				key,
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str(&key.to_lowercase())
+			)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_compare_pubkey_is_normalized() {
+		let evaluator = create_evaluator();
+		let key = "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD";
+		let hex_key = format!(
+			"0x{}",
+			hex::encode(key.parse::<solana_sdk::pubkey::Pubkey>().unwrap())
+		);
+		for literal in [format!("  {}\t", key), format!("\"{}\"", key), hex_key] {
+			assert!(evaluator
+				.compare_final_values(
+					"pubkey",
+					key,
+					&ComparisonOperator::Eq,
+					&LiteralValue::Str(&literal)
+				)
+				.unwrap());
+		}
+		assert!(evaluator
+			.compare_final_values(
+				"pubkey",
+				&format!("{}\n", key),
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str(key)
+			)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_compare_string_is_case_insensitive() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"string",
+				"Hello World",
+				&ComparisonOperator::Contains,
+				&LiteralValue::Str("hello")
+			)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_compare_string_matches_regex() {
+		let cache = RegexCache::default();
+		let evaluator = create_evaluator().with_regex_cache(&cache);
+		let memo = "Payout #1042 to treasury";
+		assert!(evaluator
+			.compare_final_values(
+				"string",
+				memo,
+				&ComparisonOperator::Matches,
+				&LiteralValue::Str(r"^Payout #\d+ ")
+			)
+			.unwrap());
+		// Patterns are applied to the original value
+		assert!(!evaluator
+			.compare_final_values(
+				"string",
+				memo,
+				&ComparisonOperator::Matches,
+				&LiteralValue::Str("^payout")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"string",
+				memo,
+				&ComparisonOperator::Matches,
+				&LiteralValue::Str("(?i)^payout")
+			)
+			.unwrap());
+		assert_eq!(cache.len(), 3);
+
+		assert!(matches!(
+			create_evaluator().compare_final_values(
+				"pubkey",
+				"So11111111111111111111111111111111111111112",
+				&ComparisonOperator::Matches,
+				&LiteralValue::Str("[")
+			),
+			Err(EvaluationError::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_compare_collection() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"vec",
+				"[1,2,3]",
+				&ComparisonOperator::Contains,
+				&LiteralValue::Number("2")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"vec",
+				"[1,2,3]",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Str("[1, 2, 3]")
+			)
+			.unwrap());
+		assert!(evaluator
+			.compare_final_values(
+				"map",
+				r#"{"mode":"Fixed"}"#,
+				&ComparisonOperator::Contains,
+				&LiteralValue::Str("Fixed")
+			)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_get_base_param() {
+		let args = vec![SolanaMatchParamEntry {
+			name: "amount".to_string(),
+			value: "42".to_string(),
+			kind: "u64".to_string(),
+			indexed: false,
+		}];
+		let evaluator = SolanaConditionEvaluator::new(&args);
+		assert_eq!(evaluator.get_base_param("amount").unwrap(), ("42", "u64"));
+		assert!(evaluator.get_base_param("missing").is_err());
+	}
+
+	#[test]
+	fn test_unknown_kind() {
+		let evaluator = create_evaluator();
+		assert!(evaluator
+			.compare_final_values(
+				"unknown",
+				"1",
+				&ComparisonOperator::Eq,
+				&LiteralValue::Number("1")
+			)
+			.is_err());
+	}
+
+	#[test]
+	fn test_compare_null() {
+		let evaluator = create_evaluator();
+		let compare = |operator, literal| {
+			evaluator
+				.compare_final_values("null", "null", &operator, &literal)
+				.unwrap()
+		};
+		assert!(compare(ComparisonOperator::Eq, LiteralValue::Str("null")));
+		assert!(compare(ComparisonOperator::Eq, LiteralValue::Str("NULL")));
+		assert!(!compare(ComparisonOperator::Ne, LiteralValue::Str("null")));
+		assert!(!compare(ComparisonOperator::Eq, LiteralValue::Number("0")));
+		assert!(compare(ComparisonOperator::Ne, LiteralValue::Number("0")));
+		assert!(!compare(ComparisonOperator::Gt, LiteralValue::Number("0")));
+		assert!(!compare(
+			ComparisonOperator::Contains,
+			LiteralValue::Str("n")
+		));
+	}
+
+	#[test]
+	fn test_inferred_kinds_are_comparable() {
+		let evaluator = create_evaluator();
+		let values = [
+			json!(true),
+			json!(42),
+			json!(-42),
+			json!(1.5),
+			json!("text"),
+			json!("So11111111111111111111111111111111111111112"),
+			json!("0xdeadbeef"),
+			json!("340282366920938463463374607431768211455"),
+			json!("-170141183460469231731687303715884105728"),
+			json!([1, 2]),
+			json!({ "a": 1 }),
+			json!(null),
+		];
+
+		for value in values {
+			let entry = helpers::value_to_param_entry("field", &value);
+			let result = evaluator.compare_final_values(
+				&entry.kind,
+				&entry.value,
+				&ComparisonOperator::Ne,
+				&LiteralValue::Str("null"),
+			);
+			assert!(
+				!matches!(&result, Err(e) if e.to_string().contains("Unknown parameter type")),
+				"kind '{}' is not understood by the evaluator",
+				entry.kind
+			);
+		}
+	}
+}
@@ -1,37 +1,2140 @@
+//! Solana blockchain filter implementation for processing and matching program instructions.
+//!
+//! This module provides functionality to:
+//! - Decode the instructions of monitored programs
+//! - Convert decoded instructions into match parameters
+//! - Filter and match Solana transactions against monitor conditions
+//! - Match the state of monitored accounts against account conditions
+//! - Match the token balance changes of transactions against balance change conditions
+//! - Match the log messages of transactions against log conditions
+//! - Expose the native SOL balance changes of transactions to transaction conditions
+//! - Evaluate complex matching expressions
+
+use std::{
+	collections::{BTreeMap, HashMap, HashSet},
+	marker::PhantomData,
+	sync::Arc,
+};
+
 use async_trait::async_trait;
-use solana_client::rpc_client::RpcClient;
+use lazy_static::lazy_static;
+use rayon::prelude::*;
+use regex::Regex;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use solana_account::Account;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Signature};
+use tracing::instrument;
 
 use crate::{
-	models::{BlockType, ContractSpec, Monitor, MonitorMatch, Network},
-	services::filter::error::FilterError,
-	services::filter::filters::BlockFilter,
+	models::{
+		AccountCondition, AccountConditionMode, AddressMatchConditions, AddressWithSpec,
+		BalanceChangeCondition, BlockType, ConditionLogic, ContractSpec, EventCondition,
+		FunctionCondition, LogCondition, MatchConditions, Monitor, MonitorMatch, Network,
+		SequenceCondition, SolanaBlock, SolanaContractSpec, SolanaEnhancedTransaction,
+		SolanaMatchArguments, SolanaMatchParamEntry, SolanaMatchParamsMap,
+		SolanaMatchedInstruction, SolanaMonitorMatch, SolanaTransaction, SolanaTransactionMetadata,
+		SolanaTransactionTokenBalance, TransactionCondition, TransactionStatus,
+	},
+	services::{
+		blockchain::SolanaClientTrait,
+		decoders::{
+			anchor_event, bpf_upgradeable_loader,
+			compute_budget::ComputeBudget,
+			idl::{anchor_instruction_discriminator, IdlInstruction},
+			AccountType, CustomInstruction, DecodedAccount, DecodedEvent, DecodedInstruction,
+			DecoderRegistry, InstructionType,
+		},
+		filter::{
+			expression::{EvaluationError, ExpressionCache},
+			filters::solana::{
+				account_state::{
+					ownership_changed, state_diff_params, AccountStateCache, AccountStates,
+				},
+				decoded_transaction::DecodedTransaction,
+				evaluator::SolanaConditionEvaluator,
+				regex_cache::RegexCache,
+			},
+			solana_helpers::{
+				are_same_instruction, args_to_param_entries, normalize_pubkey, parse_pubkey,
+				value_to_param_entry,
+			},
+			BlockFilter, FilterError,
+		},
+	},
+	utils::metrics::SOLANA_TRANSACTION_ERRORS,
 };
 
-use super::helpers::SolanaFilterHelpers;
+/// Prefix of the native SOL balance change parameters
+const SOL_DELTA_PARAM_PREFIX: &str = "sol_delta_";
+
+/// Signature of the instructions of monitored programs that no decoder understands
+pub const RAW_INSTRUCTION_NAME: &str = "raw";
+
+lazy_static! {
+	/// Matches the native SOL balance change parameters referenced in an expression
+	static ref SOL_DELTA_PARAM: Regex = Regex::new(r"\bsol_delta_[1-9A-HJ-NP-Za-km-z]{32,44}\b")
+		.expect("Invalid sol_delta parameter regex");
+}
+
+/// Implementation of the block filter for Solana blockchain
+pub struct SolanaBlockFilter<T> {
+	pub _client: PhantomData<T>,
+	/// Registry used to decode the instructions of monitored programs
+	pub decoder_registry: Arc<DecoderRegistry>,
+	/// States of the monitored accounts observed in previous blocks
+	pub account_states: Arc<AccountStateCache>,
+	/// Compiled patterns of the `matches` operator used by monitor expressions
+	pub regex_cache: Arc<RegexCache>,
+	/// Parsed monitor expressions
+	pub expression_cache: Arc<ExpressionCache>,
+}
 
-/// Solana-specific block filter implementation
-pub struct SolanaBlockFilter {
-	helpers: SolanaFilterHelpers,
+impl<T> Default for SolanaBlockFilter<T> {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-impl SolanaBlockFilter {
+impl<T> SolanaBlockFilter<T> {
+	/// Creates a new filter using the built-in decoders
 	pub fn new() -> Self {
+		Self::with_decoder_registry(Arc::new(DecoderRegistry::with_builtin_decoders()))
+	}
+
+	/// Creates a new filter using the given decoder registry
+	pub fn with_decoder_registry(decoder_registry: Arc<DecoderRegistry>) -> Self {
 		Self {
-			helpers: SolanaFilterHelpers::new(),
+			_client: PhantomData,
+			decoder_registry,
+			account_states: AccountStateCache::shared(),
+			regex_cache: RegexCache::shared(),
+			expression_cache: ExpressionCache::shared(),
+		}
+	}
+
+	/// Returns a filter sharing the decoders and caches of this one, without the client type,
+	/// so that it can be moved to a blocking thread
+	fn detached(&self) -> SolanaBlockFilter<()> {
+		SolanaBlockFilter {
+			_client: PhantomData,
+			decoder_registry: self.decoder_registry.clone(),
+			account_states: self.account_states.clone(),
+			regex_cache: self.regex_cache.clone(),
+			expression_cache: self.expression_cache.clone(),
+		}
+	}
+
+	/// Matches the transactions of a block against each monitor
+	///
+	/// Instructions are decoded once and shared by all monitors. Transactions are decoded
+	/// and evaluated in parallel on the current rayon pool, so this must not run on an
+	/// async worker.
+	///
+	/// # Arguments
+	/// * `transactions` - The transactions of the block
+	/// * `monitors` - The monitors to match
+	/// * `contract_specs` - The IDL specs of the monitored programs
+	/// * `network_slug` - The slug of the network of the block
+	/// * `enhanced` - The enhanced transactions fetched for the block, by signature
+	///
+	/// # Returns
+	/// The transaction matches of each monitor, in the order of the monitors and of the
+	/// transactions
+	pub fn match_transactions(
+		&self,
+		transactions: &[SolanaTransaction],
+		monitors: &[Monitor],
+		contract_specs: &[(String, SolanaContractSpec)],
+		network_slug: &str,
+		enhanced: &HashMap<Signature, SolanaEnhancedTransaction>,
+	) -> Vec<Vec<MonitorMatch>>
+	where
+		T: Sync,
+	{
+		let decoded_transactions = transactions
+			.par_iter()
+			.map(|transaction| DecodedTransaction::new(transaction, &self.decoder_registry))
+			.collect::<Vec<_>>();
+
+		monitors
+			.iter()
+			.map(|monitor| {
+				transactions
+					.par_iter()
+					.zip(decoded_transactions.par_iter())
+					.filter_map(|(transaction, decoded_transaction)| {
+						self.match_decoded_transaction(
+							transaction,
+							decoded_transaction,
+							monitor,
+							contract_specs,
+							network_slug,
+							enhanced.get(transaction.signature()),
+						)
+					})
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Creates the match parameters for a decoded instruction
+	///
+	/// The instruction name becomes the signature, and each decoded argument becomes a
+	/// parameter that can be referenced in expressions. Accounts with a known name are
+	/// exposed as `pubkey` parameters, unless a decoded argument already uses that name.
+	/// Loan terms of lending instructions are exposed as flat `principal_lamports`, `apy`
+	/// and `duration` parameters.
+	/// The program id is always exposed as the `program_id` parameter, and the accounts of
+	/// the instruction, in order, as the `accounts` parameter, whose entries carry the
+	/// `pubkey`, `is_signer` and `is_writable` of each account.
+	///
+	/// # Arguments
+	/// * `decoded` - The decoded instruction
+	///
+	/// # Returns
+	/// The match parameters, or `None` if the instruction type carries no decoded data
+	pub fn create_match_params(
+		&self,
+		decoded: &DecodedInstruction<InstructionType>,
+	) -> Option<SolanaMatchParamsMap> {
+		let serialized = match &decoded.data {
+			InstructionType::Custom(custom) => {
+				Ok(json!({ custom.name.clone(): custom.args.clone() }))
+			}
+			InstructionType::AddressLookupTable(instruction) => serde_json::to_value(instruction),
+			InstructionType::BpfUpgradeableLoader(instruction) => serde_json::to_value(instruction),
+			InstructionType::KaminoLendingInstruction(instruction) => {
+				serde_json::to_value(instruction)
+			}
+			InstructionType::NameService(instruction) => serde_json::to_value(instruction),
+			InstructionType::OKXDex(instruction) => serde_json::to_value(instruction),
+			InstructionType::Sharky(instruction) => serde_json::to_value(instruction),
+			InstructionType::Squads(instruction) => serde_json::to_value(instruction),
+			InstructionType::TokenProgram(instruction) => serde_json::to_value(instruction),
+			_ => return None,
+		};
+
+		// Instructions serialize as `{ "<Variant>": { <fields> } }`
+		let (name, args) = match serialized {
+			Ok(Value::Object(map)) => map.into_iter().next()?,
+			Ok(Value::String(name)) => (name, Value::Null),
+			Ok(_) => return None,
+			Err(e) => {
+				tracing::error!("Failed to serialize decoded instruction: {}", e);
+				return None;
+			}
+		};
+
+		let mut params = vec![SolanaMatchParamEntry {
+			name: "program_id".to_string(),
+			value: decoded.program_id.to_string(),
+			kind: "pubkey".to_string(),
+			indexed: false,
+		}];
+		params.extend(args_to_param_entries(&args));
+
+		// Flattened loan terms replace the nested arguments they were extracted from
+		let loan_terms = decoded.data.loan_terms();
+		if !loan_terms.is_empty() {
+			let terms = serde_json::to_value(&loan_terms).unwrap_or(Value::Null);
+			for entry in args_to_param_entries(&terms) {
+				params.retain(|param| param.name != entry.name);
+				params.push(entry);
+			}
+		}
+
+		let account_params = decoded
+			.data
+			.account_names()
+			.into_iter()
+			.zip(&decoded.accounts)
+			.filter(|(name, _)| !params.iter().any(|entry| entry.name == *name))
+			.map(|(name, account)| SolanaMatchParamEntry {
+				name: name.to_string(),
+				value: account.pubkey.to_string(),
+				kind: "pubkey".to_string(),
+				indexed: false,
+			})
+			.collect::<Vec<_>>();
+		params.extend(account_params);
+
+		if !params.iter().any(|entry| entry.name == "accounts") {
+			let account_metas = decoded
+				.accounts
+				.iter()
+				.map(|account| {
+					json!({
+						"pubkey": account.pubkey.to_string(),
+						"is_signer": account.is_signer,
+						"is_writable": account.is_writable,
+					})
+				})
+				.collect::<Vec<_>>();
+			params.push(value_to_param_entry(
+				"accounts",
+				&Value::Array(account_metas),
+			));
+		}
+
+		Some(SolanaMatchParamsMap {
+			signature: name,
+			args: Some(params),
+			hex_signature: None,
+		})
+	}
+
+	/// Decodes the instructions of a transaction that target monitored programs
+	///
+	/// Instructions of the BPF Upgradeable Loader acting on a monitored program (e.g.
+	/// `Upgrade` or `SetAuthority`) are decoded as well, so monitors are notified when the
+	/// code or the upgrade authority of the program changes.
+	///
+	/// Instructions that no decoder understands are kept as `raw` instructions exposing
+	/// their `data` as hex bytes, so they can still be matched by discriminator. When the
+	/// monitor matches all addresses, every instruction is decoded.
+	///
+	/// When the contract spec of a program declares instruction names, or holds a decoded
+	/// instruction, decoded instructions of other variants are skipped.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to decode
+	/// * `monitor` - The monitor containing the monitored program addresses
+	/// * `contract_specs` - Contract specs for the monitored programs
+	///
+	/// # Returns
+	/// The decoded instructions, in transaction order
+	pub fn decode_monitored_instructions(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+	) -> Vec<DecodedInstruction<InstructionType>> {
+		self.decode_monitored_instructions_with_enhanced(transaction, monitor, contract_specs, None)
+	}
+
+	/// Decodes the instructions of a transaction that target monitored programs, using the
+	/// parse of an enhanced transactions API first
+	///
+	/// When the enhanced API classified the transaction, each monitored instruction is named
+	/// after the transaction type (e.g. `SWAP`) and exposes the `type`, `source`,
+	/// `description`, `native_transfers`, `token_transfers` and `events` of the parse.
+	/// Otherwise, instructions are decoded as in `decode_monitored_instructions`.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to decode
+	/// * `monitor` - The monitor containing the monitored program addresses
+	/// * `contract_specs` - Contract specs for the monitored programs
+	/// * `enhanced` - The transaction parsed by an enhanced transactions API, if any
+	///
+	/// # Returns
+	/// The decoded instructions, in transaction order
+	pub fn decode_monitored_instructions_with_enhanced(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		enhanced: Option<&SolanaEnhancedTransaction>,
+	) -> Vec<DecodedInstruction<InstructionType>> {
+		let decoded_transaction = DecodedTransaction::new(transaction, &self.decoder_registry);
+		self.decode_indexed_instructions(&decoded_transaction, monitor, contract_specs, enhanced)
+			.into_iter()
+			.map(|(_, decoded)| decoded)
+			.collect()
+	}
+
+	/// Decodes the instructions of a transaction that target monitored programs, along
+	/// with their index in the transaction
+	///
+	/// See `decode_monitored_instructions_with_enhanced`.
+	fn decode_indexed_instructions(
+		&self,
+		decoded_transaction: &DecodedTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		enhanced: Option<&SolanaEnhancedTransaction>,
+	) -> Vec<(usize, DecodedInstruction<InstructionType>)> {
+		let enhanced = enhanced.filter(|enhanced| enhanced.is_classified());
+
+		decoded_transaction
+			.top_level_instructions()
+			.enumerate()
+			.filter_map(|(index, (instruction, registry_decoded))| {
+				let program_id = instruction.program_id.to_string();
+
+				let address = monitor.addresses.iter().find(|addr| {
+					normalize_pubkey(&addr.address) == program_id
+						|| (instruction.program_id == bpf_upgradeable_loader::PROGRAM_ID
+							&& parse_pubkey(&addr.address).is_some_and(|program| {
+								bpf_upgradeable_loader::targets_program(instruction, &program)
+							}))
+				});
+				if address.is_none() && !monitor.matches_all_addresses() {
+					return None;
+				}
+
+				let contract_spec = find_contract_spec(address, contract_specs, &program_id);
+
+				if let Some(enhanced) = enhanced {
+					return Some((
+						index,
+						DecodedInstruction {
+							program_id: instruction.program_id,
+							data: InstructionType::Custom(CustomInstruction {
+								name: enhanced.type_.clone(),
+								args: enhanced.args(),
+								accounts: Vec::new(),
+							}),
+							accounts: instruction.accounts.clone(),
+						},
+					));
+				}
+
+				let Some(decoded) = decode_with_spec(instruction, registry_decoded, contract_spec)
+				else {
+					return Some((index, raw_instruction(instruction)));
+				};
+
+				// Loader instructions acting on a monitored program are not restricted by
+				// the instruction names of its contract spec
+				let instruction_names = contract_spec
+					.filter(|_| {
+						address
+							.is_none_or(|address| normalize_pubkey(&address.address) == program_id)
+					})
+					.and_then(SolanaContractSpec::instruction_names);
+				match instruction_names {
+					Some(names) => {
+						let name = decoded.data.variant_name()?;
+						names
+							.iter()
+							.any(|expected| are_same_instruction(expected, &name))
+							.then_some((index, decoded))
+					}
+					None => Some((index, decoded)),
+				}
+			})
+			.collect()
+	}
+
+	/// Decodes the events emitted by monitored programs in a transaction
+	///
+	/// Events logged as `Program data:` lines and events emitted through self-CPI
+	/// instructions are decoded with the Anchor IDL of the contract spec of the program
+	/// that emitted them. Events of programs without an IDL are skipped. When the monitor
+	/// matches all addresses, the events of every program with an IDL are decoded.
+	///
+	/// # Arguments
+	/// * `transaction` - The transaction to decode
+	/// * `monitor` - The monitor containing the monitored program addresses
+	/// * `contract_specs` - Contract specs for the monitored programs
+	///
+	/// # Returns
+	/// The decoded events, logged events first
+	pub fn decode_monitored_events(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+	) -> Vec<DecodedEvent> {
+		anchor_event::extract_events(transaction)
+			.iter()
+			.filter_map(|event| {
+				let program_id = event.program_id.to_string();
+				let address = monitor
+					.addresses
+					.iter()
+					.find(|addr| normalize_pubkey(&addr.address) == program_id);
+				if address.is_none() && !monitor.matches_all_addresses() {
+					return None;
+				}
+				let contract_spec = find_contract_spec(address, contract_specs, &program_id);
+				self.decoder_registry
+					.decode_event_with_spec(event, contract_spec)
+			})
+			.collect()
+	}
+
+	/// Creates the native SOL balance change parameters of a transaction
+	///
+	/// The lamport delta of each account of the transaction is exposed as a
+	/// `sol_delta_<account>` parameter, fees included. Accounts referenced in the expression
+	/// that are not part of the transaction get a delta of 0.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction
+	/// * `expression` - The expression the parameters are evaluated against
+	pub fn create_sol_delta_params(
+		&self,
+		transaction: &SolanaTransaction,
+		expression: &str,
+	) -> Vec<SolanaMatchParamEntry> {
+		let meta = &transaction.metadata.meta;
+		let mut params = transaction
+			.account_keys()
+			.into_iter()
+			.zip(meta.pre_balances.iter().zip(&meta.post_balances))
+			.map(|(account, (pre, post))| SolanaMatchParamEntry {
+				name: format!("{}{}", SOL_DELTA_PARAM_PREFIX, account),
+				value: (i128::from(*post) - i128::from(*pre)).to_string(),
+				kind: "i128".to_string(),
+				indexed: false,
+			})
+			.collect::<Vec<_>>();
+
+		for name in SOL_DELTA_PARAM.find_iter(expression).map(|m| m.as_str()) {
+			if !params.iter().any(|param| param.name == name) {
+				params.push(SolanaMatchParamEntry {
+					name: name.to_string(),
+					value: "0".to_string(),
+					kind: "i128".to_string(),
+					indexed: false,
+				});
+			}
+		}
+
+		params
+	}
+
+	/// Creates the transaction-level match parameters of a transaction
+	///
+	/// The `signature`, `slot`, `epoch`, `fee_payer`, `fee` (in lamports), `num_signers`,
+	/// `num_instructions`, `compute_unit_price` (in micro-lamports), `compute_unit_limit` and
+	/// `priority_fee_lamports` parameters are always exposed, along with `block_time` and
+	/// `compute_units_consumed` when they are known. The compute unit price is 0 and the
+	/// limit takes its default value when the transaction does not set them.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction
+	pub fn create_transaction_params(
+		&self,
+		transaction: &SolanaTransaction,
+	) -> Vec<SolanaMatchParamEntry> {
+		let compute_budget = ComputeBudget::from_instructions(transaction.instructions());
+		let mut params = vec![
+			SolanaMatchParamEntry {
+				name: "signature".to_string(),
+				value: transaction.signature().to_string(),
+				kind: "string".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "slot".to_string(),
+				value: transaction.slot().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "epoch".to_string(),
+				value: transaction.epoch().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "fee_payer".to_string(),
+				value: transaction.metadata.fee_payer.to_string(),
+				kind: "pubkey".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "fee".to_string(),
+				value: transaction.meta().fee.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "num_signers".to_string(),
+				value: transaction
+					.message()
+					.header()
+					.num_required_signatures
+					.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "num_instructions".to_string(),
+				value: transaction.instructions().len().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "compute_unit_price".to_string(),
+				value: compute_budget
+					.compute_unit_price
+					.unwrap_or_default()
+					.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "compute_unit_limit".to_string(),
+				value: compute_budget.effective_compute_unit_limit().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "priority_fee_lamports".to_string(),
+				value: compute_budget.priority_fee_lamports().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+		];
+		if let Some(block_time) = transaction.block_time() {
+			params.push(SolanaMatchParamEntry {
+				name: "block_time".to_string(),
+				value: block_time.to_string(),
+				kind: "i64".to_string(),
+				indexed: false,
+			});
+		}
+		if let Some(compute_units_consumed) = transaction.meta().compute_units_consumed {
+			params.push(SolanaMatchParamEntry {
+				name: "compute_units_consumed".to_string(),
+				value: compute_units_consumed.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			});
+		}
+
+		params
+	}
+
+	/// Finds matching transactions based on monitor conditions
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction to check
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_transactions` - Vector to store matching transactions
+	pub fn find_matching_transaction(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_transactions: &mut Vec<TransactionCondition>,
+	) {
+		let tx_status = if transaction.metadata.meta.status.is_ok() {
+			TransactionStatus::Success
+		} else {
+			TransactionStatus::Failure
+		};
+
+		if monitor.match_conditions.transactions.is_empty() {
+			matched_transactions.push(TransactionCondition {
+				expression: None,
+				status: TransactionStatus::Any,
+			});
+			return;
+		}
+
+		let tx_params = self.create_transaction_params(transaction);
+
+		for condition in &monitor.match_conditions.transactions {
+			let status_matches = match &condition.status {
+				TransactionStatus::Any => true,
+				required_status => *required_status == tx_status,
+			};
+
+			if !status_matches {
+				continue;
+			}
+
+			match &condition.expression {
+				Some(expr) => match self.evaluate_expression(
+					expr,
+					&[
+						tx_params.as_slice(),
+						&self.create_sol_delta_params(transaction, expr),
+					]
+					.concat(),
+				) {
+					Ok(true) => matched_transactions.push(condition.clone()),
+					Ok(false) => continue,
+					Err(e) => {
+						tracing::error!("Failed to evaluate expression '{}': {}", expr, e);
+						continue;
+					}
+				},
+				None => matched_transactions.push(condition.clone()),
+			}
+		}
+	}
+
+	/// Creates the match parameters for the token balance changes of a transaction
+	///
+	/// Pre and post token balances are paired by account index and mint. Each non-zero
+	/// change exposes the `account`, `mint`, `owner`, `delta` and `ui_delta` parameters,
+	/// where `delta` is in base units and `ui_delta` is adjusted by the mint decimals.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction
+	///
+	/// # Returns
+	/// The match parameters of each balance change, with the mint as signature
+	pub fn create_balance_change_params(
+		&self,
+		transaction: &SolanaTransaction,
+	) -> Vec<SolanaMatchParamsMap> {
+		let meta = &transaction.metadata.meta;
+		let mut balances =
+			BTreeMap::<(u8, &str), [Option<&SolanaTransactionTokenBalance>; 2]>::new();
+		for balance in meta.pre_token_balances.iter().flatten() {
+			balances
+				.entry((balance.account_index, balance.mint.as_str()))
+				.or_default()[0] = Some(balance);
+		}
+		for balance in meta.post_token_balances.iter().flatten() {
+			balances
+				.entry((balance.account_index, balance.mint.as_str()))
+				.or_default()[1] = Some(balance);
+		}
+
+		let account_keys = transaction.account_keys();
+
+		let amount = |balance: Option<&SolanaTransactionTokenBalance>| {
+			balance
+				.and_then(|balance| balance.ui_token_amount.amount.parse::<i128>().ok())
+				.unwrap_or_default()
+		};
+
+		balances
+			.into_iter()
+			.filter_map(|((account_index, mint), [pre, post])| {
+				let delta = amount(post) - amount(pre);
+				if delta == 0 {
+					return None;
+				}
+
+				let balance = post.or(pre)?;
+				let owner = post
+					.map(|balance| balance.owner.as_str())
+					.filter(|owner| !owner.is_empty())
+					.or(pre.map(|balance| balance.owner.as_str()))
+					.unwrap_or_default();
+				let ui_delta = Decimal::try_from_i128_with_scale(
+					delta,
+					u32::from(balance.ui_token_amount.decimals),
+				)
+				.map(|ui_delta| ui_delta.normalize().to_string())
+				.ok()?;
+
+				let mut params = Vec::new();
+				if let Some(account) = account_keys.get(usize::from(account_index)) {
+					params.push(SolanaMatchParamEntry {
+						name: "account".to_string(),
+						value: account.to_string(),
+						kind: "pubkey".to_string(),
+						indexed: false,
+					});
+				}
+				params.extend([
+					SolanaMatchParamEntry {
+						name: "mint".to_string(),
+						value: mint.to_string(),
+						kind: "pubkey".to_string(),
+						indexed: false,
+					},
+					SolanaMatchParamEntry {
+						name: "owner".to_string(),
+						value: owner.to_string(),
+						kind: "pubkey".to_string(),
+						indexed: false,
+					},
+					SolanaMatchParamEntry {
+						name: "delta".to_string(),
+						value: delta.to_string(),
+						kind: "i128".to_string(),
+						indexed: false,
+					},
+					SolanaMatchParamEntry {
+						name: "ui_delta".to_string(),
+						value: ui_delta,
+						kind: "f64".to_string(),
+						indexed: false,
+					},
+				]);
+
+				Some(SolanaMatchParamsMap {
+					signature: mint.to_string(),
+					args: Some(params),
+					hex_signature: None,
+				})
+			})
+			.collect()
+	}
+
+	/// Finds matching token balance changes within a transaction
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction to check
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_balance_changes` - Vector to store matching balance change conditions
+	/// * `matched_on_args` - Balance changes that matched the conditions
+	pub fn find_matching_balance_changes(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_balance_changes: &mut Vec<BalanceChangeCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		if monitor.match_conditions.balance_changes.is_empty() {
+			return;
+		}
+
+		for params in self.create_balance_change_params(transaction) {
+			let param_entries = params.args.clone().unwrap_or_default();
+			let conditions = monitor
+				.match_conditions
+				.balance_changes
+				.iter()
+				.filter(|condition| {
+					self.is_condition_match(condition.expression.as_deref(), &param_entries)
+				})
+				.collect::<Vec<_>>();
+			if conditions.is_empty() {
+				continue;
+			}
+
+			for condition in conditions {
+				if !matched_balance_changes.contains(condition) {
+					matched_balance_changes.push(condition.clone());
+				}
+			}
+			matched_on_args
+				.balance_changes
+				.get_or_insert_with(Vec::new)
+				.push(params);
+		}
+	}
+
+	/// Creates match parameters from the log messages of a transaction
+	///
+	/// Each log message exposes the `log` parameter, along with the `program_id` of the
+	/// program that logged it when known.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction
+	///
+	/// # Returns
+	/// The match parameters of each log message, in order
+	pub fn create_log_params(&self, transaction: &SolanaTransaction) -> Vec<SolanaMatchParamsMap> {
+		transaction
+			.program_logs()
+			.into_iter()
+			.map(|log| {
+				let mut params = vec![SolanaMatchParamEntry {
+					name: "log".to_string(),
+					value: log.message.to_string(),
+					kind: "string".to_string(),
+					indexed: false,
+				}];
+				if let Some(program_id) = log.program_id {
+					params.push(SolanaMatchParamEntry {
+						name: "program_id".to_string(),
+						value: program_id.to_string(),
+						kind: "pubkey".to_string(),
+						indexed: false,
+					});
+				}
+
+				SolanaMatchParamsMap {
+					signature: "log".to_string(),
+					args: Some(params),
+					hex_signature: None,
+				}
+			})
+			.collect()
+	}
+
+	/// Finds matching log messages within a transaction
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction to check
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_logs` - Vector to store matching log conditions
+	/// * `matched_on_args` - Log messages that matched the conditions
+	pub fn find_matching_logs(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		matched_logs: &mut Vec<LogCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		if monitor.match_conditions.logs.is_empty() {
+			return;
+		}
+
+		for params in self.create_log_params(transaction) {
+			let param_entries = params.args.clone().unwrap_or_default();
+			let conditions = monitor
+				.match_conditions
+				.logs
+				.iter()
+				.filter(|condition| {
+					self.is_condition_match(condition.expression.as_deref(), &param_entries)
+				})
+				.collect::<Vec<_>>();
+			if conditions.is_empty() {
+				continue;
+			}
+
+			for condition in conditions {
+				if !matched_logs.contains(condition) {
+					matched_logs.push(condition.clone());
+				}
+			}
+			matched_on_args
+				.logs
+				.get_or_insert_with(Vec::new)
+				.push(params);
+		}
+	}
+
+	/// Finds instruction sequences matching within a transaction
+	///
+	/// Every instruction of the transaction, including the ones invoked through CPIs, is
+	/// decoded in execution order. Instructions of monitored programs are decoded with their
+	/// contract spec, and instructions that no decoder understands are kept as `raw`
+	/// instructions. A sequence matches when each of its steps matches an instruction
+	/// executed after the one matching the previous step.
+	///
+	/// # Arguments
+	/// * `decoded_transaction` - The Solana transaction to check, decoded
+	/// * `monitor` - The monitor containing match conditions
+	/// * `contract_specs` - Contract specs used to decode the instructions
+	/// * `matched_sequences` - Vector to store matching sequence conditions
+	/// * `matched_instructions` - Vector to store the instructions matching the steps
+	/// * `matched_on_args` - Instructions matching the steps of the sequences
+	pub fn find_matching_sequences(
+		&self,
+		decoded_transaction: &DecodedTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		matched_sequences: &mut Vec<SequenceCondition>,
+		matched_instructions: &mut Vec<SolanaMatchedInstruction>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		if monitor.match_conditions.sequences.is_empty() {
+			return;
+		}
+
+		let instruction_params = decoded_transaction
+			.instructions()
+			.filter_map(|(nested, registry_decoded)| {
+				let instruction = &nested.instruction;
+				let program_id = instruction.program_id.to_string();
+				let address = monitor
+					.addresses
+					.iter()
+					.find(|addr| normalize_pubkey(&addr.address) == program_id);
+				let contract_spec = find_contract_spec(address, contract_specs, &program_id);
+
+				let decoded = decode_with_spec(instruction, registry_decoded, contract_spec)
+					.unwrap_or_else(|| raw_instruction(instruction));
+				let params = self.create_match_params(&decoded)?;
+				let location = SolanaMatchedInstruction {
+					instruction_index: nested.metadata.instruction_index,
+					stack_height: nested.metadata.stack_height,
+					program_id: instruction.program_id,
+					data: instruction.data.clone(),
+				};
+				Some((location, params))
+			})
+			.collect::<Vec<_>>();
+
+		for condition in &monitor.match_conditions.sequences {
+			let mut steps = condition.steps.iter().peekable();
+			let mut step_params = Vec::new();
+			let mut step_instructions = Vec::new();
+			for (location, params) in &instruction_params {
+				let Some(step) = steps.peek() else {
+					break;
+				};
+				let param_entries = params.args.as_deref().unwrap_or_default();
+				if are_same_instruction(&step.signature, &params.signature)
+					&& self.is_condition_match(step.expression.as_deref(), param_entries)
+				{
+					step_params.push(params.clone());
+					step_instructions.push(location);
+					steps.next();
+				}
+			}
+			if steps.peek().is_some() {
+				continue;
+			}
+
+			if !matched_sequences.contains(condition) {
+				matched_sequences.push(condition.clone());
+			}
+			for location in step_instructions {
+				if !matched_instructions.contains(location) {
+					matched_instructions.push(location.clone());
+				}
+			}
+			matched_on_args
+				.sequences
+				.get_or_insert_with(Vec::new)
+				.extend(step_params);
+		}
+	}
+
+	/// Finds matching instructions within a transaction
+	///
+	/// Instructions are matched against the function conditions of the monitor and the ones
+	/// scoped to the address of their program. Every matching instruction is collected, and
+	/// every condition matching at least one of them is recorded once.
+	///
+	/// # Arguments
+	/// * `decoded_instructions` - Decoded instructions of the monitored programs
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_functions` - Vector to store matching instructions
+	/// * `matched_on_args` - Arguments that matched the conditions
+	pub fn find_matching_functions_for_transaction(
+		&self,
+		decoded_instructions: &[DecodedInstruction<InstructionType>],
+		monitor: &Monitor,
+		matched_functions: &mut Vec<FunctionCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		for decoded in decoded_instructions {
+			let Some(params) = self.create_match_params(decoded) else {
+				continue;
+			};
+			let param_entries = params.args.clone().unwrap_or_default();
+
+			if !has_function_conditions(monitor) {
+				// Match on all instructions
+				matched_functions.push(FunctionCondition {
+					signature: params.signature.clone(),
+					expression: None,
+				});
+				if let Some(instructions) = &mut matched_on_args.instructions {
+					instructions.push(params);
+				}
+				continue;
+			}
+
+			let program_id = decoded.program_id.to_string();
+			let scoped_conditions = find_address_conditions(monitor, &program_id);
+			let conditions = monitor
+				.match_conditions
+				.functions
+				.iter()
+				.chain(
+					scoped_conditions
+						.into_iter()
+						.flat_map(|conditions| &conditions.functions),
+				)
+				.filter(|condition| {
+					are_same_instruction(&condition.signature, &params.signature)
+						&& self.is_condition_match(condition.expression.as_deref(), &param_entries)
+				})
+				.collect::<Vec<_>>();
+			if conditions.is_empty() {
+				continue;
+			}
+
+			for condition in conditions {
+				if !matched_functions.contains(condition) {
+					matched_functions.push(condition.clone());
+				}
+			}
+			if let Some(instructions) = &mut matched_on_args.instructions {
+				instructions.push(params);
+			}
+		}
+	}
+
+	/// Creates the match parameters for a decoded event
+	///
+	/// The event name becomes the signature, and each decoded field becomes a parameter
+	/// that can be referenced in expressions. The program that emitted the event is exposed
+	/// as the `program_id` parameter.
+	///
+	/// # Arguments
+	/// * `event` - The decoded event
+	pub fn create_event_match_params(&self, event: &DecodedEvent) -> SolanaMatchParamsMap {
+		let mut params = vec![SolanaMatchParamEntry {
+			name: "program_id".to_string(),
+			value: event.program_id.to_string(),
+			kind: "pubkey".to_string(),
+			indexed: false,
+		}];
+		params.extend(
+			args_to_param_entries(&event.data)
+				.into_iter()
+				.filter(|entry| entry.name != "program_id"),
+		);
+
+		SolanaMatchParamsMap {
+			signature: event.name.clone(),
+			args: Some(params),
+			hex_signature: None,
+		}
+	}
+
+	/// Finds matching events within a transaction
+	///
+	/// Events are matched against the event conditions of the monitor and the ones scoped
+	/// to the address of the program that emitted them. Every matching event is collected,
+	/// and every condition matching at least one of them is recorded once.
+	///
+	/// # Arguments
+	/// * `decoded_events` - Decoded events of the monitored programs
+	/// * `monitor` - The monitor containing match conditions
+	/// * `matched_events` - Vector to store matching events
+	/// * `matched_on_args` - Arguments that matched the conditions
+	pub fn find_matching_events_for_transaction(
+		&self,
+		decoded_events: &[DecodedEvent],
+		monitor: &Monitor,
+		matched_events: &mut Vec<EventCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		for event in decoded_events {
+			let params = self.create_event_match_params(event);
+			let param_entries = params.args.clone().unwrap_or_default();
+
+			let program_id = event.program_id.to_string();
+			let scoped_conditions = find_address_conditions(monitor, &program_id);
+			let conditions = monitor
+				.match_conditions
+				.events
+				.iter()
+				.chain(
+					scoped_conditions
+						.into_iter()
+						.flat_map(|conditions| &conditions.events),
+				)
+				.filter(|condition| {
+					are_same_instruction(&condition.signature, &params.signature)
+						&& self.is_condition_match(condition.expression.as_deref(), &param_entries)
+				})
+				.collect::<Vec<_>>();
+			if conditions.is_empty() {
+				continue;
+			}
+
+			for condition in conditions {
+				if !matched_events.contains(condition) {
+					matched_events.push(condition.clone());
+				}
+			}
+			matched_on_args
+				.events
+				.get_or_insert_with(Vec::new)
+				.push(params);
+		}
+	}
+
+	/// Creates the match parameters for a decoded account
+	///
+	/// The account name becomes the signature, and each decoded field becomes a
+	/// parameter that can be referenced in expressions. The `address`, `owner`,
+	/// `lamports` and `executable` parameters are always exposed.
+	///
+	/// # Arguments
+	/// * `address` - The address of the account
+	/// * `decoded` - The decoded account
+	///
+	/// # Returns
+	/// The match parameters, or `None` if the account state cannot be serialized
+	pub fn create_account_match_params(
+		&self,
+		address: &Pubkey,
+		decoded: &DecodedAccount<AccountType>,
+	) -> Option<SolanaMatchParamsMap> {
+		let serialized = match &decoded.data {
+			AccountType::Custom(custom) => Ok(json!({ custom.name.clone(): custom.data.clone() })),
+			AccountType::KaminoLendingAccount(account) => serde_json::to_value(account),
+			AccountType::Squads(account) => serde_json::to_value(account),
+			account_type => serde_json::to_value(account_type),
+		};
+
+		// Accounts serialize as `{ "<Variant>": { <fields> } }`
+		let (name, data) = match serialized {
+			Ok(Value::Object(map)) => map.into_iter().next()?,
+			Ok(Value::String(name)) => (name, Value::Null),
+			Ok(_) => return None,
+			Err(e) => {
+				tracing::error!("Failed to serialize decoded account: {}", e);
+				return None;
+			}
+		};
+
+		let mut params = vec![
+			SolanaMatchParamEntry {
+				name: "address".to_string(),
+				value: address.to_string(),
+				kind: "pubkey".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "owner".to_string(),
+				value: decoded.owner.to_string(),
+				kind: "pubkey".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "lamports".to_string(),
+				value: decoded.lamports.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "executable".to_string(),
+				value: decoded.executable.to_string(),
+				kind: "bool".to_string(),
+				indexed: false,
+			},
+		];
+		let fields = args_to_param_entries(&data)
+			.into_iter()
+			.filter(|entry| !params.iter().any(|param| param.name == entry.name))
+			.collect::<Vec<_>>();
+		params.extend(fields);
+
+		Some(SolanaMatchParamsMap {
+			signature: name,
+			args: Some(params),
+			hex_signature: None,
+		})
+	}
+
+	/// Finds the account states matching the account conditions of a monitor
+	///
+	/// Besides the fields of the current state, `old_<field>` and `new_<field>` parameters
+	/// are exposed to compare the state against the one observed in a previous block.
+	///
+	/// # Arguments
+	/// * `accounts` - The fetched account states, keyed by address
+	/// * `monitor` - The monitor containing match conditions
+	/// * `contract_specs` - Contract specs used to decode accounts of custom programs
+	/// * `previous_states` - Account states observed in a previous block
+	/// * `current_states` - Map to store the decoded account states
+	/// * `matched_accounts` - Vector to store matching account conditions
+	/// * `matched_on_args` - Account states that matched the conditions
+	#[allow(clippy::too_many_arguments)]
+	pub fn find_matching_accounts(
+		&self,
+		accounts: &HashMap<Pubkey, Account>,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		previous_states: &AccountStates,
+		current_states: &mut AccountStates,
+		matched_accounts: &mut Vec<AccountCondition>,
+		matched_on_args: &mut SolanaMatchArguments,
+	) {
+		for condition in &monitor.match_conditions.accounts {
+			let Some(address) = parse_pubkey(&condition.address) else {
+				continue;
+			};
+			let Some(account) = accounts.get(&address) else {
+				continue;
+			};
+
+			let owner = account.owner.to_string();
+			let contract_spec = monitor
+				.addresses
+				.iter()
+				.find(|addr| normalize_pubkey(&addr.address) == owner)
+				.and_then(|addr| match &addr.contract_spec {
+					Some(ContractSpec::Solana(spec)) => Some(spec),
+					_ => None,
+				})
+				.or_else(|| {
+					contract_specs
+						.iter()
+						.find(|(address, _)| normalize_pubkey(address) == owner)
+						.map(|(_, spec)| spec)
+				});
+
+			let Some(decoded) = self
+				.decoder_registry
+				.decode_account_with_spec(account, contract_spec)
+			else {
+				tracing::debug!("No decoder found for account {}", condition.address);
+				continue;
+			};
+			let Some(mut params) = self.create_account_match_params(&address, &decoded) else {
+				continue;
+			};
+			// The contract spec of the account address restricts the variants it may decode as
+			let account_names = monitor
+				.addresses
+				.iter()
+				.find(|addr| parse_pubkey(&addr.address) == Some(address))
+				.and_then(|addr| match &addr.contract_spec {
+					Some(ContractSpec::Solana(spec)) => spec.account_names(),
+					_ => None,
+				});
+			if account_names.is_some_and(|names| {
+				!names
+					.iter()
+					.any(|name| are_same_instruction(name, &params.signature))
+			}) {
+				tracing::debug!(
+					"Account {} is not of the type of its contract spec",
+					condition.address
+				);
+				continue;
+			}
+			let state = params.args.clone().unwrap_or_default();
+			let previous_state = previous_states.get(&address).map(Vec::as_slice);
+			let mode_matches = match condition.mode {
+				AccountConditionMode::State => true,
+				AccountConditionMode::OwnershipChange => ownership_changed(&state, previous_state),
+			};
+			let diff = state_diff_params(&state, previous_state);
+			params.args.get_or_insert_with(Vec::new).extend(diff);
+			current_states.insert(address, state);
+
+			if !mode_matches {
+				continue;
+			}
+
+			let is_match = match &condition.expression {
+				Some(expr) => {
+					match self.evaluate_expression(expr, params.args.as_deref().unwrap_or(&[])) {
+						Ok(result) => result,
+						Err(e) => {
+							tracing::error!("Failed to evaluate expression '{}': {}", expr, e);
+							false
+						}
+					}
+				}
+				None => true,
+			};
+
+			if is_match {
+				matched_accounts.push(condition.clone());
+				matched_on_args
+					.accounts
+					.get_or_insert_with(Vec::new)
+					.push(params);
+			}
+		}
+	}
+
+	/// Matches a transaction against the instruction, event, transaction, balance change and
+	/// log conditions of a monitor
+	///
+	/// Transactions signed by an excluded address of the monitor never match.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction to check
+	/// * `monitor` - The monitor containing match conditions
+	/// * `contract_specs` - Contract specs used to decode the instructions
+	/// * `network_slug` - Slug of the network the transaction belongs to
+	/// * `enhanced` - The transaction parsed by an enhanced transactions API, if any
+	///
+	/// # Returns
+	/// The monitor match, or `None` if the transaction does not satisfy the conditions
+	pub fn match_transaction(
+		&self,
+		transaction: &SolanaTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		network_slug: &str,
+		enhanced: Option<&SolanaEnhancedTransaction>,
+	) -> Option<MonitorMatch> {
+		let decoded_transaction = DecodedTransaction::new(transaction, &self.decoder_registry);
+		self.match_decoded_transaction(
+			transaction,
+			&decoded_transaction,
+			monitor,
+			contract_specs,
+			network_slug,
+			enhanced,
+		)
+	}
+
+	/// Matches a transaction whose instructions were already decoded against the
+	/// conditions of a monitor
+	///
+	/// Filters decode the transactions of a block once and match them against every
+	/// monitor. See `match_transaction`.
+	///
+	/// # Arguments
+	/// * `transaction` - The Solana transaction to check
+	/// * `decoded_transaction` - The instructions of the transaction, decoded
+	/// * `monitor` - The monitor containing match conditions
+	/// * `contract_specs` - Contract specs used to decode the instructions
+	/// * `network_slug` - Slug of the network the transaction belongs to
+	/// * `enhanced` - The transaction parsed by an enhanced transactions API, if any
+	///
+	/// # Returns
+	/// The monitor match, or `None` if the transaction does not satisfy the conditions
+	pub fn match_decoded_transaction(
+		&self,
+		transaction: &SolanaTransaction,
+		decoded_transaction: &DecodedTransaction,
+		monitor: &Monitor,
+		contract_specs: &[(String, SolanaContractSpec)],
+		network_slug: &str,
+		enhanced: Option<&SolanaEnhancedTransaction>,
+	) -> Option<MonitorMatch> {
+		if is_signed_by_excluded_address(transaction, monitor) {
+			return None;
+		}
+
+		let mut matched_transactions = Vec::<TransactionCondition>::new();
+		let mut matched_functions = Vec::<FunctionCondition>::new();
+		let mut matched_events = Vec::<EventCondition>::new();
+		let mut matched_balance_changes = Vec::<BalanceChangeCondition>::new();
+		let mut matched_logs = Vec::<LogCondition>::new();
+		let mut matched_sequences = Vec::<SequenceCondition>::new();
+		let mut matched_instructions = Vec::<SolanaMatchedInstruction>::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+
+		let decoded_instructions = self.decode_indexed_instructions(
+			decoded_transaction,
+			monitor,
+			contract_specs,
+			enhanced,
+		);
+
+		self.find_matching_transaction(transaction, monitor, &mut matched_transactions);
+
+		// Instructions are matched one at a time to record the location of the matching ones
+		for (index, decoded) in &decoded_instructions {
+			let matched_count = matched_on_args.instructions.as_ref().map_or(0, Vec::len);
+			self.find_matching_functions_for_transaction(
+				std::slice::from_ref(decoded),
+				monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			if matched_on_args.instructions.as_ref().map_or(0, Vec::len) > matched_count {
+				let instruction = &transaction.instructions()[*index];
+				if let Some(params) = matched_on_args
+					.instructions
+					.as_mut()
+					.and_then(|instructions| instructions.last_mut())
+				{
+					let program_id = instruction.program_id.to_string();
+					let address = monitor
+						.addresses
+						.iter()
+						.find(|address| normalize_pubkey(&address.address) == program_id);
+					params.hex_signature = instruction_hex_signature(
+						&params.signature,
+						&instruction.data,
+						find_contract_spec(address, contract_specs, &program_id),
+					);
+				}
+				matched_instructions.push(SolanaMatchedInstruction {
+					instruction_index: *index,
+					stack_height: 0,
+					program_id: instruction.program_id,
+					data: instruction.data.clone(),
+				});
+			}
+		}
+
+		if has_event_conditions(monitor) {
+			let decoded_events = self.decode_monitored_events(transaction, monitor, contract_specs);
+			self.find_matching_events_for_transaction(
+				&decoded_events,
+				monitor,
+				&mut matched_events,
+				&mut matched_on_args,
+			);
+		}
+
+		self.find_matching_balance_changes(
+			transaction,
+			monitor,
+			&mut matched_balance_changes,
+			&mut matched_on_args,
+		);
+
+		self.find_matching_logs(
+			transaction,
+			monitor,
+			&mut matched_logs,
+			&mut matched_on_args,
+		);
+
+		let mut sequence_instructions = Vec::<SolanaMatchedInstruction>::new();
+		self.find_matching_sequences(
+			decoded_transaction,
+			monitor,
+			contract_specs,
+			&mut matched_sequences,
+			&mut sequence_instructions,
+			&mut matched_on_args,
+		);
+
+		let monitor_conditions = &monitor.match_conditions;
+		let has_function_conditions = has_function_conditions(monitor);
+		let has_event_conditions = has_event_conditions(monitor);
+		let has_function_match = has_function_conditions && !matched_functions.is_empty();
+		let has_event_match = has_event_conditions && !matched_events.is_empty();
+		let has_transaction_match =
+			!monitor_conditions.transactions.is_empty() && !matched_transactions.is_empty();
+		let has_balance_change_match =
+			!monitor_conditions.balance_changes.is_empty() && !matched_balance_changes.is_empty();
+
+		let has_log_match = !monitor_conditions.logs.is_empty() && !matched_logs.is_empty();
+		let has_sequence_match =
+			!monitor_conditions.sequences.is_empty() && !matched_sequences.is_empty();
+
+		// Balance change, log and sequence conditions, when defined, must always be satisfied
+		let balance_changes_match =
+			monitor_conditions.balance_changes.is_empty() || has_balance_change_match;
+		let logs_match = monitor_conditions.logs.is_empty() || has_log_match;
+		let sequences_match = monitor_conditions.sequences.is_empty() || has_sequence_match;
+		let has_conditions = has_event_conditions
+			|| has_function_conditions
+			|| !monitor_conditions.transactions.is_empty()
+			|| !monitor_conditions.balance_changes.is_empty()
+			|| !monitor_conditions.logs.is_empty()
+			|| !monitor_conditions.sequences.is_empty();
+
+		let conditions_match = match (
+			!has_event_conditions,
+			!has_function_conditions,
+			monitor_conditions.transactions.is_empty(),
+		) {
+			// Case 1: No event, function or transaction conditions defined, match every
+			// transaction touching a monitored program (any program when matching all
+			// addresses) or with a matching balance change, log message or sequence
+			(true, true, true) => {
+				!decoded_instructions.is_empty()
+					|| has_balance_change_match
+					|| has_log_match
+					|| has_sequence_match
+			}
+
+			// Case 2: Only transaction conditions defined
+			(true, true, false) => has_transaction_match,
+
+			// Case 3: No transaction conditions, match based on events/instructions
+			(_, _, true) => has_event_match || has_function_match,
+
+			// Case 4: Transaction conditions exist, they must be satisfied along
+			// with events/instructions
+			_ => (has_event_match || has_function_match) && has_transaction_match,
+		};
+
+		let should_match = match monitor_conditions.condition_logic {
+			ConditionLogic::All => {
+				balance_changes_match && logs_match && sequences_match && conditions_match
+			}
+			// Any defined condition group matching is enough, falling back to Case 1 when
+			// no condition is defined
+			ConditionLogic::Any => {
+				has_event_match
+					|| has_function_match
+					|| has_transaction_match
+					|| has_balance_change_match
+					|| has_log_match
+					|| has_sequence_match
+					|| (!has_conditions && conditions_match)
+			}
+		};
+
+		if !should_match {
+			return None;
+		}
+
+		// Without function or event conditions, every monitored instruction is part of
+		// the match
+		if !has_function_match && (has_function_conditions || has_event_conditions) {
+			matched_instructions.clear();
+		}
+		for instruction in sequence_instructions {
+			if !matched_instructions.contains(&instruction) {
+				matched_instructions.push(instruction);
+			}
+		}
+
+		Some(MonitorMatch::Solana(Box::new(SolanaMonitorMatch {
+			monitor: monitor.clone(),
+			network_slug: network_slug.to_string(),
+			transaction: transaction.clone(),
+			matched_on: MatchConditions {
+				functions: matched_functions
+					.into_iter()
+					.filter(|_| has_function_match)
+					.collect(),
+				events: matched_events
+					.into_iter()
+					.filter(|_| has_event_match)
+					.collect(),
+				transactions: matched_transactions
+					.into_iter()
+					.filter(|_| has_transaction_match)
+					.collect(),
+				accounts: vec![],
+				balance_changes: matched_balance_changes,
+				logs: matched_logs,
+				sequences: matched_sequences,
+				blocks: vec![],
+				condition_logic: monitor_conditions.condition_logic,
+			},
+			matched_on_args: Some(SolanaMatchArguments {
+				instructions: if has_function_match {
+					matched_on_args.instructions
+				} else {
+					None
+				},
+				accounts: None,
+				balance_changes: matched_on_args.balance_changes,
+				events: if has_event_match {
+					matched_on_args.events
+				} else {
+					None
+				},
+				logs: matched_on_args.logs,
+				sequences: matched_on_args.sequences,
+				blocks: None,
+			}),
+			matched_instructions,
+		})))
+	}
+
+	/// Creates the match parameters of the matches of a monitor within a block
+	///
+	/// The `slot`, the number of transactions of the block as `transaction_count` and the
+	/// number of matches as `match_count` are exposed, along with the `signatures` of the
+	/// matched transactions. Each numeric argument of the matched instructions, events and
+	/// balance changes is summed up as a `total_<name>` parameter, e.g.
+	/// `total_liquidity_amount` or `total_delta`.
+	///
+	/// # Arguments
+	/// * `slot` - Slot of the block
+	/// * `transaction_count` - Number of transactions in the block
+	/// * `matches` - Matches of the monitor within the block
+	pub fn create_block_params(
+		&self,
+		slot: u64,
+		transaction_count: usize,
+		matches: &[SolanaMonitorMatch],
+	) -> SolanaMatchParamsMap {
+		let mut params = vec![
+			SolanaMatchParamEntry {
+				name: "slot".to_string(),
+				value: slot.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "transaction_count".to_string(),
+				value: transaction_count.to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "match_count".to_string(),
+				value: matches.len().to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			value_to_param_entry(
+				"signatures",
+				&json!(matches
+					.iter()
+					.map(|monitor_match| monitor_match.signature().to_string())
+					.collect::<Vec<_>>()),
+			),
+		];
+
+		// Totals are kept in argument order, as floats when any summed value is one
+		let mut totals = Vec::<(String, Decimal, bool)>::new();
+		let entries = matches
+			.iter()
+			.filter_map(|monitor_match| monitor_match.matched_on_args.as_ref())
+			.flat_map(|args| {
+				[&args.instructions, &args.events, &args.balance_changes]
+					.into_iter()
+					.flatten()
+					.flatten()
+			})
+			.flat_map(|params| params.args.iter().flatten());
+		for entry in entries {
+			if !["u64", "i64", "u128", "i128", "f64"].contains(&entry.kind.as_str()) {
+				continue;
+			}
+			let Ok(value) = entry.value.parse::<Decimal>() else {
+				continue;
+			};
+			let is_float = entry.kind == "f64";
+			match totals.iter_mut().find(|(name, _, _)| *name == entry.name) {
+				Some((_, total, total_is_float)) => {
+					*total = total.saturating_add(value);
+					*total_is_float |= is_float;
+				}
+				None => totals.push((entry.name.clone(), value, is_float)),
+			}
+		}
+		params.extend(
+			totals
+				.into_iter()
+				.map(|(name, total, is_float)| SolanaMatchParamEntry {
+					name: format!("total_{}", name),
+					value: total.normalize().to_string(),
+					kind: if is_float { "f64" } else { "i128" }.to_string(),
+					indexed: false,
+				}),
+		);
+
+		SolanaMatchParamsMap {
+			signature: "block".to_string(),
+			args: Some(params),
+			hex_signature: None,
+		}
+	}
+
+	/// Aggregates the transaction matches of a monitor within a block into a single match
+	///
+	/// The block conditions of the monitor are evaluated against the parameters of
+	/// `create_block_params`. When at least one of them matches, the conditions and
+	/// arguments of the transaction matches are merged into a match of the block, which
+	/// carries no transaction signature, like account matches.
+	///
+	/// # Arguments
+	/// * `monitor` - The monitor containing the block conditions
+	/// * `block` - The block the matches belong to
+	/// * `transaction_count` - Number of transactions in the block
+	/// * `matches` - Transaction matches of the monitor within the block
+	/// * `network_slug` - Slug of the network the block belongs to
+	///
+	/// # Returns
+	/// The aggregated match, or `None` if no block condition matches
+	pub fn aggregate_block_matches(
+		&self,
+		monitor: &Monitor,
+		block: &SolanaBlock,
+		transaction_count: usize,
+		matches: &[MonitorMatch],
+		network_slug: &str,
+	) -> Option<MonitorMatch> {
+		let matches = matches
+			.iter()
+			.filter_map(|monitor_match| match monitor_match {
+				MonitorMatch::Solana(solana_match) => Some(solana_match.as_ref().clone()),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		let block_params = self.create_block_params(block.slot, transaction_count, &matches);
+		let param_entries = block_params.args.clone().unwrap_or_default();
+
+		let matched_blocks = monitor
+			.match_conditions
+			.blocks
+			.iter()
+			.filter(|condition| {
+				self.is_condition_match(condition.expression.as_deref(), &param_entries)
+			})
+			.cloned()
+			.collect::<Vec<_>>();
+		if matched_blocks.is_empty() {
+			return None;
+		}
+
+		let mut matched_on = MatchConditions {
+			functions: vec![],
+			events: vec![],
+			transactions: vec![],
+			accounts: vec![],
+			balance_changes: vec![],
+			logs: vec![],
+			sequences: vec![],
+			blocks: matched_blocks,
+			condition_logic: monitor.match_conditions.condition_logic,
+		};
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: None,
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: Some(vec![block_params]),
+		};
+		for monitor_match in &matches {
+			let conditions = &monitor_match.matched_on;
+			extend_unique(&mut matched_on.functions, &conditions.functions);
+			extend_unique(&mut matched_on.events, &conditions.events);
+			extend_unique(&mut matched_on.transactions, &conditions.transactions);
+			extend_unique(&mut matched_on.balance_changes, &conditions.balance_changes);
+			extend_unique(&mut matched_on.logs, &conditions.logs);
+			extend_unique(&mut matched_on.sequences, &conditions.sequences);
+
+			if let Some(args) = &monitor_match.matched_on_args {
+				extend_args(&mut matched_on_args.instructions, &args.instructions);
+				extend_args(&mut matched_on_args.balance_changes, &args.balance_changes);
+				extend_args(&mut matched_on_args.events, &args.events);
+				extend_args(&mut matched_on_args.logs, &args.logs);
+				extend_args(&mut matched_on_args.sequences, &args.sequences);
+			}
+		}
+
+		Some(MonitorMatch::Solana(Box::new(SolanaMonitorMatch {
+			monitor: monitor.clone(),
+			network_slug: network_slug.to_string(),
+			transaction: SolanaTransaction {
+				metadata: SolanaTransactionMetadata {
+					slot: block.slot,
+					signature: Signature::default(),
+					fee_payer: Pubkey::default(),
+					block_time: block.block_time,
+					..Default::default()
+				},
+				instructions: vec![],
+			},
+			matched_on,
+			matched_on_args: Some(matched_on_args),
+			matched_instructions: vec![],
+		})))
+	}
+
+	/// Evaluates a match expression against provided parameters
+	///
+	/// # Arguments
+	/// * `expression` - The expression to evaluate
+	/// * `args` - The parameters to use in evaluation
+	///
+	/// # Returns
+	/// Boolean indicating if the expression evaluates to true
+	pub fn evaluate_expression(
+		&self,
+		expression: &str,
+		args: &[SolanaMatchParamEntry],
+	) -> Result<bool, EvaluationError> {
+		let evaluator = SolanaConditionEvaluator::new(args).with_regex_cache(&self.regex_cache);
+		self.expression_cache.evaluate(expression, &evaluator)
+	}
+
+	/// Returns whether the optional expression of a condition matches the parameters
+	///
+	/// Conditions without an expression always match. Evaluation errors are logged and
+	/// treated as no match.
+	fn is_condition_match(&self, expression: Option<&str>, args: &[SolanaMatchParamEntry]) -> bool {
+		let Some(expression) = expression else {
+			return true;
+		};
+		self.evaluate_expression(expression, args)
+			.unwrap_or_else(|e| {
+				tracing::error!("Failed to evaluate expression '{}': {}", expression, e);
+				false
+			})
+	}
+}
+
+impl<T: SolanaClientTrait + Send + Sync> SolanaBlockFilter<T> {
+	/// Matches the history of the addresses of monitors against their conditions
+	///
+	/// Instead of scanning every block of the slot range, only the transactions involving
+	/// the monitored addresses are fetched. Account conditions describe the current state
+	/// of accounts and are not evaluated. Monitors matching all addresses are only matched
+	/// against the history of the addresses they list.
+	///
+	/// # Arguments
+	/// * `client` - The blockchain client used to fetch the history of the addresses
+	/// * `network` - The network being monitored
+	/// * `monitors` - List of monitors to check against
+	/// * `contract_specs` - List of contract specs to use for decoding instructions
+	/// * `start_slot` - First slot to backfill
+	/// * `end_slot` - Last slot to backfill
+	///
+	/// # Returns
+	/// Result containing the matches of each monitor, oldest first, or a filter error
+	#[instrument(skip_all, fields(network = %network.slug, start_slot, end_slot))]
+	pub async fn backfill(
+		&self,
+		client: &T,
+		network: &Network,
+		monitors: &[Monitor],
+		contract_specs: Option<&[(String, ContractSpec)]>,
+		start_slot: u64,
+		end_slot: u64,
+	) -> Result<Vec<MonitorMatch>, FilterError> {
+		let contract_specs = solana_contract_specs(contract_specs);
+
+		let mut history = HashMap::<Pubkey, Vec<SolanaTransaction>>::new();
+		for monitor in monitors {
+			if monitor.matches_all_addresses() {
+				tracing::warn!(
+					"Monitor {} matches all addresses, backfilling its listed addresses only",
+					monitor.name
+				);
+			}
+			for address in monitor
+				.addresses
+				.iter()
+				.filter_map(|address| parse_pubkey(&address.address))
+			{
+				if history.contains_key(&address) {
+					continue;
+				}
+				let transactions = client
+					.get_transactions_for_address(&address, start_slot, end_slot)
+					.await
+					.map_err(|e| {
+						FilterError::network_error(
+							format!("Failed to fetch the history of {}", address),
+							Some(e.into()),
+							None,
+						)
+					})?;
+				tracing::debug!(
+					"Fetched {} transaction(s) involving {}",
+					transactions.len(),
+					address
+				);
+				history.insert(address, transactions);
+			}
+		}
+
+		let mut matching_results = Vec::new();
+		for monitor in monitors {
+			let mut transactions = monitor
+				.addresses
+				.iter()
+				.filter_map(|address| history.get(&parse_pubkey(&address.address)?))
+				.flatten()
+				.collect::<Vec<_>>();
+			let mut seen = HashSet::new();
+			transactions.retain(|transaction| seen.insert(*transaction.signature()));
+			transactions.sort_by_key(|transaction| transaction.slot());
+
+			let enhanced = self
+				.get_enhanced_transactions(client, monitors, transactions.iter().copied())
+				.await;
+			for transaction in transactions {
+				if let Some(monitor_match) = self.match_transaction(
+					transaction,
+					monitor,
+					&contract_specs,
+					&network.slug,
+					enhanced.get(transaction.signature()),
+				) {
+					matching_results.push(monitor_match);
+				}
+			}
 		}
+
+		Ok(matching_results)
+	}
+
+	/// Fetches the enhanced parse of the transactions that involve a monitored program
+	///
+	/// Failures are logged and yield no parse, so that instructions are decoded with the
+	/// built-in decoders instead.
+	///
+	/// # Arguments
+	/// * `client` - The blockchain client used to fetch the parsed transactions
+	/// * `monitors` - The monitors whose programs are monitored
+	/// * `transactions` - The candidate transactions
+	async fn get_enhanced_transactions<'a>(
+		&self,
+		client: &T,
+		monitors: &[Monitor],
+		transactions: impl IntoIterator<Item = &'a SolanaTransaction>,
+	) -> HashMap<Signature, SolanaEnhancedTransaction> {
+		let monitored_programs = monitors
+			.iter()
+			.flat_map(|monitor| &monitor.addresses)
+			.filter_map(|address| parse_pubkey(&address.address))
+			.collect::<HashSet<_>>();
+		let signatures = transactions
+			.into_iter()
+			.filter(|transaction| {
+				transaction
+					.instructions()
+					.iter()
+					.any(|instruction| monitored_programs.contains(&instruction.program_id))
+			})
+			.map(|transaction| *transaction.signature())
+			.collect::<Vec<_>>();
+
+		client
+			.get_enhanced_transactions(&signatures)
+			.await
+			.unwrap_or_else(|e| {
+				tracing::warn!(
+					"Failed to fetch enhanced transactions, using decoders instead: {}",
+					e
+				);
+				HashMap::new()
+			})
 	}
 }
 
-impl Default for SolanaBlockFilter {
-	fn default() -> Self {
-		Self::new()
+/// Keeps the Solana contract specs
+fn solana_contract_specs(
+	contract_specs: Option<&[(String, ContractSpec)]>,
+) -> Vec<(String, SolanaContractSpec)> {
+	contract_specs
+		.unwrap_or(&[])
+		.iter()
+		.filter_map(|(address, spec)| match spec {
+			ContractSpec::Solana(spec) => Some((address.clone(), spec.clone())),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Decodes an instruction that the registered decoders may already have decoded, falling
+/// back to the contract spec of its program
+fn decode_with_spec(
+	instruction: &Instruction,
+	registry_decoded: Option<&DecodedInstruction<InstructionType>>,
+	contract_spec: Option<&SolanaContractSpec>,
+) -> Option<DecodedInstruction<InstructionType>> {
+	registry_decoded
+		.cloned()
+		.or_else(|| DecoderRegistry::decode_instruction_from_spec(instruction, contract_spec?))
+}
+
+/// Keeps an instruction that no decoder understands, exposing its data as hex bytes
+fn raw_instruction(instruction: &Instruction) -> DecodedInstruction<InstructionType> {
+	DecodedInstruction {
+		program_id: instruction.program_id,
+		data: InstructionType::Custom(CustomInstruction {
+			name: RAW_INSTRUCTION_NAME.to_string(),
+			args: json!({ "data": format!("0x{}", hex::encode(&instruction.data)) }),
+			accounts: Vec::new(),
+		}),
+		accounts: instruction.accounts.clone(),
+	}
+}
+
+/// Returns the discriminator prefixing the data of a matched instruction, as hex
+///
+/// The discriminator declared by the IDL of the program is preferred. Otherwise the Anchor
+/// discriminator derived from the instruction name is used when the data starts with it,
+/// so that instructions of native programs, which have none, are left without.
+fn instruction_hex_signature(
+	name: &str,
+	data: &[u8],
+	contract_spec: Option<&SolanaContractSpec>,
+) -> Option<String> {
+	let discriminator = contract_spec
+		.and_then(SolanaContractSpec::idl)
+		.and_then(|idl| idl.find_instruction(data))
+		.map(IdlInstruction::discriminator)
+		.or_else(|| {
+			Some(anchor_instruction_discriminator(name))
+				.filter(|discriminator| data.starts_with(discriminator))
+		})?;
+	Some(format!("0x{}", hex::encode(discriminator)))
+}
+
+/// Appends the items that are not part of a list yet
+fn extend_unique<T: PartialEq + Clone>(list: &mut Vec<T>, items: &[T]) {
+	for item in items {
+		if !list.contains(item) {
+			list.push(item.clone());
+		}
 	}
 }
 
+/// Appends matched arguments to the ones collected so far
+fn extend_args(
+	args: &mut Option<Vec<SolanaMatchParamsMap>>,
+	items: &Option<Vec<SolanaMatchParamsMap>>,
+) {
+	if let Some(items) = items {
+		args.get_or_insert_with(Vec::new)
+			.extend(items.iter().cloned());
+	}
+}
+
+/// Finds the contract spec of a monitored program, preferring the one of the monitored
+/// address over the contract specs passed to the filter
+fn find_contract_spec<'a>(
+	address: Option<&'a AddressWithSpec>,
+	contract_specs: &'a [(String, SolanaContractSpec)],
+	program_id: &str,
+) -> Option<&'a SolanaContractSpec> {
+	match address.and_then(|address| address.contract_spec.as_ref()) {
+		Some(ContractSpec::Solana(spec)) => Some(spec),
+		_ => contract_specs
+			.iter()
+			.find(|(address, _)| normalize_pubkey(address) == program_id)
+			.map(|(_, spec)| spec),
+	}
+}
+
+/// Finds the conditions scoped to the monitored address of a program
+fn find_address_conditions<'a>(
+	monitor: &'a Monitor,
+	program_id: &str,
+) -> Option<&'a AddressMatchConditions> {
+	monitor
+		.addresses
+		.iter()
+		.find(|address| normalize_pubkey(&address.address) == program_id)?
+		.match_conditions
+		.as_ref()
+}
+
+/// Returns whether a transaction is signed by one of the excluded addresses of a monitor
+fn is_signed_by_excluded_address(transaction: &SolanaTransaction, monitor: &Monitor) -> bool {
+	if monitor.excluded_addresses.is_empty() {
+		return false;
+	}
+
+	let message = transaction.message();
+	let account_keys = message.static_account_keys();
+	let signers = &account_keys
+		[..usize::from(message.header().num_required_signatures).min(account_keys.len())];
+	monitor
+		.excluded_addresses
+		.iter()
+		.filter_map(|address| parse_pubkey(address))
+		.any(|address| signers.contains(&address))
+}
+
+/// Returns whether a monitor has function conditions, scoped to an address or not
+fn has_function_conditions(monitor: &Monitor) -> bool {
+	!monitor.match_conditions.functions.is_empty()
+		|| monitor.addresses.iter().any(|address| {
+			address
+				.match_conditions
+				.as_ref()
+				.is_some_and(|conditions| !conditions.functions.is_empty())
+		})
+}
+
+/// Returns whether a monitor has event conditions, scoped to an address or not
+fn has_event_conditions(monitor: &Monitor) -> bool {
+	!monitor.match_conditions.events.is_empty()
+		|| monitor.addresses.iter().any(|address| {
+			address
+				.match_conditions
+				.as_ref()
+				.is_some_and(|conditions| !conditions.events.is_empty())
+		})
+}
+
 #[async_trait]
-impl BlockFilter for SolanaBlockFilter {
-	type Client = RpcClient;
+impl<T: SolanaClientTrait + Send + Sync> BlockFilter for SolanaBlockFilter<T> {
+	type Client = T;
 
+	/// Filters a Solana block against provided monitors
+	///
+	/// # Arguments
+	/// * `client` - The blockchain client used to fetch monitored accounts
+	/// * `network` - The network being monitored
+	/// * `block` - The block to filter
+	/// * `monitors` - List of monitors to check against
+	/// * `contract_specs` - List of contract specs to use for decoding instructions and accounts
+	///
+	/// # Returns
+	/// Result containing vector of matching monitors or a filter error
+	#[instrument(skip_all, fields(network = %network.slug))]
 	async fn filter_block(
 		&self,
 		client: &Self::Client,
@@ -40,12 +2143,3228 @@ impl BlockFilter for SolanaBlockFilter {
 		monitors: &[Monitor],
 		contract_specs: Option<&[(String, ContractSpec)]>,
 	) -> Result<Vec<MonitorMatch>, FilterError> {
-		// TODO: Implement Solana-specific block filtering logic
-		// This will include:
-		// 1. Transaction filtering
-		// 2. Account state change detection
-		// 3. Program interaction monitoring
-		// 4. Event filtering
-		Ok(Vec::new())
+		let solana_block = match block {
+			BlockType::Solana(block) => block,
+			_ => {
+				return Err(FilterError::block_type_mismatch(
+					"Expected Solana block".to_string(),
+					None,
+					None,
+				));
+			}
+		};
+
+		let contract_specs = solana_contract_specs(contract_specs);
+
+		let transactions = (0..solana_block.transactions.len())
+			.filter_map(|index| match SolanaTransaction::new(solana_block, index)? {
+				Ok(transaction) => Some(transaction),
+				Err(e) => {
+					tracing::warn!(
+						"Skipping transaction {} of slot {}: {}",
+						index,
+						solana_block.slot,
+						e
+					);
+					SOLANA_TRANSACTION_ERRORS
+						.with_label_values(&[&network.slug])
+						.inc();
+					None
+				}
+			})
+			.collect::<Vec<_>>();
+
+		tracing::debug!("Processing {} transaction(s)", transactions.len());
+
+		let mut monitored_accounts = monitors
+			.iter()
+			.flat_map(|monitor| &monitor.match_conditions.accounts)
+			.filter_map(|condition| parse_pubkey(&condition.address))
+			.collect::<Vec<_>>();
+		monitored_accounts.sort();
+		monitored_accounts.dedup();
+
+		let accounts = if monitored_accounts.is_empty() {
+			HashMap::new()
+		} else {
+			let fetched = client
+				.get_multiple_accounts(&monitored_accounts)
+				.await
+				.map_err(|e| {
+					FilterError::network_error(
+						format!(
+							"Failed to fetch {} monitored account(s)",
+							monitored_accounts.len()
+						),
+						Some(e.into()),
+						None,
+					)
+				})?;
+			monitored_accounts
+				.into_iter()
+				.zip(fetched)
+				.filter_map(|(address, account)| Some((address, account?)))
+				.collect::<HashMap<_, _>>()
+		};
+
+		let enhanced = self
+			.get_enhanced_transactions(client, monitors, &transactions)
+			.await;
+
+		// Decoding and matching are CPU heavy, so they run on the rayon pool from a blocking
+		// thread rather than on the async runtime
+		let transaction_count = transactions.len();
+		let transaction_matches = {
+			let filter = self.detached();
+			let monitors = monitors.to_vec();
+			let contract_specs = contract_specs.clone();
+			let network_slug = network.slug.clone();
+			tokio::task::spawn_blocking(move || {
+				filter.match_transactions(
+					&transactions,
+					&monitors,
+					&contract_specs,
+					&network_slug,
+					&enhanced,
+				)
+			})
+		};
+		let mut transaction_matches = transaction_matches.await.map_err(|e| {
+			FilterError::internal_error(
+				format!(
+					"Failed to match the transactions of slot {}",
+					solana_block.slot
+				),
+				Some(e.into()),
+				None,
+			)
+		})?;
+
+		let previous_states = self.account_states.get(&network.slug);
+		let mut current_states = AccountStates::new();
+		let mut matching_results = Vec::new();
+
+		for (index, monitor) in monitors.iter().enumerate() {
+			tracing::debug!("Processing monitor: {}", monitor.name);
+
+			if !monitor.match_conditions.accounts.is_empty() {
+				let mut matched_accounts = Vec::<AccountCondition>::new();
+				let mut matched_on_args = SolanaMatchArguments {
+					instructions: None,
+					accounts: None,
+					balance_changes: None,
+					events: None,
+					logs: None,
+					sequences: None,
+					blocks: None,
+				};
+
+				self.find_matching_accounts(
+					&accounts,
+					monitor,
+					&contract_specs,
+					&previous_states,
+					&mut current_states,
+					&mut matched_accounts,
+					&mut matched_on_args,
+				);
+
+				if !matched_accounts.is_empty() {
+					matching_results.push(MonitorMatch::Solana(Box::new(SolanaMonitorMatch {
+						monitor: monitor.clone(),
+						network_slug: network.slug.clone(),
+						transaction: SolanaTransaction {
+							metadata: SolanaTransactionMetadata {
+								slot: solana_block.slot,
+								signature: Signature::default(),
+								fee_payer: Pubkey::default(),
+								block_time: solana_block.block_time,
+								..Default::default()
+							},
+							instructions: vec![],
+						},
+						matched_on: MatchConditions {
+							functions: vec![],
+							events: vec![],
+							transactions: vec![],
+							accounts: matched_accounts,
+							balance_changes: vec![],
+							logs: vec![],
+							sequences: vec![],
+							blocks: vec![],
+							condition_logic: ConditionLogic::All,
+						},
+						matched_on_args: Some(matched_on_args),
+						matched_instructions: vec![],
+					})));
+				}
+			}
+
+			let transaction_matches = std::mem::take(&mut transaction_matches[index]);
+
+			// Block conditions replace the transaction matches with a single block match
+			if monitor.match_conditions.blocks.is_empty() {
+				matching_results.extend(transaction_matches);
+			} else if let Some(block_match) = self.aggregate_block_matches(
+				monitor,
+				solana_block,
+				transaction_count,
+				&transaction_matches,
+				&network.slug,
+			) {
+				matching_results.push(block_match);
+			}
+		}
+
+		self.account_states.update(&network.slug, current_states);
+
+		Ok(matching_results)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{SolanaDecodedInstruction, SolanaDecoderType, SolanaTransactionStatusMeta},
+		services::decoders::{
+			kamino_lending_decoder::{
+				accounts::{short_url::ShortUrl, KaminoLendingAccount},
+				instructions::{
+					deposit_reserve_liquidity::DepositReserveLiquidity,
+					redeem_reserve_collateral::RedeemReserveCollateral, KaminoLendingInstruction,
+				},
+				PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID,
+			},
+			CustomInstruction,
+		},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+	fn create_test_filter() -> SolanaBlockFilter<()> {
+		SolanaBlockFilter::new()
+	}
+
+	fn create_deposit_data(amount: u64) -> Vec<u8> {
+		let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+		data.extend_from_slice(&amount.to_le_bytes());
+		data
+	}
+
+	fn create_deposit_transaction(amount: u64) -> SolanaTransaction {
+		TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(amount),
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.build()
+	}
+
+	fn create_kamino_monitor(signature: &str, expression: Option<&str>) -> Monitor {
+		MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.function(signature, expression)
+			.build()
+	}
+
+	fn find_param<'a>(params: &'a SolanaMatchParamsMap, name: &str) -> &'a SolanaMatchParamEntry {
+		params
+			.args
+			.as_ref()
+			.unwrap()
+			.iter()
+			.find(|entry| entry.name == name)
+			.unwrap()
+	}
+
+	#[test]
+	fn test_create_match_params_for_kamino_deposit() {
+		let filter = create_test_filter();
+		let decoded = DecodedInstruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			data: InstructionType::KaminoLendingInstruction(
+				KaminoLendingInstruction::DepositReserveLiquidity(
+					crate::services::decoders::kamino_lending_decoder::instructions::deposit_reserve_liquidity::DepositReserveLiquidity {
+						liquidity_amount: 1500,
+					},
+				),
+			),
+			accounts: vec![],
+		};
+
+		let params = filter.create_match_params(&decoded).unwrap();
+		assert_eq!(params.signature, "DepositReserveLiquidity");
+		let amount = find_param(&params, "liquidity_amount");
+		assert_eq!(amount.value, "1500");
+		assert_eq!(amount.kind, "u64");
+		let program_id = find_param(&params, "program_id");
+		assert_eq!(program_id.value, KAMINO_LENDING_PROGRAM_ID.to_string());
+		assert_eq!(program_id.kind, "pubkey");
+	}
+
+	#[test]
+	fn test_create_match_params_for_kamino_variants_with_nested_args() {
+		use crate::services::decoders::kamino_lending_decoder::instructions::{
+			borrow_obligation_liquidity::BorrowObligationLiquidity,
+			repay_obligation_liquidity::RepayObligationLiquidity,
+			withdraw_obligation_collateral::WithdrawObligationCollateral,
+		};
+
+		let filter = create_test_filter();
+		let cases = vec![
+			(
+				KaminoLendingInstruction::BorrowObligationLiquidity(BorrowObligationLiquidity {
+					liquidity_amount: 10,
+				}),
+				"BorrowObligationLiquidity",
+				"liquidity_amount",
+			),
+			(
+				KaminoLendingInstruction::RepayObligationLiquidity(RepayObligationLiquidity {
+					liquidity_amount: 20,
+				}),
+				"RepayObligationLiquidity",
+				"liquidity_amount",
+			),
+			(
+				KaminoLendingInstruction::WithdrawObligationCollateral(
+					WithdrawObligationCollateral {
+						collateral_amount: 30,
+					},
+				),
+				"WithdrawObligationCollateral",
+				"collateral_amount",
+			),
+		];
+
+		for (instruction, signature, field) in cases {
+			let decoded = DecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: InstructionType::KaminoLendingInstruction(instruction),
+				accounts: vec![],
+			};
+			let params = filter.create_match_params(&decoded).unwrap();
+			assert_eq!(params.signature, signature);
+			assert_eq!(find_param(&params, field).kind, "u64");
+		}
+	}
+
+	#[test]
+	fn test_create_match_params_exposes_named_accounts() {
+		let filter = create_test_filter();
+		let owner = Pubkey::new_unique();
+		let reserve = Pubkey::new_unique();
+		let decoded = DecodedInstruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			data: InstructionType::KaminoLendingInstruction(
+				KaminoLendingInstruction::DepositReserveLiquidity(
+					crate::services::decoders::kamino_lending_decoder::instructions::deposit_reserve_liquidity::DepositReserveLiquidity {
+						liquidity_amount: 1,
+					},
+				),
+			),
+			accounts: vec![AccountMeta::new(owner, true), AccountMeta::new(reserve, false)],
+		};
+
+		let params = filter.create_match_params(&decoded).unwrap();
+		let owner_param = find_param(&params, "owner");
+		assert_eq!(owner_param.value, owner.to_string());
+		assert_eq!(owner_param.kind, "pubkey");
+		assert_eq!(find_param(&params, "reserve").value, reserve.to_string());
+		// Only the accounts present in the instruction are exposed
+		assert!(params
+			.args
+			.as_ref()
+			.unwrap()
+			.iter()
+			.all(|entry| entry.name != "lending_market"));
+	}
+
+	#[test]
+	fn test_match_on_instruction_accounts() {
+		let filter = create_test_filter();
+		let owner = Pubkey::new_unique();
+		let reserve = Pubkey::new_unique();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(10),
+				accounts: vec![
+					AccountMeta::new_readonly(owner, true),
+					AccountMeta::new(reserve, false),
+				],
+			})
+			.build();
+
+		for (expression, expected) in [
+			("accounts[0].is_signer == true", true),
+			("accounts[0].is_writable == true", false),
+			(
+				&format!(
+					"accounts[1].pubkey == '{}' and accounts[1].is_writable == true",
+					reserve
+				),
+				true,
+			),
+			("accounts[1].is_signer == true", false),
+		] {
+			let monitor = create_kamino_monitor("DepositReserveLiquidity", Some(expression));
+			let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: Some(Vec::new()),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_functions_for_transaction(
+				&decoded,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			assert_eq!(matched_functions.len() == 1, expected, "{}", expression);
+
+			if expected {
+				// The account metas of the matched instruction are part of the result
+				let instructions = matched_on_args.instructions.unwrap();
+				let accounts = find_param(&instructions[0], "accounts");
+				assert_eq!(accounts.kind, "vec");
+				assert_eq!(
+					serde_json::from_str::<Value>(&accounts.value).unwrap(),
+					json!([
+						{ "pubkey": owner.to_string(), "is_signer": true, "is_writable": false },
+						{ "pubkey": reserve.to_string(), "is_signer": false, "is_writable": true },
+					])
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_match_on_address_scoped_conditions() {
+		let filter = create_test_filter();
+		let first_program = Pubkey::new_unique();
+		let second_program = Pubkey::new_unique();
+		let scoped_conditions = |expression: &str| AddressMatchConditions {
+			functions: vec![FunctionCondition {
+				signature: "raw()".to_string(),
+				expression: Some(expression.to_string()),
+			}],
+			events: vec![],
+		};
+		let monitor = MonitorBuilder::new()
+			.address_with_conditions(
+				&first_program.to_string(),
+				None,
+				scoped_conditions("data starts_with 0x01"),
+			)
+			.address_with_conditions(
+				&second_program.to_string(),
+				None,
+				scoped_conditions("data starts_with 0x02"),
+			)
+			.build();
+		let transaction = |program_id: Pubkey, data: Vec<u8>| {
+			TransactionBuilder::new()
+				.instruction(SolanaDecodedInstruction {
+					program_id,
+					data,
+					accounts: vec![],
+				})
+				.build()
+		};
+		let is_match = |transaction: &SolanaTransaction| {
+			filter
+				.match_transaction(transaction, &monitor, &[], "solana_mainnet", None)
+				.is_some()
+		};
+
+		assert!(is_match(&transaction(first_program, vec![0x01, 0xff])));
+		assert!(is_match(&transaction(second_program, vec![0x02, 0xff])));
+		// Conditions scoped to one program do not apply to the other
+		assert!(!is_match(&transaction(first_program, vec![0x02, 0xff])));
+		assert!(!is_match(&transaction(second_program, vec![0x01, 0xff])));
+	}
+
+	#[test]
+	fn test_match_on_named_account() {
+		let filter = create_test_filter();
+		let owner = Pubkey::new_unique();
+		let monitor = create_kamino_monitor(
+			"DepositReserveLiquidity",
+			Some(&format!("owner == '{}'", owner)),
+		);
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(10),
+				accounts: vec![AccountMeta::new(owner, true)],
+			})
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_create_match_params_for_custom_instruction() {
+		let filter = create_test_filter();
+		let decoded = DecodedInstruction {
+			program_id: Pubkey::new_unique(),
+			data: InstructionType::Custom(CustomInstruction {
+				name: "stake".to_string(),
+				args: serde_json::json!({ "amount": 5, "validator": "validator-1" }),
+				accounts: vec!["staker".to_string(), "validator".to_string()],
+			}),
+			accounts: vec![
+				AccountMeta::new(Pubkey::new_unique(), true),
+				AccountMeta::new(Pubkey::new_unique(), false),
+			],
+		};
+
+		let params = filter.create_match_params(&decoded).unwrap();
+		assert_eq!(params.signature, "stake");
+		assert_eq!(params.args.as_ref().unwrap().len(), 5);
+		assert_eq!(find_param(&params, "staker").kind, "pubkey");
+		assert_eq!(find_param(&params, "accounts").kind, "vec");
+		// Decoded arguments take precedence over accounts with the same name
+		assert_eq!(find_param(&params, "validator").kind, "string");
+	}
+
+	#[test]
+	fn test_find_matching_functions_on_bool_argument() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let decoded = vec![DecodedInstruction {
+			program_id,
+			data: InstructionType::Custom(CustomInstruction {
+				name: "swap".to_string(),
+				args: serde_json::json!({ "amount": 5, "close_wsol_in_ata": true }),
+				accounts: vec![],
+			}),
+			accounts: vec![],
+		}];
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(find_param(&params, "close_wsol_in_ata").kind, "bool");
+
+		for (expression, should_match) in [
+			("close_wsol_in_ata == true", true),
+			("close_wsol_in_ata != TRUE", false),
+			("close_wsol_in_ata == false OR amount > 10", false),
+			("NOT (close_wsol_in_ata == false) AND amount > 1", true),
+		] {
+			let monitor = MonitorBuilder::new()
+				.address(&program_id.to_string(), None)
+				.function("swap", Some(expression))
+				.build();
+
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: Some(Vec::new()),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_functions_for_transaction(
+				&decoded,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			assert_eq!(
+				matched_functions.len(),
+				usize::from(should_match),
+				"{}",
+				expression
+			);
+		}
+	}
+
+	#[test]
+	fn test_find_matching_functions_on_optional_arguments() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let decoded = vec![DecodedInstruction {
+			program_id,
+			data: InstructionType::Custom(CustomInstruction {
+				name: "swap".to_string(),
+				args: serde_json::json!({
+					"referrer": null,
+					"config": { "fee": 5, "limit": null },
+					"hops": [{ "fee": null }, { "fee": 3 }],
+				}),
+				accounts: vec![],
+			}),
+			accounts: vec![],
+		}];
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(find_param(&params, "referrer").kind, "null");
+
+		for (expression, should_match) in [
+			("referrer is null", true),
+			("referrer is not null", false),
+			("config.limit IS NULL AND config.fee IS NOT NULL", true),
+			("config.fee is null", false),
+			("any(hops.fee) is null", true),
+			("all(hops.fee) is not null", false),
+			("NOT referrer is null", false),
+		] {
+			let monitor = MonitorBuilder::new()
+				.address(&program_id.to_string(), None)
+				.function("swap", Some(expression))
+				.build();
+
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: Some(Vec::new()),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_functions_for_transaction(
+				&decoded,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			assert_eq!(
+				matched_functions.len(),
+				usize::from(should_match),
+				"{}",
+				expression
+			);
+		}
+	}
+
+	#[test]
+	fn test_create_match_params_for_name_service_transfer() {
+		use crate::services::decoders::name_service::{
+			NameServiceInstruction, Transfer, PROGRAM_ID as NAME_SERVICE_PROGRAM_ID,
+		};
+
+		let filter = create_test_filter();
+		let new_owner = Pubkey::new_unique();
+		let name_account = Pubkey::new_unique();
+		let mut data = vec![2];
+		data.extend_from_slice(new_owner.as_ref());
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: NAME_SERVICE_PROGRAM_ID,
+				data,
+				accounts: vec![
+					AccountMeta::new(name_account, false),
+					AccountMeta::new_readonly(Pubkey::new_unique(), true),
+				],
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&NAME_SERVICE_PROGRAM_ID.to_string(), None)
+			.function("transfer", Some(&format!("new_owner == '{}'", new_owner)))
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		assert_eq!(
+			decoded[0].data,
+			InstructionType::NameService(NameServiceInstruction::Transfer(Transfer { new_owner }))
+		);
+
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "Transfer");
+		assert_eq!(find_param(&params, "new_owner").kind, "pubkey");
+		assert_eq!(
+			find_param(&params, "name_account").value,
+			name_account.to_string()
+		);
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_match_token_transfer_without_contract_spec() {
+		use crate::services::decoders::token_account::TOKEN_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let mint = Pubkey::new_unique();
+		let mut data = vec![12];
+		data.extend_from_slice(&5_000u64.to_le_bytes());
+		data.push(6);
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: TOKEN_PROGRAM_ID,
+				data,
+				accounts: vec![
+					AccountMeta::new(Pubkey::new_unique(), false),
+					AccountMeta::new_readonly(mint, false),
+					AccountMeta::new(Pubkey::new_unique(), false),
+					AccountMeta::new_readonly(Pubkey::new_unique(), true),
+				],
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&TOKEN_PROGRAM_ID.to_string(), None)
+			.function(
+				"transfer_checked",
+				Some(&format!("mint == '{}' and amount >= 5000", mint)),
+			)
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "TransferChecked");
+		assert_eq!(find_param(&params, "decimals").value, "6");
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_create_match_params_for_extend_lookup_table() {
+		use crate::services::decoders::address_lookup_table::PROGRAM_ID as ADDRESS_LOOKUP_TABLE_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let lookup_table = Pubkey::new_unique();
+		let added = Pubkey::new_unique();
+		let mut data = 2u32.to_le_bytes().to_vec();
+		data.extend_from_slice(&1u64.to_le_bytes());
+		data.extend_from_slice(added.as_ref());
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+				data,
+				accounts: vec![
+					AccountMeta::new(lookup_table, false),
+					AccountMeta::new_readonly(Pubkey::new_unique(), true),
+				],
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&ADDRESS_LOOKUP_TABLE_PROGRAM_ID.to_string(), None)
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "ExtendLookupTable");
+		assert_eq!(find_param(&params, "new_addresses").kind, "vec");
+		assert_eq!(
+			find_param(&params, "new_addresses").value,
+			format!("[\"{}\"]", added)
+		);
+		assert_eq!(
+			find_param(&params, "lookup_table").value,
+			lookup_table.to_string()
+		);
+	}
+
+	#[test]
+	fn test_match_upgrade_authority_change_of_monitored_program() {
+		use crate::services::decoders::bpf_upgradeable_loader::{
+			programdata_address, PROGRAM_ID as BPF_UPGRADEABLE_LOADER_PROGRAM_ID,
+		};
+
+		let filter = create_test_filter();
+		let program = Pubkey::new_unique();
+		let new_authority = Pubkey::new_unique();
+		let set_authority = |program: &Pubkey| SolanaDecodedInstruction {
+			program_id: BPF_UPGRADEABLE_LOADER_PROGRAM_ID,
+			data: 4u32.to_le_bytes().to_vec(),
+			accounts: vec![
+				AccountMeta::new(programdata_address(program), false),
+				AccountMeta::new_readonly(Pubkey::new_unique(), true),
+				AccountMeta::new_readonly(new_authority, false),
+			],
+		};
+		let transaction = TransactionBuilder::new()
+			.instruction(set_authority(&program))
+			.instruction(set_authority(&Pubkey::new_unique()))
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&program.to_string(), None)
+			.function(
+				"SetAuthority",
+				Some(&format!("new_authority == '{}'", new_authority)),
+			)
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		assert_eq!(decoded.len(), 1);
+
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "SetAuthority");
+		assert_eq!(
+			find_param(&params, "account").value,
+			programdata_address(&program).to_string()
+		);
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_match_okx_dex_swap_on_nested_args() {
+		use crate::services::decoders::okx_dex_decoder::PROGRAM_ID as OKX_DEX_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let payer = Pubkey::new_unique();
+		let mut data = vec![0xf8, 0xc6, 0x9e, 0x91, 0xe1, 0x75, 0x87, 0xc8];
+		data.extend_from_slice(&5_000u64.to_le_bytes());
+		data.extend_from_slice(&4_900u64.to_le_bytes());
+		data.extend_from_slice(&4_800u64.to_le_bytes());
+		data.extend_from_slice(&0u32.to_le_bytes());
+		data.extend_from_slice(&0u32.to_le_bytes());
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: OKX_DEX_PROGRAM_ID,
+				data,
+				accounts: (0..5)
+					.map(|i| {
+						AccountMeta::new(if i == 0 { payer } else { Pubkey::new_unique() }, i == 0)
+					})
+					.collect(),
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&OKX_DEX_PROGRAM_ID.to_string(), None)
+			.function(
+				"swap",
+				Some(&format!("data.amount_in > 1000 AND payer == '{}'", payer)),
+			)
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "Swap");
+		assert_eq!(find_param(&params, "data").kind, "map");
+		assert_eq!(find_param(&params, "destination_mint").kind, "pubkey");
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_match_sharky_offer_loan_on_loan_terms() {
+		use crate::services::decoders::sharky_decoder::PROGRAM_ID as SHARKY_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let lender = Pubkey::new_unique();
+		let mut data = vec![0x2c, 0x0c, 0x4c, 0x90, 0xd2, 0xd0, 0xef, 0x55];
+		data.push(254);
+		data.extend_from_slice(&5_000_000_000u64.to_le_bytes());
+		// Some(LoanTermsSpec::Time { duration })
+		data.extend_from_slice(&[1, 0]);
+		data.extend_from_slice(&604_800u64.to_le_bytes());
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: SHARKY_PROGRAM_ID,
+				data,
+				accounts: (0..11)
+					.map(|i| {
+						AccountMeta::new(if i == 0 { lender } else { Pubkey::new_unique() }, i == 0)
+					})
+					.collect(),
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&SHARKY_PROGRAM_ID.to_string(), None)
+			.function(
+				"offer_loan",
+				Some("principal_lamports > 1000000000 AND duration == 604800"),
+			)
+			.build();
+
+		let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "OfferLoan");
+		assert_eq!(
+			find_param(&params, "principal_lamports").value,
+			"5000000000"
+		);
+		assert_eq!(find_param(&params, "duration").kind, "u64");
+		assert_eq!(find_param(&params, "lender").value, lender.to_string());
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_functions_on_raw_instruction_data() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id,
+				data: vec![0x01, 0xab, 0x03, 0xc4],
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.build();
+
+		for (expression, should_match) in [
+			("data starts_with 0x01AB", true),
+			("data == b58'3URvB'", true),
+			("data starts_with 0x02", false),
+		] {
+			let monitor = MonitorBuilder::new()
+				.address(&program_id.to_string(), None)
+				.function(RAW_INSTRUCTION_NAME, Some(expression))
+				.build();
+
+			let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+			assert_eq!(decoded.len(), 1);
+			let params = filter.create_match_params(&decoded[0]).unwrap();
+			assert_eq!(params.signature, RAW_INSTRUCTION_NAME);
+			assert_eq!(find_param(&params, "data").value, "0x01ab03c4");
+			assert_eq!(find_param(&params, "data").kind, "bytes");
+
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: Some(Vec::new()),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_functions_for_transaction(
+				&decoded,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+			assert_eq!(
+				matched_functions.len(),
+				usize::from(should_match),
+				"{}",
+				expression
+			);
+		}
+	}
+
+	#[test]
+	fn test_create_match_params_for_undecoded_instruction_type() {
+		let filter = create_test_filter();
+		let decoded = DecodedInstruction {
+			program_id: Pubkey::new_unique(),
+			data: InstructionType::SystemProgram,
+			accounts: vec![],
+		};
+		assert!(filter.create_match_params(&decoded).is_none());
+	}
+
+	#[test]
+	fn test_find_matching_functions_with_expression() {
+		let filter = create_test_filter();
+		let monitor =
+			create_kamino_monitor("deposit_reserve_liquidity", Some("liquidity_amount > 1000"));
+
+		for (amount, should_match) in [(5000, true), (10, false)] {
+			let transaction = create_deposit_transaction(amount);
+			let decoded = filter.decode_monitored_instructions(&transaction, &monitor, &[]);
+			assert_eq!(decoded.len(), 1);
+
+			let mut matched_functions = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: Some(Vec::new()),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_functions_for_transaction(
+				&decoded,
+				&monitor,
+				&mut matched_functions,
+				&mut matched_on_args,
+			);
+
+			assert_eq!(matched_functions.len(), usize::from(should_match));
+			assert_eq!(
+				matched_on_args.instructions.unwrap().len(),
+				usize::from(should_match)
+			);
+		}
+	}
+
+	#[test]
+	fn test_decode_ignores_unmonitored_programs() {
+		let filter = create_test_filter();
+		let monitor = MonitorBuilder::new()
+			.address(&Pubkey::new_unique().to_string(), None)
+			.build();
+		let transaction = create_deposit_transaction(1);
+		assert!(filter
+			.decode_monitored_instructions(&transaction, &monitor, &[])
+			.is_empty());
+	}
+
+	#[test]
+	fn test_decode_restricted_to_instruction_names_of_spec() {
+		let filter = create_test_filter();
+		let mut redeem_data = vec![0xea, 0x75, 0xb5, 0x7d, 0xb9, 0x8e, 0xdc, 0x1d];
+		redeem_data.extend_from_slice(&7u64.to_le_bytes());
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(5000),
+				accounts: vec![],
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: redeem_data,
+				accounts: vec![],
+			})
+			.build();
+		let decoded_names = |spec: Option<SolanaDecoderType>| {
+			let monitor = MonitorBuilder::new()
+				.address(
+					&KAMINO_LENDING_PROGRAM_ID.to_string(),
+					spec.map(|spec| ContractSpec::Solana(SolanaContractSpec::new(spec))),
+				)
+				.build();
+			filter
+				.decode_monitored_instructions(&transaction, &monitor, &[])
+				.iter()
+				.filter_map(|decoded| decoded.data.variant_name())
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			decoded_names(None),
+			vec!["DepositReserveLiquidity", "RedeemReserveCollateral"]
+		);
+		assert_eq!(
+			decoded_names(Some(SolanaDecoderType::InstructionNames(vec![
+				"deposit_reserve_liquidity".to_string()
+			]))),
+			vec!["DepositReserveLiquidity"]
+		);
+		// A spec holding an instruction matches its variant, whatever its fields
+		assert_eq!(
+			decoded_names(Some(SolanaDecoderType::Instruction(
+				InstructionType::KaminoLendingInstruction(
+					KaminoLendingInstruction::RedeemReserveCollateral(RedeemReserveCollateral {
+						collateral_amount: 0,
+					})
+				)
+			))),
+			vec!["RedeemReserveCollateral"]
+		);
+		assert_eq!(
+			decoded_names(Some(SolanaDecoderType::Instructions(vec![
+				InstructionType::KaminoLendingInstruction(
+					KaminoLendingInstruction::RedeemReserveCollateral(RedeemReserveCollateral {
+						collateral_amount: 0,
+					})
+				),
+				InstructionType::KaminoLendingInstruction(
+					KaminoLendingInstruction::DepositReserveLiquidity(DepositReserveLiquidity {
+						liquidity_amount: 0,
+					})
+				),
+			]))),
+			vec!["DepositReserveLiquidity", "RedeemReserveCollateral"]
+		);
+	}
+
+	#[test]
+	fn test_match_transaction_of_any_address() {
+		let filter = create_test_filter();
+		let transaction = create_deposit_transaction(5000);
+		let monitor = |builder: MonitorBuilder| {
+			builder
+				.function("DepositReserveLiquidity", Some("liquidity_amount > 1000"))
+				.build()
+		};
+
+		assert!(filter
+			.match_transaction(&transaction, &monitor(MonitorBuilder::new()), &[], "", None)
+			.is_none());
+		for builder in [
+			MonitorBuilder::new().match_all_addresses(),
+			MonitorBuilder::new().address(Monitor::WILDCARD_ADDRESS, None),
+		] {
+			let monitor = monitor(builder);
+			assert!(monitor.matches_all_addresses());
+			assert_eq!(
+				filter
+					.decode_monitored_instructions(&transaction, &monitor, &[])
+					.len(),
+				1
+			);
+			assert!(filter
+				.match_transaction(&transaction, &monitor, &[], "", None)
+				.is_some());
+		}
+	}
+
+	#[test]
+	fn test_match_transaction_collects_all_matches() {
+		let filter = create_test_filter();
+		let deposit = |amount: u64| SolanaDecodedInstruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			data: create_deposit_data(amount),
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+		};
+		let transaction = TransactionBuilder::new()
+			.instruction(deposit(5000))
+			.instruction(deposit(500))
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.function("DepositReserveLiquidity", Some("liquidity_amount > 1000"))
+			.function("DepositReserveLiquidity", Some("liquidity_amount > 100"))
+			.function("DepositReserveLiquidity", Some("liquidity_amount > 10000"))
+			.build();
+
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &monitor, &[], "", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+
+		assert_eq!(
+			monitor_match.matched_on.functions,
+			monitor.match_conditions.functions[..2]
+		);
+		let instructions = monitor_match.matched_on_args.unwrap().instructions.unwrap();
+		assert_eq!(instructions.len(), 2);
+		assert_eq!(
+			find_param(&instructions[0], "liquidity_amount").value,
+			"5000"
+		);
+		assert_eq!(
+			find_param(&instructions[1], "liquidity_amount").value,
+			"500"
+		);
+	}
+
+	#[test]
+	fn test_match_transaction_with_any_condition_logic() {
+		let filter = create_test_filter();
+		let transaction = create_deposit_transaction(5000);
+		let monitor = |condition_logic: ConditionLogic, expression: &str| {
+			MonitorBuilder::new()
+				.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+				.function("DepositReserveLiquidity", Some(expression))
+				.log(Some("log contains 'Error: stale oracle'"))
+				.condition_logic(condition_logic)
+				.build()
+		};
+
+		// The log condition never matches, so the monitor only matches with `any`
+		assert!(filter
+			.match_transaction(
+				&transaction,
+				&monitor(ConditionLogic::All, "liquidity_amount > 1000"),
+				&[],
+				"",
+				None
+			)
+			.is_none());
+
+		let any_monitor = monitor(ConditionLogic::Any, "liquidity_amount > 1000");
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &any_monitor, &[], "", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+		assert_eq!(
+			monitor_match.matched_on.functions,
+			any_monitor.match_conditions.functions
+		);
+		assert!(monitor_match.matched_on.logs.is_empty());
+		assert_eq!(
+			monitor_match.matched_on.condition_logic,
+			ConditionLogic::Any
+		);
+
+		// Some group must still match
+		assert!(filter
+			.match_transaction(
+				&transaction,
+				&monitor(ConditionLogic::Any, "liquidity_amount > 10000"),
+				&[],
+				"",
+				None
+			)
+			.is_none());
+	}
+
+	#[test]
+	fn test_match_transaction_records_matched_instruction() {
+		let filter = create_test_filter();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: Pubkey::new_unique(),
+				data: vec![0x01],
+				accounts: vec![],
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(5000),
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.build();
+		let monitor = create_kamino_monitor("DepositReserveLiquidity", None);
+
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &monitor, &[], "", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+		assert_eq!(
+			monitor_match.matched_instructions,
+			vec![SolanaMatchedInstruction {
+				instruction_index: 1,
+				stack_height: 0,
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(5000),
+			}]
+		);
+		assert_eq!(
+			monitor_match.data(),
+			Some(create_deposit_data(5000).as_slice())
+		);
+		assert_eq!(monitor_match.instruction_index(), 1);
+	}
+
+	#[test]
+	fn test_match_transaction_fills_hex_signature() {
+		let filter = create_test_filter();
+		let raw_program = Pubkey::new_unique();
+		let transaction = TransactionBuilder::new()
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data: create_deposit_data(5000),
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: raw_program,
+				data: vec![0x01],
+				accounts: vec![],
+			})
+			.build();
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.address(&raw_program.to_string(), None)
+			.function(
+				"DepositReserveLiquidity(u64 liquidity_amount)",
+				Some("liquidity_amount > 1000"),
+			)
+			.function(RAW_INSTRUCTION_NAME, None)
+			.build();
+
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &monitor, &[], "", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+		let instructions = monitor_match.matched_on_args.unwrap().instructions.unwrap();
+		assert_eq!(instructions.len(), 2);
+		assert_eq!(
+			instructions[0].hex_signature.as_deref(),
+			Some("0xa9c91e7e06cd6644")
+		);
+		assert_eq!(instructions[1].hex_signature, None);
+	}
+
+	#[test]
+	fn test_match_transaction_with_sequence() {
+		let filter = create_test_filter();
+		let swap_program = Pubkey::new_unique();
+		let deposit = SolanaDecodedInstruction {
+			program_id: KAMINO_LENDING_PROGRAM_ID,
+			data: create_deposit_data(5000),
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+		};
+		let swap = SolanaDecodedInstruction {
+			program_id: swap_program,
+			data: vec![0x01],
+			accounts: vec![],
+		};
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.sequence(&[
+				("DepositReserveLiquidity", Some("liquidity_amount > 1000")),
+				(
+					RAW_INSTRUCTION_NAME,
+					Some(&format!("program_id == '{}'", swap_program)),
+				),
+			])
+			.build();
+
+		let transaction = TransactionBuilder::new()
+			.instruction(deposit.clone())
+			.instruction(swap.clone())
+			.build();
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &monitor, &[], "", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+		assert_eq!(
+			monitor_match.matched_on.sequences,
+			monitor.match_conditions.sequences
+		);
+		let steps = monitor_match
+			.matched_on_args
+			.as_ref()
+			.and_then(|args| args.sequences.as_ref())
+			.unwrap();
+		assert_eq!(
+			steps
+				.iter()
+				.map(|params| params.signature.as_str())
+				.collect::<Vec<_>>(),
+			vec!["DepositReserveLiquidity", RAW_INSTRUCTION_NAME]
+		);
+		assert_eq!(
+			monitor_match.matched_instructions,
+			vec![
+				SolanaMatchedInstruction {
+					instruction_index: 0,
+					stack_height: 0,
+					program_id: KAMINO_LENDING_PROGRAM_ID,
+					data: create_deposit_data(5000),
+				},
+				SolanaMatchedInstruction {
+					instruction_index: 1,
+					stack_height: 0,
+					program_id: swap_program,
+					data: vec![0x01],
+				},
+			]
+		);
+
+		// The steps must match in order
+		let reversed = TransactionBuilder::new()
+			.instruction(swap)
+			.instruction(deposit)
+			.build();
+		assert!(filter
+			.match_transaction(&reversed, &monitor, &[], "", None)
+			.is_none());
+	}
+
+	#[test]
+	fn test_match_transaction_skips_excluded_signers() {
+		let filter = create_test_filter();
+		let transaction = create_deposit_transaction(5000);
+		let signer = transaction.message().static_account_keys()[0];
+		let monitor = |excluded: &str| {
+			MonitorBuilder::new()
+				.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+				.function("DepositReserveLiquidity", None)
+				.excluded_address(excluded)
+				.build()
+		};
+
+		assert!(filter
+			.match_transaction(
+				&transaction,
+				&monitor(&Pubkey::new_unique().to_string()),
+				&[],
+				"",
+				None
+			)
+			.is_some());
+		assert!(filter
+			.match_transaction(&transaction, &monitor(&signer.to_string()), &[], "", None)
+			.is_none());
+	}
+
+	#[test]
+	fn test_decode_matches_normalized_monitor_addresses() {
+		let filter = create_test_filter();
+		let transaction = create_deposit_transaction(1);
+		let hex_address = format!("0x{}", hex::encode(KAMINO_LENDING_PROGRAM_ID));
+
+		for address in [format!(" {}\n", KAMINO_LENDING_PROGRAM_ID), hex_address] {
+			let monitor = MonitorBuilder::new().address(&address, None).build();
+			assert_eq!(
+				filter
+					.decode_monitored_instructions(&transaction, &monitor, &[])
+					.len(),
+				1
+			);
+		}
+	}
+
+	#[test]
+	fn test_decode_prefers_classified_enhanced_transaction() {
+		let filter = create_test_filter();
+		let transaction = create_deposit_transaction(5000);
+		let monitor = create_kamino_monitor("swap", Some("source == 'JUPITER'"));
+		let mut enhanced = SolanaEnhancedTransaction {
+			signature: transaction.signature().to_string(),
+			type_: "SWAP".to_string(),
+			source: "JUPITER".to_string(),
+			description: "A swapped 1 SOL for 150 USDC on JUPITER".to_string(),
+			native_transfers: vec![],
+			token_transfers: vec![],
+			events: serde_json::Value::Null,
+		};
+
+		let decoded = filter.decode_monitored_instructions_with_enhanced(
+			&transaction,
+			&monitor,
+			&[],
+			Some(&enhanced),
+		);
+		assert_eq!(decoded.len(), 1);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "SWAP");
+		assert_eq!(find_param(&params, "source").value, "JUPITER");
+
+		let mut matched_functions = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: Some(Vec::new()),
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+		filter.find_matching_functions_for_transaction(
+			&decoded,
+			&monitor,
+			&mut matched_functions,
+			&mut matched_on_args,
+		);
+		assert_eq!(matched_functions.len(), 1);
+
+		// Unclassified transactions fall back to the built-in decoders
+		enhanced.type_ = "UNKNOWN".to_string();
+		let decoded = filter.decode_monitored_instructions_with_enhanced(
+			&transaction,
+			&monitor,
+			&[],
+			Some(&enhanced),
+		);
+		let params = filter.create_match_params(&decoded[0]).unwrap();
+		assert_eq!(params.signature, "DepositReserveLiquidity");
+	}
+
+	#[test]
+	fn test_find_matching_transaction_status() {
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Failure,
+			expression: None,
+		}];
+
+		let mut matched = Vec::new();
+		filter.find_matching_transaction(&create_deposit_transaction(1), &monitor, &mut matched);
+		assert!(matched.is_empty());
+
+		let failed = TransactionBuilder::new()
+			.meta(SolanaTransactionStatusMeta {
+				status: Err(solana_sdk::transaction::TransactionError::AccountNotFound),
+				..Default::default()
+			})
+			.build();
+		filter.find_matching_transaction(&failed, &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_transaction_with_expression() {
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("slot > 100".to_string()),
+		}];
+
+		let mut matched = Vec::new();
+		let transaction = TransactionBuilder::new().slot(50).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert!(matched.is_empty());
+
+		let transaction = TransactionBuilder::new().slot(500).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_transaction_in_slot_range_and_epoch() {
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("slot between 367200100 and 367200200 OR epoch == 851".to_string()),
+		}];
+
+		for (slot, expected) in [
+			(367_200_099, 0),
+			(367_200_100, 1),
+			(367_200_200, 1),
+			(367_200_201, 0),
+			(367_632_000, 1),
+		] {
+			let mut matched = Vec::new();
+			let transaction = TransactionBuilder::new().slot(slot).build();
+			filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+			assert_eq!(matched.len(), expected, "slot {}", slot);
+		}
+	}
+
+	#[test]
+	fn test_create_transaction_params_from_meta_and_message() {
+		let filter = create_test_filter();
+		let payer = Pubkey::new_unique();
+		let cosigner = Pubkey::new_unique();
+		let message = solana_sdk::message::Message::new_with_compiled_instructions(
+			2,
+			0,
+			0,
+			vec![payer, cosigner],
+			Default::default(),
+			vec![],
+		);
+		let transaction = TransactionBuilder::new()
+			.fee_payer(payer)
+			.message(solana_sdk::message::VersionedMessage::Legacy(message))
+			.meta(SolanaTransactionStatusMeta {
+				fee: 5_000,
+				compute_units_consumed: Some(42_000),
+				..Default::default()
+			})
+			.instruction(SolanaDecodedInstruction {
+				program_id: Pubkey::new_unique(),
+				data: vec![],
+				accounts: vec![],
+			})
+			.build();
+
+		let params = filter.create_transaction_params(&transaction);
+		let param = |name: &str| params.iter().find(|param| param.name == name).cloned();
+		assert_eq!(param("fee").unwrap().value, "5000");
+		assert_eq!(param("fee").unwrap().kind, "u64");
+		assert_eq!(param("compute_units_consumed").unwrap().value, "42000");
+		assert_eq!(param("num_signers").unwrap().value, "2");
+		assert_eq!(param("num_instructions").unwrap().value, "1");
+		assert_eq!(param("fee_payer").unwrap().value, payer.to_string());
+
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("fee >= 5000 and num_signers > 1".to_string()),
+		}];
+		let mut matched = Vec::new();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+
+		// The consumed compute units are only exposed when the node reports them
+		let transaction = TransactionBuilder::new().build();
+		let params = filter.create_transaction_params(&transaction);
+		assert!(params
+			.iter()
+			.all(|param| param.name != "compute_units_consumed"));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_on_priority_fee() {
+		use crate::services::decoders::compute_budget::PROGRAM_ID as COMPUTE_BUDGET_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("priority_fee_lamports >= 100000".to_string()),
+		}];
+		let transaction = |price: u64| {
+			TransactionBuilder::new()
+				.instruction(SolanaDecodedInstruction {
+					program_id: COMPUTE_BUDGET_PROGRAM_ID,
+					data: [vec![2], 200_000u32.to_le_bytes().to_vec()].concat(),
+					accounts: vec![],
+				})
+				.instruction(SolanaDecodedInstruction {
+					program_id: COMPUTE_BUDGET_PROGRAM_ID,
+					data: [vec![3], price.to_le_bytes().to_vec()].concat(),
+					accounts: vec![],
+				})
+				.build()
+		};
+
+		let params = filter.create_transaction_params(&transaction(1_000_000));
+		let param = |name: &str| {
+			params
+				.iter()
+				.find(|param| param.name == name)
+				.unwrap()
+				.value
+				.clone()
+		};
+		assert_eq!(param("compute_unit_price"), "1000000");
+		assert_eq!(param("compute_unit_limit"), "200000");
+		assert_eq!(param("priority_fee_lamports"), "200000");
+
+		for (price, should_match) in [(1_000_000, true), (1_000, false)] {
+			let mut matched = Vec::new();
+			filter.find_matching_transaction(&transaction(price), &monitor, &mut matched);
+			assert_eq!(matched.len(), usize::from(should_match));
+		}
+	}
+
+	#[test]
+	fn test_find_matching_transaction_on_signed_block_time() {
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("block_time > -1 AND block_time < 1700000000".to_string()),
+		}];
+
+		let mut matched = Vec::new();
+		let transaction = TransactionBuilder::new().block_time(-3600).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert!(matched.is_empty());
+
+		let transaction = TransactionBuilder::new().block_time(1_600_000_000).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+	}
+
+	#[test]
+	fn test_find_matching_transaction_on_relative_block_time() {
+		let filter = create_test_filter();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some("block_time > now() - 1h".to_string()),
+		}];
+
+		let mut matched = Vec::new();
+		let stale = chrono::Utc::now().timestamp() - 2 * 3_600;
+		let transaction = TransactionBuilder::new().block_time(stale).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert!(matched.is_empty());
+
+		let recent = chrono::Utc::now().timestamp() - 60;
+		let transaction = TransactionBuilder::new().block_time(recent).build();
+		filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+		assert_eq!(matched.len(), 1);
+	}
+
+	#[test]
+	fn test_evaluate_expression_parses_once() {
+		let filter = SolanaBlockFilter::<()> {
+			expression_cache: Arc::new(ExpressionCache::default()),
+			..create_test_filter()
+		};
+		let args = [SolanaMatchParamEntry {
+			name: "amount".to_string(),
+			value: "150".to_string(),
+			kind: "u64".to_string(),
+			indexed: false,
+		}];
+
+		assert!(filter.evaluate_expression("amount > 100", &args).unwrap());
+		assert!(!filter.evaluate_expression("amount > 200", &args).unwrap());
+		assert!(filter.evaluate_expression("amount > 100", &args).unwrap());
+		assert!(filter.evaluate_expression("amount >", &args).is_err());
+		assert_eq!(filter.expression_cache.len(), 2);
+	}
+
+	#[test]
+	fn test_evaluate_expression_empty() {
+		let filter = create_test_filter();
+		assert!(filter.evaluate_expression("  ", &[]).is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_nesting_and_quoting() {
+		let filter = create_test_filter();
+		let args = vec![
+			SolanaMatchParamEntry {
+				name: "amount".to_string(),
+				value: "500".to_string(),
+				kind: "u64".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "memo".to_string(),
+				value: "exit AND drain OR hold".to_string(),
+				kind: "string".to_string(),
+				indexed: false,
+			},
+		];
+
+		assert!(filter
+			.evaluate_expression("memo == 'exit AND drain OR hold'", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("((amount > 100) AND (memo contains 'drain'))", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("amount < 100 AND memo == 'x' OR amount == 500", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("amount < 100 AND (memo == 'x' OR amount == 500)", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("(amount > 100 AND memo == 'exit", &args)
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_not() {
+		let filter = create_test_filter();
+		let entry = |name: &str, value: &str, kind: &str| SolanaMatchParamEntry {
+			name: name.to_string(),
+			value: value.to_string(),
+			kind: kind.to_string(),
+			indexed: false,
+		};
+		let args = vec![
+			entry("amount", "50", "u64"),
+			entry("mint", "X", "string"),
+			entry("owner", "Z", "string"),
+		];
+
+		let expression = "(amount > 100 AND mint == 'X') OR NOT (owner == 'Y')";
+		assert!(filter.evaluate_expression(expression, &args).unwrap());
+		assert!(!filter
+			.evaluate_expression("NOT owner == 'Z'", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("NOT (amount > 100 OR mint == 'Y')", &args)
+			.unwrap());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_arithmetic() {
+		let filter = create_test_filter();
+		let args = vec![SolanaMatchParamEntry {
+			name: "in_amount".to_string(),
+			value: "75000000000".to_string(),
+			kind: "u64".to_string(),
+			indexed: false,
+		}];
+
+		assert!(filter
+			.evaluate_expression("in_amount / 1000000000 >= 50", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("in_amount / 1e9 == 75", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("in_amount / 1e9 - 50 > 25", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("in_amount / 1e9 > 'abc'", &args)
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_in_list() {
+		let filter = create_test_filter();
+		let mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+		let args = vec![
+			SolanaMatchParamEntry {
+				name: "mint".to_string(),
+				value: mint.to_string(),
+				kind: "pubkey".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "decimals".to_string(),
+				value: "6".to_string(),
+				kind: "u8".to_string(),
+				indexed: false,
+			},
+		];
+
+		let expression = format!(
+			"mint in ['So11111111111111111111111111111111111111112', '{}']",
+			mint
+		);
+		assert!(filter.evaluate_expression(&expression, &args).unwrap());
+		// Public keys are compared case-sensitively
+		let expression = format!("mint in ['{}']", mint.to_lowercase());
+		assert!(!filter.evaluate_expression(&expression, &args).unwrap());
+		assert!(filter
+			.evaluate_expression("decimals in [6, 9]", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("decimals in ['6']", &args)
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_dot_paths() {
+		let filter = create_test_filter();
+		let mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+		let args = vec![
+			SolanaMatchParamEntry {
+				name: "open_dca".to_string(),
+				value: json!({ "in_amount": 5_000_000_000u64, "cycle": { "frequency": 60 } })
+					.to_string(),
+				kind: "map".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "params".to_string(),
+				value:
+					json!([{ "mint": mint, "amount": "340282366920938463463374607431768211455" }])
+						.to_string(),
+				kind: "vec".to_string(),
+				indexed: false,
+			},
+		];
+
+		assert!(filter
+			.evaluate_expression(
+				"open_dca.in_amount > 1000000000 AND open_dca.cycle.frequency == 60",
+				&args
+			)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression(&format!("params.0.mint == '{}'", mint), &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("params[0].amount > 18446744073709551615", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("params.1.mint == 'x'", &args)
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_aggregates() {
+		let filter = create_test_filter();
+		let raydium = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+		let args = vec![
+			SolanaMatchParamEntry {
+				name: "route".to_string(),
+				value: json!({
+					"hops": [
+						{ "dex": raydium, "amount_in": 5_000 },
+						{ "dex": "Phoenix", "amount_in": 250 }
+					]
+				})
+				.to_string(),
+				kind: "map".to_string(),
+				indexed: false,
+			},
+			SolanaMatchParamEntry {
+				name: "amounts".to_string(),
+				value: "[150, 20]".to_string(),
+				kind: "vec".to_string(),
+				indexed: false,
+			},
+		];
+
+		assert!(filter
+			.evaluate_expression("amounts[0] > 100 AND len(amounts) == 2", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("any(route.hops.amount_in) > 1000", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("all(route.hops.amount_in) > 1000", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression(
+				&format!("any(route.hops.dex) in ['{}', 'Orca']", raydium),
+				&args
+			)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("len(route.hops) > 1 AND all(amounts) >= 20", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("len(route.hops.0.dex) > 1", &args)
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_expression_with_matches() {
+		let filter = create_test_filter();
+		let args = vec![SolanaMatchParamEntry {
+			name: "memo".to_string(),
+			value: "Withdraw 250 USDC to 7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU".to_string(),
+			kind: "string".to_string(),
+			indexed: false,
+		}];
+
+		assert!(filter
+			.evaluate_expression(r"memo matches '^Withdraw \d+ USDC'", &args)
+			.unwrap());
+		assert!(!filter
+			.evaluate_expression("memo MATCHES '^Deposit' OR NOT memo matches 'USDC'", &args)
+			.unwrap());
+		assert!(filter
+			.evaluate_expression("memo matches '(unclosed'", &args)
+			.is_err());
+	}
+
+	fn create_vault_account(owner: Pubkey, balance: u64) -> Account {
+		let mut data = vec![4; 8];
+		data.extend_from_slice(&balance.to_le_bytes());
+		Account {
+			lamports: 2_000_000,
+			data,
+			owner,
+			executable: false,
+			rent_epoch: 0,
+		}
+	}
+
+	fn create_vault_spec() -> ContractSpec {
+		let idl: crate::services::decoders::AnchorIdl = serde_json::from_value(json!({
+			"accounts": [{
+				"name": "Vault",
+				"discriminator": [4, 4, 4, 4, 4, 4, 4, 4],
+				"type": { "kind": "struct", "fields": [{ "name": "balance", "type": "u64" }] }
+			}]
+		}))
+		.unwrap();
+		ContractSpec::Solana(SolanaContractSpec::new(
+			crate::models::SolanaDecoderType::Idl(idl),
+		))
+	}
+
+	#[test]
+	fn test_create_account_match_params() {
+		let filter = create_test_filter();
+		let address = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let decoded = DecodedAccount {
+			lamports: 42,
+			data: AccountType::Custom(crate::services::decoders::CustomAccount {
+				name: "Vault".to_string(),
+				data: json!({ "balance": 7, "owner": "ignored" }),
+			}),
+			owner,
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let params = filter
+			.create_account_match_params(&address, &decoded)
+			.unwrap();
+		assert_eq!(params.signature, "Vault");
+		assert_eq!(find_param(&params, "address").value, address.to_string());
+		assert_eq!(find_param(&params, "owner").value, owner.to_string());
+		assert_eq!(find_param(&params, "lamports").value, "42");
+		assert_eq!(find_param(&params, "executable").value, "false");
+		assert_eq!(find_param(&params, "balance").value, "7");
+	}
+
+	#[test]
+	fn test_create_account_match_params_for_builtin_account() {
+		let filter = create_test_filter();
+		let address = Pubkey::new_unique();
+		let decoded = DecodedAccount {
+			lamports: 42,
+			data: AccountType::KaminoLendingAccount(KaminoLendingAccount::ShortUrl(ShortUrl {
+				referrer: Pubkey::new_unique(),
+				short_url: "kamino".to_string(),
+			})),
+			owner: KAMINO_LENDING_PROGRAM_ID,
+			executable: false,
+			rent_epoch: 0,
+		};
+
+		let params = filter
+			.create_account_match_params(&address, &decoded)
+			.unwrap();
+		assert_eq!(params.signature, "ShortUrl");
+		assert_eq!(find_param(&params, "short_url").value, "kamino");
+		assert_eq!(find_param(&params, "short_url").kind, "string");
+	}
+
+	fn create_event_spec() -> ContractSpec {
+		let idl: crate::services::decoders::AnchorIdl = serde_json::from_value(json!({
+			"events": [{
+				"name": "Deposited",
+				"discriminator": [5, 5, 5, 5, 5, 5, 5, 5],
+				"fields": [{ "name": "amount", "type": "u64" }]
+			}]
+		}))
+		.unwrap();
+		ContractSpec::Solana(SolanaContractSpec::new(
+			crate::models::SolanaDecoderType::Idl(idl),
+		))
+	}
+
+	fn create_event_transaction(program_id: Pubkey, amount: u64) -> SolanaTransaction {
+		use base64::Engine;
+
+		let data = [vec![5; 8], amount.to_le_bytes().to_vec()].concat();
+		TransactionBuilder::new()
+			.meta(SolanaTransactionStatusMeta {
+				log_messages: Some(vec![
+					format!("Program {} invoke [1]", program_id),
+					format!(
+						"Program data: {}",
+						base64::engine::general_purpose::STANDARD.encode(data)
+					),
+					format!("Program {} success", program_id),
+				]),
+				..Default::default()
+			})
+			.build()
+	}
+
+	#[test]
+	fn test_match_transaction_on_event() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), Some(create_event_spec()))
+			.event("Deposited", Some("amount > 10"))
+			.build();
+
+		let monitor_match = filter
+			.match_transaction(
+				&create_event_transaction(program_id, 50),
+				&monitor,
+				&[],
+				"solana_mainnet",
+				None,
+			)
+			.unwrap();
+		let MonitorMatch::Solana(monitor_match) = monitor_match else {
+			panic!("Expected a Solana match");
+		};
+		assert_eq!(monitor_match.matched_on.events.len(), 1);
+		assert_eq!(monitor_match.matched_on.events[0].signature, "Deposited");
+		let events = monitor_match.matched_on_args.unwrap().events.unwrap();
+		assert_eq!(events[0].signature, "Deposited");
+		assert_eq!(find_param(&events[0], "amount").value, "50");
+		assert_eq!(
+			find_param(&events[0], "program_id").value,
+			program_id.to_string()
+		);
+
+		assert!(filter
+			.match_transaction(
+				&create_event_transaction(program_id, 5),
+				&monitor,
+				&[],
+				"solana_mainnet",
+				None,
+			)
+			.is_none());
+	}
+
+	#[test]
+	fn test_match_transaction_ignores_events_of_other_programs() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let transaction = create_event_transaction(program_id, 50);
+
+		// Events of unmonitored programs are not decoded
+		let monitor = MonitorBuilder::new()
+			.address(&Pubkey::new_unique().to_string(), Some(create_event_spec()))
+			.event("Deposited", None)
+			.build();
+		assert!(filter
+			.match_transaction(&transaction, &monitor, &[], "solana_mainnet", None)
+			.is_none());
+
+		// Events of monitored programs without an IDL cannot be decoded
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), None)
+			.event("Deposited", None)
+			.build();
+		assert!(filter
+			.match_transaction(&transaction, &monitor, &[], "solana_mainnet", None)
+			.is_none());
+	}
+
+	#[test]
+	fn test_find_matching_accounts_with_expression() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let vault = Pubkey::new_unique();
+		let accounts = HashMap::from([(vault, create_vault_account(program_id, 500))]);
+
+		let contract_specs = match create_vault_spec() {
+			ContractSpec::Solana(spec) => vec![(program_id.to_string(), spec)],
+			_ => unreachable!(),
+		};
+
+		for (expression, should_match) in [("balance < 1000", true), ("balance >= 1000", false)] {
+			let monitor = MonitorBuilder::new()
+				.account(&vault.to_string(), Some(expression))
+				.build();
+			let mut matched_accounts = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+
+			filter.find_matching_accounts(
+				&accounts,
+				&monitor,
+				&contract_specs,
+				&AccountStates::new(),
+				&mut AccountStates::new(),
+				&mut matched_accounts,
+				&mut matched_on_args,
+			);
+
+			assert_eq!(matched_accounts.len(), usize::from(should_match));
+			assert_eq!(matched_on_args.accounts.is_some(), should_match);
+		}
+	}
+
+	#[test]
+	fn test_find_matching_accounts_uses_monitor_address_spec() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let vault = Pubkey::new_unique();
+		let accounts = HashMap::from([(vault, create_vault_account(program_id, 5))]);
+
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), Some(create_vault_spec()))
+			.account(&vault.to_string(), None)
+			.build();
+		let mut matched_accounts = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: None,
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+
+		filter.find_matching_accounts(
+			&accounts,
+			&monitor,
+			&[],
+			&AccountStates::new(),
+			&mut AccountStates::new(),
+			&mut matched_accounts,
+			&mut matched_on_args,
+		);
+
+		assert_eq!(matched_accounts.len(), 1);
+		let params = &matched_on_args.accounts.unwrap()[0];
+		assert_eq!(params.signature, "Vault");
+		assert_eq!(find_param(params, "balance").value, "5");
+	}
+
+	#[test]
+	fn test_find_matching_accounts_restricted_to_account_spec() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let vault = Pubkey::new_unique();
+		let accounts = HashMap::from([(vault, create_vault_account(program_id, 5))]);
+		let matched_count = |account_spec: SolanaDecoderType| {
+			let monitor = MonitorBuilder::new()
+				.address(&program_id.to_string(), Some(create_vault_spec()))
+				.address(
+					&vault.to_string(),
+					Some(ContractSpec::Solana(SolanaContractSpec::new(account_spec))),
+				)
+				.account(&vault.to_string(), None)
+				.build();
+			let mut matched_accounts = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+			filter.find_matching_accounts(
+				&accounts,
+				&monitor,
+				&[],
+				&AccountStates::new(),
+				&mut AccountStates::new(),
+				&mut matched_accounts,
+				&mut matched_on_args,
+			);
+			matched_accounts.len()
+		};
+
+		assert_eq!(
+			matched_count(SolanaDecoderType::AccountNames(vec!["Vault".to_string()])),
+			1
+		);
+		assert_eq!(
+			matched_count(SolanaDecoderType::AccountNames(vec![
+				"Obligation".to_string()
+			])),
+			0
+		);
+		assert_eq!(
+			matched_count(SolanaDecoderType::Account(AccountType::Custom(
+				crate::services::decoders::CustomAccount {
+					name: "Vault".to_string(),
+					data: Value::Null,
+				}
+			))),
+			1
+		);
+	}
+
+	#[test]
+	fn test_find_matching_accounts_skips_missing_or_undecodable_accounts() {
+		let filter = create_test_filter();
+		let vault = Pubkey::new_unique();
+		let accounts = HashMap::from([(vault, create_vault_account(Pubkey::new_unique(), 5))]);
+
+		let monitor = MonitorBuilder::new()
+			.account(&vault.to_string(), None)
+			.account(&Pubkey::new_unique().to_string(), None)
+			.account("not-a-pubkey", None)
+			.build();
+		let mut matched_accounts = Vec::new();
+		let mut matched_on_args = SolanaMatchArguments {
+			instructions: None,
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		};
+
+		filter.find_matching_accounts(
+			&accounts,
+			&monitor,
+			&[],
+			&AccountStates::new(),
+			&mut AccountStates::new(),
+			&mut matched_accounts,
+			&mut matched_on_args,
+		);
+
+		assert!(matched_accounts.is_empty());
+		assert!(matched_on_args.accounts.is_none());
+	}
+
+	#[test]
+	fn test_find_matching_accounts_on_state_change() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let vault = Pubkey::new_unique();
+		let contract_specs = match create_vault_spec() {
+			ContractSpec::Solana(spec) => vec![(program_id.to_string(), spec)],
+			_ => unreachable!(),
+		};
+		let monitor = MonitorBuilder::new()
+			.account(
+				&vault.to_string(),
+				Some("old_balance < 1000 AND new_balance >= 1000"),
+			)
+			.build();
+
+		let mut previous_states = AccountStates::new();
+		let mut matches = Vec::new();
+		for balance in [500, 1500, 2000] {
+			let accounts = HashMap::from([(vault, create_vault_account(program_id, balance))]);
+			let mut current_states = AccountStates::new();
+			let mut matched_accounts = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+
+			filter.find_matching_accounts(
+				&accounts,
+				&monitor,
+				&contract_specs,
+				&previous_states,
+				&mut current_states,
+				&mut matched_accounts,
+				&mut matched_on_args,
+			);
+
+			matches.push(!matched_accounts.is_empty());
+			previous_states = current_states;
+		}
+
+		assert_eq!(matches, vec![false, true, false]);
+		assert_eq!(find_param_in(&previous_states[&vault], "balance"), "2000");
+	}
+
+	#[test]
+	fn test_find_matching_accounts_on_ownership_change() {
+		use solana_program::program_option::COption;
+		use solana_program_pack::Pack;
+		use spl_token::state::{Account as SplTokenAccount, AccountState};
+
+		let filter = create_test_filter();
+		let token_account = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let drainer = Pubkey::new_unique();
+		let monitor = MonitorBuilder::new()
+			.account_with_mode(
+				&token_account.to_string(),
+				None,
+				AccountConditionMode::OwnershipChange,
+			)
+			.build();
+		let create_account = |owner: Pubkey, close_authority: Option<Pubkey>| {
+			let mut data = vec![0; SplTokenAccount::LEN];
+			SplTokenAccount {
+				mint: Pubkey::default(),
+				owner,
+				amount: 1_000,
+				delegate: COption::None,
+				state: AccountState::Initialized,
+				is_native: COption::None,
+				delegated_amount: 0,
+				close_authority: close_authority.into(),
+			}
+			.pack_into_slice(&mut data);
+			Account {
+				lamports: 2_039_280,
+				data,
+				owner: spl_token::id(),
+				executable: false,
+				rent_epoch: 0,
+			}
+		};
+
+		let mut previous_states = AccountStates::new();
+		let mut matches = Vec::new();
+		for account in [
+			create_account(owner, None),
+			create_account(owner, None),
+			create_account(owner, Some(drainer)),
+			create_account(drainer, Some(drainer)),
+			create_account(drainer, Some(drainer)),
+		] {
+			let accounts = HashMap::from([(token_account, account)]);
+			let mut current_states = AccountStates::new();
+			let mut matched_accounts = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+
+			filter.find_matching_accounts(
+				&accounts,
+				&monitor,
+				&[],
+				&previous_states,
+				&mut current_states,
+				&mut matched_accounts,
+				&mut matched_on_args,
+			);
+
+			matches.push(!matched_accounts.is_empty());
+			previous_states = current_states;
+		}
+
+		assert_eq!(matches, vec![false, false, true, true, false]);
+		assert_eq!(
+			find_param_in(&previous_states[&token_account], "token_owner"),
+			drainer.to_string()
+		);
+	}
+
+	#[test]
+	fn test_find_matching_accounts_on_squads_proposal_approval() {
+		use crate::services::decoders::squads::PROGRAM_ID as SQUADS_PROGRAM_ID;
+
+		let filter = create_test_filter();
+		let proposal = Pubkey::new_unique();
+		let multisig = Pubkey::new_unique();
+		let monitor = MonitorBuilder::new()
+			.account(
+				&proposal.to_string(),
+				Some("old_status != 'Approved' AND new_status == 'Approved'"),
+			)
+			.build();
+		let create_proposal = |status_tag: u8| {
+			let mut data = vec![0x1a, 0x5e, 0xbd, 0xbb, 0x74, 0x88, 0x35, 0x21];
+			data.extend_from_slice(multisig.as_ref());
+			data.extend_from_slice(&1u64.to_le_bytes());
+			data.push(status_tag);
+			data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+			data.push(255);
+			data.extend_from_slice(&[0; 12]);
+			Account {
+				lamports: 1_000_000,
+				data,
+				owner: SQUADS_PROGRAM_ID,
+				executable: false,
+				rent_epoch: 0,
+			}
+		};
+
+		let mut previous_states = AccountStates::new();
+		let mut matches = Vec::new();
+		for status_tag in [1, 1, 3, 3, 5] {
+			let accounts = HashMap::from([(proposal, create_proposal(status_tag))]);
+			let mut current_states = AccountStates::new();
+			let mut matched_accounts = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+
+			filter.find_matching_accounts(
+				&accounts,
+				&monitor,
+				&[],
+				&previous_states,
+				&mut current_states,
+				&mut matched_accounts,
+				&mut matched_on_args,
+			);
+
+			if let Some(params) = matched_on_args.accounts.as_ref().and_then(|a| a.first()) {
+				assert_eq!(params.signature, "Proposal");
+			}
+			matches.push(!matched_accounts.is_empty());
+			previous_states = current_states;
+		}
+
+		assert_eq!(matches, vec![false, false, true, false, false]);
+	}
+
+	fn find_param_in<'a>(params: &'a [SolanaMatchParamEntry], name: &str) -> &'a str {
+		&params
+			.iter()
+			.find(|entry| entry.name == name)
+			.unwrap()
+			.value
+	}
+
+	fn create_token_balance(
+		account_index: u8,
+		mint: &Pubkey,
+		owner: &Pubkey,
+		amount: u64,
+	) -> SolanaTransactionTokenBalance {
+		SolanaTransactionTokenBalance {
+			account_index,
+			mint: mint.to_string(),
+			ui_token_amount: solana_account_decoder::parse_token::UiTokenAmount {
+				ui_amount: None,
+				decimals: 6,
+				amount: amount.to_string(),
+				ui_amount_string: String::new(),
+			},
+			owner: owner.to_string(),
+			program_id: String::new(),
+		}
+	}
+
+	fn create_usdc_transfer_transaction(
+		mint: &Pubkey,
+		sender: &Pubkey,
+		receiver: &Pubkey,
+		amount: u64,
+	) -> SolanaTransaction {
+		let sender_account = Pubkey::new_unique();
+		let message = solana_sdk::message::Message {
+			account_keys: vec![Pubkey::new_unique(), sender_account, Pubkey::new_unique()],
+			..Default::default()
+		};
+		TransactionBuilder::new()
+			.message(solana_sdk::message::VersionedMessage::Legacy(message))
+			.meta(SolanaTransactionStatusMeta {
+				pre_token_balances: Some(vec![
+					create_token_balance(1, mint, sender, 50_000_000_000),
+					create_token_balance(2, mint, receiver, 0),
+				]),
+				post_token_balances: Some(vec![
+					create_token_balance(1, mint, sender, 50_000_000_000 - amount),
+					create_token_balance(2, mint, receiver, amount),
+				]),
+				..Default::default()
+			})
+			.build()
+	}
+
+	#[test]
+	fn test_create_balance_change_params() {
+		let filter = create_test_filter();
+		let mint = Pubkey::new_unique();
+		let sender = Pubkey::new_unique();
+		let receiver = Pubkey::new_unique();
+		let transaction =
+			create_usdc_transfer_transaction(&mint, &sender, &receiver, 12_500_500_000);
+
+		let changes = filter.create_balance_change_params(&transaction);
+		assert_eq!(changes.len(), 2);
+
+		let sent = &changes[0];
+		assert_eq!(sent.signature, mint.to_string());
+		assert_eq!(
+			find_param(sent, "account").value,
+			transaction.metadata.message.static_account_keys()[1].to_string()
+		);
+		assert_eq!(find_param(sent, "owner").value, sender.to_string());
+		assert_eq!(find_param(sent, "delta").value, "-12500500000");
+		assert_eq!(find_param(sent, "ui_delta").value, "-12500.5");
+
+		let received = &changes[1];
+		assert_eq!(find_param(received, "owner").value, receiver.to_string());
+		assert_eq!(find_param(received, "delta").value, "12500500000");
+	}
+
+	#[test]
+	fn test_create_balance_change_params_skips_unchanged_balances() {
+		let filter = create_test_filter();
+		let mint = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let balance = create_token_balance(0, &mint, &owner, 100);
+		let transaction = TransactionBuilder::new()
+			.meta(SolanaTransactionStatusMeta {
+				pre_token_balances: Some(vec![balance.clone()]),
+				post_token_balances: Some(vec![balance]),
+				..Default::default()
+			})
+			.build();
+
+		assert!(filter.create_balance_change_params(&transaction).is_empty());
+	}
+
+	#[test]
+	fn test_find_matching_balance_changes() {
+		let filter = create_test_filter();
+		let mint = Pubkey::new_unique();
+		let sender = Pubkey::new_unique();
+		let receiver = Pubkey::new_unique();
+		let monitor = MonitorBuilder::new()
+			.balance_change(Some(&format!(
+				"owner == '{}' AND mint == '{}' AND ui_delta < -10000",
+				sender, mint
+			)))
+			.build();
+
+		for (amount, should_match) in [(12_000_000_000, true), (9_000_000_000, false)] {
+			let transaction = create_usdc_transfer_transaction(&mint, &sender, &receiver, amount);
+			let mut matched_balance_changes = Vec::new();
+			let mut matched_on_args = SolanaMatchArguments {
+				instructions: None,
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			};
+
+			filter.find_matching_balance_changes(
+				&transaction,
+				&monitor,
+				&mut matched_balance_changes,
+				&mut matched_on_args,
+			);
+
+			assert_eq!(matched_balance_changes.len(), usize::from(should_match));
+			assert_eq!(
+				matched_on_args.balance_changes.map(|changes| changes.len()),
+				should_match.then_some(1)
+			);
+		}
+	}
+
+	fn create_failed_borrow_transaction(program_id: &Pubkey) -> SolanaTransaction {
+		TransactionBuilder::new()
+			.meta(SolanaTransactionStatusMeta {
+				log_messages: Some(vec![
+					format!("Program {} invoke [1]", program_id),
+					"Program log: Instruction: Borrow".to_string(),
+					"Program log: Error: insufficient collateral".to_string(),
+					format!(
+						"Program {} failed: custom program error: 0x1771",
+						program_id
+					),
+				]),
+				..Default::default()
+			})
+			.build()
+	}
+
+	#[test]
+	fn test_create_log_params() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let transaction = create_failed_borrow_transaction(&program_id);
+
+		let logs = filter.create_log_params(&transaction);
+		assert_eq!(logs.len(), 4);
+		assert!(logs.iter().all(|log| log.signature == "log"));
+		assert_eq!(
+			find_param(&logs[2], "log").value,
+			"Program log: Error: insufficient collateral"
+		);
+		assert_eq!(find_param(&logs[2], "log").kind, "string");
+		assert_eq!(
+			find_param(&logs[2], "program_id").value,
+			program_id.to_string()
+		);
+
+		assert!(filter
+			.create_log_params(&TransactionBuilder::new().build())
+			.is_empty());
+	}
+
+	#[test]
+	fn test_match_transaction_on_logs() {
+		let filter = create_test_filter();
+		let program_id = Pubkey::new_unique();
+		let transaction = create_failed_borrow_transaction(&program_id);
+
+		let monitor = MonitorBuilder::new()
+			.log(Some("log contains 'Error: insufficient collateral'"))
+			.build();
+		let Some(MonitorMatch::Solana(monitor_match)) =
+			filter.match_transaction(&transaction, &monitor, &[], "solana_mainnet", None)
+		else {
+			panic!("Expected a Solana monitor match");
+		};
+		assert_eq!(monitor_match.matched_on.logs.len(), 1);
+		let logs = monitor_match.matched_on_args.unwrap().logs.unwrap();
+		assert_eq!(logs.len(), 1);
+		assert_eq!(
+			find_param(&logs[0], "log").value,
+			"Program log: Error: insufficient collateral"
+		);
+
+		let monitor = MonitorBuilder::new()
+			.log(Some(&format!(
+				r"program_id == '{}' AND log matches 'custom program error: 0x[0-9a-f]+$'",
+				program_id
+			)))
+			.build();
+		assert!(filter
+			.match_transaction(&transaction, &monitor, &[], "solana_mainnet", None)
+			.is_some());
+
+		// Log conditions must be satisfied along with the other conditions
+		let monitor = MonitorBuilder::new()
+			.log(Some("log contains 'Error: stale oracle'"))
+			.transaction(None)
+			.build();
+		assert!(filter
+			.match_transaction(&transaction, &monitor, &[], "solana_mainnet", None)
+			.is_none());
+	}
+
+	fn create_sol_transfer_transaction(
+		sender: &Pubkey,
+		receiver: &Pubkey,
+		lamports: u64,
+	) -> SolanaTransaction {
+		let message = solana_sdk::message::Message {
+			account_keys: vec![*sender, *receiver, Pubkey::default()],
+			..Default::default()
+		};
+		TransactionBuilder::new()
+			.message(solana_sdk::message::VersionedMessage::Legacy(message))
+			.meta(SolanaTransactionStatusMeta {
+				fee: 5_000,
+				pre_balances: vec![10_000_000_000, 0, 1],
+				post_balances: vec![10_000_000_000 - lamports - 5_000, lamports, 1],
+				..Default::default()
+			})
+			.build()
+	}
+
+	#[test]
+	fn test_create_sol_delta_params() {
+		let filter = create_test_filter();
+		let sender = Pubkey::new_unique();
+		let receiver = Pubkey::new_unique();
+		let unrelated = Pubkey::new_unique();
+		let transaction = create_sol_transfer_transaction(&sender, &receiver, 1_000);
+
+		let params =
+			filter.create_sol_delta_params(&transaction, &format!("sol_delta_{} < 0", unrelated));
+		let delta = |account: &Pubkey| {
+			params
+				.iter()
+				.find(|param| param.name == format!("sol_delta_{}", account))
+				.map(|param| param.value.as_str())
+		};
+
+		assert_eq!(delta(&sender), Some("-6000"));
+		assert_eq!(delta(&receiver), Some("1000"));
+		assert_eq!(delta(&Pubkey::default()), Some("0"));
+		assert_eq!(delta(&unrelated), Some("0"));
+		assert!(params.iter().all(|param| param.kind == "i128"));
+	}
+
+	#[test]
+	fn test_find_matching_transaction_on_sol_delta() {
+		let filter = create_test_filter();
+		let wallet = Pubkey::new_unique();
+		let mut monitor = MonitorBuilder::new().build();
+		monitor.match_conditions.transactions = vec![TransactionCondition {
+			status: TransactionStatus::Any,
+			expression: Some(format!("sol_delta_{} < -1000000000", wallet)),
+		}];
+
+		for (transaction, should_match) in [
+			(
+				create_sol_transfer_transaction(&wallet, &Pubkey::new_unique(), 5_000_000_000),
+				true,
+			),
+			(
+				create_sol_transfer_transaction(&wallet, &Pubkey::new_unique(), 1_000),
+				false,
+			),
+			(
+				create_sol_transfer_transaction(
+					&Pubkey::new_unique(),
+					&Pubkey::new_unique(),
+					5_000_000_000,
+				),
+				false,
+			),
+		] {
+			let mut matched = Vec::new();
+			filter.find_matching_transaction(&transaction, &monitor, &mut matched);
+			assert_eq!(matched.len(), usize::from(should_match));
+		}
+	}
+
+	/// Client serving a fixed history for each address, and fixed accounts
+	struct HistoryClient {
+		history: HashMap<Pubkey, Vec<SolanaTransaction>>,
+		accounts: HashMap<Pubkey, Account>,
+	}
+
+	#[async_trait]
+	impl SolanaClientTrait for HistoryClient {
+		async fn get_multiple_accounts(
+			&self,
+			addresses: &[Pubkey],
+		) -> Result<Vec<Option<Account>>, anyhow::Error> {
+			Ok(addresses
+				.iter()
+				.map(|address| self.accounts.get(address).cloned())
+				.collect())
+		}
+
+		async fn get_transaction(
+			&self,
+			signature: &Signature,
+		) -> Result<Option<SolanaTransaction>, anyhow::Error> {
+			Ok(self
+				.history
+				.values()
+				.flatten()
+				.find(|transaction| transaction.signature() == signature)
+				.cloned())
+		}
+
+		async fn get_signatures_for_address(
+			&self,
+			address: &Pubkey,
+			start_slot: u64,
+			end_slot: u64,
+		) -> Result<Vec<Signature>, anyhow::Error> {
+			Ok(self
+				.get_transactions_for_address(address, start_slot, end_slot)
+				.await?
+				.iter()
+				.map(|transaction| *transaction.signature())
+				.collect())
+		}
+
+		async fn get_latest_slot(&self) -> Result<u64, anyhow::Error> {
+			Ok(self
+				.history
+				.values()
+				.flatten()
+				.map(|transaction| transaction.slot())
+				.max()
+				.unwrap_or_default())
+		}
+
+		async fn record_slot_lag(&self, last_processed_slot: u64) -> Result<u64, anyhow::Error> {
+			Ok(self
+				.get_latest_slot()
+				.await?
+				.saturating_sub(last_processed_slot))
+		}
+
+		async fn get_transactions_for_address(
+			&self,
+			address: &Pubkey,
+			start_slot: u64,
+			end_slot: u64,
+		) -> Result<Vec<SolanaTransaction>, anyhow::Error> {
+			Ok(self
+				.history
+				.get(address)
+				.into_iter()
+				.flatten()
+				.filter(|transaction| (start_slot..=end_slot).contains(&transaction.slot()))
+				.cloned()
+				.collect())
+		}
+
+		async fn get_idl(
+			&self,
+			_program_id: &Pubkey,
+		) -> Result<Option<crate::services::decoders::AnchorIdl>, anyhow::Error> {
+			// No program of the history publishes an IDL
+			Ok(None)
+		}
+	}
+
+	#[tokio::test]
+	async fn test_backfill_matches_history_of_monitored_addresses() {
+		let filter = SolanaBlockFilter::<HistoryClient>::new();
+		let program_id = Pubkey::new_unique();
+		let other_program_id = Pubkey::new_unique();
+
+		let transaction = |slot: u64, data: u8| {
+			TransactionBuilder::new()
+				.slot(slot)
+				.signature(Signature::new_unique())
+				.instruction(SolanaDecodedInstruction {
+					program_id,
+					data: vec![data],
+					accounts: vec![AccountMeta::new(other_program_id, false)],
+				})
+				.instruction(SolanaDecodedInstruction {
+					program_id: other_program_id,
+					data: vec![data],
+					accounts: vec![],
+				})
+				.build()
+		};
+		let first = transaction(10, 1);
+		let second = transaction(20, 2);
+		let ignored = transaction(30, 2);
+
+		// Both transactions involve both programs and must only be matched once
+		let client = HistoryClient {
+			history: HashMap::from([
+				(program_id, vec![second.clone(), first.clone(), ignored]),
+				(other_program_id, vec![first.clone(), second.clone()]),
+			]),
+			accounts: HashMap::new(),
+		};
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), None)
+			.address(&other_program_id.to_string(), None)
+			.function(RAW_INSTRUCTION_NAME, Some("data == 0x02"))
+			.build();
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("solana_mainnet")
+			.build();
+
+		let matches = filter
+			.backfill(&client, &network, &[monitor], None, 0, 25)
+			.await
+			.unwrap();
+
+		assert_eq!(matches.len(), 1);
+		match &matches[0] {
+			MonitorMatch::Solana(monitor_match) => {
+				assert_eq!(monitor_match.transaction.signature(), second.signature());
+				assert_eq!(monitor_match.network_slug, "solana_mainnet");
+			}
+			_ => panic!("Expected a Solana match"),
+		}
+
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), None)
+			.address(&other_program_id.to_string(), None)
+			.build();
+		let matches = filter
+			.backfill(&client, &network, &[monitor], None, 0, 25)
+			.await
+			.unwrap();
+		let slots = matches
+			.iter()
+			.map(|monitor_match| match monitor_match {
+				MonitorMatch::Solana(monitor_match) => monitor_match.transaction.slot(),
+				_ => panic!("Expected a Solana match"),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(slots, vec![10, 20]);
+	}
+
+	#[tokio::test]
+	async fn test_filter_block_aggregates_block_conditions() {
+		let filter = SolanaBlockFilter::<HistoryClient>::new();
+		let client = HistoryClient {
+			history: HashMap::new(),
+			accounts: HashMap::new(),
+		};
+		let deposit = |amount: u64| solana_sdk::transaction::Transaction {
+			signatures: vec![Signature::new_unique()],
+			message: solana_sdk::message::Message::new(
+				&[solana_sdk::instruction::Instruction {
+					program_id: KAMINO_LENDING_PROGRAM_ID,
+					accounts: vec![],
+					data: create_deposit_data(amount),
+				}],
+				Some(&Pubkey::new_unique()),
+			),
+		};
+		let block = BlockType::Solana(Box::new(crate::models::SolanaBlock {
+			slot: 42,
+			blockhash: String::new(),
+			parent_slot: 41,
+			transactions: vec![deposit(1000), deposit(2000), deposit(3000)],
+			block_time: Some(1_700_000_000),
+			block_height: None,
+			rewards: None,
+			commitment: Default::default(),
+			decoded_transactions: vec![],
+		}));
+		let monitor = |expression: &str| {
+			MonitorBuilder::new()
+				.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+				.function("DepositReserveLiquidity", Some("liquidity_amount > 1500"))
+				.block(Some(expression))
+				.build()
+		};
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("solana_mainnet")
+			.build();
+
+		let matches = filter
+			.filter_block(
+				&client,
+				&network,
+				&block,
+				&[monitor(
+					"match_count >= 2 AND total_liquidity_amount == 5000",
+				)],
+				None,
+			)
+			.await
+			.unwrap();
+		assert_eq!(matches.len(), 1);
+		let MonitorMatch::Solana(block_match) = &matches[0] else {
+			panic!("Expected a Solana match");
+		};
+		assert_eq!(block_match.slot(), 42);
+		assert_eq!(*block_match.signature(), Signature::default());
+		assert_eq!(block_match.matched_on.blocks.len(), 1);
+		assert_eq!(block_match.matched_on.functions.len(), 1);
+		let args = block_match.matched_on_args.as_ref().unwrap();
+		assert_eq!(args.instructions.as_ref().unwrap().len(), 2);
+		let block_params = &args.blocks.as_ref().unwrap()[0];
+		assert_eq!(find_param(block_params, "transaction_count").value, "3");
+		assert_eq!(find_param(block_params, "match_count").value, "2");
+		assert_eq!(
+			find_param(block_params, "total_liquidity_amount").value,
+			"5000"
+		);
+		// Block matches are not tied to an instruction
+		assert_eq!(block_match.program_id(), None);
+		assert_eq!(block_match.accounts(), None);
+		assert_eq!(block_match.data(), None);
+
+		// Transaction matches are dropped when no block condition matches
+		let matches = filter
+			.filter_block(
+				&client,
+				&network,
+				&block,
+				&[monitor("match_count > 2")],
+				None,
+			)
+			.await
+			.unwrap();
+		assert!(matches.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_filter_block_matches_monitored_accounts() {
+		let filter = SolanaBlockFilter::<HistoryClient>::new();
+		let program_id = Pubkey::new_unique();
+		let vault = Pubkey::new_unique();
+		let client = HistoryClient {
+			history: HashMap::new(),
+			accounts: HashMap::from([(vault, create_vault_account(program_id, 500))]),
+		};
+		let block = BlockType::Solana(Box::new(crate::models::SolanaBlock {
+			slot: 42,
+			blockhash: String::new(),
+			parent_slot: 41,
+			transactions: vec![],
+			block_time: Some(1_700_000_000),
+			block_height: None,
+			rewards: None,
+			commitment: Default::default(),
+			decoded_transactions: vec![],
+		}));
+		let monitor = MonitorBuilder::new()
+			.account(&vault.to_string(), Some("balance < 1000"))
+			.build();
+		let contract_specs = vec![(program_id.to_string(), create_vault_spec())];
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("solana_mainnet")
+			.build();
+
+		let matches = filter
+			.filter_block(
+				&client,
+				&network,
+				&block,
+				&[monitor],
+				Some(contract_specs.as_slice()),
+			)
+			.await
+			.unwrap();
+
+		assert_eq!(matches.len(), 1);
+		let MonitorMatch::Solana(account_match) = &matches[0] else {
+			panic!("Expected a Solana match");
+		};
+		assert_eq!(account_match.slot(), 42);
+		assert_eq!(account_match.matched_on.accounts.len(), 1);
+		// Account matches are not tied to an instruction
+		assert_eq!(account_match.program_id(), None);
+		assert_eq!(account_match.accounts(), None);
+		assert_eq!(account_match.data(), None);
+	}
+
+	#[tokio::test]
+	async fn test_filter_block_keeps_transaction_order() {
+		let filter = SolanaBlockFilter::<HistoryClient>::new();
+		let client = HistoryClient {
+			history: HashMap::new(),
+			accounts: HashMap::new(),
+		};
+		let transactions = (0..200u64)
+			.map(|amount| solana_sdk::transaction::Transaction {
+				signatures: vec![Signature::new_unique()],
+				message: solana_sdk::message::Message::new(
+					&[solana_sdk::instruction::Instruction {
+						program_id: KAMINO_LENDING_PROGRAM_ID,
+						accounts: vec![],
+						data: create_deposit_data(amount),
+					}],
+					Some(&Pubkey::new_unique()),
+				),
+			})
+			.collect::<Vec<_>>();
+		let signatures = transactions
+			.iter()
+			.skip(50)
+			.map(|transaction| transaction.signatures[0])
+			.collect::<Vec<_>>();
+		let block = BlockType::Solana(Box::new(crate::models::SolanaBlock {
+			slot: 42,
+			blockhash: String::new(),
+			parent_slot: 41,
+			transactions,
+			block_time: None,
+			block_height: None,
+			rewards: None,
+			commitment: Default::default(),
+			decoded_transactions: vec![],
+		}));
+		let monitor =
+			create_kamino_monitor("DepositReserveLiquidity", Some("liquidity_amount >= 50"));
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("solana_mainnet")
+			.build();
+
+		let matches = filter
+			.filter_block(&client, &network, &block, &[monitor], None)
+			.await
+			.unwrap();
+
+		let matched_signatures = matches
+			.iter()
+			.map(|monitor_match| match monitor_match {
+				MonitorMatch::Solana(monitor_match) => *monitor_match.signature(),
+				_ => panic!("Expected a Solana match"),
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(matched_signatures, signatures);
+	}
+
+	#[tokio::test]
+	async fn test_filter_block_skips_malformed_transactions() {
+		let filter = SolanaBlockFilter::<HistoryClient>::new();
+		let client = HistoryClient {
+			history: HashMap::new(),
+			accounts: HashMap::new(),
+		};
+		let program_id = Pubkey::new_unique();
+		let transaction = |signatures: Vec<Signature>| solana_sdk::transaction::Transaction {
+			signatures,
+			message: solana_sdk::message::Message::new(
+				&[solana_sdk::instruction::Instruction {
+					program_id,
+					accounts: vec![],
+					data: vec![1],
+				}],
+				Some(&Pubkey::new_unique()),
+			),
+		};
+		let block = BlockType::Solana(Box::new(crate::models::SolanaBlock {
+			slot: 42,
+			blockhash: String::new(),
+			parent_slot: 41,
+			transactions: vec![
+				transaction(vec![]),
+				transaction(vec![Signature::new_unique()]),
+			],
+			block_time: None,
+			block_height: None,
+			rewards: None,
+			commitment: Default::default(),
+			decoded_transactions: vec![],
+		}));
+		let monitor = MonitorBuilder::new()
+			.address(&program_id.to_string(), None)
+			.build();
+		let network = crate::utils::tests::builders::network::NetworkBuilder::new()
+			.slug("solana_malformed_transactions")
+			.build();
+
+		let matches = filter
+			.filter_block(&client, &network, &block, &[monitor], None)
+			.await
+			.unwrap();
+
+		assert_eq!(matches.len(), 1);
+		assert_eq!(
+			SOLANA_TRANSACTION_ERRORS
+				.with_label_values(&["solana_malformed_transactions"])
+				.get(),
+			1
+		);
 	}
 }
@@ -1,12 +1,16 @@
-use solana_client::rpc_client::RpcClient;
-use solana_sdk::transaction::Transaction;
+//! Helper functions for Solana-specific operations.
+//!
+//! This module provides utility functions for working with Solana-specific data types
+//! and formatting, including instruction name normalization and conversion of decoded
+//! instruction arguments into match parameters.
 
-use crate::{
-	models::{BlockType, Monitor, MonitorMatch},
-	services::filter::error::FilterError,
-};
+use serde_json::Value;
+use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
+
+use crate::models::SolanaMatchParamEntry;
 
 /// Helper functions for Solana block filtering
+#[derive(Default)]
 pub struct SolanaFilterHelpers;
 
 impl SolanaFilterHelpers {
@@ -35,3 +39,344 @@ impl SolanaFilterHelpers {
 		tx.message.instructions.iter().any(|ix| ix.data == data)
 	}
 }
+
+/// Checks if a string is a valid base58 encoded public key.
+///
+/// # Arguments
+/// * `value` - The string to check
+///
+/// # Returns
+/// `true` if the string decodes to a 32 byte public key
+pub fn is_pubkey(value: &str) -> bool {
+	(32..=44).contains(&value.len()) && value.parse::<Pubkey>().is_ok()
+}
+
+/// Parses a public key written in any of the forms accepted in configs.
+///
+/// Surrounding whitespace and quotes are ignored, and besides the base58 form returned by
+/// `Pubkey::to_string`, the 32 bytes can be given as `0x` prefixed hex or as a JSON byte
+/// array (e.g., the contents of a keypair-style file).
+///
+/// # Arguments
+/// * `value` - The public key to parse
+///
+/// # Returns
+/// The public key, or `None` if the value is not a public key in a supported form
+pub fn parse_pubkey(value: &str) -> Option<Pubkey> {
+	let trimmed = value.trim().trim_matches(|c| c == '\'' || c == '"').trim();
+
+	if let Some(hex_value) = trimmed
+		.strip_prefix("0x")
+		.or_else(|| trimmed.strip_prefix("0X"))
+	{
+		return hex::decode(hex_value)
+			.ok()
+			.and_then(|bytes| Pubkey::try_from(bytes.as_slice()).ok());
+	}
+
+	if trimmed.starts_with('[') {
+		return serde_json::from_str::<Vec<u8>>(trimmed)
+			.ok()
+			.and_then(|bytes| Pubkey::try_from(bytes.as_slice()).ok());
+	}
+
+	trimmed.parse().ok()
+}
+
+/// Normalizes a public key for comparison.
+///
+/// Values that parse as a public key (see [`parse_pubkey`]) are converted to their base58
+/// form, other values are only trimmed. Base58 is case-sensitive, so case is preserved.
+///
+/// # Arguments
+/// * `value` - The public key to normalize
+///
+/// # Returns
+/// The normalized public key
+pub fn normalize_pubkey(value: &str) -> String {
+	parse_pubkey(value)
+		.map(|pubkey| pubkey.to_string())
+		.unwrap_or_else(|| value.trim().to_string())
+}
+
+/// Normalizes an instruction name or signature for comparison.
+///
+/// Any argument list is dropped, and the name is lowercased with underscores removed.
+/// This makes `deposit_reserve_liquidity`, `depositReserveLiquidity` and
+/// `DepositReserveLiquidity(u64)` equivalent.
+///
+/// # Arguments
+/// * `name` - The instruction name or signature
+///
+/// # Returns
+/// The normalized instruction name
+pub fn normalize_instruction_name(name: &str) -> String {
+	name.split('(')
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.filter(|c| *c != '_' && !c.is_whitespace())
+		.flat_map(char::to_lowercase)
+		.collect()
+}
+
+/// Compares an instruction signature from a monitor condition with a decoded instruction name.
+///
+/// # Arguments
+/// * `signature` - The signature from the monitor condition
+/// * `instruction_name` - The decoded instruction name
+///
+/// # Returns
+/// `true` if both refer to the same instruction
+pub fn are_same_instruction(signature: &str, instruction_name: &str) -> bool {
+	normalize_instruction_name(signature) == normalize_instruction_name(instruction_name)
+}
+
+/// Parses the parameters declared by an instruction signature.
+///
+/// Parameters are declared by type only, like `deposit(u64)`, or followed by their name,
+/// like `deposit(u64 amount, pubkey owner)`.
+///
+/// # Arguments
+/// * `signature` - The signature from the monitor condition
+///
+/// # Returns
+/// The type and, when declared, the name of each parameter, in order
+pub fn parse_signature_params(signature: &str) -> Vec<(String, Option<String>)> {
+	let Some((_, rest)) = signature.split_once('(') else {
+		return Vec::new();
+	};
+	let params = rest.rsplit_once(')').map_or(rest, |(params, _)| params);
+
+	let mut parts = Vec::new();
+	let mut depth = 0usize;
+	let mut start = 0;
+	for (i, c) in params.char_indices() {
+		match c {
+			'(' | '[' | '<' => depth += 1,
+			')' | ']' | '>' => depth = depth.saturating_sub(1),
+			',' if depth == 0 => {
+				parts.push(&params[start..i]);
+				start = i + 1;
+			}
+			_ => {}
+		}
+	}
+	parts.push(&params[start..]);
+
+	parts
+		.into_iter()
+		.map(str::trim)
+		.filter(|part| !part.is_empty())
+		.map(|part| match part.rsplit_once(char::is_whitespace) {
+			Some((ty, name))
+				if name
+					.chars()
+					.next()
+					.is_some_and(|c| c.is_alphabetic() || c == '_')
+					&& name.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+			{
+				(ty.trim().to_string(), Some(name.to_string()))
+			}
+			_ => (part.to_string(), None),
+		})
+		.collect()
+}
+
+/// Gets the kind of a decoded JSON value.
+///
+/// # Arguments
+/// * `value` - The JSON value to get the kind for
+///
+/// # Returns
+/// The kind used by the Solana condition evaluator
+pub fn get_kind_from_value(value: &Value) -> String {
+	match value {
+		Value::Bool(_) => "bool".to_string(),
+		Value::Number(n) => {
+			if n.is_u64() {
+				"u64".to_string()
+			} else if n.is_i64() {
+				"i64".to_string()
+			} else {
+				"f64".to_string()
+			}
+		}
+		Value::String(s) => {
+			if is_pubkey(s) {
+				"pubkey".to_string()
+			} else if s.starts_with("0x") && hex::decode(&s[2..]).is_ok() {
+				"bytes".to_string()
+			} else if !s.is_empty() && s.parse::<u128>().is_ok() {
+				"u128".to_string()
+			} else if !s.is_empty() && s.parse::<i128>().is_ok() {
+				"i128".to_string()
+			} else {
+				"string".to_string()
+			}
+		}
+		Value::Array(_) => "vec".to_string(),
+		Value::Object(_) => "map".to_string(),
+		Value::Null => "null".to_string(),
+	}
+}
+
+/// Converts a named JSON value into a match parameter entry.
+///
+/// # Arguments
+/// * `name` - The parameter name
+/// * `value` - The decoded JSON value
+///
+/// # Returns
+/// A match parameter entry with the value kind inferred from the JSON value
+pub fn value_to_param_entry(name: &str, value: &Value) -> SolanaMatchParamEntry {
+	SolanaMatchParamEntry {
+		name: name.to_string(),
+		kind: get_kind_from_value(value),
+		value: match value {
+			Value::String(s) => s.clone(),
+			_ => value.to_string(),
+		},
+		indexed: false,
+	}
+}
+
+/// Converts decoded instruction arguments into match parameter entries.
+///
+/// Each field of a JSON object becomes its own parameter. Any other value is exposed
+/// as a single parameter named `0`.
+///
+/// # Arguments
+/// * `args` - The decoded instruction arguments
+///
+/// # Returns
+/// The match parameter entries for the arguments
+pub fn args_to_param_entries(args: &Value) -> Vec<SolanaMatchParamEntry> {
+	match args {
+		Value::Object(map) => map
+			.iter()
+			.map(|(name, value)| value_to_param_entry(name, value))
+			.collect(),
+		Value::Null => Vec::new(),
+		other => vec![value_to_param_entry("0", other)],
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_normalize_instruction_name() {
+		assert_eq!(
+			normalize_instruction_name("deposit_reserve_liquidity"),
+			"depositreserveliquidity"
+		);
+		assert_eq!(
+			normalize_instruction_name("DepositReserveLiquidity(u64)"),
+			"depositreserveliquidity"
+		);
+		assert!(are_same_instruction(
+			"depositReserveLiquidity",
+			"DepositReserveLiquidity"
+		));
+		assert!(!are_same_instruction("deposit", "DepositReserveLiquidity"));
+	}
+
+	#[test]
+	fn test_parse_signature_params() {
+		assert!(parse_signature_params("deposit").is_empty());
+		assert!(parse_signature_params("deposit()").is_empty());
+		assert_eq!(
+			parse_signature_params("deposit(u64)"),
+			vec![("u64".to_string(), None)]
+		);
+		assert_eq!(
+			parse_signature_params("deposit(u64 amount, [u8; 32], Option<pubkey> owner)"),
+			vec![
+				("u64".to_string(), Some("amount".to_string())),
+				("[u8; 32]".to_string(), None),
+				("Option<pubkey>".to_string(), Some("owner".to_string())),
+			]
+		);
+	}
+
+	#[test]
+	fn test_parse_pubkey_forms() {
+		let base58 = "KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD";
+		let pubkey = base58.parse::<Pubkey>().unwrap();
+
+		assert_eq!(parse_pubkey(base58), Some(pubkey));
+		assert_eq!(parse_pubkey(&format!(" \t{}\n", base58)), Some(pubkey));
+		assert_eq!(parse_pubkey(&format!("'{}'", base58)), Some(pubkey));
+		assert_eq!(
+			parse_pubkey(&format!("0x{}", hex::encode(pubkey.to_bytes()))),
+			Some(pubkey)
+		);
+		assert_eq!(
+			parse_pubkey(&serde_json::to_string(&pubkey.to_bytes()).unwrap()),
+			Some(pubkey)
+		);
+
+		// Lowercasing produces the non-base58 character 'l'
+		assert_eq!(parse_pubkey(&base58.to_lowercase()), None);
+		assert_eq!(parse_pubkey("0xdeadbeef"), None);
+		assert_eq!(parse_pubkey("[1, 2, 3]"), None);
+		assert_eq!(parse_pubkey("hello"), None);
+	}
+
+	#[test]
+	fn test_normalize_pubkey() {
+		let pubkey = Pubkey::new_unique();
+		assert_eq!(
+			normalize_pubkey(&format!(" {} ", pubkey)),
+			pubkey.to_string()
+		);
+		assert_eq!(
+			normalize_pubkey(&format!("0X{}", hex::encode(pubkey.to_bytes()))),
+			pubkey.to_string()
+		);
+		assert_eq!(normalize_pubkey(" not a key "), "not a key");
+	}
+
+	#[test]
+	fn test_get_kind_from_value() {
+		assert_eq!(get_kind_from_value(&json!(true)), "bool");
+		assert_eq!(get_kind_from_value(&json!(1)), "u64");
+		assert_eq!(get_kind_from_value(&json!(-1)), "i64");
+		assert_eq!(get_kind_from_value(&json!(1.5)), "f64");
+		assert_eq!(
+			get_kind_from_value(&json!("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD")),
+			"pubkey"
+		);
+		assert_eq!(get_kind_from_value(&json!("0xdeadbeef")), "bytes");
+		assert_eq!(
+			get_kind_from_value(&json!("340282366920938463463374607431768211455")),
+			"u128"
+		);
+		assert_eq!(get_kind_from_value(&json!("-1")), "i128");
+		assert_eq!(get_kind_from_value(&json!("hello")), "string");
+		assert_eq!(get_kind_from_value(&json!([1, 2])), "vec");
+		assert_eq!(get_kind_from_value(&json!({ "a": 1 })), "map");
+		assert_eq!(get_kind_from_value(&Value::Null), "null");
+	}
+
+	#[test]
+	fn test_args_to_param_entries() {
+		let entries = args_to_param_entries(&json!({ "amount": 100, "memo": "hi" }));
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].name, "amount");
+		assert_eq!(entries[0].value, "100");
+		assert_eq!(entries[0].kind, "u64");
+		assert_eq!(entries[1].name, "memo");
+		assert_eq!(entries[1].value, "hi");
+		assert_eq!(entries[1].kind, "string");
+
+		let entries = args_to_param_entries(&json!(5));
+		assert_eq!(entries.len(), 1);
+		assert_eq!(entries[0].name, "0");
+
+		assert!(args_to_param_entries(&Value::Null).is_empty());
+	}
+}
@@ -0,0 +1,81 @@
+//! Cache of the compiled patterns used by the `matches` operator.
+//!
+//! Filters are created for every processed block and expressions are evaluated for every
+//! transaction, so each pattern of a monitor is compiled once and kept in a cache shared by
+//! all filters.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+	static ref SHARED_REGEX_CACHE: Arc<RegexCache> = Arc::new(RegexCache::default());
+}
+
+/// Compiled regular expressions, keyed by pattern
+#[derive(Debug, Default)]
+pub struct RegexCache {
+	patterns: RwLock<HashMap<String, Regex>>,
+}
+
+impl RegexCache {
+	/// Returns the cache shared by all Solana filters
+	pub fn shared() -> Arc<Self> {
+		SHARED_REGEX_CACHE.clone()
+	}
+
+	/// Returns the compiled regular expression of a pattern, compiling it on first use
+	///
+	/// Patterns that fail to compile are not cached.
+	pub fn get_or_compile(&self, pattern: &str) -> Result<Regex, regex::Error> {
+		if let Some(regex) = self
+			.patterns
+			.read()
+			.ok()
+			.and_then(|patterns| patterns.get(pattern).cloned())
+		{
+			return Ok(regex);
+		}
+
+		let regex = Regex::new(pattern)?;
+		if let Ok(mut patterns) = self.patterns.write() {
+			patterns.insert(pattern.to_string(), regex.clone());
+		}
+		Ok(regex)
+	}
+
+	/// Returns the number of cached patterns
+	pub fn len(&self) -> usize {
+		self.patterns
+			.read()
+			.map(|patterns| patterns.len())
+			.unwrap_or(0)
+	}
+
+	/// Returns true if no pattern is cached
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_get_or_compile_caches_valid_patterns() {
+		let cache = RegexCache::default();
+
+		let regex = cache.get_or_compile("^swap_[0-9]+$").unwrap();
+		assert!(regex.is_match("swap_42"));
+		cache.get_or_compile("^swap_[0-9]+$").unwrap();
+		assert_eq!(cache.len(), 1);
+
+		assert!(cache.get_or_compile("(unclosed").is_err());
+		assert_eq!(cache.len(), 1);
+	}
+}
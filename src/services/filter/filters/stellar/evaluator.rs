@@ -5,7 +5,7 @@ use super::helpers;
 use crate::{
 	models::StellarMatchParamEntry,
 	services::filter::expression::{
-		compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
+		self, compare_ordered_values, ComparisonOperator, ConditionEvaluator, EvaluationError,
 		LiteralValue,
 	},
 };
@@ -505,14 +505,7 @@ impl ConditionEvaluator for StellarConditionEvaluator<'_> {
 	/// Returns:
 	/// - The base parameter.
 	fn get_base_param(&self, name: &str) -> Result<(&str, &str), EvaluationError> {
-		self.args
-			.iter()
-			.find(|entry| entry.name == name)
-			.map(|entry| (entry.value.as_str(), entry.kind.as_str()))
-			.ok_or_else(|| {
-				let msg = format!("Base parameter not found: {}", name);
-				EvaluationError::variable_not_found(msg, None, None)
-			})
+		expression::get_base_param(self.args, name)
 	}
 
 	/// This method is used to get the kind of the value from the JSON value.
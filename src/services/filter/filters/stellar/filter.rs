@@ -16,10 +16,11 @@ use tracing::instrument;
 
 use crate::{
 	models::{
-		BlockType, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-		MonitorMatch, Network, StellarContractFunction, StellarEvent, StellarFormattedContractSpec,
-		StellarMatchArguments, StellarMatchParamEntry, StellarMatchParamsMap, StellarMonitorMatch,
-		StellarTransaction, TransactionCondition, TransactionStatus,
+		BlockType, ConditionLogic, ContractSpec, EventCondition, FunctionCondition,
+		MatchConditions, Monitor, MonitorMatch, Network, StellarContractFunction, StellarEvent,
+		StellarFormattedContractSpec, StellarMatchArguments, StellarMatchParamEntry,
+		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, TransactionCondition,
+		TransactionStatus,
 	},
 	services::{
 		blockchain::{BlockChainClient, StellarClientTrait},
@@ -695,28 +696,7 @@ impl<T> StellarBlockFilter<T> {
 		expression: &str,
 		args: &[StellarMatchParamEntry],
 	) -> Result<bool, EvaluationError> {
-		// Check if the expression is empty
-		if expression.trim().is_empty() {
-			tracing::error!("Empty expression provided for evaluation");
-			return Err(EvaluationError::parse_error(
-				"Expression cannot be empty".to_string(),
-				None,
-				None,
-			));
-		}
-
-		let evaluator = StellarConditionEvaluator::new(args);
-
-		// Parse the expression
-		let parsed_ast = expression::parse(expression).map_err(|e| {
-			tracing::error!("Failed to parse expression '{}': {}", expression, e);
-			let msg = format!("Failed to parse expression '{}': {}", expression, e);
-			EvaluationError::parse_error(msg, None, None)
-		})?;
-		tracing::debug!("Parsed AST for '{}': {:?}", expression, parsed_ast);
-
-		// Evaluate the expression
-		expression::evaluate(&parsed_ast, &evaluator)
+		expression::evaluate_expression(expression, &StellarConditionEvaluator::new(args))
 	}
 }
 
@@ -900,6 +880,12 @@ impl<T: BlockChainClient + StellarClientTrait> BlockFilter for StellarBlockFilte
 								.into_iter()
 								.filter(|_| has_transaction_match)
 								.collect(),
+							accounts: vec![],
+							balance_changes: vec![],
+							logs: vec![],
+							sequences: vec![],
+							blocks: vec![],
+							condition_logic: ConditionLogic::All,
 						},
 						matched_on_args: Some(StellarMatchArguments {
 							events: if has_event_match {
@@ -970,6 +956,12 @@ mod tests {
 				events: event_conditions,
 				functions: function_conditions,
 				transactions: transaction_conditions,
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			})
 			.build()
 	}
@@ -1415,6 +1407,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
@@ -1494,6 +1487,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
@@ -1568,6 +1562,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
@@ -1642,6 +1637,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_different_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
@@ -1720,6 +1716,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
@@ -1795,6 +1792,7 @@ mod tests {
 			vec![AddressWithSpec {
 				address: normalized_contract_address.clone(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 		);
 
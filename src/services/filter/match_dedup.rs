@@ -0,0 +1,309 @@
+//! Deduplication of monitor matches.
+//!
+//! Re-processing a block, e.g. after a restart or a failover, produces the same matches
+//! again. The deduplicator remembers the matches it has seen for a configurable time, keyed
+//! by monitor, network, transaction and matched conditions, so that their triggers only fire
+//! once. Deduplication is disabled unless a TTL is configured. Seen matches can be persisted
+//! to a file to survive restarts.
+
+use std::{
+	collections::HashMap,
+	path::PathBuf,
+	sync::atomic::{AtomicBool, Ordering},
+	time::Duration,
+};
+
+use sha2::{Digest, Sha256};
+use solana_sdk::signature::Signature;
+use tokio::sync::Mutex;
+
+use crate::{
+	models::{MatchConditions, MonitorMatch},
+	services::filter::evm_helpers::b256_to_string,
+	utils::write_atomically,
+};
+
+/// Time during which identical matches are not notified again, unless configured otherwise
+///
+/// Zero, so that every match is notified unless deduplication is enabled.
+pub const DEFAULT_MATCH_DEDUP_TTL: Duration = Duration::ZERO;
+
+/// Default file the seen matches are persisted to
+pub const DEFAULT_MATCH_DEDUP_PATH: &str = "data/match_dedup.json";
+
+/// Interval at which the seen matches are persisted
+pub const MATCH_DEDUP_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Filters out the monitor matches that were already seen recently
+pub struct MatchDeduplicator {
+	/// Time during which a seen match is considered a duplicate
+	ttl: Duration,
+	/// File the seen matches are persisted to, if any
+	storage_path: Option<PathBuf>,
+	/// Keys of the seen matches, with the Unix timestamp at which they expire
+	seen: Mutex<HashMap<String, i64>>,
+	/// Whether matches were seen since the last time they were persisted
+	dirty: AtomicBool,
+}
+
+impl MatchDeduplicator {
+	/// Creates a deduplicator keeping the seen matches in memory only
+	///
+	/// # Arguments
+	/// * `ttl` - Time during which a seen match is considered a duplicate, zero disabling
+	///   deduplication
+	pub fn new(ttl: Duration) -> Self {
+		Self {
+			ttl,
+			storage_path: None,
+			seen: Mutex::new(HashMap::new()),
+			dirty: AtomicBool::new(false),
+		}
+	}
+
+	/// Creates a deduplicator persisting the seen matches to a file
+	///
+	/// The matches seen before a restart are loaded from the file. A missing or unreadable
+	/// file starts the deduplicator empty.
+	///
+	/// # Arguments
+	/// * `ttl` - Time during which a seen match is considered a duplicate, zero disabling
+	///   deduplication
+	/// * `storage_path` - File the seen matches are persisted to
+	pub async fn with_storage(ttl: Duration, storage_path: PathBuf) -> Self {
+		let seen = match tokio::fs::read_to_string(&storage_path).await {
+			Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+				tracing::warn!(
+					"Ignoring unreadable match deduplication file {}: {}",
+					storage_path.display(),
+					e
+				);
+				HashMap::new()
+			}),
+			Err(_) => HashMap::new(),
+		};
+
+		Self {
+			ttl,
+			storage_path: Some(storage_path),
+			seen: Mutex::new(seen),
+			dirty: AtomicBool::new(false),
+		}
+	}
+
+	/// Returns the matches that were not seen within the TTL, and records them as seen
+	///
+	/// Expired entries are pruned. New matches are persisted by the next `flush`.
+	///
+	/// # Arguments
+	/// * `matches` - The matches of a processed block
+	pub async fn retain_new(&self, matches: &[MonitorMatch]) -> Vec<MonitorMatch> {
+		if self.ttl.is_zero() {
+			return matches.to_vec();
+		}
+
+		let now = chrono::Utc::now().timestamp();
+		let expires_at = now.saturating_add(self.ttl.as_secs().try_into().unwrap_or(i64::MAX));
+		let mut seen = self.seen.lock().await;
+		seen.retain(|_, expiry| *expiry > now);
+
+		let new_matches: Vec<MonitorMatch> = matches
+			.iter()
+			.filter(|monitor_match| {
+				let key = match_key(monitor_match);
+				if seen.contains_key(&key) {
+					tracing::debug!("Skipping duplicate match {}", key);
+					return false;
+				}
+				seen.insert(key, expires_at);
+				true
+			})
+			.cloned()
+			.collect();
+
+		if !new_matches.is_empty() {
+			self.dirty.store(true, Ordering::Relaxed);
+		}
+
+		new_matches
+	}
+
+	/// Persists the seen matches if new ones were recorded since the last flush
+	///
+	/// Matches are written to a temporary file renamed over the previous one, so that a crash
+	/// never leaves a truncated file behind. Failing to persist them only logs a warning, and
+	/// they are persisted again by the next flush.
+	pub async fn flush(&self) {
+		let Some(path) = &self.storage_path else {
+			return;
+		};
+		if !self.dirty.swap(false, Ordering::Relaxed) {
+			return;
+		}
+
+		let json = serde_json::to_string(&*self.seen.lock().await);
+		let persisted = match json {
+			Ok(json) => write_atomically(path, json).await,
+			Err(e) => Err(e.into()),
+		};
+		if let Err(e) = persisted {
+			self.dirty.store(true, Ordering::Relaxed);
+			tracing::warn!(
+				"Failed to persist seen matches to {}: {}",
+				path.display(),
+				e
+			);
+		}
+	}
+}
+
+/// Returns the key identifying a match across block re-processing
+///
+/// The key is the SHA-256 of the monitor name, network, transaction and matched conditions.
+/// Solana account matches have no transaction, so their slot is used instead.
+pub fn match_key(monitor_match: &MonitorMatch) -> String {
+	let (monitor, network, transaction, matched_on): (_, _, _, &MatchConditions) =
+		match monitor_match {
+			MonitorMatch::EVM(evm_match) => (
+				&evm_match.monitor.name,
+				&evm_match.network_slug,
+				b256_to_string(*evm_match.transaction.hash()),
+				&evm_match.matched_on,
+			),
+			MonitorMatch::Stellar(stellar_match) => (
+				&stellar_match.monitor.name,
+				&stellar_match.network_slug,
+				stellar_match.transaction.hash().clone(),
+				&stellar_match.matched_on,
+			),
+			MonitorMatch::Solana(solana_match) => (
+				&solana_match.monitor.name,
+				&solana_match.network_slug,
+				if *solana_match.signature() == Signature::default() {
+					format!("slot {}", solana_match.slot())
+				} else {
+					solana_match.signature().to_string()
+				},
+				solana_match.matched_on(),
+			),
+		};
+
+	let identity = serde_json::json!([monitor, network, transaction, matched_on]);
+	hex::encode(Sha256::digest(identity.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{SolanaMonitorMatch, SolanaTransaction},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use tempfile::TempDir;
+
+	const TTL: Duration = Duration::from_secs(3600);
+
+	fn create_solana_match(monitor_name: &str, transaction: &SolanaTransaction) -> MonitorMatch {
+		let monitor = MonitorBuilder::new()
+			.name(monitor_name)
+			.function("DepositReserveLiquidity", None)
+			.build();
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch {
+			matched_on: monitor.match_conditions.clone(),
+			monitor,
+			network_slug: "solana_mainnet".to_string(),
+			transaction: transaction.clone(),
+			matched_on_args: None,
+			matched_instructions: vec![],
+		}))
+	}
+
+	#[tokio::test]
+	async fn test_retain_new_skips_seen_matches() {
+		let deduplicator = MatchDeduplicator::new(TTL);
+		let transaction = TransactionBuilder::new().build();
+		let other_transaction = TransactionBuilder::new().build();
+
+		let matches = vec![
+			create_solana_match("Deposits", &transaction),
+			create_solana_match("Deposits", &transaction),
+			create_solana_match("Large deposits", &transaction),
+		];
+		assert_eq!(deduplicator.retain_new(&matches).await.len(), 2);
+
+		// Re-processing the block yields no new match
+		assert!(deduplicator.retain_new(&matches).await.is_empty());
+		assert_eq!(
+			deduplicator
+				.retain_new(&[create_solana_match("Deposits", &other_transaction)])
+				.await
+				.len(),
+			1
+		);
+	}
+
+	#[tokio::test]
+	async fn test_retain_new_after_expiry() {
+		let transaction = TransactionBuilder::new().build();
+		let matches = vec![create_solana_match("Deposits", &transaction)];
+
+		// Deduplication is disabled by default
+		let deduplicator = MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL);
+		assert_eq!(deduplicator.retain_new(&matches).await.len(), 1);
+		assert_eq!(deduplicator.retain_new(&matches).await.len(), 1);
+
+		let deduplicator = MatchDeduplicator::new(TTL);
+		deduplicator
+			.seen
+			.lock()
+			.await
+			.insert(match_key(&matches[0]), chrono::Utc::now().timestamp() - 1);
+		assert_eq!(deduplicator.retain_new(&matches).await.len(), 1);
+		assert!(deduplicator.retain_new(&matches).await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_seen_matches_survive_restarts() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("match_dedup.json");
+		let transaction = TransactionBuilder::new().build();
+		let matches = vec![create_solana_match("Deposits", &transaction)];
+
+		let deduplicator = MatchDeduplicator::with_storage(TTL, path.clone()).await;
+		assert_eq!(deduplicator.retain_new(&matches).await.len(), 1);
+
+		// Seen matches are only written by a flush
+		assert!(!path.exists());
+		deduplicator.flush().await;
+		assert!(path.exists());
+		assert!(!temp_dir.path().join("match_dedup.json.tmp").exists());
+
+		let restarted = MatchDeduplicator::with_storage(TTL, path.clone()).await;
+		assert!(restarted.retain_new(&matches).await.is_empty());
+
+		// An unreadable file starts the deduplicator empty
+		tokio::fs::write(&path, "not json").await.unwrap();
+		let restarted = MatchDeduplicator::with_storage(TTL, path).await;
+		assert_eq!(restarted.retain_new(&matches).await.len(), 1);
+	}
+
+	#[test]
+	fn test_match_key_of_account_matches_uses_the_slot() {
+		// Account matches are reported with the default signature
+		let transaction = |slot: u64| {
+			TransactionBuilder::new()
+				.slot(slot)
+				.signature(Signature::default())
+				.build()
+		};
+
+		assert_eq!(
+			match_key(&create_solana_match("Accounts", &transaction(1))),
+			match_key(&create_solana_match("Accounts", &transaction(1)))
+		);
+		assert_ne!(
+			match_key(&create_solana_match("Accounts", &transaction(1))),
+			match_key(&create_solana_match("Accounts", &transaction(2)))
+		);
+	}
+}
@@ -9,14 +9,24 @@ mod error;
 mod expression;
 mod filter_match;
 mod filters;
+mod match_dedup;
 
 pub use error::FilterError;
 pub use filter_match::handle_match;
+pub use match_dedup::{
+	match_key, MatchDeduplicator, DEFAULT_MATCH_DEDUP_PATH, DEFAULT_MATCH_DEDUP_TTL,
+	MATCH_DEDUP_FLUSH_INTERVAL,
+};
 
 pub use filters::{
-	evm::helpers as evm_helpers, stellar::helpers as stellar_helpers, BlockFilter, EVMArgs,
-	EVMBlockFilter, EVMConditionEvaluator, EventMap, FilterService, StellarArgs,
-	StellarBlockFilter, StellarConditionEvaluator,
+	evm::helpers as evm_helpers, solana::helpers as solana_helpers,
+	stellar::helpers as stellar_helpers, BlockFilter, EVMArgs, EVMBlockFilter,
+	EVMConditionEvaluator, EventMap, FilterService, SolanaArgs, SolanaBlockFilter,
+	SolanaConditionEvaluator, SolanaDecodedTransaction, StellarArgs, StellarBlockFilter,
+	StellarConditionEvaluator, SOLANA_RAW_INSTRUCTION_NAME,
 };
 
-pub use expression::{ComparisonOperator, ConditionEvaluator, EvaluationError, LiteralValue};
+pub use expression::{
+	evaluate_expression, ComparisonOperator, ConditionEvaluator, EvaluationError, LiteralValue,
+	ParamLookup,
+};
@@ -0,0 +1,259 @@
+//! Email digests.
+//!
+//! Email triggers with a digest interval do not send an email per match. The messages of
+//! their matches are queued instead, and sent together in a single email once the interval
+//! has elapsed since the first message of the digest was queued. Digests failing to be sent
+//! are handed to the notification retry queue, which sends them again and dead-letters them
+//! once they exhausted their retries.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex, OnceLock},
+	time::Duration,
+};
+
+use crate::{
+	models::TriggerTypeConfig,
+	services::{
+		notification::{EmailNotifier, NotificationError},
+		trigger::NotificationRetryQueue,
+	},
+};
+
+/// Messages of an email trigger waiting to be sent in a digest
+#[derive(Debug)]
+struct PendingDigest {
+	/// Configuration of the trigger the digest is sent with
+	config: TriggerTypeConfig,
+	/// Formatted messages of the matches, in the order they were queued
+	messages: Vec<String>,
+}
+
+/// Queue of the email digests waiting for their interval to elapse, by trigger slug
+#[derive(Debug, Default, Clone)]
+pub struct EmailDigestQueue {
+	pending: Arc<Mutex<HashMap<String, PendingDigest>>>,
+	/// Queue the digests failing to be sent are retried from
+	retry_queue: Arc<OnceLock<Arc<NotificationRetryQueue>>>,
+}
+
+impl EmailDigestQueue {
+	/// Creates an empty digest queue
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the queue the digests failing to be sent are retried from
+	///
+	/// Digests failing to be sent before a retry queue is set are dropped.
+	pub fn set_retry_queue(&self, retry_queue: Arc<NotificationRetryQueue>) {
+		let _ = self.retry_queue.set(retry_queue);
+	}
+
+	/// Queues the message of a match in the digest of a trigger
+	///
+	/// The first message of a digest schedules the digest to be sent after the interval.
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Slug of the email trigger
+	/// * `config` - Configuration of the email trigger
+	/// * `interval` - Interval over which the messages of the trigger are batched
+	/// * `message` - Formatted message of the match
+	pub fn push(
+		&self,
+		trigger_slug: &str,
+		config: &TriggerTypeConfig,
+		interval: Duration,
+		message: String,
+	) {
+		let mut pending = self.pending.lock().unwrap();
+		if let Some(digest) = pending.get_mut(trigger_slug) {
+			digest.messages.push(message);
+			return;
+		}
+
+		pending.insert(
+			trigger_slug.to_string(),
+			PendingDigest {
+				config: config.clone(),
+				messages: vec![message],
+			},
+		);
+
+		let queue = self.clone();
+		let trigger_slug = trigger_slug.to_string();
+		tokio::spawn(async move {
+			tokio::time::sleep(interval).await;
+			if let Err(e) = queue.send(&trigger_slug).await {
+				tracing::error!("Failed to send email digest of {}: {}", trigger_slug, e);
+			}
+		});
+	}
+
+	/// Returns the number of messages waiting in the digest of a trigger
+	pub fn pending_messages(&self, trigger_slug: &str) -> usize {
+		self.pending
+			.lock()
+			.unwrap()
+			.get(trigger_slug)
+			.map_or(0, |digest| digest.messages.len())
+	}
+
+	/// Sends all pending digests without waiting for their interval, e.g. on shutdown
+	pub async fn flush(&self) {
+		let trigger_slugs: Vec<String> = self.pending.lock().unwrap().keys().cloned().collect();
+		for trigger_slug in trigger_slugs {
+			if let Err(e) = self.send(&trigger_slug).await {
+				tracing::error!("Failed to send email digest of {}: {}", trigger_slug, e);
+			}
+		}
+	}
+
+	/// Sends the digest of a trigger, if it was not sent already
+	///
+	/// A digest failing to be sent is queued for a retry, if a retry queue is set.
+	async fn send(&self, trigger_slug: &str) -> Result<(), NotificationError> {
+		let Some(digest) = self.pending.lock().unwrap().remove(trigger_slug) else {
+			return Ok(());
+		};
+
+		let result = match EmailNotifier::from_config(&digest.config) {
+			Ok(notifier) => notifier.notify_digest(&digest.messages).await,
+			Err(e) => Err(e),
+		};
+		if let (Err(e), Some(retry_queue)) = (&result, self.retry_queue.get()) {
+			if retry_queue
+				.enqueue_digest(trigger_slug, digest.messages, e.to_string())
+				.await
+			{
+				tracing::warn!("Email digest of {} queued for retry", trigger_slug);
+			}
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{NotificationMessage, SecretString, SecretValue},
+		services::trigger::RetryPolicy,
+	};
+
+	fn create_invalid_config() -> TriggerTypeConfig {
+		TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new(
+				"https://slack.example.com".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		}
+	}
+
+	#[tokio::test]
+	async fn test_push_batches_messages_by_trigger() {
+		let queue = EmailDigestQueue::new();
+		let config = create_invalid_config();
+		let interval = Duration::from_secs(3600);
+
+		queue.push("trigger_a", &config, interval, "first".to_string());
+		queue.push("trigger_a", &config, interval, "second".to_string());
+		queue.push("trigger_b", &config, interval, "third".to_string());
+
+		assert_eq!(queue.pending_messages("trigger_a"), 2);
+		assert_eq!(queue.pending_messages("trigger_b"), 1);
+		assert_eq!(queue.pending_messages("trigger_c"), 0);
+	}
+
+	#[tokio::test]
+	async fn test_digest_is_sent_after_interval() {
+		let queue = EmailDigestQueue::new();
+		let config = create_invalid_config();
+
+		queue.push(
+			"trigger",
+			&config,
+			Duration::from_millis(20),
+			"first".to_string(),
+		);
+		assert_eq!(queue.pending_messages("trigger"), 1);
+
+		tokio::time::sleep(Duration::from_millis(100)).await;
+		assert_eq!(queue.pending_messages("trigger"), 0);
+
+		// A new digest is started after the previous one was sent
+		queue.push(
+			"trigger",
+			&config,
+			Duration::from_secs(3600),
+			"second".to_string(),
+		);
+		assert_eq!(queue.pending_messages("trigger"), 1);
+	}
+
+	#[tokio::test]
+	async fn test_flush_sends_pending_digests() {
+		let queue = EmailDigestQueue::new();
+		let config = create_invalid_config();
+		let interval = Duration::from_secs(3600);
+
+		queue.push("trigger_a", &config, interval, "first".to_string());
+		queue.push("trigger_b", &config, interval, "second".to_string());
+		queue.flush().await;
+
+		assert_eq!(queue.pending_messages("trigger_a"), 0);
+		assert_eq!(queue.pending_messages("trigger_b"), 0);
+	}
+
+	#[tokio::test]
+	async fn test_send_invalid_config() {
+		let queue = EmailDigestQueue::new();
+		queue.push(
+			"trigger",
+			&create_invalid_config(),
+			Duration::from_secs(3600),
+			"first".to_string(),
+		);
+
+		let result = queue.send("trigger").await;
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+		assert!(queue.send("trigger").await.is_ok());
+	}
+
+	#[tokio::test]
+	async fn test_failed_digest_is_queued_for_retry() {
+		let queue = EmailDigestQueue::new();
+		let retry_queue = Arc::new(NotificationRetryQueue::new(RetryPolicy {
+			max_attempts: 3,
+			initial_backoff: Duration::ZERO,
+			max_backoff: Duration::ZERO,
+		}));
+		queue.set_retry_queue(retry_queue.clone());
+
+		let interval = Duration::from_secs(3600);
+		queue.push(
+			"trigger",
+			&create_invalid_config(),
+			interval,
+			"first".into(),
+		);
+		queue.push(
+			"trigger",
+			&create_invalid_config(),
+			interval,
+			"second".into(),
+		);
+		queue.flush().await;
+
+		let due = retry_queue.due().await;
+		assert_eq!(due.len(), 1);
+		assert_eq!(due[0].trigger_slug, "trigger");
+		assert_eq!(
+			due[0].digest.as_deref(),
+			Some(&["first".to_string(), "second".to_string()][..])
+		);
+	}
+}
@@ -9,14 +9,24 @@ use serde_json;
 use std::collections::HashMap;
 
 use crate::{
-	models::TriggerTypeConfig,
-	services::notification::{NotificationError, Notifier, WebhookConfig, WebhookNotifier},
+	models::{MonitorMatch, TriggerTypeConfig},
+	services::notification::{
+		MatchSummary, NotificationError, Notifier, WebhookConfig, WebhookNotifier,
+	},
 };
 
+/// Maximum number of fields in a Discord embed
+const DISCORD_MAX_EMBED_FIELDS: usize = 25;
+
+/// Maximum length of the value of a Discord embed field
+const DISCORD_MAX_FIELD_VALUE_LENGTH: usize = 1024;
+
 /// Implementation of Discord notifications via webhooks
 #[derive(Debug)]
 pub struct DiscordNotifier {
 	inner: WebhookNotifier,
+	/// Whether matches are sent as embeds
+	embed: bool,
 }
 
 /// Represents a field in a Discord embed message
@@ -27,6 +37,7 @@ struct DiscordField {
 	/// The value of the field (max 1024 characters)
 	value: String,
 	/// Indicates whether the field should be displayed inline with other fields (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	inline: Option<bool>,
 }
 
@@ -36,24 +47,34 @@ struct DiscordEmbed {
 	/// The title of the embed (max 256 characters)
 	title: String,
 	/// The description of the embed (max 4096 characters)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	description: Option<String>,
 	/// A URL that the title links to (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	url: Option<String>,
 	/// The color of the embed represented as a hexadecimal integer (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	color: Option<u32>,
 	/// A list of fields included in the embed (max 25 fields, optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	fields: Option<Vec<DiscordField>>,
 	/// Indicates whether text-to-speech is enabled for the embed (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	tts: Option<bool>,
 	/// A thumbnail image for the embed (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	thumbnail: Option<String>,
 	/// An image for the embed (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	image: Option<String>,
 	/// Footer information for the embed (max 2048 characters, optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	footer: Option<String>,
 	/// Author information for the embed (max 256 characters, optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	author: Option<String>,
 	/// A timestamp for the embed (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	timestamp: Option<String>,
 }
 
@@ -63,10 +84,13 @@ struct DiscordMessage {
 	/// The content of the message
 	content: String,
 	/// The username to display as the sender of the message (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	username: Option<String>,
 	/// The avatar URL to display for the sender (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	avatar_url: Option<String>,
 	/// A list of embeds included in the message (max 10 embeds, optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
 	embeds: Option<Vec<DiscordEmbed>>,
 }
 
@@ -93,6 +117,7 @@ impl DiscordNotifier {
 				headers: None,
 				payload_fields: None,
 			})?,
+			embed: false,
 		})
 	}
 
@@ -118,6 +143,7 @@ impl DiscordNotifier {
 	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
 		if let TriggerTypeConfig::Discord {
 			discord_url,
+			embed,
 			message,
 		} = config
 		{
@@ -134,12 +160,105 @@ impl DiscordNotifier {
 
 			Ok(Self {
 				inner: WebhookNotifier::new(webhook_config)?,
+				embed: embed.unwrap_or(false),
 			})
 		} else {
 			let msg = format!("Invalid discord configuration: {:?}", config);
 			Err(NotificationError::config_error(msg, None, None))
 		}
 	}
+
+	/// Sends a monitor match to Discord
+	///
+	/// The match is sent as an embed if the trigger enables embeds, and as the formatted
+	/// message otherwise.
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	/// * `monitor_match` - The monitor match to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_match(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let message = self.format_message(variables);
+		if !self.embed {
+			return self.notify(&message).await;
+		}
+
+		let embed = self.match_embed(variables, monitor_match);
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert("embeds".to_string(), serde_json::json!([embed]));
+
+		self.inner
+			.notify_with_payload(&message, payload_fields)
+			.await
+	}
+
+	/// Builds the embed of a monitor match
+	///
+	/// The embed holds fields for the monitor, network and transaction of the match, followed
+	/// by a field per matched instruction, function or event listing its decoded arguments.
+	fn match_embed(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> DiscordEmbed {
+		let summary = MatchSummary::new(monitor_match);
+		let mut fields = vec![
+			DiscordField {
+				name: "Monitor".to_string(),
+				value: summary.monitor_name.clone(),
+				inline: Some(true),
+			},
+			DiscordField {
+				name: "Network".to_string(),
+				value: summary.network_slug.clone(),
+				inline: Some(true),
+			},
+			DiscordField {
+				name: summary.transaction_label.to_string(),
+				value: summary.transaction.clone(),
+				inline: Some(false),
+			},
+		];
+		fields.extend(summary.params.iter().map(|params| DiscordField {
+			name: params.signature.clone(),
+			value: truncate_field_value(params.describe_args()),
+			inline: Some(false),
+		}));
+		fields.truncate(DISCORD_MAX_EMBED_FIELDS);
+
+		DiscordEmbed {
+			title: self.inner.title.clone(),
+			description: Some(self.inner.format_message(variables)),
+			url: None,
+			color: None,
+			fields: Some(fields),
+			tts: None,
+			thumbnail: None,
+			image: None,
+			footer: None,
+			author: None,
+			timestamp: None,
+		}
+	}
+}
+
+/// Truncates the value of an embed field to the length allowed by Discord
+fn truncate_field_value(value: String) -> String {
+	if value.chars().count() <= DISCORD_MAX_FIELD_VALUE_LENGTH {
+		return value;
+	}
+	let mut truncated: String = value
+		.chars()
+		.take(DISCORD_MAX_FIELD_VALUE_LENGTH - 1)
+		.collect();
+	truncated.push('…');
+	truncated
 }
 
 #[async_trait]
@@ -173,7 +292,23 @@ impl Notifier for DiscordNotifier {
 
 #[cfg(test)]
 mod tests {
-	use crate::models::{NotificationMessage, SecretString, SecretValue};
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			MatchConditions, NotificationMessage, SecretString, SecretValue, SolanaMatchArguments,
+			SolanaMatchParamEntry, SolanaMatchParamsMap, SolanaMonitorMatch,
+		},
+		services::filter::evm_helpers::b256_to_string,
+		utils::tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			solana::{
+				monitor::MonitorBuilder as SolanaMonitorBuilder,
+				transaction::TransactionBuilder as SolanaTransactionBuilder,
+			},
+		},
+	};
+	use alloy::primitives::B256;
+	use solana_sdk::signature::Signature;
 
 	use super::*;
 
@@ -186,11 +321,46 @@ mod tests {
 		.unwrap()
 	}
 
+	fn create_test_evm_match(events: Vec<EVMMatchParamsMap>) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfer").build(),
+			transaction: TransactionBuilder::new()
+				.hash(B256::repeat_byte(0xab))
+				.build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(events),
+			}),
+		}))
+	}
+
+	fn create_test_evm_event(signature: &str, args: &[(&str, &str)]) -> EVMMatchParamsMap {
+		EVMMatchParamsMap {
+			signature: signature.to_string(),
+			args: Some(
+				args.iter()
+					.map(|(name, value)| EVMMatchParamEntry {
+						name: name.to_string(),
+						value: value.to_string(),
+						indexed: false,
+						kind: "uint256".to_string(),
+					})
+					.collect(),
+			),
+			hex_signature: None,
+		}
+	}
+
 	fn create_test_discord_config() -> TriggerTypeConfig {
 		TriggerTypeConfig::Discord {
 			discord_url: SecretValue::Plain(SecretString::new(
 				"https://discord.example.com".to_string(),
 			)),
+			embed: Some(true),
 			message: NotificationMessage {
 				title: "Test Alert".to_string(),
 				body: "Test message ${value}".to_string(),
@@ -270,6 +440,133 @@ mod tests {
 		assert!(matches!(error, NotificationError::ConfigError { .. }));
 	}
 
+	#[test]
+	fn test_from_config_embed_disabled_by_default() {
+		let config = TriggerTypeConfig::Discord {
+			discord_url: SecretValue::Plain(SecretString::new(
+				"https://discord.example.com".to_string(),
+			)),
+			embed: None,
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+
+		assert!(!DiscordNotifier::from_config(&config).unwrap().embed);
+		assert!(
+			DiscordNotifier::from_config(&create_test_discord_config())
+				.unwrap()
+				.embed
+		);
+	}
+
+	////////////////////////////////////////////////////////////
+	// match_embed tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_match_embed_solana() {
+		let notifier = DiscordNotifier::from_config(&create_test_discord_config()).unwrap();
+		let signature = Signature::from([7; 64]);
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			SolanaMonitorBuilder::new().name("Token Transfers").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			Some(SolanaMatchArguments {
+				instructions: Some(vec![SolanaMatchParamsMap {
+					signature: "TransferChecked".to_string(),
+					args: Some(vec![
+						SolanaMatchParamEntry {
+							name: "amount".to_string(),
+							value: "1500".to_string(),
+							kind: "u64".to_string(),
+							indexed: false,
+						},
+						SolanaMatchParamEntry {
+							name: "decimals".to_string(),
+							value: "6".to_string(),
+							kind: "u8".to_string(),
+							indexed: false,
+						},
+					]),
+					hex_signature: None,
+				}]),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			}),
+			SolanaTransactionBuilder::new().signature(signature).build(),
+		)));
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "42".to_string());
+		let embed = notifier.match_embed(&variables, &monitor_match);
+
+		assert_eq!(
+			serde_json::to_value(embed).unwrap(),
+			serde_json::json!({
+				"title": "Test Alert",
+				"description": "Test message 42",
+				"fields": [
+					{ "name": "Monitor", "value": "Token Transfers", "inline": true },
+					{ "name": "Network", "value": "solana_mainnet", "inline": true },
+					{ "name": "Signature", "value": signature.to_string(), "inline": false },
+					{
+						"name": "TransferChecked",
+						"value": "amount: 1500\ndecimals: 6",
+						"inline": false
+					},
+				]
+			})
+		);
+	}
+
+	#[test]
+	fn test_match_embed_evm() {
+		let notifier = DiscordNotifier::from_config(&create_test_discord_config()).unwrap();
+		let monitor_match = create_test_evm_match(vec![
+			create_test_evm_event(
+				"Transfer(address,address,uint256)",
+				&[("from", "0x01"), ("to", "0x02"), ("value", "1000")],
+			),
+			create_test_evm_event("Paused()", &[]),
+		]);
+
+		let embed = notifier.match_embed(&HashMap::new(), &monitor_match);
+		let fields = embed.fields.unwrap();
+
+		assert_eq!(fields.len(), 5);
+		assert_eq!(fields[2].name, "Transaction");
+		assert_eq!(fields[2].value, b256_to_string(B256::repeat_byte(0xab)));
+		assert_eq!(fields[3].name, "Transfer(address,address,uint256)");
+		assert_eq!(fields[3].value, "from: 0x01\nto: 0x02\nvalue: 1000");
+		assert_eq!(fields[4].name, "Paused()");
+		assert_eq!(fields[4].value, "No arguments");
+	}
+
+	#[test]
+	fn test_match_embed_limits() {
+		let notifier = DiscordNotifier::from_config(&create_test_discord_config()).unwrap();
+		let long_value = "x".repeat(2 * DISCORD_MAX_FIELD_VALUE_LENGTH);
+		let events = (0..30)
+			.map(|_| create_test_evm_event("Data(bytes)", &[("data", &long_value)]))
+			.collect();
+
+		let embed = notifier.match_embed(&HashMap::new(), &create_test_evm_match(events));
+		let fields = embed.fields.unwrap();
+
+		assert_eq!(fields.len(), DISCORD_MAX_EMBED_FIELDS);
+		assert_eq!(
+			fields[3].value.chars().count(),
+			DISCORD_MAX_FIELD_VALUE_LENGTH
+		);
+		assert!(fields[3].value.ends_with('…'));
+	}
+
 	////////////////////////////////////////////////////////////
 	// notify tests
 	////////////////////////////////////////////////////////////
@@ -284,6 +581,28 @@ mod tests {
 		assert!(matches!(error, NotificationError::NotifyFailed { .. }));
 	}
 
+	#[tokio::test]
+	async fn test_notify_match_embed_failure() {
+		let notifier = DiscordNotifier::from_config(&TriggerTypeConfig::Discord {
+			discord_url: SecretValue::Plain(SecretString::new(
+				"https://non-existent-url-discord-webhook.com".to_string(),
+			)),
+			embed: Some(true),
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		})
+		.unwrap();
+		let result = notifier
+			.notify_match(&HashMap::new(), &create_test_evm_match(vec![]))
+			.await;
+		assert!(result.is_err());
+
+		let error = result.unwrap_err();
+		assert!(matches!(error, NotificationError::NotifyFailed { .. }));
+	}
+
 	#[tokio::test]
 	async fn test_notify_with_payload_failure() {
 		let notifier = create_test_notifier("Test message");
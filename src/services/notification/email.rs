@@ -16,7 +16,7 @@ use lettre::{
 use std::collections::HashMap;
 
 use crate::{
-	models::TriggerTypeConfig,
+	models::{SmtpTlsMode, TriggerTypeConfig},
 	services::notification::{NotificationError, Notifier},
 };
 use pulldown_cmark::{html, Options, Parser};
@@ -41,6 +41,7 @@ pub struct EmailNotifier<T: Transport + Send + Sync> {
 pub struct SmtpConfig {
 	pub host: String,
 	pub port: u16,
+	pub tls: SmtpTlsMode,
 	pub username: String,
 	pub password: String,
 }
@@ -75,6 +76,78 @@ where
 			client: transport,
 		}
 	}
+
+	/// Sends the messages of several matches in a single email
+	///
+	/// The messages are separated by horizontal rules, and the subject is followed by the
+	/// number of matches.
+	///
+	/// # Arguments
+	/// * `messages` - The formatted messages of the matches
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_digest(&self, messages: &[String]) -> Result<(), NotificationError> {
+		let subject = format!(
+			"{} ({} match{})",
+			self.subject,
+			messages.len(),
+			if messages.len() == 1 { "" } else { "es" }
+		);
+		self.send(&subject, messages.join("\n<hr>\n"))
+	}
+
+	/// Sends an email to the recipients
+	fn send(&self, subject: &str, body: String) -> Result<(), NotificationError> {
+		let recipients_str = self
+			.recipients
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		let mailboxes: Mailboxes = recipients_str.parse::<Mailboxes>().map_err(|e| {
+			NotificationError::notify_failed(
+				format!("Failed to parse recipients: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
+		let recipients_header: header::To = mailboxes.into();
+
+		let email = Message::builder()
+			.mailbox(recipients_header)
+			.from(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to parse sender: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?)
+			.reply_to(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to parse reply-to: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?)
+			.subject(subject)
+			.header(ContentType::TEXT_HTML)
+			.body(body)
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to build email message: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?;
+
+		self.client.send(&email).map_err(|e| {
+			NotificationError::notify_failed(format!("Failed to send email: {}", e), None, None)
+		})?;
+
+		Ok(())
+	}
 }
 
 impl EmailNotifier<SmtpTransport> {
@@ -90,7 +163,12 @@ impl EmailNotifier<SmtpTransport> {
 		smtp_config: SmtpConfig,
 		email_content: EmailContent,
 	) -> Result<Self, NotificationError> {
-		let client = SmtpTransport::relay(&smtp_config.host)
+		let relay = match smtp_config.tls {
+			SmtpTlsMode::Tls => SmtpTransport::relay(&smtp_config.host),
+			SmtpTlsMode::StartTls => SmtpTransport::starttls_relay(&smtp_config.host),
+			SmtpTlsMode::Disabled => Ok(SmtpTransport::builder_dangerous(&smtp_config.host)),
+		};
+		let client = relay
 			.map_err(|e| {
 				NotificationError::internal_error(
 					format!("Failed to create SMTP relay: {}", e),
@@ -150,16 +228,20 @@ impl EmailNotifier<SmtpTransport> {
 		if let TriggerTypeConfig::Email {
 			host,
 			port,
+			tls,
 			username,
 			password,
 			message,
 			sender,
 			recipients,
+			..
 		} = config
 		{
+			let port = port.unwrap_or(465);
 			let smtp_config = SmtpConfig {
 				host: host.clone(),
-				port: port.unwrap_or(465),
+				port,
+				tls: tls.unwrap_or(SmtpTlsMode::for_port(port)),
 				username: username.as_ref().to_string(),
 				password: password.as_ref().to_string(),
 			};
@@ -195,54 +277,7 @@ where
 	/// # Returns
 	/// * `Result<(), NotificationError>` - Success or error
 	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
-		let recipients_str = self
-			.recipients
-			.iter()
-			.map(ToString::to_string)
-			.collect::<Vec<_>>()
-			.join(", ");
-
-		let mailboxes: Mailboxes = recipients_str.parse::<Mailboxes>().map_err(|e| {
-			NotificationError::notify_failed(
-				format!("Failed to parse recipients: {}", e),
-				Some(e.into()),
-				None,
-			)
-		})?;
-		let recipients_header: header::To = mailboxes.into();
-
-		let email = Message::builder()
-			.mailbox(recipients_header)
-			.from(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to parse sender: {}", e),
-					Some(e.into()),
-					None,
-				)
-			})?)
-			.reply_to(self.sender.to_string().parse::<Mailbox>().map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to parse reply-to: {}", e),
-					Some(e.into()),
-					None,
-				)
-			})?)
-			.subject(&self.subject)
-			.header(ContentType::TEXT_HTML)
-			.body(message.to_owned())
-			.map_err(|e| {
-				NotificationError::notify_failed(
-					format!("Failed to build email message: {}", e),
-					Some(e.into()),
-					None,
-				)
-			})?;
-
-		self.client.send(&email).map_err(|e| {
-			NotificationError::notify_failed(format!("Failed to send email: {}", e), None, None)
-		})?;
-
-		Ok(())
+		self.send(&self.subject, message.to_owned())
 	}
 }
 
@@ -256,6 +291,7 @@ mod tests {
 		let smtp_config = SmtpConfig {
 			host: "dummy.smtp.com".to_string(),
 			port: 465,
+			tls: SmtpTlsMode::Tls,
 			username: "test".to_string(),
 			password: "test".to_string(),
 		};
@@ -274,6 +310,7 @@ mod tests {
 		TriggerTypeConfig::Email {
 			host: "smtp.test.com".to_string(),
 			port,
+			tls: None,
 			username: SecretValue::Plain(SecretString::new("testuser".to_string())),
 			password: SecretValue::Plain(SecretString::new("testpass".to_string())),
 			message: NotificationMessage {
@@ -282,6 +319,7 @@ mod tests {
 			},
 			sender: "sender@test.com".parse().unwrap(),
 			recipients: vec!["recipient@test.com".parse().unwrap()],
+			digest_interval_mins: None,
 		}
 	}
 
@@ -0,0 +1,474 @@
+//! Google Chat notification implementation.
+//!
+//! Provides functionality to send monitor matches to Google Chat spaces via incoming
+//! webhooks, formatted as cards with the fields of the match and a button opening the
+//! transaction on the block explorer of its network.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+	models::{MonitorMatch, TriggerTypeConfig},
+	services::notification::{
+		MatchSummary, NotificationError, Notifier, WebhookConfig, WebhookNotifier,
+	},
+};
+
+/// Maximum number of matched instructions, functions or events displayed in a card
+const GOOGLE_CHAT_MAX_PARAMS_SECTIONS: usize = 20;
+
+/// Identifier of the cards of monitor matches
+const GOOGLE_CHAT_CARD_ID: &str = "monitor-match";
+
+/// Implementation of Google Chat notifications via incoming webhooks
+#[derive(Debug)]
+pub struct GoogleChatNotifier {
+	inner: WebhookNotifier,
+}
+
+/// Represents a Google Chat card
+#[derive(Debug, Serialize)]
+struct GoogleChatCard {
+	/// Header of the card
+	header: GoogleChatCardHeader,
+	/// Sections of the card, separated by dividers
+	sections: Vec<GoogleChatSection>,
+}
+
+/// Represents the header of a Google Chat card
+#[derive(Debug, Serialize)]
+struct GoogleChatCardHeader {
+	/// Title of the card
+	title: String,
+	/// Subtitle of the card (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	subtitle: Option<String>,
+}
+
+/// Represents a section of a Google Chat card
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GoogleChatSection {
+	/// Header of the section (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	header: Option<String>,
+	/// Whether the section is collapsed by default
+	#[serde(skip_serializing_if = "std::ops::Not::not")]
+	collapsible: bool,
+	/// Widgets of the section
+	widgets: Vec<GoogleChatWidget>,
+}
+
+/// Represents a widget of a Google Chat card section
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum GoogleChatWidget {
+	/// Paragraph of text
+	TextParagraph { text: String },
+	/// Text with a label
+	DecoratedText(GoogleChatDecoratedText),
+	/// List of buttons
+	ButtonList { buttons: Vec<GoogleChatButton> },
+}
+
+/// Represents a text with a label in a Google Chat card
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GoogleChatDecoratedText {
+	/// Label displayed above the text
+	top_label: String,
+	/// Text of the widget
+	text: String,
+	/// Whether the text wraps instead of being truncated
+	wrap_text: bool,
+}
+
+/// Represents a button of a Google Chat card opening a link
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GoogleChatButton {
+	/// Text of the button
+	text: String,
+	/// Action of the button
+	on_click: serde_json::Value,
+}
+
+impl GoogleChatNotifier {
+	/// Creates a new Google Chat notifier instance
+	///
+	/// # Arguments
+	/// * `url` - Google Chat webhook URL
+	/// * `title` - Title of the card
+	/// * `body_template` - Message template with variables
+	pub fn new(
+		url: String,
+		title: String,
+		body_template: String,
+	) -> Result<Self, NotificationError> {
+		Ok(Self {
+			inner: WebhookNotifier::new(WebhookConfig {
+				url,
+				url_params: None,
+				title,
+				body_template,
+				method: Some("POST".to_string()),
+				secret: None,
+				headers: None,
+				payload_fields: None,
+			})?,
+		})
+	}
+
+	/// Formats a message by substituting variables in the template
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message with variables replaced
+	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
+		let message = self.inner.format_message(variables);
+		format!("*{}*\n\n{}", self.inner.title, message)
+	}
+
+	/// Creates a Google Chat notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Google Chat parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Google Chat type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::GoogleChat {
+			google_chat_url,
+			message,
+		} = config
+		{
+			Self::new(
+				google_chat_url.as_ref().to_string(),
+				message.title.clone(),
+				message.body.clone(),
+			)
+		} else {
+			let msg = format!("Invalid google chat configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Sends a monitor match to Google Chat as a card
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	/// * `monitor_match` - The monitor match to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_match(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let card = self.match_card(variables, monitor_match);
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert(
+			"cardsV2".to_string(),
+			serde_json::json!([{ "cardId": GOOGLE_CHAT_CARD_ID, "card": card }]),
+		);
+
+		self.inner
+			.notify_with_payload(&self.format_message(variables), payload_fields)
+			.await
+	}
+
+	/// Builds the card of a monitor match
+	///
+	/// The card holds the message, the network and transaction of the match with a button
+	/// opening the transaction on the explorer, followed by a collapsible section per matched
+	/// instruction, function or event listing its decoded arguments.
+	fn match_card(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> GoogleChatCard {
+		let summary = MatchSummary::new(monitor_match);
+
+		let mut match_widgets = vec![
+			GoogleChatWidget::DecoratedText(GoogleChatDecoratedText {
+				top_label: "Network".to_string(),
+				text: summary.network_slug.clone(),
+				wrap_text: false,
+			}),
+			GoogleChatWidget::DecoratedText(GoogleChatDecoratedText {
+				top_label: summary.transaction_label.to_string(),
+				text: summary.transaction.clone(),
+				wrap_text: true,
+			}),
+		];
+		if let Some(url) = summary.explorer_url() {
+			match_widgets.push(GoogleChatWidget::ButtonList {
+				buttons: vec![GoogleChatButton {
+					text: "View in explorer".to_string(),
+					on_click: serde_json::json!({ "openLink": { "url": url } }),
+				}],
+			});
+		}
+
+		let mut sections = vec![
+			GoogleChatSection {
+				header: None,
+				collapsible: false,
+				widgets: vec![GoogleChatWidget::TextParagraph {
+					text: self.inner.format_message(variables),
+				}],
+			},
+			GoogleChatSection {
+				header: None,
+				collapsible: false,
+				widgets: match_widgets,
+			},
+		];
+		sections.extend(
+			summary
+				.params
+				.iter()
+				.take(GOOGLE_CHAT_MAX_PARAMS_SECTIONS)
+				.map(|params| GoogleChatSection {
+					header: Some(params.signature.clone()),
+					collapsible: true,
+					widgets: vec![GoogleChatWidget::TextParagraph {
+						text: params.describe_args(),
+					}],
+				}),
+		);
+
+		GoogleChatCard {
+			header: GoogleChatCardHeader {
+				title: self.inner.title.clone(),
+				subtitle: Some(summary.monitor_name),
+			},
+			sections,
+		}
+	}
+}
+
+#[async_trait]
+impl Notifier for GoogleChatNotifier {
+	/// Sends a formatted message to Google Chat
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert("text".to_string(), serde_json::json!(message));
+
+		self.inner
+			.notify_with_payload(message, payload_fields)
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		models::{
+			MatchConditions, NotificationMessage, SecretString, SecretValue, SolanaMatchArguments,
+			SolanaMatchParamEntry, SolanaMatchParamsMap, SolanaMonitorMatch,
+		},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use solana_sdk::signature::Signature;
+
+	use super::*;
+
+	fn create_test_notifier(url: &str) -> GoogleChatNotifier {
+		GoogleChatNotifier::new(
+			url.to_string(),
+			"Alert".to_string(),
+			"Value is ${value}".to_string(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_solana_match(network_slug: &str, signature: Signature) -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("Token Transfers").build(),
+			network_slug.to_string(),
+			MatchConditions::default(),
+			Some(SolanaMatchArguments {
+				instructions: Some(vec![SolanaMatchParamsMap {
+					signature: "TransferChecked".to_string(),
+					args: Some(vec![SolanaMatchParamEntry {
+						name: "amount".to_string(),
+						value: "1500".to_string(),
+						kind: "u64".to_string(),
+						indexed: false,
+					}]),
+					hex_signature: None,
+				}]),
+				accounts: None,
+				balance_changes: None,
+				events: None,
+				logs: None,
+				sequences: None,
+				blocks: None,
+			}),
+			TransactionBuilder::new().signature(signature).build(),
+		)))
+	}
+
+	////////////////////////////////////////////////////////////
+	// format_message tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_format_message() {
+		let notifier = create_test_notifier("https://chat.googleapis.com/v1/spaces/x");
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "100".to_string());
+
+		let result = notifier.format_message(&variables);
+		assert_eq!(result, "*Alert*\n\nValue is 100");
+	}
+
+	////////////////////////////////////////////////////////////
+	// from_config tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_from_config_with_google_chat_config() {
+		let config = TriggerTypeConfig::GoogleChat {
+			google_chat_url: SecretValue::Plain(SecretString::new(
+				"https://chat.googleapis.com/v1/spaces/x".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message ${value}".to_string(),
+			},
+		};
+
+		let notifier = GoogleChatNotifier::from_config(&config).unwrap();
+		assert_eq!(
+			notifier.inner.url,
+			"https://chat.googleapis.com/v1/spaces/x"
+		);
+		assert_eq!(notifier.inner.title, "Test Alert");
+		assert_eq!(notifier.inner.body_template, "Test message ${value}");
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new(
+				"https://slack.example.com".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+
+		let result = GoogleChatNotifier::from_config(&config);
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	////////////////////////////////////////////////////////////
+	// match_card tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_match_card() {
+		let notifier = create_test_notifier("https://chat.googleapis.com/v1/spaces/x");
+		let signature = Signature::from([7; 64]);
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "42".to_string());
+		let card = notifier.match_card(
+			&variables,
+			&create_test_solana_match("solana_mainnet", signature),
+		);
+
+		assert_eq!(
+			serde_json::to_value(card).unwrap(),
+			serde_json::json!({
+				"header": { "title": "Alert", "subtitle": "Token Transfers" },
+				"sections": [
+					{ "widgets": [{ "textParagraph": { "text": "Value is 42" } }] },
+					{
+						"widgets": [
+							{
+								"decoratedText": {
+									"topLabel": "Network",
+									"text": "solana_mainnet",
+									"wrapText": false
+								}
+							},
+							{
+								"decoratedText": {
+									"topLabel": "Signature",
+									"text": signature.to_string(),
+									"wrapText": true
+								}
+							},
+							{
+								"buttonList": {
+									"buttons": [{
+										"text": "View in explorer",
+										"onClick": {
+											"openLink": {
+												"url": format!("https://explorer.solana.com/tx/{}", signature)
+											}
+										}
+									}]
+								}
+							}
+						]
+					},
+					{
+						"header": "TransferChecked",
+						"collapsible": true,
+						"widgets": [{ "textParagraph": { "text": "amount: 1500" } }]
+					}
+				]
+			})
+		);
+	}
+
+	#[test]
+	fn test_match_card_without_explorer() {
+		let notifier = create_test_notifier("https://chat.googleapis.com/v1/spaces/x");
+		let card = notifier.match_card(
+			&HashMap::new(),
+			&create_test_solana_match("solana_localnet", Signature::from([7; 64])),
+		);
+
+		assert_eq!(card.sections[1].widgets.len(), 2);
+	}
+
+	////////////////////////////////////////////////////////////
+	// notify tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_failure() {
+		let notifier = create_test_notifier("https://non-existent-url-google-chat.com");
+		let result = notifier.notify("Test message").await;
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	}
+
+	#[tokio::test]
+	async fn test_notify_match_failure() {
+		let notifier = create_test_notifier("https://non-existent-url-google-chat.com");
+		let result = notifier
+			.notify_match(
+				&HashMap::new(),
+				&create_test_solana_match("solana_mainnet", Signature::from([7; 64])),
+			)
+			.await;
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	}
+}
@@ -0,0 +1,317 @@
+//! Matrix notification implementation.
+//!
+//! Provides functionality to send messages to Matrix rooms through the client-server API,
+//! authenticated with the access token of the account sending them. Message templates are
+//! written in Markdown, and sent along with their HTML rendering.
+
+use async_trait::async_trait;
+use pulldown_cmark::{html, Event, Options, Parser};
+use std::collections::HashMap;
+
+use crate::{
+	models::TriggerTypeConfig,
+	services::notification::{NotificationError, Notifier, WebhookConfig, WebhookNotifier},
+};
+
+/// Format of the HTML body of Matrix messages
+const MATRIX_HTML_FORMAT: &str = "org.matrix.custom.html";
+
+/// Maximum number of retries of messages failing with transient errors, e.g. rate limits
+const MATRIX_MAX_RETRIES: u32 = 3;
+
+/// Implementation of Matrix notifications via the client-server API
+#[derive(Debug)]
+pub struct MatrixNotifier {
+	inner: WebhookNotifier,
+	/// Whether messages are sent along with their HTML rendering
+	html: bool,
+}
+
+impl MatrixNotifier {
+	/// Creates a new Matrix notifier instance
+	///
+	/// # Arguments
+	/// * `homeserver_url` - URL of the homeserver of the account
+	/// * `room_id` - ID of the room to send messages to
+	/// * `access_token` - Access token of the account
+	/// * `html` - Send messages along with their HTML rendering (default true)
+	/// * `title` - Title to display in the message
+	/// * `body_template` - Message template with variables
+	pub fn new(
+		homeserver_url: &str,
+		room_id: &str,
+		access_token: &str,
+		html: Option<bool>,
+		title: String,
+		body_template: String,
+	) -> Result<Self, NotificationError> {
+		let url = format!(
+			"{}/_matrix/client/v3/rooms/{}/send/m.room.message",
+			homeserver_url.trim_end_matches('/'),
+			urlencoding::encode(room_id)
+		);
+
+		let mut headers = HashMap::new();
+		headers.insert(
+			"Authorization".to_string(),
+			format!("Bearer {}", access_token),
+		);
+
+		Ok(Self {
+			inner: WebhookNotifier::new(WebhookConfig {
+				url,
+				url_params: None,
+				title,
+				body_template,
+				method: Some("PUT".to_string()),
+				secret: None,
+				headers: Some(headers),
+				payload_fields: None,
+			})?,
+			html: html.unwrap_or(true),
+		})
+	}
+
+	/// Formats a message by substituting variables in the template
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message with variables replaced
+	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
+		let message = self.inner.format_message(variables);
+		format!("**{}**\n\n{}", self.inner.title, message)
+	}
+
+	/// Renders a Markdown message as HTML
+	///
+	/// Raw HTML of the message, e.g. in variable values, is escaped rather than rendered.
+	///
+	/// # Arguments
+	/// * `message` - Markdown message
+	///
+	/// # Returns
+	/// * `String` - HTML rendering of the message
+	pub fn render_html(message: &str) -> String {
+		let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+		let parser = Parser::new_ext(message, options).map(|event| match event {
+			Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+			event => event,
+		});
+
+		let mut html_out = String::new();
+		html::push_html(&mut html_out, parser);
+		html_out.trim_end().to_string()
+	}
+
+	/// Creates a Matrix notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Matrix parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Matrix type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Matrix {
+			homeserver_url,
+			room_id,
+			access_token,
+			html,
+			message,
+		} = config
+		{
+			Self::new(
+				homeserver_url,
+				room_id,
+				access_token.as_ref(),
+				*html,
+				message.title.clone(),
+				message.body.clone(),
+			)
+		} else {
+			let msg = format!("Invalid matrix configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+}
+
+#[async_trait]
+impl Notifier for MatrixNotifier {
+	/// Sends a formatted message to the Matrix room
+	///
+	/// Every message is sent with a new transaction ID, which the homeserver uses to
+	/// deduplicate retries of the same message.
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert("msgtype".to_string(), serde_json::json!("m.text"));
+		payload_fields.insert("body".to_string(), serde_json::json!(message));
+		if self.html {
+			payload_fields.insert("format".to_string(), serde_json::json!(MATRIX_HTML_FORMAT));
+			payload_fields.insert(
+				"formatted_body".to_string(),
+				serde_json::json!(Self::render_html(message)),
+			);
+		}
+
+		let notifier = WebhookNotifier::new(WebhookConfig {
+			url: format!("{}/{}", self.inner.url, uuid::Uuid::new_v4()),
+			url_params: None,
+			title: self.inner.title.clone(),
+			body_template: self.inner.body_template.clone(),
+			method: Some("PUT".to_string()),
+			secret: None,
+			headers: self.inner.headers.clone(),
+			payload_fields: None,
+		})?
+		.with_retries(MATRIX_MAX_RETRIES);
+
+		notifier.notify_with_payload(message, payload_fields).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::models::{NotificationMessage, SecretString, SecretValue};
+
+	use super::*;
+
+	fn create_test_notifier(body_template: &str) -> MatrixNotifier {
+		MatrixNotifier::new(
+			"https://matrix.example.com/",
+			"!room:example.com",
+			"test-token",
+			None,
+			"Alert".to_string(),
+			body_template.to_string(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_matrix_config() -> TriggerTypeConfig {
+		TriggerTypeConfig::Matrix {
+			homeserver_url: "https://matrix.example.com".to_string(),
+			room_id: "!room:example.com".to_string(),
+			access_token: SecretValue::Plain(SecretString::new("test-token".to_string())),
+			html: Some(false),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message ${value}".to_string(),
+			},
+		}
+	}
+
+	////////////////////////////////////////////////////////////
+	// new tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_new_builds_room_url() {
+		let notifier = create_test_notifier("Test message");
+
+		assert_eq!(
+			notifier.inner.url,
+			"https://matrix.example.com/_matrix/client/v3/rooms/%21room%3Aexample.com/send/m.room.message"
+		);
+		assert_eq!(
+			notifier.inner.headers.as_ref().unwrap()["Authorization"],
+			"Bearer test-token"
+		);
+		assert!(notifier.html);
+	}
+
+	////////////////////////////////////////////////////////////
+	// format_message tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_format_message() {
+		let notifier = create_test_notifier("Value is ${value} and status is ${status}");
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "100".to_string());
+		variables.insert("status".to_string(), "critical".to_string());
+
+		let result = notifier.format_message(&variables);
+		assert_eq!(result, "**Alert**\n\nValue is 100 and status is critical");
+	}
+
+	////////////////////////////////////////////////////////////
+	// render_html tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_render_html() {
+		let html = MatrixNotifier::render_html("**Alert**\n\nTransfer of `100` [tx](https://x.io)");
+		assert_eq!(
+			html,
+			"<p><strong>Alert</strong></p>\n<p>Transfer of <code>100</code> <a href=\"https://x.io\">tx</a></p>"
+		);
+	}
+
+	#[test]
+	fn test_render_html_escapes_raw_html() {
+		let html = MatrixNotifier::render_html("Memo: <script>alert(1)</script>");
+		assert!(!html.contains("<script>"));
+		assert!(html.contains("&lt;script&gt;"));
+	}
+
+	////////////////////////////////////////////////////////////
+	// from_config tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_from_config_with_matrix_config() {
+		let notifier = MatrixNotifier::from_config(&create_test_matrix_config()).unwrap();
+
+		assert!(notifier
+			.inner
+			.url
+			.starts_with("https://matrix.example.com/"));
+		assert_eq!(notifier.inner.title, "Test Alert");
+		assert_eq!(notifier.inner.body_template, "Test message ${value}");
+		assert!(!notifier.html);
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new(
+				"https://slack.example.com".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+
+		let result = MatrixNotifier::from_config(&config);
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	////////////////////////////////////////////////////////////
+	// notify tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_failure() {
+		let notifier = MatrixNotifier::new(
+			"https://non-existent-url-matrix.com",
+			"!room:example.com",
+			"test-token",
+			None,
+			"Alert".to_string(),
+			"Test message".to_string(),
+		)
+		.unwrap();
+
+		let result = notifier.notify("Test message").await;
+		assert!(result.is_err());
+	}
+}
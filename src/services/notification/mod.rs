@@ -5,14 +5,24 @@
 
 use async_trait::async_trait;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
+mod digest;
 mod discord;
 mod email;
 mod error;
+mod google_chat;
+mod matrix;
+mod nats;
+mod pagerduty;
+mod payload;
+mod rate_limit;
 mod script;
 mod slack;
+mod summary;
+mod teams;
 mod telegram;
+mod twilio;
 mod webhook;
 
 use crate::{
@@ -20,12 +30,25 @@ use crate::{
 	utils::normalize_string,
 };
 
+pub use digest::EmailDigestQueue;
 pub use discord::DiscordNotifier;
 pub use email::{EmailContent, EmailNotifier, SmtpConfig};
 pub use error::NotificationError;
+pub use google_chat::GoogleChatNotifier;
+pub use matrix::MatrixNotifier;
+pub use nats::NatsNotifier;
+pub use pagerduty::PagerDutyNotifier;
+pub use payload::{
+	match_payload, MatchPayloadV1, MatchedParamsPayloadV1, MonitorPayloadV1, TransactionPayloadV1,
+	MATCH_PAYLOAD_SCHEMA_VERSION, SUPPORTED_MATCH_PAYLOAD_SCHEMA_VERSIONS,
+};
+pub use rate_limit::TriggerRateLimiter;
 pub use script::ScriptNotifier;
 pub use slack::SlackNotifier;
+pub use summary::{explorer_url, register_explorer, MatchSummary, MatchedParams};
+pub use teams::TeamsNotifier;
 pub use telegram::TelegramNotifier;
+pub use twilio::TwilioNotifier;
 pub use webhook::{WebhookConfig, WebhookNotifier};
 
 /// Interface for notification implementations
@@ -83,17 +106,32 @@ pub trait ScriptExecutor {
 }
 
 /// Service for managing notifications across different channels
-pub struct NotificationService;
+#[derive(Clone)]
+pub struct NotificationService {
+	/// Email digests waiting for their interval to elapse
+	email_digests: Arc<EmailDigestQueue>,
+	/// Notifications recently sent by rate-limited triggers
+	rate_limiter: Arc<TriggerRateLimiter>,
+}
 
 impl NotificationService {
 	/// Creates a new notification service instance
 	pub fn new() -> Self {
-		NotificationService
+		NotificationService {
+			email_digests: Arc::new(EmailDigestQueue::new()),
+			rate_limiter: Arc::new(TriggerRateLimiter::new()),
+		}
+	}
+
+	/// Returns the queue of the email digests, e.g. to flush them on shutdown
+	pub fn email_digests(&self) -> Arc<EmailDigestQueue> {
+		self.email_digests.clone()
 	}
 
 	/// Executes a notification based on the trigger configuration
 	///
 	/// # Arguments
+	/// * `trigger_slug` - Slug of the trigger, keying its email digest
 	/// * `trigger` - Trigger containing the notification type and parameters
 	/// * `variables` - Variables to substitute in message templates
 	/// * `monitor_match` - Monitor match to send (needed for custom script trigger)
@@ -104,6 +142,7 @@ impl NotificationService {
 	/// * `Result<(), NotificationError>` - Success or error
 	pub async fn execute(
 		&self,
+		trigger_slug: &str,
 		trigger: &Trigger,
 		variables: &HashMap<String, String>,
 		monitor_match: &MonitorMatch,
@@ -118,65 +157,168 @@ impl NotificationService {
 			TriggerType::Email => {
 				let notifier = EmailNotifier::from_config(&trigger.config)?;
 				let message = notifier.format_message(variables);
-				notifier.notify(&message).await?;
+				match &trigger.config {
+					TriggerTypeConfig::Email {
+						digest_interval_mins: Some(interval),
+						..
+					} => self.email_digests.push(
+						trigger_slug,
+						&trigger.config,
+						Duration::from_secs(interval * 60),
+						message,
+					),
+					_ => notifier.notify(&message).await?,
+				}
 			}
 			TriggerType::Webhook => {
 				let notifier = WebhookNotifier::from_config(&trigger.config)?;
 				let message = notifier.format_message(variables);
-				notifier.notify(&message).await?;
+				notifier
+					.notify_match(
+						&message,
+						monitor_match,
+						variables.get("match.id").map(String::as_str),
+					)
+					.await?;
 			}
 			TriggerType::Discord => {
 				let notifier = DiscordNotifier::from_config(&trigger.config)?;
-				let message = notifier.format_message(variables);
-				notifier.notify(&message).await?;
+				notifier.notify_match(variables, monitor_match).await?;
+			}
+			TriggerType::GoogleChat => {
+				let notifier = GoogleChatNotifier::from_config(&trigger.config)?;
+				notifier.notify_match(variables, monitor_match).await?;
+			}
+			TriggerType::Teams => {
+				let notifier = TeamsNotifier::from_config(&trigger.config)?;
+				notifier.notify_match(variables, monitor_match).await?;
 			}
 			TriggerType::Telegram => {
 				let notifier = TelegramNotifier::from_config(&trigger.config)?;
 				let message = notifier.format_message(variables);
 				notifier.notify(&message).await?;
 			}
-			TriggerType::Script => {
-				let notifier = ScriptNotifier::from_config(&trigger.config);
-				if let Some(notifier) = notifier {
-					let monitor_name = match monitor_match {
-						MonitorMatch::Solana(solana_match) => &solana_match.monitor.name,
-						MonitorMatch::EVM(evm_match) => &evm_match.monitor.name,
-						MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.name,
-					};
-					let script_path = match &trigger.config {
-						TriggerTypeConfig::Script { script_path, .. } => script_path,
-						_ => {
-							return Err(NotificationError::config_error(
-								"Invalid script configuration".to_string(),
-								None,
-								None,
-							))
-						}
-					};
-					let script = trigger_scripts
-						.get(&format!("{}|{}", monitor_name, script_path))
-						.ok_or_else(|| {
-							NotificationError::config_error(
-								"Script content not found".to_string(),
-								None,
-								None,
-							)
-						});
-					let script_content = match &script {
-						Ok(content) => content,
-						Err(e) => {
-							return Err(NotificationError::config_error(e.to_string(), None, None))
-						}
-					};
-
-					notifier
-						.script_notify(monitor_match, script_content)
-						.await?;
+			TriggerType::PagerDuty => {
+				let notifier = PagerDutyNotifier::from_config(&trigger.config)?;
+				notifier
+					.notify_match(
+						variables,
+						monitor_match,
+						variables.get("match.id").map(String::as_str),
+					)
+					.await?;
+			}
+			TriggerType::Nats => {
+				let notifier = NatsNotifier::from_config(&trigger.config)?;
+				let message = notifier.format_message(variables);
+				notifier.notify_match(&message, monitor_match).await?;
+			}
+			TriggerType::Matrix => {
+				let notifier = MatrixNotifier::from_config(&trigger.config)?;
+				let message = notifier.format_message(variables);
+				notifier.notify(&message).await?;
+			}
+			TriggerType::Twilio => {
+				let notifier = TwilioNotifier::from_config(&trigger.config)?;
+				let (max_messages, window) = notifier.rate_limit();
+				if !self
+					.rate_limiter
+					.try_acquire(&trigger.name, max_messages, window)
+				{
+					tracing::warn!(
+						"Twilio trigger {} reached its limit of {} messages per hour, dropping match",
+						trigger.name,
+						max_messages
+					);
+					return Ok(());
 				}
+				let message = notifier.format_message(variables);
+				notifier.notify(&message).await?;
+			}
+			TriggerType::Script => {
+				let notifier = ScriptNotifier::from_config(&trigger.config)?;
+				let monitor_name = match monitor_match {
+					MonitorMatch::Solana(solana_match) => &solana_match.monitor.name,
+					MonitorMatch::EVM(evm_match) => &evm_match.monitor.name,
+					MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.name,
+				};
+				let script_path = match &trigger.config {
+					TriggerTypeConfig::Script { script_path, .. } => script_path,
+					_ => {
+						return Err(NotificationError::config_error(
+							"Invalid script configuration".to_string(),
+							None,
+							None,
+						))
+					}
+				};
+				let script = trigger_scripts
+					.get(&format!(
+						"{}|{}",
+						normalize_string(monitor_name),
+						script_path
+					))
+					.ok_or_else(|| {
+						NotificationError::config_error(
+							"Script content not found".to_string(),
+							None,
+							None,
+						)
+					});
+				let script_content = match &script {
+					Ok(content) => content,
+					Err(e) => {
+						return Err(NotificationError::config_error(e.to_string(), None, None))
+					}
+				};
+
+				notifier
+					.script_notify(monitor_match, script_content)
+					.await?;
 			}
 		}
 		Ok(())
 	}
+
+	/// Sends the messages of an email digest of a trigger
+	///
+	/// # Arguments
+	/// * `trigger` - Email trigger of the digest
+	/// * `messages` - Formatted messages of the matches of the digest
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn send_digest(
+		&self,
+		trigger: &Trigger,
+		messages: &[String],
+	) -> Result<(), NotificationError> {
+		EmailNotifier::from_config(&trigger.config)?
+			.notify_digest(messages)
+			.await
+	}
+
+	/// Resolves the alerts a trigger raised for an incident
+	///
+	/// Only PagerDuty alerts are resolved, other notifications cannot be withdrawn.
+	///
+	/// # Arguments
+	/// * `trigger` - Trigger that notified the matches of the incident
+	/// * `incident_id` - Identifier of the resolved incident
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn resolve(
+		&self,
+		trigger: &Trigger,
+		incident_id: &str,
+	) -> Result<(), NotificationError> {
+		if let TriggerType::PagerDuty = trigger.trigger_type {
+			let notifier = PagerDutyNotifier::from_config(&trigger.config)?;
+			notifier.resolve(incident_id).await?;
+		}
+		Ok(())
+	}
 }
 
 impl Default for NotificationService {
@@ -190,9 +332,9 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			AddressWithSpec, EVMMonitorMatch, EVMTransactionReceipt, EventCondition,
-			FunctionCondition, MatchConditions, Monitor, MonitorMatch, ScriptLanguage,
-			TransactionCondition, TriggerType,
+			AddressWithSpec, ConditionLogic, EVMMonitorMatch, EVMTransactionReceipt,
+			EventCondition, FunctionCondition, MatchConditions, Monitor, MonitorMatch,
+			ScriptLanguage, TransactionCondition, TriggerType,
 		},
 		utils::tests::{
 			builders::{evm::monitor::MonitorBuilder, trigger::TriggerBuilder},
@@ -241,6 +383,12 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			matched_on_args: None,
 		}))
@@ -259,6 +407,7 @@ mod tests {
 		let variables = HashMap::new();
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -287,6 +436,7 @@ mod tests {
 		let variables = HashMap::new();
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -315,6 +465,7 @@ mod tests {
 		let variables = HashMap::new();
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -343,6 +494,7 @@ mod tests {
 		let variables = HashMap::new();
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -371,6 +523,7 @@ mod tests {
 		let variables = HashMap::new();
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -386,6 +539,202 @@ mod tests {
 		}
 	}
 
+	#[tokio::test]
+	async fn test_pagerduty_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_pagerduty")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::PagerDuty) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid pagerduty configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_resolve() {
+		let service = NotificationService::new();
+
+		// Only PagerDuty alerts are resolved
+		let slack = TriggerBuilder::new()
+			.name("test_slack")
+			.slack("https://hooks.slack.com/services/test")
+			.build();
+		assert!(service.resolve(&slack, "incident-id").await.is_ok());
+
+		let pagerduty = TriggerBuilder::new()
+			.name("test_pagerduty")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::PagerDuty) // Intentionally wrong config type
+			.build();
+		assert!(matches!(
+			service.resolve(&pagerduty, "incident-id").await,
+			Err(NotificationError::ConfigError(_))
+		));
+	}
+
+	#[tokio::test]
+	async fn test_nats_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_nats")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Nats) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid nats configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_matrix_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_matrix")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Matrix) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid matrix configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_google_chat_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_google_chat")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::GoogleChat) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid google chat configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_teams_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_teams")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Teams) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid teams configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
+	#[tokio::test]
+	async fn test_twilio_notification_invalid_config() {
+		let service = NotificationService::new();
+
+		let trigger = TriggerBuilder::new()
+			.name("test_twilio")
+			.script("invalid", ScriptLanguage::Python)
+			.trigger_type(TriggerType::Twilio) // Intentionally wrong config type
+			.build();
+
+		let variables = HashMap::new();
+		let result = service
+			.execute(
+				"test_trigger",
+				&trigger,
+				&variables,
+				&create_mock_monitor_match(),
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_err());
+		match result {
+			Err(NotificationError::ConfigError(ctx)) => {
+				assert!(ctx.message.contains("Invalid twilio configuration"));
+			}
+			_ => panic!("Expected ConfigError"),
+		}
+	}
+
 	#[tokio::test]
 	async fn test_script_notification_invalid_config() {
 		let service = NotificationService::new();
@@ -400,6 +749,7 @@ mod tests {
 
 		let result = service
 			.execute(
+				"test_trigger",
 				&trigger,
 				&variables,
 				&create_mock_monitor_match(),
@@ -0,0 +1,534 @@
+//! PagerDuty notification implementation.
+//!
+//! Provides functionality to page on-call responders through the PagerDuty Events API v2.
+//! Alerts of a match are deduplicated on its incident, so that the matches of an incident raise
+//! a single PagerDuty alert, which is resolved when the incident is resolved.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+	models::{Monitor, MonitorMatch, PagerDutySeverity, TriggerTypeConfig},
+	services::{
+		filter::evm_helpers::b256_to_string,
+		notification::{NotificationError, Notifier, WebhookConfig, WebhookNotifier},
+	},
+	utils::normalize_string,
+};
+
+/// Endpoint of the PagerDuty Events API v2
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// Maximum length of the summary of a PagerDuty alert
+const PAGERDUTY_MAX_SUMMARY_LENGTH: usize = 1024;
+
+/// Maximum length of the deduplication key of a PagerDuty alert
+const PAGERDUTY_MAX_DEDUP_KEY_LENGTH: usize = 255;
+
+/// Source reported for alerts that are not sent for a monitor match
+const PAGERDUTY_DEFAULT_SOURCE: &str = "openzeppelin-monitor";
+
+/// Implementation of PagerDuty notifications via the Events API v2
+#[derive(Debug)]
+pub struct PagerDutyNotifier {
+	inner: WebhookNotifier,
+	/// Integration key of the PagerDuty service
+	routing_key: String,
+	/// Severity of the alerts, defaulting to the severity of the monitor of the match
+	severity: Option<PagerDutySeverity>,
+}
+
+/// Action of a PagerDuty event
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum PagerDutyEventAction {
+	/// Raises an alert, or adds to the alert with the same deduplication key
+	Trigger,
+	/// Resolves the alert with the deduplication key
+	Resolve,
+}
+
+/// Represents the payload of a triggered PagerDuty alert
+#[derive(Debug, Serialize)]
+struct PagerDutyPayload {
+	/// Summary of the alert (max 1024 characters)
+	summary: String,
+	/// Network or system the alert originates from
+	source: String,
+	/// Severity of the alert
+	severity: PagerDutySeverity,
+	/// Component responsible for the alert (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	component: Option<String>,
+	/// Additional details about the alert (optional)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	custom_details: Option<serde_json::Value>,
+}
+
+impl PagerDutyNotifier {
+	/// Creates a new PagerDuty notifier instance
+	///
+	/// # Arguments
+	/// * `url` - Events API endpoint, or `None` for the PagerDuty endpoint
+	/// * `routing_key` - Integration key of the PagerDuty service
+	/// * `severity` - Severity of the alerts (default: severity of the monitor, or critical)
+	/// * `title` - Alert title
+	/// * `body_template` - Message template with variables
+	pub fn new(
+		url: Option<String>,
+		routing_key: String,
+		severity: Option<PagerDutySeverity>,
+		title: String,
+		body_template: String,
+	) -> Result<Self, NotificationError> {
+		Ok(Self {
+			inner: WebhookNotifier::new(WebhookConfig {
+				url: url.unwrap_or(PAGERDUTY_EVENTS_URL.to_string()),
+				url_params: None,
+				title,
+				body_template,
+				method: Some("POST".to_string()),
+				secret: None,
+				headers: None,
+				payload_fields: None,
+			})?,
+			routing_key,
+			severity,
+		})
+	}
+
+	/// Formats a message by substituting variables in the template
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message with variables replaced
+	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
+		let message = self.inner.format_message(variables);
+		format!("{}: {}", self.inner.title, message)
+	}
+
+	/// Creates a PagerDuty notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing PagerDuty parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is PagerDuty type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::PagerDuty {
+			routing_key,
+			severity,
+			message,
+		} = config
+		{
+			Self::new(
+				None,
+				routing_key.as_ref().to_string(),
+				*severity,
+				message.title.clone(),
+				message.body.clone(),
+			)
+		} else {
+			let msg = format!("Invalid pagerduty configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Returns the deduplication key of the alerts of a monitor match
+	///
+	/// The key is derived from the monitor and the transaction of the match, so that
+	/// notifying the same match again does not raise another alert. It is only used for the
+	/// matches notified outside of an incident.
+	///
+	/// # Arguments
+	/// * `monitor_match` - The monitor match to derive the key from
+	///
+	/// # Returns
+	/// * `String` - Deduplication key of the match
+	pub fn dedup_key(monitor_match: &MonitorMatch) -> String {
+		let (monitor_name, transaction) = match monitor_match {
+			MonitorMatch::EVM(evm_match) => (
+				&evm_match.monitor.name,
+				b256_to_string(*evm_match.transaction.hash()),
+			),
+			MonitorMatch::Stellar(stellar_match) => (
+				&stellar_match.monitor.name,
+				stellar_match.transaction.hash().clone(),
+			),
+			MonitorMatch::Solana(solana_match) => (
+				&solana_match.monitor.name,
+				solana_match.signature().to_string(),
+			),
+		};
+		format!("{}:{}", normalize_string(monitor_name), transaction)
+			.chars()
+			.take(PAGERDUTY_MAX_DEDUP_KEY_LENGTH)
+			.collect()
+	}
+
+	/// Returns the severity of the alerts of a monitor
+	///
+	/// The severity of the trigger takes precedence over the severity of the monitor, and
+	/// alerts are critical when neither is set.
+	pub fn severity(&self, monitor: Option<&Monitor>) -> PagerDutySeverity {
+		self.severity
+			.or_else(|| monitor.and_then(|monitor| monitor.severity).map(Into::into))
+			.unwrap_or_default()
+	}
+
+	/// Raises a PagerDuty alert for a monitor match
+	///
+	/// The alerts of the matches of an incident share its identifier as deduplication key, so
+	/// that they are grouped in a single alert, which is resolved with the incident.
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	/// * `monitor_match` - The monitor match to page for
+	/// * `incident_id` - Identifier of the incident of the match, if any
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_match(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		incident_id: Option<&str>,
+	) -> Result<(), NotificationError> {
+		let (monitor, network_slug) = match monitor_match {
+			MonitorMatch::EVM(evm_match) => (&evm_match.monitor, &evm_match.network_slug),
+			MonitorMatch::Stellar(stellar_match) => {
+				(&stellar_match.monitor, &stellar_match.network_slug)
+			}
+			MonitorMatch::Solana(solana_match) => {
+				(&solana_match.monitor, &solana_match.network_slug)
+			}
+		};
+		let dedup_key = match incident_id {
+			Some(incident_id) => incident_id.to_string(),
+			None => Self::dedup_key(monitor_match),
+		};
+		let payload = PagerDutyPayload {
+			summary: truncate_summary(self.format_message(variables)),
+			source: network_slug.clone(),
+			severity: self.severity(Some(monitor)),
+			component: Some(monitor.name.clone()),
+			custom_details: Some(serde_json::json!(variables)),
+		};
+		self.send_event(
+			PagerDutyEventAction::Trigger,
+			Some(&dedup_key),
+			Some(payload),
+		)
+		.await
+	}
+
+	/// Resolves the PagerDuty alert with a deduplication key
+	///
+	/// # Arguments
+	/// * `dedup_key` - Deduplication key of the alert to resolve, i.e. the identifier of its
+	///   incident
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn resolve(&self, dedup_key: &str) -> Result<(), NotificationError> {
+		self.send_event(PagerDutyEventAction::Resolve, Some(dedup_key), None)
+			.await
+	}
+
+	/// Sends an event to the Events API
+	async fn send_event(
+		&self,
+		action: PagerDutyEventAction,
+		dedup_key: Option<&str>,
+		payload: Option<PagerDutyPayload>,
+	) -> Result<(), NotificationError> {
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert(
+			"routing_key".to_string(),
+			serde_json::json!(self.routing_key),
+		);
+		payload_fields.insert("event_action".to_string(), serde_json::json!(action));
+		if let Some(dedup_key) = dedup_key {
+			payload_fields.insert("dedup_key".to_string(), serde_json::json!(dedup_key));
+		}
+		let summary = payload.as_ref().map(|payload| payload.summary.clone());
+		if let Some(payload) = payload {
+			payload_fields.insert("payload".to_string(), serde_json::json!(payload));
+		}
+
+		self.inner
+			.notify_with_payload(&summary.unwrap_or_default(), payload_fields)
+			.await
+	}
+}
+
+/// Truncates the summary of an alert to the length allowed by PagerDuty
+fn truncate_summary(summary: String) -> String {
+	if summary.chars().count() <= PAGERDUTY_MAX_SUMMARY_LENGTH {
+		return summary;
+	}
+	let mut truncated: String = summary
+		.chars()
+		.take(PAGERDUTY_MAX_SUMMARY_LENGTH - 1)
+		.collect();
+	truncated.push('…');
+	truncated
+}
+
+#[async_trait]
+impl Notifier for PagerDutyNotifier {
+	/// Raises a PagerDuty alert with the formatted message as summary
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let payload = PagerDutyPayload {
+			summary: truncate_summary(message.to_string()),
+			source: PAGERDUTY_DEFAULT_SOURCE.to_string(),
+			severity: self.severity(None),
+			component: None,
+			custom_details: None,
+		};
+		self.send_event(PagerDutyEventAction::Trigger, None, Some(payload))
+			.await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		models::{
+			MatchConditions, MonitorSeverity, NotificationMessage, SecretString, SecretValue,
+			SolanaMonitorMatch,
+		},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use solana_sdk::signature::Signature;
+
+	use super::*;
+
+	fn create_test_notifier(body_template: &str) -> PagerDutyNotifier {
+		PagerDutyNotifier::new(
+			Some("https://non-existent-url-pagerduty.com".to_string()),
+			"test-routing-key".to_string(),
+			None,
+			"Alert".to_string(),
+			body_template.to_string(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_pagerduty_config() -> TriggerTypeConfig {
+		TriggerTypeConfig::PagerDuty {
+			routing_key: SecretValue::Plain(SecretString::new("test-routing-key".to_string())),
+			severity: Some(PagerDutySeverity::Warning),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message ${value}".to_string(),
+			},
+		}
+	}
+
+	fn create_test_solana_match(monitor_name: &str, signature: Signature) -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name(monitor_name).build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().signature(signature).build(),
+		)))
+	}
+
+	////////////////////////////////////////////////////////////
+	// format_message tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_format_message() {
+		let notifier = create_test_notifier("Value is ${value} and status is ${status}");
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "100".to_string());
+		variables.insert("status".to_string(), "critical".to_string());
+
+		let result = notifier.format_message(&variables);
+		assert_eq!(result, "Alert: Value is 100 and status is critical");
+	}
+
+	#[test]
+	fn test_truncate_summary() {
+		let summary = "x".repeat(2 * PAGERDUTY_MAX_SUMMARY_LENGTH);
+		let truncated = truncate_summary(summary);
+		assert_eq!(truncated.chars().count(), PAGERDUTY_MAX_SUMMARY_LENGTH);
+		assert!(truncated.ends_with('…'));
+
+		assert_eq!(truncate_summary("short".to_string()), "short");
+	}
+
+	////////////////////////////////////////////////////////////
+	// from_config tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_from_config_with_pagerduty_config() {
+		let notifier = PagerDutyNotifier::from_config(&create_test_pagerduty_config()).unwrap();
+
+		assert_eq!(notifier.inner.url, PAGERDUTY_EVENTS_URL);
+		assert_eq!(notifier.routing_key, "test-routing-key");
+		assert_eq!(notifier.severity, Some(PagerDutySeverity::Warning));
+		assert_eq!(notifier.inner.title, "Test Alert");
+		assert_eq!(notifier.inner.body_template, "Test message ${value}");
+	}
+
+	#[test]
+	fn test_from_config_defaults() {
+		let config = TriggerTypeConfig::PagerDuty {
+			routing_key: SecretValue::Plain(SecretString::new("test-routing-key".to_string())),
+			severity: None,
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		let notifier = PagerDutyNotifier::from_config(&config).unwrap();
+
+		assert_eq!(notifier.severity, None);
+		assert_eq!(notifier.severity(None), PagerDutySeverity::Critical);
+	}
+
+	////////////////////////////////////////////////////////////
+	// severity tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_severity_defaults_to_monitor_severity() {
+		let notifier = create_test_notifier("Test message");
+		let monitor = MonitorBuilder::new()
+			.severity(MonitorSeverity::Warning)
+			.build();
+
+		assert_eq!(
+			notifier.severity(Some(&monitor)),
+			PagerDutySeverity::Warning
+		);
+		assert_eq!(
+			notifier.severity(Some(&MonitorBuilder::new().build())),
+			PagerDutySeverity::Critical
+		);
+	}
+
+	#[test]
+	fn test_trigger_severity_overrides_monitor_severity() {
+		let notifier = PagerDutyNotifier::from_config(&create_test_pagerduty_config()).unwrap();
+		let monitor = MonitorBuilder::new()
+			.severity(MonitorSeverity::Critical)
+			.build();
+
+		assert_eq!(
+			notifier.severity(Some(&monitor)),
+			PagerDutySeverity::Warning
+		);
+	}
+
+	#[test]
+	fn test_severity_from_monitor_severity() {
+		assert_eq!(
+			PagerDutySeverity::from(MonitorSeverity::Info),
+			PagerDutySeverity::Info
+		);
+		assert_eq!(
+			PagerDutySeverity::from(MonitorSeverity::Warning),
+			PagerDutySeverity::Warning
+		);
+		assert_eq!(
+			PagerDutySeverity::from(MonitorSeverity::Error),
+			PagerDutySeverity::Error
+		);
+		assert_eq!(
+			PagerDutySeverity::from(MonitorSeverity::Critical),
+			PagerDutySeverity::Critical
+		);
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new("random.url".to_string())),
+			message: NotificationMessage {
+				title: "Test Slack".to_string(),
+				body: "This is a test message".to_string(),
+			},
+		};
+
+		let notifier = PagerDutyNotifier::from_config(&config);
+		assert!(notifier.is_err());
+
+		let error = notifier.unwrap_err();
+		assert!(matches!(error, NotificationError::ConfigError { .. }));
+	}
+
+	////////////////////////////////////////////////////////////
+	// dedup_key tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_dedup_key() {
+		let signature = Signature::from([3; 64]);
+		let monitor_match = create_test_solana_match("Kamino Exploit", signature);
+
+		assert_eq!(
+			PagerDutyNotifier::dedup_key(&monitor_match),
+			format!("kamino exploit:{}", signature)
+		);
+		assert_eq!(
+			PagerDutyNotifier::dedup_key(&monitor_match),
+			PagerDutyNotifier::dedup_key(&create_test_solana_match(" KAMINO EXPLOIT ", signature))
+		);
+		assert_ne!(
+			PagerDutyNotifier::dedup_key(&monitor_match),
+			PagerDutyNotifier::dedup_key(&create_test_solana_match(
+				"Kamino Exploit",
+				Signature::from([4; 64])
+			))
+		);
+	}
+
+	#[test]
+	fn test_dedup_key_length() {
+		let monitor_match = create_test_solana_match(&"m".repeat(300), Signature::default());
+		assert_eq!(
+			PagerDutyNotifier::dedup_key(&monitor_match).len(),
+			PAGERDUTY_MAX_DEDUP_KEY_LENGTH
+		);
+	}
+
+	////////////////////////////////////////////////////////////
+	// notify tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_failure() {
+		let notifier = create_test_notifier("Test message");
+		let result = notifier.notify("Test message").await;
+		assert!(result.is_err());
+
+		let error = result.unwrap_err();
+		assert!(matches!(error, NotificationError::NotifyFailed { .. }));
+	}
+
+	#[tokio::test]
+	async fn test_resolve_failure() {
+		let notifier = create_test_notifier("Test message");
+		let result = notifier.resolve("test-dedup-key").await;
+		assert!(result.is_err());
+
+		let error = result.unwrap_err();
+		assert!(matches!(error, NotificationError::NotifyFailed { .. }));
+	}
+}
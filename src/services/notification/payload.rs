@@ -0,0 +1,249 @@
+//! Versioned payloads of monitor matches.
+//!
+//! Webhooks including the monitor match receive it in an explicit JSON schema rather than
+//! as a serialization of the internal models, which change between releases. Each schema
+//! version is built from the internal models by its own conversion, so that a trigger pinning
+//! a version keeps receiving the same payload when the models or the latest schema change.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	models::MonitorMatch,
+	services::notification::{MatchSummary, NotificationError},
+};
+
+/// Schema version of the match payloads sent by triggers not pinning a version
+pub const MATCH_PAYLOAD_SCHEMA_VERSION: u32 = 1;
+
+/// Schema versions of the match payloads that can be sent
+pub const SUPPORTED_MATCH_PAYLOAD_SCHEMA_VERSIONS: &[u32] = &[1];
+
+/// Monitor match in version 1 of the payload schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchPayloadV1 {
+	/// Version of the schema, always 1
+	pub schema_version: u32,
+	/// Chain of the match, "evm", "stellar" or "solana"
+	pub chain: String,
+	/// Slug of the network of the match
+	pub network: String,
+	/// Monitor that matched
+	pub monitor: MonitorPayloadV1,
+	/// Matched transaction
+	pub transaction: TransactionPayloadV1,
+	/// URL of the transaction on the block explorer of the network, if known
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub explorer_url: Option<String>,
+	/// Matched functions, events and instructions
+	pub matches: Vec<MatchedParamsPayloadV1>,
+}
+
+/// Monitor of a match in version 1 of the payload schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MonitorPayloadV1 {
+	/// Name of the monitor
+	pub name: String,
+	/// Severity of the monitor, "info", "warning", "error" or "critical"
+	pub severity: String,
+}
+
+/// Transaction of a match in version 1 of the payload schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionPayloadV1 {
+	/// Hash of the transaction, or signature on Solana
+	pub hash: String,
+	/// Slot of the transaction, on Solana only
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub slot: Option<u64>,
+}
+
+/// Matched function, event or instruction in version 1 of the payload schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchedParamsPayloadV1 {
+	/// Signature of the function, event or instruction
+	pub signature: String,
+	/// Decoded arguments by name
+	pub args: BTreeMap<String, String>,
+}
+
+impl From<&MonitorMatch> for MatchPayloadV1 {
+	fn from(monitor_match: &MonitorMatch) -> Self {
+		let summary = MatchSummary::new(monitor_match);
+		let (chain, monitor, slot) = match monitor_match {
+			MonitorMatch::EVM(evm_match) => ("evm", &evm_match.monitor, None),
+			MonitorMatch::Stellar(stellar_match) => ("stellar", &stellar_match.monitor, None),
+			MonitorMatch::Solana(solana_match) => {
+				("solana", &solana_match.monitor, Some(solana_match.slot()))
+			}
+		};
+
+		Self {
+			schema_version: 1,
+			chain: chain.to_string(),
+			explorer_url: summary.explorer_url(),
+			network: summary.network_slug,
+			monitor: MonitorPayloadV1 {
+				name: summary.monitor_name,
+				severity: monitor.severity().to_string(),
+			},
+			transaction: TransactionPayloadV1 {
+				hash: summary.transaction,
+				slot,
+			},
+			matches: summary
+				.params
+				.into_iter()
+				.map(|params| MatchedParamsPayloadV1 {
+					signature: params.signature,
+					args: params.args.into_iter().collect(),
+				})
+				.collect(),
+		}
+	}
+}
+
+/// Converts a monitor match to a version of the payload schema
+///
+/// # Arguments
+/// * `monitor_match` - The monitor match to convert
+/// * `schema_version` - Version of the payload schema
+///
+/// # Returns
+/// * `Result<serde_json::Value, NotificationError>` - The payload, or an error if the version
+///   is not supported
+pub fn match_payload(
+	monitor_match: &MonitorMatch,
+	schema_version: u32,
+) -> Result<serde_json::Value, NotificationError> {
+	let payload = match schema_version {
+		1 => serde_json::to_value(MatchPayloadV1::from(monitor_match)),
+		_ => {
+			return Err(NotificationError::config_error(
+				format!(
+					"Unsupported match payload schema version {}, supported versions are {:?}",
+					schema_version, SUPPORTED_MATCH_PAYLOAD_SCHEMA_VERSIONS
+				),
+				None,
+				None,
+			))
+		}
+	};
+
+	payload.map_err(|e| {
+		NotificationError::internal_error(
+			format!("Failed to serialize monitor match: {}", e),
+			Some(e.into()),
+			None,
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			MatchConditions, MonitorSeverity, SolanaMonitorMatch,
+		},
+		utils::tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			solana::{
+				monitor::MonitorBuilder as SolanaMonitorBuilder,
+				transaction::TransactionBuilder as SolanaTransactionBuilder,
+			},
+		},
+	};
+	use alloy::primitives::B256;
+	use serde_json::json;
+	use solana_sdk::signature::Signature;
+
+	#[test]
+	fn test_match_payload_v1_evm() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new()
+				.name("Large Transfer")
+				.severity(MonitorSeverity::Critical)
+				.build(),
+			transaction: TransactionBuilder::new()
+				.hash(B256::repeat_byte(0xab))
+				.build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(vec![EVMMatchParamsMap {
+					signature: "Transfer(address,address,uint256)".to_string(),
+					args: Some(vec![EVMMatchParamEntry {
+						name: "value".to_string(),
+						value: "1000".to_string(),
+						kind: "uint256".to_string(),
+						indexed: false,
+					}]),
+					hex_signature: None,
+				}]),
+			}),
+		}));
+		let hash = format!("0x{}", "ab".repeat(32));
+
+		assert_eq!(
+			match_payload(&monitor_match, 1).unwrap(),
+			json!({
+				"schema_version": 1,
+				"chain": "evm",
+				"network": "ethereum_mainnet",
+				"monitor": {"name": "Large Transfer", "severity": "critical"},
+				"transaction": {"hash": hash},
+				"explorer_url": format!("https://etherscan.io/tx/{}", hash),
+				"matches": [{
+					"signature": "Transfer(address,address,uint256)",
+					"args": {"value": "1000"}
+				}]
+			})
+		);
+	}
+
+	#[test]
+	fn test_match_payload_v1_solana() {
+		let signature = Signature::from([7; 64]);
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			SolanaMonitorBuilder::new().name("Token Transfers").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			SolanaTransactionBuilder::new()
+				.signature(signature)
+				.slot(123)
+				.build(),
+		)));
+
+		let payload = match_payload(&monitor_match, MATCH_PAYLOAD_SCHEMA_VERSION).unwrap();
+		let payload: MatchPayloadV1 = serde_json::from_value(payload).unwrap();
+		assert_eq!(payload.schema_version, 1);
+		assert_eq!(payload.chain, "solana");
+		assert_eq!(payload.monitor.severity, "info");
+		assert_eq!(payload.transaction.hash, signature.to_string());
+		assert_eq!(payload.transaction.slot, Some(123));
+		assert!(payload.matches.is_empty());
+	}
+
+	#[test]
+	fn test_match_payload_unsupported_version() {
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			SolanaMonitorBuilder::new().build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			SolanaTransactionBuilder::new().build(),
+		)));
+
+		let err = match_payload(&monitor_match, 0).unwrap_err();
+		assert!(err
+			.to_string()
+			.contains("Unsupported match payload schema version 0"));
+	}
+}
@@ -0,0 +1,86 @@
+//! Trigger rate limiting.
+//!
+//! Limits the number of notifications sent by a trigger over a sliding window, so that a
+//! burst of matches does not flood channels whose messages are costly or disruptive.
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+/// Notifications recently sent by each trigger, by trigger name
+#[derive(Debug, Default)]
+pub struct TriggerRateLimiter {
+	sent: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl TriggerRateLimiter {
+	/// Creates a rate limiter with no notification sent
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Records a notification of a trigger, if the trigger is within its limit
+	///
+	/// # Arguments
+	/// * `trigger_name` - Name of the trigger sending the notification
+	/// * `max_notifications` - Maximum number of notifications sent over the window
+	/// * `window` - Sliding window over which notifications are counted
+	///
+	/// # Returns
+	/// * `bool` - Whether the notification can be sent
+	pub fn try_acquire(
+		&self,
+		trigger_name: &str,
+		max_notifications: u32,
+		window: Duration,
+	) -> bool {
+		let now = Instant::now();
+		let mut sent = self.sent.lock().unwrap();
+		let timestamps = sent.entry(trigger_name.to_string()).or_default();
+
+		while timestamps
+			.front()
+			.is_some_and(|sent_at| now.duration_since(*sent_at) >= window)
+		{
+			timestamps.pop_front();
+		}
+
+		if timestamps.len() >= max_notifications as usize {
+			return false;
+		}
+		timestamps.push_back(now);
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_try_acquire_limits_by_trigger() {
+		let limiter = TriggerRateLimiter::new();
+		let window = Duration::from_secs(3600);
+
+		assert!(limiter.try_acquire("trigger_a", 2, window));
+		assert!(limiter.try_acquire("trigger_a", 2, window));
+		assert!(!limiter.try_acquire("trigger_a", 2, window));
+
+		// Other triggers have their own limit
+		assert!(limiter.try_acquire("trigger_b", 2, window));
+	}
+
+	#[test]
+	fn test_try_acquire_after_window() {
+		let limiter = TriggerRateLimiter::new();
+		let window = Duration::from_millis(20);
+
+		assert!(limiter.try_acquire("trigger", 1, window));
+		assert!(!limiter.try_acquire("trigger", 1, window));
+
+		std::thread::sleep(Duration::from_millis(50));
+		assert!(limiter.try_acquire("trigger", 1, window));
+	}
+}
@@ -204,6 +204,7 @@ mod tests {
 			discord_url: SecretValue::Plain(SecretString::new(
 				"https://discord.example.com".to_string(),
 			)),
+			embed: None,
 			message: NotificationMessage {
 				title: "Test Alert".to_string(),
 				body: "Test message ${value}".to_string(),
@@ -0,0 +1,365 @@
+//! Monitor match summaries.
+//!
+//! Extracts the fields of a monitor match displayed by the notifiers formatting matches as
+//! structured messages, e.g. Discord embeds or chat cards, and links transactions to the
+//! block explorer of their network.
+
+use std::{collections::HashMap, sync::RwLock};
+
+use lazy_static::lazy_static;
+
+use crate::{
+	models::{BlockExplorer, MonitorMatch},
+	services::filter::evm_helpers::b256_to_string,
+};
+
+lazy_static! {
+	/// Block explorers configured by networks, by network slug
+	static ref NETWORK_EXPLORERS: RwLock<HashMap<String, BlockExplorer>> =
+		RwLock::new(HashMap::new());
+}
+
+/// Transaction URLs of the block explorers of known networks, by network slug
+const EXPLORER_TRANSACTION_URLS: &[(&str, &str)] = &[
+	("solana_mainnet", "https://explorer.solana.com/tx/{}"),
+	(
+		"solana_devnet",
+		"https://explorer.solana.com/tx/{}?cluster=devnet",
+	),
+	(
+		"solana_testnet",
+		"https://explorer.solana.com/tx/{}?cluster=testnet",
+	),
+	("ethereum_mainnet", "https://etherscan.io/tx/{}"),
+	("ethereum_sepolia", "https://sepolia.etherscan.io/tx/{}"),
+	("arbitrum_one", "https://arbiscan.io/tx/{}"),
+	("arbitrum_nova", "https://nova.arbiscan.io/tx/{}"),
+	("arbitrum_sepolia", "https://sepolia.arbiscan.io/tx/{}"),
+	("base", "https://basescan.org/tx/{}"),
+	("base-sepolia", "https://sepolia.basescan.org/tx/{}"),
+	("bsc", "https://bscscan.com/tx/{}"),
+	("bsctest", "https://testnet.bscscan.com/tx/{}"),
+	("optimism_mainnet", "https://optimistic.etherscan.io/tx/{}"),
+	(
+		"optimism_sepolia",
+		"https://sepolia-optimism.etherscan.io/tx/{}",
+	),
+	("polygon_mainnet", "https://polygonscan.com/tx/{}"),
+	("polygon_amoy", "https://amoy.polygonscan.com/tx/{}"),
+	("unichain_sepolia", "https://sepolia.uniscan.xyz/tx/{}"),
+	("zksync", "https://explorer.zksync.io/tx/{}"),
+	(
+		"zksync_era_sepolia",
+		"https://sepolia.explorer.zksync.io/tx/{}",
+	),
+	(
+		"stellar_mainnet",
+		"https://stellar.expert/explorer/public/tx/{}",
+	),
+	(
+		"stellar_testnet",
+		"https://stellar.expert/explorer/testnet/tx/{}",
+	),
+];
+
+/// Sets the block explorer linked from the matches of a network, instead of its default one
+///
+/// # Arguments
+/// * `network_slug` - Slug of the network
+/// * `explorer` - Block explorer configured by the network
+pub fn register_explorer(network_slug: &str, explorer: BlockExplorer) {
+	if let Ok(mut explorers) = NETWORK_EXPLORERS.write() {
+		explorers.insert(network_slug.to_string(), explorer);
+	}
+}
+
+/// Returns the URL of a transaction on the block explorer of its network
+///
+/// The explorer configured by the network is used if any, and the default explorer of known
+/// networks otherwise.
+///
+/// # Arguments
+/// * `network_slug` - Slug of the network of the transaction
+/// * `transaction` - Hash or signature of the transaction
+///
+/// # Returns
+/// * `Option<String>` - URL of the transaction, if the network has a known explorer
+pub fn explorer_url(network_slug: &str, transaction: &str) -> Option<String> {
+	let configured = NETWORK_EXPLORERS.read().ok().and_then(|explorers| {
+		explorers
+			.get(network_slug)
+			.map(|explorer| explorer.transaction_url(network_slug, transaction))
+	});
+	if configured.is_some() {
+		return configured;
+	}
+
+	EXPLORER_TRANSACTION_URLS
+		.iter()
+		.find(|(slug, _)| *slug == network_slug)
+		.map(|(_, url)| url.replace("{}", transaction))
+}
+
+/// Matched instruction, function or event of a monitor match
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchedParams {
+	/// Signature of the instruction, function or event
+	pub signature: String,
+	/// Names and values of the decoded arguments
+	pub args: Vec<(String, String)>,
+}
+
+impl MatchedParams {
+	/// Returns the arguments as `name: value` lines, or "No arguments"
+	pub fn describe_args(&self) -> String {
+		if self.args.is_empty() {
+			return "No arguments".to_string();
+		}
+		self.args
+			.iter()
+			.map(|(name, value)| format!("{}: {}", name, value))
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
+
+/// Fields of a monitor match displayed in structured messages
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchSummary {
+	/// Name of the monitor that matched
+	pub monitor_name: String,
+	/// Slug of the network of the match
+	pub network_slug: String,
+	/// Label of the transaction identifier, "Transaction" or "Signature"
+	pub transaction_label: &'static str,
+	/// Hash or signature of the transaction
+	pub transaction: String,
+	/// Matched instructions, functions and events
+	pub params: Vec<MatchedParams>,
+}
+
+impl MatchSummary {
+	/// Extracts the summary of a monitor match
+	pub fn new(monitor_match: &MonitorMatch) -> Self {
+		match monitor_match {
+			MonitorMatch::EVM(evm_match) => Self {
+				monitor_name: evm_match.monitor.name.clone(),
+				network_slug: evm_match.network_slug.clone(),
+				transaction_label: "Transaction",
+				transaction: b256_to_string(*evm_match.transaction.hash()),
+				params: evm_match
+					.matched_on_args
+					.iter()
+					.flat_map(|args| args.functions.iter().chain(args.events.iter()).flatten())
+					.map(|params| MatchedParams {
+						signature: params.signature.clone(),
+						args: params
+							.args
+							.iter()
+							.flatten()
+							.map(|arg| (arg.name.clone(), arg.value.clone()))
+							.collect(),
+					})
+					.collect(),
+			},
+			MonitorMatch::Stellar(stellar_match) => Self {
+				monitor_name: stellar_match.monitor.name.clone(),
+				network_slug: stellar_match.network_slug.clone(),
+				transaction_label: "Transaction",
+				transaction: stellar_match.transaction.hash().clone(),
+				params: stellar_match
+					.matched_on_args
+					.iter()
+					.flat_map(|args| args.functions.iter().chain(args.events.iter()).flatten())
+					.map(|params| MatchedParams {
+						signature: params.signature.clone(),
+						args: params
+							.args
+							.iter()
+							.flatten()
+							.map(|arg| (arg.name.clone(), arg.value.clone()))
+							.collect(),
+					})
+					.collect(),
+			},
+			MonitorMatch::Solana(solana_match) => Self {
+				monitor_name: solana_match.monitor.name.clone(),
+				network_slug: solana_match.network_slug.clone(),
+				transaction_label: "Signature",
+				transaction: solana_match.signature().to_string(),
+				params: solana_match
+					.matched_on_args()
+					.iter()
+					.flat_map(|args| {
+						[
+							&args.instructions,
+							&args.accounts,
+							&args.balance_changes,
+							&args.events,
+							&args.logs,
+							&args.sequences,
+							&args.blocks,
+						]
+						.into_iter()
+						.flatten()
+						.flatten()
+					})
+					.map(|params| MatchedParams {
+						signature: params.signature.clone(),
+						args: params
+							.args
+							.iter()
+							.flatten()
+							.map(|arg| (arg.name.clone(), arg.value.clone()))
+							.collect(),
+					})
+					.collect(),
+			},
+		}
+	}
+
+	/// Returns the URL of the transaction on the block explorer of the network, if known
+	pub fn explorer_url(&self) -> Option<String> {
+		explorer_url(&self.network_slug, &self.transaction)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			MatchConditions, SolanaMonitorMatch,
+		},
+		utils::tests::{
+			evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+			solana::{
+				monitor::MonitorBuilder as SolanaMonitorBuilder,
+				transaction::TransactionBuilder as SolanaTransactionBuilder,
+			},
+		},
+	};
+	use alloy::primitives::B256;
+	use solana_sdk::signature::Signature;
+
+	#[test]
+	fn test_explorer_url() {
+		assert_eq!(
+			explorer_url("solana_mainnet", "5sig").as_deref(),
+			Some("https://explorer.solana.com/tx/5sig")
+		);
+		assert_eq!(
+			explorer_url("solana_devnet", "5sig").as_deref(),
+			Some("https://explorer.solana.com/tx/5sig?cluster=devnet")
+		);
+		assert_eq!(
+			explorer_url("ethereum_mainnet", "0xab").as_deref(),
+			Some("https://etherscan.io/tx/0xab")
+		);
+		assert_eq!(explorer_url("unknown_network", "0xab"), None);
+	}
+
+	#[test]
+	fn test_explorer_url_of_registered_explorer() {
+		register_explorer("test_solana_devnet", BlockExplorer::Solscan);
+		assert_eq!(
+			explorer_url("test_solana_devnet", "5sig").as_deref(),
+			Some("https://solscan.io/tx/5sig?cluster=devnet")
+		);
+
+		// Registered explorers replace the default explorer of known networks
+		register_explorer(
+			"polygon_amoy",
+			BlockExplorer::Custom {
+				url: "https://www.oklink.com/amoy/tx/{}".to_string(),
+			},
+		);
+		assert_eq!(
+			explorer_url("polygon_amoy", "0xab").as_deref(),
+			Some("https://www.oklink.com/amoy/tx/0xab")
+		);
+
+		register_explorer("test_solana_testnet", BlockExplorer::Xray);
+		assert_eq!(
+			explorer_url("test_solana_testnet", "5sig").as_deref(),
+			Some("https://xray.helius.xyz/tx/5sig?network=testnet")
+		);
+
+		register_explorer(
+			"test_custom_network",
+			BlockExplorer::Custom {
+				url: "https://explorer.example.com/transactions/{}".to_string(),
+			},
+		);
+		assert_eq!(
+			explorer_url("test_custom_network", "0xab").as_deref(),
+			Some("https://explorer.example.com/transactions/0xab")
+		);
+	}
+
+	#[test]
+	fn test_summary_solana() {
+		let signature = Signature::from([7; 64]);
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			SolanaMonitorBuilder::new().name("Token Transfers").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			SolanaTransactionBuilder::new().signature(signature).build(),
+		)));
+
+		let summary = MatchSummary::new(&monitor_match);
+		assert_eq!(summary.monitor_name, "Token Transfers");
+		assert_eq!(summary.network_slug, "solana_mainnet");
+		assert_eq!(summary.transaction_label, "Signature");
+		assert_eq!(summary.transaction, signature.to_string());
+		assert!(summary.params.is_empty());
+		assert_eq!(
+			summary.explorer_url(),
+			Some(format!("https://explorer.solana.com/tx/{}", signature))
+		);
+	}
+
+	#[test]
+	fn test_summary_evm() {
+		let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfer").build(),
+			transaction: TransactionBuilder::new()
+				.hash(B256::repeat_byte(0xab))
+				.build(),
+			receipt: None,
+			logs: None,
+			network_slug: "ethereum_mainnet".to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(vec![
+					EVMMatchParamsMap {
+						signature: "Transfer(address,address,uint256)".to_string(),
+						args: Some(vec![EVMMatchParamEntry {
+							name: "value".to_string(),
+							value: "1000".to_string(),
+							indexed: false,
+							kind: "uint256".to_string(),
+						}]),
+						hex_signature: None,
+					},
+					EVMMatchParamsMap {
+						signature: "Paused()".to_string(),
+						args: None,
+						hex_signature: None,
+					},
+				]),
+			}),
+		}));
+
+		let summary = MatchSummary::new(&monitor_match);
+		assert_eq!(summary.transaction_label, "Transaction");
+		assert_eq!(summary.transaction, b256_to_string(B256::repeat_byte(0xab)));
+		assert_eq!(summary.params.len(), 2);
+		assert_eq!(summary.params[0].describe_args(), "value: 1000");
+		assert_eq!(summary.params[1].signature, "Paused()");
+		assert_eq!(summary.params[1].describe_args(), "No arguments");
+	}
+}
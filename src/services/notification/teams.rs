@@ -0,0 +1,493 @@
+//! Microsoft Teams notification implementation.
+//!
+//! Provides functionality to send monitor matches to Microsoft Teams channels via incoming
+//! webhooks, formatted as Adaptive Cards with the fields of the match and an action opening
+//! the transaction on the block explorer of its network.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::{
+	models::{MonitorMatch, TriggerTypeConfig},
+	services::notification::{
+		MatchSummary, NotificationError, Notifier, WebhookConfig, WebhookNotifier,
+	},
+};
+
+/// Schema of Adaptive Cards
+const ADAPTIVE_CARD_SCHEMA: &str = "http://adaptivecards.io/schemas/adaptive-card.json";
+
+/// Version of the Adaptive Cards sent, the latest supported by Teams webhooks
+const ADAPTIVE_CARD_VERSION: &str = "1.4";
+
+/// Content type of Adaptive Card attachments
+const ADAPTIVE_CARD_CONTENT_TYPE: &str = "application/vnd.microsoft.card.adaptive";
+
+/// Maximum number of matched instructions, functions or events displayed in a card
+const TEAMS_MAX_PARAMS_FACT_SETS: usize = 20;
+
+/// Implementation of Microsoft Teams notifications via incoming webhooks
+#[derive(Debug)]
+pub struct TeamsNotifier {
+	inner: WebhookNotifier,
+}
+
+/// Represents an Adaptive Card
+#[derive(Debug, Serialize)]
+struct AdaptiveCard {
+	/// Schema of the card
+	#[serde(rename = "$schema")]
+	schema: &'static str,
+	/// Type of the card, always "AdaptiveCard"
+	#[serde(rename = "type")]
+	card_type: &'static str,
+	/// Version of the card schema
+	version: &'static str,
+	/// Elements of the card
+	body: Vec<AdaptiveCardElement>,
+	/// Actions displayed below the card (optional)
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	actions: Vec<AdaptiveCardAction>,
+}
+
+/// Represents an element of an Adaptive Card
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+enum AdaptiveCardElement {
+	/// Block of text
+	TextBlock {
+		/// Text of the block, supporting a subset of Markdown
+		text: String,
+		/// Weight of the text (optional)
+		#[serde(skip_serializing_if = "Option::is_none")]
+		weight: Option<&'static str>,
+		/// Size of the text (optional)
+		#[serde(skip_serializing_if = "Option::is_none")]
+		size: Option<&'static str>,
+		/// Whether the text wraps instead of being truncated
+		wrap: bool,
+	},
+	/// List of facts displayed as a table
+	FactSet {
+		/// Facts of the set
+		facts: Vec<AdaptiveCardFact>,
+	},
+}
+
+/// Represents a fact of an Adaptive Card fact set
+#[derive(Debug, Serialize)]
+struct AdaptiveCardFact {
+	/// Title of the fact
+	title: String,
+	/// Value of the fact
+	value: String,
+}
+
+/// Represents an action of an Adaptive Card
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+enum AdaptiveCardAction {
+	/// Opens a URL
+	#[serde(rename = "Action.OpenUrl")]
+	OpenUrl {
+		/// Title of the action
+		title: String,
+		/// URL to open
+		url: String,
+	},
+}
+
+impl AdaptiveCard {
+	/// Creates an Adaptive Card with the given elements and actions
+	fn new(body: Vec<AdaptiveCardElement>, actions: Vec<AdaptiveCardAction>) -> Self {
+		Self {
+			schema: ADAPTIVE_CARD_SCHEMA,
+			card_type: "AdaptiveCard",
+			version: ADAPTIVE_CARD_VERSION,
+			body,
+			actions,
+		}
+	}
+}
+
+impl TeamsNotifier {
+	/// Creates a new Microsoft Teams notifier instance
+	///
+	/// # Arguments
+	/// * `url` - Teams webhook URL
+	/// * `title` - Title of the card
+	/// * `body_template` - Message template with variables
+	pub fn new(
+		url: String,
+		title: String,
+		body_template: String,
+	) -> Result<Self, NotificationError> {
+		Ok(Self {
+			inner: WebhookNotifier::new(WebhookConfig {
+				url,
+				url_params: None,
+				title,
+				body_template,
+				method: Some("POST".to_string()),
+				secret: None,
+				headers: None,
+				payload_fields: None,
+			})?,
+		})
+	}
+
+	/// Formats a message by substituting variables in the template
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message with variables replaced
+	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
+		self.inner.format_message(variables)
+	}
+
+	/// Creates a Microsoft Teams notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Teams parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Teams type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Teams { teams_url, message } = config {
+			Self::new(
+				teams_url.as_ref().to_string(),
+				message.title.clone(),
+				message.body.clone(),
+			)
+		} else {
+			let msg = format!("Invalid teams configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Sends a monitor match to Microsoft Teams as an Adaptive Card
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	/// * `monitor_match` - The monitor match to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_match(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> Result<(), NotificationError> {
+		let card = self.match_card(variables, monitor_match);
+		self.send_card(&self.format_message(variables), card).await
+	}
+
+	/// Builds the Adaptive Card of a monitor match
+	///
+	/// The card holds the title and message, the monitor, network and transaction of the
+	/// match, followed by the decoded arguments of each matched instruction, function or
+	/// event, and an action opening the transaction on the explorer.
+	fn match_card(
+		&self,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> AdaptiveCard {
+		let summary = MatchSummary::new(monitor_match);
+
+		let mut body = self.message_elements(&self.format_message(variables));
+		body.push(AdaptiveCardElement::FactSet {
+			facts: vec![
+				AdaptiveCardFact {
+					title: "Monitor".to_string(),
+					value: summary.monitor_name.clone(),
+				},
+				AdaptiveCardFact {
+					title: "Network".to_string(),
+					value: summary.network_slug.clone(),
+				},
+				AdaptiveCardFact {
+					title: summary.transaction_label.to_string(),
+					value: summary.transaction.clone(),
+				},
+			],
+		});
+		for params in summary.params.iter().take(TEAMS_MAX_PARAMS_FACT_SETS) {
+			body.push(AdaptiveCardElement::TextBlock {
+				text: params.signature.clone(),
+				weight: Some("Bolder"),
+				size: None,
+				wrap: true,
+			});
+			if params.args.is_empty() {
+				body.push(AdaptiveCardElement::TextBlock {
+					text: params.describe_args(),
+					weight: None,
+					size: None,
+					wrap: true,
+				});
+			} else {
+				body.push(AdaptiveCardElement::FactSet {
+					facts: params
+						.args
+						.iter()
+						.map(|(name, value)| AdaptiveCardFact {
+							title: name.clone(),
+							value: value.clone(),
+						})
+						.collect(),
+				});
+			}
+		}
+
+		let actions = summary
+			.explorer_url()
+			.map(|url| AdaptiveCardAction::OpenUrl {
+				title: "View in explorer".to_string(),
+				url,
+			})
+			.into_iter()
+			.collect();
+
+		AdaptiveCard::new(body, actions)
+	}
+
+	/// Returns the elements displaying the title and a message
+	fn message_elements(&self, message: &str) -> Vec<AdaptiveCardElement> {
+		vec![
+			AdaptiveCardElement::TextBlock {
+				text: self.inner.title.clone(),
+				weight: Some("Bolder"),
+				size: Some("Medium"),
+				wrap: true,
+			},
+			AdaptiveCardElement::TextBlock {
+				text: message.to_string(),
+				weight: None,
+				size: None,
+				wrap: true,
+			},
+		]
+	}
+
+	/// Sends an Adaptive Card as the attachment of a message
+	async fn send_card(&self, message: &str, card: AdaptiveCard) -> Result<(), NotificationError> {
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert("type".to_string(), serde_json::json!("message"));
+		payload_fields.insert(
+			"attachments".to_string(),
+			serde_json::json!([{
+				"contentType": ADAPTIVE_CARD_CONTENT_TYPE,
+				"contentUrl": null,
+				"content": card,
+			}]),
+		);
+
+		self.inner
+			.notify_with_payload(message, payload_fields)
+			.await
+	}
+}
+
+#[async_trait]
+impl Notifier for TeamsNotifier {
+	/// Sends a formatted message to Microsoft Teams as an Adaptive Card
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let card = AdaptiveCard::new(self.message_elements(message), vec![]);
+		self.send_card(message, card).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		models::{
+			EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+			MatchConditions, NotificationMessage, SecretString, SecretValue,
+		},
+		services::filter::evm_helpers::b256_to_string,
+		utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use alloy::primitives::B256;
+
+	use super::*;
+
+	fn create_test_notifier(url: &str) -> TeamsNotifier {
+		TeamsNotifier::new(
+			url.to_string(),
+			"Alert".to_string(),
+			"Value is ${value}".to_string(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_evm_match(network_slug: &str) -> MonitorMatch {
+		MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+			monitor: MonitorBuilder::new().name("Large Transfer").build(),
+			transaction: TransactionBuilder::new()
+				.hash(B256::repeat_byte(0xab))
+				.build(),
+			receipt: None,
+			logs: None,
+			network_slug: network_slug.to_string(),
+			matched_on: MatchConditions::default(),
+			matched_on_args: Some(EVMMatchArguments {
+				functions: None,
+				events: Some(vec![
+					EVMMatchParamsMap {
+						signature: "Transfer(address,address,uint256)".to_string(),
+						args: Some(vec![EVMMatchParamEntry {
+							name: "value".to_string(),
+							value: "1000".to_string(),
+							indexed: false,
+							kind: "uint256".to_string(),
+						}]),
+						hex_signature: None,
+					},
+					EVMMatchParamsMap {
+						signature: "Paused()".to_string(),
+						args: None,
+						hex_signature: None,
+					},
+				]),
+			}),
+		}))
+	}
+
+	////////////////////////////////////////////////////////////
+	// from_config tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_from_config_with_teams_config() {
+		let config = TriggerTypeConfig::Teams {
+			teams_url: SecretValue::Plain(SecretString::new(
+				"https://example.webhook.office.com/webhookb2/x".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message ${value}".to_string(),
+			},
+		};
+
+		let notifier = TeamsNotifier::from_config(&config).unwrap();
+		assert_eq!(
+			notifier.inner.url,
+			"https://example.webhook.office.com/webhookb2/x"
+		);
+		assert_eq!(notifier.inner.title, "Test Alert");
+		assert_eq!(notifier.inner.body_template, "Test message ${value}");
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new(
+				"https://slack.example.com".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+
+		let result = TeamsNotifier::from_config(&config);
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	////////////////////////////////////////////////////////////
+	// match_card tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_match_card() {
+		let notifier = create_test_notifier("https://example.webhook.office.com/webhookb2/x");
+		let transaction = b256_to_string(B256::repeat_byte(0xab));
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "42".to_string());
+		let card = notifier.match_card(&variables, &create_test_evm_match("ethereum_mainnet"));
+
+		assert_eq!(
+			serde_json::to_value(card).unwrap(),
+			serde_json::json!({
+				"$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+				"type": "AdaptiveCard",
+				"version": "1.4",
+				"body": [
+					{
+						"type": "TextBlock",
+						"text": "Alert",
+						"weight": "Bolder",
+						"size": "Medium",
+						"wrap": true
+					},
+					{ "type": "TextBlock", "text": "Value is 42", "wrap": true },
+					{
+						"type": "FactSet",
+						"facts": [
+							{ "title": "Monitor", "value": "Large Transfer" },
+							{ "title": "Network", "value": "ethereum_mainnet" },
+							{ "title": "Transaction", "value": transaction },
+						]
+					},
+					{
+						"type": "TextBlock",
+						"text": "Transfer(address,address,uint256)",
+						"weight": "Bolder",
+						"wrap": true
+					},
+					{ "type": "FactSet", "facts": [{ "title": "value", "value": "1000" }] },
+					{ "type": "TextBlock", "text": "Paused()", "weight": "Bolder", "wrap": true },
+					{ "type": "TextBlock", "text": "No arguments", "wrap": true },
+				],
+				"actions": [{
+					"type": "Action.OpenUrl",
+					"title": "View in explorer",
+					"url": format!("https://etherscan.io/tx/{}", transaction)
+				}]
+			})
+		);
+	}
+
+	#[test]
+	fn test_match_card_without_explorer() {
+		let notifier = create_test_notifier("https://example.webhook.office.com/webhookb2/x");
+		let card = notifier.match_card(&HashMap::new(), &create_test_evm_match("devnet"));
+
+		assert!(card.actions.is_empty());
+		assert!(!serde_json::to_value(card)
+			.unwrap()
+			.as_object()
+			.unwrap()
+			.contains_key("actions"));
+	}
+
+	////////////////////////////////////////////////////////////
+	// notify tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_failure() {
+		let notifier = create_test_notifier("https://non-existent-url-teams.com");
+		let result = notifier.notify("Test message").await;
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	}
+
+	#[tokio::test]
+	async fn test_notify_match_failure() {
+		let notifier = create_test_notifier("https://non-existent-url-teams.com");
+		let result = notifier
+			.notify_match(&HashMap::new(), &create_test_evm_match("ethereum_mainnet"))
+			.await;
+		assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	}
+}
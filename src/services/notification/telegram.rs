@@ -12,6 +12,11 @@ use crate::{
 	services::notification::{NotificationError, Notifier, WebhookConfig, WebhookNotifier},
 };
 
+/// Telegram MarkdownV2 metacharacters (including backslash)
+const MARKDOWN_V2_SPECIAL: &[char] = &[
+	'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!', '\\',
+];
+
 /// Implementation of Telegram notifications via webhooks
 #[derive(Debug)]
 pub struct TelegramNotifier {
@@ -65,18 +70,54 @@ impl TelegramNotifier {
 
 	/// Formats a message by substituting variables in the template
 	///
+	/// The template is escaped with its entities preserved, while variable values are escaped
+	/// entirely, so that values such as base58 signatures, snake_case argument names or
+	/// decimal amounts are displayed as-is even inside entities of the template.
+	///
 	/// # Arguments
 	/// * `variables` - Map of variable names to values
 	///
 	/// # Returns
 	/// * `String` - Formatted message with variables replaced
 	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
-		let message = self.inner.format_message(variables);
-		let escaped_message = Self::escape_markdown_v2(&message);
+		// Substitute variables with placeholders holding no MarkdownV2 characters, so that
+		// their values are not mistaken for entities when the template is escaped
+		let placeholder = |index: usize| format!("\u{E000}{}\u{E001}", index);
+		let values: Vec<&String> = variables.values().collect();
+		let placeholders: HashMap<String, String> = variables
+			.keys()
+			.enumerate()
+			.map(|(index, key)| (key.clone(), placeholder(index)))
+			.collect();
+
+		let template = self.inner.format_message(&placeholders);
+		let mut escaped_message = Self::escape_markdown_v2(&template);
+		for (index, value) in values.into_iter().enumerate() {
+			escaped_message =
+				escaped_message.replace(&placeholder(index), &Self::escape_text(value));
+		}
 		let escaped_title = Self::escape_markdown_v2(&self.inner.title);
 		format!("*{}* \n\n{}", escaped_title, escaped_message)
 	}
 
+	/// Escape every MarkdownV2 metacharacter of a text, so that it is displayed verbatim
+	///
+	/// # Arguments
+	/// * `text` - The text to escape
+	///
+	/// # Returns
+	/// * `String` - The escaped text
+	pub fn escape_text(text: &str) -> String {
+		let mut out = String::with_capacity(text.len() * 2);
+		for c in text.chars() {
+			if MARKDOWN_V2_SPECIAL.contains(&c) {
+				out.push('\\');
+			}
+			out.push(c);
+		}
+		out
+	}
+
 	/// Escape a full MarkdownV2 message, preserving entities and
 	/// escaping *all* special chars inside link URLs too.
 	///
@@ -86,12 +127,6 @@ impl TelegramNotifier {
 	/// # Returns
 	/// * `String` - The escaped text
 	pub fn escape_markdown_v2(text: &str) -> String {
-		// Full set of Telegram MDV2 metacharacters (including backslash)
-		const SPECIAL: &[char] = &[
-			'_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.',
-			'!', '\\',
-		];
-
 		// Regex that captures either:
 		//  - any MD entity: ```…```, `…`, *…*, _…_, ~…~
 		//  - or an inline link, capturing label & URL separately
@@ -106,31 +141,14 @@ impl TelegramNotifier {
 			let mat = caps.get(0).unwrap();
 
 			// 1) escape everything before this match
-			for c in text[last..mat.start()].chars() {
-				if SPECIAL.contains(&c) {
-					out.push('\\');
-				}
-				out.push(c);
-			}
+			out.push_str(&Self::escape_text(&text[last..mat.start()]));
 
 			// 2) if this is an inline link (has two capture groups)
 			if let (Some(lbl), Some(url)) = (caps.get(1), caps.get(2)) {
 				// fully escape the label
-				let mut esc_label = String::with_capacity(lbl.as_str().len() * 2);
-				for c in lbl.as_str().chars() {
-					if SPECIAL.contains(&c) {
-						esc_label.push('\\');
-					}
-					esc_label.push(c);
-				}
+				let esc_label = Self::escape_text(lbl.as_str());
 				// fully escape the URL (dots, hyphens, slashes, etc.)
-				let mut esc_url = String::with_capacity(url.as_str().len() * 2);
-				for c in url.as_str().chars() {
-					if SPECIAL.contains(&c) {
-						esc_url.push('\\');
-					}
-					esc_url.push(c);
-				}
+				let esc_url = Self::escape_text(url.as_str());
 				// emit the link markers unescaped
 				out.push('[');
 				out.push_str(&esc_label);
@@ -147,12 +165,7 @@ impl TelegramNotifier {
 		}
 
 		// 4) escape the trailing text after the last match
-		for c in text[last..].chars() {
-			if SPECIAL.contains(&c) {
-				out.push('\\');
-			}
-			out.push(c);
-		}
+		out.push_str(&Self::escape_text(&text[last..]));
 
 		out
 	}
@@ -173,7 +186,7 @@ impl TelegramNotifier {
 		} = config
 		{
 			let mut url_params = HashMap::new();
-			url_params.insert("chat_id".to_string(), chat_id.clone());
+			url_params.insert("chat_id".to_string(), chat_id.as_ref().to_string());
 			url_params.insert("parse_mode".to_string(), "MarkdownV2".to_string());
 
 			let webhook_config = WebhookConfig {
@@ -256,7 +269,7 @@ mod tests {
 	fn create_test_telegram_config() -> TriggerTypeConfig {
 		TriggerTypeConfig::Telegram {
 			token: SecretValue::Plain(SecretString::new("test-token".to_string())),
-			chat_id: "test-chat-id".to_string(),
+			chat_id: SecretValue::Plain(SecretString::new("test-chat-id".to_string())),
 			disable_web_preview: Some(true),
 			message: NotificationMessage {
 				title: "Alert".to_string(),
@@ -296,6 +309,46 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn test_format_message_escapes_variables() {
+		let notifier = create_test_notifier(
+			"*${instructions.0.signature}* of _${instructions.0.args.amount}_ on ${network} \
+			 [View](https://solscan.io/tx/${transaction.signature})",
+		);
+
+		let signature = "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW";
+		let mut variables = HashMap::new();
+		variables.insert(
+			"instructions.0.signature".to_string(),
+			"Transfer_Checked".to_string(),
+		);
+		variables.insert("instructions.0.args.amount".to_string(), "1.5".to_string());
+		variables.insert("network".to_string(), "solana_mainnet".to_string());
+		variables.insert("transaction.signature".to_string(), signature.to_string());
+
+		let result = notifier.format_message(&variables);
+		assert_eq!(
+			result,
+			format!(
+				"*Alert* \n\n*Transfer\\_Checked* of _1\\.5_ on solana\\_mainnet \
+				 [View](https://solscan\\.io/tx/{})",
+				signature
+			)
+		);
+	}
+
+	#[test]
+	fn test_escape_text() {
+		assert_eq!(
+			TelegramNotifier::escape_text("*bold* [link](https://a.b) `code`"),
+			"\\*bold\\* \\[link\\]\\(https://a\\.b\\) \\`code\\`"
+		);
+		assert_eq!(
+			TelegramNotifier::escape_text("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD"),
+			"KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD"
+		);
+	}
+
 	#[test]
 	fn test_format_message_with_empty_template() {
 		let notifier = create_test_notifier("");
@@ -349,7 +402,7 @@ mod tests {
 	fn test_from_config_disable_web_preview_default_in_config() {
 		let config = TriggerTypeConfig::Telegram {
 			token: SecretValue::Plain(SecretString::new("test-token".to_string())),
-			chat_id: "test-chat-id".to_string(),
+			chat_id: SecretValue::Plain(SecretString::new("test-chat-id".to_string())),
 			disable_web_preview: None, // Test default within TriggerTypeConfig
 			message: NotificationMessage {
 				title: "Alert".to_string(),
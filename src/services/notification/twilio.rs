@@ -0,0 +1,364 @@
+//! Twilio notification implementation.
+//!
+//! Provides functionality to send SMS through the Twilio Messaging API, for break-glass
+//! alerts that must reach responders away from their chat clients. Each match is sent to
+//! every recipient of the trigger, within the hourly limit of the trigger.
+
+use async_trait::async_trait;
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+	models::TriggerTypeConfig,
+	services::notification::{NotificationError, Notifier, WebhookConfig, WebhookNotifier},
+};
+
+/// Base URL of the Twilio REST API
+const TWILIO_API_URL: &str = "https://api.twilio.com";
+
+/// Maximum length of the body of a Twilio message
+const TWILIO_MAX_BODY_LENGTH: usize = 1600;
+
+/// Maximum number of retries of messages failing with transient errors, e.g. rate limits
+const TWILIO_MAX_RETRIES: u32 = 3;
+
+/// Default maximum number of matches sent by a trigger per hour
+const TWILIO_DEFAULT_MAX_MESSAGES_PER_HOUR: u32 = 5;
+
+/// Window over which the messages of a trigger are counted
+const TWILIO_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(3600);
+
+/// Implementation of SMS notifications via the Twilio Messaging API
+#[derive(Debug)]
+pub struct TwilioNotifier {
+	inner: WebhookNotifier,
+	/// SID of the Twilio account
+	account_sid: String,
+	/// Auth token of the Twilio account
+	auth_token: String,
+	/// Phone number the SMS are sent from
+	from: String,
+	/// Phone numbers the SMS are sent to
+	to: Vec<String>,
+	/// Maximum number of matches sent per hour
+	max_messages_per_hour: u32,
+}
+
+impl TwilioNotifier {
+	/// Creates a new Twilio notifier instance
+	///
+	/// # Arguments
+	/// * `api_url` - Base URL of the REST API, or `None` for the Twilio API
+	/// * `account_sid` - SID of the Twilio account
+	/// * `auth_token` - Auth token of the Twilio account
+	/// * `from` - Phone number the SMS are sent from
+	/// * `to` - Phone numbers the SMS are sent to
+	/// * `max_messages_per_hour` - Maximum number of matches sent per hour (default 5)
+	/// * `title` - Title to display in the message
+	/// * `body_template` - Message template with variables
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		api_url: Option<String>,
+		account_sid: String,
+		auth_token: String,
+		from: String,
+		to: Vec<String>,
+		max_messages_per_hour: Option<u32>,
+		title: String,
+		body_template: String,
+	) -> Result<Self, NotificationError> {
+		let url = format!(
+			"{}/2010-04-01/Accounts/{}/Messages.json",
+			api_url
+				.as_deref()
+				.unwrap_or(TWILIO_API_URL)
+				.trim_end_matches('/'),
+			account_sid
+		);
+
+		Ok(Self {
+			inner: WebhookNotifier::new(WebhookConfig {
+				url,
+				url_params: None,
+				title,
+				body_template,
+				method: Some("POST".to_string()),
+				secret: None,
+				headers: None,
+				payload_fields: None,
+			})?
+			.with_retries(TWILIO_MAX_RETRIES),
+			account_sid,
+			auth_token,
+			from,
+			to,
+			max_messages_per_hour: max_messages_per_hour
+				.unwrap_or(TWILIO_DEFAULT_MAX_MESSAGES_PER_HOUR),
+		})
+	}
+
+	/// Formats a message by substituting variables in the template
+	///
+	/// The message is truncated to the maximum length of a Twilio message.
+	///
+	/// # Arguments
+	/// * `variables` - Map of variable names to values
+	///
+	/// # Returns
+	/// * `String` - Formatted message with variables replaced
+	pub fn format_message(&self, variables: &HashMap<String, String>) -> String {
+		let message = format!(
+			"{}\n{}",
+			self.inner.title,
+			self.inner.format_message(variables)
+		);
+		if message.chars().count() <= TWILIO_MAX_BODY_LENGTH {
+			return message;
+		}
+		let mut truncated: String = message.chars().take(TWILIO_MAX_BODY_LENGTH - 1).collect();
+		truncated.push('…');
+		truncated
+	}
+
+	/// Returns the maximum number of matches sent by the trigger, and the window they are
+	/// counted over
+	pub fn rate_limit(&self) -> (u32, Duration) {
+		(self.max_messages_per_hour, TWILIO_RATE_LIMIT_WINDOW)
+	}
+
+	/// Creates a Twilio notifier from a trigger configuration
+	///
+	/// # Arguments
+	/// * `config` - Trigger configuration containing Twilio parameters
+	///
+	/// # Returns
+	/// * `Result<Self, NotificationError>` - Notifier instance if config is Twilio type
+	pub fn from_config(config: &TriggerTypeConfig) -> Result<Self, NotificationError> {
+		if let TriggerTypeConfig::Twilio {
+			account_sid,
+			auth_token,
+			from,
+			to,
+			max_messages_per_hour,
+			message,
+		} = config
+		{
+			Self::new(
+				None,
+				account_sid.clone(),
+				auth_token.as_ref().to_string(),
+				from.clone(),
+				to.clone(),
+				*max_messages_per_hour,
+				message.title.clone(),
+				message.body.clone(),
+			)
+		} else {
+			let msg = format!("Invalid twilio configuration: {:?}", config);
+			Err(NotificationError::config_error(msg, None, None))
+		}
+	}
+
+	/// Sends a message to a single recipient
+	async fn send_sms(&self, to: &str, message: &str) -> Result<(), NotificationError> {
+		let params = [("To", to), ("From", self.from.as_str()), ("Body", message)];
+
+		let response = self
+			.inner
+			.client
+			.post(self.inner.url.as_str())
+			.basic_auth(&self.account_sid, Some(&self.auth_token))
+			.form(&params)
+			.send()
+			.await
+			.map_err(|e| {
+				NotificationError::notify_failed(
+					format!("Failed to send Twilio request: {}", e),
+					Some(e.into()),
+					None,
+				)
+			})?;
+
+		let status = response.status();
+		if !status.is_success() {
+			let body = response.text().await.unwrap_or_default();
+			return Err(NotificationError::notify_failed(
+				format!(
+					"Twilio request failed with status: {}, body: {}",
+					status, body
+				),
+				None,
+				None,
+			));
+		}
+
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Notifier for TwilioNotifier {
+	/// Sends a formatted message to every recipient
+	///
+	/// The message is sent to all recipients even if sending it to some of them fails.
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	async fn notify(&self, message: &str) -> Result<(), NotificationError> {
+		let mut failures = Vec::new();
+		for to in &self.to {
+			if let Err(e) = self.send_sms(to, message).await {
+				failures.push(format!("{}: {}", to, e));
+			}
+		}
+
+		if !failures.is_empty() {
+			return Err(NotificationError::notify_failed(
+				format!(
+					"Failed to send SMS to {} of {} recipients: {}",
+					failures.len(),
+					self.to.len(),
+					failures.join("; ")
+				),
+				None,
+				None,
+			));
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::models::{NotificationMessage, SecretString, SecretValue};
+
+	use super::*;
+
+	const TEST_ACCOUNT_SID: &str = "AC0123456789abcdef0123456789abcdef";
+
+	fn create_test_notifier(body_template: &str) -> TwilioNotifier {
+		TwilioNotifier::new(
+			None,
+			TEST_ACCOUNT_SID.to_string(),
+			"test-token".to_string(),
+			"+14155550100".to_string(),
+			vec!["+14155550101".to_string()],
+			None,
+			"Alert".to_string(),
+			body_template.to_string(),
+		)
+		.unwrap()
+	}
+
+	fn create_test_twilio_config() -> TriggerTypeConfig {
+		TriggerTypeConfig::Twilio {
+			account_sid: TEST_ACCOUNT_SID.to_string(),
+			auth_token: SecretValue::Plain(SecretString::new("test-token".to_string())),
+			from: "+14155550100".to_string(),
+			to: vec!["+14155550101".to_string()],
+			max_messages_per_hour: Some(1),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message ${value}".to_string(),
+			},
+		}
+	}
+
+	////////////////////////////////////////////////////////////
+	// new tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_new_builds_messages_url() {
+		let notifier = create_test_notifier("Test message");
+
+		assert_eq!(
+			notifier.inner.url,
+			"https://api.twilio.com/2010-04-01/Accounts/AC0123456789abcdef0123456789abcdef/Messages.json"
+		);
+		assert_eq!(notifier.rate_limit(), (5, Duration::from_secs(3600)));
+	}
+
+	////////////////////////////////////////////////////////////
+	// format_message tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_format_message() {
+		let notifier = create_test_notifier("Value is ${value} and status is ${status}");
+
+		let mut variables = HashMap::new();
+		variables.insert("value".to_string(), "100".to_string());
+		variables.insert("status".to_string(), "critical".to_string());
+
+		let result = notifier.format_message(&variables);
+		assert_eq!(result, "Alert\nValue is 100 and status is critical");
+	}
+
+	#[test]
+	fn test_format_message_truncates_long_message() {
+		let notifier = create_test_notifier(&"a".repeat(2000));
+
+		let result = notifier.format_message(&HashMap::new());
+		assert_eq!(result.chars().count(), TWILIO_MAX_BODY_LENGTH);
+		assert!(result.ends_with('…'));
+	}
+
+	////////////////////////////////////////////////////////////
+	// from_config tests
+	////////////////////////////////////////////////////////////
+
+	#[test]
+	fn test_from_config_with_twilio_config() {
+		let notifier = TwilioNotifier::from_config(&create_test_twilio_config()).unwrap();
+
+		assert_eq!(notifier.account_sid, TEST_ACCOUNT_SID);
+		assert_eq!(notifier.auth_token, "test-token");
+		assert_eq!(notifier.from, "+14155550100");
+		assert_eq!(notifier.to, vec!["+14155550101"]);
+		assert_eq!(notifier.inner.title, "Test Alert");
+		assert_eq!(notifier.inner.body_template, "Test message ${value}");
+		assert_eq!(notifier.rate_limit(), (1, Duration::from_secs(3600)));
+	}
+
+	#[test]
+	fn test_from_config_invalid_type() {
+		let config = TriggerTypeConfig::Slack {
+			slack_url: SecretValue::Plain(SecretString::new(
+				"https://slack.example.com".to_string(),
+			)),
+			message: NotificationMessage {
+				title: "Test Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+
+		let result = TwilioNotifier::from_config(&config);
+		assert!(matches!(result, Err(NotificationError::ConfigError(_))));
+	}
+
+	////////////////////////////////////////////////////////////
+	// notify tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_failure() {
+		let notifier = TwilioNotifier::new(
+			Some("https://non-existent-url-twilio.com".to_string()),
+			TEST_ACCOUNT_SID.to_string(),
+			"test-token".to_string(),
+			"+14155550100".to_string(),
+			vec!["+14155550101".to_string()],
+			None,
+			"Alert".to_string(),
+			"Test message".to_string(),
+		)
+		.unwrap();
+
+		let result = notifier.notify("Test message").await;
+		assert!(result.is_err());
+	}
+}
@@ -10,13 +10,21 @@ use reqwest::{
 	header::{HeaderMap, HeaderName, HeaderValue},
 	Client, Method,
 };
+use reqwest_middleware::ClientWithMiddleware;
 use serde::Serialize;
 use sha2::Sha256;
 use std::collections::HashMap;
 
 use crate::{
-	models::TriggerTypeConfig,
-	services::notification::{NotificationError, Notifier},
+	models::{MonitorMatch, TriggerTypeConfig},
+	services::{
+		blockchain::TransientErrorRetryStrategy,
+		notification::{
+			payload::{match_payload, MATCH_PAYLOAD_SCHEMA_VERSION},
+			NotificationError, Notifier,
+		},
+	},
+	utils::http::{create_retryable_http_client, HttpRetryConfig},
 };
 
 /// HMAC SHA256 type alias
@@ -54,7 +62,7 @@ pub struct WebhookNotifier {
 	/// Message template with variable placeholders
 	pub body_template: String,
 	/// HTTP client for webhook requests
-	pub client: Client,
+	pub client: ClientWithMiddleware,
 	/// HTTP method to use for the webhook request
 	pub method: Option<String>,
 	/// Secret to use for the webhook request
@@ -63,14 +71,10 @@ pub struct WebhookNotifier {
 	pub headers: Option<HashMap<String, String>>,
 	/// Payload fields to use for the webhook request
 	pub payload_fields: Option<HashMap<String, serde_json::Value>>,
-}
-
-/// Represents a formatted webhook message
-#[derive(Serialize, Debug)]
-pub struct WebhookMessage {
-	/// The content of the message
-	title: String,
-	body: String,
+	/// Whether the monitor match is sent along with the message
+	pub include_match: bool,
+	/// Schema version of the monitor match sent along with the message
+	pub match_schema_version: u32,
 }
 
 impl WebhookNotifier {
@@ -91,14 +95,27 @@ impl WebhookNotifier {
 			url_params: config.url_params,
 			title: config.title,
 			body_template: config.body_template,
-			client: Client::new(),
+			client: create_retryable_client(0),
 			method: Some(config.method.unwrap_or("POST".to_string())),
 			secret: config.secret,
 			headers: Some(headers),
 			payload_fields: config.payload_fields,
+			include_match: false,
+			match_schema_version: MATCH_PAYLOAD_SCHEMA_VERSION,
 		})
 	}
 
+	/// Retries requests failing with transient errors, with exponential backoff
+	///
+	/// Requests are retried on connection errors, timeouts, rate limiting and server errors.
+	///
+	/// # Arguments
+	/// * `max_retries` - Maximum number of retries of a request
+	pub fn with_retries(mut self, max_retries: u32) -> Self {
+		self.client = create_retryable_client(max_retries);
+		self
+	}
+
 	/// Formats a message by substituting variables in the template
 	///
 	/// # Arguments
@@ -128,6 +145,9 @@ impl WebhookNotifier {
 			method,
 			secret,
 			headers,
+			include_match,
+			match_schema_version,
+			max_retries,
 		} = config
 		{
 			let webhook_config = WebhookConfig {
@@ -141,17 +161,33 @@ impl WebhookNotifier {
 				payload_fields: None,
 			};
 
-			WebhookNotifier::new(webhook_config)
+			let mut notifier = WebhookNotifier::new(webhook_config)?;
+			notifier.include_match = include_match.unwrap_or(false);
+			notifier.match_schema_version =
+				match_schema_version.unwrap_or(MATCH_PAYLOAD_SCHEMA_VERSION);
+			Ok(notifier.with_retries(max_retries.unwrap_or(0)))
 		} else {
 			let msg = format!("Invalid webhook configuration: {:?}", config);
 			Err(NotificationError::config_error(msg, None, None))
 		}
 	}
 
+	/// Signs the body of a request with HMAC-SHA256
+	///
+	/// The signature covers the body followed by the timestamp of the request, so that
+	/// receivers can authenticate the payload and reject replayed requests.
+	///
+	/// # Arguments
+	/// * `secret` - Secret shared with the receiver
+	/// * `body` - Serialized body of the request
+	///
+	/// # Returns
+	/// * `Result<(String, String), NotificationError>` - Hex-encoded signature and timestamp
+	///   in milliseconds
 	pub fn sign_request(
 		&self,
 		secret: &str,
-		payload: &WebhookMessage,
+		body: &str,
 	) -> Result<(String, String), NotificationError> {
 		// Explicitly reject empty secret, because `HmacSha256::new_from_slice` currently allows empty secrets
 		if secret.is_empty() {
@@ -170,7 +206,7 @@ impl WebhookNotifier {
 		})?; // Handle error if secret is invalid
 
 		// Create the message to sign
-		let message = format!("{:?}{}", payload, timestamp);
+		let message = format!("{}{}", body, timestamp);
 		mac.update(message.as_bytes());
 
 		// Get the HMAC result
@@ -178,6 +214,53 @@ impl WebhookNotifier {
 
 		Ok((signature, timestamp.to_string()))
 	}
+
+	/// Sends a formatted message to the webhook for a monitor match
+	///
+	/// The monitor match is sent in the `monitor_match` field of the payload if the trigger
+	/// includes it, in the schema version of the trigger, and the identifier of its incident in
+	/// the `match_id` field.
+	///
+	/// # Arguments
+	/// * `message` - The formatted message to send
+	/// * `monitor_match` - The monitor match the message is sent for
+	/// * `match_id` - Identifier of the incident of the match, if any
+	///
+	/// # Returns
+	/// * `Result<(), NotificationError>` - Success or error
+	pub async fn notify_match(
+		&self,
+		message: &str,
+		monitor_match: &MonitorMatch,
+		match_id: Option<&str>,
+	) -> Result<(), NotificationError> {
+		let mut payload_fields = HashMap::new();
+		payload_fields.insert("title".to_string(), serde_json::json!(self.title));
+		payload_fields.insert("body".to_string(), serde_json::json!(message));
+		if let Some(match_id) = match_id {
+			payload_fields.insert("match_id".to_string(), serde_json::json!(match_id));
+		}
+		if self.include_match {
+			payload_fields.insert(
+				"monitor_match".to_string(),
+				match_payload(monitor_match, self.match_schema_version)?,
+			);
+		}
+
+		self.notify_with_payload(message, payload_fields).await
+	}
+}
+
+/// Creates the HTTP client of a webhook, retrying failed requests up to `max_retries` times
+fn create_retryable_client(max_retries: u32) -> ClientWithMiddleware {
+	create_retryable_http_client(
+		&HttpRetryConfig {
+			max_retries,
+			..HttpRetryConfig::default()
+		},
+		Client::new(),
+		Some(TransientErrorRetryStrategy),
+	)
 }
 
 #[async_trait]
@@ -208,7 +291,7 @@ impl Notifier for WebhookNotifier {
 	/// * `Result<(), NotificationError>` - Success or error
 	async fn notify_with_payload(
 		&self,
-		message: &str,
+		_message: &str,
 		mut payload_fields: HashMap<String, serde_json::Value>,
 	) -> Result<(), NotificationError> {
 		let mut url = self.url.clone();
@@ -238,6 +321,18 @@ impl Notifier for WebhookNotifier {
 			Method::POST
 		};
 
+		// Serialize the payload once, so that the signature covers the exact body sent
+		let payload = WebhookPayload {
+			fields: payload_fields,
+		};
+		let body = serde_json::to_string(&payload).map_err(|e| {
+			NotificationError::internal_error(
+				format!("Failed to serialize webhook payload: {}", e),
+				Some(e.into()),
+				None,
+			)
+		})?;
+
 		// Add default headers
 		let mut headers = HeaderMap::new();
 		headers.insert(
@@ -246,17 +341,9 @@ impl Notifier for WebhookNotifier {
 		);
 
 		if let Some(secret) = &self.secret {
-			// Create a WebhookMessage for signing
-			let payload_for_signing = WebhookMessage {
-				title: self.title.clone(),
-				body: message.to_string(),
-			};
-
-			let (signature, timestamp) =
-				self.sign_request(secret, &payload_for_signing)
-					.map_err(|e| {
-						NotificationError::internal_error(e.to_string(), Some(e.into()), None)
-					})?;
+			let (signature, timestamp) = self.sign_request(secret, &body).map_err(|e| {
+				NotificationError::internal_error(e.to_string(), Some(e.into()), None)
+			})?;
 
 			// Add signature headers
 			headers.insert(
@@ -302,16 +389,12 @@ impl Notifier for WebhookNotifier {
 			}
 		}
 
-		let payload = WebhookPayload {
-			fields: payload_fields,
-		};
-
 		// Send request with custom payload
 		let response = self
 			.client
 			.request(method, url.as_str())
 			.headers(headers)
-			.json(&payload)
+			.body(body)
 			.send()
 			.await
 			.map_err(|e| {
@@ -338,11 +421,17 @@ impl Notifier for WebhookNotifier {
 
 #[cfg(test)]
 mod tests {
-	use crate::models::{NotificationMessage, SecretString, SecretValue};
+	use crate::{
+		models::{
+			MatchConditions, NotificationMessage, SecretString, SecretValue, SolanaMonitorMatch,
+		},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
 
 	use super::*;
 	use mockito::{Matcher, Mock};
 	use serde_json::json;
+	use solana_sdk::signature::Signature;
 
 	fn create_test_notifier(
 		url: &str,
@@ -363,6 +452,18 @@ mod tests {
 		.unwrap()
 	}
 
+	fn create_test_solana_match() -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("Test Monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new()
+				.signature(Signature::from([1; 64]))
+				.build(),
+		)))
+	}
+
 	fn create_test_webhook_config() -> TriggerTypeConfig {
 		TriggerTypeConfig::Webhook {
 			url: SecretValue::Plain(SecretString::new("https://webhook.example.com".to_string())),
@@ -373,6 +474,9 @@ mod tests {
 				title: "Test Alert".to_string(),
 				body: "Test message ${value}".to_string(),
 			},
+			include_match: None,
+			match_schema_version: None,
+			max_retries: None,
 		}
 	}
 
@@ -435,13 +539,10 @@ mod tests {
 			Some("test-secret"),
 			None,
 		);
-		let payload = WebhookMessage {
-			title: "Test Title".to_string(),
-			body: "Test message".to_string(),
-		};
+		let payload = r#"{"title":"Test Title","body":"Test message"}"#;
 		let secret = "test-secret";
 
-		let result = notifier.sign_request(secret, &payload).unwrap();
+		let result = notifier.sign_request(secret, payload).unwrap();
 		let (signature, timestamp) = result;
 
 		assert!(!signature.is_empty());
@@ -452,13 +553,10 @@ mod tests {
 	fn test_sign_request_fails_empty_secret() {
 		let notifier =
 			create_test_notifier("https://webhook.example.com", "Test message", None, None);
-		let payload = WebhookMessage {
-			title: "Test Title".to_string(),
-			body: "Test message".to_string(),
-		};
+		let payload = r#"{"title":"Test Title","body":"Test message"}"#;
 		let empty_secret = "";
 
-		let result = notifier.sign_request(empty_secret, &payload);
+		let result = notifier.sign_request(empty_secret, payload);
 		assert!(result.is_err());
 
 		let error = result.unwrap_err();
@@ -482,6 +580,24 @@ mod tests {
 		assert_eq!(notifier.body_template, "Test message ${value}");
 	}
 
+	#[test]
+	fn test_from_config_with_match_and_retries() {
+		let mut config = create_test_webhook_config();
+		if let TriggerTypeConfig::Webhook {
+			include_match,
+			max_retries,
+			..
+		} = &mut config
+		{
+			*include_match = Some(true);
+			*max_retries = Some(3);
+		}
+
+		let notifier = WebhookNotifier::from_config(&config).unwrap();
+		assert!(notifier.include_match);
+		assert!(!create_test_notifier("https://webhook.example.com", "", None, None).include_match);
+	}
+
 	#[test]
 	fn test_from_config_invalid_type() {
 		// Create a config that is not a Telegram type
@@ -644,12 +760,9 @@ mod tests {
 			None,
 		);
 
-		let payload = WebhookMessage {
-			title: "Test Title".to_string(),
-			body: "Test message".to_string(),
-		};
+		let payload = r#"{"title":"Test Title","body":"Test message"}"#;
 
-		let result = notifier.sign_request("test-secret", &payload).unwrap();
+		let result = notifier.sign_request("test-secret", payload).unwrap();
 		let (signature, timestamp) = result;
 
 		// Validate signature format (should be a hex string)
@@ -889,4 +1002,160 @@ mod tests {
 		assert!(result.is_err());
 		mock.assert();
 	}
+
+	////////////////////////////////////////////////////////////
+	// notify_match tests
+	////////////////////////////////////////////////////////////
+
+	#[tokio::test]
+	async fn test_notify_match_without_monitor_match() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.match_body(Matcher::Json(json!({
+				"title": "Alert",
+				"body": "Test message",
+			})))
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let notifier = create_test_notifier(server.url().as_str(), "Test message", None, None);
+
+		let result = notifier
+			.notify_match("Test message", &create_test_solana_match(), None)
+			.await;
+		assert!(result.is_ok());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_notify_match_includes_monitor_match() {
+		let monitor_match = create_test_solana_match();
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.match_body(Matcher::Json(json!({
+				"title": "Alert",
+				"body": "Test message",
+				"monitor_match": match_payload(&monitor_match, 1).unwrap(),
+			})))
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let mut notifier = create_test_notifier(server.url().as_str(), "Test message", None, None);
+		notifier.include_match = true;
+
+		let result = notifier
+			.notify_match("Test message", &monitor_match, None)
+			.await;
+		assert!(result.is_ok());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_notify_match_includes_match_id() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.match_body(Matcher::Json(json!({
+				"title": "Alert",
+				"body": "Test message",
+				"match_id": "incident",
+			})))
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let notifier = create_test_notifier(server.url().as_str(), "Test message", None, None);
+
+		let result = notifier
+			.notify_match(
+				"Test message",
+				&create_test_solana_match(),
+				Some("incident"),
+			)
+			.await;
+		assert!(result.is_ok());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_notify_signature_covers_body() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.match_request(|request| {
+				let header = |name: &str| {
+					request
+						.header(name)
+						.first()
+						.and_then(|value| value.to_str().ok())
+						.map(str::to_string)
+				};
+				let (Some(signature), Some(timestamp)) =
+					(header("x-signature"), header("x-timestamp"))
+				else {
+					return false;
+				};
+				let body = request.utf8_lossy_body().unwrap();
+
+				let mut mac = HmacSha256::new_from_slice(b"top-secret").unwrap();
+				mac.update(format!("{}{}", body, timestamp).as_bytes());
+				hex::encode(mac.finalize().into_bytes()) == signature
+			})
+			.with_status(200)
+			.create_async()
+			.await;
+
+		let notifier = create_test_notifier(
+			server.url().as_str(),
+			"Test message",
+			Some("top-secret"),
+			None,
+		);
+
+		let result = notifier
+			.notify_match("Test message", &create_test_solana_match(), None)
+			.await;
+		assert!(result.is_ok());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_notify_retries_server_errors() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.with_status(503)
+			.expect(3)
+			.create_async()
+			.await;
+
+		let notifier =
+			create_test_notifier(server.url().as_str(), "Test message", None, None).with_retries(2);
+
+		let result = notifier.notify("Test message").await;
+		assert!(result.is_err());
+		mock.assert();
+	}
+
+	#[tokio::test]
+	async fn test_notify_does_not_retry_client_errors() {
+		let mut server = mockito::Server::new_async().await;
+		let mock = server
+			.mock("POST", "/")
+			.with_status(400)
+			.expect(1)
+			.create_async()
+			.await;
+
+		let notifier =
+			create_test_notifier(server.url().as_str(), "Test message", None, None).with_retries(2);
+
+		let result = notifier.notify("Test message").await;
+		assert!(result.is_err());
+		mock.assert();
+	}
 }
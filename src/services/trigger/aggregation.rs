@@ -0,0 +1,297 @@
+//! Match aggregation.
+//!
+//! Triggers with an aggregation window do not send a notification per match. The first match
+//! of a monitor opens a window, and the matches of the monitor within the window are sent
+//! together once it ends, in a single notification with their count and a table of their
+//! transactions.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use crate::{
+	models::{MonitorMatch, Trigger},
+	services::notification::{MatchSummary, NotificationError, NotificationService},
+};
+
+/// Matches of a monitor waiting to be sent in an aggregated notification
+#[derive(Debug)]
+struct PendingAggregate {
+	/// Slug of the trigger the notification is sent with
+	trigger_slug: String,
+	/// Trigger the notification is sent with
+	trigger: Trigger,
+	/// Variables of the first match, used to format the notification title
+	variables: HashMap<String, String>,
+	/// First match of the window
+	monitor_match: MonitorMatch,
+	/// Summaries of the matches, in the order they were aggregated
+	matches: Vec<MatchSummary>,
+}
+
+/// Matches waiting for their aggregation window to end, by trigger and monitor name
+#[derive(Clone)]
+pub struct MatchAggregator {
+	pending: Arc<Mutex<HashMap<(String, String), PendingAggregate>>>,
+	notification_service: NotificationService,
+}
+
+impl MatchAggregator {
+	/// Creates an aggregator with no pending match
+	///
+	/// # Arguments
+	/// * `notification_service` - Service sending the aggregated notifications
+	pub fn new(notification_service: NotificationService) -> Self {
+		Self {
+			pending: Arc::new(Mutex::new(HashMap::new())),
+			notification_service,
+		}
+	}
+
+	/// Adds a match to the aggregated notification of a trigger for its monitor
+	///
+	/// The first match of a monitor schedules the notification to be sent after the window.
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Slug of the trigger notifying the match
+	/// * `trigger` - Trigger notifying the match
+	/// * `variables` - Variables of the match
+	/// * `monitor_match` - Match to aggregate
+	/// * `window` - Window over which the matches of the monitor are aggregated
+	pub fn push(
+		&self,
+		trigger_slug: &str,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		window: Duration,
+	) {
+		let summary = MatchSummary::new(monitor_match);
+		let key = (trigger.name.clone(), summary.monitor_name.clone());
+
+		let mut pending = self.pending.lock().unwrap();
+		if let Some(aggregate) = pending.get_mut(&key) {
+			aggregate.matches.push(summary);
+			return;
+		}
+
+		pending.insert(
+			key.clone(),
+			PendingAggregate {
+				trigger_slug: trigger_slug.to_string(),
+				trigger: trigger.clone(),
+				variables: variables.clone(),
+				monitor_match: monitor_match.clone(),
+				matches: vec![summary],
+			},
+		);
+
+		let aggregator = self.clone();
+		tokio::spawn(async move {
+			tokio::time::sleep(window).await;
+			if let Err(e) = aggregator.send(&key).await {
+				tracing::error!(
+					"Failed to send aggregated notification of {} for {}: {}",
+					key.0,
+					key.1,
+					e
+				);
+			}
+		});
+	}
+
+	/// Returns the number of matches waiting in the notification of a trigger for a monitor
+	pub fn pending_matches(&self, trigger_name: &str, monitor_name: &str) -> usize {
+		self.pending
+			.lock()
+			.unwrap()
+			.get(&(trigger_name.to_string(), monitor_name.to_string()))
+			.map_or(0, |aggregate| aggregate.matches.len())
+	}
+
+	/// Sends all pending notifications without waiting for their window, e.g. on shutdown
+	pub async fn flush(&self) {
+		let keys: Vec<(String, String)> = self.pending.lock().unwrap().keys().cloned().collect();
+		for key in keys {
+			if let Err(e) = self.send(&key).await {
+				tracing::error!(
+					"Failed to send aggregated notification of {} for {}: {}",
+					key.0,
+					key.1,
+					e
+				);
+			}
+		}
+	}
+
+	/// Sends the aggregated notification of a trigger for a monitor, if it was not sent already
+	///
+	/// A window with a single match is notified with the message of the trigger, while the
+	/// message body of a window with several matches is replaced by their count and table.
+	async fn send(&self, key: &(String, String)) -> Result<(), NotificationError> {
+		let Some(mut aggregate) = self.pending.lock().unwrap().remove(key) else {
+			return Ok(());
+		};
+
+		if aggregate.matches.len() > 1 {
+			let window_secs = aggregate
+				.trigger
+				.aggregation
+				.map_or(0, |aggregation| aggregation.window_secs);
+			let body = aggregate_body(&key.1, window_secs, &aggregate.matches);
+			if let Some(message) = aggregate.trigger.config.message_mut() {
+				message.body = body;
+			}
+		}
+
+		self.notification_service
+			.execute(
+				&aggregate.trigger_slug,
+				&aggregate.trigger,
+				&aggregate.variables,
+				&aggregate.monitor_match,
+				&HashMap::new(),
+			)
+			.await
+	}
+}
+
+/// Formats the count and the table of the transactions of aggregated matches
+///
+/// # Arguments
+/// * `monitor_name` - Name of the monitor of the matches
+/// * `window_secs` - Duration of the aggregation window in seconds
+/// * `matches` - Summaries of the matches
+///
+/// # Returns
+/// * `String` - Body of the aggregated notification
+pub fn aggregate_body(monitor_name: &str, window_secs: u64, matches: &[MatchSummary]) -> String {
+	let transaction_label = matches
+		.first()
+		.map_or("Transaction", |summary| summary.transaction_label);
+	let mut body = format!(
+		"{} matches of monitor {} over the last {} seconds\n\n| Network | {} | Explorer |\n| --- \
+		 | --- | --- |",
+		matches.len(),
+		monitor_name,
+		window_secs,
+		transaction_label
+	);
+	for summary in matches {
+		body.push_str(&format!(
+			"\n| {} | {} | {} |",
+			summary.network_slug,
+			summary.transaction,
+			summary.explorer_url().unwrap_or_else(|| "-".to_string())
+		));
+	}
+	body
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{MatchConditions, SolanaMonitorMatch, TriggerType},
+		utils::tests::{
+			builders::trigger::TriggerBuilder,
+			solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		},
+	};
+	use solana_sdk::signature::Signature;
+
+	fn create_solana_match(monitor_name: &str, signature: Signature) -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name(monitor_name).build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().signature(signature).build(),
+		)))
+	}
+
+	fn create_trigger() -> Trigger {
+		TriggerBuilder::new()
+			.name("aggregated")
+			.slack("https://hooks.slack.com/services/xxx")
+			.aggregation(3600)
+			.build()
+	}
+
+	#[tokio::test]
+	async fn test_push_aggregates_by_trigger_and_monitor() {
+		let aggregator = MatchAggregator::new(NotificationService::new());
+		let trigger = create_trigger();
+		let window = Duration::from_secs(3600);
+
+		for byte in 0..3 {
+			aggregator.push(
+				"aggregated",
+				&trigger,
+				&HashMap::new(),
+				&create_solana_match("monitor_a", Signature::from([byte; 64])),
+				window,
+			);
+		}
+		aggregator.push(
+			"aggregated",
+			&trigger,
+			&HashMap::new(),
+			&create_solana_match("monitor_b", Signature::from([9; 64])),
+			window,
+		);
+
+		assert_eq!(aggregator.pending_matches("aggregated", "monitor_a"), 3);
+		assert_eq!(aggregator.pending_matches("aggregated", "monitor_b"), 1);
+		assert_eq!(aggregator.pending_matches("other", "monitor_a"), 0);
+	}
+
+	#[tokio::test]
+	async fn test_flush_clears_pending_matches() {
+		let aggregator = MatchAggregator::new(NotificationService::new());
+		// The configuration does not match the trigger type, so sending fails without a request
+		let trigger = TriggerBuilder::new()
+			.name("aggregated")
+			.slack("https://hooks.slack.com/services/xxx")
+			.trigger_type(TriggerType::Email)
+			.build();
+
+		aggregator.push(
+			"aggregated",
+			&trigger,
+			&HashMap::new(),
+			&create_solana_match("monitor", Signature::from([1; 64])),
+			Duration::from_secs(3600),
+		);
+		aggregator.flush().await;
+
+		assert_eq!(aggregator.pending_matches("aggregated", "monitor"), 0);
+	}
+
+	#[test]
+	fn test_aggregate_body() {
+		let signatures = [Signature::from([1; 64]), Signature::from([2; 64])];
+		let matches: Vec<MatchSummary> = signatures
+			.iter()
+			.map(|signature| MatchSummary::new(&create_solana_match("monitor", *signature)))
+			.collect();
+
+		let body = aggregate_body("monitor", 60, &matches);
+		let lines: Vec<&str> = body.lines().collect();
+		assert_eq!(
+			lines[0],
+			"2 matches of monitor monitor over the last 60 seconds"
+		);
+		assert_eq!(lines[2], "| Network | Signature | Explorer |");
+		assert_eq!(
+			lines[4],
+			format!(
+				"| solana_mainnet | {} | https://explorer.solana.com/tx/{} |",
+				signatures[0], signatures[0]
+			)
+		);
+		assert_eq!(lines.len(), 6);
+	}
+}
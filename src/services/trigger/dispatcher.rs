@@ -0,0 +1,209 @@
+//! Trigger dispatching.
+//!
+//! The triggers of the matches of a block are executed concurrently, up to a maximum number of
+//! matches at a time across all blocks. Matches waiting for their execution are held in a
+//! bounded queue: once it is full, queueing the next match waits for a slot, so that a burst
+//! of matches slows block processing down instead of spawning an unbounded number of tasks.
+//! The depth of the queue and the time matches wait in it show when notification delivery
+//! lags block processing.
+
+use std::{future::Future, sync::Arc};
+
+use prometheus::Gauge;
+use tokio::{
+	sync::{OwnedSemaphorePermit, Semaphore},
+	time::Instant,
+};
+
+use crate::utils::metrics::{
+	TRIGGER_EXECUTIONS_IN_FLIGHT, TRIGGER_QUEUE_DEPTH, TRIGGER_QUEUE_FULL,
+	TRIGGER_QUEUE_WAIT_SECONDS,
+};
+
+/// Maximum number of matches whose triggers are executed at a time, unless configured otherwise
+pub const DEFAULT_TRIGGER_MAX_CONCURRENCY: usize = 16;
+
+/// Maximum number of matches queued or executing, unless configured otherwise
+pub const DEFAULT_TRIGGER_QUEUE_CAPACITY: usize = 1024;
+
+/// Bounded queue of the matches whose triggers are to be executed
+#[derive(Clone)]
+pub struct TriggerDispatcher {
+	/// Slots of the matches queued or executing
+	slots: Arc<Semaphore>,
+	/// Permits of the matches executing
+	executions: Arc<Semaphore>,
+}
+
+impl Default for TriggerDispatcher {
+	fn default() -> Self {
+		Self::new(
+			DEFAULT_TRIGGER_MAX_CONCURRENCY,
+			DEFAULT_TRIGGER_QUEUE_CAPACITY,
+		)
+	}
+}
+
+impl TriggerDispatcher {
+	/// Creates a dispatcher with an empty queue
+	///
+	/// # Arguments
+	/// * `max_concurrency` - Maximum number of matches executed at a time, at least 1
+	/// * `queue_capacity` - Maximum number of matches queued or executing, at least
+	///   `max_concurrency`
+	pub fn new(max_concurrency: usize, queue_capacity: usize) -> Self {
+		let max_concurrency = max_concurrency.max(1);
+		Self {
+			slots: Arc::new(Semaphore::new(queue_capacity.max(max_concurrency))),
+			executions: Arc::new(Semaphore::new(max_concurrency)),
+		}
+	}
+
+	/// Queues a match, waiting for a slot while the queue is full
+	///
+	/// # Returns
+	/// * `QueuedExecution` - Slot of the match in the queue, released once its triggers are
+	///   executed
+	pub async fn enqueue(&self) -> QueuedExecution {
+		let slot = match self.slots.clone().try_acquire_owned() {
+			Ok(slot) => slot,
+			Err(_) => {
+				TRIGGER_QUEUE_FULL.inc();
+				tracing::debug!("Trigger queue is full, waiting for a slot");
+				self.slots
+					.clone()
+					.acquire_owned()
+					.await
+					.expect("trigger queue is never closed")
+			}
+		};
+
+		QueuedExecution {
+			_slot: slot,
+			executions: self.executions.clone(),
+			queued_at: Instant::now(),
+			queued: GaugeGuard::inc(&TRIGGER_QUEUE_DEPTH),
+		}
+	}
+
+	/// Returns the number of free slots in the queue
+	pub fn available_slots(&self) -> usize {
+		self.slots.available_permits()
+	}
+}
+
+/// Match queued for the execution of its triggers
+pub struct QueuedExecution {
+	/// Slot of the match in the queue
+	_slot: OwnedSemaphorePermit,
+	/// Permits of the matches executing
+	executions: Arc<Semaphore>,
+	/// Time the match was queued at
+	queued_at: Instant,
+	/// Count of the match in the queue depth
+	queued: GaugeGuard,
+}
+
+impl QueuedExecution {
+	/// Executes the triggers of the match once fewer matches than the maximum concurrency are
+	/// executing, then releases its slot in the queue
+	///
+	/// # Arguments
+	/// * `execution` - Execution of the triggers of the match
+	pub async fn run<F: Future<Output = ()>>(self, execution: F) {
+		let _permit = self
+			.executions
+			.clone()
+			.acquire_owned()
+			.await
+			.expect("trigger executions are never closed");
+		TRIGGER_QUEUE_WAIT_SECONDS.observe(self.queued_at.elapsed().as_secs_f64());
+		drop(self.queued);
+
+		let _in_flight = GaugeGuard::inc(&TRIGGER_EXECUTIONS_IN_FLIGHT);
+		execution.await;
+	}
+}
+
+/// Gauge incremented while the guard lives, so that cancelled executions are not counted
+struct GaugeGuard(&'static Gauge);
+
+impl GaugeGuard {
+	fn inc(gauge: &'static Gauge) -> Self {
+		gauge.inc();
+		Self(gauge)
+	}
+}
+
+impl Drop for GaugeGuard {
+	fn drop(&mut self) {
+		self.0.dec();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{
+		sync::atomic::{AtomicUsize, Ordering},
+		time::Duration,
+	};
+
+	#[test]
+	fn test_queue_capacity_covers_concurrency() {
+		assert_eq!(TriggerDispatcher::new(8, 2).available_slots(), 8);
+		assert_eq!(TriggerDispatcher::new(0, 0).available_slots(), 1);
+		assert_eq!(
+			TriggerDispatcher::default().available_slots(),
+			DEFAULT_TRIGGER_QUEUE_CAPACITY
+		);
+	}
+
+	#[tokio::test]
+	async fn test_executions_are_bounded_by_concurrency() {
+		let dispatcher = TriggerDispatcher::new(2, 10);
+		let executing = Arc::new(AtomicUsize::new(0));
+		let max_executing = Arc::new(AtomicUsize::new(0));
+
+		let mut handles = Vec::new();
+		for _ in 0..10 {
+			let queued = dispatcher.enqueue().await;
+			let executing = executing.clone();
+			let max_executing = max_executing.clone();
+			handles.push(tokio::spawn(queued.run(async move {
+				let count = executing.fetch_add(1, Ordering::SeqCst) + 1;
+				max_executing.fetch_max(count, Ordering::SeqCst);
+				tokio::time::sleep(Duration::from_millis(10)).await;
+				executing.fetch_sub(1, Ordering::SeqCst);
+			})));
+		}
+		for handle in handles {
+			handle.await.unwrap();
+		}
+
+		assert_eq!(max_executing.load(Ordering::SeqCst), 2);
+		assert_eq!(dispatcher.available_slots(), 10);
+	}
+
+	#[tokio::test]
+	async fn test_enqueue_waits_while_queue_is_full() {
+		let dispatcher = TriggerDispatcher::new(1, 1);
+		let full_before = TRIGGER_QUEUE_FULL.get();
+
+		let queued = dispatcher.enqueue().await;
+		assert_eq!(dispatcher.available_slots(), 0);
+		assert!(
+			tokio::time::timeout(Duration::from_millis(50), dispatcher.enqueue())
+				.await
+				.is_err()
+		);
+		assert!(TRIGGER_QUEUE_FULL.get() > full_before);
+
+		// Executing the queued match frees its slot
+		queued.run(async {}).await;
+		assert_eq!(dispatcher.available_slots(), 1);
+		let _queued = tokio::time::timeout(Duration::from_millis(50), dispatcher.enqueue())
+			.await
+			.expect("slot should be free");
+	}
+}
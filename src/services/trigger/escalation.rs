@@ -0,0 +1,363 @@
+//! Match escalation.
+//!
+//! Monitors with an escalation fire the triggers of its steps in order while their matches
+//! are not acknowledged, e.g. Slack immediately and PagerDuty if nobody acknowledged the
+//! match within 10 minutes. Escalations are keyed on the incident of the match, so that the
+//! matches of an incident are escalated once. Each escalation gets a callback URL holding a
+//! random token, available to the messages of the steps as `${escalation.ack_url}`, which
+//! stops the escalation when it is sent a `POST` request.
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex, RwLock},
+	time::Duration,
+};
+
+use uuid::Uuid;
+
+use crate::{
+	models::{MonitorMatch, Trigger},
+	services::notification::NotificationService,
+};
+
+/// Address the acknowledgement callbacks are served on, unless configured otherwise
+pub const DEFAULT_CALLBACK_ADDRESS: &str = "127.0.0.1:8082";
+
+/// Escalation of the matches of an incident
+#[derive(Debug)]
+struct Escalation {
+	/// Token of the acknowledgement URL
+	token: String,
+	/// Whether the escalation was acknowledged, so that it is not started again
+	acknowledged: bool,
+}
+
+/// Escalations of the matches waiting for an acknowledgement
+#[derive(Clone)]
+pub struct EscalationManager {
+	/// Escalations running or acknowledged, by identifier of their incident
+	escalations: Arc<Mutex<HashMap<String, Escalation>>>,
+	/// Service sending the notifications of the steps
+	notification_service: NotificationService,
+	/// Base URL of the acknowledgement callbacks
+	callback_url: Arc<RwLock<String>>,
+}
+
+impl EscalationManager {
+	/// Creates a manager with no pending escalation
+	///
+	/// # Arguments
+	/// * `notification_service` - Service sending the notifications of the steps
+	pub fn new(notification_service: NotificationService) -> Self {
+		Self {
+			escalations: Arc::new(Mutex::new(HashMap::new())),
+			notification_service,
+			callback_url: Arc::new(RwLock::new(format!("http://{}", DEFAULT_CALLBACK_ADDRESS))),
+		}
+	}
+
+	/// Sets the base URL the acknowledgement callbacks are reachable at
+	///
+	/// # Arguments
+	/// * `callback_url` - Base URL of the callback server, e.g. `https://monitor.example.com`
+	pub fn set_callback_url(&self, callback_url: &str) {
+		*self.callback_url.write().unwrap() = callback_url.trim_end_matches('/').to_string();
+	}
+
+	/// Returns the URL acknowledging an escalation
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the escalation
+	/// * `token` - Token of the escalation
+	pub fn ack_url(&self, id: &str, token: &str) -> String {
+		format!(
+			"{}/escalations/{}/acknowledge?token={}",
+			self.callback_url.read().unwrap(),
+			id,
+			token
+		)
+	}
+
+	/// Starts the escalation of the matches of an incident
+	///
+	/// The trigger of each step fires once its delay elapsed since the match, unless the
+	/// escalation was acknowledged. The variables of the match are completed with
+	/// `escalation.id`, `escalation.ack_url` and the number of the step, `escalation.step`.
+	/// Nothing is started while the escalation of the incident is running or acknowledged.
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the incident of the match, identifying its escalation
+	/// * `steps` - Slugs and triggers of the steps, with their delay since the match
+	/// * `variables` - Variables of the match
+	/// * `monitor_match` - Match to escalate
+	///
+	/// # Returns
+	/// * `bool` - Whether the escalation was started
+	pub fn start(
+		&self,
+		id: &str,
+		steps: Vec<(String, Trigger, Duration)>,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+	) -> bool {
+		let token = Uuid::new_v4().simple().to_string();
+		{
+			let mut escalations = self.escalations.lock().unwrap();
+			if escalations.contains_key(id) {
+				return false;
+			}
+			escalations.insert(
+				id.to_string(),
+				Escalation {
+					token: token.clone(),
+					acknowledged: false,
+				},
+			);
+		}
+
+		let mut variables = variables.clone();
+		variables.insert("escalation.id".to_string(), id.to_string());
+		variables.insert("escalation.ack_url".to_string(), self.ack_url(id, &token));
+
+		let manager = self.clone();
+		let monitor_match = monitor_match.clone();
+		let escalation_id = id.to_string();
+		tokio::spawn(async move {
+			let started_at = tokio::time::Instant::now();
+			for (index, (trigger_slug, trigger, delay)) in steps.into_iter().enumerate() {
+				tokio::time::sleep_until(started_at + delay).await;
+				if !manager.is_pending(&escalation_id) {
+					tracing::info!(
+						"Escalation {} acknowledged before step {}",
+						escalation_id,
+						index + 1
+					);
+					return;
+				}
+
+				variables.insert("escalation.step".to_string(), (index + 1).to_string());
+				if let Err(e) = manager
+					.notification_service
+					.execute(
+						&trigger_slug,
+						&trigger,
+						&variables,
+						&monitor_match,
+						&HashMap::new(),
+					)
+					.await
+				{
+					tracing::error!(
+						"Failed to send step {} of escalation {} with {}: {}",
+						index + 1,
+						escalation_id,
+						trigger.name,
+						e
+					);
+				}
+			}
+			manager.remove(&escalation_id);
+		});
+
+		true
+	}
+
+	/// Acknowledges an escalation, so that its remaining steps do not fire
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the escalation
+	/// * `token` - Token of the acknowledgement URL of the escalation
+	///
+	/// # Returns
+	/// * `bool` - Whether the escalation was pending and the token is valid
+	pub fn acknowledge(&self, id: &str, token: &str) -> bool {
+		let mut escalations = self.escalations.lock().unwrap();
+		match escalations.get_mut(id) {
+			Some(escalation)
+				if !escalation.acknowledged && tokens_match(&escalation.token, token) =>
+			{
+				escalation.acknowledged = true;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// Stops the escalation of an incident and forgets it, e.g. once the incident is resolved
+	/// or acknowledged
+	///
+	/// # Returns
+	/// * `bool` - Whether the escalation was running or acknowledged
+	pub fn remove(&self, id: &str) -> bool {
+		self.escalations.lock().unwrap().remove(id).is_some()
+	}
+
+	/// Returns the token of the acknowledgement URL of an escalation
+	#[cfg(test)]
+	pub(crate) fn token(&self, id: &str) -> Option<String> {
+		self.escalations
+			.lock()
+			.unwrap()
+			.get(id)
+			.map(|escalation| escalation.token.clone())
+	}
+
+	/// Returns whether an escalation is neither acknowledged nor completed
+	pub fn is_pending(&self, id: &str) -> bool {
+		self.escalations
+			.lock()
+			.unwrap()
+			.get(id)
+			.is_some_and(|escalation| !escalation.acknowledged)
+	}
+}
+
+/// Compares the token of a callback URL in constant time
+pub(crate) fn tokens_match(expected: &str, token: &str) -> bool {
+	expected.len() == token.len()
+		&& expected
+			.bytes()
+			.zip(token.bytes())
+			.fold(0, |difference, (a, b)| difference | (a ^ b))
+			== 0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{MatchConditions, SolanaMonitorMatch, TriggerType},
+		utils::tests::{
+			builders::trigger::TriggerBuilder,
+			solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		},
+	};
+
+	fn create_solana_match() -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().build(),
+		)))
+	}
+
+	// The configuration does not match the trigger type, so sending fails without a request
+	fn create_trigger(name: &str) -> Trigger {
+		TriggerBuilder::new()
+			.name(name)
+			.slack("https://hooks.slack.com/services/xxx")
+			.trigger_type(TriggerType::Email)
+			.build()
+	}
+
+	#[test]
+	fn test_ack_url() {
+		let manager = EscalationManager::new(NotificationService::new());
+		assert_eq!(
+			manager.ack_url("id", "token"),
+			"http://127.0.0.1:8082/escalations/id/acknowledge?token=token"
+		);
+
+		manager.set_callback_url("https://monitor.example.com/");
+		assert_eq!(
+			manager.ack_url("id", "token"),
+			"https://monitor.example.com/escalations/id/acknowledge?token=token"
+		);
+	}
+
+	#[test]
+	fn test_tokens_match() {
+		assert!(tokens_match("token", "token"));
+		assert!(!tokens_match("token", "tokem"));
+		assert!(!tokens_match("token", "toke"));
+		assert!(!tokens_match("token", ""));
+	}
+
+	#[tokio::test]
+	async fn test_acknowledge_stops_escalation() {
+		let manager = EscalationManager::new(NotificationService::new());
+		assert!(manager.start(
+			"incident",
+			vec![
+				("slack".to_string(), create_trigger("slack"), Duration::ZERO),
+				(
+					"pagerduty".to_string(),
+					create_trigger("pagerduty"),
+					Duration::from_secs(600)
+				),
+			],
+			&HashMap::new(),
+			&create_solana_match(),
+		));
+		let token = manager.token("incident").unwrap();
+
+		assert!(manager.is_pending("incident"));
+		assert!(!manager.acknowledge("incident", "invalid"));
+		assert!(manager.is_pending("incident"));
+		assert!(manager.acknowledge("incident", &token));
+		assert!(!manager.is_pending("incident"));
+		assert!(!manager.acknowledge("incident", &token));
+	}
+
+	#[tokio::test]
+	async fn test_escalation_starts_once_per_incident() {
+		let manager = EscalationManager::new(NotificationService::new());
+		let steps = vec![(
+			"pagerduty".to_string(),
+			create_trigger("pagerduty"),
+			Duration::from_secs(600),
+		)];
+
+		assert!(manager.start(
+			"incident",
+			steps.clone(),
+			&HashMap::new(),
+			&create_solana_match()
+		));
+		assert!(!manager.start(
+			"incident",
+			steps.clone(),
+			&HashMap::new(),
+			&create_solana_match()
+		));
+
+		// Acknowledged escalations are not started again until they are removed
+		let token = manager.token("incident").unwrap();
+		assert!(manager.acknowledge("incident", &token));
+		assert!(!manager.start(
+			"incident",
+			steps.clone(),
+			&HashMap::new(),
+			&create_solana_match()
+		));
+
+		assert!(manager.remove("incident"));
+		assert!(manager.start("incident", steps, &HashMap::new(), &create_solana_match()));
+		assert!(manager.start("other", vec![], &HashMap::new(), &create_solana_match()));
+	}
+
+	#[tokio::test]
+	async fn test_escalation_completes_after_last_step() {
+		let manager = EscalationManager::new(NotificationService::new());
+		manager.start(
+			"incident",
+			vec![
+				("slack".to_string(), create_trigger("slack"), Duration::ZERO),
+				(
+					"pagerduty".to_string(),
+					create_trigger("pagerduty"),
+					Duration::from_millis(10),
+				),
+			],
+			&HashMap::new(),
+			&create_solana_match(),
+		);
+		let token = manager.token("incident").unwrap();
+
+		tokio::time::sleep(Duration::from_millis(200)).await;
+		assert!(!manager.is_pending("incident"));
+		assert!(!manager.acknowledge("incident", &token));
+	}
+}
@@ -0,0 +1,499 @@
+//! Match lifecycle.
+//!
+//! Matches of the same monitor, network and matched conditions belong to the same incident.
+//! An incident is open from its first match until it is acknowledged, through the
+//! acknowledgement URL included in its notifications, and then resolved. The matches of an
+//! acknowledged incident are not notified again, so that a recurring condition only pages once.
+//! Resolving an incident, explicitly or once it had no match for a while, makes its next match
+//! open a new incident, and is published to the subscribers of the resolutions, e.g. to resolve
+//! the PagerDuty alerts of the incident. The acknowledgement and resolution URLs hold a random
+//! token, without which the state of an incident cannot be changed.
+//!
+//! Incidents are only kept in memory: after a restart, the next match of an incident opens a
+//! new incident, the callback URLs of the previous incidents no longer work, and the alerts of
+//! the previous incidents are not resolved.
+
+use std::{
+	collections::HashMap,
+	fmt,
+	sync::{Arc, Mutex, RwLock},
+	time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::{
+	models::{MatchConditions, MonitorMatch},
+	services::trigger::escalation::tokens_match,
+};
+
+/// Time without match after which an incident is resolved, unless configured otherwise
+pub const DEFAULT_INCIDENT_RESOLVE_AFTER: Duration = Duration::from_secs(3600);
+
+/// Interval at which incidents without recent match are resolved
+pub const INCIDENT_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Number of resolutions buffered for subscribers lagging behind
+const RESOLUTIONS_CHANNEL_CAPACITY: usize = 256;
+
+/// State of an incident
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchState {
+	/// Matches are notified
+	Open,
+	/// Someone is handling the incident, matches are not notified
+	Acknowledged,
+	/// The incident is over, the next match opens a new incident
+	Resolved,
+}
+
+impl fmt::Display for MatchState {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MatchState::Open => write!(f, "open"),
+			MatchState::Acknowledged => write!(f, "acknowledged"),
+			MatchState::Resolved => write!(f, "resolved"),
+		}
+	}
+}
+
+/// Matches of a monitor sharing their network and matched conditions
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Incident {
+	/// Identifier of the incident, included in the notifications of its matches
+	pub id: String,
+	/// Name of the monitor of the matches
+	pub monitor_name: String,
+	/// Current state of the incident
+	pub state: MatchState,
+	/// Unix timestamp of the first match
+	pub opened_at: i64,
+	/// Unix timestamp of the last match
+	pub last_match_at: i64,
+	/// Number of matches of the incident
+	pub matches: u64,
+	/// Triggers that notified the matches of the incident
+	#[serde(skip)]
+	pub triggers: Vec<String>,
+	/// Token of the acknowledgement and resolution URLs of the incident
+	#[serde(skip)]
+	pub token: String,
+}
+
+/// Incidents of the monitor matches, with their state
+#[derive(Clone)]
+pub struct MatchLifecycle {
+	/// Incidents by key of their matches
+	incidents: Arc<Mutex<HashMap<String, Incident>>>,
+	/// Time without match after which an incident is resolved, zero disabling it
+	resolve_after: Arc<RwLock<Duration>>,
+	/// Base URL of the acknowledgement callbacks, unset while the callback server is not running
+	callback_url: Arc<RwLock<Option<String>>>,
+	/// Incidents as they are resolved
+	resolutions: broadcast::Sender<Incident>,
+}
+
+impl Default for MatchLifecycle {
+	fn default() -> Self {
+		Self {
+			incidents: Arc::new(Mutex::new(HashMap::new())),
+			resolve_after: Arc::new(RwLock::new(DEFAULT_INCIDENT_RESOLVE_AFTER)),
+			callback_url: Arc::new(RwLock::new(None)),
+			resolutions: broadcast::channel(RESOLUTIONS_CHANNEL_CAPACITY).0,
+		}
+	}
+}
+
+impl MatchLifecycle {
+	/// Sets the time without match after which an incident is resolved
+	///
+	/// # Arguments
+	/// * `resolve_after` - Time without match, zero keeping incidents until they are resolved
+	///   explicitly
+	pub fn set_resolve_after(&self, resolve_after: Duration) {
+		*self.resolve_after.write().unwrap() = resolve_after;
+	}
+
+	/// Sets the base URL the acknowledgement callbacks are reachable at
+	///
+	/// # Arguments
+	/// * `callback_url` - Base URL of the callback server, e.g. `https://monitor.example.com`
+	pub fn set_callback_url(&self, callback_url: &str) {
+		*self.callback_url.write().unwrap() = Some(callback_url.trim_end_matches('/').to_string());
+	}
+
+	/// Returns the URL acknowledging an incident, unless no callback URL is set
+	pub fn ack_url(&self, incident: &Incident) -> Option<String> {
+		self.callback_url
+			.read()
+			.unwrap()
+			.as_ref()
+			.map(|callback_url| {
+				format!(
+					"{}/matches/{}/acknowledge?token={}",
+					callback_url, incident.id, incident.token
+				)
+			})
+	}
+
+	/// Returns the URL resolving an incident, unless no callback URL is set
+	pub fn resolve_url(&self, incident: &Incident) -> Option<String> {
+		self.callback_url
+			.read()
+			.unwrap()
+			.as_ref()
+			.map(|callback_url| {
+				format!(
+					"{}/matches/{}/resolve?token={}",
+					callback_url, incident.id, incident.token
+				)
+			})
+	}
+
+	/// Subscribes to the incidents as they are resolved, explicitly or for lack of recent match
+	pub fn subscribe_resolutions(&self) -> broadcast::Receiver<Incident> {
+		self.resolutions.subscribe()
+	}
+
+	/// Records a match in its incident
+	///
+	/// A new incident is opened when the match has no incident, or when its incident was
+	/// resolved or had no match for longer than the resolution delay.
+	///
+	/// # Arguments
+	/// * `monitor_match` - The match to record
+	/// * `triggers` - Triggers notifying the match, whose alerts are resolved with the incident
+	///
+	/// # Returns
+	/// * `Incident` - Incident of the match, whose matches are not notified when acknowledged
+	pub fn record(&self, monitor_match: &MonitorMatch, triggers: &[String]) -> Incident {
+		let now = chrono::Utc::now().timestamp();
+		let mut incidents = self.incidents.lock().unwrap();
+		self.prune(&mut incidents, now);
+
+		let key = incident_key(monitor_match);
+		let incident = incidents
+			.entry(key)
+			.and_modify(|incident| {
+				if incident.state == MatchState::Resolved {
+					*incident = Incident::open(monitor_match, now);
+				}
+			})
+			.or_insert_with(|| Incident::open(monitor_match, now));
+		incident.last_match_at = now;
+		incident.matches += 1;
+		for trigger in triggers {
+			if !incident.triggers.contains(trigger) {
+				incident.triggers.push(trigger.clone());
+			}
+		}
+		incident.clone()
+	}
+
+	/// Resolves the incidents without recent match
+	///
+	/// Incidents are otherwise only resolved for lack of recent match when the next match is
+	/// recorded, so this is called periodically for their resolution to be published.
+	pub fn sweep(&self) {
+		let now = chrono::Utc::now().timestamp();
+		self.prune(&mut self.incidents.lock().unwrap(), now);
+	}
+
+	/// Resolves the incidents without recent match, and forgets the resolved ones
+	fn prune(&self, incidents: &mut HashMap<String, Incident>, now: i64) {
+		let resolve_after = *self.resolve_after.read().unwrap();
+		let resolve_after: i64 = resolve_after.as_secs().try_into().unwrap_or(i64::MAX);
+		incidents.retain(|_, incident| {
+			if incident.state == MatchState::Resolved {
+				return false;
+			}
+			if resolve_after == 0 || now.saturating_sub(incident.last_match_at) < resolve_after {
+				return true;
+			}
+			incident.state = MatchState::Resolved;
+			let _ = self.resolutions.send(incident.clone());
+			false
+		});
+	}
+
+	/// Returns an incident by identifier
+	pub fn get(&self, id: &str) -> Option<Incident> {
+		self.incidents
+			.lock()
+			.unwrap()
+			.values()
+			.find(|incident| incident.id == id)
+			.cloned()
+	}
+
+	/// Acknowledges an open incident, so that its next matches are not notified
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the incident
+	/// * `token` - Token of the callback URLs of the incident
+	///
+	/// # Returns
+	/// * `bool` - Whether the incident was open and the token is valid
+	pub fn acknowledge(&self, id: &str, token: &str) -> bool {
+		self.transition(id, token, &[MatchState::Open], MatchState::Acknowledged)
+			.is_some()
+	}
+
+	/// Resolves an incident, so that its next match opens a new incident
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the incident
+	/// * `token` - Token of the callback URLs of the incident
+	///
+	/// # Returns
+	/// * `bool` - Whether the incident was open or acknowledged and the token is valid
+	pub fn resolve(&self, id: &str, token: &str) -> bool {
+		match self.transition(
+			id,
+			token,
+			&[MatchState::Open, MatchState::Acknowledged],
+			MatchState::Resolved,
+		) {
+			Some(incident) => {
+				let _ = self.resolutions.send(incident);
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Moves an incident from one of some states to another
+	///
+	/// # Returns
+	/// * `Option<Incident>` - The incident after the transition, if it was in one of the
+	///   `from` states and the token is valid
+	fn transition(
+		&self,
+		id: &str,
+		token: &str,
+		from: &[MatchState],
+		to: MatchState,
+	) -> Option<Incident> {
+		let mut incidents = self.incidents.lock().unwrap();
+		let incident = incidents.values_mut().find(|incident| {
+			incident.id == id
+				&& from.contains(&incident.state)
+				&& tokens_match(&incident.token, token)
+		})?;
+		incident.state = to;
+		Some(incident.clone())
+	}
+}
+
+impl Incident {
+	/// Creates an open incident starting with a match
+	fn open(monitor_match: &MonitorMatch, now: i64) -> Self {
+		let monitor_name = match monitor_match {
+			MonitorMatch::EVM(evm_match) => &evm_match.monitor.name,
+			MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor.name,
+			MonitorMatch::Solana(solana_match) => &solana_match.monitor.name,
+		};
+		Self {
+			id: Uuid::new_v4().to_string(),
+			monitor_name: monitor_name.clone(),
+			state: MatchState::Open,
+			opened_at: now,
+			last_match_at: now,
+			matches: 0,
+			triggers: Vec::new(),
+			token: Uuid::new_v4().simple().to_string(),
+		}
+	}
+}
+
+/// Returns the key grouping the matches of an incident
+///
+/// The key is the SHA-256 of the monitor name, network and matched conditions, so that the
+/// matches of different transactions share the same incident.
+pub fn incident_key(monitor_match: &MonitorMatch) -> String {
+	let (monitor, network, matched_on): (_, _, &MatchConditions) = match monitor_match {
+		MonitorMatch::EVM(evm_match) => (
+			&evm_match.monitor.name,
+			&evm_match.network_slug,
+			&evm_match.matched_on,
+		),
+		MonitorMatch::Stellar(stellar_match) => (
+			&stellar_match.monitor.name,
+			&stellar_match.network_slug,
+			&stellar_match.matched_on,
+		),
+		MonitorMatch::Solana(solana_match) => (
+			&solana_match.monitor.name,
+			&solana_match.network_slug,
+			solana_match.matched_on(),
+		),
+	};
+
+	let identity = serde_json::json!([monitor, network, matched_on]);
+	hex::encode(Sha256::digest(identity.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::SolanaMonitorMatch,
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+
+	fn create_solana_match(monitor_name: &str) -> MonitorMatch {
+		let monitor = MonitorBuilder::new()
+			.name(monitor_name)
+			.function("DepositReserveLiquidity", None)
+			.build();
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			monitor.clone(),
+			"solana_mainnet".to_string(),
+			monitor.match_conditions,
+			None,
+			TransactionBuilder::new().build(),
+		)))
+	}
+
+	#[test]
+	fn test_record_groups_matches_in_incidents() {
+		let lifecycle = MatchLifecycle::default();
+
+		let first = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		let second = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		let other = lifecycle.record(&create_solana_match("Large deposits"), &[]);
+
+		assert_eq!(first.state, MatchState::Open);
+		assert_eq!(first.matches, 1);
+		assert_eq!(second.id, first.id);
+		assert_eq!(second.matches, 2);
+		assert_ne!(other.id, first.id);
+		assert_eq!(lifecycle.get(&first.id).unwrap().matches, 2);
+	}
+
+	#[test]
+	fn test_acknowledge_and_resolve() {
+		let lifecycle = MatchLifecycle::default();
+		let incident = lifecycle.record(&create_solana_match("Deposits"), &[]);
+
+		assert!(!lifecycle.acknowledge(&incident.id, "invalid"));
+		assert!(lifecycle.acknowledge(&incident.id, &incident.token));
+		assert!(!lifecycle.acknowledge(&incident.id, &incident.token));
+		assert_eq!(
+			lifecycle
+				.record(&create_solana_match("Deposits"), &[])
+				.state,
+			MatchState::Acknowledged
+		);
+
+		assert!(!lifecycle.resolve(&incident.id, ""));
+		assert!(lifecycle.resolve(&incident.id, &incident.token));
+		assert!(!lifecycle.resolve(&incident.id, &incident.token));
+		assert_eq!(
+			lifecycle.get(&incident.id).unwrap().state,
+			MatchState::Resolved
+		);
+
+		// The next match opens a new incident
+		let reopened = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		assert_ne!(reopened.id, incident.id);
+		assert_eq!(reopened.state, MatchState::Open);
+		assert_eq!(reopened.matches, 1);
+		assert!(lifecycle.get(&incident.id).is_none());
+		assert!(!lifecycle.acknowledge("unknown", &incident.token));
+	}
+
+	#[test]
+	fn test_incidents_resolve_without_recent_match() {
+		let lifecycle = MatchLifecycle::default();
+		let incident = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		assert!(lifecycle.acknowledge(&incident.id, &incident.token));
+
+		// Move the last match of the incident before the resolution delay
+		for incident in lifecycle.incidents.lock().unwrap().values_mut() {
+			incident.last_match_at -= DEFAULT_INCIDENT_RESOLVE_AFTER.as_secs() as i64;
+		}
+
+		let reopened = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		assert_ne!(reopened.id, incident.id);
+		assert_eq!(reopened.state, MatchState::Open);
+	}
+
+	#[test]
+	fn test_resolved_incidents_are_forgotten_without_resolution_delay() {
+		let lifecycle = MatchLifecycle::default();
+		lifecycle.set_resolve_after(Duration::ZERO);
+		let resolved = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		let open = lifecycle.record(&create_solana_match("Large deposits"), &[]);
+		assert!(lifecycle.resolve(&resolved.id, &resolved.token));
+
+		// Incidents without recent match stay open, resolved incidents are forgotten
+		for incident in lifecycle.incidents.lock().unwrap().values_mut() {
+			incident.last_match_at -= DEFAULT_INCIDENT_RESOLVE_AFTER.as_secs() as i64;
+		}
+		lifecycle.sweep();
+		assert!(lifecycle.get(&resolved.id).is_none());
+		assert_eq!(lifecycle.get(&open.id).unwrap().state, MatchState::Open);
+		assert_eq!(lifecycle.incidents.lock().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_record_keeps_incident_triggers() {
+		let lifecycle = MatchLifecycle::default();
+		let triggers = ["pagerduty".to_string(), "slack".to_string()];
+
+		lifecycle.record(&create_solana_match("Deposits"), &triggers[..1]);
+		let incident = lifecycle.record(&create_solana_match("Deposits"), &triggers);
+		assert_eq!(incident.triggers, triggers);
+	}
+
+	#[test]
+	fn test_resolutions_are_published() {
+		let lifecycle = MatchLifecycle::default();
+		let mut resolutions = lifecycle.subscribe_resolutions();
+		let resolved = lifecycle.record(&create_solana_match("Deposits"), &[]);
+		let expired = lifecycle.record(&create_solana_match("Large deposits"), &[]);
+
+		assert!(lifecycle.resolve(&resolved.id, &resolved.token));
+		let incident = resolutions.try_recv().unwrap();
+		assert_eq!(incident.id, resolved.id);
+		assert_eq!(incident.state, MatchState::Resolved);
+
+		// Sweeping resolves the incidents without recent match
+		lifecycle.sweep();
+		assert!(resolutions.try_recv().is_err());
+		for incident in lifecycle.incidents.lock().unwrap().values_mut() {
+			incident.last_match_at -= DEFAULT_INCIDENT_RESOLVE_AFTER.as_secs() as i64;
+		}
+		lifecycle.sweep();
+		assert_eq!(resolutions.try_recv().unwrap().id, expired.id);
+		assert!(lifecycle.get(&expired.id).is_none());
+	}
+
+	#[test]
+	fn test_callback_urls() {
+		let lifecycle = MatchLifecycle::default();
+		let incident = Incident {
+			id: "id".to_string(),
+			token: "token".to_string(),
+			..lifecycle.record(&create_solana_match("Deposits"), &[])
+		};
+		// Incidents have no callback URLs while the callback server is not running
+		assert!(lifecycle.ack_url(&incident).is_none());
+		assert!(lifecycle.resolve_url(&incident).is_none());
+
+		lifecycle.set_callback_url("https://monitor.example.com/");
+		assert_eq!(
+			lifecycle.ack_url(&incident).as_deref(),
+			Some("https://monitor.example.com/matches/id/acknowledge?token=token")
+		);
+		assert_eq!(
+			lifecycle.resolve_url(&incident).as_deref(),
+			Some("https://monitor.example.com/matches/id/resolve?token=token")
+		);
+	}
+}
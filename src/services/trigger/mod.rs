@@ -4,13 +4,34 @@
 //! which are configurable actions that can be initiated based on
 //! various conditions.
 
+mod aggregation;
+mod dispatcher;
 mod error;
+mod escalation;
+mod lifecycle;
+mod retry;
 mod script;
 mod service;
+mod throttle;
 
+pub use aggregation::{aggregate_body, MatchAggregator};
+pub use dispatcher::{
+	QueuedExecution, TriggerDispatcher, DEFAULT_TRIGGER_MAX_CONCURRENCY,
+	DEFAULT_TRIGGER_QUEUE_CAPACITY,
+};
 pub use error::TriggerError;
+pub use escalation::{EscalationManager, DEFAULT_CALLBACK_ADDRESS};
+pub use lifecycle::{
+	incident_key, Incident, MatchLifecycle, MatchState, DEFAULT_INCIDENT_RESOLVE_AFTER,
+	INCIDENT_SWEEP_INTERVAL,
+};
+pub use retry::{
+	NotificationRetryQueue, RetryEntry, RetryPolicy, DEFAULT_DEAD_LETTER_PATH,
+	DEFAULT_RETRY_QUEUE_PATH, RETRY_POLL_INTERVAL,
+};
 pub use script::{
 	process_script_output, validate_script_config, ScriptError, ScriptExecutor,
 	ScriptExecutorFactory,
 };
 pub use service::{TriggerExecutionService, TriggerExecutionServiceTrait};
+pub use throttle::{PendingSummary, ThrottleDecision, TriggerThrottler};
@@ -0,0 +1,579 @@
+//! Notification retry queue.
+//!
+//! Notifications that still fail after the HTTP retries of their notifier, e.g. while the
+//! endpoint is down, are queued and sent again later with an exponential backoff, instead of
+//! losing the match. Email digests failing to be sent are queued the same way. Notifications
+//! exhausting their retries are appended to a dead-letter file. The queue can be persisted to a
+//! file to survive restarts.
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use uuid::Uuid;
+
+use crate::{models::MonitorMatch, utils::write_atomically};
+
+/// Default file the pending notifications are persisted to
+pub const DEFAULT_RETRY_QUEUE_PATH: &str = "data/notification_retry_queue.json";
+
+/// Default file the notifications exhausting their retries are appended to
+pub const DEFAULT_DEAD_LETTER_PATH: &str = "data/notification_dead_letters.jsonl";
+
+/// Interval at which the notifications due for a retry are sent
+pub const RETRY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of times and delays with which failed notifications are sent again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+	/// Maximum number of retries of a notification, zero disabling the queue
+	pub max_attempts: u32,
+	/// Delay before the first retry, doubled after each failed retry
+	pub initial_backoff: Duration,
+	/// Maximum delay between two retries
+	pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 5,
+			initial_backoff: Duration::from_secs(30),
+			max_backoff: Duration::from_secs(3600),
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Returns the delay before the next retry of a notification
+	///
+	/// # Arguments
+	/// * `attempts` - Number of retries already made
+	pub fn backoff(&self, attempts: u32) -> Duration {
+		self.initial_backoff
+			.checked_mul(2u32.saturating_pow(attempts))
+			.unwrap_or(self.max_backoff)
+			.min(self.max_backoff)
+	}
+}
+
+/// Notification waiting to be sent again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryEntry {
+	/// Identifier of the entry in the queue
+	pub id: String,
+	/// Slug of the trigger sending the notification
+	pub trigger_slug: String,
+	/// Variables of the match
+	pub variables: HashMap<String, String>,
+	/// Match notified, unless the notification is an email digest
+	pub monitor_match: Option<MonitorMatch>,
+	/// Messages of the matches of an email digest
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub digest: Option<Vec<String>>,
+	/// Number of retries already made
+	pub attempts: u32,
+	/// Unix timestamp from which the notification is sent again
+	pub next_attempt_at: i64,
+	/// Error of the last attempt
+	pub last_error: String,
+}
+
+/// Files the queue is persisted to
+#[derive(Debug, Clone)]
+struct RetryStorage {
+	queue_path: PathBuf,
+	dead_letter_path: PathBuf,
+}
+
+#[derive(Debug, Default)]
+struct RetryState {
+	policy: RetryPolicy,
+	storage: Option<RetryStorage>,
+	entries: Vec<RetryEntry>,
+}
+
+/// Failed notifications waiting to be sent again
+#[derive(Debug, Default)]
+pub struct NotificationRetryQueue {
+	state: Mutex<RetryState>,
+}
+
+impl NotificationRetryQueue {
+	/// Creates an empty queue keeping the notifications in memory only
+	///
+	/// # Arguments
+	/// * `policy` - Retries of the failed notifications
+	pub fn new(policy: RetryPolicy) -> Self {
+		Self {
+			state: Mutex::new(RetryState {
+				policy,
+				..RetryState::default()
+			}),
+		}
+	}
+
+	/// Configures the retries of the queue and persists it to a file
+	///
+	/// The notifications left pending by a previous run are loaded from the queue file. A
+	/// missing or unreadable file leaves the queue as it is.
+	///
+	/// # Arguments
+	/// * `policy` - Retries of the failed notifications
+	/// * `queue_path` - File the pending notifications are persisted to
+	/// * `dead_letter_path` - File the notifications exhausting their retries are appended to
+	pub async fn configure(
+		&self,
+		policy: RetryPolicy,
+		queue_path: PathBuf,
+		dead_letter_path: PathBuf,
+	) {
+		let restored: Vec<RetryEntry> = match tokio::fs::read_to_string(&queue_path).await {
+			Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+				tracing::warn!(
+					"Ignoring unreadable notification retry queue {}: {}",
+					queue_path.display(),
+					e
+				);
+				Vec::new()
+			}),
+			Err(_) => Vec::new(),
+		};
+		if !restored.is_empty() {
+			tracing::info!(
+				"Restored {} notification(s) pending for a retry",
+				restored.len()
+			);
+		}
+
+		let mut state = self.state.lock().await;
+		state.policy = policy;
+		state.storage = Some(RetryStorage {
+			queue_path,
+			dead_letter_path,
+		});
+		state.entries.extend(restored);
+	}
+
+	/// Queues a notification that failed to be sent
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Slug of the trigger sending the notification
+	/// * `variables` - Variables of the match
+	/// * `monitor_match` - Match notified
+	/// * `error` - Error of the failed attempt
+	///
+	/// # Returns
+	/// * `bool` - Whether the notification was queued, i.e. retries are enabled
+	pub async fn enqueue(
+		&self,
+		trigger_slug: &str,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		error: String,
+	) -> bool {
+		self.push(
+			trigger_slug,
+			variables.clone(),
+			Some(monitor_match.clone()),
+			None,
+			error,
+		)
+		.await
+	}
+
+	/// Queues an email digest that failed to be sent
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Slug of the email trigger of the digest
+	/// * `messages` - Messages of the matches of the digest
+	/// * `error` - Error of the failed attempt
+	///
+	/// # Returns
+	/// * `bool` - Whether the digest was queued, i.e. retries are enabled
+	pub async fn enqueue_digest(
+		&self,
+		trigger_slug: &str,
+		messages: Vec<String>,
+		error: String,
+	) -> bool {
+		self.push(trigger_slug, HashMap::new(), None, Some(messages), error)
+			.await
+	}
+
+	/// Queues a failed notification for its first retry
+	async fn push(
+		&self,
+		trigger_slug: &str,
+		variables: HashMap<String, String>,
+		monitor_match: Option<MonitorMatch>,
+		digest: Option<Vec<String>>,
+		error: String,
+	) -> bool {
+		let mut state = self.state.lock().await;
+		if state.policy.max_attempts == 0 {
+			return false;
+		}
+
+		let next_attempt_at = next_attempt_at(state.policy.backoff(0));
+		state.entries.push(RetryEntry {
+			id: Uuid::new_v4().to_string(),
+			trigger_slug: trigger_slug.to_string(),
+			variables,
+			monitor_match,
+			digest,
+			attempts: 0,
+			next_attempt_at,
+			last_error: error,
+		});
+		persist(&state).await;
+		true
+	}
+
+	/// Returns the notifications due for a retry
+	///
+	/// The notifications stay in the queue until they are completed or failed again.
+	pub async fn due(&self) -> Vec<RetryEntry> {
+		let now = chrono::Utc::now().timestamp();
+		self.state
+			.lock()
+			.await
+			.entries
+			.iter()
+			.filter(|entry| entry.next_attempt_at <= now)
+			.cloned()
+			.collect()
+	}
+
+	/// Removes a notification sent successfully from the queue
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the entry
+	pub async fn complete(&self, id: &str) {
+		let mut state = self.state.lock().await;
+		state.entries.retain(|entry| entry.id != id);
+		persist(&state).await;
+	}
+
+	/// Records a failed retry of a notification
+	///
+	/// The notification is scheduled again after the backoff of its attempts, or moved to the
+	/// dead-letter file once it exhausted its retries.
+	///
+	/// # Arguments
+	/// * `id` - Identifier of the entry
+	/// * `error` - Error of the failed retry
+	pub async fn fail(&self, id: &str, error: String) {
+		let mut state = self.state.lock().await;
+		let Some(index) = state.entries.iter().position(|entry| entry.id == id) else {
+			return;
+		};
+
+		let policy = state.policy;
+		let entry = &mut state.entries[index];
+		entry.attempts += 1;
+		entry.last_error = error;
+
+		if entry.attempts < policy.max_attempts {
+			entry.next_attempt_at = next_attempt_at(policy.backoff(entry.attempts));
+			persist(&state).await;
+			return;
+		}
+
+		let entry = state.entries.remove(index);
+		tracing::error!(
+			"Notification of trigger {} dead-lettered after {} retries: {}",
+			entry.trigger_slug,
+			entry.attempts,
+			entry.last_error
+		);
+		if let Some(storage) = &state.storage {
+			if let Err(e) = dead_letter(&storage.dead_letter_path, &entry).await {
+				tracing::error!(
+					"Failed to write dead-lettered notification to {}: {}",
+					storage.dead_letter_path.display(),
+					e
+				);
+			}
+		}
+		persist(&state).await;
+	}
+
+	/// Returns the number of notifications waiting to be sent again
+	pub async fn len(&self) -> usize {
+		self.state.lock().await.entries.len()
+	}
+
+	/// Returns whether no notification is waiting to be sent again
+	pub async fn is_empty(&self) -> bool {
+		self.state.lock().await.entries.is_empty()
+	}
+}
+
+/// Returns the Unix timestamp after a delay from now
+fn next_attempt_at(delay: Duration) -> i64 {
+	chrono::Utc::now()
+		.timestamp()
+		.saturating_add(delay.as_secs().try_into().unwrap_or(i64::MAX))
+}
+
+/// Writes the pending notifications to the queue file, if the queue is persisted
+///
+/// The file is replaced atomically, so that a crash while writing does not lose the queue.
+/// Failing to persist them only logs a warning.
+async fn persist(state: &RetryState) {
+	let Some(storage) = &state.storage else {
+		return;
+	};
+
+	let persisted = match serde_json::to_string(&state.entries) {
+		Ok(json) => write_atomically(&storage.queue_path, json)
+			.await
+			.map_err(|e| e.to_string()),
+		Err(e) => Err(e.to_string()),
+	};
+	if let Err(e) = persisted {
+		tracing::warn!(
+			"Failed to persist notification retry queue to {}: {}",
+			storage.queue_path.display(),
+			e
+		);
+	}
+}
+
+/// Appends a notification exhausting its retries to the dead-letter file, one JSON per line
+async fn dead_letter(path: &Path, entry: &RetryEntry) -> Result<(), anyhow::Error> {
+	let mut line = serde_json::to_string(entry)?;
+	line.push('\n');
+
+	let mut file = tokio::fs::OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(path)
+		.await?;
+	file.write_all(line.as_bytes()).await?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{MatchConditions, SolanaMonitorMatch},
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use tempfile::TempDir;
+
+	fn create_solana_match() -> MonitorMatch {
+		MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().build(),
+		)))
+	}
+
+	fn immediate_policy(max_attempts: u32) -> RetryPolicy {
+		RetryPolicy {
+			max_attempts,
+			initial_backoff: Duration::ZERO,
+			max_backoff: Duration::ZERO,
+		}
+	}
+
+	#[test]
+	fn test_backoff_is_exponential_and_capped() {
+		let policy = RetryPolicy {
+			max_attempts: 10,
+			initial_backoff: Duration::from_secs(30),
+			max_backoff: Duration::from_secs(300),
+		};
+
+		assert_eq!(policy.backoff(0), Duration::from_secs(30));
+		assert_eq!(policy.backoff(1), Duration::from_secs(60));
+		assert_eq!(policy.backoff(3), Duration::from_secs(240));
+		assert_eq!(policy.backoff(4), Duration::from_secs(300));
+		assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(300));
+	}
+
+	#[tokio::test]
+	async fn test_enqueue_and_complete() {
+		let queue = NotificationRetryQueue::new(immediate_policy(3));
+		assert!(
+			queue
+				.enqueue(
+					"slack",
+					&HashMap::new(),
+					&create_solana_match(),
+					"down".into()
+				)
+				.await
+		);
+
+		let due = queue.due().await;
+		assert_eq!(due.len(), 1);
+		assert_eq!(due[0].trigger_slug, "slack");
+		assert_eq!(due[0].attempts, 0);
+
+		queue.complete(&due[0].id).await;
+		assert!(queue.is_empty().await);
+	}
+
+	#[tokio::test]
+	async fn test_enqueue_digest() {
+		let temp_dir = TempDir::new().unwrap();
+		let queue_path = temp_dir.path().join("queue.json");
+		let queue = NotificationRetryQueue::default();
+		queue
+			.configure(
+				immediate_policy(3),
+				queue_path.clone(),
+				temp_dir.path().join("dead_letters.jsonl"),
+			)
+			.await;
+
+		assert!(
+			queue
+				.enqueue_digest(
+					"email",
+					vec!["first".to_string(), "second".to_string()],
+					"down".into()
+				)
+				.await
+		);
+
+		// The digest is persisted with its messages
+		let restarted = NotificationRetryQueue::default();
+		restarted
+			.configure(
+				immediate_policy(3),
+				queue_path,
+				temp_dir.path().join("dead_letters.jsonl"),
+			)
+			.await;
+		let due = restarted.due().await;
+		assert_eq!(due.len(), 1);
+		assert_eq!(due[0].trigger_slug, "email");
+		assert!(due[0].monitor_match.is_none());
+		assert_eq!(
+			due[0].digest.as_deref(),
+			Some(&["first".to_string(), "second".to_string()][..])
+		);
+	}
+
+	#[tokio::test]
+	async fn test_enqueue_waits_for_backoff() {
+		let queue = NotificationRetryQueue::new(RetryPolicy::default());
+		queue
+			.enqueue(
+				"slack",
+				&HashMap::new(),
+				&create_solana_match(),
+				"down".into(),
+			)
+			.await;
+
+		assert_eq!(queue.len().await, 1);
+		assert!(queue.due().await.is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_enqueue_disabled() {
+		let queue = NotificationRetryQueue::new(immediate_policy(0));
+		assert!(
+			!queue
+				.enqueue(
+					"slack",
+					&HashMap::new(),
+					&create_solana_match(),
+					"down".into()
+				)
+				.await
+		);
+		assert!(queue.is_empty().await);
+	}
+
+	#[tokio::test]
+	async fn test_fail_dead_letters_after_max_attempts() {
+		let temp_dir = TempDir::new().unwrap();
+		let dead_letter_path = temp_dir.path().join("dead_letters.jsonl");
+		let queue = NotificationRetryQueue::default();
+		queue
+			.configure(
+				immediate_policy(2),
+				temp_dir.path().join("queue.json"),
+				dead_letter_path.clone(),
+			)
+			.await;
+
+		queue
+			.enqueue(
+				"slack",
+				&HashMap::new(),
+				&create_solana_match(),
+				"down".into(),
+			)
+			.await;
+		let id = queue.due().await[0].id.clone();
+
+		queue.fail(&id, "still down".into()).await;
+		assert_eq!(queue.due().await[0].attempts, 1);
+
+		queue.fail(&id, "down for good".into()).await;
+		assert!(queue.is_empty().await);
+
+		let content = tokio::fs::read_to_string(&dead_letter_path).await.unwrap();
+		let lines: Vec<&str> = content.lines().collect();
+		assert_eq!(lines.len(), 1);
+		let entry: RetryEntry = serde_json::from_str(lines[0]).unwrap();
+		assert_eq!(entry.attempts, 2);
+		assert_eq!(entry.last_error, "down for good");
+	}
+
+	#[tokio::test]
+	async fn test_pending_notifications_survive_restarts() {
+		let temp_dir = TempDir::new().unwrap();
+		let queue_path = temp_dir.path().join("queue.json");
+		let dead_letter_path = temp_dir.path().join("dead_letters.jsonl");
+
+		let queue = NotificationRetryQueue::default();
+		queue
+			.configure(
+				immediate_policy(3),
+				queue_path.clone(),
+				dead_letter_path.clone(),
+			)
+			.await;
+		queue
+			.enqueue(
+				"slack",
+				&HashMap::new(),
+				&create_solana_match(),
+				"down".into(),
+			)
+			.await;
+
+		let restarted = NotificationRetryQueue::default();
+		restarted
+			.configure(
+				immediate_policy(3),
+				queue_path.clone(),
+				dead_letter_path.clone(),
+			)
+			.await;
+		assert_eq!(restarted.due().await.len(), 1);
+
+		// An unreadable file starts the queue empty
+		tokio::fs::write(&queue_path, "not json").await.unwrap();
+		let restarted = NotificationRetryQueue::default();
+		restarted
+			.configure(immediate_policy(3), queue_path, dead_letter_path)
+			.await;
+		assert!(restarted.is_empty().await);
+	}
+}
@@ -51,12 +51,7 @@ impl ScriptExecutor for PythonScriptExecutor {
 		args: Option<&[String]>,
 		from_custom_notification: bool,
 	) -> Result<bool, anyhow::Error> {
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(&input, args)?;
 
 		let cmd = tokio::process::Command::new("python3")
 			.arg("-c")
@@ -64,6 +59,7 @@ impl ScriptExecutor for PythonScriptExecutor {
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
 			.stderr(Stdio::piped())
+			.kill_on_drop(true)
 			.spawn()
 			.with_context(|| "Failed to spawn python3 process")?;
 
@@ -89,13 +85,7 @@ impl ScriptExecutor for JavaScriptScriptExecutor {
 		args: Option<&[String]>,
 		from_custom_notification: bool,
 	) -> Result<bool, anyhow::Error> {
-		// Create a combined input with both the monitor match and arguments
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(&input, args)?;
 
 		let cmd = tokio::process::Command::new("node")
 			.arg("-e")
@@ -103,6 +93,7 @@ impl ScriptExecutor for JavaScriptScriptExecutor {
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
 			.stderr(Stdio::piped())
+			.kill_on_drop(true)
 			.spawn()
 			.with_context(|| "Failed to spawn node process")?;
 		process_command(cmd, &input_json, timeout_ms, from_custom_notification).await
@@ -127,14 +118,7 @@ impl ScriptExecutor for BashScriptExecutor {
 		args: Option<&[String]>,
 		from_custom_notification: bool,
 	) -> Result<bool, anyhow::Error> {
-		// Create a combined input with both the monitor match and arguments
-		let combined_input = serde_json::json!({
-			"monitor_match": input,
-			"args": args
-		});
-
-		let input_json = serde_json::to_string(&combined_input)
-			.with_context(|| "Failed to serialize monitor match and arguments")?;
+		let input_json = script_input(&input, args)?;
 
 		let cmd = tokio::process::Command::new("sh")
 			.arg("-c")
@@ -142,6 +126,7 @@ impl ScriptExecutor for BashScriptExecutor {
 			.stdin(Stdio::piped())
 			.stdout(Stdio::piped())
 			.stderr(Stdio::piped())
+			.kill_on_drop(true)
 			.spawn()
 			.with_context(|| "Failed to spawn shell process")?;
 
@@ -149,6 +134,31 @@ impl ScriptExecutor for BashScriptExecutor {
 	}
 }
 
+/// Serializes the input of a script, combining the monitor match and the script arguments
+///
+/// The transactions of Solana matches serialize their signature as bytes, so Solana inputs
+/// also carry the base58 signature and the slot of the transaction.
+///
+/// # Arguments
+/// * `input` - The monitor match to pass to the script
+/// * `args` - Additional arguments passed to the script
+///
+/// # Returns
+/// * `Result<String, anyhow::Error>` - JSON written to the stdin of the script
+fn script_input(input: &MonitorMatch, args: Option<&[String]>) -> Result<String, anyhow::Error> {
+	let mut combined_input = serde_json::json!({
+		"monitor_match": input,
+		"args": args
+	});
+	if let MonitorMatch::Solana(solana_match) = input {
+		combined_input["signature"] = solana_match.signature().to_string().into();
+		combined_input["slot"] = solana_match.slot().into();
+	}
+
+	serde_json::to_string(&combined_input)
+		.with_context(|| "Failed to serialize monitor match and arguments")
+}
+
 /// Processes the output from script execution.
 ///
 /// # Arguments
@@ -167,12 +177,15 @@ pub fn process_script_output(
 	output: std::process::Output,
 	from_custom_notification: bool,
 ) -> Result<bool, anyhow::Error> {
+	let stderr = String::from_utf8_lossy(&output.stderr);
 	if !output.status.success() {
-		let error_message = String::from_utf8_lossy(&output.stderr).to_string();
-		return Err(anyhow::anyhow!(
-			"Script execution failed: {}",
-			error_message
-		));
+		tracing::warn!("Script exited with {}", output.status);
+		return Err(anyhow::anyhow!("Script execution failed: {}", stderr));
+	}
+
+	// The stderr of successful scripts is logged, e.g. for their diagnostics
+	if !stderr.trim().is_empty() {
+		tracing::info!("Script stderr: {}", stderr.trim());
 	}
 
 	// If the script is from a custom notification and the status is success, we don't need to check
@@ -226,13 +239,17 @@ async fn process_command(
 
 	let timeout_duration = Duration::from_millis(u64::from(*timeout_ms));
 
+	// Scripts are spawned with `kill_on_drop`, so those exceeding their timeout are killed
 	match timeout(timeout_duration, cmd.wait_with_output()).await {
 		Ok(result) => {
 			let output =
 				result.map_err(|e| anyhow::anyhow!("Failed to wait for script output: {}", e))?;
 			process_script_output(output, from_custom_notification)
 		}
-		Err(_) => Err(anyhow::anyhow!("Script execution timed out")),
+		Err(_) => Err(anyhow::anyhow!(
+			"Script execution timed out after {} ms",
+			timeout_ms
+		)),
 	}
 }
 
@@ -241,11 +258,18 @@ mod tests {
 	use super::*;
 	use crate::{
 		models::{
-			AddressWithSpec, EVMMonitorMatch, EVMReceiptLog, EventCondition, FunctionCondition,
-			MatchConditions, Monitor, MonitorMatch, TransactionCondition,
+			AddressWithSpec, ConditionLogic, EVMMonitorMatch, EVMReceiptLog, EventCondition,
+			FunctionCondition, MatchConditions, Monitor, MonitorMatch, SolanaMonitorMatch,
+			TransactionCondition,
 		},
-		utils::tests::evm::{
-			monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
+		utils::tests::{
+			evm::{
+				monitor::MonitorBuilder, receipt::ReceiptBuilder, transaction::TransactionBuilder,
+			},
+			solana::{
+				monitor::MonitorBuilder as SolanaMonitorBuilder,
+				transaction::TransactionBuilder as SolanaTransactionBuilder,
+			},
 		},
 	};
 	use std::{fs, path::Path, time::Instant};
@@ -304,6 +328,12 @@ mod tests {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			matched_on_args: None,
 		}))
@@ -782,6 +812,47 @@ input_json = sys.stdin.read()
 		assert!(result.unwrap());
 	}
 
+	#[tokio::test]
+	async fn test_script_executor_with_solana_match() {
+		let script_content = r#"
+import sys
+import json
+
+data = json.loads(sys.stdin.read())
+solana_match = data["monitor_match"]["Solana"]
+if data["signature"] != data["args"][0]:
+    sys.exit(1)
+if data["slot"] != solana_match["transaction"]["metadata"]["slot"]:
+    sys.exit(1)
+sys.stderr.write("Notified " + solana_match["monitor"]["name"] + "\n")
+"#;
+
+		let executor = PythonScriptExecutor {
+			script_content: script_content.to_string(),
+		};
+
+		let transaction = SolanaTransactionBuilder::new().slot(42).build();
+		let signature = transaction.signature().to_string();
+		let input = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			SolanaMonitorBuilder::new().name("solana_monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			transaction,
+		)));
+
+		let result = executor
+			.execute(input.clone(), &1000, Some(&[signature]), true)
+			.await;
+		assert!(result.is_ok());
+
+		// A script exiting with a non-zero code fails
+		let result = executor
+			.execute(input, &1000, Some(&["other".to_string()]), true)
+			.await;
+		assert!(result.is_err());
+	}
+
 	#[tokio::test]
 	async fn test_script_executor_with_non_zero_exit() {
 		let script_content = r#"
@@ -3,15 +3,25 @@
 //! Provides functionality to execute triggers with variable substitution
 //! and notification delivery. Manages trigger lookup and execution flow.
 
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, path::Path, sync::Arc, time::Duration};
 
 use anyhow::Context;
 use async_trait::async_trait;
 
 use crate::{
-	models::{Monitor, MonitorMatch, ScriptLanguage, TriggerTypeConfig},
+	models::{Monitor, MonitorMatch, ScriptLanguage, Trigger, TriggerTypeConfig},
 	repositories::{TriggerRepositoryTrait, TriggerService},
-	services::{notification::NotificationService, trigger::error::TriggerError},
+	services::{
+		notification::{NotificationError, NotificationService},
+		trigger::{
+			aggregation::MatchAggregator,
+			error::TriggerError,
+			escalation::EscalationManager,
+			lifecycle::{Incident, MatchLifecycle, MatchState},
+			retry::NotificationRetryQueue,
+			throttle::{PendingSummary, ThrottleDecision, TriggerThrottler},
+		},
+	},
 	utils::normalize_string,
 };
 
@@ -43,6 +53,16 @@ pub struct TriggerExecutionService<T: TriggerRepositoryTrait> {
 	trigger_service: TriggerService<T>,
 	/// Service for sending notifications
 	notification_service: NotificationService,
+	/// Notifications recently sent by throttled triggers
+	throttler: Arc<TriggerThrottler>,
+	/// Matches waiting for the aggregation window of their trigger to end
+	aggregator: MatchAggregator,
+	/// Notifications that failed to be sent, waiting to be sent again
+	retry_queue: Arc<NotificationRetryQueue>,
+	/// Escalations of the matches waiting for an acknowledgement
+	escalations: EscalationManager,
+	/// Incidents of the matches, with their state
+	lifecycle: MatchLifecycle,
 }
 
 impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
@@ -58,10 +78,200 @@ impl<T: TriggerRepositoryTrait> TriggerExecutionService<T> {
 		trigger_service: TriggerService<T>,
 		notification_service: NotificationService,
 	) -> Self {
+		// Email digests failing to be sent are retried with the other notifications
+		let retry_queue = Arc::new(NotificationRetryQueue::default());
+		notification_service
+			.email_digests()
+			.set_retry_queue(retry_queue.clone());
+
 		Self {
 			trigger_service,
+			aggregator: MatchAggregator::new(notification_service.clone()),
+			escalations: EscalationManager::new(notification_service.clone()),
 			notification_service,
+			throttler: Arc::new(TriggerThrottler::new()),
+			retry_queue,
+			lifecycle: MatchLifecycle::default(),
+		}
+	}
+
+	/// Returns the service used to send notifications
+	pub fn notification_service(&self) -> &NotificationService {
+		&self.notification_service
+	}
+
+	/// Returns the aggregator of the matches, e.g. to flush it on shutdown
+	pub fn aggregator(&self) -> MatchAggregator {
+		self.aggregator.clone()
+	}
+
+	/// Returns the manager of the escalations, e.g. to acknowledge them
+	pub fn escalations(&self) -> EscalationManager {
+		self.escalations.clone()
+	}
+
+	/// Returns the incidents of the matches, e.g. to acknowledge them
+	pub fn lifecycle(&self) -> MatchLifecycle {
+		self.lifecycle.clone()
+	}
+
+	/// Sets the base URL the acknowledgement callbacks of escalations and incidents are
+	/// reachable at
+	pub fn set_callback_url(&self, callback_url: &str) {
+		self.escalations.set_callback_url(callback_url);
+		self.lifecycle.set_callback_url(callback_url);
+	}
+
+	/// Returns the queue of the notifications waiting to be sent again, e.g. to persist it
+	pub fn retry_queue(&self) -> Arc<NotificationRetryQueue> {
+		self.retry_queue.clone()
+	}
+
+	/// Sends again the queued notifications due for a retry
+	///
+	/// Notifications whose trigger no longer exists are failed without being sent.
+	pub async fn retry_pending(&self) {
+		for entry in self.retry_queue.due().await {
+			let Some(trigger) = self.trigger_service.get(&entry.trigger_slug) else {
+				self.retry_queue
+					.fail(
+						&entry.id,
+						format!("Trigger {} not found", entry.trigger_slug),
+					)
+					.await;
+				continue;
+			};
+
+			let result = match (&entry.digest, &entry.monitor_match) {
+				(Some(messages), _) => {
+					self.notification_service
+						.send_digest(&trigger, messages)
+						.await
+				}
+				(None, Some(monitor_match)) => {
+					self.notification_service
+						.execute(
+							&entry.trigger_slug,
+							&trigger,
+							&entry.variables,
+							monitor_match,
+							&HashMap::new(),
+						)
+						.await
+				}
+				(None, None) => Err(NotificationError::config_error(
+					"Queued notification has no match".to_string(),
+					None,
+					None,
+				)),
+			};
+			match result {
+				Ok(()) => {
+					tracing::info!(
+						"Notification of trigger {} sent after {} retries",
+						entry.trigger_slug,
+						entry.attempts + 1
+					);
+					self.retry_queue.complete(&entry.id).await;
+				}
+				Err(e) => self.retry_queue.fail(&entry.id, e.to_string()).await,
+			}
+		}
+	}
+
+	/// Stops the escalation of an incident and resolves the alerts the triggers of the
+	/// incident raised for its matches
+	///
+	/// # Arguments
+	/// * `incident` - The resolved incident
+	pub async fn resolve_incident(&self, incident: &Incident) {
+		self.escalations.remove(&incident.id);
+		for trigger_slug in &incident.triggers {
+			let Some(trigger) = self.trigger_service.get(trigger_slug) else {
+				continue;
+			};
+			if let Err(e) = self
+				.notification_service
+				.resolve(&trigger, &incident.id)
+				.await
+			{
+				tracing::error!(
+					"Failed to resolve the alert of trigger {} for incident {}: {}",
+					trigger.name,
+					incident.id,
+					e
+				);
+			}
+		}
+	}
+
+	/// Sends the summary of the notifications of a trigger suppressed over a throttle window
+	/// once the window elapses
+	///
+	/// The summary replaces the message body of the trigger and is sent with the variables and
+	/// match of the first suppressed notification. Script triggers are not summarized.
+	///
+	/// # Arguments
+	/// * `trigger_slug` - Slug of the throttled trigger
+	/// * `trigger` - Throttled trigger
+	/// * `variables` - Variables of the first suppressed notification
+	/// * `monitor_match` - Match of the first suppressed notification
+	/// * `summary` - Summary of the suppressed notifications
+	fn schedule_summary(
+		&self,
+		trigger_slug: &str,
+		trigger: &Trigger,
+		variables: &HashMap<String, String>,
+		monitor_match: &MonitorMatch,
+		summary: PendingSummary,
+	) {
+		let mut trigger = trigger.clone();
+		if trigger.config.message_mut().is_none() {
+			return;
 		}
+		let trigger_slug = trigger_slug.to_string();
+		let variables = variables.clone();
+		let monitor_match = monitor_match.clone();
+		let notification_service = self.notification_service.clone();
+
+		tokio::spawn(async move {
+			tokio::time::sleep(summary.delay).await;
+
+			let window_secs = trigger.throttle.map_or(0, |throttle| throttle.window_secs);
+			let body = format!(
+				"{} notification(s) of monitor {} were suppressed by trigger {} over the last {} \
+				 seconds",
+				summary.suppressed(),
+				match_monitor(&monitor_match).name,
+				trigger.name,
+				window_secs
+			);
+			if let Some(message) = trigger.config.message_mut() {
+				message.body = body;
+			}
+
+			if let Err(e) = notification_service
+				.execute(
+					&trigger_slug,
+					&trigger,
+					&variables,
+					&monitor_match,
+					&HashMap::new(),
+				)
+				.await
+			{
+				tracing::error!("Failed to send throttle summary of {}: {}", trigger.name, e);
+			}
+		});
+	}
+}
+
+/// Returns the monitor of a match
+fn match_monitor(monitor_match: &MonitorMatch) -> &Monitor {
+	match monitor_match {
+		MonitorMatch::EVM(evm_match) => &evm_match.monitor,
+		MonitorMatch::Stellar(stellar_match) => &stellar_match.monitor,
+		MonitorMatch::Solana(solana_match) => &solana_match.monitor,
 	}
 }
 
@@ -90,17 +300,141 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 	) -> Result<(), TriggerError> {
 		use futures::future::join_all;
 
+		// Matches of acknowledged incidents are not notified again
+		let monitor = match_monitor(monitor_match);
+		let mut triggers = trigger_slugs.to_vec();
+		if let Some(escalation) = &monitor.escalation {
+			triggers.extend(escalation.iter().map(|step| step.trigger.clone()));
+		}
+		let incident = self.lifecycle.record(monitor_match, &triggers);
+		if incident.state == MatchState::Acknowledged {
+			tracing::info!(
+				"Match of acknowledged incident {} of monitor {} not notified",
+				incident.id,
+				incident.monitor_name
+			);
+			return Ok(());
+		}
+		let mut variables = variables;
+		variables.insert("match.id".to_string(), incident.id.clone());
+		variables.insert("match.state".to_string(), incident.state.to_string());
+		// Incidents can only be changed through their callback URLs while the server runs
+		if let (Some(ack_url), Some(resolve_url)) = (
+			self.lifecycle.ack_url(&incident),
+			self.lifecycle.resolve_url(&incident),
+		) {
+			variables.insert("match.ack_url".to_string(), ack_url);
+			variables.insert("match.resolve_url".to_string(), resolve_url);
+		}
+
+		// Monitors with an escalation fire its steps while the incident is not acknowledged, the
+		// matches of an incident being escalated once. Steps whose trigger is missing are skipped
+		// so that the regular triggers of the monitor still notify the match.
+		if let Some(escalation) = &monitor.escalation {
+			let steps = escalation
+				.iter()
+				.filter_map(|step| match self.trigger_service.get(&step.trigger) {
+					Some(trigger) => Some((
+						step.trigger.clone(),
+						trigger,
+						Duration::from_secs(step.after_secs),
+					)),
+					None => {
+						tracing::warn!(
+							"Escalation step trigger {} of monitor {} not found, step skipped",
+							step.trigger,
+							monitor.name
+						);
+						None
+					}
+				})
+				.collect::<Vec<_>>();
+			if self
+				.escalations
+				.start(&incident.id, steps, &variables, monitor_match)
+			{
+				tracing::info!(
+					"Escalation of incident {} started for monitor {}",
+					incident.id,
+					monitor.name
+				);
+			}
+		}
+
 		let futures = trigger_slugs.iter().map(|trigger_slug| async {
 			let trigger = self
 				.trigger_service
 				.get(trigger_slug)
 				.ok_or_else(|| TriggerError::not_found(trigger_slug.to_string(), None, None))?;
 
-			self.notification_service
-				.execute(&trigger, &variables, monitor_match, trigger_scripts)
-				.await
-				// We remove logging capability here since we're logging it further down
-				.map_err(|e| TriggerError::execution_error_without_log(e.to_string(), None, None))
+			// Triggers only notify the matches of monitors with one of their severities
+			let severity = match_monitor(monitor_match).severity();
+			if !trigger.accepts_severity(severity) {
+				tracing::debug!("Trigger {} skipped for {} severity", trigger.name, severity);
+				return Ok(());
+			}
+
+			// Aggregated matches are notified together once the window ends, without throttling
+			if let Some(aggregation) = &trigger.aggregation {
+				self.aggregator.push(
+					trigger_slug,
+					&trigger,
+					&variables,
+					monitor_match,
+					Duration::from_secs(aggregation.window_secs),
+				);
+				return Ok(());
+			}
+
+			if let Some(throttle) = &trigger.throttle {
+				let monitor_name = &match_monitor(monitor_match).name;
+				if let ThrottleDecision::Suppress(summary) =
+					self.throttler.check(&trigger.name, monitor_name, throttle)
+				{
+					tracing::warn!(
+						"Notification of trigger {} for monitor {} suppressed by throttle",
+						trigger.name,
+						monitor_name
+					);
+					if let Some(summary) = summary {
+						self.schedule_summary(
+							trigger_slug,
+							&trigger,
+							&variables,
+							monitor_match,
+							summary,
+						);
+					}
+					return Ok(());
+				}
+			}
+
+			let result = self
+				.notification_service
+				.execute(
+					trigger_slug,
+					&trigger,
+					&variables,
+					monitor_match,
+					trigger_scripts,
+				)
+				.await;
+
+			// Failed notifications are sent again later, except scripts which are not delivered
+			// to an endpoint
+			if let Err(e) = &result {
+				if !matches!(trigger.config, TriggerTypeConfig::Script { .. })
+					&& self
+						.retry_queue
+						.enqueue(trigger_slug, &variables, monitor_match, e.to_string())
+						.await
+				{
+					tracing::warn!("Notification of trigger {} queued for retry", trigger.name);
+				}
+			}
+
+			// We remove logging capability here since we're logging it further down
+			result.map_err(|e| TriggerError::execution_error_without_log(e.to_string(), None, None))
 		});
 
 		let results = join_all(futures).await;
@@ -151,7 +485,10 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 
 		for monitor in monitors {
 			// Skip monitors without trigger conditions
-			if monitor.trigger_conditions.is_empty() && monitor.triggers.is_empty() {
+			if monitor.trigger_conditions.is_empty()
+				&& monitor.triggers.is_empty()
+				&& monitor.trigger_routes.is_empty()
+			{
 				continue;
 			}
 
@@ -176,8 +513,12 @@ impl<T: TriggerRepositoryTrait + Send + Sync> TriggerExecutionServiceTrait
 				);
 			}
 
-			// For each trigger, we'll load the script
-			for trigger in &monitor.triggers {
+			// For each trigger, including the routed ones, we'll load the script
+			let routed_triggers = monitor
+				.trigger_routes
+				.iter()
+				.flat_map(|route| &route.triggers);
+			for trigger in monitor.triggers.iter().chain(routed_triggers) {
 				let trigger_config =
 					self.trigger_service.get(trigger.as_str()).ok_or_else(|| {
 						TriggerError::configuration_error(
@@ -0,0 +1,213 @@
+//! Trigger throttling.
+//!
+//! Limits the number of notifications sent by a trigger for each monitor over fixed windows,
+//! so that chain-wide incidents matching many transactions do not cause notification storms.
+//! The notifications suppressed over a window can be summarized once the window elapses.
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicU32, Ordering},
+		Arc, Mutex,
+	},
+	time::{Duration, Instant},
+};
+
+use crate::models::TriggerThrottle;
+
+/// Notifications sent and suppressed over the current window of a trigger and monitor
+#[derive(Debug)]
+struct ThrottleWindow {
+	started_at: Instant,
+	sent: u32,
+	suppressed: Arc<AtomicU32>,
+}
+
+/// Summary of the notifications suppressed over a window, to send once the window elapses
+#[derive(Debug, Clone)]
+pub struct PendingSummary {
+	/// Time remaining until the end of the window
+	pub delay: Duration,
+	/// Number of notifications suppressed over the window, updated until the window elapses
+	pub suppressed: Arc<AtomicU32>,
+}
+
+impl PendingSummary {
+	/// Returns the number of notifications suppressed over the window
+	pub fn suppressed(&self) -> u32 {
+		self.suppressed.load(Ordering::SeqCst)
+	}
+}
+
+/// Outcome of throttling a notification
+#[derive(Debug, Clone)]
+pub enum ThrottleDecision {
+	/// The notification can be sent
+	Send,
+	/// The notification is suppressed, with the summary to schedule on the first suppression
+	/// of a window summarizing its suppressed notifications
+	Suppress(Option<PendingSummary>),
+}
+
+/// Notification windows of the throttled triggers, by trigger and monitor name
+#[derive(Debug, Default)]
+pub struct TriggerThrottler {
+	windows: Mutex<HashMap<(String, String), ThrottleWindow>>,
+}
+
+impl TriggerThrottler {
+	/// Creates a throttler with no notification sent
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Records a notification of a trigger for a monitor
+	///
+	/// Windows start with the first notification sent after the previous window elapsed.
+	///
+	/// # Arguments
+	/// * `trigger_name` - Name of the trigger sending the notification
+	/// * `monitor_name` - Name of the monitor that matched
+	/// * `throttle` - Throttling policy of the trigger
+	///
+	/// # Returns
+	/// * `ThrottleDecision` - Whether the notification can be sent
+	pub fn check(
+		&self,
+		trigger_name: &str,
+		monitor_name: &str,
+		throttle: &TriggerThrottle,
+	) -> ThrottleDecision {
+		let now = Instant::now();
+		let window_duration = Duration::from_secs(throttle.window_secs);
+		let mut windows = self.windows.lock().unwrap();
+		let window = windows
+			.entry((trigger_name.to_string(), monitor_name.to_string()))
+			.or_insert_with(|| ThrottleWindow {
+				started_at: now,
+				sent: 0,
+				suppressed: Arc::new(AtomicU32::new(0)),
+			});
+
+		if now.duration_since(window.started_at) >= window_duration {
+			*window = ThrottleWindow {
+				started_at: now,
+				sent: 0,
+				suppressed: Arc::new(AtomicU32::new(0)),
+			};
+		}
+
+		if window.sent < throttle.max_notifications {
+			window.sent += 1;
+			return ThrottleDecision::Send;
+		}
+
+		let previously_suppressed = window.suppressed.fetch_add(1, Ordering::SeqCst);
+		if previously_suppressed > 0 || !throttle.summarize.unwrap_or(true) {
+			return ThrottleDecision::Suppress(None);
+		}
+		ThrottleDecision::Suppress(Some(PendingSummary {
+			delay: window_duration.saturating_sub(now.duration_since(window.started_at)),
+			suppressed: window.suppressed.clone(),
+		}))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn throttle(
+		max_notifications: u32,
+		window_secs: u64,
+		summarize: Option<bool>,
+	) -> TriggerThrottle {
+		TriggerThrottle {
+			max_notifications,
+			window_secs,
+			summarize,
+		}
+	}
+
+	#[test]
+	fn test_check_limits_by_trigger_and_monitor() {
+		let throttler = TriggerThrottler::new();
+		let throttle = throttle(2, 300, None);
+
+		assert!(matches!(
+			throttler.check("trigger", "monitor_a", &throttle),
+			ThrottleDecision::Send
+		));
+		assert!(matches!(
+			throttler.check("trigger", "monitor_a", &throttle),
+			ThrottleDecision::Send
+		));
+		assert!(matches!(
+			throttler.check("trigger", "monitor_a", &throttle),
+			ThrottleDecision::Suppress(_)
+		));
+
+		// Other monitors and triggers have their own windows
+		assert!(matches!(
+			throttler.check("trigger", "monitor_b", &throttle),
+			ThrottleDecision::Send
+		));
+		assert!(matches!(
+			throttler.check("other_trigger", "monitor_a", &throttle),
+			ThrottleDecision::Send
+		));
+	}
+
+	#[test]
+	fn test_check_schedules_summary_on_first_suppression() {
+		let throttler = TriggerThrottler::new();
+		let throttle = throttle(1, 300, None);
+
+		throttler.check("trigger", "monitor", &throttle);
+		let ThrottleDecision::Suppress(Some(summary)) =
+			throttler.check("trigger", "monitor", &throttle)
+		else {
+			panic!("expected a summary on the first suppression");
+		};
+		assert!(summary.delay <= Duration::from_secs(300));
+
+		assert!(matches!(
+			throttler.check("trigger", "monitor", &throttle),
+			ThrottleDecision::Suppress(None)
+		));
+		assert_eq!(summary.suppressed(), 2);
+	}
+
+	#[test]
+	fn test_check_without_summary() {
+		let throttler = TriggerThrottler::new();
+		let throttle = throttle(1, 300, Some(false));
+
+		throttler.check("trigger", "monitor", &throttle);
+		assert!(matches!(
+			throttler.check("trigger", "monitor", &throttle),
+			ThrottleDecision::Suppress(None)
+		));
+	}
+
+	#[test]
+	fn test_check_after_window() {
+		let throttler = TriggerThrottler::new();
+		let throttle = throttle(1, 1, None);
+
+		throttler.check("trigger", "monitor", &throttle);
+		let ThrottleDecision::Suppress(Some(summary)) =
+			throttler.check("trigger", "monitor", &throttle)
+		else {
+			panic!("expected a summary on the first suppression");
+		};
+
+		std::thread::sleep(Duration::from_millis(1100));
+		assert!(matches!(
+			throttler.check("trigger", "monitor", &throttle),
+			ThrottleDecision::Send
+		));
+		// Notifications of the new window are not counted in the previous summary
+		assert_eq!(summary.suppressed(), 1);
+	}
+}
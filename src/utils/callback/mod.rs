@@ -0,0 +1,6 @@
+//! Callback module for the application.
+//!
+//! - This module contains the HTTP server receiving the acknowledgements of escalated matches
+//!   and incidents.
+
+pub mod server;
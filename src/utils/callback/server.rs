@@ -0,0 +1,318 @@
+//! Callback server module
+//!
+//! This module provides an HTTP server receiving the acknowledgements of escalated matches and
+//! the acknowledgements and resolutions of incidents, through the callback URLs included in
+//! their notifications.
+//!
+//! State changes are only made by `POST` requests carrying the token of the callback URL.
+//! Opening a callback URL from a message only shows a page confirming the action, so that
+//! link previews and crawlers do not acknowledge anything.
+
+use actix_web::middleware::{DefaultHeaders, NormalizePath};
+use actix_web::{web, App, HttpResponse, HttpServer, Responder};
+use serde::Deserialize;
+use tracing::info;
+
+use crate::services::trigger::{EscalationManager, MatchLifecycle};
+
+/// Page confirming the action of a callback URL opened in a browser
+///
+/// The form posts to the URL of the page, token included.
+const CONFIRMATION_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>OpenZeppelin Monitor</title></head>
+<body>
+<form method="post" action=""><button type="submit">Confirm</button></form>
+</body>
+</html>
+"#;
+
+/// Query of the callback URLs
+#[derive(Debug, Deserialize)]
+struct CallbackQuery {
+	/// Token of the callback URL
+	token: Option<String>,
+}
+
+/// Confirmation endpoint handler, answering the GET requests of the callback URLs
+async fn confirmation_handler() -> impl Responder {
+	HttpResponse::Ok()
+		.content_type("text/html; charset=utf-8")
+		.body(CONFIRMATION_PAGE)
+}
+
+/// Acknowledgement endpoint handler
+async fn acknowledge_handler(
+	escalations: web::Data<EscalationManager>,
+	id: web::Path<String>,
+	query: web::Query<CallbackQuery>,
+) -> impl Responder {
+	if escalations.acknowledge(&id, query.token.as_deref().unwrap_or_default()) {
+		info!("Escalation {} acknowledged", id);
+		HttpResponse::Ok().body(format!("Escalation {} acknowledged", id))
+	} else {
+		HttpResponse::NotFound().body(format!(
+			"Escalation {} is unknown, already acknowledged or completed, or the token is invalid",
+			id
+		))
+	}
+}
+
+/// Incident endpoint handler, returning the state of an incident
+async fn incident_handler(
+	lifecycle: web::Data<MatchLifecycle>,
+	id: web::Path<String>,
+) -> impl Responder {
+	match lifecycle.get(&id) {
+		Some(incident) => HttpResponse::Ok().json(incident),
+		None => HttpResponse::NotFound().body(format!("Incident {} is unknown", id)),
+	}
+}
+
+/// Incident acknowledgement endpoint handler, stopping the escalation of the incident
+async fn acknowledge_incident_handler(
+	lifecycle: web::Data<MatchLifecycle>,
+	escalations: web::Data<EscalationManager>,
+	id: web::Path<String>,
+	query: web::Query<CallbackQuery>,
+) -> impl Responder {
+	if lifecycle.acknowledge(&id, query.token.as_deref().unwrap_or_default()) {
+		escalations.remove(&id);
+		info!("Incident {} acknowledged", id);
+		HttpResponse::Ok().body(format!("Incident {} acknowledged", id))
+	} else {
+		HttpResponse::NotFound().body(format!(
+			"Incident {} is unknown, already acknowledged or resolved, or the token is invalid",
+			id
+		))
+	}
+}
+
+/// Incident resolution endpoint handler
+async fn resolve_incident_handler(
+	lifecycle: web::Data<MatchLifecycle>,
+	id: web::Path<String>,
+	query: web::Query<CallbackQuery>,
+) -> impl Responder {
+	if lifecycle.resolve(&id, query.token.as_deref().unwrap_or_default()) {
+		info!("Incident {} resolved", id);
+		HttpResponse::Ok().body(format!("Incident {} resolved", id))
+	} else {
+		HttpResponse::NotFound().body(format!(
+			"Incident {} is unknown or already resolved, or the token is invalid",
+			id
+		))
+	}
+}
+
+// Create callback server
+pub fn create_callback_server(
+	bind_address: String,
+	escalations: EscalationManager,
+	lifecycle: MatchLifecycle,
+) -> std::io::Result<actix_web::dev::Server> {
+	info!("Starting callback server on {}", bind_address);
+
+	Ok(HttpServer::new(move || {
+		App::new()
+			.wrap(NormalizePath::trim())
+			.wrap(DefaultHeaders::new())
+			.app_data(web::Data::new(escalations.clone()))
+			.app_data(web::Data::new(lifecycle.clone()))
+			.route(
+				"/escalations/{id}/acknowledge",
+				web::get().to(confirmation_handler),
+			)
+			.route(
+				"/escalations/{id}/acknowledge",
+				web::post().to(acknowledge_handler),
+			)
+			.route("/matches/{id}", web::get().to(incident_handler))
+			.route(
+				"/matches/{id}/acknowledge",
+				web::get().to(confirmation_handler),
+			)
+			.route(
+				"/matches/{id}/acknowledge",
+				web::post().to(acknowledge_incident_handler),
+			)
+			.route("/matches/{id}/resolve", web::get().to(confirmation_handler))
+			.route(
+				"/matches/{id}/resolve",
+				web::post().to(resolve_incident_handler),
+			)
+	})
+	.workers(1)
+	.bind(bind_address)?
+	.shutdown_timeout(5)
+	.run())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{MatchConditions, MonitorMatch, SolanaMonitorMatch},
+		services::{notification::NotificationService, trigger::MatchState},
+		utils::tests::{
+			builders::trigger::TriggerBuilder,
+			solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		},
+	};
+	use actix_web::test;
+	use std::{collections::HashMap, time::Duration};
+
+	#[actix_web::test]
+	async fn test_acknowledge_handler() {
+		let escalations = EscalationManager::new(NotificationService::new());
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().build(),
+		)));
+		let trigger = TriggerBuilder::new()
+			.name("pagerduty")
+			.pagerduty("R0123456789ABCDEF0123456789ABCDEF")
+			.build();
+		assert!(escalations.start(
+			"incident",
+			vec![("pagerduty".to_string(), trigger, Duration::from_secs(600))],
+			&HashMap::new(),
+			&monitor_match,
+		));
+		let token = escalations.token("incident").unwrap();
+
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(escalations.clone()))
+				.route(
+					"/escalations/{id}/acknowledge",
+					web::get().to(confirmation_handler),
+				)
+				.route(
+					"/escalations/{id}/acknowledge",
+					web::post().to(acknowledge_handler),
+				),
+		)
+		.await;
+
+		// Opening the callback URL does not acknowledge the escalation
+		let uri = format!("/escalations/incident/acknowledge?token={}", token);
+		let resp = test::call_service(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+		assert!(resp.status().is_success());
+		assert!(escalations.is_pending("incident"));
+
+		// Nor does a request without the token of the escalation
+		for invalid_uri in [
+			"/escalations/incident/acknowledge",
+			"/escalations/incident/acknowledge?token=invalid",
+		] {
+			let resp = test::call_service(
+				&app,
+				test::TestRequest::post().uri(invalid_uri).to_request(),
+			)
+			.await;
+			assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+			assert!(escalations.is_pending("incident"));
+		}
+
+		let resp = test::call_service(&app, test::TestRequest::post().uri(&uri).to_request()).await;
+		assert!(resp.status().is_success());
+		assert!(!escalations.is_pending("incident"));
+
+		// Acknowledging twice is reported as unknown
+		let resp = test::call_service(&app, test::TestRequest::post().uri(&uri).to_request()).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+	}
+
+	#[actix_web::test]
+	async fn test_incident_handlers() {
+		let lifecycle = MatchLifecycle::default();
+		let monitor_match = MonitorMatch::Solana(Box::new(SolanaMonitorMatch::new(
+			MonitorBuilder::new().name("monitor").build(),
+			"solana_mainnet".to_string(),
+			MatchConditions::default(),
+			None,
+			TransactionBuilder::new().build(),
+		)));
+		let incident = lifecycle.record(&monitor_match, &[]);
+		let escalations = EscalationManager::new(NotificationService::new());
+		let trigger = TriggerBuilder::new()
+			.name("pagerduty")
+			.pagerduty("R0123456789ABCDEF0123456789ABCDEF")
+			.build();
+		assert!(escalations.start(
+			&incident.id,
+			vec![("pagerduty".to_string(), trigger, Duration::from_secs(600))],
+			&HashMap::new(),
+			&monitor_match,
+		));
+		assert!(escalations.is_pending(&incident.id));
+
+		let app = test::init_service(
+			App::new()
+				.app_data(web::Data::new(lifecycle.clone()))
+				.app_data(web::Data::new(escalations.clone()))
+				.route("/matches/{id}", web::get().to(incident_handler))
+				.route(
+					"/matches/{id}/acknowledge",
+					web::get().to(confirmation_handler),
+				)
+				.route(
+					"/matches/{id}/acknowledge",
+					web::post().to(acknowledge_incident_handler),
+				)
+				.route(
+					"/matches/{id}/resolve",
+					web::post().to(resolve_incident_handler),
+				),
+		)
+		.await;
+
+		// Opening the callback URL, or posting without the token, does not acknowledge it
+		let uri = format!(
+			"/matches/{}/acknowledge?token={}",
+			incident.id, incident.token
+		);
+		let resp = test::call_service(&app, test::TestRequest::get().uri(&uri).to_request()).await;
+		assert!(resp.status().is_success());
+		let invalid_uri = format!("/matches/{}/acknowledge?token=invalid", incident.id);
+		let resp = test::call_service(
+			&app,
+			test::TestRequest::post().uri(&invalid_uri).to_request(),
+		)
+		.await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+		assert_eq!(lifecycle.get(&incident.id).unwrap().state, MatchState::Open);
+
+		// Acknowledging the incident stops its escalation
+		let resp = test::call_service(&app, test::TestRequest::post().uri(&uri).to_request()).await;
+		assert!(resp.status().is_success());
+		assert!(!escalations.is_pending(&incident.id));
+
+		let uri = format!("/matches/{}", incident.id);
+		let resp: serde_json::Value =
+			test::call_and_read_body_json(&app, test::TestRequest::get().uri(&uri).to_request())
+				.await;
+		assert_eq!(resp["state"], "acknowledged");
+		assert_eq!(resp["monitor_name"], "monitor");
+		assert!(resp.get("token").is_none());
+
+		let uri = format!("/matches/{}/resolve?token={}", incident.id, incident.token);
+		let resp = test::call_service(&app, test::TestRequest::post().uri(&uri).to_request()).await;
+		assert!(resp.status().is_success());
+		let resp = test::call_service(&app, test::TestRequest::post().uri(&uri).to_request()).await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+		let resp = test::call_service(
+			&app,
+			test::TestRequest::get()
+				.uri("/matches/unknown")
+				.to_request(),
+		)
+		.await;
+		assert_eq!(resp.status(), actix_web::http::StatusCode::NOT_FOUND);
+	}
+}
@@ -0,0 +1,37 @@
+//! Utility functions for working with files
+//!
+//! This module provides helpers for persisting state to files.
+
+use std::path::Path;
+
+/// Writes a file through a temporary file renamed over it
+///
+/// A crash while writing leaves the previous content of the file intact.
+///
+/// # Arguments
+/// * `path` - File to write
+/// * `content` - Content of the file
+pub async fn write_atomically(path: &Path, content: String) -> std::io::Result<()> {
+	let mut temp_path = path.as_os_str().to_owned();
+	temp_path.push(".tmp");
+	tokio::fs::write(&temp_path, content).await?;
+	tokio::fs::rename(&temp_path, path).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tempfile::TempDir;
+
+	#[tokio::test]
+	async fn test_write_atomically_replaces_file() {
+		let temp_dir = TempDir::new().unwrap();
+		let path = temp_dir.path().join("state.json");
+
+		write_atomically(&path, "first".to_string()).await.unwrap();
+		write_atomically(&path, "second".to_string()).await.unwrap();
+
+		assert_eq!(tokio::fs::read_to_string(&path).await.unwrap(), "second");
+		assert!(!temp_dir.path().join("state.json.tmp").exists());
+	}
+}
@@ -5,7 +5,10 @@
 
 pub mod server;
 use lazy_static::lazy_static;
-use prometheus::{Encoder, Gauge, GaugeVec, Opts, Registry, TextEncoder};
+use prometheus::{
+	Encoder, Gauge, GaugeVec, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec,
+	Opts, Registry, TextEncoder,
+};
 use sysinfo::{Disks, System};
 
 lazy_static! {
@@ -134,6 +137,83 @@ lazy_static! {
 		REGISTRY.register(Box::new(gauge.clone())).unwrap();
 		gauge
 	};
+
+	/// Gauge Vector for the slot lag of Solana networks.
+	///
+	/// Tracks the number of slots between the chain tip and the last processed slot, with the
+	/// network slug as a label.
+	pub static ref SOLANA_SLOT_LAG: GaugeVec = {
+		let gauge = GaugeVec::new(
+			Opts::new("solana_slot_lag", "Number of slots between the chain tip and the last processed slot"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter Vector for the Solana transactions skipped by the filter.
+	///
+	/// Counts the malformed transactions that could not be matched against monitors, with the
+	/// network slug as a label.
+	pub static ref SOLANA_TRANSACTION_ERRORS: IntCounterVec = {
+		let counter = IntCounterVec::new(
+			Opts::new("solana_transaction_errors_total", "Number of Solana transactions skipped because they are malformed"),
+			&["network"]
+		).unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Histogram Vector for RPC request latencies.
+	///
+	/// Tracks the duration of RPC requests in seconds, with the network slug and the RPC
+	/// method as labels.
+	pub static ref RPC_REQUEST_DURATION_SECONDS: HistogramVec = {
+		let histogram = HistogramVec::new(
+			HistogramOpts::new("rpc_request_duration_seconds", "Duration of RPC requests in seconds"),
+			&["network", "method"]
+		).unwrap();
+		REGISTRY.register(Box::new(histogram.clone())).unwrap();
+		histogram
+	};
+
+	/// Gauge for the matches waiting for a trigger execution slot.
+	///
+	/// Grows when notification delivery lags block processing.
+	pub static ref TRIGGER_QUEUE_DEPTH: Gauge = {
+		let gauge = Gauge::new("trigger_queue_depth", "Number of matches waiting for their triggers to be executed").unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Gauge for the matches whose triggers are being executed.
+	///
+	/// Bounded by the trigger execution concurrency.
+	pub static ref TRIGGER_EXECUTIONS_IN_FLIGHT: Gauge = {
+		let gauge = Gauge::new("trigger_executions_in_flight", "Number of matches whose triggers are being executed").unwrap();
+		REGISTRY.register(Box::new(gauge.clone())).unwrap();
+		gauge
+	};
+
+	/// Counter for the matches held back because the trigger queue was full.
+	///
+	/// Counts the times block processing had to wait for notification delivery.
+	pub static ref TRIGGER_QUEUE_FULL: IntCounter = {
+		let counter = IntCounter::new("trigger_queue_full_total", "Number of matches held back because the trigger queue was full").unwrap();
+		REGISTRY.register(Box::new(counter.clone())).unwrap();
+		counter
+	};
+
+	/// Histogram for the time matches wait before their triggers are executed.
+	///
+	/// Tracks the delay in seconds between queueing a match and executing its triggers.
+	pub static ref TRIGGER_QUEUE_WAIT_SECONDS: Histogram = {
+		let histogram = Histogram::with_opts(
+			HistogramOpts::new("trigger_queue_wait_seconds", "Time matches wait before their triggers are executed in seconds")
+		).unwrap();
+		REGISTRY.register(Box::new(histogram.clone())).unwrap();
+		histogram
+	};
 }
 
 /// Gather all metrics and encode into the provided format.
@@ -294,6 +374,9 @@ mod tests {
 		CONTRACTS_MONITORED.set(0.0);
 		NETWORKS_MONITORED.set(0.0);
 		NETWORK_MONITORS.reset();
+		SOLANA_SLOT_LAG.reset();
+		SOLANA_TRANSACTION_ERRORS.reset();
+		RPC_REQUEST_DURATION_SECONDS.reset();
 	}
 
 	// Helper function to create a test network
@@ -362,6 +445,16 @@ mod tests {
 		CONTRACTS_MONITORED.set(4.0);
 		NETWORKS_MONITORED.set(2.0);
 		NETWORK_MONITORS.with_label_values(&["test"]).set(1.0);
+		SOLANA_SLOT_LAG.with_label_values(&["test"]).set(10.0);
+		SOLANA_TRANSACTION_ERRORS.with_label_values(&["test"]).inc();
+		RPC_REQUEST_DURATION_SECONDS
+			.with_label_values(&["test", "getSlot"])
+			.observe(0.1);
+		// Trigger queue metrics are shared with the dispatcher tests, so they are only registered
+		lazy_static::initialize(&TRIGGER_QUEUE_DEPTH);
+		lazy_static::initialize(&TRIGGER_EXECUTIONS_IN_FLIGHT);
+		lazy_static::initialize(&TRIGGER_QUEUE_FULL);
+		lazy_static::initialize(&TRIGGER_QUEUE_WAIT_SECONDS);
 
 		let metrics = gather_metrics().expect("failed to gather metrics");
 		let output = String::from_utf8(metrics).expect("metrics output is not valid UTF-8");
@@ -382,6 +475,13 @@ mod tests {
 		assert!(output.contains("contracts_monitored"));
 		assert!(output.contains("networks_monitored"));
 		assert!(output.contains("network_monitors"));
+		assert!(output.contains("solana_slot_lag"));
+		assert!(output.contains("solana_transaction_errors_total"));
+		assert!(output.contains("rpc_request_duration_seconds"));
+		assert!(output.contains("trigger_queue_depth"));
+		assert!(output.contains("trigger_executions_in_flight"));
+		assert!(output.contains("trigger_queue_full_total"));
+		assert!(output.contains("trigger_queue_wait_seconds"));
 	}
 
 	#[test]
@@ -3,9 +3,10 @@
 //! This module provides various utility functions and types that are used across
 //! the application. Currently includes:
 //!
+//! - callback: Callback server for the acknowledgements of escalated matches and incidents
 //! - constants: Constants for the application
 //! - cron_utils: Utilities for working with cron schedules and time intervals
-//! - expression: Utilities for working with cron expressions
+//! - fs: Utilities for persisting state to files
 //! - logging: Logging utilities
 //! - macros: Macros for common functionality
 //! - metrics: Metrics utilities
@@ -15,8 +16,9 @@
 //! - http: HTTP client utilities (i.e. creation retryable HTTP clients)
 
 mod cron_utils;
-mod expression;
+mod fs;
 
+pub mod callback;
 pub mod constants;
 pub mod http;
 pub mod logging;
@@ -28,7 +30,7 @@ pub mod tests;
 
 pub use constants::*;
 pub use cron_utils::*;
-pub use expression::*;
+pub use fs::*;
 pub use http::*;
 pub use macros::*;
 pub use parsing::*;
@@ -0,0 +1,151 @@
+//! Backfill monitor module
+//!
+//! This module provides functionality to match a Solana monitor against the history of the
+//! addresses it watches over a range of slots, to recover the matches of a period the monitor
+//! was not running, e.g. after an outage. Matches are reported without executing triggers.
+use crate::{
+	models::MonitorMatch,
+	repositories::{
+		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
+		TriggerRepositoryTrait,
+	},
+	services::{
+		blockchain::{SolanaClient, SolanaClientTrait, SolanaTransportClient},
+		filter::SolanaBlockFilter,
+	},
+	utils::monitor::{
+		dry_run::connect_solana_network, execution::ExecutionResult, MonitorExecutionError,
+	},
+};
+use std::{path::Path, sync::Arc};
+use tokio::sync::Mutex;
+use tracing::instrument;
+
+/// Configuration for backfilling a monitor over a range of slots
+///
+/// # Arguments
+///
+/// * `path` - The path to the monitor to backfill
+/// * `network_slug` - The network to backfill, defaults to the first Solana network of the
+///   monitor
+/// * `start_slot` - First slot to backfill
+/// * `end_slot` - Last slot to backfill, defaults to the latest slot of the network
+/// * `monitor_service` - The monitor service to use
+/// * `network_service` - The network service to use
+pub struct BackfillConfig<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+> {
+	pub path: String,
+	pub network_slug: Option<String>,
+	pub start_slot: u64,
+	pub end_slot: Option<u64>,
+	pub monitor_service: Arc<Mutex<MonitorService<M, N, TR>>>,
+	pub network_service: Arc<Mutex<NetworkService<N>>>,
+}
+
+/// Matches a monitor against the history of its addresses over a range of slots
+///
+/// # Arguments
+///
+/// * `config` - Configuration of the backfill
+///
+/// # Returns
+/// * `Result<Vec<MonitorMatch>, ExecutionError>` - The matches of the monitor, oldest first,
+///   or error
+#[instrument(skip_all, fields(start_slot = config.start_slot, end_slot = ?config.end_slot))]
+pub async fn backfill_monitor<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+>(
+	config: BackfillConfig<M, N, TR>,
+) -> ExecutionResult<Vec<MonitorMatch>> {
+	if let Some(end_slot) = config.end_slot {
+		validate_slot_range(config.start_slot, end_slot)?;
+	}
+
+	tracing::debug!("Loading monitor configuration");
+	let mut monitor = config
+		.monitor_service
+		.lock()
+		.await
+		.load_from_path(Some(Path::new(&config.path)), None, None)
+		.await
+		.map_err(|e| MonitorExecutionError::execution_error(e.to_string(), None, None))?;
+
+	let (network, client) = connect_solana_network(
+		&config.network_service,
+		config.network_slug.as_deref(),
+		&mut monitor,
+	)
+	.await?;
+
+	let end_slot = match config.end_slot {
+		Some(end_slot) => end_slot,
+		None => {
+			let latest_slot = client.get_latest_slot().await.map_err(|e| {
+				MonitorExecutionError::execution_error(
+					format!("Failed to get the latest slot: {}", e),
+					None,
+					None,
+				)
+			})?;
+			validate_slot_range(config.start_slot, latest_slot)?;
+			latest_slot
+		}
+	};
+
+	tracing::debug!(end_slot, "Backfilling monitor");
+	let filter = SolanaBlockFilter::<SolanaClient<SolanaTransportClient>>::new();
+	filter
+		.backfill(
+			&client,
+			&network,
+			std::slice::from_ref(&monitor),
+			None,
+			config.start_slot,
+			end_slot,
+		)
+		.await
+		.map_err(|e| {
+			MonitorExecutionError::execution_error(
+				format!(
+					"Failed to backfill slots {} to {}",
+					config.start_slot, end_slot
+				),
+				Some(e.into()),
+				None,
+			)
+		})
+}
+
+/// Checks that a slot range is not empty
+#[allow(clippy::result_large_err)]
+fn validate_slot_range(start_slot: u64, end_slot: u64) -> ExecutionResult<()> {
+	if start_slot > end_slot {
+		return Err(MonitorExecutionError::execution_error(
+			format!("Start slot {} is after end slot {}", start_slot, end_slot),
+			None,
+			None,
+		));
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_slot_range() {
+		assert!(validate_slot_range(10, 10).is_ok());
+		assert!(validate_slot_range(10, 20).is_ok());
+
+		let error = validate_slot_range(20, 10).unwrap_err();
+		assert!(error
+			.to_string()
+			.contains("Start slot 20 is after end slot 10"));
+	}
+}
@@ -0,0 +1,709 @@
+//! Dry-run monitor module
+//!
+//! This module provides functionality to evaluate the conditions of a monitor against a single
+//! Solana transaction, reporting the outcome of each condition along with the parameters
+//! extracted from the transaction. It is meant for debugging monitors that do not fire.
+use crate::{
+	bootstrap::get_solana_idl_specs,
+	models::{
+		BlockChainType, Monitor, Network, SolanaMatchArguments, SolanaMatchParamEntry,
+		SolanaTransaction, TransactionStatus,
+	},
+	repositories::{
+		MonitorRepositoryTrait, MonitorService, NetworkRepositoryTrait, NetworkService,
+		TriggerRepositoryTrait,
+	},
+	services::{
+		blockchain::{SolanaClient, SolanaClientTrait, SolanaTransportClient},
+		filter::{
+			solana_helpers::{are_same_instruction, normalize_pubkey},
+			SolanaBlockFilter, SolanaDecodedTransaction,
+		},
+	},
+	utils::monitor::{execution::ExecutionResult, MonitorExecutionError},
+};
+use serde::Serialize;
+use serde_json::Value;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::{fmt, path::Path, str::FromStr, sync::Arc};
+use tokio::sync::Mutex;
+use tracing::instrument;
+
+/// Source of the transaction a monitor is evaluated against
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransactionSource {
+	/// Signature of a transaction to fetch from the network
+	Signature(String),
+	/// Path to a JSON fixture holding a `getTransaction` RPC response or its result
+	Fixture(String),
+}
+
+/// Configuration for evaluating a monitor against a single transaction
+///
+/// # Arguments
+///
+/// * `path` - The path to the monitor to evaluate
+/// * `source` - The transaction to evaluate the monitor against
+/// * `network_slug` - The network to fetch the transaction from, defaults to the first Solana
+///   network of the monitor
+/// * `monitor_service` - The monitor service to use
+/// * `network_service` - The network service to use
+pub struct DryRunConfig<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+> {
+	pub path: String,
+	pub source: TransactionSource,
+	pub network_slug: Option<String>,
+	pub monitor_service: Arc<Mutex<MonitorService<M, N, TR>>>,
+	pub network_service: Arc<Mutex<NetworkService<N>>>,
+}
+
+/// Kind of a monitor condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionKind {
+	Function,
+	Transaction,
+	BalanceChange,
+	Log,
+	Sequence,
+}
+
+impl fmt::Display for ConditionKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Function => write!(f, "Function"),
+			Self::Transaction => write!(f, "Transaction"),
+			Self::BalanceChange => write!(f, "Balance change"),
+			Self::Log => write!(f, "Log"),
+			Self::Sequence => write!(f, "Sequence"),
+		}
+	}
+}
+
+/// Outcome of a single monitor condition
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConditionOutcome {
+	/// Kind of the condition
+	pub kind: ConditionKind,
+	/// Instruction signature, transaction status or `*` for balance changes
+	pub target: String,
+	/// Expression of the condition, if any
+	pub expression: Option<String>,
+	/// Whether the condition matched the transaction
+	pub matched: bool,
+	/// Why the condition did not match, when known
+	pub reason: Option<String>,
+}
+
+/// Parameters extracted from the transaction
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExtractedParams {
+	/// Kind of the conditions the parameters are evaluated against
+	pub kind: ConditionKind,
+	/// Instruction signature, `transaction` or mint of the balance change
+	pub signature: String,
+	/// The parameters
+	pub params: Vec<SolanaMatchParamEntry>,
+}
+
+/// Result of evaluating a monitor against a single transaction
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DryRunReport {
+	/// Name of the monitor
+	pub monitor: String,
+	/// Signature of the transaction
+	pub signature: String,
+	/// Slot of the transaction
+	pub slot: u64,
+	/// Whether the monitor matches the transaction as a whole
+	pub matched: bool,
+	/// Outcome of each condition of the monitor
+	pub conditions: Vec<ConditionOutcome>,
+	/// Parameters extracted from the transaction
+	pub params: Vec<ExtractedParams>,
+	/// Number of account conditions, which cannot be evaluated against a transaction
+	pub skipped_account_conditions: usize,
+}
+
+/// Evaluates a monitor against a single transaction fetched from the network or loaded from
+/// a fixture.
+///
+/// # Arguments
+///
+/// * `config` - Configuration of the dry run
+///
+/// # Returns
+/// * `Result<DryRunReport, ExecutionError>` - The outcome of each condition or error
+#[instrument(skip_all)]
+pub async fn dry_run_monitor<
+	M: MonitorRepositoryTrait<N, TR>,
+	N: NetworkRepositoryTrait + Send + Sync + 'static,
+	TR: TriggerRepositoryTrait + Send + Sync + 'static,
+>(
+	config: DryRunConfig<M, N, TR>,
+) -> ExecutionResult<DryRunReport> {
+	tracing::debug!("Loading monitor configuration");
+	let mut monitor = config
+		.monitor_service
+		.lock()
+		.await
+		.load_from_path(Some(Path::new(&config.path)), None, None)
+		.await
+		.map_err(|e| MonitorExecutionError::execution_error(e.to_string(), None, None))?;
+
+	let transaction = match &config.source {
+		TransactionSource::Fixture(path) => load_transaction_fixture(path)?,
+		TransactionSource::Signature(signature) => {
+			let signature = Signature::from_str(signature).map_err(|e| {
+				MonitorExecutionError::execution_error(
+					format!("Invalid transaction signature '{}': {}", signature, e),
+					None,
+					None,
+				)
+			})?;
+
+			let (_, client) = connect_solana_network(
+				&config.network_service,
+				config.network_slug.as_deref(),
+				&mut monitor,
+			)
+			.await?;
+
+			tracing::debug!(signature = %signature, "Fetching transaction");
+			client
+				.get_transaction(&signature)
+				.await
+				.map_err(|e| MonitorExecutionError::execution_error(e.to_string(), None, None))?
+				.ok_or_else(|| {
+					MonitorExecutionError::not_found(
+						format!("Transaction {} not found", signature),
+						None,
+						None,
+					)
+				})?
+		}
+	};
+
+	let filter = SolanaBlockFilter::<SolanaClient<SolanaTransportClient>>::new();
+	Ok(evaluate_transaction(&filter, &monitor, &transaction))
+}
+
+/// Connects to the Solana network a monitor is evaluated on
+///
+/// Programs of the monitor without a contract spec are given the IDL they publish on the
+/// network, if any.
+///
+/// # Arguments
+///
+/// * `network_service` - The network service to use
+/// * `network_slug` - The network to connect to, defaults to the first Solana network of the
+///   monitor
+/// * `monitor` - The monitor to evaluate
+pub(crate) async fn connect_solana_network<N: NetworkRepositoryTrait + Send + Sync + 'static>(
+	network_service: &Mutex<NetworkService<N>>,
+	network_slug: Option<&str>,
+	monitor: &mut Monitor,
+) -> ExecutionResult<(Network, SolanaClient<SolanaTransportClient>)> {
+	let network = {
+		let network_service = network_service.lock().await;
+		match network_slug {
+			Some(network_slug) => network_service.get(network_slug),
+			None => monitor
+				.networks
+				.iter()
+				.filter_map(|network_slug| network_service.get(network_slug))
+				.find(|network| network.network_type == BlockChainType::Solana),
+		}
+	}
+	.ok_or_else(|| {
+		MonitorExecutionError::not_found(
+			format!(
+				"Solana network '{}' not found",
+				network_slug.unwrap_or_default()
+			),
+			None,
+			None,
+		)
+	})?;
+
+	if network.network_type != BlockChainType::Solana {
+		return Err(MonitorExecutionError::execution_error(
+			format!("Network '{}' is not a Solana network", network.slug),
+			None,
+			None,
+		));
+	}
+
+	let client = SolanaClient::new(&network).await.map_err(|e| {
+		MonitorExecutionError::execution_error(
+			format!("Failed to get Solana client: {}", e),
+			None,
+			None,
+		)
+	})?;
+
+	// Programs without a contract spec are decoded with the IDL they publish, if any
+	let idl_specs = get_solana_idl_specs(&client, &network, std::slice::from_ref(monitor)).await;
+	for address in &mut monitor.addresses {
+		if address.contract_spec.is_none() {
+			address.contract_spec = idl_specs
+				.iter()
+				.find(|(program_id, _)| *program_id == normalize_pubkey(&address.address))
+				.map(|(_, spec)| spec.clone());
+		}
+	}
+
+	Ok((network, client))
+}
+
+/// Loads a transaction from a JSON fixture
+///
+/// The fixture holds either a full `getTransaction` RPC response or its `result`, with the
+/// transaction encoded in base64 or base58.
+///
+/// # Arguments
+///
+/// * `path` - The path to the fixture
+#[allow(clippy::result_large_err)]
+pub fn load_transaction_fixture(path: &str) -> ExecutionResult<SolanaTransaction> {
+	let content = std::fs::read_to_string(path).map_err(|e| {
+		MonitorExecutionError::not_found(
+			format!("Failed to read transaction fixture '{}': {}", path, e),
+			None,
+			None,
+		)
+	})?;
+
+	let mut value: Value = serde_json::from_str(&content).map_err(|e| {
+		MonitorExecutionError::execution_error(
+			format!("Invalid transaction fixture '{}': {}", path, e),
+			None,
+			None,
+		)
+	})?;
+	if let Some(result) = value.get_mut("result") {
+		value = result.take();
+	}
+
+	let encoded: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_value(value)
+		.map_err(|e| {
+			MonitorExecutionError::execution_error(
+				format!("Invalid transaction fixture '{}': {}", path, e),
+				None,
+				None,
+			)
+		})?;
+
+	SolanaTransaction::from_encoded(encoded).map_err(|e| {
+		MonitorExecutionError::execution_error(
+			format!(
+				"Failed to decode the transaction of fixture '{}': {}",
+				path, e
+			),
+			None,
+			None,
+		)
+	})
+}
+
+/// Evaluates each condition of a monitor against a transaction
+///
+/// # Arguments
+///
+/// * `filter` - The filter used to decode instructions and evaluate expressions
+/// * `monitor` - The monitor to evaluate
+/// * `transaction` - The transaction to evaluate the monitor against
+pub fn evaluate_transaction<T>(
+	filter: &SolanaBlockFilter<T>,
+	monitor: &Monitor,
+	transaction: &SolanaTransaction,
+) -> DryRunReport {
+	let conditions = &monitor.match_conditions;
+	let decoded_instructions = filter.decode_monitored_instructions(transaction, monitor, &[]);
+	let instructions = decoded_instructions
+		.iter()
+		.filter_map(|decoded| filter.create_match_params(decoded))
+		.map(|params| ExtractedParams {
+			kind: ConditionKind::Function,
+			signature: params.signature,
+			params: params.args.unwrap_or_default(),
+		})
+		.collect::<Vec<_>>();
+	let balance_changes = filter
+		.create_balance_change_params(transaction)
+		.into_iter()
+		.map(|params| ExtractedParams {
+			kind: ConditionKind::BalanceChange,
+			signature: params.signature,
+			params: params.args.unwrap_or_default(),
+		})
+		.collect::<Vec<_>>();
+	let logs = filter
+		.create_log_params(transaction)
+		.into_iter()
+		.map(|params| ExtractedParams {
+			kind: ConditionKind::Log,
+			signature: params.signature,
+			params: params.args.unwrap_or_default(),
+		})
+		.collect::<Vec<_>>();
+	let transaction_params = filter.create_transaction_params(transaction);
+
+	let mut outcomes = Vec::new();
+
+	for condition in &conditions.functions {
+		let candidates = instructions
+			.iter()
+			.filter(|params| are_same_instruction(&condition.signature, &params.signature))
+			.collect::<Vec<_>>();
+		let (matched, reason) = if candidates.is_empty() {
+			(
+				false,
+				Some(format!(
+					"No '{}' instruction of a monitored program in the transaction",
+					condition.signature
+				)),
+			)
+		} else {
+			evaluate_candidates(
+				filter,
+				condition.expression.as_deref(),
+				candidates.iter().map(|params| params.params.clone()),
+			)
+		};
+
+		outcomes.push(ConditionOutcome {
+			kind: ConditionKind::Function,
+			target: condition.signature.clone(),
+			expression: condition.expression.clone(),
+			matched,
+			reason,
+		});
+	}
+
+	let status = if transaction.metadata.meta.status.is_ok() {
+		TransactionStatus::Success
+	} else {
+		TransactionStatus::Failure
+	};
+	for condition in &conditions.transactions {
+		let (matched, reason) =
+			if condition.status != TransactionStatus::Any && condition.status != status {
+				(false, Some(format!("Transaction status is {:?}", status)))
+			} else {
+				evaluate_candidates(
+					filter,
+					condition.expression.as_deref(),
+					std::iter::once(
+						[
+							transaction_params.as_slice(),
+							&filter.create_sol_delta_params(
+								transaction,
+								condition.expression.as_deref().unwrap_or_default(),
+							),
+						]
+						.concat(),
+					),
+				)
+			};
+
+		outcomes.push(ConditionOutcome {
+			kind: ConditionKind::Transaction,
+			target: format!("{:?}", condition.status),
+			expression: condition.expression.clone(),
+			matched,
+			reason,
+		});
+	}
+
+	for condition in &conditions.balance_changes {
+		let (matched, reason) = if balance_changes.is_empty() {
+			(
+				false,
+				Some("No token balance change in the transaction".to_string()),
+			)
+		} else {
+			evaluate_candidates(
+				filter,
+				condition.expression.as_deref(),
+				balance_changes.iter().map(|params| params.params.clone()),
+			)
+		};
+
+		outcomes.push(ConditionOutcome {
+			kind: ConditionKind::BalanceChange,
+			target: "*".to_string(),
+			expression: condition.expression.clone(),
+			matched,
+			reason,
+		});
+	}
+
+	for condition in &conditions.logs {
+		let (matched, reason) = if logs.is_empty() {
+			(false, Some("No log message in the transaction".to_string()))
+		} else {
+			evaluate_candidates(
+				filter,
+				condition.expression.as_deref(),
+				logs.iter().map(|params| params.params.clone()),
+			)
+		};
+
+		outcomes.push(ConditionOutcome {
+			kind: ConditionKind::Log,
+			target: "*".to_string(),
+			expression: condition.expression.clone(),
+			matched,
+			reason,
+		});
+	}
+
+	let mut matched_sequences = Vec::new();
+	filter.find_matching_sequences(
+		&SolanaDecodedTransaction::new(transaction, &filter.decoder_registry),
+		monitor,
+		&[],
+		&mut matched_sequences,
+		&mut Vec::new(),
+		&mut SolanaMatchArguments {
+			instructions: None,
+			accounts: None,
+			balance_changes: None,
+			events: None,
+			logs: None,
+			sequences: None,
+			blocks: None,
+		},
+	);
+	for condition in &conditions.sequences {
+		let matched = matched_sequences.contains(condition);
+		outcomes.push(ConditionOutcome {
+			kind: ConditionKind::Sequence,
+			target: condition
+				.steps
+				.iter()
+				.map(|step| step.signature.as_str())
+				.collect::<Vec<_>>()
+				.join(" -> "),
+			expression: None,
+			matched,
+			reason: (!matched)
+				.then(|| "The steps did not match instructions in this order".to_string()),
+		});
+	}
+
+	let mut params = vec![ExtractedParams {
+		kind: ConditionKind::Transaction,
+		signature: "transaction".to_string(),
+		params: transaction_params,
+	}];
+	params.extend(instructions);
+	params.extend(balance_changes);
+	params.extend(logs);
+
+	DryRunReport {
+		monitor: monitor.name.clone(),
+		signature: transaction.signature().to_string(),
+		slot: transaction.slot(),
+		matched: filter
+			.match_transaction(transaction, monitor, &[], "", None)
+			.is_some(),
+		conditions: outcomes,
+		params,
+		skipped_account_conditions: conditions.accounts.len(),
+	}
+}
+
+/// Evaluates an optional expression against each set of parameters, stopping at the first match
+///
+/// # Returns
+/// Whether any set of parameters matched, and the last evaluation error otherwise
+fn evaluate_candidates<T>(
+	filter: &SolanaBlockFilter<T>,
+	expression: Option<&str>,
+	candidates: impl Iterator<Item = Vec<SolanaMatchParamEntry>>,
+) -> (bool, Option<String>) {
+	let Some(expression) = expression else {
+		return (true, None);
+	};
+
+	let mut reason = Some("Expression evaluated to false".to_string());
+	for params in candidates {
+		match filter.evaluate_expression(expression, &params) {
+			Ok(true) => return (true, None),
+			Ok(false) => {}
+			Err(e) => reason = Some(e.to_string()),
+		}
+	}
+
+	(false, reason)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		models::{SolanaDecodedInstruction, TransactionCondition},
+		services::decoders::kamino_lending_decoder::PROGRAM_ID as KAMINO_LENDING_PROGRAM_ID,
+		utils::tests::solana::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+	};
+	use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+
+	fn create_deposit_transaction(amount: u64) -> SolanaTransaction {
+		let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+		data.extend_from_slice(&amount.to_le_bytes());
+
+		TransactionBuilder::new()
+			.slot(42)
+			.instruction(SolanaDecodedInstruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				data,
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), true)],
+			})
+			.build()
+	}
+
+	#[test]
+	fn test_evaluate_transaction_reports_each_condition() {
+		let filter = SolanaBlockFilter::<()>::new();
+		let mut monitor = MonitorBuilder::new()
+			.name("Kamino deposits")
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.function("DepositReserveLiquidity", Some("liquidity_amount > 1000"))
+			.function("RedeemReserveCollateral", None)
+			.build();
+		monitor
+			.match_conditions
+			.transactions
+			.push(TransactionCondition {
+				status: TransactionStatus::Failure,
+				expression: None,
+			});
+
+		let report = evaluate_transaction(&filter, &monitor, &create_deposit_transaction(5000));
+
+		assert_eq!(report.monitor, "Kamino deposits");
+		assert_eq!(report.slot, 42);
+		assert!(!report.matched);
+		assert_eq!(report.conditions.len(), 3);
+		assert!(report.conditions[0].matched);
+		assert!(!report.conditions[1].matched);
+		assert!(report.conditions[1]
+			.reason
+			.as_ref()
+			.unwrap()
+			.contains("No 'RedeemReserveCollateral' instruction"));
+		assert_eq!(report.conditions[2].kind, ConditionKind::Transaction);
+		assert!(!report.conditions[2].matched);
+		assert_eq!(
+			report.conditions[2].reason.as_deref(),
+			Some("Transaction status is Success")
+		);
+
+		assert_eq!(report.params[0].signature, "transaction");
+		assert!(report.params[0]
+			.params
+			.iter()
+			.any(|param| param.name == "slot" && param.value == "42"));
+		assert_eq!(report.params[1].signature, "DepositReserveLiquidity");
+		assert!(report.params[1]
+			.params
+			.iter()
+			.any(|param| param.name == "liquidity_amount" && param.value == "5000"));
+	}
+
+	#[test]
+	fn test_evaluate_transaction_reports_why_expressions_fail() {
+		let filter = SolanaBlockFilter::<()>::new();
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.function("DepositReserveLiquidity", Some("liquidity_amount > 1000"))
+			.build();
+
+		assert!(evaluate_transaction(&filter, &monitor, &create_deposit_transaction(5000)).matched);
+		let report = evaluate_transaction(&filter, &monitor, &create_deposit_transaction(10));
+		assert!(!report.matched);
+		assert_eq!(
+			report.conditions[0].reason.as_deref(),
+			Some("Expression evaluated to false")
+		);
+
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.transaction(Some("slot >"))
+			.build();
+		let report = evaluate_transaction(&filter, &monitor, &create_deposit_transaction(5000));
+		assert!(!report.matched);
+		assert!(report.conditions[0]
+			.reason
+			.as_ref()
+			.unwrap()
+			.contains("Failed to parse expression"));
+	}
+
+	#[test]
+	fn test_load_transaction_fixture() {
+		use solana_sdk::{
+			signature::{Keypair, Signer},
+			transaction::{Transaction, VersionedTransaction},
+		};
+		use solana_transaction_status::{Encodable, UiTransactionEncoding};
+
+		let fee_payer = Keypair::new();
+		let mut data = vec![0xa9, 0xc9, 0x1e, 0x7e, 0x06, 0xcd, 0x66, 0x44];
+		data.extend_from_slice(&5000u64.to_le_bytes());
+		let mut transaction = Transaction::new_with_payer(
+			&[solana_sdk::instruction::Instruction {
+				program_id: KAMINO_LENDING_PROGRAM_ID,
+				accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+				data,
+			}],
+			Some(&fee_payer.pubkey()),
+		);
+		transaction.sign(&[&fee_payer], Default::default());
+
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("transaction.json");
+		std::fs::write(
+			&path,
+			serde_json::json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": {
+					"slot": 42,
+					"blockTime": null,
+					"transaction": VersionedTransaction::from(transaction.clone())
+						.encode(UiTransactionEncoding::Base64),
+					"meta": {
+						"err": null,
+						"status": { "Ok": null },
+						"fee": 5000,
+						"preBalances": [1_000_000, 0, 1],
+						"postBalances": [995_000, 0, 1]
+					}
+				}
+			})
+			.to_string(),
+		)
+		.unwrap();
+
+		let loaded = load_transaction_fixture(path.to_str().unwrap()).unwrap();
+		assert_eq!(loaded.signature(), &transaction.signatures[0]);
+		assert_eq!(loaded.slot(), 42);
+
+		let monitor = MonitorBuilder::new()
+			.address(&KAMINO_LENDING_PROGRAM_ID.to_string(), None)
+			.function("DepositReserveLiquidity", Some("liquidity_amount == 5000"))
+			.build();
+		assert!(evaluate_transaction(&SolanaBlockFilter::<()>::new(), &monitor, &loaded).matched);
+
+		std::fs::write(&path, "{}").unwrap();
+		assert!(load_transaction_fixture(path.to_str().unwrap()).is_err());
+		assert!(load_transaction_fixture("nonexistent_transaction.json").is_err());
+	}
+}
@@ -3,8 +3,12 @@
 //! This module provides functionality for executing monitors against a specific block
 //!
 //! - execution: Monitor execution logic against a specific block
+//! - dry_run: Monitor condition evaluation against a single Solana transaction
+//! - backfill: Solana monitor matching over the history of a range of slots
 //! - error: Error types for monitor execution
 
 mod error;
 pub use error::MonitorExecutionError;
+pub mod backfill;
+pub mod dry_run;
 pub mod execution;
@@ -2,9 +2,12 @@
 //!
 //! - `MonitorBuilder`: Builder for creating test Monitor instances
 
+use std::collections::HashMap;
+
 use crate::models::{
-	AddressWithSpec, ContractSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-	ScriptLanguage, TransactionCondition, TransactionStatus, TriggerConditions,
+	AddressWithSpec, ConditionLogic, ContractSpec, EscalationStep, EventCondition,
+	FunctionCondition, MatchConditions, Monitor, MonitorSeverity, ScriptLanguage,
+	TransactionCondition, TransactionStatus, TriggerConditions, TriggerRoute,
 };
 
 /// Builder for creating test Monitor instances
@@ -16,6 +19,10 @@ pub struct MonitorBuilder {
 	match_conditions: MatchConditions,
 	trigger_conditions: Vec<TriggerConditions>,
 	triggers: Vec<String>,
+	constants: HashMap<String, serde_json::Value>,
+	severity: Option<MonitorSeverity>,
+	escalation: Option<Vec<EscalationStep>>,
+	trigger_routes: Vec<TriggerRoute>,
 }
 
 impl Default for MonitorBuilder {
@@ -27,14 +34,25 @@ impl Default for MonitorBuilder {
 			addresses: vec![AddressWithSpec {
 				address: "0x0000000000000000000000000000000000000000".to_string(),
 				contract_spec: None,
+				match_conditions: None,
 			}],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			trigger_conditions: vec![],
 			triggers: vec![],
+			constants: HashMap::new(),
+			severity: None,
+			escalation: None,
+			trigger_routes: vec![],
 		}
 	}
 }
@@ -59,10 +77,16 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn constant(mut self, name: &str, value: serde_json::Value) -> Self {
+		self.constants.insert(name.to_string(), value);
+		self
+	}
+
 	pub fn address(mut self, address: &str) -> Self {
 		self.addresses = vec![AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			match_conditions: None,
 		}];
 		self
 	}
@@ -73,6 +97,7 @@ impl MonitorBuilder {
 			.map(|addr| AddressWithSpec {
 				address: addr,
 				contract_spec: None,
+				match_conditions: None,
 			})
 			.collect();
 		self
@@ -82,6 +107,7 @@ impl MonitorBuilder {
 		self.addresses.push(AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: None,
+			match_conditions: None,
 		});
 		self
 	}
@@ -90,6 +116,7 @@ impl MonitorBuilder {
 		self.addresses = vec![AddressWithSpec {
 			address: address.to_string(),
 			contract_spec: spec,
+			match_conditions: None,
 		}];
 		self
 	}
@@ -100,6 +127,7 @@ impl MonitorBuilder {
 			.map(|(addr, spec)| AddressWithSpec {
 				address: addr.to_string(),
 				contract_spec: spec,
+				match_conditions: None,
 			})
 			.collect();
 		self
@@ -154,15 +182,47 @@ impl MonitorBuilder {
 		self
 	}
 
+	pub fn severity(mut self, severity: MonitorSeverity) -> Self {
+		self.severity = Some(severity);
+		self
+	}
+
+	pub fn escalation(mut self, steps: Vec<(&str, u64)>) -> Self {
+		self.escalation = Some(
+			steps
+				.into_iter()
+				.map(|(trigger, after_secs)| EscalationStep {
+					trigger: trigger.to_string(),
+					after_secs,
+				})
+				.collect(),
+		);
+		self
+	}
+
+	pub fn trigger_route(mut self, expression: &str, triggers: Vec<&str>) -> Self {
+		self.trigger_routes.push(TriggerRoute {
+			expression: expression.to_string(),
+			triggers: triggers.into_iter().map(|s| s.to_string()).collect(),
+		});
+		self
+	}
+
 	pub fn build(self) -> Monitor {
 		Monitor {
 			name: self.name,
 			networks: self.networks,
 			paused: self.paused,
 			addresses: self.addresses,
+			match_all_addresses: false,
+			excluded_addresses: vec![],
 			match_conditions: self.match_conditions,
 			trigger_conditions: self.trigger_conditions,
 			triggers: self.triggers,
+			constants: self.constants,
+			severity: self.severity,
+			escalation: self.escalation,
+			trigger_routes: self.trigger_routes,
 		}
 	}
 }
@@ -313,6 +373,12 @@ mod tests {
 				}],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			})
 			.build();
 		assert_eq!(monitor.match_conditions.functions.len(), 1);
@@ -2,7 +2,11 @@
 //!
 //! - `NetworkBuilder`: Builder for creating test Network instances
 
-use crate::models::{BlockChainType, Network, RpcUrl, SecretString, SecretValue};
+use std::collections::HashMap;
+
+use crate::models::{
+	BlockChainType, BlockExplorer, Network, RpcAuth, RpcUrl, SecretString, SecretValue,
+};
 
 /// Builder for creating test Network instances
 pub struct NetworkBuilder {
@@ -12,6 +16,8 @@ pub struct NetworkBuilder {
 	chain_id: Option<u64>,
 	network_passphrase: Option<String>,
 	store_blocks: Option<bool>,
+	fetch_onchain_idls: Option<bool>,
+	explorer: Option<BlockExplorer>,
 	rpc_urls: Vec<RpcUrl>,
 	block_time_ms: u64,
 	confirmation_blocks: u64,
@@ -28,10 +34,15 @@ impl Default for NetworkBuilder {
 			chain_id: Some(1),
 			network_passphrase: None,
 			store_blocks: Some(true),
+			fetch_onchain_idls: None,
+			explorer: None,
 			rpc_urls: vec![RpcUrl {
 				type_: "rpc".to_string(),
 				url: SecretValue::Plain(SecretString::new("https://test.network".to_string())),
 				weight: 100,
+				rate_limit: None,
+				headers: None,
+				auth: None,
 			}],
 			block_time_ms: 1000,
 			confirmation_blocks: 1,
@@ -76,11 +87,24 @@ impl NetworkBuilder {
 		self
 	}
 
+	pub fn fetch_onchain_idls(mut self, fetch: bool) -> Self {
+		self.fetch_onchain_idls = Some(fetch);
+		self
+	}
+
+	pub fn explorer(mut self, explorer: BlockExplorer) -> Self {
+		self.explorer = Some(explorer);
+		self
+	}
+
 	pub fn rpc_url(mut self, url: &str) -> Self {
 		self.rpc_urls = vec![RpcUrl {
 			type_: "rpc".to_string(),
 			url: SecretValue::Plain(SecretString::new(url.to_string())),
 			weight: 100,
+			rate_limit: None,
+			headers: None,
+			auth: None,
 		}];
 		self
 	}
@@ -92,6 +116,9 @@ impl NetworkBuilder {
 				type_: "rpc".to_string(),
 				url: SecretValue::Plain(SecretString::new(url.to_string())),
 				weight: 100,
+				rate_limit: None,
+				headers: None,
+				auth: None,
 			})
 			.collect();
 		self
@@ -102,6 +129,9 @@ impl NetworkBuilder {
 			type_: type_.to_string(),
 			url: SecretValue::Plain(SecretString::new(url.to_string())),
 			weight,
+			rate_limit: None,
+			headers: None,
+			auth: None,
 		});
 		self
 	}
@@ -111,6 +141,39 @@ impl NetworkBuilder {
 			type_: type_.to_string(),
 			url,
 			weight,
+			rate_limit: None,
+			headers: None,
+			auth: None,
+		});
+		self
+	}
+
+	pub fn add_rate_limited_rpc_url(mut self, url: &str, weight: u32, rate_limit: u32) -> Self {
+		self.rpc_urls.push(RpcUrl {
+			type_: "rpc".to_string(),
+			url: SecretValue::Plain(SecretString::new(url.to_string())),
+			weight,
+			rate_limit: Some(rate_limit),
+			headers: None,
+			auth: None,
+		});
+		self
+	}
+
+	pub fn add_authenticated_rpc_url(
+		mut self,
+		url: &str,
+		weight: u32,
+		headers: Option<HashMap<String, SecretValue>>,
+		auth: Option<RpcAuth>,
+	) -> Self {
+		self.rpc_urls.push(RpcUrl {
+			type_: "rpc".to_string(),
+			url: SecretValue::Plain(SecretString::new(url.to_string())),
+			weight,
+			rate_limit: None,
+			headers,
+			auth,
 		});
 		self
 	}
@@ -148,6 +211,8 @@ impl NetworkBuilder {
 			chain_id: self.chain_id,
 			network_passphrase: self.network_passphrase,
 			store_blocks: self.store_blocks,
+			fetch_onchain_idls: self.fetch_onchain_idls,
+			explorer: self.explorer,
 			rpc_urls: self.rpc_urls,
 			block_time_ms: self.block_time_ms,
 			confirmation_blocks: self.confirmation_blocks,
@@ -13,6 +13,12 @@ pub struct InstructionBuilder {
 	data: Vec<u8>,
 }
 
+impl Default for InstructionBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl InstructionBuilder {
 	/// Creates a new InstructionBuilder with default values
 	pub fn new() -> Self {
@@ -64,6 +70,12 @@ pub struct InstructionMetadataBuilder {
 	instruction_index: usize,
 }
 
+impl Default for InstructionMetadataBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl InstructionMetadataBuilder {
 	/// Creates a new InstructionMetadataBuilder with default values
 	pub fn new() -> Self {
@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use crate::models::{
-	AddressWithSpec, EventCondition, FunctionCondition, MatchConditions, Monitor,
-	TransactionCondition, TriggerConditions,
+	AccountCondition, AccountConditionMode, AddressMatchConditions, AddressWithSpec,
+	BalanceChangeCondition, BlockCondition, ConditionLogic, EscalationStep, EventCondition,
+	FunctionCondition, LogCondition, MatchConditions, Monitor, MonitorSeverity, SequenceCondition,
+	TransactionCondition, TriggerConditions, TriggerRoute,
 };
 
 /// Builder for creating test monitors
@@ -8,10 +12,22 @@ pub struct MonitorBuilder {
 	name: String,
 	networks: Vec<String>,
 	addresses: Vec<AddressWithSpec>,
+	match_all_addresses: bool,
+	excluded_addresses: Vec<String>,
 	match_conditions: MatchConditions,
 	trigger_conditions: Vec<TriggerConditions>,
 	triggers: Vec<String>,
 	paused: bool,
+	constants: HashMap<String, serde_json::Value>,
+	severity: Option<MonitorSeverity>,
+	escalation: Option<Vec<EscalationStep>>,
+	trigger_routes: Vec<TriggerRoute>,
+}
+
+impl Default for MonitorBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
 impl MonitorBuilder {
@@ -21,14 +37,26 @@ impl MonitorBuilder {
 			name: "TestMonitor".to_string(),
 			networks: vec!["solana_mainnet".to_string()],
 			addresses: vec![],
+			match_all_addresses: false,
+			excluded_addresses: vec![],
 			match_conditions: MatchConditions {
 				functions: vec![],
 				events: vec![],
 				transactions: vec![],
+				accounts: vec![],
+				balance_changes: vec![],
+				logs: vec![],
+				sequences: vec![],
+				blocks: vec![],
+				condition_logic: ConditionLogic::All,
 			},
 			trigger_conditions: vec![],
 			triggers: vec![],
 			paused: false,
+			constants: HashMap::new(),
+			severity: None,
+			escalation: None,
+			trigger_routes: vec![],
 		}
 	}
 
@@ -73,6 +101,66 @@ impl MonitorBuilder {
 		self
 	}
 
+	/// Adds an account condition
+	pub fn account(self, address: &str, expression: Option<&str>) -> Self {
+		self.account_with_mode(address, expression, AccountConditionMode::State)
+	}
+
+	/// Adds an account condition with the given match mode
+	pub fn account_with_mode(
+		mut self,
+		address: &str,
+		expression: Option<&str>,
+		mode: AccountConditionMode,
+	) -> Self {
+		self.match_conditions.accounts.push(AccountCondition {
+			address: address.to_string(),
+			expression: expression.map(|s| s.to_string()),
+			mode,
+		});
+		self
+	}
+
+	/// Adds a balance change condition
+	pub fn balance_change(mut self, expression: Option<&str>) -> Self {
+		self.match_conditions
+			.balance_changes
+			.push(BalanceChangeCondition {
+				expression: expression.map(|s| s.to_string()),
+			});
+		self
+	}
+
+	/// Adds a log condition
+	pub fn log(mut self, expression: Option<&str>) -> Self {
+		self.match_conditions.logs.push(LogCondition {
+			expression: expression.map(|s| s.to_string()),
+		});
+		self
+	}
+
+	/// Adds a block condition
+	pub fn block(mut self, expression: Option<&str>) -> Self {
+		self.match_conditions.blocks.push(BlockCondition {
+			expression: expression.map(|s| s.to_string()),
+		});
+		self
+	}
+
+	/// Adds a sequence condition whose steps are given as signature and expression pairs
+	pub fn sequence(mut self, steps: &[(&str, Option<&str>)]) -> Self {
+		self.match_conditions.sequences.push(SequenceCondition {
+			steps: steps
+				.iter()
+				.map(|(signature, expression)| FunctionCondition {
+					signature: signature.to_string(),
+					expression: expression.map(|s| s.to_string()),
+				})
+				.collect(),
+		});
+		self
+	}
+
 	/// Adds an address with contract spec
 	pub fn address(
 		mut self,
@@ -82,10 +170,44 @@ impl MonitorBuilder {
 		self.addresses.push(AddressWithSpec {
 			address: address.to_string(),
 			contract_spec,
+			match_conditions: None,
 		});
 		self
 	}
 
+	/// Adds an address with contract spec and conditions scoped to it
+	pub fn address_with_conditions(
+		mut self,
+		address: &str,
+		contract_spec: Option<crate::models::ContractSpec>,
+		match_conditions: AddressMatchConditions,
+	) -> Self {
+		self.addresses.push(AddressWithSpec {
+			address: address.to_string(),
+			contract_spec,
+			match_conditions: Some(match_conditions),
+		});
+		self
+	}
+
+	/// Matches the activity of any address
+	pub fn match_all_addresses(mut self) -> Self {
+		self.match_all_addresses = true;
+		self
+	}
+
+	/// Adds an address whose transactions are never matched
+	pub fn excluded_address(mut self, address: &str) -> Self {
+		self.excluded_addresses.push(address.to_string());
+		self
+	}
+
+	/// Sets how the condition groups combine
+	pub fn condition_logic(mut self, condition_logic: ConditionLogic) -> Self {
+		self.match_conditions.condition_logic = condition_logic;
+		self
+	}
+
 	/// Sets the trigger conditions
 	pub fn trigger_conditions(mut self, conditions: Vec<TriggerConditions>) -> Self {
 		self.trigger_conditions = conditions;
@@ -104,15 +226,56 @@ impl MonitorBuilder {
 		self
 	}
 
+	/// Sets a named constant referenced from expressions
+	pub fn constant(mut self, name: &str, value: serde_json::Value) -> Self {
+		self.constants.insert(name.to_string(), value);
+		self
+	}
+
+	/// Sets the severity of the monitor
+	pub fn severity(mut self, severity: MonitorSeverity) -> Self {
+		self.severity = Some(severity);
+		self
+	}
+
+	/// Sets the escalation steps of the monitor, as trigger slugs and delays in seconds
+	pub fn escalation(mut self, steps: Vec<(&str, u64)>) -> Self {
+		self.escalation = Some(
+			steps
+				.into_iter()
+				.map(|(trigger, after_secs)| EscalationStep {
+					trigger: trigger.to_string(),
+					after_secs,
+				})
+				.collect(),
+		);
+		self
+	}
+
+	/// Adds a route executing triggers for the matches satisfying an expression
+	pub fn trigger_route(mut self, expression: &str, triggers: Vec<&str>) -> Self {
+		self.trigger_routes.push(TriggerRoute {
+			expression: expression.to_string(),
+			triggers: triggers.into_iter().map(|s| s.to_string()).collect(),
+		});
+		self
+	}
+
 	/// Builds the monitor
 	pub fn build(self) -> Monitor {
 		Monitor {
 			name: self.name,
 			networks: self.networks,
 			addresses: self.addresses,
+			match_all_addresses: self.match_all_addresses,
+			excluded_addresses: self.excluded_addresses,
 			match_conditions: self.match_conditions,
 			trigger_conditions: self.trigger_conditions,
 			triggers: self.triggers,
+			constants: self.constants,
+			severity: self.severity,
+			escalation: self.escalation,
+			trigger_routes: self.trigger_routes,
 			paused: self.paused,
 		}
 	}
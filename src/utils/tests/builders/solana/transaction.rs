@@ -19,6 +19,12 @@ pub struct TransactionBuilder {
 	instructions: Vec<SolanaDecodedInstruction<Vec<u8>>>,
 }
 
+impl Default for TransactionBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl TransactionBuilder {
 	/// Creates a new TransactionBuilder with default values
 	pub fn new() -> Self {
@@ -3,7 +3,8 @@
 //! - `TriggerBuilder`: Builder for creating test Trigger instances
 
 use crate::models::{
-	NotificationMessage, ScriptLanguage, SecretString, SecretValue, Trigger, TriggerType,
+	MonitorSeverity, NotificationMessage, PagerDutySeverity, ScriptLanguage, SecretString,
+	SecretValue, SmtpTlsMode, Trigger, TriggerAggregation, TriggerThrottle, TriggerType,
 	TriggerTypeConfig,
 };
 use email_address::EmailAddress;
@@ -13,6 +14,9 @@ pub struct TriggerBuilder {
 	name: String,
 	trigger_type: TriggerType,
 	config: TriggerTypeConfig,
+	throttle: Option<TriggerThrottle>,
+	aggregation: Option<TriggerAggregation>,
+	severities: Option<Vec<MonitorSeverity>>,
 }
 
 impl Default for TriggerBuilder {
@@ -27,11 +31,17 @@ impl Default for TriggerBuilder {
 				secret: None,
 				method: Some("POST".to_string()),
 				headers: None,
+				include_match: None,
+				match_schema_version: None,
+				max_retries: None,
 				message: NotificationMessage {
 					title: "Alert".to_string(),
 					body: "Test message".to_string(),
 				},
 			},
+			throttle: None,
+			aggregation: None,
+			severities: None,
 		}
 	}
 }
@@ -58,6 +68,9 @@ impl TriggerBuilder {
 			secret: None,
 			method: Some("POST".to_string()),
 			headers: None,
+			include_match: None,
+			match_schema_version: None,
+			max_retries: None,
 			message: NotificationMessage {
 				title: "Alert".to_string(),
 				body: "Test message".to_string(),
@@ -82,6 +95,31 @@ impl TriggerBuilder {
 		self.trigger_type = TriggerType::Discord;
 		self.config = TriggerTypeConfig::Discord {
 			discord_url: SecretValue::Plain(SecretString::new(webhook_url.to_string())),
+			embed: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn google_chat(mut self, webhook_url: &str) -> Self {
+		self.trigger_type = TriggerType::GoogleChat;
+		self.config = TriggerTypeConfig::GoogleChat {
+			google_chat_url: SecretValue::Plain(SecretString::new(webhook_url.to_string())),
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn teams(mut self, webhook_url: &str) -> Self {
+		self.trigger_type = TriggerType::Teams;
+		self.config = TriggerTypeConfig::Teams {
+			teams_url: SecretValue::Plain(SecretString::new(webhook_url.to_string())),
 			message: NotificationMessage {
 				title: "Alert".to_string(),
 				body: "Test message".to_string(),
@@ -94,7 +132,7 @@ impl TriggerBuilder {
 		self.trigger_type = TriggerType::Telegram;
 		self.config = TriggerTypeConfig::Telegram {
 			token: SecretValue::Plain(SecretString::new(token.to_string())),
-			chat_id: chat_id.to_string(),
+			chat_id: SecretValue::Plain(SecretString::new(chat_id.to_string())),
 			disable_web_preview: Some(disable_web_preview),
 			message: NotificationMessage {
 				title: "Test title".to_string(),
@@ -111,6 +149,145 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn telegram_chat_id(mut self, chat_id: SecretValue) -> Self {
+		if let TriggerTypeConfig::Telegram { chat_id: c, .. } = &mut self.config {
+			*c = chat_id;
+		}
+		self
+	}
+
+	pub fn pagerduty(mut self, routing_key: &str) -> Self {
+		self.trigger_type = TriggerType::PagerDuty;
+		self.config = TriggerTypeConfig::PagerDuty {
+			routing_key: SecretValue::Plain(SecretString::new(routing_key.to_string())),
+			severity: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn pagerduty_severity(mut self, severity: PagerDutySeverity) -> Self {
+		if let TriggerTypeConfig::PagerDuty { severity: s, .. } = &mut self.config {
+			*s = Some(severity);
+		}
+		self
+	}
+
+	pub fn nats(mut self, server_url: &str, subject: &str) -> Self {
+		self.trigger_type = TriggerType::Nats;
+		self.config = TriggerTypeConfig::Nats {
+			server_url: SecretValue::Plain(SecretString::new(server_url.to_string())),
+			subject: subject.to_string(),
+			token: None,
+			jetstream: None,
+			ack_timeout_ms: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn nats_token(mut self, token: SecretValue) -> Self {
+		if let TriggerTypeConfig::Nats { token: t, .. } = &mut self.config {
+			*t = Some(token);
+		}
+		self
+	}
+
+	pub fn nats_jetstream(mut self, jetstream: bool) -> Self {
+		if let TriggerTypeConfig::Nats { jetstream: j, .. } = &mut self.config {
+			*j = Some(jetstream);
+		}
+		self
+	}
+
+	pub fn nats_ack_timeout_ms(mut self, ack_timeout_ms: u64) -> Self {
+		if let TriggerTypeConfig::Nats {
+			ack_timeout_ms: a, ..
+		} = &mut self.config
+		{
+			*a = Some(ack_timeout_ms);
+		}
+		self
+	}
+
+	pub fn matrix(mut self, homeserver_url: &str, room_id: &str, access_token: &str) -> Self {
+		self.trigger_type = TriggerType::Matrix;
+		self.config = TriggerTypeConfig::Matrix {
+			homeserver_url: homeserver_url.to_string(),
+			room_id: room_id.to_string(),
+			access_token: SecretValue::Plain(SecretString::new(access_token.to_string())),
+			html: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn matrix_access_token(mut self, access_token: SecretValue) -> Self {
+		if let TriggerTypeConfig::Matrix {
+			access_token: t, ..
+		} = &mut self.config
+		{
+			*t = access_token;
+		}
+		self
+	}
+
+	pub fn matrix_html(mut self, html: bool) -> Self {
+		if let TriggerTypeConfig::Matrix { html: h, .. } = &mut self.config {
+			*h = Some(html);
+		}
+		self
+	}
+
+	pub fn twilio(
+		mut self,
+		account_sid: &str,
+		auth_token: &str,
+		from: &str,
+		to: Vec<&str>,
+	) -> Self {
+		self.trigger_type = TriggerType::Twilio;
+		self.config = TriggerTypeConfig::Twilio {
+			account_sid: account_sid.to_string(),
+			auth_token: SecretValue::Plain(SecretString::new(auth_token.to_string())),
+			from: from.to_string(),
+			to: to.into_iter().map(|number| number.to_string()).collect(),
+			max_messages_per_hour: None,
+			message: NotificationMessage {
+				title: "Alert".to_string(),
+				body: "Test message".to_string(),
+			},
+		};
+		self
+	}
+
+	pub fn twilio_auth_token(mut self, auth_token: SecretValue) -> Self {
+		if let TriggerTypeConfig::Twilio { auth_token: t, .. } = &mut self.config {
+			*t = auth_token;
+		}
+		self
+	}
+
+	pub fn twilio_max_messages_per_hour(mut self, max_messages_per_hour: u32) -> Self {
+		if let TriggerTypeConfig::Twilio {
+			max_messages_per_hour: m,
+			..
+		} = &mut self.config
+		{
+			*m = Some(max_messages_per_hour);
+		}
+		self
+	}
+
 	pub fn script(mut self, script_path: &str, language: ScriptLanguage) -> Self {
 		self.trigger_type = TriggerType::Script;
 		self.config = TriggerTypeConfig::Script {
@@ -141,7 +318,13 @@ impl TriggerBuilder {
 			TriggerTypeConfig::Webhook { message, .. }
 			| TriggerTypeConfig::Slack { message, .. }
 			| TriggerTypeConfig::Discord { message, .. }
+			| TriggerTypeConfig::GoogleChat { message, .. }
+			| TriggerTypeConfig::Teams { message, .. }
 			| TriggerTypeConfig::Telegram { message, .. }
+			| TriggerTypeConfig::PagerDuty { message, .. }
+			| TriggerTypeConfig::Nats { message, .. }
+			| TriggerTypeConfig::Matrix { message, .. }
+			| TriggerTypeConfig::Twilio { message, .. }
 			| TriggerTypeConfig::Email { message, .. } => {
 				message.title = title.to_string();
 				message.body = body.to_string();
@@ -168,6 +351,7 @@ impl TriggerBuilder {
 		self.config = TriggerTypeConfig::Email {
 			host: host.to_string(),
 			port: Some(587),
+			tls: None,
 			username: SecretValue::Plain(SecretString::new(username.to_string())),
 			password: SecretValue::Plain(SecretString::new(password.to_string())),
 			message: NotificationMessage {
@@ -179,6 +363,7 @@ impl TriggerBuilder {
 				.into_iter()
 				.map(EmailAddress::new_unchecked)
 				.collect(),
+			digest_interval_mins: None,
 		};
 		self
 	}
@@ -190,6 +375,24 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn email_tls(mut self, tls: SmtpTlsMode) -> Self {
+		if let TriggerTypeConfig::Email { tls: t, .. } = &mut self.config {
+			*t = Some(tls);
+		}
+		self
+	}
+
+	pub fn email_digest_interval_mins(mut self, minutes: u64) -> Self {
+		if let TriggerTypeConfig::Email {
+			digest_interval_mins: d,
+			..
+		} = &mut self.config
+		{
+			*d = Some(minutes);
+		}
+		self
+	}
+
 	pub fn email_subject(mut self, subject: &str) -> Self {
 		if let TriggerTypeConfig::Email { message, .. } = &mut self.config {
 			message.title = subject.to_string();
@@ -232,6 +435,34 @@ impl TriggerBuilder {
 		self
 	}
 
+	pub fn webhook_include_match(mut self, include_match: bool) -> Self {
+		if let TriggerTypeConfig::Webhook {
+			include_match: i, ..
+		} = &mut self.config
+		{
+			*i = Some(include_match);
+		}
+		self
+	}
+
+	pub fn webhook_match_schema_version(mut self, schema_version: u32) -> Self {
+		if let TriggerTypeConfig::Webhook {
+			match_schema_version: v,
+			..
+		} = &mut self.config
+		{
+			*v = Some(schema_version);
+		}
+		self
+	}
+
+	pub fn webhook_max_retries(mut self, max_retries: u32) -> Self {
+		if let TriggerTypeConfig::Webhook { max_retries: r, .. } = &mut self.config {
+			*r = Some(max_retries);
+		}
+		self
+	}
+
 	pub fn url(mut self, url: SecretValue) -> Self {
 		self.config = match self.config {
 			TriggerTypeConfig::Webhook {
@@ -239,19 +470,27 @@ impl TriggerBuilder {
 				method,
 				headers,
 				secret,
+				include_match,
+				match_schema_version,
+				max_retries,
 				message,
 			} => TriggerTypeConfig::Webhook {
 				url,
 				method,
 				headers,
 				secret,
+				include_match,
+				match_schema_version,
+				max_retries,
 				message,
 			},
 			TriggerTypeConfig::Discord {
 				discord_url: _,
+				embed,
 				message,
 			} => TriggerTypeConfig::Discord {
 				discord_url: url,
+				embed,
 				message,
 			},
 			TriggerTypeConfig::Slack {
@@ -261,16 +500,52 @@ impl TriggerBuilder {
 				slack_url: url,
 				message,
 			},
+			TriggerTypeConfig::GoogleChat {
+				google_chat_url: _,
+				message,
+			} => TriggerTypeConfig::GoogleChat {
+				google_chat_url: url,
+				message,
+			},
+			TriggerTypeConfig::Teams {
+				teams_url: _,
+				message,
+			} => TriggerTypeConfig::Teams {
+				teams_url: url,
+				message,
+			},
 			config => config,
 		};
 		self
 	}
 
+	pub fn throttle(mut self, max_notifications: u32, window_secs: u64, summarize: bool) -> Self {
+		self.throttle = Some(TriggerThrottle {
+			max_notifications,
+			window_secs,
+			summarize: Some(summarize),
+		});
+		self
+	}
+
+	pub fn aggregation(mut self, window_secs: u64) -> Self {
+		self.aggregation = Some(TriggerAggregation { window_secs });
+		self
+	}
+
+	pub fn severities(mut self, severities: Vec<MonitorSeverity>) -> Self {
+		self.severities = Some(severities);
+		self
+	}
+
 	pub fn build(self) -> Trigger {
 		Trigger {
 			name: self.name,
 			trigger_type: self.trigger_type,
 			config: self.config,
+			throttle: self.throttle,
+			aggregation: self.aggregation,
+			severities: self.severities,
 		}
 	}
 }
@@ -306,6 +581,9 @@ mod tests {
 				secret: Some(SecretValue::Plain(SecretString::new("secret".to_string()))),
 				method: Some("POST".to_string()),
 				headers: None,
+				include_match: None,
+				match_schema_version: None,
+				max_retries: None,
 				message: NotificationMessage {
 					title: "Alert".to_string(),
 					body: "Test message".to_string(),
@@ -359,6 +637,9 @@ mod tests {
 				"secret123".to_string(),
 			)))
 			.webhook_headers(headers.clone())
+			.webhook_include_match(true)
+			.webhook_match_schema_version(1)
+			.webhook_max_retries(5)
 			.message("Custom Alert", "Something happened!")
 			.build();
 
@@ -371,6 +652,9 @@ mod tests {
 				method,
 				secret,
 				headers: h,
+				include_match,
+				match_schema_version,
+				max_retries,
 				message,
 			} => {
 				assert_eq!(url.as_ref().to_string(), "https://webhook.example.com");
@@ -380,6 +664,9 @@ mod tests {
 					Some("secret123".to_string())
 				);
 				assert_eq!(h, Some(headers));
+				assert_eq!(include_match, Some(true));
+				assert_eq!(match_schema_version, Some(1));
+				assert_eq!(max_retries, Some(5));
 				assert_eq!(message.title, "Custom Alert");
 				assert_eq!(message.body, "Something happened!");
 			}
@@ -419,6 +706,7 @@ mod tests {
 			TriggerTypeConfig::Discord {
 				discord_url,
 				message,
+				..
 			} => {
 				assert_eq!(
 					discord_url.as_ref().to_string(),
@@ -488,6 +776,238 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn test_pagerduty_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("pagerduty_alert")
+			.pagerduty("routing-key")
+			.pagerduty_severity(PagerDutySeverity::Error)
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::PagerDuty);
+		match trigger.config {
+			TriggerTypeConfig::PagerDuty {
+				routing_key,
+				severity,
+				message,
+			} => {
+				assert_eq!(routing_key.as_ref().to_string(), "routing-key");
+				assert_eq!(severity, Some(PagerDutySeverity::Error));
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected pagerduty config"),
+		}
+	}
+
+	#[test]
+	fn test_nats_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("nats_alert")
+			.nats("nats://localhost:4222", "monitor.matches")
+			.nats_token(SecretValue::Plain(SecretString::new("token".to_string())))
+			.nats_jetstream(true)
+			.nats_ack_timeout_ms(1000)
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Nats);
+		match trigger.config {
+			TriggerTypeConfig::Nats {
+				server_url,
+				subject,
+				token,
+				jetstream,
+				ack_timeout_ms,
+				message,
+			} => {
+				assert_eq!(server_url.as_ref().to_string(), "nats://localhost:4222");
+				assert_eq!(subject, "monitor.matches");
+				assert_eq!(
+					token.as_ref().map(|t| t.as_ref().to_string()),
+					Some("token".to_string())
+				);
+				assert_eq!(jetstream, Some(true));
+				assert_eq!(ack_timeout_ms, Some(1000));
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected nats config"),
+		}
+	}
+
+	#[test]
+	fn test_matrix_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("matrix_alert")
+			.matrix("https://matrix.example.com", "!room:example.com", "token")
+			.matrix_access_token(SecretValue::Environment("MATRIX_TOKEN".to_string()))
+			.matrix_html(false)
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Matrix);
+		match trigger.config {
+			TriggerTypeConfig::Matrix {
+				homeserver_url,
+				room_id,
+				access_token,
+				html,
+				message,
+			} => {
+				assert_eq!(homeserver_url, "https://matrix.example.com");
+				assert_eq!(room_id, "!room:example.com");
+				assert_eq!(
+					access_token,
+					SecretValue::Environment("MATRIX_TOKEN".to_string())
+				);
+				assert_eq!(html, Some(false));
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected matrix config"),
+		}
+	}
+
+	#[test]
+	fn test_google_chat_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("google_chat_alert")
+			.google_chat("https://chat.googleapis.com/v1/spaces/xxx/messages")
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::GoogleChat);
+		match trigger.config {
+			TriggerTypeConfig::GoogleChat {
+				google_chat_url,
+				message,
+			} => {
+				assert_eq!(
+					google_chat_url.as_ref().to_string(),
+					"https://chat.googleapis.com/v1/spaces/xxx/messages"
+				);
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected google chat config"),
+		}
+	}
+
+	#[test]
+	fn test_teams_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("teams_alert")
+			.teams("https://example.webhook.office.com/webhookb2/xxx")
+			.url(SecretValue::Environment("TEAMS_WEBHOOK_URL".to_string()))
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Teams);
+		match trigger.config {
+			TriggerTypeConfig::Teams { teams_url, message } => {
+				assert_eq!(
+					teams_url,
+					SecretValue::Environment("TEAMS_WEBHOOK_URL".to_string())
+				);
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected teams config"),
+		}
+	}
+
+	#[test]
+	fn test_trigger_throttle() {
+		let trigger = TriggerBuilder::new()
+			.name("throttled")
+			.slack("https://hooks.slack.com/services/xxx")
+			.throttle(10, 300, false)
+			.build();
+
+		assert_eq!(
+			trigger.throttle,
+			Some(TriggerThrottle {
+				max_notifications: 10,
+				window_secs: 300,
+				summarize: Some(false),
+			})
+		);
+		assert_eq!(TriggerBuilder::new().build().throttle, None);
+	}
+
+	#[test]
+	fn test_trigger_aggregation() {
+		let trigger = TriggerBuilder::new()
+			.name("aggregated")
+			.slack("https://hooks.slack.com/services/xxx")
+			.aggregation(60)
+			.build();
+
+		assert_eq!(
+			trigger.aggregation,
+			Some(TriggerAggregation { window_secs: 60 })
+		);
+		assert_eq!(TriggerBuilder::new().build().aggregation, None);
+	}
+
+	#[test]
+	fn test_trigger_severities() {
+		let trigger = TriggerBuilder::new()
+			.name("critical_only")
+			.pagerduty("routing_key")
+			.severities(vec![MonitorSeverity::Critical])
+			.build();
+
+		assert_eq!(trigger.severities, Some(vec![MonitorSeverity::Critical]));
+		assert!(trigger.accepts_severity(MonitorSeverity::Critical));
+		assert!(!trigger.accepts_severity(MonitorSeverity::Info));
+		assert!(TriggerBuilder::new()
+			.build()
+			.accepts_severity(MonitorSeverity::Info));
+	}
+
+	#[test]
+	fn test_twilio_trigger() {
+		let trigger = TriggerBuilder::new()
+			.name("twilio_alert")
+			.twilio(
+				"AC0123456789abcdef0123456789abcdef",
+				"token",
+				"+14155550100",
+				vec!["+14155550101", "+14155550102"],
+			)
+			.twilio_auth_token(SecretValue::Environment("TWILIO_AUTH_TOKEN".to_string()))
+			.twilio_max_messages_per_hour(2)
+			.message("Alert", "Test message")
+			.build();
+
+		assert_eq!(trigger.trigger_type, TriggerType::Twilio);
+		match trigger.config {
+			TriggerTypeConfig::Twilio {
+				account_sid,
+				auth_token,
+				from,
+				to,
+				max_messages_per_hour,
+				message,
+			} => {
+				assert_eq!(account_sid, "AC0123456789abcdef0123456789abcdef");
+				assert_eq!(
+					auth_token,
+					SecretValue::Environment("TWILIO_AUTH_TOKEN".to_string())
+				);
+				assert_eq!(from, "+14155550100");
+				assert_eq!(to, vec!["+14155550101", "+14155550102"]);
+				assert_eq!(max_messages_per_hour, Some(2));
+				assert_eq!(message.title, "Alert");
+				assert_eq!(message.body, "Test message");
+			}
+			_ => panic!("Expected twilio config"),
+		}
+	}
+
 	#[test]
 	fn test_telegram_trigger() {
 		let trigger = TriggerBuilder::new()
@@ -512,7 +1032,7 @@ mod tests {
 					token.as_ref().to_string(),
 					"1234567890:ABCdefGHIjklMNOpqrSTUvwxYZ123456789".to_string() // noboost
 				);
-				assert_eq!(chat_id, "1234567890");
+				assert_eq!(chat_id.as_ref().to_string(), "1234567890");
 				assert_eq!(message.title, "Alert");
 				assert_eq!(message.body, "Test message");
 			}
@@ -532,6 +1052,8 @@ mod tests {
 				vec!["recipient@example.com"],
 			)
 			.email_port(465)
+			.email_tls(SmtpTlsMode::Tls)
+			.email_digest_interval_mins(15)
 			.email_subject("Custom Subject")
 			.build();
 
@@ -540,15 +1062,18 @@ mod tests {
 			TriggerTypeConfig::Email {
 				host,
 				port,
+				tls,
 				username,
 				password,
 				message,
 				sender,
 				recipients,
-				..
+				digest_interval_mins,
 			} => {
 				assert_eq!(host, "smtp.example.com");
 				assert_eq!(port, Some(465));
+				assert_eq!(tls, Some(SmtpTlsMode::Tls));
+				assert_eq!(digest_interval_mins, Some(15));
 				assert_eq!(username.as_ref().to_string(), "user");
 				assert_eq!(password.as_ref().to_string(), "pass");
 				assert_eq!(message.title, "Custom Subject");
@@ -23,6 +23,11 @@ mod integration {
 				mod http;
 				mod transport;
 			}
+			mod solana {
+				mod http;
+				mod transport;
+				mod ws;
+			}
 			mod endpoint_manager;
 			mod http;
 		}
@@ -47,9 +52,15 @@ mod integration {
 	mod notifications {
 		mod discord;
 		mod email;
+		mod google_chat;
+		mod matrix;
+		mod nats;
+		mod pagerduty;
 		mod script;
 		mod slack;
+		mod teams;
 		mod telegram;
+		mod twilio;
 		mod webhook;
 	}
 	mod monitor {
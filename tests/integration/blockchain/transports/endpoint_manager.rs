@@ -542,3 +542,103 @@ async fn test_send_raw_request_returns_http_error_if_non_transient() {
 
 	mock.assert();
 }
+
+#[tokio::test]
+async fn test_promote_url() {
+	let manager = EndpointManager::new(
+		get_mock_client_builder(),
+		"https://primary.network",
+		vec![
+			"https://fallback1.network/".to_string(),
+			"https://fallback2.network".to_string(),
+		],
+	);
+
+	manager
+		.promote_url("https://fallback2.network/")
+		.await
+		.unwrap();
+	assert_eq!(
+		&*manager.active_url.read().await,
+		"https://fallback2.network"
+	);
+	assert_eq!(
+		&*manager.fallback_urls.read().await,
+		&vec![
+			"https://fallback1.network/".to_string(),
+			"https://primary.network".to_string(),
+		]
+	);
+
+	let result = manager.promote_url("https://unknown.network").await;
+	assert!(matches!(result, Err(TransportError::UrlRotation(_))));
+	assert_eq!(
+		&*manager.active_url.read().await,
+		"https://fallback2.network"
+	);
+}
+
+#[tokio::test]
+async fn test_send_batch_request() {
+	let mut server = Server::new_async().await;
+
+	// Responses of a batch may come in any order
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Json(json!([
+			{"jsonrpc": "2.0", "id": 0, "method": "getBlockTime", "params": [100]},
+			{"jsonrpc": "2.0", "id": 1, "method": "getBlockTime", "params": [101]},
+		])))
+		.with_status(200)
+		.with_body(
+			json!([
+				{"jsonrpc": "2.0", "id": 1, "result": 1678901235},
+				{"jsonrpc": "2.0", "id": 0, "result": 1678901234},
+			])
+			.to_string(),
+		)
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let responses = manager
+		.send_batch_request(
+			&transport,
+			&[
+				("getBlockTime", Some(json!([100]))),
+				("getBlockTime", Some(json!([101]))),
+			],
+		)
+		.await
+		.unwrap();
+	assert_eq!(responses.len(), 2);
+	assert_eq!(responses[0]["result"], json!(1678901234));
+	assert_eq!(responses[1]["result"], json!(1678901235));
+	mock.assert();
+
+	let responses = manager.send_batch_request(&transport, &[]).await.unwrap();
+	assert!(responses.is_empty());
+}
+
+#[tokio::test]
+async fn test_send_batch_request_missing_response() {
+	let mut server = Server::new_async().await;
+
+	let mock = server
+		.mock("POST", "/")
+		.with_status(200)
+		.with_body(json!([{"jsonrpc": "2.0", "id": 0, "result": 1}]).to_string())
+		.create_async()
+		.await;
+
+	let manager = EndpointManager::new(get_mock_client_builder(), server.url().as_ref(), vec![]);
+	let transport = MockTransport::new();
+
+	let result = manager
+		.send_batch_request(&transport, &[("getSlot", None), ("getBlockHeight", None)])
+		.await;
+	assert!(matches!(result, Err(TransportError::ResponseParse(_))));
+	mock.assert();
+}
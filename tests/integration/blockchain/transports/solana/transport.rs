@@ -0,0 +1,1001 @@
+use base64::Engine;
+use flate2::{write::ZlibEncoder, Compression};
+use mockall::predicate;
+use mockito::Matcher;
+use openzeppelin_monitor::{
+	bootstrap::get_solana_idl_specs,
+	models::{
+		BlockChainType, BlockType, ContractSpec, RpcAuth, RpcUrl, SecretString, SecretValue,
+		SolanaContractSpec, SolanaDecoderType,
+	},
+	services::{
+		blockchain::{
+			BlockChainClient, SolanaBlockSource, SolanaClient, SolanaClientTrait,
+			SolanaEnhancedClient, SolanaGeyserTransportClient,
+		},
+		decoders::idl::idl_account_address,
+	},
+	utils::{
+		metrics::{RPC_REQUEST_DURATION_SECONDS, SOLANA_SLOT_LAG},
+		tests::{network::NetworkBuilder, solana::monitor::MonitorBuilder},
+	},
+};
+use serde_json::{json, Value};
+use solana_sdk::{
+	hash::Hash,
+	instruction::{AccountMeta, CompiledInstruction, Instruction},
+	message::{v0, MessageHeader, VersionedMessage},
+	pubkey::Pubkey,
+	signature::{Keypair, Signature, Signer},
+	transaction::{Transaction, VersionedTransaction},
+};
+use solana_transaction_status::{Encodable, UiTransactionEncoding};
+use std::io::Write;
+
+use crate::integration::mocks::MockSolanaTransportClient;
+
+#[tokio::test]
+async fn test_get_multiple_accounts_success() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let existing = Pubkey::new_unique();
+	let missing = Pubkey::new_unique();
+	let owner = Pubkey::new_unique();
+
+	let expected_params = json!([
+		[existing.to_string(), missing.to_string()],
+		{ "encoding": "base64", "commitment": "confirmed" }
+	]);
+
+	let mock_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"context": { "slot": 100 },
+			"value": [
+				{
+					"lamports": 1_000_000,
+					"data": [base64::engine::general_purpose::STANDARD.encode([1, 2, 3]), "base64"],
+					"owner": owner.to_string(),
+					"executable": false,
+					"rentEpoch": 18446744073709551615u64,
+					"space": 3
+				},
+				null
+			]
+		}
+	});
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getMultipleAccounts"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap() == &expected_params
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(mock_response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let accounts = client
+		.get_multiple_accounts(&[existing, missing])
+		.await
+		.unwrap();
+
+	assert_eq!(accounts.len(), 2);
+	let account = accounts[0].as_ref().unwrap();
+	assert_eq!(account.lamports, 1_000_000);
+	assert_eq!(account.owner, owner);
+	assert_eq!(account.data, vec![1, 2, 3]);
+	assert!(accounts[1].is_none());
+}
+
+#[tokio::test]
+async fn test_get_multiple_accounts_batches_requests() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let addresses = (0..150).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(2)
+		.returning(|_, params| {
+			let count = params.unwrap()[0].as_array().unwrap().len();
+			Ok(json!({ "result": { "value": vec![Value::Null; count] } }))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let accounts = client.get_multiple_accounts(&addresses).await.unwrap();
+
+	assert_eq!(accounts.len(), 150);
+	assert!(accounts.iter().all(Option::is_none));
+}
+
+#[tokio::test]
+async fn test_get_multiple_accounts_rpc_error() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	let mock_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"error": {
+			"code": -32603,
+			"message": "Internal error"
+		}
+	});
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(1)
+		.returning(move |_, _| Ok(mock_response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_multiple_accounts(&[Pubkey::new_unique()]).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Solana RPC request failed for method 'getMultipleAccounts'"));
+}
+
+#[tokio::test]
+async fn test_get_multiple_accounts_unexpected_response_structure() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({ "result": { "unexpectedField": [] } })));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_multiple_accounts(&[Pubkey::new_unique()]).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Missing 'result.value' field"));
+}
+
+#[tokio::test]
+async fn test_get_transaction_success() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let fee_payer = Keypair::new();
+	let program_id = Pubkey::new_unique();
+	let mut transaction = Transaction::new_with_payer(
+		&[Instruction {
+			program_id,
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+			data: vec![1, 2, 3],
+		}],
+		Some(&fee_payer.pubkey()),
+	);
+	transaction.sign(&[&fee_payer], Default::default());
+	let signature = transaction.signatures[0];
+
+	let expected_params = json!([
+		signature.to_string(),
+		{ "encoding": "base64", "commitment": "confirmed", "maxSupportedTransactionVersion": 0 }
+	]);
+
+	let mock_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"slot": 100,
+			"blockTime": 1678901234,
+			"transaction": VersionedTransaction::from(transaction).encode(UiTransactionEncoding::Base64),
+			"meta": {
+				"err": null,
+				"status": { "Ok": null },
+				"fee": 5000,
+				"preBalances": [1_000_000, 0, 1],
+				"postBalances": [995_000, 0, 1]
+			}
+		}
+	});
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getTransaction"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap() == &expected_params
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(mock_response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let transaction = client.get_transaction(&signature).await.unwrap().unwrap();
+
+	assert_eq!(transaction.signature(), &signature);
+	assert_eq!(transaction.slot(), 100);
+	assert_eq!(transaction.block_time(), Some(1678901234));
+	assert_eq!(transaction.fee_payer(), &fee_payer.pubkey());
+	assert_eq!(transaction.meta().fee, 5000);
+	assert_eq!(transaction.instructions().len(), 1);
+	assert_eq!(transaction.instructions()[0].program_id, program_id);
+}
+
+#[tokio::test]
+async fn test_get_transaction_resolves_lookup_tables() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let program_id = Pubkey::new_unique();
+	let table = Pubkey::new_unique();
+	let loaded = [Pubkey::new_unique(), Pubkey::new_unique()];
+	let transaction = VersionedTransaction {
+		signatures: vec![Signature::new_unique()],
+		message: VersionedMessage::V0(v0::Message {
+			header: MessageHeader {
+				num_required_signatures: 1,
+				num_readonly_signed_accounts: 0,
+				num_readonly_unsigned_accounts: 1,
+			},
+			account_keys: vec![Pubkey::new_unique(), program_id],
+			recent_blockhash: Hash::default(),
+			instructions: vec![CompiledInstruction {
+				program_id_index: 1,
+				accounts: vec![2, 3],
+				data: vec![1, 2, 3],
+			}],
+			address_table_lookups: vec![v0::MessageAddressTableLookup {
+				account_key: table,
+				writable_indexes: vec![0],
+				readonly_indexes: vec![1],
+			}],
+		}),
+	};
+
+	// The response lacks `loadedAddresses`
+	let transaction_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"slot": 100,
+			"blockTime": null,
+			"transaction": transaction.encode(UiTransactionEncoding::Base64),
+			"meta": {
+				"err": null,
+				"status": { "Ok": null },
+				"fee": 5000,
+				"preBalances": [],
+				"postBalances": []
+			}
+		}
+	});
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getTransaction"), predicate::always())
+		.times(1)
+		.returning(move |_, _| Ok(transaction_response.clone()));
+
+	let mut table_data = vec![0u8; 56];
+	table_data[0] = 1;
+	for address in &loaded {
+		table_data.extend_from_slice(address.as_ref());
+	}
+	let expected_params = json!([
+		[table.to_string()],
+		{ "encoding": "base64", "commitment": "confirmed" }
+	]);
+	let table_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"context": { "slot": 100 },
+			"value": [{
+				"lamports": 1_000_000,
+				"data": [base64::engine::general_purpose::STANDARD.encode(&table_data), "base64"],
+				"owner": "AddressLookupTab1e1111111111111111111111111",
+				"executable": false,
+				"rentEpoch": 0,
+				"space": table_data.len()
+			}]
+		}
+	});
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getMultipleAccounts"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap() == &expected_params
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(table_response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let transaction = client
+		.get_transaction(&transaction.signatures[0])
+		.await
+		.unwrap()
+		.unwrap();
+
+	assert_eq!(transaction.instructions()[0].program_id, program_id);
+	assert_eq!(
+		transaction.instructions()[0].accounts,
+		vec![
+			AccountMeta::new(loaded[0], false),
+			AccountMeta::new_readonly(loaded[1], false)
+		]
+	);
+}
+
+#[tokio::test]
+async fn test_get_transaction_missing_lookup_table() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let table = Pubkey::new_unique();
+	let transaction = VersionedTransaction {
+		signatures: vec![Signature::new_unique()],
+		message: VersionedMessage::V0(v0::Message {
+			header: MessageHeader {
+				num_required_signatures: 1,
+				num_readonly_signed_accounts: 0,
+				num_readonly_unsigned_accounts: 1,
+			},
+			account_keys: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+			recent_blockhash: Hash::default(),
+			instructions: vec![CompiledInstruction {
+				program_id_index: 1,
+				accounts: vec![2],
+				data: vec![],
+			}],
+			address_table_lookups: vec![v0::MessageAddressTableLookup {
+				account_key: table,
+				writable_indexes: vec![0],
+				readonly_indexes: vec![],
+			}],
+		}),
+	};
+
+	let transaction_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"slot": 100,
+			"blockTime": null,
+			"transaction": transaction.encode(UiTransactionEncoding::Base64),
+			"meta": null
+		}
+	});
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getTransaction"), predicate::always())
+		.times(1)
+		.returning(move |_, _| Ok(transaction_response.clone()));
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": { "context": { "slot": 100 }, "value": [null] }
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_transaction(&transaction.signatures[0]).await;
+
+	// Closed lookup tables are reported as an error rather than a panic
+	let error = format!("{:#}", result.unwrap_err());
+	assert!(error.contains("Failed to decode transaction"));
+	assert!(error.contains(&format!(
+		"Missing addresses loaded from lookup tables {}",
+		table
+	)));
+}
+
+#[tokio::test]
+async fn test_get_transaction_not_found() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getTransaction"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": null })));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let transaction = client
+		.get_transaction(&Signature::new_unique())
+		.await
+		.unwrap();
+
+	assert!(transaction.is_none());
+}
+
+#[tokio::test]
+async fn test_get_transaction_rpc_error() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getTransaction"), predicate::always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": { "code": -32602, "message": "Invalid param" }
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let result = client.get_transaction(&Signature::new_unique()).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Solana RPC request failed for method 'getTransaction'"));
+}
+
+#[tokio::test]
+async fn test_record_slot_lag() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getSlot"),
+			predicate::eq(Some(json!([{ "commitment": "confirmed" }]))),
+		)
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": 1_000 })));
+
+	let client =
+		SolanaClient::new_with_transport(mock_solana).with_network_slug("solana_slot_lag_test");
+	let slot_lag = client.record_slot_lag(940).await.unwrap();
+
+	assert_eq!(slot_lag, 60);
+	assert_eq!(
+		SOLANA_SLOT_LAG
+			.with_label_values(&["solana_slot_lag_test"])
+			.get(),
+		60.0
+	);
+	assert_eq!(
+		RPC_REQUEST_DURATION_SECONDS
+			.with_label_values(&["solana_slot_lag_test", "getSlot"])
+			.get_sample_count(),
+		1
+	);
+}
+
+#[tokio::test]
+async fn test_record_slot_lag_rpc_error() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getSlot"), predicate::always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": { "code": -32005, "message": "Node is behind" }
+			}))
+		});
+
+	let client =
+		SolanaClient::new_with_transport(mock_solana).with_network_slug("solana_rpc_error_test");
+	let result = client.record_slot_lag(0).await;
+
+	assert!(result.is_err());
+	assert!(result
+		.unwrap_err()
+		.to_string()
+		.contains("Solana RPC request failed for method 'getSlot'"));
+	// Failed requests are timed as well
+	assert_eq!(
+		RPC_REQUEST_DURATION_SECONDS
+			.with_label_values(&["solana_rpc_error_test", "getSlot"])
+			.get_sample_count(),
+		1
+	);
+}
+
+fn signature_statuses(slots: impl Iterator<Item = u64>) -> (Vec<Signature>, Value) {
+	let signatures = slots
+		.map(|slot| (Signature::new_unique(), slot))
+		.collect::<Vec<_>>();
+	let statuses = signatures
+		.iter()
+		.map(|(signature, slot)| {
+			json!({
+				"signature": signature.to_string(),
+				"slot": slot,
+				"err": null,
+				"memo": null,
+				"blockTime": null,
+				"confirmationStatus": "finalized"
+			})
+		})
+		.collect::<Vec<_>>();
+	(
+		signatures
+			.into_iter()
+			.map(|(signature, _)| signature)
+			.collect(),
+		json!({ "jsonrpc": "2.0", "id": 1, "result": statuses }),
+	)
+}
+
+#[tokio::test]
+async fn test_get_signatures_for_address_pages_through_slot_range() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let address = Pubkey::new_unique();
+
+	// A full first page, newest first, followed by a page reaching past the start slot
+	let (first_signatures, first_page) = signature_statuses((1001..=2000).rev());
+	let (second_signatures, second_page) = signature_statuses((990..=1000).rev());
+	let before = first_signatures.last().unwrap().to_string();
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getSignaturesForAddress"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap()[1].get("before").is_none()
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(first_page.clone()));
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getSignaturesForAddress"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap()[1]["before"] == json!(before)
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(second_page.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let signatures = client
+		.get_signatures_for_address(&address, 995, 1500)
+		.await
+		.unwrap();
+
+	// Slots 995 to 1000 from the second page, then 1001 to 1500 from the first one
+	let expected = second_signatures[..6]
+		.iter()
+		.rev()
+		.chain(first_signatures[500..].iter().rev())
+		.copied()
+		.collect::<Vec<_>>();
+	assert_eq!(signatures, expected);
+}
+
+#[tokio::test]
+async fn test_get_transactions_for_address() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let address = Pubkey::new_unique();
+	let fee_payer = Keypair::new();
+	let mut transaction = Transaction::new_with_payer(
+		&[Instruction {
+			program_id: address,
+			accounts: vec![],
+			data: vec![1],
+		}],
+		Some(&fee_payer.pubkey()),
+	);
+	transaction.sign(&[&fee_payer], Default::default());
+	let signature = transaction.signatures[0];
+
+	let statuses = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": [
+			{ "signature": signature.to_string(), "slot": 100, "err": null, "memo": null, "blockTime": null },
+			{ "signature": Signature::new_unique().to_string(), "slot": 50, "err": null, "memo": null, "blockTime": null }
+		]
+	});
+	let transaction_response = json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"slot": 100,
+			"blockTime": 1678901234,
+			"transaction": VersionedTransaction::from(transaction).encode(UiTransactionEncoding::Base64),
+			"meta": {
+				"err": null,
+				"status": { "Ok": null },
+				"fee": 5000,
+				"preBalances": [1_000_000, 1],
+				"postBalances": [995_000, 1]
+			}
+		}
+	});
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getSignaturesForAddress"),
+			predicate::always(),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(statuses.clone()));
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getTransaction"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap()[0] == json!(signature.to_string())
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(transaction_response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let transactions = client
+		.get_transactions_for_address(&address, 60, 200)
+		.await
+		.unwrap();
+
+	assert_eq!(transactions.len(), 1);
+	assert_eq!(transactions[0].signature(), &signature);
+	assert_eq!(transactions[0].slot(), 100);
+}
+
+#[tokio::test]
+async fn test_get_enhanced_transactions() {
+	let mut server = mockito::Server::new_async().await;
+	let signature = Keypair::new().sign_message(b"swap");
+	let missing = Keypair::new().sign_message(b"missing");
+
+	let mock = server
+		.mock("POST", "/v0/transactions")
+		.match_header("authorization", "Bearer token")
+		.match_body(Matcher::Json(json!({
+			"transactions": [signature.to_string(), missing.to_string()]
+		})))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(
+			json!([{
+				"signature": signature.to_string(),
+				"type": "SWAP",
+				"source": "JUPITER",
+				"description": "A swapped 1 SOL for 150 USDC on JUPITER",
+				"tokenTransfers": [{ "tokenAmount": 150 }]
+			}])
+			.to_string(),
+		)
+		.create_async()
+		.await;
+
+	let enhanced_client = SolanaEnhancedClient::new(&RpcUrl {
+		type_: "enhanced".to_string(),
+		url: SecretValue::Plain(SecretString::new(format!(
+			"{}/v0/transactions",
+			server.url()
+		))),
+		weight: 100,
+		rate_limit: None,
+		headers: None,
+		auth: Some(RpcAuth::Bearer {
+			token: SecretValue::Plain(SecretString::new("token".to_string())),
+		}),
+	})
+	.await
+	.unwrap();
+	let client = SolanaClient::new_with_transport(MockSolanaTransportClient::new())
+		.with_enhanced_client(enhanced_client);
+
+	let transactions = client
+		.get_enhanced_transactions(&[signature, missing])
+		.await
+		.unwrap();
+
+	assert_eq!(transactions.len(), 1);
+	assert_eq!(transactions[&signature].type_, "SWAP");
+	assert_eq!(transactions[&signature].source, "JUPITER");
+	assert_eq!(transactions[&signature].token_transfers.len(), 1);
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_enhanced_transactions_without_enhanced_client() {
+	let client = SolanaClient::new_with_transport(MockSolanaTransportClient::new());
+	let signature = Keypair::new().sign_message(b"swap");
+
+	assert!(client
+		.get_enhanced_transactions(&[signature])
+		.await
+		.unwrap()
+		.is_empty());
+}
+
+/// Builds the `getMultipleAccounts` response of an Anchor IDL account holding an IDL
+fn idl_account_response(program_id: &Pubkey, idl: &Value) -> Value {
+	let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+	encoder.write_all(idl.to_string().as_bytes()).unwrap();
+	let compressed = encoder.finish().unwrap();
+
+	let mut data = vec![0u8; 8];
+	data.extend_from_slice(Pubkey::new_unique().as_ref());
+	data.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+	data.extend_from_slice(&compressed);
+
+	json!({
+		"jsonrpc": "2.0",
+		"id": 1,
+		"result": {
+			"context": { "slot": 100 },
+			"value": [{
+				"lamports": 1_000_000,
+				"data": [base64::engine::general_purpose::STANDARD.encode(&data), "base64"],
+				"owner": program_id.to_string(),
+				"executable": false,
+				"rentEpoch": 18446744073709551615u64,
+				"space": data.len()
+			}]
+		}
+	})
+}
+
+#[tokio::test]
+async fn test_get_idl_is_cached() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let program_id = Pubkey::new_unique();
+	let expected_params = json!([
+		[idl_account_address(&program_id).unwrap().to_string()],
+		{ "encoding": "base64", "commitment": "confirmed" }
+	]);
+	let response = idl_account_response(
+		&program_id,
+		&json!({ "instructions": [{ "name": "deposit", "accounts": [], "args": [] }] }),
+	);
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getMultipleAccounts"),
+			predicate::function(move |params: &Option<Value>| {
+				params.as_ref().unwrap() == &expected_params
+			}),
+		)
+		.times(1)
+		.returning(move |_, _| Ok(response.clone()));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	for _ in 0..2 {
+		let idl = client.get_idl(&program_id).await.unwrap().unwrap();
+		assert_eq!(idl.instructions[0].name, "deposit");
+		assert!(idl.instructions[0].discriminator.is_some());
+	}
+}
+
+#[tokio::test]
+async fn test_get_idl_without_idl_account() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": { "context": { "slot": 100 }, "value": [null] }
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	let program_id = Pubkey::new_unique();
+	assert!(client.get_idl(&program_id).await.unwrap().is_none());
+	assert!(client.get_idl(&program_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_get_solana_idl_specs() {
+	let program_id = Pubkey::new_unique();
+	let response = idl_account_response(
+		&program_id,
+		&json!({ "instructions": [{ "name": "deposit", "accounts": [], "args": [] }] }),
+	);
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getMultipleAccounts"), predicate::always())
+		.times(1)
+		.returning(move |_, _| Ok(response.clone()));
+	let client = SolanaClient::new_with_transport(mock_solana);
+
+	let spec = ContractSpec::Solana(SolanaContractSpec::new(
+		SolanaDecoderType::InstructionNames(vec!["Deposit".to_string()]),
+	));
+	let monitor = MonitorBuilder::new()
+		.address(&program_id.to_string(), None)
+		.address(&Pubkey::new_unique().to_string(), Some(spec))
+		// Programs with a built-in decoder need no IDL
+		.address("KLend2g3cP87fffoy8q1mQqGKjrxjC8boSyAYavgmjD", None)
+		.build();
+	let network = NetworkBuilder::new()
+		.network_type(BlockChainType::Solana)
+		.fetch_onchain_idls(true)
+		.build();
+
+	let specs = get_solana_idl_specs(&client, &network, &[monitor.clone()]).await;
+	assert_eq!(specs.len(), 1);
+	assert_eq!(specs[0].0, program_id.to_string());
+	let ContractSpec::Solana(spec) = &specs[0].1 else {
+		panic!("expected a Solana contract spec");
+	};
+	assert_eq!(spec.idl().unwrap().instructions[0].name, "deposit");
+
+	// IDLs are only fetched for networks that enable it
+	let network = NetworkBuilder::new()
+		.network_type(BlockChainType::Solana)
+		.build();
+	assert!(get_solana_idl_specs(&client, &network, &[monitor])
+		.await
+		.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_blocks_fetches_confirmed_slots() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	let fee_payer = Keypair::new();
+	let mut transaction = Transaction::new_with_payer(
+		&[Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+			data: vec![1, 2, 3],
+		}],
+		Some(&fee_payer.pubkey()),
+	);
+	transaction.sign(&[&fee_payer], Default::default());
+	let signature = transaction.signatures[0];
+	let encoded = VersionedTransaction::from(transaction).encode(UiTransactionEncoding::Base64);
+
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getBlocks"),
+			predicate::eq(Some(json!([100, 102, { "commitment": "confirmed" }]))),
+		)
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": [100, 102] })));
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getBlock"),
+			predicate::function(|params: &Option<Value>| params.as_ref().unwrap()[0] == 100),
+		)
+		.times(1)
+		.returning(move |_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"result": {
+					"previousBlockhash": "11111111111111111111111111111111",
+					"blockhash": "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn",
+					"parentSlot": 99,
+					"blockTime": 1678901234,
+					"blockHeight": 90,
+					"transactions": [{
+						"transaction": encoded.clone(),
+						"meta": {
+							"err": null,
+							"status": { "Ok": null },
+							"fee": 5000,
+							"preBalances": [1_000_000, 0, 1],
+							"postBalances": [995_000, 0, 1]
+						}
+					}]
+				}
+			}))
+		});
+	// The node no longer has the block of slot 102
+	mock_solana
+		.expect_send_raw_request()
+		.with(
+			predicate::eq("getBlock"),
+			predicate::function(|params: &Option<Value>| params.as_ref().unwrap()[0] == 102),
+		)
+		.times(1)
+		.returning(|_, _| {
+			Ok(json!({
+				"jsonrpc": "2.0",
+				"id": 1,
+				"error": {
+					"code": -32009,
+					"message": "Slot 102 was skipped, or missing in long-term storage"
+				}
+			}))
+		});
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	assert!(client.block_source().is_none());
+	let blocks = client.get_blocks(100, Some(102)).await.unwrap();
+
+	assert_eq!(blocks.len(), 1);
+	let BlockType::Solana(block) = &blocks[0] else {
+		panic!("Expected Solana block");
+	};
+	assert_eq!(block.slot, 100);
+	assert_eq!(block.parent_slot, 99);
+	assert_eq!(block.block_height, Some(90));
+	assert_eq!(block.transactions.len(), 1);
+	assert_eq!(block.decoded_transactions[0].signature(), &signature);
+	assert_eq!(block.decoded_transactions[0].meta().fee, 5000);
+}
+
+#[tokio::test]
+async fn test_get_latest_block_number_without_block_source() {
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getSlot"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": 100 })));
+
+	let client = SolanaClient::new_with_transport(mock_solana);
+	assert_eq!(client.get_latest_block_number().await.unwrap(), 100);
+}
+
+#[tokio::test]
+async fn test_new_prefers_geyser_block_source() {
+	let mut server = mockito::Server::new_async().await;
+	let _health_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({ "method": "getHealth" })))
+		.with_header("content-type", "application/json")
+		.with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": "ok" }).to_string())
+		.create();
+
+	let network = NetworkBuilder::new()
+		.name("test")
+		.slug("test")
+		.network_type(BlockChainType::Solana)
+		.clear_rpc_urls()
+		.add_rpc_url(&server.url(), "rpc", 100)
+		.add_rpc_url("ws://127.0.0.1:1", "ws", 100)
+		.add_rpc_url("http://127.0.0.1:1", "geyser", 100)
+		.build();
+	let client = SolanaClient::new(&network).await.unwrap();
+
+	let Some(SolanaBlockSource::Geyser(geyser_client)) = client.block_source() else {
+		panic!("Expected Geyser block source");
+	};
+	assert_eq!(geyser_client.geyser_urls(), ["http://127.0.0.1:1"]);
+}
+
+#[tokio::test]
+async fn test_get_blocks_falls_back_while_geyser_is_unreachable() {
+	let network = NetworkBuilder::new()
+		.network_type(BlockChainType::Solana)
+		.clear_rpc_urls()
+		.add_rpc_url("http://127.0.0.1:1", "geyser", 100)
+		.build();
+	let geyser_client = SolanaGeyserTransportClient::new(&network).unwrap();
+
+	let mut mock_solana = MockSolanaTransportClient::new();
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getSlot"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": 100 })));
+	mock_solana
+		.expect_send_raw_request()
+		.with(predicate::eq("getBlocks"), predicate::always())
+		.times(1)
+		.returning(|_, _| Ok(json!({ "jsonrpc": "2.0", "id": 1, "result": [] })));
+
+	let client = SolanaClient::new_with_transport(mock_solana)
+		.with_block_source(SolanaBlockSource::Geyser(geyser_client));
+
+	// No block was received from the stream, so the slots are fetched over HTTP
+	assert_eq!(client.get_latest_block_number().await.unwrap(), 100);
+	assert!(client.get_blocks(99, Some(100)).await.unwrap().is_empty());
+}
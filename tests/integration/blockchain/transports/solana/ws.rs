@@ -0,0 +1,496 @@
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use mockito::{Matcher, Server};
+use openzeppelin_monitor::{
+	models::{BlockChainType, BlockType, Network},
+	services::blockchain::{
+		BlockChainClient, SolanaBlockSource, SolanaClient, SolanaWsTransportClient,
+	},
+	utils::tests::network::NetworkBuilder,
+};
+use serde_json::{json, Value};
+use solana_sdk::{
+	instruction::{AccountMeta, Instruction},
+	pubkey::Pubkey,
+	signature::{Keypair, Signer},
+	transaction::{Transaction, VersionedTransaction},
+};
+use solana_transaction_status::{Encodable, UiTransactionEncoding};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Address nothing listens on, used to simulate an unreachable WebSocket endpoint
+const UNREACHABLE_WS_URL: &str = "ws://127.0.0.1:1";
+
+fn create_solana_test_network(ws_url: &str, http_url: Option<&str>) -> Network {
+	let builder = NetworkBuilder::new()
+		.name("test")
+		.slug("test")
+		.network_type(BlockChainType::Solana)
+		.block_time_ms(50)
+		.clear_rpc_urls()
+		.add_rpc_url(ws_url, "ws", 100);
+
+	match http_url {
+		Some(url) => builder.add_rpc_url(url, "rpc", 100),
+		None => builder,
+	}
+	.build()
+}
+
+fn mock_rpc(server: &mut Server, method: &str, result: Value) -> mockito::Mock {
+	server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(json!({ "method": method })))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string())
+		.create()
+}
+
+/// Answers batches of requests to a method, with one result per request of the batch
+fn mock_batch_rpc(server: &mut Server, method: &str, results: Vec<Value>) -> mockito::Mock {
+	let responses = results
+		.into_iter()
+		.enumerate()
+		.map(|(id, result)| json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+		.collect::<Vec<_>>();
+	server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(format!(r#"^\[.*"method":"{}""#, method)))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(Value::Array(responses).to_string())
+		.create()
+}
+
+/// Accepts WebSocket connections, answering slot subscriptions with the given slots
+/// and closing each connection once its slots are sent
+async fn serve_slots(listener: TcpListener, connections: Vec<Vec<u64>>) {
+	for slots in connections {
+		let (stream, _) = listener.accept().await.unwrap();
+		let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+		let request = loop {
+			match ws.next().await.unwrap().unwrap() {
+				Message::Text(text) => break serde_json::from_str::<Value>(&text).unwrap(),
+				_ => continue,
+			}
+		};
+		assert_eq!(request["method"], "slotSubscribe");
+
+		let response = json!({ "jsonrpc": "2.0", "result": 1, "id": request["id"] });
+		ws.send(Message::Text(response.to_string())).await.unwrap();
+
+		for slot in slots {
+			let notification = json!({
+				"jsonrpc": "2.0",
+				"method": "slotNotification",
+				"params": {
+					"result": { "slot": slot, "parent": slot - 1, "root": slot - 2 },
+					"subscription": 1
+				}
+			});
+			ws.send(Message::Text(notification.to_string()))
+				.await
+				.unwrap();
+		}
+		ws.close(None).await.unwrap();
+	}
+}
+
+/// Accepts a WebSocket connection, answering a block subscription with the given blocks
+/// and keeping the connection open
+async fn serve_blocks(listener: TcpListener, blocks: Vec<Value>) {
+	let (stream, _) = listener.accept().await.unwrap();
+	let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+	let request = loop {
+		match ws.next().await.unwrap().unwrap() {
+			Message::Text(text) => break serde_json::from_str::<Value>(&text).unwrap(),
+			_ => continue,
+		}
+	};
+	assert_eq!(request["method"], "blockSubscribe");
+	let response = json!({ "jsonrpc": "2.0", "result": 1, "id": request["id"] });
+	ws.send(Message::Text(response.to_string())).await.unwrap();
+
+	for block in blocks {
+		let slot = block["parentSlot"].as_u64().unwrap() + 1;
+		let notification = json!({
+			"jsonrpc": "2.0",
+			"method": "blockNotification",
+			"params": {
+				"result": {
+					"context": { "slot": slot },
+					"value": { "slot": slot, "block": block, "err": null }
+				},
+				"subscription": 1
+			}
+		});
+		ws.send(Message::Text(notification.to_string()))
+			.await
+			.unwrap();
+	}
+
+	while ws.next().await.is_some() {}
+}
+
+async fn recv<T>(receiver: &mut tokio::sync::mpsc::Receiver<T>) -> T {
+	tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+		.await
+		.expect("Timed out waiting for an update")
+		.expect("Subscription closed")
+}
+
+#[tokio::test]
+async fn test_subscribe_slots_reconnects_and_skips_seen_slots() {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+	tokio::spawn(serve_slots(listener, vec![vec![5, 5, 6], vec![6, 7]]));
+
+	let network = create_solana_test_network(&ws_url, None);
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+	let mut slots = client.subscribe_slots();
+
+	assert_eq!(recv(&mut slots).await, 5);
+	assert_eq!(recv(&mut slots).await, 6);
+	assert_eq!(recv(&mut slots).await, 7);
+}
+
+#[tokio::test]
+async fn test_subscribe_slots_falls_back_to_http_polling() {
+	let mut server = Server::new_async().await;
+	let health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+
+	let network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+	assert!(client.has_http_fallback());
+
+	let mut slots = client.subscribe_slots();
+	assert_eq!(recv(&mut slots).await, 100);
+
+	health_mock.assert();
+}
+
+#[tokio::test]
+async fn test_subscribe_blocks_falls_back_to_http_polling() {
+	let mut server = Server::new_async().await;
+	let fee_payer = Keypair::new();
+	let program_id = Pubkey::new_unique();
+	let mut transaction = Transaction::new_with_payer(
+		&[Instruction {
+			program_id,
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+			data: vec![1, 2, 3],
+		}],
+		Some(&fee_payer.pubkey()),
+	);
+	transaction.sign(&[&fee_payer], Default::default());
+	let signature = transaction.signatures[0];
+
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+	let blocks_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::PartialJson(
+			json!({ "method": "getBlocks", "params": [100, 100] }),
+		))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(json!({ "jsonrpc": "2.0", "id": 1, "result": [100] }).to_string())
+		.create();
+	let block_mock = mock_batch_rpc(
+		&mut server,
+		"getBlock",
+		vec![json!({
+			"previousBlockhash": "11111111111111111111111111111111",
+			"blockhash": "11111111111111111111111111111111",
+			"parentSlot": 99,
+			"blockTime": 1678901234,
+			"blockHeight": 90,
+			"transactions": [{
+				"transaction": VersionedTransaction::from(transaction)
+					.encode(UiTransactionEncoding::Base64),
+				"meta": {
+					"err": null,
+					"status": { "Ok": null },
+					"fee": 5000,
+					"preBalances": [1_000_000, 0, 1],
+					"postBalances": [995_000, 0, 1]
+				}
+			}]
+		})],
+	);
+
+	let network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+
+	let mut blocks = client.subscribe_blocks();
+	let block = recv(&mut blocks).await;
+
+	assert_eq!(block.slot, 100);
+	assert_eq!(block.block_time, Some(1678901234));
+	assert_eq!(block.transactions.len(), 1);
+	assert_eq!(block.transactions[0].signature(), &signature);
+	assert_eq!(block.transactions[0].slot(), 100);
+	assert_eq!(block.transactions[0].meta().fee, 5000);
+	blocks_mock.assert();
+	block_mock.assert();
+}
+
+#[tokio::test]
+async fn test_subscribe_blocks_does_not_fetch_skipped_slots() {
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+	let blocks_mock = mock_rpc(&mut server, "getBlocks", json!([]));
+	let block_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r#""method":"getBlock""#.to_string()))
+		.expect(0)
+		.create();
+
+	let network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+
+	let mut blocks = client.subscribe_blocks();
+	tokio::time::sleep(Duration::from_millis(300)).await;
+
+	assert!(blocks.try_recv().is_err());
+	blocks_mock.assert();
+	block_mock.assert();
+}
+
+#[tokio::test]
+async fn test_subscribe_blocks_fetches_old_blocks_from_archive() {
+	let block = |slot: u64| {
+		json!({
+			"previousBlockhash": "11111111111111111111111111111111",
+			"blockhash": "11111111111111111111111111111111",
+			"parentSlot": slot - 1,
+			"blockTime": 1678901234,
+			"blockHeight": slot,
+			"transactions": []
+		})
+	};
+
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+	let _blocks_mock = mock_rpc(&mut server, "getBlocks", json!([99, 100]));
+	let block_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r#"^\[.*"method":"getBlock""#.to_string()))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(
+			json!([
+				{
+					"jsonrpc": "2.0",
+					"id": 0,
+					"error": {
+						"code": -32009,
+						"message": "Slot 99 was skipped, or missing in long-term storage"
+					}
+				},
+				{ "jsonrpc": "2.0", "id": 1, "result": block(100) }
+			])
+			.to_string(),
+		)
+		.create();
+
+	let mut archive_server = Server::new_async().await;
+	let _archive_health_mock = mock_rpc(&mut archive_server, "getHealth", json!("ok"));
+	let archive_block_mock = archive_server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(
+			r#"^\[\{"id":0,"jsonrpc":"2.0","method":"getBlock","params":\[99,"#.to_string(),
+		))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(json!([{ "jsonrpc": "2.0", "id": 0, "result": block(99) }]).to_string())
+		.create();
+
+	let mut network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	network.rpc_urls.extend(
+		NetworkBuilder::new()
+			.clear_rpc_urls()
+			.add_rpc_url(&archive_server.url(), "archive", 100)
+			.build()
+			.rpc_urls,
+	);
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+
+	let mut blocks = client.subscribe_blocks();
+	assert_eq!(recv(&mut blocks).await.slot, 99);
+	assert_eq!(recv(&mut blocks).await.slot, 100);
+	block_mock.assert();
+	archive_block_mock.assert();
+}
+
+#[tokio::test]
+async fn test_subscribe_blocks_skips_old_blocks_without_archive() {
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+	let _blocks_mock = mock_rpc(&mut server, "getBlocks", json!([100]));
+	let block_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r#"^\[.*"method":"getBlock""#.to_string()))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(
+			json!([{
+				"jsonrpc": "2.0",
+				"id": 0,
+				"error": {
+					"code": -32009,
+					"message": "Slot 100 was skipped, or missing in long-term storage"
+				}
+			}])
+			.to_string(),
+		)
+		.expect(1)
+		.create();
+
+	let network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+
+	let mut blocks = client.subscribe_blocks();
+	tokio::time::sleep(Duration::from_millis(300)).await;
+
+	// The slot is not requested again on the next polls
+	assert!(blocks.try_recv().is_err());
+	block_mock.assert();
+}
+
+#[tokio::test]
+async fn test_subscribe_blocks_retries_unavailable_blocks() {
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(100));
+	let _blocks_mock = mock_rpc(&mut server, "getBlocks", json!([100]));
+	let block_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r#"^\[.*"method":"getBlock""#.to_string()))
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(
+			json!([{
+				"jsonrpc": "2.0",
+				"id": 0,
+				"error": { "code": -32004, "message": "Block not available for slot 100" }
+			}])
+			.to_string(),
+		)
+		.expect_at_least(2)
+		.create();
+
+	let network = create_solana_test_network(UNREACHABLE_WS_URL, Some(&server.url()));
+	let client = SolanaWsTransportClient::new(&network).await.unwrap();
+
+	let mut blocks = client.subscribe_blocks();
+	tokio::time::sleep(Duration::from_millis(300)).await;
+
+	// Transient failures are retried on the next polls
+	assert!(blocks.try_recv().is_err());
+	block_mock.assert();
+}
+
+#[tokio::test]
+async fn test_solana_client_receives_blocks_over_ws() {
+	let fee_payer = Keypair::new();
+	let mut transaction = Transaction::new_with_payer(
+		&[Instruction {
+			program_id: Pubkey::new_unique(),
+			accounts: vec![AccountMeta::new(Pubkey::new_unique(), false)],
+			data: vec![1, 2, 3],
+		}],
+		Some(&fee_payer.pubkey()),
+	);
+	transaction.sign(&[&fee_payer], Default::default());
+	let signature = transaction.signatures[0];
+
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+	tokio::spawn(serve_blocks(
+		listener,
+		vec![json!({
+			"previousBlockhash": "11111111111111111111111111111111",
+			"blockhash": "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn",
+			"parentSlot": 99,
+			"blockTime": 1678901234,
+			"blockHeight": 90,
+			"transactions": [{
+				"transaction": VersionedTransaction::from(transaction)
+					.encode(UiTransactionEncoding::Base64),
+				"meta": {
+					"err": null,
+					"status": { "Ok": null },
+					"fee": 5000,
+					"preBalances": [1_000_000, 0, 1],
+					"postBalances": [995_000, 0, 1]
+				}
+			}]
+		})],
+	));
+
+	// Only the latest slot is requested over HTTP, until the first block is received
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+	let _slot_mock = mock_rpc(&mut server, "getSlot", json!(99));
+	let blocks_mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Regex(r#""method":"getBlocks?""#.to_string()))
+		.expect(0)
+		.create();
+
+	let network = create_solana_test_network(&ws_url, Some(&server.url()));
+	let client = SolanaClient::new(&network).await.unwrap();
+	assert!(matches!(
+		client.block_source(),
+		Some(SolanaBlockSource::WebSocket(_))
+	));
+
+	tokio::time::timeout(Duration::from_secs(10), async {
+		while client.get_latest_block_number().await.unwrap() != 100 {
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.expect("Timed out waiting for the block");
+
+	let blocks = client.get_blocks(100, None).await.unwrap();
+	assert_eq!(blocks.len(), 1);
+	let BlockType::Solana(block) = &blocks[0] else {
+		panic!("Expected Solana block");
+	};
+	assert_eq!(block.slot, 100);
+	assert_eq!(
+		block.blockhash,
+		"4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn"
+	);
+	assert_eq!(block.decoded_transactions[0].signature(), &signature);
+	assert_eq!(block.decoded_transactions[0].meta().fee, 5000);
+	blocks_mock.assert();
+}
+
+#[tokio::test]
+async fn test_solana_client_without_ws_urls_has_no_block_source() {
+	let mut server = Server::new_async().await;
+	let _health_mock = mock_rpc(&mut server, "getHealth", json!("ok"));
+
+	let network = NetworkBuilder::new()
+		.name("test")
+		.slug("test")
+		.network_type(BlockChainType::Solana)
+		.clear_rpc_urls()
+		.add_rpc_url(&server.url(), "rpc", 100)
+		.build();
+	let client = SolanaClient::new(&network).await.unwrap();
+
+	assert!(client.block_source().is_none());
+}
@@ -9,9 +9,12 @@ use crate::integration::mocks::{
 };
 use openzeppelin_monitor::{
 	models::{BlockChainType, BlockType, Network, ProcessedBlock},
-	services::blockwatcher::{
-		process_new_blocks, BlockTracker, BlockTrackerTrait, BlockWatcherError,
-		BlockWatcherService, NetworkBlockWatcher,
+	services::{
+		blockwatcher::{
+			process_new_blocks, BlockTracker, BlockTrackerTrait, BlockWatcherError,
+			BlockWatcherService, NetworkBlockWatcher,
+		},
+		trigger::TriggerDispatcher,
 	},
 	utils::get_cron_interval_ms,
 };
@@ -476,6 +479,89 @@ async fn test_ordered_trigger_handling() {
 	);
 }
 
+#[tokio::test]
+async fn test_trigger_handling_waits_while_queue_is_full() {
+	let network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
+
+	let blocks_to_process: Vec<u64> = (101..103).collect();
+
+	let config = MockConfig {
+		last_processed_block: Some(100),
+		latest_block: 103,
+		blocks_to_return: blocks_to_process
+			.iter()
+			.map(|&num| create_test_block(BlockChainType::EVM, num))
+			.collect(),
+		expected_save_block: Some(102),
+		expected_block_range: Some((101, Some(102))),
+		expected_tracked_blocks: blocks_to_process.clone(),
+		store_blocks: false,
+		history_size: 10,
+	};
+
+	let (block_storage, block_tracker, rpc_client) = setup_mocks(config);
+
+	let block_handler = Arc::new(move |block: BlockType, network: Network| {
+		Box::pin(async move {
+			ProcessedBlock {
+				block_number: block.number().unwrap_or(0),
+				network_slug: network.slug,
+				processing_results: vec![],
+			}
+		}) as BoxFuture<'static, ProcessedBlock>
+	});
+
+	// Create trigger handler that queues a match per block
+	let trigger_dispatcher = TriggerDispatcher::new(1, 1);
+	let queued_blocks = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+	let trigger_handler = {
+		let trigger_dispatcher = trigger_dispatcher.clone();
+		let queued_blocks = queued_blocks.clone();
+
+		Arc::new(move |block: &ProcessedBlock| {
+			let trigger_dispatcher = trigger_dispatcher.clone();
+			let queued_blocks = queued_blocks.clone();
+			let block_number = block.block_number;
+
+			tokio::spawn(async move {
+				let queued = trigger_dispatcher.enqueue().await;
+				queued_blocks.lock().await.push(block_number);
+				tokio::spawn(queued.run(async {}));
+			})
+		})
+	};
+
+	// The only slot of the queue is taken by a match of a previous block
+	let queued = trigger_dispatcher.enqueue().await;
+
+	let processing = process_new_blocks(
+		&network,
+		&rpc_client,
+		block_storage.clone(),
+		block_handler,
+		trigger_handler,
+		Arc::new(block_tracker),
+	);
+	tokio::pin!(processing);
+
+	assert!(
+		tokio::time::timeout(tokio::time::Duration::from_millis(200), &mut processing)
+			.await
+			.is_err(),
+		"Block processing should wait while the trigger queue is full"
+	);
+	assert!(queued_blocks.lock().await.is_empty());
+
+	// Executing the previous match frees the slot for the matches of the blocks
+	queued.run(async {}).await;
+	let result = tokio::time::timeout(tokio::time::Duration::from_secs(5), processing)
+		.await
+		.expect("Block processing should resume once a slot is free");
+
+	assert!(result.is_ok(), "Block processing should succeed");
+	assert_eq!(*queued_blocks.lock().await, blocks_to_process);
+}
+
 #[tokio::test]
 async fn test_block_storage_enabled() {
 	let mut network = create_test_network("Test Network", "test-network", BlockChainType::EVM);
@@ -10,6 +10,7 @@ use crate::integration::{
 		MockTriggerRepository,
 	},
 };
+use alloy::primitives::B256;
 use openzeppelin_monitor::{
 	bootstrap::{
 		create_block_handler, create_trigger_handler, get_contract_specs, initialize_services,
@@ -20,12 +21,18 @@ use openzeppelin_monitor::{
 		EVMTransactionReceipt, MatchConditions, Monitor, MonitorMatch, ProcessedBlock,
 		ScriptLanguage, SecretString, SecretValue, StellarBlock, StellarContractSpec,
 		StellarFormattedContractSpec, StellarMonitorMatch, TransactionType, Trigger,
-		TriggerConditions,
+		TriggerConditions, TriggerType,
 	},
 	services::{
-		filter::{stellar_helpers::are_same_address, FilterService},
+		filter::{
+			stellar_helpers::are_same_address, FilterService, MatchDeduplicator,
+			DEFAULT_MATCH_DEDUP_TTL,
+		},
 		notification::NotificationService,
-		trigger::{TriggerExecutionService, TriggerExecutionServiceTrait},
+		trigger::{
+			RetryPolicy, TriggerDispatcher, TriggerExecutionService, TriggerExecutionServiceTrait,
+			DEFAULT_TRIGGER_QUEUE_CAPACITY,
+		},
 	},
 	utils::tests::{
 		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
@@ -38,7 +45,7 @@ use stellar_xdr::curr::{
 };
 
 use serde_json::json;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use tokio::sync::watch;
 
 fn create_test_monitor(
@@ -89,6 +96,17 @@ fn create_test_monitor_match(chain: BlockChainType) -> MonitorMatch {
 	}
 }
 
+/// Waits for the queued matches to be executed and release their slots
+async fn wait_for_executions(trigger_dispatcher: &TriggerDispatcher, queue_capacity: usize) {
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while trigger_dispatcher.available_slots() < queue_capacity {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.expect("Queued matches should be executed");
+}
+
 #[tokio::test]
 async fn test_initialize_services() {
 	let mut mocked_networks = HashMap::new();
@@ -223,11 +241,14 @@ async fn test_create_trigger_handler() {
 		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
 			.await;
 
+	let trigger_dispatcher = TriggerDispatcher::default();
 	let (shutdown_tx, _) = watch::channel(false);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		HashMap::new(),
+		Arc::new(MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL)),
+		trigger_dispatcher.clone(),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -242,6 +263,151 @@ async fn test_create_trigger_handler() {
 	handle
 		.await
 		.expect("Trigger handler task should complete successfully");
+	wait_for_executions(&trigger_dispatcher, DEFAULT_TRIGGER_QUEUE_CAPACITY).await;
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_bounded_concurrency() {
+	let ctx = MockTriggerExecutionService::<MockTriggerRepository>::new_context();
+	ctx.expect()
+		.with(mockall::predicate::always(), mockall::predicate::always())
+		.returning(|_trigger_service, _notification_service| {
+			let mut mock = MockTriggerExecutionService::default();
+			mock.expect_execute()
+				.times(3)
+				.returning(|_, _, _, _| Ok(()));
+			mock
+		});
+
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+
+	// A single slot makes each match wait for the execution of the previous one
+	let trigger_dispatcher = TriggerDispatcher::new(1, 1);
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		Arc::new(MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL)),
+		trigger_dispatcher.clone(),
+	);
+
+	let processing_results = (1..=3)
+		.map(|byte| {
+			let mut monitor_match = create_test_monitor_match(BlockChainType::EVM);
+			if let MonitorMatch::EVM(evm_match) = &mut monitor_match {
+				evm_match.transaction = TransactionBuilder::new()
+					.hash(B256::repeat_byte(byte))
+					.build();
+			}
+			monitor_match
+		})
+		.collect();
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results,
+	};
+
+	trigger_handler(&processed_block)
+		.await
+		.expect("Trigger handler task should complete successfully");
+	wait_for_executions(&trigger_dispatcher, 1).await;
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_waits_while_queue_is_full() {
+	let ctx = MockTriggerExecutionService::<MockTriggerRepository>::new_context();
+	ctx.expect()
+		.with(mockall::predicate::always(), mockall::predicate::always())
+		.returning(|_trigger_service, _notification_service| {
+			let mut mock = MockTriggerExecutionService::default();
+			mock.expect_execute()
+				.times(1)
+				.return_once(|_, _, _, _| Ok(()));
+			mock
+		});
+
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+
+	let trigger_dispatcher = TriggerDispatcher::new(1, 1);
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		Arc::new(MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL)),
+		trigger_dispatcher.clone(),
+	);
+
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+	};
+
+	// The only slot of the queue is taken by a match of a previous block
+	let queued = trigger_dispatcher.enqueue().await;
+	let mut handle = trigger_handler(&processed_block);
+	assert!(
+		tokio::time::timeout(Duration::from_millis(100), &mut handle)
+			.await
+			.is_err()
+	);
+
+	// Executing the previous match frees the slot for the match of the block
+	queued.run(async {}).await;
+	tokio::time::timeout(Duration::from_secs(5), handle)
+		.await
+		.expect("Match should be queued once a slot is free")
+		.expect("Trigger handler task should complete successfully");
+	wait_for_executions(&trigger_dispatcher, 1).await;
+}
+
+#[tokio::test]
+async fn test_create_trigger_handler_skips_duplicate_matches() {
+	let ctx = MockTriggerExecutionService::<MockTriggerRepository>::new_context();
+	ctx.expect()
+		.with(mockall::predicate::always(), mockall::predicate::always())
+		.returning(|_trigger_service, _notification_service| {
+			let mut mock = MockTriggerExecutionService::default();
+			mock.expect_execute()
+				.times(1)
+				.return_once(|_, _, _, _| Ok(()));
+			mock
+		});
+
+	let trigger_execution_service =
+		setup_trigger_execution_service("tests/integration/fixtures/evm/triggers/trigger.json")
+			.await;
+
+	let trigger_dispatcher = TriggerDispatcher::default();
+	let (shutdown_tx, _) = watch::channel(false);
+	let trigger_handler = create_trigger_handler(
+		shutdown_tx,
+		Arc::new(trigger_execution_service),
+		HashMap::new(),
+		Arc::new(MatchDeduplicator::new(Duration::from_secs(3600))),
+		trigger_dispatcher.clone(),
+	);
+
+	let processed_block = ProcessedBlock {
+		block_number: 100,
+		network_slug: "ethereum_mainnet".to_string(),
+		processing_results: vec![create_test_monitor_match(BlockChainType::EVM)],
+	};
+
+	// Re-processing the block does not execute the triggers again
+	for _ in 0..2 {
+		trigger_handler(&processed_block)
+			.await
+			.expect("Trigger handler task should complete successfully");
+	}
+	wait_for_executions(&trigger_dispatcher, DEFAULT_TRIGGER_QUEUE_CAPACITY).await;
 }
 
 #[tokio::test]
@@ -256,6 +422,8 @@ async fn test_create_trigger_handler_empty_matches() {
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		HashMap::new(),
+		Arc::new(MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL)),
+		TriggerDispatcher::default(),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -473,11 +641,14 @@ print(True)  # Always return true for test
 		),
 	);
 
+	let trigger_dispatcher = TriggerDispatcher::default();
 	let (shutdown_tx, _) = watch::channel(false);
 	let trigger_handler = create_trigger_handler(
 		shutdown_tx,
 		Arc::new(trigger_execution_service),
 		trigger_scripts,
+		Arc::new(MatchDeduplicator::new(DEFAULT_MATCH_DEDUP_TTL)),
+		trigger_dispatcher.clone(),
 	);
 
 	assert!(Arc::strong_count(&trigger_handler) == 1);
@@ -509,6 +680,7 @@ print(True)  # Always return true for test
 	handle
 		.await
 		.expect("Trigger handler task should complete successfully");
+	wait_for_executions(&trigger_dispatcher, DEFAULT_TRIGGER_QUEUE_CAPACITY).await;
 }
 
 #[tokio::test]
@@ -1015,6 +1187,159 @@ async fn test_trigger_execution_service_execute_multiple_triggers_success() {
 	webhook_mock.assert();
 }
 
+#[tokio::test]
+async fn test_trigger_execution_service_execute_with_missing_escalation_trigger() {
+	let mut webhook_server = mockito::Server::new_async().await;
+	let webhook_mock = webhook_server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Any)
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"example_trigger_webhook".to_string(),
+		TriggerBuilder::new()
+			.name("example_trigger_webhook")
+			.webhook(&webhook_server.url())
+			.webhook_method("POST")
+			.message("Test Title", "Test Body")
+			.build(),
+	);
+
+	let mock_trigger_service = setup_trigger_service(mocked_triggers);
+	let trigger_execution_service =
+		TriggerExecutionService::new(mock_trigger_service, NotificationService::new());
+
+	// The escalation step refers to a trigger that does not exist
+	let monitor_match = MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor: MonitorBuilder::new()
+			.name("test")
+			.networks(vec!["ethereum_mainnet".to_string()])
+			.escalation(vec![("missing_trigger", 600)])
+			.build(),
+		transaction: TransactionBuilder::new().build(),
+		network_slug: "ethereum_mainnet".to_string(),
+		receipt: Some(EVMTransactionReceipt::default()),
+		logs: Some(vec![]),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}));
+
+	let result = trigger_execution_service
+		.execute(
+			&["example_trigger_webhook".to_string()],
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+
+	// The regular trigger of the monitor still notifies the match
+	assert!(result.is_ok());
+	webhook_mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_execute_without_callback_server() {
+	let mut slack_server = mockito::Server::new_async().await;
+
+	// The callback URLs are left out of the message while the callback server is not running
+	let slack_mock = slack_server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Json(json!({
+			"blocks": [
+				{
+					"type": "section",
+					"text": {
+						"type": "mrkdwn",
+						"text": "*Incident*\n\nopen ${match.ack_url} ${match.resolve_url}"
+					}
+				}
+			]
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"example_trigger_slack".to_string(),
+		TriggerBuilder::new()
+			.name("example_trigger_slack")
+			.slack(&slack_server.url())
+			.message(
+				"Incident",
+				"${match.state} ${match.ack_url} ${match.resolve_url}",
+			)
+			.build(),
+	);
+
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+	let monitor_match = create_test_monitor_match(BlockChainType::EVM);
+
+	let result = trigger_execution_service
+		.execute(
+			&["example_trigger_slack".to_string()],
+			HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+
+	assert!(result.is_ok());
+	slack_mock.assert();
+}
+
+#[tokio::test]
+async fn test_trigger_execution_service_retries_digest_with_its_trigger_slug() {
+	// The configuration does not match the trigger type, so sending fails without a request
+	let mut mocked_triggers = HashMap::new();
+	mocked_triggers.insert(
+		"email_digest".to_string(),
+		TriggerBuilder::new()
+			.name("Email Digest")
+			.slack("https://hooks.slack.com/services/xxx")
+			.trigger_type(TriggerType::Email)
+			.build(),
+	);
+
+	let trigger_execution_service = TriggerExecutionService::new(
+		setup_trigger_service(mocked_triggers),
+		NotificationService::new(),
+	);
+	let temp_dir = tempfile::TempDir::new().unwrap();
+	let retry_queue = trigger_execution_service.retry_queue();
+	retry_queue
+		.configure(
+			RetryPolicy {
+				max_attempts: 3,
+				initial_backoff: Duration::ZERO,
+				max_backoff: Duration::ZERO,
+			},
+			temp_dir.path().join("queue.json"),
+			temp_dir.path().join("dead_letters.jsonl"),
+		)
+		.await;
+	assert!(
+		retry_queue
+			.enqueue_digest("email_digest", vec!["first".to_string()], "down".into())
+			.await
+	);
+
+	trigger_execution_service.retry_pending().await;
+
+	// The digest was sent again with its trigger, found by slug
+	let due = retry_queue.due().await;
+	assert_eq!(due.len(), 1);
+	assert_eq!(due[0].attempts, 1);
+	assert!(due[0].last_error.contains("Invalid email configuration"));
+}
+
 #[tokio::test]
 async fn test_trigger_execution_service_execute_multiple_triggers_partial_success() {
 	// Set up mock servers for both Slack and Webhook endpoints
@@ -1148,11 +1473,13 @@ async fn test_get_contract_specs() {
 				"stateMutability": "nonpayable"
 			}]),
 		))),
+		match_conditions: None,
 	});
 
 	monitor.addresses.push(AddressWithSpec {
 		address: "0x1234567890123456789012345678901234567890".to_string(),
 		contract_spec: None,
+		match_conditions: None,
 	});
 
 	let monitors = vec![monitor];
@@ -1239,12 +1566,14 @@ async fn test_get_contract_specs() {
 				outputs: vec![ScSpecTypeDef::Bool].try_into().unwrap(),
 			}),
 		]) as StellarContractSpec)),
+		match_conditions: None,
 	});
 
 	// Add an address without a contract spec to test fetching from chain
 	stellar_monitor.addresses.push(AddressWithSpec {
 		address: "GZYXWVUTSRQPONMLKJIHGFEDCBA0987654321".to_string(),
 		contract_spec: None,
+		match_conditions: None,
 	});
 
 	let network_monitors = vec![(network, vec![stellar_monitor])];
@@ -709,7 +709,7 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 
 	// Create a monitor match with an argument named "signature"
 	use openzeppelin_monitor::models::{
-		EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
+		ConditionLogic, EVMMatchArguments, EVMMatchParamEntry, EVMMatchParamsMap, EVMMonitorMatch,
 		FunctionCondition, MatchConditions,
 	};
 
@@ -742,6 +742,12 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}],
 			events: vec![],
 			transactions: vec![],
+			accounts: vec![],
+			balance_changes: vec![],
+			logs: vec![],
+			sequences: vec![],
+			blocks: vec![],
+			condition_logic: ConditionLogic::All,
 		},
 		matched_on_args: Some(EVMMatchArguments {
 			functions: Some(vec![EVMMatchParamsMap {
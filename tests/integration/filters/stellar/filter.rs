@@ -7,7 +7,7 @@ use std::collections::HashMap;
 
 use openzeppelin_monitor::{
 	models::{
-		AddressWithSpec, BlockChainType, BlockType, ContractSpec, EventCondition,
+		AddressWithSpec, BlockChainType, BlockType, ConditionLogic, ContractSpec, EventCondition,
 		FunctionCondition, MatchConditions, Monitor, MonitorMatch, StellarBlock,
 		StellarContractSpec, StellarEvent, StellarMatchArguments, StellarMatchParamEntry,
 		StellarMatchParamsMap, StellarMonitorMatch, StellarTransaction, StellarTransactionInfo,
@@ -764,6 +764,7 @@ async fn test_handle_match() -> Result<(), Box<FilterError>> {
 				trigger_name == ["example_trigger_slack"]
 				// Monitor metadata
 				&& variables.get("monitor.name") == Some(&"Large Transfer of USDC Token".to_string())
+				&& variables.get("monitor.severity") == Some(&"info".to_string())
 				// Transaction variables
 				&& variables.get("transaction.hash")
 					== Some(&"2c89fc3311bc275415ed6a764c77d7b0349cb9f4ce37fd2bbfc6604920811503".to_string())
@@ -991,6 +992,12 @@ async fn test_handle_match_with_key_collision() -> Result<(), Box<FilterError>>
 			}],
 			events: vec![],
 			transactions: vec![],
+			accounts: vec![],
+			balance_changes: vec![],
+			logs: vec![],
+			sequences: vec![],
+			blocks: vec![],
+			condition_logic: ConditionLogic::All,
 		},
 		matched_on_args: Some(StellarMatchArguments {
 			functions: Some(vec![StellarMatchParamsMap {
@@ -1254,6 +1261,7 @@ async fn test_filter_with_abi_in_config() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		match_conditions: None,
 	}];
 
 	// Run filter_block with the test data
@@ -1379,6 +1387,7 @@ async fn test_filter_with_udt_expression() -> Result<(), Box<FilterError>> {
 	monitor.addresses = vec![AddressWithSpec {
 		address: contract_with_spec.0.clone(),
 		contract_spec: Some(contract_with_spec.1.clone()),
+		match_conditions: None,
 	}];
 
 	// Run filter_block with the test data
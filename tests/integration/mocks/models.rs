@@ -84,6 +84,29 @@ pub fn create_evm_test_network_with_urls(urls: Vec<&str>) -> Network {
 		.build()
 }
 
+pub fn create_solana_valid_server_mock_network_response(server: &mut Server) -> Mock {
+	server
+		.mock("POST", "/")
+		.match_body(r#"{"id":1,"jsonrpc":"2.0","method":"getHealth","params":[]}"#)
+		.with_header("content-type", "application/json")
+		.with_status(200)
+		.with_body(r#"{"jsonrpc":"2.0","id":1,"result":"ok"}"#)
+		.create()
+}
+
+pub fn create_solana_test_network_with_urls(urls: Vec<&str>) -> Network {
+	NetworkBuilder::new()
+		.name("test")
+		.slug("test")
+		.network_type(BlockChainType::Solana)
+		.cron_schedule("*/5 * * * * *")
+		.confirmation_blocks(1)
+		.store_blocks(false)
+		.block_time_ms(400)
+		.rpc_urls(urls)
+		.build()
+}
+
 pub fn create_http_valid_server_mock_network_response(server: &mut Server) -> Mock {
 	server
 		.mock("POST", "/")
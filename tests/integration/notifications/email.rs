@@ -128,6 +128,7 @@ async fn test_notification_service_email_execution_failure() {
 	let trigger_config = TriggerTypeConfig::Email {
 		host: "dummy.smtp.host.invalid".to_string(), // Will cause SmtpTransport to fail connection
 		port: Some(587),
+		tls: None,
 		username: SecretValue::Plain(SecretString::new("user".to_string())),
 		password: SecretValue::Plain(SecretString::new("pass".to_string())),
 		message: NotificationMessage {
@@ -136,6 +137,7 @@ async fn test_notification_service_email_execution_failure() {
 		},
 		sender: "sender@example.com".parse().unwrap(),
 		recipients: vec!["recipient@example.com".parse().unwrap()],
+		digest_interval_mins: None,
 	};
 
 	let trigger = TriggerBuilder::new()
@@ -149,7 +151,13 @@ async fn test_notification_service_email_execution_failure() {
 	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor_email"));
 
 	let result = notification_service
-		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.execute(
+			"test_trigger",
+			&trigger,
+			&variables,
+			&monitor_match,
+			&HashMap::new(),
+		)
 		.await;
 
 	assert!(
@@ -164,3 +172,75 @@ async fn test_notification_service_email_execution_failure() {
 		e => panic!("Expected NotifyFailed, got {:?}", e),
 	}
 }
+
+#[tokio::test]
+async fn test_email_digest_notification_success() {
+	let email_content = EmailContent {
+		subject: "Test".to_string(),
+		body_template: "Test message".to_string(),
+		sender: EmailAddress::new_unchecked("sender@test.com"),
+		recipients: vec![EmailAddress::new_unchecked("recipient@test.com")],
+	};
+
+	let mut mock_transport = MockSmtpTransport::new();
+
+	mock_transport
+		.expect_send()
+		.times(1)
+		.withf(|message: &Message| {
+			let formatted = String::from_utf8(message.formatted()).unwrap();
+			formatted.contains("Subject: Test (2 matches)")
+				&& formatted.contains("First match")
+				&& formatted.contains("Second match")
+		})
+		.returning(|_| Ok("250 OK".to_string()));
+
+	let notifier = EmailNotifier::with_transport(email_content, mock_transport);
+
+	let result = notifier
+		.notify_digest(&["First match".to_string(), "Second match".to_string()])
+		.await;
+	assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_notification_service_email_digest_is_queued() {
+	let notification_service = NotificationService::new();
+
+	let trigger = TriggerBuilder::new()
+		.name("Email Digest")
+		.email(
+			"dummy.smtp.host.invalid",
+			"user",
+			"pass",
+			"sender@example.com",
+			vec!["recipient@example.com"],
+		)
+		.email_digest_interval_mins(60)
+		.message("Email Test Alert", "Test email message with value ${value}")
+		.build();
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "123".to_string());
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor_email"));
+
+	for _ in 0..2 {
+		let result = notification_service
+			.execute(
+				"test_email_digest",
+				&trigger,
+				&variables,
+				&monitor_match,
+				&HashMap::new(),
+			)
+			.await;
+		assert!(result.is_ok());
+	}
+
+	assert_eq!(
+		notification_service
+			.email_digests()
+			.pending_messages("test_email_digest"),
+		2
+	);
+}
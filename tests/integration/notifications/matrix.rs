@@ -0,0 +1,173 @@
+use mockito::Matcher;
+use openzeppelin_monitor::{
+	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch},
+	services::notification::{MatrixNotifier, NotificationError, NotificationService, Notifier},
+	utils::tests::{
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		trigger::TriggerBuilder,
+	},
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+/// Path of the messages sent to the test room, followed by their transaction ID
+const ROOM_MESSAGES_PATH: &str =
+	r"^/_matrix/client/v3/rooms/%21room%3Aexample\.com/send/m\.room\.message/[0-9a-f-]{36}$";
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}))
+}
+
+fn create_test_notifier(url: &str, html: Option<bool>) -> MatrixNotifier {
+	MatrixNotifier::new(
+		url,
+		"!room:example.com",
+		"test_token",
+		html,
+		"Test Alert".to_string(),
+		"Test message with value `${value}`".to_string(),
+	)
+	.unwrap()
+}
+
+#[tokio::test]
+async fn test_matrix_notification_success() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", Matcher::Regex(ROOM_MESSAGES_PATH.to_string()))
+		.match_header("authorization", "Bearer test_token")
+		.match_body(Matcher::Json(json!({
+			"msgtype": "m.text",
+			"body": "**Test Alert**\n\nTest message with value `42`",
+			"format": "org.matrix.custom.html",
+			"formatted_body": "<p><strong>Test Alert</strong></p>\n<p>Test message with value <code>42</code></p>",
+		})))
+		.with_status(200)
+		.with_body(r#"{"event_id": "$event"}"#)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), None);
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let message = notifier.format_message(&variables);
+
+	let result = notifier.notify(&message).await;
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_matrix_notification_without_html() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", Matcher::Regex(ROOM_MESSAGES_PATH.to_string()))
+		.match_body(Matcher::Json(json!({
+			"msgtype": "m.text",
+			"body": "Test message",
+		})))
+		.with_status(200)
+		.with_body(r#"{"event_id": "$event"}"#)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), Some(false));
+
+	let result = notifier.notify("Test message").await;
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_matrix_notification_retried_when_rate_limited() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", Matcher::Regex(ROOM_MESSAGES_PATH.to_string()))
+		.with_status(429)
+		.with_body(r#"{"errcode": "M_LIMIT_EXCEEDED"}"#)
+		.expect(4)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), None);
+
+	let result = notifier.notify("Test message").await;
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_matrix_notification_failure() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", Matcher::Regex(ROOM_MESSAGES_PATH.to_string()))
+		.with_status(403)
+		.with_body(r#"{"errcode": "M_FORBIDDEN"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), None);
+
+	let result = notifier.notify("Test message").await;
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_matrix_execution() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("PUT", Matcher::Regex(ROOM_MESSAGES_PATH.to_string()))
+		.match_header("authorization", "Bearer test_token")
+		.match_body(Matcher::PartialJson(json!({
+			"body": "**Test Alert**\n\nTest message with value 42",
+		})))
+		.with_status(200)
+		.with_body(r#"{"event_id": "$event"}"#)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.matrix(&server.url(), "!room:example.com", "test_token")
+		.message("Test Alert", "Test message with value ${value}")
+		.build();
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+
+	let result = NotificationService::new()
+		.execute(
+			"test_trigger",
+			&trigger,
+			&variables,
+			&create_test_evm_match(create_test_monitor("test_monitor")),
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+	mock.assert();
+}
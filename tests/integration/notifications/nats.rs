@@ -0,0 +1,304 @@
+use openzeppelin_monitor::{
+	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, SecretString, SecretValue},
+	services::notification::{
+		match_payload, NatsNotifier, NotificationError, NotificationService, Notifier,
+		MATCH_PAYLOAD_SCHEMA_VERSION,
+	},
+	utils::tests::{
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		trigger::TriggerBuilder,
+	},
+};
+use serde_json::{json, Value};
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+	net::TcpListener,
+};
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+/// Reply of the mock server to JetStream publishes
+#[derive(Clone, Copy)]
+enum AckMode {
+	/// Acknowledge every publish
+	Ack,
+	/// Close the connection without acknowledging the first publishes
+	DropFirst(usize),
+	/// Reply that no stream is bound to the subject
+	NoResponders,
+}
+
+/// Message received by the mock server
+#[derive(Debug, Clone)]
+struct ReceivedMessage {
+	subject: String,
+	headers: Option<String>,
+	payload: Value,
+}
+
+#[derive(Default)]
+struct MockNatsState {
+	connect_options: Vec<Value>,
+	messages: Vec<ReceivedMessage>,
+}
+
+/// Starts a mock NATS server speaking enough of the client protocol to receive publishes
+async fn start_mock_nats_server(ack_mode: AckMode) -> (String, Arc<Mutex<MockNatsState>>) {
+	let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let url = format!("nats://127.0.0.1:{}", listener.local_addr().unwrap().port());
+	let state = Arc::new(Mutex::new(MockNatsState::default()));
+
+	let server_state = state.clone();
+	tokio::spawn(async move {
+		let mut publishes = 0;
+		while let Ok((stream, _)) = listener.accept().await {
+			let (reader, mut writer) = stream.into_split();
+			let mut reader = BufReader::new(reader);
+			writer
+				.write_all(b"INFO {\"server_id\":\"mock\",\"headers\":true}\r\n")
+				.await
+				.unwrap();
+
+			let mut subscription = None;
+			let mut line = String::new();
+			loop {
+				line.clear();
+				if reader.read_line(&mut line).await.unwrap_or(0) == 0 {
+					break;
+				}
+				let fields: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+				match fields[0].as_str() {
+					"CONNECT" => {
+						let options = serde_json::from_str(line["CONNECT".len()..].trim()).unwrap();
+						server_state.lock().unwrap().connect_options.push(options);
+					}
+					"PING" => writer.write_all(b"PONG\r\n").await.unwrap(),
+					"SUB" => subscription = Some((fields[1].clone(), fields[2].clone())),
+					"PUB" | "HPUB" => {
+						let has_headers = fields[0] == "HPUB";
+						let lengths = if has_headers { 2 } else { 1 };
+						let reply_to = (fields.len() == 3 + lengths).then(|| fields[2].clone());
+						let length: usize = fields[fields.len() - 1].parse().unwrap();
+						let headers_length: usize = if has_headers {
+							fields[fields.len() - 2].parse().unwrap()
+						} else {
+							0
+						};
+
+						let mut data = vec![0; length + 2];
+						reader.read_exact(&mut data).await.unwrap();
+						let headers = has_headers
+							.then(|| String::from_utf8(data[..headers_length].to_vec()).unwrap());
+						server_state.lock().unwrap().messages.push(ReceivedMessage {
+							subject: fields[1].clone(),
+							headers,
+							payload: serde_json::from_slice(&data[headers_length..length]).unwrap(),
+						});
+						publishes += 1;
+
+						let (Some(reply_to), Some((inbox, sid))) = (reply_to, &subscription) else {
+							continue;
+						};
+						assert_eq!(&reply_to, inbox);
+						match ack_mode {
+							AckMode::DropFirst(count) if publishes <= count => break,
+							AckMode::Ack | AckMode::DropFirst(_) => {
+								let ack = format!(r#"{{"stream":"MATCHES","seq":{}}}"#, publishes);
+								writer
+									.write_all(
+										format!(
+											"MSG {} {} {}\r\n{}\r\n",
+											inbox,
+											sid,
+											ack.len(),
+											ack
+										)
+										.as_bytes(),
+									)
+									.await
+									.unwrap();
+							}
+							AckMode::NoResponders => {
+								let headers = "NATS/1.0 503\r\n\r\n";
+								writer
+									.write_all(
+										format!(
+											"HMSG {} {} {} {}\r\n{}\r\n",
+											inbox,
+											sid,
+											headers.len(),
+											headers.len(),
+											headers
+										)
+										.as_bytes(),
+									)
+									.await
+									.unwrap();
+							}
+						}
+					}
+					_ => {}
+				}
+			}
+		}
+	});
+
+	(url, state)
+}
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}))
+}
+
+fn create_test_notifier(url: &str, jetstream: bool) -> NatsNotifier {
+	NatsNotifier::new(
+		url,
+		"monitor.matches".to_string(),
+		Some("test_token".to_string()),
+		Some(jetstream),
+		Some(500),
+		"Test Alert".to_string(),
+		"Test message with value ${value}".to_string(),
+	)
+	.unwrap()
+}
+
+#[tokio::test]
+async fn test_nats_notification_success() {
+	let (url, state) = start_mock_nats_server(AckMode::Ack).await;
+	let notifier = create_test_notifier(&url, false);
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let message = notifier.format_message(&variables);
+
+	let result = notifier.notify(&message).await;
+	assert!(result.is_ok());
+
+	let state = state.lock().unwrap();
+	assert_eq!(state.connect_options[0]["auth_token"], "test_token");
+	assert_eq!(state.messages.len(), 1);
+	assert_eq!(state.messages[0].subject, "monitor.matches");
+	assert!(state.messages[0].headers.is_none());
+	assert_eq!(
+		state.messages[0].payload,
+		json!({
+			"title": "Test Alert",
+			"body": "Test message with value 42",
+		})
+	);
+}
+
+#[tokio::test]
+async fn test_nats_match_notification_with_jetstream() {
+	let (url, state) = start_mock_nats_server(AckMode::Ack).await;
+	let notifier = create_test_notifier(&url, true);
+	let monitor_match = create_test_evm_match(create_test_monitor("Test Monitor"));
+
+	let result = notifier.notify_match("Test message", &monitor_match).await;
+	assert!(result.is_ok());
+
+	let state = state.lock().unwrap();
+	assert_eq!(state.messages.len(), 1);
+	assert!(state.messages[0]
+		.headers
+		.as_ref()
+		.unwrap()
+		.contains("Nats-Msg-Id: "));
+	assert_eq!(
+		state.messages[0].payload,
+		json!({
+			"title": "Test Alert",
+			"body": "Test message",
+			"monitor_match": match_payload(&monitor_match, MATCH_PAYLOAD_SCHEMA_VERSION).unwrap(),
+		})
+	);
+}
+
+#[tokio::test]
+async fn test_nats_jetstream_publish_is_retried_with_same_message_id() {
+	let (url, state) = start_mock_nats_server(AckMode::DropFirst(1)).await;
+	let notifier = create_test_notifier(&url, true);
+
+	let result = notifier.notify("Test message").await;
+	assert!(result.is_ok());
+
+	let state = state.lock().unwrap();
+	assert_eq!(state.messages.len(), 2);
+	assert_eq!(state.messages[0].headers, state.messages[1].headers);
+}
+
+#[tokio::test]
+async fn test_nats_jetstream_publish_fails_without_stream() {
+	let (url, state) = start_mock_nats_server(AckMode::NoResponders).await;
+	let notifier = create_test_notifier(&url, true);
+
+	let result = notifier.notify("Test message").await;
+	match result {
+		Err(NotificationError::NotifyFailed(ctx)) => {
+			assert!(ctx
+				.message
+				.contains("No JetStream stream is bound to subject monitor.matches"));
+		}
+		_ => panic!("Expected NotifyFailed"),
+	}
+	assert_eq!(state.lock().unwrap().messages.len(), 3);
+}
+
+#[tokio::test]
+async fn test_nats_notification_via_service() {
+	let (url, state) = start_mock_nats_server(AckMode::Ack).await;
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.nats(&url, "monitor.matches")
+		.nats_token(SecretValue::Plain(SecretString::new(
+			"test_token".to_string(),
+		)))
+		.nats_jetstream(true)
+		.message("Test Alert", "Test message with value ${value}")
+		.build();
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+
+	let result = NotificationService::new()
+		.execute(
+			"test_trigger",
+			&trigger,
+			&variables,
+			&create_test_evm_match(create_test_monitor("test_monitor")),
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+
+	let state = state.lock().unwrap();
+	assert_eq!(state.messages.len(), 1);
+	assert_eq!(
+		state.messages[0].payload["body"],
+		"Test message with value 42"
+	);
+}
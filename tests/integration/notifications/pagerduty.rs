@@ -0,0 +1,218 @@
+use openzeppelin_monitor::{
+	models::{
+		EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch, MonitorSeverity, PagerDutySeverity,
+	},
+	services::{
+		notification::{NotificationError, Notifier, PagerDutyNotifier},
+		trigger::{MatchLifecycle, MatchState},
+	},
+	utils::tests::evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+};
+
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}))
+}
+
+fn create_test_notifier(url: String, severity: Option<PagerDutySeverity>) -> PagerDutyNotifier {
+	PagerDutyNotifier::new(
+		Some(url),
+		"test_routing_key".to_string(),
+		severity,
+		"Test Alert".to_string(),
+		"Test message with value ${value}".to_string(),
+	)
+	.unwrap()
+}
+
+#[tokio::test]
+async fn test_pagerduty_notification_success() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Json(json!({
+			"routing_key": "test_routing_key",
+			"event_action": "trigger",
+			"payload": {
+				"summary": "Test Alert: Test message with value 42",
+				"source": "openzeppelin-monitor",
+				"severity": "error",
+			},
+		})))
+		.with_status(202)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(server.url(), Some(PagerDutySeverity::Error));
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let message = notifier.format_message(&variables);
+
+	let result = notifier.notify(&message).await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_pagerduty_notification_failure() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.with_body(r#"{"status": "invalid event"}"#)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(server.url(), Some(PagerDutySeverity::Error));
+
+	let result = notifier.notify("Test message").await;
+
+	assert!(result.is_err());
+	assert!(matches!(
+		result.unwrap_err(),
+		NotificationError::NotifyFailed(_)
+	));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_pagerduty_match_notification_with_dedup_key() {
+	let mut server = mockito::Server::new_async().await;
+	let monitor_match = create_test_evm_match(create_test_monitor("Test Monitor"));
+	let dedup_key = PagerDutyNotifier::dedup_key(&monitor_match);
+	assert_eq!(
+		dedup_key,
+		"test monitor:0x0000000000000000000000000000000000000000000000000000000000000000"
+	);
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Json(json!({
+			"routing_key": "test_routing_key",
+			"event_action": "trigger",
+			"dedup_key": dedup_key,
+			"payload": {
+				"summary": "Test Alert: Test message with value 42",
+				"source": "ethereum_mainnet",
+				"severity": "error",
+				"component": "Test Monitor",
+				"custom_details": { "value": "42" },
+			},
+		})))
+		.with_status(202)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(server.url(), Some(PagerDutySeverity::Error));
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let result = notifier
+		.notify_match(&variables, &monitor_match, None)
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_pagerduty_incident_alert_uses_monitor_severity() {
+	let mut server = mockito::Server::new_async().await;
+	let monitor = MonitorBuilder::new()
+		.name("Test Monitor")
+		.severity(MonitorSeverity::Warning)
+		.build();
+	let monitor_match = create_test_evm_match(monitor);
+
+	let mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(json!({
+			"event_action": "trigger",
+			"dedup_key": "incident-id",
+			"payload": { "severity": "warning" },
+		})))
+		.with_status(202)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(server.url(), None);
+	let result = notifier
+		.notify_match(&HashMap::new(), &monitor_match, Some("incident-id"))
+		.await;
+
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_pagerduty_alert_resolved_with_incident() {
+	let mut server = mockito::Server::new_async().await;
+	let monitor_match = create_test_evm_match(create_test_monitor("Test Monitor"));
+	let lifecycle = MatchLifecycle::default();
+	let mut resolutions = lifecycle.subscribe_resolutions();
+	let incident = lifecycle.record(&monitor_match, &["test_trigger".to_string()]);
+
+	let trigger_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::PartialJson(json!({
+			"event_action": "trigger",
+			"dedup_key": incident.id,
+		})))
+		.with_status(202)
+		.create_async()
+		.await;
+	let resolve_mock = server
+		.mock("POST", "/")
+		.match_body(mockito::Matcher::Json(json!({
+			"routing_key": "test_routing_key",
+			"event_action": "resolve",
+			"dedup_key": incident.id,
+		})))
+		.with_status(202)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(server.url(), None);
+	let result = notifier
+		.notify_match(&HashMap::new(), &monitor_match, Some(&incident.id))
+		.await;
+	assert!(result.is_ok());
+	trigger_mock.assert();
+
+	// The alert stays open until the incident is resolved
+	assert!(resolutions.try_recv().is_err());
+	assert!(lifecycle.resolve(&incident.id, &incident.token));
+
+	let resolved = resolutions.recv().await.unwrap();
+	assert_eq!(resolved.id, incident.id);
+	assert_eq!(resolved.state, MatchState::Resolved);
+	assert_eq!(resolved.triggers, ["test_trigger"]);
+	notifier.resolve(&resolved.id).await.unwrap();
+	resolve_mock.assert();
+}
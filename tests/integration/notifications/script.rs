@@ -62,7 +62,13 @@ async fn test_notification_service_script_execution() {
 
 	// Execute the notification
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &trigger_scripts)
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&trigger_scripts,
+		)
 		.await;
 	assert!(result.is_ok());
 }
@@ -83,7 +89,13 @@ async fn test_notification_service_script_execution_failure() {
 	let trigger_scripts = create_test_trigger_scripts(None);
 
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &trigger_scripts)
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&trigger_scripts,
+		)
 		.await;
 
 	assert!(result.is_err());
@@ -117,7 +129,13 @@ async fn test_notification_service_script_execution_normalized_monitor_name() {
 
 	// Execute the notification
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &trigger_scripts)
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&trigger_scripts,
+		)
 		.await;
 	assert!(result.is_ok());
 }
@@ -0,0 +1,145 @@
+use mockito::Matcher;
+use openzeppelin_monitor::{
+	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch},
+	services::notification::{NotificationError, NotificationService, Notifier, TeamsNotifier},
+	utils::tests::{
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		trigger::TriggerBuilder,
+	},
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}))
+}
+
+#[tokio::test]
+async fn test_teams_notification_success() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::Json(json!({
+			"type": "message",
+			"attachments": [{
+				"contentType": "application/vnd.microsoft.card.adaptive",
+				"contentUrl": null,
+				"content": {
+					"$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+					"type": "AdaptiveCard",
+					"version": "1.4",
+					"body": [
+						{
+							"type": "TextBlock",
+							"text": "Test Alert",
+							"weight": "Bolder",
+							"size": "Medium",
+							"wrap": true
+						},
+						{
+							"type": "TextBlock",
+							"text": "Test message with value 42",
+							"wrap": true
+						}
+					]
+				}
+			}]
+		})))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let notifier = TeamsNotifier::new(
+		server.url(),
+		"Test Alert".to_string(),
+		"Test message with value ${value}".to_string(),
+	)
+	.unwrap();
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let message = notifier.format_message(&variables);
+
+	let result = notifier.notify(&message).await;
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_teams_notification_failure() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.with_status(400)
+		.create_async()
+		.await;
+
+	let notifier = TeamsNotifier::new(
+		server.url(),
+		"Test Alert".to_string(),
+		"Test message".to_string(),
+	)
+	.unwrap();
+
+	let result = notifier.notify("Test message").await;
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_teams_execution() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", "/")
+		.match_body(Matcher::AllOf(vec![
+			Matcher::PartialJson(json!({ "type": "message" })),
+			Matcher::Regex(r#""title":"Monitor","value":"test_monitor""#.to_string()),
+			Matcher::Regex(r#""type":"Action.OpenUrl""#.to_string()),
+			Matcher::Regex(r#""url":"https://etherscan\.io/tx/0x[0-9a-f]{64}""#.to_string()),
+		]))
+		.with_status(200)
+		.create_async()
+		.await;
+
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.teams(&server.url())
+		.message("Test Alert", "Test message with value ${value}")
+		.build();
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+
+	let result = NotificationService::new()
+		.execute(
+			"test_trigger",
+			&trigger,
+			&variables,
+			&create_test_evm_match(create_test_monitor("test_monitor")),
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+	mock.assert();
+}
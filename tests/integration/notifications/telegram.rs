@@ -135,7 +135,13 @@ async fn test_notification_service_telegram_execution_failure() {
 	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
 
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
 		.await;
 
 	assert!(result.is_err());
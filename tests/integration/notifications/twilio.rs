@@ -0,0 +1,187 @@
+use mockito::Matcher;
+use openzeppelin_monitor::{
+	models::{EVMMonitorMatch, MatchConditions, Monitor, MonitorMatch},
+	services::notification::{NotificationError, NotificationService, Notifier, TwilioNotifier},
+	utils::tests::{
+		evm::{monitor::MonitorBuilder, transaction::TransactionBuilder},
+		trigger::TriggerBuilder,
+	},
+};
+use std::collections::HashMap;
+
+use crate::integration::mocks::{create_test_evm_logs, create_test_evm_transaction_receipt};
+
+const TEST_ACCOUNT_SID: &str = "AC0123456789abcdef0123456789abcdef";
+
+/// Path of the messages sent by the test account
+const MESSAGES_PATH: &str = "/2010-04-01/Accounts/AC0123456789abcdef0123456789abcdef/Messages.json";
+
+fn create_test_monitor(name: &str) -> Monitor {
+	MonitorBuilder::new()
+		.name(name)
+		.networks(vec!["ethereum_mainnet".to_string()])
+		.paused(false)
+		.triggers(vec!["test_trigger".to_string()])
+		.build()
+}
+
+fn create_test_evm_match(monitor: Monitor) -> MonitorMatch {
+	let transaction = TransactionBuilder::new().build();
+
+	MonitorMatch::EVM(Box::new(EVMMonitorMatch {
+		monitor,
+		transaction,
+		receipt: Some(create_test_evm_transaction_receipt()),
+		logs: Some(create_test_evm_logs()),
+		network_slug: "ethereum_mainnet".to_string(),
+		matched_on: MatchConditions::default(),
+		matched_on_args: None,
+	}))
+}
+
+fn create_test_notifier(url: &str, to: Vec<&str>) -> TwilioNotifier {
+	TwilioNotifier::new(
+		Some(url.to_string()),
+		TEST_ACCOUNT_SID.to_string(),
+		"test_token".to_string(),
+		"+14155550100".to_string(),
+		to.into_iter().map(|number| number.to_string()).collect(),
+		None,
+		"Test Alert".to_string(),
+		"Test message with value ${value}".to_string(),
+	)
+	.unwrap()
+}
+
+#[tokio::test]
+async fn test_twilio_notification_success() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", MESSAGES_PATH)
+		// Basic auth of `AC0123456789abcdef0123456789abcdef:test_token`
+		.match_header(
+			"authorization",
+			"Basic QUMwMTIzNDU2Nzg5YWJjZGVmMDEyMzQ1Njc4OWFiY2RlZjp0ZXN0X3Rva2Vu",
+		)
+		.match_body(Matcher::AllOf(vec![
+			Matcher::UrlEncoded("To".to_string(), "+14155550101".to_string()),
+			Matcher::UrlEncoded("From".to_string(), "+14155550100".to_string()),
+			Matcher::UrlEncoded(
+				"Body".to_string(),
+				"Test Alert\nTest message with value 42".to_string(),
+			),
+		]))
+		.with_status(201)
+		.with_body(r#"{"sid": "SM0123456789abcdef0123456789abcdef"}"#)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), vec!["+14155550101"]);
+
+	let mut variables = HashMap::new();
+	variables.insert("value".to_string(), "42".to_string());
+	let message = notifier.format_message(&variables);
+
+	let result = notifier.notify(&message).await;
+	assert!(result.is_ok());
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_twilio_notification_sent_to_all_recipients() {
+	let mut server = mockito::Server::new_async().await;
+	let failed_mock = server
+		.mock("POST", MESSAGES_PATH)
+		.match_body(Matcher::UrlEncoded(
+			"To".to_string(),
+			"+14155550101".to_string(),
+		))
+		.with_status(400)
+		.with_body(r#"{"code": 21211, "message": "Invalid 'To' Phone Number"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+	let sent_mock = server
+		.mock("POST", MESSAGES_PATH)
+		.match_body(Matcher::UrlEncoded(
+			"To".to_string(),
+			"+14155550102".to_string(),
+		))
+		.with_status(201)
+		.with_body(r#"{"sid": "SM0123456789abcdef0123456789abcdef"}"#)
+		.expect(1)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), vec!["+14155550101", "+14155550102"]);
+
+	let result = notifier.notify("Test message").await;
+	match result {
+		Err(NotificationError::NotifyFailed(ctx)) => {
+			assert!(ctx.message.contains("1 of 2 recipients"));
+			assert!(ctx.message.contains("21211"));
+		}
+		_ => panic!("Expected NotifyFailed"),
+	}
+	failed_mock.assert();
+	sent_mock.assert();
+}
+
+#[tokio::test]
+async fn test_twilio_notification_retried_when_rate_limited() {
+	let mut server = mockito::Server::new_async().await;
+	let mock = server
+		.mock("POST", MESSAGES_PATH)
+		.with_status(429)
+		.with_body(r#"{"code": 20429, "message": "Too Many Requests"}"#)
+		.expect(4)
+		.create_async()
+		.await;
+
+	let notifier = create_test_notifier(&server.url(), vec!["+14155550101"]);
+
+	let result = notifier.notify("Test message").await;
+	assert!(matches!(result, Err(NotificationError::NotifyFailed(_))));
+	mock.assert();
+}
+
+#[tokio::test]
+async fn test_notification_service_twilio_rate_limit() {
+	let trigger = TriggerBuilder::new()
+		.name("test_trigger")
+		.twilio(
+			TEST_ACCOUNT_SID,
+			"test_token",
+			"+14155550100",
+			vec!["+14155550101"],
+		)
+		.twilio_max_messages_per_hour(1)
+		.build();
+
+	let service = NotificationService::new();
+	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
+
+	// The first match is sent, and rejected by Twilio for the invalid credentials
+	let result = service
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_err());
+
+	// Further matches within the hour are dropped without being sent
+	let result = service
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
+		.await;
+	assert!(result.is_ok());
+}
@@ -128,7 +128,13 @@ async fn test_notification_service_webhook_execution() {
 	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
 
 	let result = notification_service
-		.execute(&trigger, &variables, &monitor_match, &HashMap::new())
+		.execute(
+			"test_trigger",
+			&trigger,
+			&variables,
+			&monitor_match,
+			&HashMap::new(),
+		)
 		.await;
 
 	assert!(result.is_ok());
@@ -158,7 +164,13 @@ async fn test_notification_service_webhook_execution_failure() {
 	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
 
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
 		.await;
 
 	assert!(result.is_err());
@@ -183,7 +195,13 @@ async fn test_notification_service_webhook_execution_invalid_config() {
 	let monitor_match = create_test_evm_match(create_test_monitor("test_monitor"));
 
 	let result = notification_service
-		.execute(&trigger, &HashMap::new(), &monitor_match, &HashMap::new())
+		.execute(
+			"test_trigger",
+			&trigger,
+			&HashMap::new(),
+			&monitor_match,
+			&HashMap::new(),
+		)
 		.await;
 
 	// Verify we get the specific "Invalid webhook configuration" error
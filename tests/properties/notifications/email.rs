@@ -5,7 +5,10 @@
 //! The tests ensure that the email notification system handles template variables correctly
 //! and produces consistent, well-formed output across various input combinations.
 
-use openzeppelin_monitor::services::notification::{EmailContent, EmailNotifier, SmtpConfig};
+use openzeppelin_monitor::{
+	models::SmtpTlsMode,
+	services::notification::{EmailContent, EmailNotifier, SmtpConfig},
+};
 use proptest::{prelude::*, test_runner::Config};
 use std::collections::HashMap;
 
@@ -37,6 +40,7 @@ proptest! {
 			SmtpConfig {
 				host: "smtp.test.com".to_string(),
 				port: 465,
+				tls: SmtpTlsMode::Tls,
 				username: "test".to_string(),
 				password: "test".to_string(),
 			},
@@ -69,6 +73,7 @@ proptest! {
 			SmtpConfig {
 				host: "smtp.test.com".to_string(),
 				port: 465,
+				tls: SmtpTlsMode::Tls,
 				username: "test".to_string(),
 				password: "test".to_string(),
 			},
@@ -100,6 +105,7 @@ proptest! {
 			SmtpConfig {
 				host: "smtp.test.com".to_string(),
 				port: 465,
+				tls: SmtpTlsMode::Tls,
 				username: "test".to_string(),
 				password: "test".to_string(),
 			},
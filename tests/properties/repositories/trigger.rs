@@ -123,7 +123,7 @@ proptest! {
 					}
 				}
 				TriggerType::Email => {
-					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _ } = &trigger.config {
+					if let TriggerTypeConfig::Email { host: _, port: _, username: _, password: _, message: _, sender: _, recipients: _, .. } = &trigger.config {
 						// Test empty recipients
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Email { recipients: r, .. } = &mut invalid_trigger.config {
@@ -150,7 +150,7 @@ proptest! {
 					}
 				}
 				TriggerType::Webhook => {
-					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _ } = &trigger.config {
+					if let TriggerTypeConfig::Webhook { url: _, method: _, headers: _, secret: _, message: _, .. } = &trigger.config {
 						// Test invalid method
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Webhook { method: m, .. } = &mut invalid_trigger.config {
@@ -187,7 +187,7 @@ proptest! {
 					}
 				}
 				TriggerType::Discord => {
-					if let TriggerTypeConfig::Discord { discord_url: _, message: _ } = &trigger.config {
+					if let TriggerTypeConfig::Discord { discord_url: _, embed: _, message: _ } = &trigger.config {
 						// Test invalid URL
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Discord { discord_url: u, .. } = &mut invalid_trigger.config {
@@ -228,7 +228,7 @@ proptest! {
 						// Test invalid chat id
 						invalid_trigger = trigger.clone();
 						if let TriggerTypeConfig::Telegram { chat_id: c, .. } = &mut invalid_trigger.config {
-							*c = "   ".to_string();
+							*c = SecretValue::Plain(SecretString::new("   ".to_string()));
 						}
 						prop_assert!(invalid_trigger.validate().is_err());
 
@@ -253,6 +253,139 @@ proptest! {
 						prop_assert!(invalid_trigger.validate().is_err());
 					}
 				}
+				TriggerType::PagerDuty => {
+					if let TriggerTypeConfig::PagerDuty { routing_key: _, severity: _, message: _ } = &trigger.config {
+						// Test empty routing key
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::PagerDuty { routing_key: k, .. } = &mut invalid_trigger.config {
+							*k = SecretValue::Plain(SecretString::new("   ".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test empty title
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::PagerDuty { message: m, .. } = &mut invalid_trigger.config {
+							*m = NotificationMessage {
+								title: "".to_string(),
+								body: "test".to_string(),
+							};
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Nats => {
+					if let TriggerTypeConfig::Nats { server_url: _, subject: _, token: _, jetstream: _, ack_timeout_ms: _, message: _ } = &trigger.config {
+						// Test invalid server URL
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Nats { server_url: u, .. } = &mut invalid_trigger.config {
+							*u = SecretValue::Plain(SecretString::new("http://localhost:4222".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test wildcard subject
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Nats { subject: s, .. } = &mut invalid_trigger.config {
+							*s = format!("{}.>", s);
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test zero acknowledgement timeout
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Nats { ack_timeout_ms: a, .. } = &mut invalid_trigger.config {
+							*a = Some(0);
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Matrix => {
+					if let TriggerTypeConfig::Matrix { homeserver_url: _, room_id: _, access_token: _, html: _, message: _ } = &trigger.config {
+						// Test invalid homeserver URL
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Matrix { homeserver_url: u, .. } = &mut invalid_trigger.config {
+							*u = "matrix.example.com".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test room alias instead of room ID
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Matrix { room_id: r, .. } = &mut invalid_trigger.config {
+							*r = r.replacen('!', "#", 1);
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test empty access token
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Matrix { access_token: t, .. } = &mut invalid_trigger.config {
+							*t = SecretValue::Plain(SecretString::new("".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::GoogleChat => {
+					if let TriggerTypeConfig::GoogleChat { google_chat_url: _, message: _ } = &trigger.config {
+						// Test invalid webhook URL
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::GoogleChat { google_chat_url: u, .. } = &mut invalid_trigger.config {
+							*u = SecretValue::Plain(SecretString::new("https://invalid-url.com".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test empty title
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::GoogleChat { message: m, .. } = &mut invalid_trigger.config {
+							m.title = "".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Teams => {
+					if let TriggerTypeConfig::Teams { teams_url: _, message: _ } = &trigger.config {
+						// Test invalid webhook URL
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Teams { teams_url: u, .. } = &mut invalid_trigger.config {
+							*u = SecretValue::Plain(SecretString::new("http://example.webhook.office.com".to_string()));
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test empty title
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Teams { message: m, .. } = &mut invalid_trigger.config {
+							m.title = "".to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
+				TriggerType::Twilio => {
+					if let TriggerTypeConfig::Twilio { account_sid: _, auth_token: _, from: _, to: _, max_messages_per_hour: _, message: _ } = &trigger.config {
+						// Test invalid account SID
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Twilio { account_sid: a, .. } = &mut invalid_trigger.config {
+							*a = a.replacen("AC", "SK", 1);
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test phone number without country code
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Twilio { from: f, .. } = &mut invalid_trigger.config {
+							*f = f.trim_start_matches('+').to_string();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test empty recipients
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Twilio { to: t, .. } = &mut invalid_trigger.config {
+							t.clear();
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+
+						// Test zero messages per hour
+						invalid_trigger = trigger.clone();
+						if let TriggerTypeConfig::Twilio { max_messages_per_hour: m, .. } = &mut invalid_trigger.config {
+							*m = Some(0);
+						}
+						prop_assert!(invalid_trigger.validate().is_err());
+					}
+				}
 				TriggerType::Script => {
 					if let TriggerTypeConfig::Script { script_path: _, arguments: _, language: _, timeout_ms: _ } = &trigger.config {
 						// Test invalid path
@@ -1,10 +1,10 @@
 use email_address::EmailAddress;
 use openzeppelin_monitor::{
 	models::{
-		AddressWithSpec, BlockChainType, EventCondition, FunctionCondition, MatchConditions,
-		Monitor, Network, NotificationMessage, RpcUrl, ScriptLanguage, SecretString, SecretValue,
-		TransactionCondition, TransactionStatus, Trigger, TriggerConditions, TriggerType,
-		TriggerTypeConfig,
+		AddressWithSpec, BlockChainType, ConditionLogic, EventCondition, FunctionCondition,
+		MatchConditions, Monitor, Network, NotificationMessage, PagerDutySeverity, RpcUrl,
+		ScriptLanguage, SecretString, SecretValue, SmtpTlsMode, TransactionCondition,
+		TransactionStatus, Trigger, TriggerConditions, TriggerType, TriggerTypeConfig,
 	},
 	utils::tests::{
 		evm::monitor::MonitorBuilder, network::NetworkBuilder, trigger::TriggerBuilder,
@@ -37,6 +37,7 @@ pub fn monitor_strategy(
 				AddressWithSpec {
 					address,
 					contract_spec: None,
+					match_conditions: None,
 				}
 			}),
 			MIN_COLLECTION_SIZE..MAX_ADDRESSES,
@@ -103,6 +104,42 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 				.trigger_type(trigger_type)
 				.config(config)
 				.build(),),
+		// Google Chat strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::GoogleChat),
+			(
+				"https://chat\\.googleapis\\.com/v1/spaces/[a-zA-Z0-9]+/messages".prop_map(|s| s.to_string()),
+				notification_message_strategy(),
+			)
+				.prop_map(|(google_chat_url, message)| TriggerTypeConfig::GoogleChat {
+					google_chat_url: SecretValue::Plain(SecretString::new(google_chat_url)),
+					message,
+				})
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
+		// Teams strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::Teams),
+			(
+				"https://[a-z0-9]+\\.webhook\\.office\\.com/webhookb2/[a-zA-Z0-9]+".prop_map(|s| s.to_string()),
+				notification_message_strategy(),
+			)
+				.prop_map(|(teams_url, message)| TriggerTypeConfig::Teams {
+					teams_url: SecretValue::Plain(SecretString::new(teams_url)),
+					message,
+				})
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
 		// Email strategy
 		(
 			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
@@ -110,6 +147,11 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 			(
 				"smtp\\.[a-z0-9]+\\.com".prop_map(|s| s.to_string()),
 				option::of(1..65535u16),
+				option::of(prop_oneof![
+					Just(SmtpTlsMode::Tls),
+					Just(SmtpTlsMode::StartTls),
+					Just(SmtpTlsMode::Disabled),
+				]),
 				"[a-zA-Z0-9]+".prop_map(|s| s.to_string()),
 				"[a-zA-Z0-9]+".prop_map(|s| s.to_string()),
 				notification_message_strategy(),
@@ -118,17 +160,30 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 					"[a-zA-Z0-9]+@[a-z0-9]+\\.com".prop_map(|s| EmailAddress::new_unchecked(&s)),
 					1..5,
 				),
+				option::of(1..1440u64),
 			)
 				.prop_map(
-					|(host, port, username, password, message, sender, recipients)| {
+					|(
+						host,
+						port,
+						tls,
+						username,
+						password,
+						message,
+						sender,
+						recipients,
+						digest_interval_mins,
+					)| {
 						TriggerTypeConfig::Email {
 							host,
 							port,
+							tls,
 							username: SecretValue::Plain(SecretString::new(username)),
 							password: SecretValue::Plain(SecretString::new(password)),
 							message,
 							sender,
 							recipients,
+							digest_interval_mins,
 						}
 					}
 				)
@@ -159,6 +214,122 @@ pub fn trigger_strategy() -> impl Strategy<Value = Trigger> {
 						method,
 						headers,
 						secret: secret.map(|s| SecretValue::Plain(SecretString::new(s))),
+						include_match: None,
+						match_schema_version: None,
+						max_retries: None,
+						message,
+					}
+				})
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
+		// PagerDuty strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::PagerDuty),
+			(
+				"[a-zA-Z0-9]{32}".prop_map(|s| s.to_string()),
+				option::of(prop_oneof![
+					Just(PagerDutySeverity::Critical),
+					Just(PagerDutySeverity::Error),
+					Just(PagerDutySeverity::Warning),
+					Just(PagerDutySeverity::Info),
+				]),
+				notification_message_strategy(),
+			)
+				.prop_map(
+					|(routing_key, severity, message)| {
+						TriggerTypeConfig::PagerDuty {
+							routing_key: SecretValue::Plain(SecretString::new(routing_key)),
+							severity,
+							message,
+						}
+					}
+				)
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
+		// NATS strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::Nats),
+			(
+				"nats://[a-z0-9-]+(:[0-9]{4})?".prop_map(|s| s.to_string()),
+				"[a-z0-9_-]{1,10}(\\.[a-z0-9_-]{1,10}){0,3}".prop_map(|s| s.to_string()),
+				option::of("[a-zA-Z0-9]{16}".prop_map(|s| s.to_string())),
+				option::of(any::<bool>()),
+				option::of(1..60000u64),
+				notification_message_strategy(),
+			)
+				.prop_map(
+					|(server_url, subject, token, jetstream, ack_timeout_ms, message)| {
+						TriggerTypeConfig::Nats {
+							server_url: SecretValue::Plain(SecretString::new(server_url)),
+							subject,
+							token: token.map(|t| SecretValue::Plain(SecretString::new(t))),
+							jetstream,
+							ack_timeout_ms,
+							message,
+						}
+					}
+				)
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
+		// Matrix strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::Matrix),
+			(
+				"https://[a-z0-9-]+\\.[a-z]{2,}".prop_map(|s| s.to_string()),
+				"![a-zA-Z0-9]{1,18}:[a-z0-9-]+\\.[a-z]{2,}".prop_map(|s| s.to_string()),
+				"[a-zA-Z0-9_]{16,40}".prop_map(|s| s.to_string()),
+				option::of(any::<bool>()),
+				notification_message_strategy(),
+			)
+				.prop_map(|(homeserver_url, room_id, access_token, html, message)| {
+					TriggerTypeConfig::Matrix {
+						homeserver_url,
+						room_id,
+						access_token: SecretValue::Plain(SecretString::new(access_token)),
+						html,
+						message,
+					}
+				})
+		)
+			.prop_map(|(name, trigger_type, config)| TriggerBuilder::new()
+				.name(name.as_str())
+				.trigger_type(trigger_type)
+				.config(config)
+				.build(),),
+		// Twilio strategy
+		(
+			"[a-zA-Z0-9_]{1,10}".prop_map(|s| s.to_string()),
+			Just(TriggerType::Twilio),
+			(
+				"AC[a-f0-9]{32}".prop_map(|s| s.to_string()),
+				"[a-zA-Z0-9]{32}".prop_map(|s| s.to_string()),
+				"\\+[1-9][0-9]{7,14}".prop_map(|s| s.to_string()),
+				proptest::collection::vec("\\+[1-9][0-9]{7,14}".prop_map(|s| s.to_string()), 1..3),
+				option::of(1u32..100u32),
+				notification_message_strategy(),
+			)
+				.prop_map(|(account_sid, auth_token, from, to, max_messages_per_hour, message)| {
+					TriggerTypeConfig::Twilio {
+						account_sid,
+						auth_token: SecretValue::Plain(SecretString::new(auth_token)),
+						from,
+						to,
+						max_messages_per_hour,
 						message,
 					}
 				})
@@ -181,6 +352,9 @@ pub fn rpc_url_strategy() -> impl Strategy<Value = RpcUrl> {
 			type_,
 			url: SecretValue::Plain(SecretString::new(url)),
 			weight,
+			rate_limit: None,
+			headers: None,
+			auth: None,
 		})
 }
 
@@ -278,6 +452,12 @@ pub fn match_conditions_strategy() -> impl Strategy<Value = MatchConditions> {
 			functions,
 			events,
 			transactions,
+			accounts: vec![],
+			balance_changes: vec![],
+			logs: vec![],
+			sequences: vec![],
+			blocks: vec![],
+			condition_logic: ConditionLogic::All,
 		})
 }
 